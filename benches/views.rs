@@ -0,0 +1,165 @@
+//! Rendering benchmarks for the widgets with the largest views.
+//!
+//! Each benchmark renders one frame of a widget at a realistic terminal
+//! size, as a program does after every message. Run them with
+//! `cargo bench --bench views`.
+//!
+//! Mean time per frame before and after the table render cache and the
+//! textarea style hoisting (`--warm-up-time 1 --measurement-time 3`):
+//!
+//! | Benchmark                      | Before    | After     |
+//! |--------------------------------|-----------|-----------|
+//! | list view 1000 items           | 73 µs     | 74 µs     |
+//! | table view 1000 rows           | 296 ms    | 132 µs    |
+//! | table move and view 1000 rows  | 250 ms    | 254 ms    |
+//! | viewport view 10000 lines      | 4.0 µs    | 2.3 µs    |
+//! | textarea view 500 lines        | 4.38 ms   | 2.42 ms   |
+//!
+//! The list and viewport code did not change; their differences are
+//! run-to-run noise.
+//!
+//! The table before and after rendering and hashing only the rows in view,
+//! keyed on a generation counter instead of its formatted styles:
+//!
+//! | Benchmark                      | Before    | After     |
+//! |--------------------------------|-----------|-----------|
+//! | table view 1000 rows           | 132 µs    | 3.9 µs    |
+//! | table view_into 1000 rows      | -         | 3.4 µs    |
+//! | table move and view 1000 rows  | 254 ms    | 1.45 ms   |
+//!
+//! The text area before and after moving its lines into a chunked buffer
+//! with per-line row counts, and drawing only the rows in view:
+//!
+//! | Benchmark                           | Before    | After     |
+//! |-------------------------------------|-----------|-----------|
+//! | textarea view 500 lines             | 2.65 ms   | 168 µs    |
+//! | textarea type and view 10000 lines  | 83.7 ms   | 426 µs    |
+
+use bubbletea_rs::{KeyMsg, Model as BubbleTeaModel};
+use bubbletea_widgets::list::{DefaultDelegate, DefaultItem, Model as List};
+use bubbletea_widgets::table::{Column, Model as Table, Row};
+use bubbletea_widgets::{textarea, viewport, Component};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const WIDTH: usize = 100;
+const HEIGHT: usize = 30;
+
+fn lorem(i: usize) -> String {
+    format!(
+        "{} the quick brown fox jumps over the lazy dog, line {} of the sample text",
+        ["Alpha", "Bravo", "Charlie", "Delta", "Echo"][i % 5],
+        i
+    )
+}
+
+fn list(c: &mut Criterion) {
+    let items: Vec<DefaultItem> = (0..1_000)
+        .map(|i| DefaultItem::new(&format!("Item {}", i), &lorem(i)))
+        .collect();
+    let list = List::new(items, DefaultDelegate::new(), WIDTH, HEIGHT).with_title("Items");
+    c.bench_function("list view 1000 items", |b| {
+        b.iter(|| black_box(list.view()))
+    });
+}
+
+fn table(c: &mut Criterion) {
+    let columns = vec![
+        Column::new("ID", 6),
+        Column::new("Name", 20),
+        Column::new("Status", 10),
+        Column::new("Owner", 16),
+        Column::new("Description", 40),
+    ];
+    let rows: Vec<Row> = (0..1_000)
+        .map(|i| {
+            Row::new(vec![
+                i.to_string(),
+                format!("Task {}", i),
+                ["open", "closed", "blocked"][i % 3].to_string(),
+                format!("user{}", i % 17),
+                lorem(i),
+            ])
+        })
+        .collect();
+    let mut table = Table::new(columns).with_rows(rows);
+    table.set_width(WIDTH as i32);
+    table.set_height(HEIGHT as i32);
+    c.bench_function("table view 1000 rows", |b| {
+        b.iter(|| black_box(table.view()))
+    });
+    let mut frame = String::new();
+    c.bench_function("table view_into 1000 rows", |b| {
+        b.iter(|| {
+            frame.clear();
+            table.view_into(&mut frame);
+            black_box(&frame);
+        })
+    });
+    // Every frame after a keypress changes the selection, so nothing is reused
+    let mut down = true;
+    c.bench_function("table move and view 1000 rows", |b| {
+        b.iter(|| {
+            if down {
+                table.move_down(1);
+            } else {
+                table.move_up(1);
+            }
+            down = !down;
+            black_box(table.view())
+        })
+    });
+}
+
+fn viewport(c: &mut Criterion) {
+    let content: Vec<String> = (0..10_000).map(lorem).collect();
+    let mut viewport = viewport::new(WIDTH, HEIGHT);
+    viewport.set_content(&content.join("\n"));
+    viewport.set_y_offset(5_000);
+    c.bench_function("viewport view 10000 lines", |b| {
+        b.iter(|| black_box(viewport.view()))
+    });
+}
+
+fn textarea(c: &mut Criterion) {
+    let content: Vec<String> = (0..500).map(lorem).collect();
+    let mut textarea = textarea::new();
+    textarea.set_width(WIDTH);
+    textarea.set_height(HEIGHT);
+    textarea.set_value(content.join("\n"));
+    std::mem::drop(textarea.focus());
+    c.bench_function("textarea view 500 lines", |b| {
+        b.iter(|| black_box(textarea.view()))
+    });
+
+    // A keypress in a long document: the edit, its undo snapshot and the
+    // frame after it
+    let content: Vec<String> = (0..10_000).map(lorem).collect();
+    let mut textarea = textarea::new();
+    textarea.char_limit = 0;
+    textarea.set_max_lines(0);
+    textarea.set_width(WIDTH);
+    textarea.set_height(HEIGHT);
+    textarea.set_value(content.join("\n"));
+    textarea.set_position(5_000, 0);
+    std::mem::drop(textarea.focus());
+    let mut typed = false;
+    c.bench_function("textarea type and view 10000 lines", |b| {
+        b.iter(|| {
+            let key = if typed {
+                KeyCode::Backspace
+            } else {
+                KeyCode::Char('x')
+            };
+            typed = !typed;
+            textarea.update(Some(Box::new(KeyMsg {
+                key,
+                modifiers: KeyModifiers::NONE,
+            })));
+            black_box(textarea.view())
+        })
+    });
+}
+
+criterion_group!(benches, list, table, viewport, textarea);
+criterion_main!(benches);
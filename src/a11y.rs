@@ -0,0 +1,130 @@
+//! Accessible, screen-reader-friendly rendering.
+//!
+//! Turning the mode on with [`set_enabled`] switches the widgets' `view`
+//! to plain text: no colors, no box drawing, no glyphs that only make sense
+//! when seen, and an explicit announcement of state where the normal view
+//! relies on highlighting. A list says `item 3 of 10 selected: Banana`,
+//! a toggle says `Dark mode: on`, a progress bar says `Progress: 45%`.
+//!
+//! The mode is process-wide, like a terminal setting. Each widget that
+//! supports it also has an `accessible_view` method, which renders the
+//! plain form regardless of the mode.
+//!
+//! Widgets with an accessible view: checkbox and radio groups, confirm,
+//! file picker, list, menu, paginator, progress, select, spinner, table,
+//! text area, text input, toggle and viewport.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::{a11y, toggle};
+//!
+//! // Follow the ACCESSIBLE environment variable, as other Charm tools do
+//! a11y::enable_from_env();
+//!
+//! a11y::set_enabled(true);
+//! let t = toggle::Model::new("Dark mode").with_on(true);
+//! assert_eq!(t.view(), "Dark mode: on");
+//! ```
+
+use lipgloss_extras::lipgloss::strip_ansi;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether widgets render their accessible view.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Turns accessible rendering on or off for every widget.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Turns accessible rendering on when the `ACCESSIBLE` environment
+/// variable is set to anything but an empty string, `0` or `false`.
+/// Returns whether it was turned on; the mode is left alone otherwise.
+pub fn enable_from_env() -> bool {
+    let on = std::env::var("ACCESSIBLE")
+        .map(|v| !matches!(v.trim().to_lowercase().as_str(), "" | "0" | "false"))
+        .unwrap_or(false);
+    if on {
+        set_enabled(true);
+    }
+    on
+}
+
+/// Reduces rendered output to plain text: strips styling, drops box
+/// drawing, block and Braille characters, trims each line and removes
+/// empty lines.
+///
+/// ```rust
+/// use bubbletea_widgets::a11y::plain;
+///
+/// assert_eq!(plain("╭────╮\n│ \x1b[1mHi\x1b[0m │\n╰────╯"), "Hi");
+/// ```
+pub fn plain(s: &str) -> String {
+    strip_ansi(s)
+        .lines()
+        .map(|line| {
+            line.chars()
+                .filter(|c| !is_decoration(*c))
+                .collect::<String>()
+                .trim()
+                .to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Box drawing, block elements, geometric shapes and Braille patterns.
+fn is_decoration(c: char) -> bool {
+    matches!(c, '\u{2500}'..='\u{25FF}' | '\u{2800}'..='\u{28FF}')
+}
+
+/// Describes the position of the `index`th of `total` things, counting
+/// from zero: `position("item", 2, 10)` is `"item 3 of 10"`.
+pub fn position(noun: &str, index: usize, total: usize) -> String {
+    format!("{} {} of {}", noun, index + 1, total)
+}
+
+/// Announces the selected one of `total` things with its label, such as
+/// `"item 3 of 10 selected: Banana"`, or that there is nothing to select.
+pub fn selected(noun: &str, index: usize, total: usize, label: &str) -> String {
+    if total == 0 {
+        return format!("no {} to select", noun);
+    }
+    let label = plain(label).replace('\n', " ");
+    if label.is_empty() {
+        format!("{} selected", position(noun, index, total))
+    } else {
+        format!("{} selected: {}", position(noun, index, total), label)
+    }
+}
+
+/// Describes a binary state: `"checked"` or `"not checked"`.
+pub fn checked(on: bool) -> &'static str {
+    if on {
+        "checked"
+    } else {
+        "not checked"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_announcements() {
+        assert_eq!(position("row", 0, 4), "row 1 of 4");
+        assert_eq!(
+            selected("item", 2, 10, "\x1b[1mBanana\x1b[0m"),
+            "item 3 of 10 selected: Banana"
+        );
+        assert_eq!(selected("item", 0, 0, ""), "no item to select");
+        assert_eq!(plain("  ● ○ ○\n\n⠋ Loading"), "Loading");
+    }
+}
@@ -0,0 +1,554 @@
+//! Springs, easing curves and frame ticks for animated widgets.
+//!
+//! The building blocks the crate's own animations use, available to
+//! applications as well:
+//!
+//! - [`Spring`] is a port of [harmonica](https://github.com/charmbracelet/harmonica)'s
+//!   damped spring. It moves a value toward a target with natural-looking
+//!   acceleration and, if underdamped, a little overshoot. Progress bars
+//!   and smooth viewport scrolling use it.
+//! - [`Tween`] interpolates between two values over a fixed duration along
+//!   an [`Easing`] curve. Toast slide-in uses it.
+//! - [`Frames`] is a frame clock: it schedules [`FrameMsg`]s at a steady
+//!   rate and recognizes its own among the messages passed to `update`.
+//!
+//! # Examples
+//!
+//! Driving a spring from `update`:
+//!
+//! ```rust
+//! use bubbletea_rs::{Cmd, Msg};
+//! use bubbletea_widgets::anim::{self, Frames, Spring};
+//!
+//! struct Gauge {
+//!     frames: Frames,
+//!     spring: Spring,
+//!     shown: f64,
+//!     velocity: f64,
+//!     target: f64,
+//! }
+//!
+//! impl Gauge {
+//!     fn set(&mut self, target: f64) -> Option<Cmd> {
+//!         self.target = target;
+//!         self.frames.start()
+//!     }
+//!
+//!     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+//!         if !self.frames.accepts(&msg) {
+//!             return None;
+//!         }
+//!         (self.shown, self.velocity) = self.spring.update(self.shown, self.velocity, self.target);
+//!         if (self.shown - self.target).abs() < 0.01 && self.velocity.abs() < 0.01 {
+//!             self.shown = self.target;
+//!             self.frames.stop();
+//!             return None;
+//!         }
+//!         Some(self.frames.next())
+//!     }
+//! }
+//!
+//! let mut gauge = Gauge {
+//!     frames: Frames::new(),
+//!     spring: Spring::new(anim::fps(anim::FPS), 6.0, 0.5),
+//!     shown: 0.0,
+//!     velocity: 0.0,
+//!     target: 0.0,
+//! };
+//! assert!(gauge.set(100.0).is_some());
+//! ```
+//!
+//! Easing a value over time:
+//!
+//! ```rust
+//! use bubbletea_widgets::anim::{Easing, Tween};
+//! use std::time::Duration;
+//!
+//! let mut tween = Tween::new(0.0, 10.0, Duration::from_millis(200)).with_easing(Easing::OutCubic);
+//! tween.advance(Duration::from_millis(100));
+//! assert!(tween.value() > 5.0); // ease-out covers most of the way early
+//! tween.advance(Duration::from_millis(100));
+//! assert!(tween.is_done());
+//! assert_eq!(tween.value(), 10.0);
+//! ```
+
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, Msg};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+/// The frame rate the crate's animations run at.
+pub const FPS: u32 = 60;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+/// Returns a new animation ID, unique among everything that sends
+/// [`FrameMsg`]s.
+pub fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Returns the time between frames at `n` frames per second, in seconds:
+/// the `delta_time` a [`Spring`] steps by.
+pub fn fps(n: u32) -> f64 {
+    frame_duration(n).as_secs_f64()
+}
+
+fn frame_duration(fps: u32) -> Duration {
+    Duration::from_nanos(1_000_000_000 / u64::from(fps.max(1)))
+}
+
+const EPSILON: f64 = f64::EPSILON;
+
+/// A damped spring, stepped one frame at a time.
+///
+/// The spring's behaviour is set by its angular frequency, which is how
+/// fast it moves, and its damping ratio:
+///
+/// - below `1.0` it is underdamped and overshoots the target before
+///   settling, bouncing more the closer the ratio is to zero;
+/// - at `1.0` it is critically damped and reaches the target as fast as
+///   possible without overshooting;
+/// - above `1.0` it is overdamped and creeps toward the target.
+///
+/// The motion is solved exactly rather than integrated, so a spring stays
+/// stable at any frame rate and any frequency.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::anim::{self, Spring};
+///
+/// let spring = Spring::new(anim::fps(60), 18.0, 1.0);
+/// let (mut pos, mut vel) = (0.0, 0.0);
+/// for _ in 0..60 {
+///     (pos, vel) = spring.update(pos, vel, 1.0);
+/// }
+/// assert!((pos - 1.0).abs() < 0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    delta_time: f64,
+    angular_frequency: f64,
+    damping_ratio: f64,
+    pos_pos: f64,
+    pos_vel: f64,
+    vel_pos: f64,
+    vel_vel: f64,
+}
+
+impl Spring {
+    /// Creates a spring that steps by `delta_time` seconds, usually
+    /// [`fps`]`(n)`. Negative frequencies and ratios are taken as zero.
+    pub fn new(delta_time: f64, angular_frequency: f64, damping_ratio: f64) -> Self {
+        let af = angular_frequency.max(0.0);
+        let dr = damping_ratio.max(0.0);
+        let mut spring = Self {
+            delta_time,
+            angular_frequency: af,
+            damping_ratio: dr,
+            pos_pos: 1.0,
+            pos_vel: 0.0,
+            vel_pos: 0.0,
+            vel_vel: 1.0,
+        };
+        if af < EPSILON {
+            // No force: the value keeps its position and velocity.
+            return spring;
+        }
+
+        if dr > 1.0 + EPSILON {
+            // Overdamped
+            let za = -af * dr;
+            let zb = af * (dr * dr - 1.0).sqrt();
+            let z1 = za - zb;
+            let z2 = za + zb;
+            let e1 = (z1 * delta_time).exp();
+            let e2 = (z2 * delta_time).exp();
+            let inv_two_zb = 1.0 / (2.0 * zb);
+            let e1_over_two_zb = e1 * inv_two_zb;
+            let e2_over_two_zb = e2 * inv_two_zb;
+            let z1e1_over_two_zb = z1 * e1_over_two_zb;
+            let z2e2_over_two_zb = z2 * e2_over_two_zb;
+            spring.pos_pos = e1_over_two_zb * z2 - z2e2_over_two_zb + e2;
+            spring.pos_vel = -e1_over_two_zb + e2_over_two_zb;
+            spring.vel_pos = (z1e1_over_two_zb - z2e2_over_two_zb + e2) * z2;
+            spring.vel_vel = -z1e1_over_two_zb + z2e2_over_two_zb;
+        } else if dr < 1.0 - EPSILON {
+            // Underdamped
+            let omega_zeta = af * dr;
+            let alpha = af * (1.0 - dr * dr).sqrt();
+            let exp_term = (-omega_zeta * delta_time).exp();
+            let cos_term = (alpha * delta_time).cos();
+            let sin_term = (alpha * delta_time).sin();
+            let inv_alpha = 1.0 / alpha;
+            let exp_sin = exp_term * sin_term;
+            let exp_cos = exp_term * cos_term;
+            let exp_omega_zeta_sin_over_alpha = exp_term * omega_zeta * sin_term * inv_alpha;
+            spring.pos_pos = exp_cos + exp_omega_zeta_sin_over_alpha;
+            spring.pos_vel = exp_sin * inv_alpha;
+            spring.vel_pos = -exp_sin * alpha - omega_zeta * exp_omega_zeta_sin_over_alpha;
+            spring.vel_vel = exp_cos - exp_omega_zeta_sin_over_alpha;
+        } else {
+            // Critically damped
+            let exp_term = (-af * delta_time).exp();
+            let time_exp = delta_time * exp_term;
+            let time_exp_freq = time_exp * af;
+            spring.pos_pos = time_exp_freq + exp_term;
+            spring.pos_vel = time_exp;
+            spring.vel_pos = -af * time_exp_freq;
+            spring.vel_vel = -time_exp_freq + exp_term;
+        }
+        spring
+    }
+
+    /// Advances one frame from `pos` moving at `vel` toward `target`, and
+    /// returns the new position and velocity.
+    pub fn update(&self, pos: f64, vel: f64, target: f64) -> (f64, f64) {
+        let offset = pos - target;
+        (
+            offset * self.pos_pos + vel * self.pos_vel + target,
+            offset * self.vel_pos + vel * self.vel_vel,
+        )
+    }
+
+    /// Returns the time step in seconds.
+    pub fn delta_time(&self) -> f64 {
+        self.delta_time
+    }
+
+    /// Returns the angular frequency.
+    pub fn angular_frequency(&self) -> f64 {
+        self.angular_frequency
+    }
+
+    /// Returns the damping ratio.
+    pub fn damping_ratio(&self) -> f64 {
+        self.damping_ratio
+    }
+}
+
+/// The shape of a [`Tween`]: how far along the change is at each point in
+/// time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Starts slowly and speeds up.
+    InQuad,
+    /// Starts fast and slows down.
+    OutQuad,
+    /// Slow at both ends.
+    InOutQuad,
+    /// Like [`InQuad`](Self::InQuad), more pronounced.
+    InCubic,
+    /// Like [`OutQuad`](Self::OutQuad), more pronounced.
+    OutCubic,
+    /// Like [`InOutQuad`](Self::InOutQuad), more pronounced.
+    InOutCubic,
+}
+
+impl Easing {
+    /// Maps the fraction of time elapsed, `t` from 0.0 to 1.0, to the
+    /// fraction of the change made. `t` outside that range is clamped.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::InQuad => t * t,
+            Easing::OutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::InOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::InCubic => t * t * t,
+            Easing::OutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::InOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Returns the value `t` of the way from `from` to `to`.
+pub fn lerp(from: f64, to: f64, t: f64) -> f64 {
+    from + (to - from) * t
+}
+
+/// A change from one value to another over a fixed duration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    /// The starting value.
+    pub from: f64,
+    /// The final value.
+    pub to: f64,
+    /// How long the change takes.
+    pub duration: Duration,
+    /// The curve the change follows.
+    pub easing: Easing,
+    elapsed: Duration,
+}
+
+impl Tween {
+    /// Creates a linear tween from `from` to `to` taking `duration`.
+    pub fn new(from: f64, to: f64, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing: Easing::Linear,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Sets the easing curve.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Moves the tween `dt` further along and returns the new value.
+    pub fn advance(&mut self, dt: Duration) -> f64 {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        self.value()
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        lerp(self.from, self.to, self.easing.apply(self.progress()))
+    }
+
+    /// Returns the fraction of the duration elapsed, from 0.0 to 1.0.
+    pub fn progress(&self) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        self.elapsed.as_secs_f64() / self.duration.as_secs_f64()
+    }
+
+    /// Returns the time elapsed so far.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns whether the tween has reached its final value.
+    pub fn is_done(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Asks the animation with the matching `id` and `tag` to draw its next
+/// frame.
+///
+/// The `tag` changes whenever an animation is restarted or stopped, so
+/// frames scheduled before then are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameMsg {
+    /// The animation the frame belongs to, from [`next_id`].
+    pub id: i64,
+    /// The run of the animation the frame belongs to.
+    pub tag: i64,
+}
+
+/// Returns a command that sends a [`FrameMsg`] for `id` and `tag` one
+/// frame from now, at [`FPS`].
+pub fn frame(id: i64, tag: i64) -> Cmd {
+    bubbletea_tick(frame_duration(FPS), move |_| {
+        Box::new(FrameMsg { id, tag }) as Msg
+    })
+}
+
+/// A frame clock for one animation.
+///
+/// [`start`](Self::start) schedules the first frame; for every frame
+/// [`accepts`](Self::accepts) returns true, advance the animation and
+/// either schedule the [`next`](Self::next) frame or [`stop`](Self::stop)
+/// the clock.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    id: i64,
+    tag: i64,
+    running: bool,
+    fps: u32,
+}
+
+impl Default for Frames {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Frames {
+    /// Creates a stopped clock running at [`FPS`].
+    pub fn new() -> Self {
+        Self {
+            id: next_id(),
+            tag: 0,
+            running: false,
+            fps: FPS,
+        }
+    }
+
+    /// Sets the frame rate.
+    pub fn with_fps(mut self, fps: u32) -> Self {
+        self.fps = fps.max(1);
+        self
+    }
+
+    /// Returns the ID carried by this clock's frames.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the tag of the current run, carried by its frames.
+    pub fn tag(&self) -> i64 {
+        self.tag
+    }
+
+    /// Returns the time between frames.
+    pub fn interval(&self) -> Duration {
+        frame_duration(self.fps)
+    }
+
+    /// Returns whether frames are being scheduled.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Starts the clock and returns the command for the first frame, or
+    /// `None` if it is already running.
+    pub fn start(&mut self) -> Option<Cmd> {
+        if self.running {
+            return None;
+        }
+        self.running = true;
+        self.tag += 1;
+        Some(self.next())
+    }
+
+    /// Stops the clock. Frames already scheduled are ignored.
+    pub fn stop(&mut self) {
+        self.running = false;
+        self.tag += 1;
+    }
+
+    /// Returns whether `msg` is the frame this clock is waiting for.
+    pub fn accepts(&self, msg: &Msg) -> bool {
+        self.running
+            && msg
+                .downcast_ref::<FrameMsg>()
+                .is_some_and(|frame| frame.id == self.id && frame.tag == self.tag)
+    }
+
+    /// Returns the command for the next frame.
+    pub fn next(&self) -> Cmd {
+        let (id, tag) = (self.id, self.tag);
+        bubbletea_tick(self.interval(), move |_| {
+            Box::new(FrameMsg { id, tag }) as Msg
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settle(spring: Spring) -> (f64, f64, usize) {
+        let (mut pos, mut vel, mut peak) = (0.0, 0.0, 0.0f64);
+        for frame in 1..=600 {
+            (pos, vel) = spring.update(pos, vel, 1.0);
+            peak = peak.max(pos);
+            if (pos - 1.0).abs() < 0.001 && vel.abs() < 0.01 {
+                return (pos, peak, frame);
+            }
+        }
+        (pos, peak, usize::MAX)
+    }
+
+    #[test]
+    fn test_spring_damping_regimes() {
+        let dt = fps(60);
+        // Critically damped: settles without overshoot
+        let (pos, peak, frames) = settle(Spring::new(dt, 18.0, 1.0));
+        assert!((pos - 1.0).abs() < 0.001);
+        assert!(peak <= 1.0 + 1e-9, "peak {peak}");
+        assert!(frames < 60, "took {frames} frames");
+        // Underdamped: overshoots, then settles
+        let (_, peak, frames) = settle(Spring::new(dt, 18.0, 0.3));
+        assert!(peak > 1.05, "peak {peak}");
+        assert!(frames < 600);
+        // Overdamped: no overshoot, slower than critical
+        let (_, peak, slow) = settle(Spring::new(dt, 18.0, 3.0));
+        assert!(peak <= 1.0 + 1e-9);
+        assert!(slow > settle(Spring::new(dt, 18.0, 1.0)).2);
+        // Zero frequency: nothing moves
+        assert_eq!(Spring::new(dt, 0.0, 1.0).update(0.5, 0.0, 1.0), (0.5, 0.0));
+    }
+
+    #[test]
+    fn test_easing_endpoints_and_shape() {
+        let all = [
+            Easing::Linear,
+            Easing::InQuad,
+            Easing::OutQuad,
+            Easing::InOutQuad,
+            Easing::InCubic,
+            Easing::OutCubic,
+            Easing::InOutCubic,
+        ];
+        for easing in all {
+            assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-12, "{easing:?}");
+            assert_eq!(easing.apply(2.0), easing.apply(1.0));
+        }
+        assert!(Easing::InQuad.apply(0.5) < 0.5);
+        assert!(Easing::OutCubic.apply(0.5) > Easing::OutQuad.apply(0.5));
+        assert!((Easing::InOutCubic.apply(0.5) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_tween_advances_and_clamps() {
+        let mut tween = Tween::new(10.0, 20.0, Duration::from_millis(100));
+        assert_eq!(tween.value(), 10.0);
+        assert_eq!(tween.advance(Duration::from_millis(50)), 15.0);
+        assert!(!tween.is_done());
+        assert_eq!(tween.advance(Duration::from_secs(1)), 20.0);
+        assert!(tween.is_done());
+        assert_eq!(tween.elapsed(), Duration::from_millis(100));
+        assert!(Tween::new(0.0, 1.0, Duration::ZERO).is_done());
+    }
+
+    #[test]
+    fn test_frames_accept_only_current_run() {
+        let mut frames = Frames::new();
+        let own = |f: &Frames, tag: i64| -> Msg { Box::new(FrameMsg { id: f.id(), tag }) };
+        assert!(
+            !frames.accepts(&own(&frames, frames.tag())),
+            "stopped clock"
+        );
+        assert!(frames.start().is_some());
+        assert!(frames.start().is_none(), "already running");
+        let first = frames.tag();
+        assert!(frames.accepts(&own(&frames, first)));
+        assert!(!frames.accepts(&own(&frames, first - 1)), "stale run");
+        let other = Frames::new();
+        assert!(!frames.accepts(&own(&other, first)), "other clock");
+
+        frames.stop();
+        assert!(!frames.accepts(&own(&frames, first)));
+        assert!(frames.start().is_some());
+        assert!(
+            !frames.accepts(&own(&frames, first)),
+            "frames from before the stop"
+        );
+        assert!(frames.accepts(&own(&frames, frames.tag())));
+    }
+}
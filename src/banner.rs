@@ -0,0 +1,416 @@
+//! Large block-letter text.
+//!
+//! A banner draws text five rows tall with an embedded block font, for
+//! splash screens and section headers. The font covers letters (lowercase is
+//! drawn as uppercase), digits and common punctuation; other characters are
+//! drawn as `?`.
+//!
+//! Banners are colored with a gradient across their width, or down their
+//! height with [`Direction::Vertical`]. With a `width` set, the text is
+//! word-wrapped to it and each line is aligned left, centered or right.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::banner;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let title = banner::new("HI!").with_fill('#');
+//! assert_eq!(
+//!     strip_ansi(&title.view()),
+//!     [
+//!         "#   # ### #",
+//!         "#   #  #  #",
+//!         "#####  #  #",
+//!         "#   #  #",
+//!         "#   # ### #",
+//!     ]
+//!     .join("\n")
+//! );
+//! ```
+
+pub use crate::text::Align;
+use crate::theme::Theme;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::lipgloss::blending::blend_1d;
+use lipgloss_extras::prelude::*;
+
+/// Height of the font in rows.
+const HEIGHT: usize = 5;
+
+/// The embedded font. `#` marks a filled cell.
+const FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [" ### ", "#   #", "#####", "#   #", "#   #"]),
+    ('B', ["#### ", "#   #", "#### ", "#   #", "#### "]),
+    ('C', [" ####", "#    ", "#    ", "#    ", " ####"]),
+    ('D', ["#### ", "#   #", "#   #", "#   #", "#### "]),
+    ('E', ["#####", "#    ", "#### ", "#    ", "#####"]),
+    ('F', ["#####", "#    ", "#### ", "#    ", "#    "]),
+    ('G', [" ####", "#    ", "#  ##", "#   #", " ####"]),
+    ('H', ["#   #", "#   #", "#####", "#   #", "#   #"]),
+    ('I', ["###", " # ", " # ", " # ", "###"]),
+    ('J', ["    #", "    #", "    #", "#   #", " ### "]),
+    ('K', ["#   #", "#  # ", "###  ", "#  # ", "#   #"]),
+    ('L', ["#    ", "#    ", "#    ", "#    ", "#####"]),
+    ('M', ["#   #", "## ##", "# # #", "#   #", "#   #"]),
+    ('N', ["#   #", "##  #", "# # #", "#  ##", "#   #"]),
+    ('O', [" ### ", "#   #", "#   #", "#   #", " ### "]),
+    ('P', ["#### ", "#   #", "#### ", "#    ", "#    "]),
+    ('Q', [" ### ", "#   #", "# # #", "#  # ", " ## #"]),
+    ('R', ["#### ", "#   #", "#### ", "#  # ", "#   #"]),
+    ('S', [" ####", "#    ", " ### ", "    #", "#### "]),
+    ('T', ["#####", "  #  ", "  #  ", "  #  ", "  #  "]),
+    ('U', ["#   #", "#   #", "#   #", "#   #", " ### "]),
+    ('V', ["#   #", "#   #", "#   #", " # # ", "  #  "]),
+    ('W', ["#   #", "#   #", "# # #", "## ##", "#   #"]),
+    ('X', ["#   #", " # # ", "  #  ", " # # ", "#   #"]),
+    ('Y', ["#   #", " # # ", "  #  ", "  #  ", "  #  "]),
+    ('Z', ["#####", "   # ", "  #  ", " #   ", "#####"]),
+    ('0', [" ### ", "#  ##", "# # #", "##  #", " ### "]),
+    ('1', [" # ", "## ", " # ", " # ", "###"]),
+    ('2', ["#### ", "    #", " ### ", "#    ", "#####"]),
+    ('3', ["#### ", "    #", " ### ", "    #", "#### "]),
+    ('4', ["#   #", "#   #", "#####", "    #", "    #"]),
+    ('5', ["#####", "#    ", "#### ", "    #", "#### "]),
+    ('6', [" ### ", "#    ", "#### ", "#   #", " ### "]),
+    ('7', ["#####", "    #", "   # ", "  #  ", "  #  "]),
+    ('8', [" ### ", "#   #", " ### ", "#   #", " ### "]),
+    ('9', [" ### ", "#   #", " ####", "    #", " ### "]),
+    (' ', ["   ", "   ", "   ", "   ", "   "]),
+    ('.', [" ", " ", " ", " ", "#"]),
+    (',', ["  ", "  ", "  ", " #", "# "]),
+    ('!', ["#", "#", "#", " ", "#"]),
+    ('?', ["### ", "   #", " ## ", "    ", " #  "]),
+    (':', [" ", "#", " ", "#", " "]),
+    ('\'', ["#", "#", " ", " ", " "]),
+    ('-', ["   ", "   ", "###", "   ", "   "]),
+    ('_', ["    ", "    ", "    ", "    ", "####"]),
+    ('+', ["   ", " # ", "###", " # ", "   "]),
+    ('=', ["   ", "###", "   ", "###", "   "]),
+    ('/', ["    #", "   # ", "  #  ", " #   ", "#    "]),
+    ('(', [" #", "# ", "# ", "# ", " #"]),
+    (')', ["# ", " #", " #", " #", "# "]),
+];
+
+fn glyph(c: char) -> &'static [&'static str; HEIGHT] {
+    let c = c.to_ascii_uppercase();
+    FONT.iter()
+        .find(|(g, _)| *g == c)
+        .or_else(|| FONT.iter().find(|(g, _)| *g == '?'))
+        .map(|(_, rows)| rows)
+        .expect("font has a fallback glyph")
+}
+
+/// Returns the width of `text` drawn in the font, with one column between
+/// letters.
+fn text_width(text: &str) -> usize {
+    let glyphs: usize = text.chars().map(|c| glyph(c)[0].len()).sum();
+    glyphs + text.chars().count().saturating_sub(1)
+}
+
+/// Which way the gradient runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// From the left edge to the right edge.
+    #[default]
+    Horizontal,
+    /// From the top row to the bottom row.
+    Vertical,
+}
+
+/// Visual styles for the banner.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the letters when there is no gradient.
+    pub text: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            text: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates banner styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            text: Style::new().foreground(theme.palette.primary.clone()),
+        }
+    }
+}
+
+/// Text drawn in large block letters.
+#[derive(Debug, Clone)]
+pub struct Model {
+    text: String,
+    /// Width to wrap and align within; 0 fits the text.
+    pub width: usize,
+    /// Placement of each line within `width`.
+    pub align: Align,
+    /// Gradient stops; fewer than two uses `styles.text` or the one color.
+    pub gradient: Vec<Color>,
+    /// Which way the gradient runs.
+    pub direction: Direction,
+    /// Character drawn for filled cells.
+    pub fill: char,
+    /// Blank rows between wrapped lines.
+    pub line_gap: usize,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a banner for `text` with the default gradient.
+pub fn new(text: &str) -> Model {
+    Model::new(text)
+}
+
+impl Model {
+    /// Creates a left-aligned banner for `text` with the default gradient.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            width: 0,
+            align: Align::Left,
+            gradient: vec![Color::from("#5A56E0"), Color::from("#EE6FF8")],
+            direction: Direction::Horizontal,
+            fill: '█',
+            line_gap: 1,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the width to wrap and align within.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the placement of each line.
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets a two-color gradient.
+    pub fn with_gradient(mut self, start: &str, end: &str) -> Self {
+        self.gradient = vec![Color::from(start), Color::from(end)];
+        self
+    }
+
+    /// Sets which way the gradient runs.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the character drawn for filled cells.
+    pub fn with_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Colors the letters with `styles.text` instead of a gradient.
+    pub fn without_gradient(mut self) -> Self {
+        self.gradient.clear();
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Sets the text.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Splits the text into lines that fit `width`, breaking between words.
+    /// A word wider than `width` gets a line of its own.
+    fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in self.text.lines() {
+            if self.width == 0 {
+                lines.push(paragraph.to_string());
+                continue;
+            }
+            let mut line = String::new();
+            for word in paragraph.split_whitespace() {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{} {}", line, word)
+                };
+                if text_width(&candidate) <= self.width || line.is_empty() {
+                    line = candidate;
+                } else {
+                    lines.push(std::mem::replace(&mut line, word.to_string()));
+                }
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Draws one line of text as `HEIGHT` rows of `#` and spaces.
+    fn rasterize(line: &str) -> Vec<String> {
+        (0..HEIGHT)
+            .map(|row| {
+                line.chars()
+                    .map(|c| glyph(c)[row])
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect()
+    }
+
+    /// Banners are static and ignore messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the banner.
+    pub fn view(&self) -> String {
+        let lines = self.lines();
+        let mut rows: Vec<(usize, String)> = Vec::new();
+        let width = lines
+            .iter()
+            .map(|l| text_width(l))
+            .max()
+            .unwrap_or(0)
+            .max(self.width);
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                rows.extend((0..self.line_gap).map(|_| (0, String::new())));
+            }
+            let pad = width - text_width(line);
+            let indent = match self.align {
+                Align::Left => 0,
+                Align::Center => pad / 2,
+                Align::Right => pad,
+            };
+            rows.extend(Self::rasterize(line).into_iter().map(|r| (indent, r)));
+        }
+
+        let steps = match self.direction {
+            Direction::Horizontal => width,
+            Direction::Vertical => rows.len(),
+        };
+        let colors = match self.gradient.len() {
+            0 => Vec::new(),
+            1 => vec![self.gradient[0].clone(); steps.max(1)],
+            _ => blend_1d(steps.max(2), self.gradient.clone()),
+        };
+        let fill = self.fill.to_string();
+        rows.iter()
+            .enumerate()
+            .map(|(y, (indent, row))| {
+                let mut out = " ".repeat(*indent);
+                for (x, cell) in row.trim_end().chars().enumerate() {
+                    if cell != '#' {
+                        out.push(' ');
+                        continue;
+                    }
+                    let index = match self.direction {
+                        Direction::Horizontal => indent + x,
+                        Direction::Vertical => y,
+                    };
+                    let style = match colors.get(index) {
+                        Some(color) => Style::new().foreground(color.clone()),
+                        None => self.styles.text.clone(),
+                    };
+                    out.push_str(&style.render(&fill));
+                }
+                out
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+        if !self.gradient.is_empty() {
+            self.gradient = vec![theme.palette.primary.clone(), theme.palette.accent.clone()];
+        }
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new("Hello"), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::renderer::{self, ColorProfileKind};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_font_rows_are_even() {
+        for (c, rows) in FONT {
+            assert!(
+                rows.iter().all(|r| r.len() == rows[0].len()),
+                "glyph {:?} has ragged rows",
+                c
+            );
+        }
+        assert_eq!(glyph('a'), glyph('A'));
+        assert_eq!(glyph('€'), glyph('?'));
+    }
+
+    #[test]
+    fn test_wraps_and_aligns() {
+        let b = Model::new("go go")
+            .with_fill('#')
+            .without_gradient()
+            .with_width(13)
+            .with_align(Align::Right);
+        let view = strip_ansi(&b.view());
+        let rows: Vec<&str> = view.lines().collect();
+        assert_eq!(rows.len(), 11);
+        assert_eq!(rows[0], "   ####  ###");
+        assert_eq!(rows[5], "");
+        assert_eq!(rows[6], rows[0]);
+        let centered = b.with_align(Align::Center);
+        assert!(strip_ansi(&centered.view()).starts_with("  ####"));
+    }
+
+    #[test]
+    fn test_gradient_colors_each_column() {
+        renderer::set_color_profile(ColorProfileKind::TrueColor);
+        let b = Model::new("I").with_gradient("#FF0000", "#0000FF");
+        let first_row = b.view().lines().next().unwrap().to_string();
+        // Three columns, each rendered with its own color.
+        assert_eq!(first_row.matches('█').count(), 3);
+        assert!(first_row.matches("\x1b[").count() >= 3);
+        let vertical = b.with_direction(Direction::Vertical);
+        assert_ne!(
+            vertical.view().lines().next(),
+            vertical.view().lines().last()
+        );
+    }
+}
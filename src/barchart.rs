@@ -0,0 +1,584 @@
+//! Bar charts of labeled values.
+//!
+//! A bar chart draws one bar per [`Bar`], either horizontally (one row per
+//! bar, label on the left) or vertically (one column group per bar, label
+//! underneath). Bars use eighth-block characters so lengths are drawn with
+//! sub-cell precision. The scale is rounded up to a "nice" number (1, 2 or 5
+//! times a power of ten) unless a fixed maximum is set, and an axis with the
+//! `0` and maximum ticks is drawn alongside the bars.
+//!
+//! Negative values are drawn as empty bars.
+//!
+//! # Small Sizes
+//!
+//! When the chart does not fit, it degrades instead of overflowing:
+//!
+//! - Horizontal charts first drop value labels, then shorten bar labels.
+//! - Vertical charts first narrow the bars and the gaps between them, then
+//!   drop value labels that no longer fit, and finally show only as many
+//!   bars as there are columns.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::barchart::{self, Bar};
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let chart = barchart::new(30, 10).with_bars(vec![
+//!     Bar::new("api", 8.0),
+//!     Bar::new("db", 4.0),
+//! ]);
+//! assert_eq!(chart.scale_max(), 10.0);
+//!
+//! let view = strip_ansi(&chart.view());
+//! assert!(view.starts_with("api │"));
+//! ```
+
+use crate::text::{self, Align};
+use crate::theme::Theme;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+
+/// Eighth blocks growing left to right, indexed by eighths filled.
+const H_EIGHTHS: [&str; 9] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉", "█"];
+/// Eighth blocks growing bottom to top, indexed by eighths filled.
+const V_EIGHTHS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// Shortest horizontal bar area kept before labels are shortened.
+const MIN_BAR_LEN: usize = 4;
+
+/// Direction the bars grow in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Bars grow left to right, one row per bar.
+    #[default]
+    Horizontal,
+    /// Bars grow bottom to top, side by side.
+    Vertical,
+}
+
+/// A labeled value.
+#[derive(Debug, Clone)]
+pub struct Bar {
+    /// Text shown next to or under the bar.
+    pub label: String,
+    /// The value drawn.
+    pub value: f64,
+    /// Style overriding [`Styles::bar`] for this bar.
+    pub style: Option<Style>,
+}
+
+impl Bar {
+    /// Creates a bar using the chart's bar style.
+    pub fn new(label: &str, value: f64) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            style: None,
+        }
+    }
+
+    /// Sets the style of this bar.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// Visual styles for the bar chart.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Default style of the bars.
+    pub bar: Style,
+    /// Style of the bar labels.
+    pub label: Style,
+    /// Style of the value labels.
+    pub value: Style,
+    /// Style of the axis lines and ticks.
+    pub axis: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            bar: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+            label: Style::new(),
+            value: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            axis: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates bar chart styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            bar: Style::new().foreground(theme.palette.primary.clone()),
+            label: theme.text.clone(),
+            value: theme.muted.clone(),
+            axis: theme.subtle.clone(),
+        }
+    }
+}
+
+/// A bar chart.
+#[derive(Debug, Clone)]
+pub struct Model {
+    bars: Vec<Bar>,
+    /// Direction the bars grow in.
+    pub orientation: Orientation,
+    /// Total width in columns.
+    pub width: usize,
+    /// Total height in rows. Only used by vertical charts; horizontal charts
+    /// take one row per bar plus the axis.
+    pub height: usize,
+    /// Fixed top of the scale; `None` rounds the largest value up.
+    pub max: Option<f64>,
+    /// Decimal places in value and axis labels.
+    pub decimals: usize,
+    /// Preferred bar width in vertical charts.
+    pub bar_width: usize,
+    /// Preferred gap between bars in vertical charts.
+    pub gap: usize,
+    /// Whether values are printed next to or above the bars.
+    pub show_values: bool,
+    /// Whether the axis is drawn.
+    pub show_axis: bool,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an empty horizontal chart of the given size.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty horizontal chart of the given size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            bars: Vec::new(),
+            orientation: Orientation::Horizontal,
+            width,
+            height,
+            max: None,
+            decimals: 0,
+            bar_width: 3,
+            gap: 1,
+            show_values: true,
+            show_axis: true,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the bars.
+    pub fn with_bars(mut self, bars: Vec<Bar>) -> Self {
+        self.bars = bars;
+        self
+    }
+
+    /// Sets the direction the bars grow in.
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Fixes the top of the scale.
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the decimal places in value and axis labels.
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Sets the preferred bar width and gap of vertical charts.
+    pub fn with_bar_width(mut self, bar_width: usize, gap: usize) -> Self {
+        self.bar_width = bar_width.max(1);
+        self.gap = gap;
+        self
+    }
+
+    /// Shows or hides value labels.
+    pub fn with_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    /// Shows or hides the axis.
+    pub fn with_axis(mut self, show: bool) -> Self {
+        self.show_axis = show;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the bars.
+    pub fn bars(&self) -> &[Bar] {
+        &self.bars
+    }
+
+    /// Replaces the bars.
+    pub fn set_bars(&mut self, bars: Vec<Bar>) {
+        self.bars = bars;
+    }
+
+    /// Appends a bar.
+    pub fn push(&mut self, bar: Bar) {
+        self.bars.push(bar);
+    }
+
+    /// Updates the value of the first bar with `label`, returning `false`
+    /// if there is none.
+    pub fn set_value(&mut self, label: &str, value: f64) -> bool {
+        match self.bars.iter_mut().find(|b| b.label == label) {
+            Some(bar) => {
+                bar.value = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the value at the end of the axis: the fixed maximum, or the
+    /// largest value rounded up to 1, 2 or 5 times a power of ten.
+    pub fn scale_max(&self) -> f64 {
+        if let Some(max) = self.max.filter(|m| *m > 0.0) {
+            return max;
+        }
+        let largest = self
+            .bars
+            .iter()
+            .map(|b| b.value)
+            .filter(|v| v.is_finite())
+            .fold(0.0, f64::max);
+        nice_ceiling(largest)
+    }
+
+    fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.decimals, value)
+    }
+
+    /// Returns the fraction of the scale covered by `value`.
+    fn fraction(&self, value: f64) -> f64 {
+        if value.is_finite() {
+            (value / self.scale_max()).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn bar_style<'a>(&'a self, bar: &'a Bar) -> &'a Style {
+        bar.style.as_ref().unwrap_or(&self.styles.bar)
+    }
+
+    fn view_horizontal(&self) -> String {
+        let s = &self.styles;
+        let axis_w = usize::from(self.show_axis);
+        let mut label_w = self
+            .bars
+            .iter()
+            .map(|b| text::width(&b.label))
+            .max()
+            .unwrap_or(0);
+        let mut value_w = if self.show_values {
+            self.bars
+                .iter()
+                .map(|b| text::width(&self.format(b.value)))
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        let bar_len = |label_w: usize, value_w: usize| {
+            let label = if label_w > 0 { label_w + 1 } else { 0 };
+            let value = if value_w > 0 { value_w + 1 } else { 0 };
+            self.width.saturating_sub(label + axis_w + value)
+        };
+        if bar_len(label_w, value_w) < MIN_BAR_LEN {
+            value_w = 0;
+        }
+        if bar_len(label_w, value_w) < MIN_BAR_LEN {
+            label_w = self.width.saturating_sub(MIN_BAR_LEN + axis_w + 1);
+        }
+        let len = bar_len(label_w, value_w);
+
+        let mut lines = Vec::new();
+        for bar in &self.bars {
+            let mut line = String::new();
+            if label_w > 0 {
+                line.push_str(&s.label.render(&text::pad(
+                    &text::truncate_with(&bar.label, label_w, ""),
+                    label_w,
+                    Align::Left,
+                )));
+                line.push(' ');
+            }
+            if self.show_axis {
+                line.push_str(&s.axis.render("│"));
+            }
+            let eighths = (self.fraction(bar.value) * (len * 8) as f64).round() as usize;
+            let mut cells = "█".repeat(eighths / 8);
+            cells.push_str(H_EIGHTHS[eighths % 8]);
+            let drawn = text::width(&cells);
+            if !cells.is_empty() {
+                line.push_str(&self.bar_style(bar).render(&cells));
+            }
+            if value_w > 0 {
+                line.push_str(&" ".repeat(len - drawn + 1));
+                line.push_str(&s.value.render(&self.format(bar.value)));
+            }
+            lines.push(line);
+        }
+
+        if self.show_axis && len > 0 {
+            let indent = " ".repeat(if label_w > 0 { label_w + 1 } else { 0 });
+            lines.push(format!(
+                "{}{}",
+                indent,
+                s.axis.render(&format!("└{}", "─".repeat(len)))
+            ));
+            let max = self.format(self.scale_max());
+            let ticks = if len + 1 >= text::width(&max) + 2 {
+                format!("0{}{}", " ".repeat(len + 1 - 1 - text::width(&max)), max)
+            } else {
+                "0".to_string()
+            };
+            lines.push(format!("{}{}", indent, s.axis.render(&ticks)));
+        }
+        lines.join("\n")
+    }
+
+    fn view_vertical(&self) -> String {
+        let s = &self.styles;
+        let n = self.bars.len();
+        let max_label = self.format(self.scale_max());
+        let axis_w = if self.show_axis {
+            text::width(&max_label).max(1) + 1
+        } else {
+            0
+        };
+        let avail = self.width.saturating_sub(axis_w);
+        if n == 0 || avail == 0 {
+            return String::new();
+        }
+
+        // Narrow bars, then gaps, then drop bars until the chart fits.
+        let (mut bw, mut gap) = (self.bar_width.max(1), self.gap);
+        if need_for(n, bw, gap) > avail {
+            bw = ((avail + gap) / n).saturating_sub(gap).max(1);
+        }
+        if need_for(n, bw, gap) > avail {
+            gap = 0;
+            bw = (avail / n).max(1);
+        }
+        let shown = n.min((avail + gap) / (bw + gap));
+        let bars = &self.bars[..shown];
+        let used = need_for(shown, bw, gap);
+
+        let values: Vec<String> = bars.iter().map(|b| self.format(b.value)).collect();
+        let show_values = self.show_values && values.iter().all(|v| text::width(v) <= bw);
+        let reserved = usize::from(show_values) + 1 + usize::from(self.show_axis);
+        let plot_h = self.height.saturating_sub(reserved).max(1);
+
+        let mut lines = Vec::new();
+        let blank_axis = " ".repeat(axis_w);
+        if show_values {
+            let row: Vec<String> = values
+                .iter()
+                .map(|v| s.value.render(&text::pad(v, bw, Align::Center)))
+                .collect();
+            lines.push(format!("{}{}", blank_axis, row.join(&" ".repeat(gap))));
+        }
+        for r in 0..plot_h {
+            let level = plot_h - 1 - r;
+            let mut line = String::new();
+            if self.show_axis {
+                let tick = if r == 0 {
+                    &max_label
+                } else if level == 0 {
+                    "0"
+                } else {
+                    ""
+                };
+                let mark = if tick.is_empty() { "│" } else { "┤" };
+                line.push_str(
+                    &s.axis
+                        .render(&format!("{:>w$}{}", tick, mark, w = axis_w - 1)),
+                );
+            }
+            let cells: Vec<String> = bars
+                .iter()
+                .map(|bar| {
+                    let eighths = (self.fraction(bar.value) * (plot_h * 8) as f64).round() as usize;
+                    let fill = eighths.saturating_sub(level * 8).min(8);
+                    let cell = V_EIGHTHS[fill].to_string().repeat(bw);
+                    if fill == 0 {
+                        cell
+                    } else {
+                        self.bar_style(bar).render(&cell)
+                    }
+                })
+                .collect();
+            line.push_str(&cells.join(&" ".repeat(gap)));
+            lines.push(line);
+        }
+        if self.show_axis {
+            lines.push(
+                s.axis
+                    .render(&format!("{:>w$}└{}", "", "─".repeat(used), w = axis_w - 1)),
+            );
+        }
+        let labels: Vec<String> = bars
+            .iter()
+            .map(|b| {
+                s.label.render(&text::pad(
+                    &text::truncate_with(&b.label, bw, ""),
+                    bw,
+                    Align::Center,
+                ))
+            })
+            .collect();
+        lines.push(format!("{}{}", blank_axis, labels.join(&" ".repeat(gap))));
+        lines.join("\n")
+    }
+
+    /// Bar charts are static and ignore messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the chart.
+    pub fn view(&self) -> String {
+        match self.orientation {
+            Orientation::Horizontal => self.view_horizontal(),
+            Orientation::Vertical => self.view_vertical(),
+        }
+    }
+}
+
+/// Columns taken by `n` bars of width `bw` separated by `gap`.
+fn need_for(n: usize, bw: usize, gap: usize) -> usize {
+    n * bw + n.saturating_sub(1) * gap
+}
+
+/// Rounds `value` up to 1, 2 or 5 times a power of ten.
+fn nice_ceiling(value: f64) -> f64 {
+    if value <= 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(value.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .find(|m| m * magnitude >= value)
+        .unwrap_or(10.0);
+    step * magnitude
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(40, 10), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn sample() -> Vec<Bar> {
+        vec![Bar::new("a", 10.0), Bar::new("bb", 5.0)]
+    }
+
+    #[test]
+    fn test_nice_scale() {
+        assert_eq!(nice_ceiling(7.0), 10.0);
+        assert_eq!(nice_ceiling(13.0), 20.0);
+        assert_eq!(nice_ceiling(0.3), 0.5);
+        assert_eq!(nice_ceiling(0.0), 1.0);
+        let chart = Model::new(20, 5).with_bars(sample()).with_max(40.0);
+        assert_eq!(chart.scale_max(), 40.0);
+    }
+
+    #[test]
+    fn test_horizontal_view() {
+        let chart = Model::new(17, 0).with_bars(sample());
+        assert_eq!(
+            strip_ansi(&chart.view()),
+            [
+                "a  │██████████ 10",
+                "bb │█████      5",
+                "   └──────────",
+                "   0        10",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_vertical_view() {
+        let chart = Model::new(12, 6)
+            .with_orientation(Orientation::Vertical)
+            .with_bars(sample());
+        assert_eq!(
+            strip_ansi(&chart.view()),
+            [
+                "   10   5 ",
+                "10┤███    ",
+                "  │███ ▄▄▄",
+                " 0┤███ ███",
+                "  └───────",
+                "    a  bb ",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_degrades_at_small_widths() {
+        let chart = Model::new(8, 0).with_bars(vec![Bar::new("requests", 3.0)]);
+        let first = strip_ansi(&chart.view())
+            .lines()
+            .next()
+            .unwrap()
+            .to_string();
+        assert_eq!(first, "re │██▍");
+
+        let narrow = Model::new(6, 5)
+            .with_orientation(Orientation::Vertical)
+            .with_bars(sample())
+            .with_max(100.0);
+        let view = strip_ansi(&narrow.view());
+        assert!(view.lines().all(|l| text::width(l) <= 6));
+        assert_eq!(view.lines().count(), 5);
+    }
+}
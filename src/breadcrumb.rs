@@ -0,0 +1,448 @@
+//! Breadcrumb trail of path segments.
+//!
+//! A breadcrumb shows where the user is in a hierarchy, such as
+//! `home › projects › bubbles › src`. A cursor moves between segments with
+//! the arrow keys and `enter` emits a [`JumpMsg`] naming the selected
+//! segment, so the application can navigate back up the hierarchy. The
+//! segment under the cursor uses the `active` style; it starts on the last
+//! segment.
+//!
+//! When the trail is wider than `width`, segments are dropped from the
+//! middle and replaced with `…`. The first and last segments and the one
+//! under the cursor always stay visible.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `←`/`h`, `→`/`l` | Move between segments |
+//! | `home`, `end` | Jump to the first or last segment |
+//! | `enter` | Emit a [`JumpMsg`] for the selected segment |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::breadcrumb;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut crumbs = breadcrumb::new(&["home", "projects", "bubbles", "src"]);
+//! assert_eq!(strip_ansi(&crumbs.view()), "home › projects › bubbles › src");
+//!
+//! crumbs.width = 20;
+//! assert_eq!(strip_ansi(&crumbs.view()), "home › … › src");
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, ELLIPSIS};
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted when the user jumps to a segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JumpMsg {
+    /// The ID of the breadcrumb.
+    pub id: i64,
+    /// Index of the segment.
+    pub index: usize,
+    /// Text of the segment.
+    pub segment: String,
+}
+
+/// Key bindings for the breadcrumb.
+#[derive(Debug, Clone)]
+pub struct BreadcrumbKeyMap {
+    /// Moves to the previous segment. Default: `←`, `h`.
+    pub prev: key::Binding,
+    /// Moves to the next segment. Default: `→`, `l`.
+    pub next: key::Binding,
+    /// Moves to the first segment. Default: `home`.
+    pub first: key::Binding,
+    /// Moves to the last segment. Default: `end`.
+    pub last: key::Binding,
+    /// Jumps to the selected segment. Default: `enter`.
+    pub jump: key::Binding,
+}
+
+impl Default for BreadcrumbKeyMap {
+    fn default() -> Self {
+        Self {
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["left", "h"]),
+                key::with_help("←/h", "prev"),
+            ]),
+            next: key::new_binding(vec![
+                key::with_keys_str(&["right", "l"]),
+                key::with_help("→/l", "next"),
+            ]),
+            first: key::new_binding(vec![
+                key::with_keys_str(&["home"]),
+                key::with_help("home", "first"),
+            ]),
+            last: key::new_binding(vec![
+                key::with_keys_str(&["end"]),
+                key::with_help("end", "last"),
+            ]),
+            jump: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "go"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for BreadcrumbKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.prev, &self.next, &self.jump]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.prev, &self.next],
+            vec![&self.first, &self.last, &self.jump],
+        ]
+    }
+}
+
+/// Visual styles for the breadcrumb.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Segments other than the selected one.
+    pub segment: Style,
+    /// The selected segment.
+    pub active: Style,
+    /// Separators between segments.
+    pub separator: Style,
+    /// The `…` standing in for hidden segments.
+    pub ellipsis: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            segment: Style::new().foreground(AdaptiveColor {
+                Light: "#847A85",
+                Dark: "#A49FA5",
+            }),
+            active: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            separator: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            ellipsis: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates breadcrumb styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            segment: theme.muted.clone(),
+            active: theme.selected.clone(),
+            separator: theme.subtle.clone(),
+            ellipsis: theme.subtle.clone(),
+        }
+    }
+}
+
+/// A breadcrumb trail.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    segments: Vec<String>,
+    cursor: usize,
+    focus: bool,
+    /// Text between segments.
+    pub separator: String,
+    /// Widest the trail may be; zero for no limit.
+    pub width: usize,
+    /// Key bindings.
+    pub keymap: BreadcrumbKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused breadcrumb with the cursor on the last segment.
+pub fn new(segments: &[&str]) -> Model {
+    Model::new(segments)
+}
+
+impl Model {
+    /// Creates a focused breadcrumb with the cursor on the last segment.
+    pub fn new(segments: &[&str]) -> Self {
+        let mut m = Self {
+            id: next_id(),
+            segments: Vec::new(),
+            cursor: 0,
+            focus: true,
+            separator: " › ".to_string(),
+            width: 0,
+            keymap: BreadcrumbKeyMap::default(),
+            styles: Styles::default(),
+        };
+        m.set_segments(segments.iter().map(|s| s.to_string()).collect());
+        m
+    }
+
+    /// Sets the text between segments.
+    pub fn with_separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Sets the widest the trail may be.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this breadcrumb.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the segments.
+    pub fn segments(&self) -> &[String] {
+        &self.segments
+    }
+
+    /// Replaces the segments and moves the cursor to the last one.
+    pub fn set_segments(&mut self, segments: Vec<String>) {
+        self.segments = segments;
+        self.cursor = self.segments.len().saturating_sub(1);
+    }
+
+    /// Appends a segment and moves the cursor to it.
+    pub fn push(&mut self, segment: &str) {
+        self.segments.push(segment.to_string());
+        self.cursor = self.segments.len() - 1;
+    }
+
+    /// Removes the last segment and moves the cursor to the new last one.
+    pub fn pop(&mut self) -> Option<String> {
+        let popped = self.segments.pop();
+        self.cursor = self.segments.len().saturating_sub(1);
+        popped
+    }
+
+    /// Drops every segment after `index`, as when navigating up to it.
+    pub fn truncate(&mut self, index: usize) {
+        self.segments.truncate(index + 1);
+        self.cursor = self.segments.len().saturating_sub(1);
+    }
+
+    /// Returns the index of the selected segment.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Selects a segment; out-of-range indices are clamped.
+    pub fn select(&mut self, index: usize) {
+        self.cursor = index.min(self.segments.len().saturating_sub(1));
+    }
+
+    /// Returns the indices of the segments that fit in `width`, in order.
+    fn visible(&self) -> Vec<usize> {
+        let n = self.segments.len();
+        let seg_w = |i: usize| text::width(&self.segments[i]);
+        let sep_w = text::width(&self.separator);
+        let total_w = |shown: &[usize]| {
+            let mut w = 0;
+            let mut parts: usize = 0;
+            for (k, &i) in shown.iter().enumerate() {
+                if k > 0 && i > shown[k - 1] + 1 {
+                    w += text::width(ELLIPSIS);
+                    parts += 1;
+                }
+                w += seg_w(i);
+                parts += 1;
+            }
+            w + parts.saturating_sub(1) * sep_w
+        };
+        let all: Vec<usize> = (0..n).collect();
+        if self.width == 0 || n == 0 || total_w(&all) <= self.width {
+            return all;
+        }
+
+        let mut shown = vec![0, self.cursor, n - 1];
+        shown.sort_unstable();
+        shown.dedup();
+        if total_w(&shown) > self.width {
+            return vec![self.cursor];
+        }
+        // Fill in segments closest to the cursor, favouring the deeper end
+        // of the trail.
+        let mut candidates: Vec<usize> = (0..n).filter(|i| !shown.contains(i)).collect();
+        candidates.sort_by_key(|&i| {
+            let after = i > self.cursor;
+            (i.abs_diff(self.cursor), !after)
+        });
+        for i in candidates {
+            let mut trial = shown.clone();
+            trial.push(i);
+            trial.sort_unstable();
+            if total_w(&trial) <= self.width {
+                shown = trial;
+            }
+        }
+        shown
+    }
+
+    /// Moves between segments and emits a [`JumpMsg`] on `enter`. Blurred
+    /// breadcrumbs ignore input.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus || self.segments.is_empty() {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if self.keymap.prev.matches(key_msg) {
+            self.cursor = self.cursor.saturating_sub(1);
+        } else if self.keymap.next.matches(key_msg) {
+            self.select(self.cursor + 1);
+        } else if self.keymap.first.matches(key_msg) {
+            self.cursor = 0;
+        } else if self.keymap.last.matches(key_msg) {
+            self.cursor = self.segments.len() - 1;
+        } else if self.keymap.jump.matches(key_msg) {
+            let (id, index) = (self.id, self.cursor);
+            let segment = self.segments[index].clone();
+            return Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+                Box::new(JumpMsg {
+                    id,
+                    index,
+                    segment: segment.clone(),
+                }) as Msg
+            }));
+        }
+        None
+    }
+
+    /// Renders the trail.
+    pub fn view(&self) -> String {
+        let s = &self.styles;
+        let shown = self.visible();
+        let mut parts = Vec::new();
+        for (k, &i) in shown.iter().enumerate() {
+            if k > 0 && i > shown[k - 1] + 1 {
+                parts.push(s.ellipsis.render(ELLIPSIS));
+            }
+            let style = if i == self.cursor {
+                &s.active
+            } else {
+                &s.segment
+            };
+            parts.push(style.render(&self.segments[i]));
+        }
+        parts.join(&s.separator.render(&self.separator))
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(&[]), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_navigation_and_jump() {
+        let mut b = Model::new(&["a", "b", "c"]);
+        assert_eq!(b.cursor(), 2);
+        b.update(key(KeyCode::Right));
+        assert_eq!(b.cursor(), 2);
+        b.update(key(KeyCode::Left));
+        b.update(key(KeyCode::Left));
+        b.update(key(KeyCode::Left));
+        assert_eq!(b.cursor(), 0);
+        b.update(key(KeyCode::End));
+        assert_eq!(b.cursor(), 2);
+        assert!(b.update(key(KeyCode::Enter)).is_some());
+    }
+
+    #[test]
+    fn test_middle_truncation_keeps_cursor_visible() {
+        let mut b = Model::new(&["root", "one", "two", "three", "leaf"]).with_width(24);
+        assert_eq!(strip_ansi(&b.view()), "root › … › three › leaf");
+        b.select(1);
+        assert_eq!(strip_ansi(&b.view()), "root › one › … › leaf");
+        b.width = 4;
+        assert_eq!(strip_ansi(&b.view()), "one");
+    }
+
+    #[test]
+    fn test_push_pop_truncate() {
+        let mut b = Model::new(&["a"]);
+        b.push("b");
+        b.push("c");
+        assert_eq!(b.cursor(), 2);
+        assert_eq!(b.pop().as_deref(), Some("c"));
+        b.push("d");
+        b.truncate(0);
+        assert_eq!(b.segments(), ["a".to_string()]);
+        assert_eq!(b.cursor(), 0);
+    }
+}
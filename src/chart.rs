@@ -0,0 +1,494 @@
+//! Braille line charts of streaming series.
+//!
+//! A chart plots one or more [`Series`] as lines drawn with braille
+//! characters, which pack a 2×4 grid of dots into each cell and so give
+//! twice the horizontal and four times the vertical resolution of the
+//! terminal. Each series is a list of samples taken at a fixed interval;
+//! new samples are appended with [`Model::push`] and the oldest are dropped
+//! once `capacity` samples are kept. The newest samples are drawn at the
+//! right edge, one dot column per sample.
+//!
+//! The vertical scale covers the visible samples of every series unless a
+//! fixed minimum or maximum is set. The y axis shows the top and bottom of
+//! the scale, and a legend below the chart names each series in its color.
+//! Where lines from several series cross the same cell, the cell takes the
+//! color of the series added last.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::chart;
+//! use lipgloss_extras::prelude::*;
+//!
+//! let mut chart = chart::new(40, 10);
+//! let cpu = chart.add_series("cpu", Style::new().foreground(Color::from("#04B575")));
+//! let mem = chart.add_series("mem", Style::new().foreground(Color::from("#7571F9")));
+//! for i in 0..60 {
+//!     chart.push(cpu, (i as f64 / 5.0).sin() * 50.0 + 50.0);
+//!     chart.push(mem, 40.0 + i as f64 / 2.0);
+//! }
+//! assert_eq!(chart.view().lines().count(), 10);
+//! ```
+
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::collections::VecDeque;
+
+/// Dot bits of a braille cell, indexed by `[row][column]`.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+/// The empty braille pattern; dot bits are added to it.
+const BRAILLE_BASE: u32 = 0x2800;
+
+/// A named series of samples.
+#[derive(Debug, Clone)]
+pub struct Series {
+    /// Name shown in the legend.
+    pub name: String,
+    /// Style of the line and legend marker.
+    pub style: Style,
+    points: VecDeque<f64>,
+}
+
+impl Series {
+    /// Returns the samples, oldest first.
+    pub fn points(&self) -> impl Iterator<Item = f64> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// Returns the newest sample.
+    pub fn last(&self) -> Option<f64> {
+        self.points.back().copied()
+    }
+}
+
+/// Visual styles for the chart.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the axis lines.
+    pub axis: Style,
+    /// Style of the axis labels.
+    pub label: Style,
+    /// Style of the series names in the legend.
+    pub legend: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            axis: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            label: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            legend: Style::new(),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates chart styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            axis: theme.subtle.clone(),
+            label: theme.muted.clone(),
+            legend: theme.text.clone(),
+        }
+    }
+}
+
+/// A braille line chart.
+#[derive(Debug, Clone)]
+pub struct Model {
+    series: Vec<Series>,
+    capacity: usize,
+    /// Total width in columns, including the axis.
+    pub width: usize,
+    /// Total height in rows, including the axis and legend.
+    pub height: usize,
+    /// Fixed bottom of the scale; `None` uses the smallest visible sample.
+    pub min: Option<f64>,
+    /// Fixed top of the scale; `None` uses the largest visible sample.
+    pub max: Option<f64>,
+    /// Decimal places in axis labels.
+    pub decimals: usize,
+    /// Whether the axis is drawn.
+    pub show_axis: bool,
+    /// Whether the legend is drawn.
+    pub show_legend: bool,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an empty chart of the given size.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty chart of the given size that keeps enough samples
+    /// to fill its width.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            series: Vec::new(),
+            capacity: width * 2,
+            width,
+            height,
+            min: None,
+            max: None,
+            decimals: 0,
+            show_axis: true,
+            show_legend: true,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets how many samples each series keeps.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        for s in &mut self.series {
+            while s.points.len() > capacity {
+                s.points.pop_front();
+            }
+        }
+        self
+    }
+
+    /// Fixes both bounds of the scale.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the decimal places in axis labels.
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Shows or hides the axis.
+    pub fn with_axis(mut self, show: bool) -> Self {
+        self.show_axis = show;
+        self
+    }
+
+    /// Shows or hides the legend.
+    pub fn with_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Adds an empty series and returns its index.
+    pub fn add_series(&mut self, name: &str, style: Style) -> usize {
+        self.series.push(Series {
+            name: name.to_string(),
+            style,
+            points: VecDeque::new(),
+        });
+        self.series.len() - 1
+    }
+
+    /// Returns the series.
+    pub fn series(&self) -> &[Series] {
+        &self.series
+    }
+
+    /// Returns how many samples each series keeps.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Appends a sample to a series, dropping its oldest sample when full.
+    /// Unknown indices are ignored.
+    pub fn push(&mut self, series: usize, value: f64) {
+        let capacity = self.capacity;
+        let Some(s) = self.series.get_mut(series) else {
+            return;
+        };
+        if capacity == 0 {
+            return;
+        }
+        if s.points.len() == capacity {
+            s.points.pop_front();
+        }
+        s.points.push_back(value);
+    }
+
+    /// Appends one sample to each series in order.
+    pub fn push_all(&mut self, values: &[f64]) {
+        for (i, v) in values.iter().enumerate() {
+            self.push(i, *v);
+        }
+    }
+
+    /// Removes all samples, keeping the series.
+    pub fn clear(&mut self) {
+        for s in &mut self.series {
+            s.points.clear();
+        }
+    }
+
+    fn axis_labels(&self, lo: f64, hi: f64) -> (String, String) {
+        (
+            format!("{:.*}", self.decimals, hi),
+            format!("{:.*}", self.decimals, lo),
+        )
+    }
+
+    /// Returns the plot area size in cells for the current settings.
+    fn plot_size(&self, axis_w: usize) -> (usize, usize) {
+        let reserved = usize::from(self.show_axis) + usize::from(self.show_legend);
+        (
+            self.width.saturating_sub(axis_w),
+            self.height.saturating_sub(reserved),
+        )
+    }
+
+    fn axis_width(&self) -> usize {
+        if !self.show_axis {
+            return 0;
+        }
+        let (lo, hi) = self.bounds();
+        let (top, bottom) = self.axis_labels(lo, hi);
+        text::width(&top).max(text::width(&bottom)) + 1
+    }
+
+    /// Returns the number of samples of each series that fit on screen.
+    fn visible_len(&self) -> usize {
+        self.plot_size(self.axis_width()).0 * 2
+    }
+
+    /// Returns the scale used for drawing.
+    pub fn bounds(&self) -> (f64, f64) {
+        let mut lo = f64::INFINITY;
+        let mut hi = f64::NEG_INFINITY;
+        // The axis width depends on the bounds, so estimate the visible
+        // samples from the full width here.
+        let visible = self.width * 2;
+        for s in &self.series {
+            for v in s
+                .points
+                .iter()
+                .skip(s.points.len().saturating_sub(visible))
+                .filter(|v| v.is_finite())
+            {
+                lo = lo.min(*v);
+                hi = hi.max(*v);
+            }
+        }
+        let lo = self.min.unwrap_or(if lo.is_finite() { lo } else { 0.0 });
+        let hi = self.max.unwrap_or(if hi.is_finite() { hi } else { 1.0 });
+        if hi > lo {
+            (lo, hi)
+        } else {
+            (lo - 1.0, lo + 1.0)
+        }
+    }
+
+    /// Plots every series into braille cells, returning the dot bits and
+    /// the index of the series owning each cell.
+    fn plot(&self, cols: usize, rows: usize, lo: f64, hi: f64) -> (Vec<u8>, Vec<Option<usize>>) {
+        let mut dots = vec![0u8; cols * rows];
+        let mut owner = vec![None; cols * rows];
+        let (px_w, px_h) = (cols * 2, rows * 4);
+        if px_w == 0 || px_h == 0 {
+            return (dots, owner);
+        }
+        let to_y = |v: f64| {
+            let t = ((v - lo) / (hi - lo)).clamp(0.0, 1.0);
+            px_h - 1 - (t * (px_h - 1) as f64).round() as usize
+        };
+        let mut set = |x: usize, y: usize, series: usize| {
+            let cell = (y / 4) * cols + x / 2;
+            dots[cell] |= BRAILLE_DOTS[y % 4][x % 2];
+            owner[cell] = Some(series);
+        };
+        let visible = self.visible_len().min(px_w);
+        for (si, s) in self.series.iter().enumerate() {
+            let shown = s.points.len().min(visible);
+            let start_x = px_w - shown;
+            let mut prev: Option<usize> = None;
+            for (i, v) in s.points.iter().skip(s.points.len() - shown).enumerate() {
+                if !v.is_finite() {
+                    prev = None;
+                    continue;
+                }
+                let x = start_x + i;
+                let y = to_y(*v);
+                // Join to the previous sample with a vertical run so steep
+                // changes stay connected.
+                let (a, b) = match prev {
+                    Some(p) if p < y => (p + 1, y),
+                    Some(p) if p > y => (y, p - 1),
+                    _ => (y, y),
+                };
+                for yy in a..=b {
+                    set(x, yy, si);
+                }
+                set(x, y, si);
+                prev = Some(y);
+            }
+        }
+        (dots, owner)
+    }
+
+    /// Charts are driven by [`push`](Self::push) and ignore messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the axis, plot and legend.
+    pub fn view(&self) -> String {
+        let s = &self.styles;
+        let (lo, hi) = self.bounds();
+        let (top, bottom) = self.axis_labels(lo, hi);
+        let axis_w = self.axis_width();
+        let (cols, rows) = self.plot_size(axis_w);
+        let (dots, owner) = self.plot(cols, rows, lo, hi);
+
+        let mut lines = Vec::new();
+        for r in 0..rows {
+            let mut line = String::new();
+            if self.show_axis {
+                let label = if r == 0 {
+                    top.as_str()
+                } else if r == rows - 1 {
+                    bottom.as_str()
+                } else {
+                    ""
+                };
+                line.push_str(&s.label.render(&format!("{:>w$}", label, w = axis_w - 1)));
+                line.push_str(&s.axis.render(if label.is_empty() { "│" } else { "┤" }));
+            }
+            // Group runs of cells with the same owner into one styled span.
+            let mut c = 0;
+            while c < cols {
+                let cell_owner = owner[r * cols + c];
+                let mut run = String::new();
+                while c < cols && owner[r * cols + c] == cell_owner {
+                    let bits = dots[r * cols + c];
+                    run.push(if bits == 0 {
+                        ' '
+                    } else {
+                        char::from_u32(BRAILLE_BASE + bits as u32).unwrap_or(' ')
+                    });
+                    c += 1;
+                }
+                match cell_owner {
+                    Some(i) => line.push_str(&self.series[i].style.render(&run)),
+                    None => line.push_str(&run),
+                }
+            }
+            lines.push(line);
+        }
+        if self.show_axis {
+            lines.push(s.axis.render(&format!(
+                "{:>w$}└{}",
+                "",
+                "─".repeat(cols),
+                w = axis_w.saturating_sub(1)
+            )));
+        }
+        if self.show_legend {
+            let entries: Vec<String> = self
+                .series
+                .iter()
+                .map(|ser| format!("{} {}", ser.style.render("●"), s.legend.render(&ser.name)))
+                .collect();
+            lines.push(format!("{}{}", " ".repeat(axis_w), entries.join("  ")));
+        }
+        lines.join("\n")
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(60, 15), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_push_respects_capacity() {
+        let mut c = Model::new(10, 5).with_capacity(3);
+        let a = c.add_series("a", Style::new());
+        c.add_series("b", Style::new());
+        c.push_all(&[1.0, 2.0]);
+        for v in [3.0, 4.0, 5.0] {
+            c.push(a, v);
+        }
+        c.push(7, 1.0);
+        let points: Vec<f64> = c.series()[a].points().collect();
+        assert_eq!(points, vec![3.0, 4.0, 5.0]);
+        assert_eq!(c.series()[1].last(), Some(2.0));
+    }
+
+    #[test]
+    fn test_bounds_auto_scale_and_flat_series() {
+        let mut c = Model::new(10, 5);
+        let a = c.add_series("a", Style::new());
+        assert_eq!(c.bounds(), (0.0, 1.0));
+        c.push(a, 3.0);
+        assert_eq!(c.bounds(), (2.0, 4.0));
+        c.push(a, 9.0);
+        assert_eq!(c.bounds(), (3.0, 9.0));
+        let fixed = c.clone().with_range(0.0, 100.0);
+        assert_eq!(fixed.bounds(), (0.0, 100.0));
+    }
+
+    #[test]
+    fn test_braille_plot() {
+        let mut c = Model::new(1, 1)
+            .with_axis(false)
+            .with_legend(false)
+            .with_range(0.0, 3.0);
+        let a = c.add_series("a", Style::new());
+        c.push(a, 3.0);
+        c.push(a, 0.0);
+        // Left column: top dot. Right column: a run from row 1 down to 3.
+        assert_eq!(strip_ansi(&c.view()), "⢱");
+    }
+
+    #[test]
+    fn test_axis_and_legend() {
+        let mut c = Model::new(8, 4).with_range(0.0, 10.0);
+        let a = c.add_series("cpu", Style::new());
+        c.push(a, 5.0);
+        let view = strip_ansi(&c.view());
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("10┤"));
+        assert!(lines[1].starts_with(" 0┤"));
+        assert_eq!(lines[2], "  └─────");
+        assert_eq!(lines[3], "   ● cpu");
+    }
+}
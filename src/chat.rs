@@ -0,0 +1,463 @@
+//! Chat message list.
+//!
+//! The chat widget is an append-oriented scrollback of [`Message`]s shown in
+//! an embedded [`viewport`](crate::viewport). Each message is drawn as a
+//! word-wrapped bubble under an author and time header; messages from the
+//! local author are aligned right and everyone else's left. Streaming
+//! replies, such as tokens from a language model, can grow the newest
+//! message in place with [`Model::append_to_last`].
+//!
+//! In follow mode the list sticks to the bottom as messages arrive.
+//! Scrolling up pauses following and scrolling back to the bottom resumes
+//! it. Messages that arrive while not following are unread: a
+//! `── N new ──` separator marks where they start until
+//! [`Model::mark_read`] is called or the local author sends a message.
+//!
+//! # Navigation Controls
+//!
+//! Scrolling uses the viewport key bindings. In addition:
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `F` | Toggle follow mode |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::chat::{self, Message};
+//!
+//! let mut chat = chat::new(60, 20).with_local_author("me");
+//! chat.push(Message::new("me", "What's the weather like?"));
+//! chat.push(Message::new("bot", "Sunny"));
+//! chat.append_to_last(" with a light breeze.");
+//!
+//! assert_eq!(chat.len(), 2);
+//! assert_eq!(chat.messages().last().unwrap().text, "Sunny with a light breeze.");
+//! assert!(chat.view().contains("bot"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, format_time, wrap};
+use crate::theme::Theme;
+use crate::viewport;
+use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::time::SystemTime;
+
+/// A chat message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    /// Who sent the message.
+    pub author: String,
+    /// The message body; may contain newlines.
+    pub text: String,
+    /// When the message was sent.
+    pub time: SystemTime,
+}
+
+impl Message {
+    /// Creates a message sent now.
+    pub fn new(author: &str, text: &str) -> Self {
+        Self {
+            author: author.to_string(),
+            text: text.to_string(),
+            time: SystemTime::now(),
+        }
+    }
+
+    /// Sets when the message was sent.
+    pub fn at(mut self, time: SystemTime) -> Self {
+        self.time = time;
+        self
+    }
+}
+
+/// Visual styles for the chat.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Bubbles of messages from the local author.
+    pub local_bubble: Style,
+    /// Bubbles of messages from everyone else.
+    pub remote_bubble: Style,
+    /// Author name in message headers.
+    pub author: Style,
+    /// Time in message headers.
+    pub timestamp: Style,
+    /// The unread separator.
+    pub unread: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        let bubble = Style::new().border(rounded_border()).padding(0, 1, 0, 1);
+        Self {
+            local_bubble: bubble.clone().border_foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+            remote_bubble: bubble.border_foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            author: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            timestamp: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            unread: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates chat styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let bubble = Style::new().border(rounded_border()).padding(0, 1, 0, 1);
+        Self {
+            local_bubble: bubble.clone().border_foreground(p.primary.clone()),
+            remote_bubble: bubble.border_foreground(p.subtle.clone()),
+            author: theme.selected.clone(),
+            timestamp: theme.muted.clone(),
+            unread: theme.error.clone(),
+        }
+    }
+}
+
+/// Key bindings for the chat, in addition to the viewport's.
+#[derive(Debug, Clone)]
+pub struct ChatKeyMap {
+    /// Toggles follow mode. Default: `F`.
+    pub follow: key::Binding,
+}
+
+impl Default for ChatKeyMap {
+    fn default() -> Self {
+        Self {
+            follow: key::new_binding(vec![
+                key::with_keys_str(&["F"]),
+                key::with_help("F", "follow"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for ChatKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.follow]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![vec![&self.follow]]
+    }
+}
+
+/// A scrolling list of chat messages.
+#[derive(Debug, Clone)]
+pub struct Model {
+    messages: Vec<Message>,
+    follow: bool,
+    unread_from: Option<usize>,
+    /// Messages by this author are aligned right.
+    pub local_author: String,
+    /// Whether headers show the time of each message.
+    pub show_timestamps: bool,
+    /// The viewport displaying the messages.
+    pub viewport: viewport::Model,
+    /// Visual styles.
+    pub styles: Styles,
+    /// Key bindings.
+    pub keymap: ChatKeyMap,
+}
+
+/// Creates an empty chat with the given viewport size.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty chat in follow mode.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            messages: Vec::new(),
+            follow: true,
+            unread_from: None,
+            local_author: String::new(),
+            show_timestamps: true,
+            viewport: viewport::new(width, height),
+            styles: Styles::default(),
+            keymap: ChatKeyMap::default(),
+        }
+    }
+
+    /// Sets the author whose messages are aligned right.
+    pub fn with_local_author(mut self, author: &str) -> Self {
+        self.local_author = author.to_string();
+        self.refresh();
+        self
+    }
+
+    /// Shows or hides message times.
+    pub fn with_timestamps(mut self, show: bool) -> Self {
+        self.show_timestamps = show;
+        self.refresh();
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self.refresh();
+        self
+    }
+
+    /// Appends a message. Messages arriving while not following are
+    /// unread; a message from the local author marks everything read.
+    pub fn push(&mut self, message: Message) {
+        if message.author == self.local_author {
+            self.unread_from = None;
+        } else if !self.follow && self.unread_from.is_none() {
+            self.unread_from = Some(self.messages.len());
+        }
+        self.messages.push(message);
+        self.refresh();
+    }
+
+    /// Appends text to the newest message, as when a reply is streamed.
+    /// Does nothing if there are no messages.
+    pub fn append_to_last(&mut self, text: &str) {
+        if let Some(last) = self.messages.last_mut() {
+            last.text.push_str(text);
+            self.refresh();
+        }
+    }
+
+    /// Removes all messages.
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.unread_from = None;
+        self.refresh();
+    }
+
+    /// Returns the messages, oldest first.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Returns the number of messages.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns whether there are no messages.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Returns the number of unread messages.
+    pub fn unread(&self) -> usize {
+        self.unread_from
+            .map_or(0, |from| self.messages.len().saturating_sub(from))
+    }
+
+    /// Marks every message read, removing the unread separator.
+    pub fn mark_read(&mut self) {
+        if self.unread_from.take().is_some() {
+            self.refresh();
+        }
+    }
+
+    /// Returns whether the chat sticks to the newest message.
+    pub fn following(&self) -> bool {
+        self.follow
+    }
+
+    /// Enables or disables follow mode. Enabling it jumps to the bottom.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        if follow {
+            self.viewport.goto_bottom();
+        }
+    }
+
+    /// Resizes the viewport and re-wraps the messages.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.viewport.width = width;
+        self.viewport.height = height;
+        self.refresh();
+    }
+
+    /// Returns the width available to messages inside the viewport frame.
+    fn inner_width(&self) -> usize {
+        let frame = self.viewport.style.get_horizontal_frame_size() as usize;
+        self.viewport.width.saturating_sub(frame)
+    }
+
+    /// Renders one message as header and bubble lines, aligned by author.
+    fn render_message(&self, message: &Message) -> Vec<String> {
+        let s = &self.styles;
+        let width = self.inner_width();
+        let local = !self.local_author.is_empty() && message.author == self.local_author;
+        let bubble_style = if local {
+            &s.local_bubble
+        } else {
+            &s.remote_bubble
+        };
+        // Bubbles take at most three quarters of the width, less their
+        // border and padding.
+        let frame = bubble_style.get_horizontal_frame_size() as usize;
+        let max_text = (width * 3 / 4).saturating_sub(frame).max(1);
+        let body = wrap(&message.text, max_text).join("\n");
+
+        let mut header = s.author.render(&message.author);
+        if self.show_timestamps {
+            header.push_str(
+                &s.timestamp
+                    .render(&format!(" · {}", format_time(message.time))),
+            );
+        }
+        let bubble = bubble_style.render(&body);
+        std::iter::once(header)
+            .chain(bubble.lines().map(str::to_string))
+            .map(|line| {
+                if local {
+                    let pad = width.saturating_sub(text::width(&line));
+                    format!("{}{}", " ".repeat(pad), line)
+                } else {
+                    line
+                }
+            })
+            .collect()
+    }
+
+    fn refresh(&mut self) {
+        let mut lines = Vec::new();
+        for (i, message) in self.messages.iter().enumerate() {
+            if self.unread_from == Some(i) {
+                let label = format!(" {} new ", self.messages.len() - i);
+                let side = self.inner_width().saturating_sub(text::width(&label)) / 2;
+                lines.push(self.styles.unread.render(&format!(
+                    "{}{}{}",
+                    "─".repeat(side),
+                    label,
+                    "─".repeat(side)
+                )));
+            } else if i > 0 {
+                lines.push(String::new());
+            }
+            lines.extend(self.render_message(message));
+        }
+        self.viewport.set_content_lines(lines);
+        if self.follow {
+            self.viewport.goto_bottom();
+        }
+    }
+
+    /// Handles the follow key and forwards everything else to the viewport.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.keymap.follow.matches(key_msg) {
+                self.set_follow(!self.follow);
+                return None;
+            }
+            let cmd = self.viewport.update(msg);
+            // Scrolling away from the bottom pauses following; returning
+            // to it resumes.
+            self.follow = self.viewport.at_bottom();
+            return cmd;
+        }
+        self.viewport.update(msg)
+    }
+
+    /// Renders the visible part of the conversation.
+    pub fn view(&self) -> String {
+        self.viewport.view()
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+        self.refresh();
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(80, 24), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_bubbles_align_by_author() {
+        let mut chat = Model::new(30, 20)
+            .with_local_author("me")
+            .with_timestamps(false);
+        chat.push(Message::new("me", "hi"));
+        chat.push(Message::new("bot", "hello"));
+        let view = strip_ansi(&chat.view());
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines[0].trim_end(), format!("{:>28}", "me"));
+        assert_eq!(lines[2].trim_end(), format!("{:>28}", "│ hi │"));
+        assert!(lines[5].starts_with("bot"));
+        assert!(lines[7].starts_with("│ hello │"));
+    }
+
+    #[test]
+    fn test_unread_separator_while_scrolled_up() {
+        let mut chat = Model::new(30, 6).with_local_author("me");
+        for i in 0..5 {
+            chat.push(Message::new("bot", &format!("message {}", i)));
+        }
+        assert!(chat.following());
+        chat.update(key(KeyCode::Char('k')));
+        assert!(!chat.following());
+        chat.push(Message::new("bot", "new one"));
+        chat.push(Message::new("bot", "another"));
+        assert_eq!(chat.unread(), 2);
+        assert!(strip_ansi(&chat.viewport.lines().join("\n")).contains(" 2 new "));
+        chat.push(Message::new("me", "reply"));
+        assert_eq!(chat.unread(), 0);
+    }
+
+    #[test]
+    fn test_follow_toggle_and_streaming() {
+        let mut chat = Model::new(30, 4);
+        chat.push(Message::new("bot", "a"));
+        chat.update(key(KeyCode::Char('F')));
+        assert!(!chat.following());
+        chat.append_to_last("bc");
+        assert_eq!(chat.messages()[0].text, "abc");
+        chat.set_follow(true);
+        assert!(chat.viewport.at_bottom());
+    }
+}
@@ -0,0 +1,781 @@
+//! Radio button and checkbox groups.
+//!
+//! [`RadioGroup`] picks exactly one option and [`CheckboxGroup`] any number
+//! of them. Both hold typed values, so the selection comes back as `&T`
+//! rather than an index into a separate vector, and both support disabled
+//! options and a vertical or horizontal layout. They are lighter than a full
+//! [`list`](crate::list) when a form only needs a handful of fixed options.
+//!
+//! Every change to the selection emits a [`ChangeMsg`].
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `↑`/`k`, `←`/`h` | Previous enabled option |
+//! | `↓`/`j`, `→`/`l` | Next enabled option |
+//! | `space`, `enter` | Select (radio) or toggle (checkbox) the option |
+//! | `a` | Toggle all enabled options (checkbox only) |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::choice::{CheckboxGroup, Choice, RadioGroup};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! enum Size { Small, Medium, Large }
+//!
+//! let mut size = RadioGroup::new(vec![
+//!     Choice::new("Small", Size::Small),
+//!     Choice::new("Medium", Size::Medium),
+//!     Choice::new("Large", Size::Large).disabled(),
+//! ]);
+//! size.select(1);
+//! assert_eq!(size.selected(), Some(&Size::Medium));
+//!
+//! let mut toppings = CheckboxGroup::new(vec![
+//!     Choice::new("Cheese", "cheese"),
+//!     Choice::new("Olives", "olives"),
+//! ]);
+//! toppings.toggle(0);
+//! assert_eq!(toppings.selected(), vec![&"cheese"]);
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// How the options are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// One option per line.
+    #[default]
+    Vertical,
+    /// All options on one line.
+    Horizontal,
+}
+
+/// A labelled option holding a typed value.
+#[derive(Debug, Clone)]
+pub struct Choice<T> {
+    /// Text shown next to the marker.
+    pub label: String,
+    /// Value returned by the selection accessors.
+    pub value: T,
+    /// Disabled options are skipped by navigation and cannot be selected.
+    pub disabled: bool,
+}
+
+impl<T> Choice<T> {
+    /// Creates an enabled option.
+    pub fn new(label: &str, value: T) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            disabled: false,
+        }
+    }
+
+    /// Marks the option as disabled.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+/// Message emitted when the selection of a group changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeMsg {
+    /// The ID of the group that changed.
+    pub id: i64,
+    /// Indices of the selected options, in ascending order.
+    pub selected: Vec<usize>,
+}
+
+/// Key bindings shared by both groups.
+#[derive(Debug, Clone)]
+pub struct ChoiceKeyMap {
+    /// Moves to the previous option. Default: `↑`, `k`, `←`, `h`.
+    pub prev: key::Binding,
+    /// Moves to the next option. Default: `↓`, `j`, `→`, `l`.
+    pub next: key::Binding,
+    /// Selects or toggles the option under the cursor. Default: `space`, `enter`.
+    pub toggle: key::Binding,
+    /// Toggles all options in a checkbox group. Default: `a`.
+    pub toggle_all: key::Binding,
+}
+
+impl Default for ChoiceKeyMap {
+    fn default() -> Self {
+        Self {
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["up", "k", "left", "h"]),
+                key::with_help("↑/k", "previous"),
+            ]),
+            next: key::new_binding(vec![
+                key::with_keys_str(&["down", "j", "right", "l"]),
+                key::with_help("↓/j", "next"),
+            ]),
+            toggle: key::new_binding(vec![
+                key::with_keys_str(&["space", "enter"]),
+                key::with_help("space", "select"),
+            ]),
+            toggle_all: key::new_binding(vec![
+                key::with_keys_str(&["a"]),
+                key::with_help("a", "toggle all"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for ChoiceKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.prev, &self.next, &self.toggle]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.prev, &self.next],
+            vec![&self.toggle, &self.toggle_all],
+        ]
+    }
+}
+
+/// Visual styles shared by both groups.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the option under the cursor while focused.
+    pub cursor: Style,
+    /// Style of other enabled options.
+    pub option: Style,
+    /// Style of the marker of selected options.
+    pub selected: Style,
+    /// Style of disabled options.
+    pub disabled: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            cursor: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            option: Style::new(),
+            selected: Style::new().foreground(AdaptiveColor {
+                Light: "#2E8B57",
+                Dark: "#04B575",
+            }),
+            disabled: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates group styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            cursor: theme.selected.clone(),
+            option: theme.text.clone(),
+            selected: theme.success.clone(),
+            disabled: theme.subtle.clone(),
+        }
+    }
+}
+
+/// State shared by both group kinds: the options, cursor and presentation.
+#[derive(Debug, Clone)]
+struct Group<T> {
+    id: i64,
+    options: Vec<Choice<T>>,
+    cursor: usize,
+    focus: bool,
+}
+
+impl<T> Group<T> {
+    fn new(options: Vec<Choice<T>>) -> Self {
+        let cursor = options.iter().position(|o| !o.disabled).unwrap_or(0);
+        Self {
+            id: next_id(),
+            options,
+            cursor,
+            focus: true,
+        }
+    }
+
+    fn enabled(&self, index: usize) -> bool {
+        self.options.get(index).is_some_and(|o| !o.disabled)
+    }
+
+    /// Moves the cursor to the nearest enabled option in the given
+    /// direction, staying put at either end.
+    fn step(&mut self, forward: bool) {
+        let mut i = self.cursor;
+        loop {
+            i = match (forward, i) {
+                (true, i) if i + 1 < self.options.len() => i + 1,
+                (false, i) if i > 0 => i - 1,
+                _ => return,
+            };
+            if self.enabled(i) {
+                self.cursor = i;
+                return;
+            }
+        }
+    }
+
+    fn changed(&self, selected: Vec<usize>) -> Cmd {
+        let id = self.id;
+        bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ChangeMsg {
+                id,
+                selected: selected.clone(),
+            }) as Msg
+        })
+    }
+
+    fn render(&self, layout: Layout, styles: &Styles, marker: impl Fn(usize) -> String) -> String {
+        let rows: Vec<String> = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, o)| {
+                let text = format!("{} {}", marker(i), o.label);
+                let on_cursor = i == self.cursor && self.focus;
+                let styled = if o.disabled {
+                    styles.disabled.render(&text)
+                } else if on_cursor {
+                    styles.cursor.render(&text)
+                } else {
+                    styles.option.render(&text)
+                };
+                match layout {
+                    Layout::Vertical if on_cursor => format!("> {}", styled),
+                    Layout::Vertical => format!("  {}", styled),
+                    Layout::Horizontal => styled,
+                }
+            })
+            .collect();
+        match layout {
+            Layout::Vertical => rows.join("\n"),
+            Layout::Horizontal => rows.join("  "),
+        }
+    }
+
+    /// One line per option stating `state(i)`, then the cursor position
+    /// while focused.
+    fn render_accessible(&self, state: impl Fn(usize) -> &'static str) -> String {
+        let mut lines: Vec<String> = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, o)| {
+                let disabled = if o.disabled { ", unavailable" } else { "" };
+                format!("{}: {}{}", o.label, state(i), disabled)
+            })
+            .collect();
+        if let Some(option) = self.options.get(self.cursor).filter(|_| self.focus) {
+            lines.push(format!(
+                "{} focused: {}",
+                crate::a11y::position("option", self.cursor, self.options.len()),
+                option.label
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// A group of mutually exclusive options.
+#[derive(Debug, Clone)]
+pub struct RadioGroup<T> {
+    group: Group<T>,
+    selected: Option<usize>,
+    /// Arrangement of the options.
+    pub layout: Layout,
+    /// Key bindings.
+    pub keymap: ChoiceKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+impl<T> RadioGroup<T> {
+    /// Creates a focused radio group with nothing selected.
+    pub fn new(options: Vec<Choice<T>>) -> Self {
+        Self {
+            group: Group::new(options),
+            selected: None,
+            layout: Layout::Vertical,
+            keymap: ChoiceKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the layout.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Preselects the option at `index`.
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.select(index);
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this group.
+    pub fn id(&self) -> i64 {
+        self.group.id
+    }
+
+    /// Returns the options.
+    pub fn options(&self) -> &[Choice<T>] {
+        &self.group.options
+    }
+
+    /// Returns the index of the option under the cursor.
+    pub fn cursor(&self) -> usize {
+        self.group.cursor
+    }
+
+    /// Returns the index of the selected option.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the value of the selected option.
+    pub fn selected(&self) -> Option<&T> {
+        self.selected.map(|i| &self.group.options[i].value)
+    }
+
+    /// Selects the option at `index` and moves the cursor to it. Returns
+    /// `false` for disabled or out-of-range options.
+    pub fn select(&mut self, index: usize) -> bool {
+        if !self.group.enabled(index) {
+            return false;
+        }
+        self.selected = Some(index);
+        self.group.cursor = index;
+        true
+    }
+
+    /// Handles key input, returning a [`ChangeMsg`] command when the
+    /// selection changed.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.group.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if self.keymap.prev.matches(key_msg) {
+            self.group.step(false);
+        } else if self.keymap.next.matches(key_msg) {
+            self.group.step(true);
+        } else if self.keymap.toggle.matches(key_msg) {
+            let cursor = self.group.cursor;
+            if self.selected != Some(cursor) && self.select(cursor) {
+                return Some(self.group.changed(vec![cursor]));
+            }
+        }
+        None
+    }
+
+    /// Renders the group with `(•)` markers, or the
+    /// [`accessible_view`](Self::accessible_view) in accessible mode.
+    pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        self.group.render(self.layout, &self.styles, |i| {
+            if self.selected == Some(i) {
+                self.styles.selected.render("(•)")
+            } else {
+                "( )".to_string()
+            }
+        })
+    }
+
+    /// Renders the group as plain text: each option with whether it is
+    /// selected, then which option has the cursor.
+    pub fn accessible_view(&self) -> String {
+        self.group.render_accessible(|i| {
+            if self.selected == Some(i) {
+                "selected"
+            } else {
+                "not selected"
+            }
+        })
+    }
+}
+
+/// A group of independently toggled options.
+#[derive(Debug, Clone)]
+pub struct CheckboxGroup<T> {
+    group: Group<T>,
+    checked: Vec<bool>,
+    /// Arrangement of the options.
+    pub layout: Layout,
+    /// Key bindings.
+    pub keymap: ChoiceKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+impl<T> CheckboxGroup<T> {
+    /// Creates a focused checkbox group with nothing checked.
+    pub fn new(options: Vec<Choice<T>>) -> Self {
+        let checked = vec![false; options.len()];
+        Self {
+            group: Group::new(options),
+            checked,
+            layout: Layout::Vertical,
+            keymap: ChoiceKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the layout.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Pre-checks the options at `indices`.
+    pub fn with_checked(mut self, indices: &[usize]) -> Self {
+        for &i in indices {
+            if self.group.enabled(i) {
+                self.checked[i] = true;
+            }
+        }
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this group.
+    pub fn id(&self) -> i64 {
+        self.group.id
+    }
+
+    /// Returns the options.
+    pub fn options(&self) -> &[Choice<T>] {
+        &self.group.options
+    }
+
+    /// Returns the index of the option under the cursor.
+    pub fn cursor(&self) -> usize {
+        self.group.cursor
+    }
+
+    /// Returns whether the option at `index` is checked.
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.get(index).copied().unwrap_or(false)
+    }
+
+    /// Returns the indices of the checked options.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        (0..self.checked.len())
+            .filter(|&i| self.checked[i])
+            .collect()
+    }
+
+    /// Returns the values of the checked options.
+    pub fn selected(&self) -> Vec<&T> {
+        self.selected_indices()
+            .into_iter()
+            .map(|i| &self.group.options[i].value)
+            .collect()
+    }
+
+    /// Toggles the option at `index`. Returns `false` for disabled or
+    /// out-of-range options.
+    pub fn toggle(&mut self, index: usize) -> bool {
+        if !self.group.enabled(index) {
+            return false;
+        }
+        self.checked[index] = !self.checked[index];
+        true
+    }
+
+    /// Checks every enabled option, or unchecks them all if they were
+    /// already checked.
+    pub fn toggle_all(&mut self) {
+        let enabled: Vec<usize> = (0..self.checked.len())
+            .filter(|&i| self.group.enabled(i))
+            .collect();
+        let all = enabled.iter().all(|&i| self.checked[i]);
+        for i in enabled {
+            self.checked[i] = !all;
+        }
+    }
+
+    /// Handles key input, returning a [`ChangeMsg`] command when the
+    /// selection changed.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.group.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let before = self.checked.clone();
+        if self.keymap.prev.matches(key_msg) {
+            self.group.step(false);
+        } else if self.keymap.next.matches(key_msg) {
+            self.group.step(true);
+        } else if self.keymap.toggle.matches(key_msg) {
+            self.toggle(self.group.cursor);
+        } else if self.keymap.toggle_all.matches(key_msg) {
+            self.toggle_all();
+        }
+        (self.checked != before).then(|| self.group.changed(self.selected_indices()))
+    }
+
+    /// Renders the group with `[x]` markers, or the
+    /// [`accessible_view`](Self::accessible_view) in accessible mode.
+    pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        self.group.render(self.layout, &self.styles, |i| {
+            if self.checked[i] {
+                self.styles.selected.render("[x]")
+            } else {
+                "[ ]".to_string()
+            }
+        })
+    }
+
+    /// Renders the group as plain text: each option with whether it is
+    /// checked, then which option has the cursor.
+    pub fn accessible_view(&self) -> String {
+        self.group
+            .render_accessible(|i| crate::a11y::checked(self.checked[i]))
+    }
+}
+
+impl<T> crate::Component for RadioGroup<T> {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.group.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.group.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.group.focus
+    }
+}
+
+impl<T> crate::Component for CheckboxGroup<T> {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.group.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.group.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.group.focus
+    }
+}
+
+impl<T> crate::theme::Themeable for RadioGroup<T> {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl<T> crate::theme::Themeable for CheckboxGroup<T> {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+/// Saved cursor and selection of either group kind; see
+/// [`Stateful`](crate::state::Stateful). Like [`ChangeMsg`], a radio group
+/// uses `selected` for at most one index.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Index of the option under the cursor.
+    pub cursor: usize,
+    /// Indices of the selected options.
+    pub selected: Vec<usize>,
+}
+
+impl<T> Group<T> {
+    fn restore_cursor(&mut self, cursor: usize) {
+        if self.enabled(cursor) {
+            self.cursor = cursor;
+        }
+    }
+}
+
+impl<T> crate::state::Stateful for RadioGroup<T> {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            cursor: self.group.cursor,
+            selected: self.selected.into_iter().collect(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.selected = state.selected.into_iter().find(|&i| self.group.enabled(i));
+        self.group.restore_cursor(state.cursor);
+    }
+}
+
+impl<T> crate::state::Stateful for CheckboxGroup<T> {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            cursor: self.group.cursor,
+            selected: self.selected_indices(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.checked.fill(false);
+        for i in state.selected {
+            if self.group.enabled(i) {
+                self.checked[i] = true;
+            }
+        }
+        self.group.restore_cursor(state.cursor);
+    }
+}
+
+impl<T: Send + 'static> BubbleTeaModel for RadioGroup<T> {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new()), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+impl<T: Send + 'static> BubbleTeaModel for CheckboxGroup<T> {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new()), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn options() -> Vec<Choice<u8>> {
+        vec![
+            Choice::new("One", 1),
+            Choice::new("Two", 2).disabled(),
+            Choice::new("Three", 3),
+        ]
+    }
+
+    #[test]
+    fn test_navigation_skips_disabled() {
+        let mut radio = RadioGroup::new(options());
+        radio.update(key(KeyCode::Down));
+        assert_eq!(radio.cursor(), 2);
+        radio.update(key(KeyCode::Down));
+        assert_eq!(radio.cursor(), 2);
+        radio.update(key(KeyCode::Up));
+        assert_eq!(radio.cursor(), 0);
+        assert!(!radio.select(1));
+    }
+
+    #[test]
+    fn test_radio_selection_is_exclusive() {
+        let mut radio = RadioGroup::new(options()).with_selected(0);
+        radio.update(key(KeyCode::Down));
+        assert!(radio.update(key(KeyCode::Char(' '))).is_some());
+        assert_eq!(radio.selected(), Some(&3));
+        assert!(radio.update(key(KeyCode::Enter)).is_none());
+    }
+
+    #[test]
+    fn test_checkbox_toggle_and_toggle_all() {
+        let mut boxes = CheckboxGroup::new(options());
+        assert!(boxes.update(key(KeyCode::Char(' '))).is_some());
+        assert_eq!(boxes.selected(), vec![&1]);
+        boxes.update(key(KeyCode::Char('a')));
+        assert_eq!(boxes.selected_indices(), vec![0, 2]);
+        boxes.update(key(KeyCode::Char('a')));
+        assert!(boxes.selected().is_empty());
+    }
+
+    #[test]
+    fn test_views() {
+        let radio = RadioGroup::new(options()).with_selected(2);
+        assert_eq!(
+            strip_ansi(&radio.view()),
+            "  ( ) One\n  ( ) Two\n> (•) Three"
+        );
+        let boxes = CheckboxGroup::new(options())
+            .with_checked(&[0])
+            .with_layout(Layout::Horizontal);
+        assert_eq!(strip_ansi(&boxes.view()), "[x] One  [ ] Two  [ ] Three");
+    }
+
+    #[test]
+    fn test_accessible_views_announce_state() {
+        let radio = RadioGroup::new(options()).with_selected(2);
+        assert_eq!(
+            radio.accessible_view(),
+            "One: not selected\nTwo: not selected, unavailable\nThree: selected\n\
+             option 3 of 3 focused: Three"
+        );
+        let boxes = CheckboxGroup::new(options()).with_checked(&[0]);
+        assert!(boxes.accessible_view().starts_with("One: checked\n"));
+    }
+}
@@ -0,0 +1,299 @@
+//! Pluggable clipboard backends.
+//!
+//! Widgets never talk to a clipboard directly. They hold a [`Clipboard`]
+//! handle wrapping a [`ClipboardProvider`], so where copied text goes and
+//! where pasted text comes from can be chosen per application, or per
+//! widget:
+//!
+//! - [`System`] uses the operating system clipboard. It needs the
+//!   `clipboard-support` feature (on by default).
+//! - [`Osc52`] asks the terminal to set its clipboard with an OSC 52 escape
+//!   sequence. This works over SSH and inside containers, where there is
+//!   no system clipboard to reach. Terminals do not let applications read
+//!   the clipboard this way; pastes arrive as bracketed paste instead.
+//! - [`Memory`] keeps the text in the process. It is the fallback without
+//!   the `clipboard-support` feature and the natural choice for tests.
+//!
+//! Widgets created without a clipboard of their own use the process-wide
+//! default, which [`set_default`] replaces.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::clipboard::{self, Clipboard, Memory, Osc52};
+//! use bubbletea_widgets::textarea;
+//!
+//! // Over SSH, copy through the terminal instead
+//! if std::env::var_os("SSH_TTY").is_some() {
+//!     clipboard::set_default(Clipboard::new(Osc52::new()));
+//! }
+//!
+//! // In a test, give one widget a clipboard that can be inspected
+//! let memory = Memory::with_text("pasted");
+//! let editor = textarea::Model::new().with_clipboard(Clipboard::new(memory.clone()));
+//! editor.copy_to_clipboard("copied").unwrap();
+//! assert_eq!(memory.text(), "copied");
+//! ```
+
+use crate::error::{Error, Result};
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, Msg};
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::io::Write;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::Duration;
+
+/// A place text can be copied to and pasted from.
+pub trait ClipboardProvider: Send + Sync {
+    /// Returns the text on the clipboard.
+    fn read(&self) -> Result<String>;
+
+    /// Puts `text` on the clipboard.
+    fn write(&self, text: &str) -> Result<()>;
+}
+
+/// A shared handle to a [`ClipboardProvider`]. Cloning it shares the
+/// provider.
+#[derive(Clone)]
+pub struct Clipboard {
+    provider: Arc<dyn ClipboardProvider>,
+}
+
+impl fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clipboard").finish_non_exhaustive()
+    }
+}
+
+/// The process-wide default, set on first use.
+static DEFAULT: Lazy<RwLock<Clipboard>> = Lazy::new(|| {
+    #[cfg(feature = "clipboard-support")]
+    let clipboard = Clipboard::new(System);
+    #[cfg(not(feature = "clipboard-support"))]
+    let clipboard = Clipboard::new(Memory::new());
+    RwLock::new(clipboard)
+});
+
+/// Returns the clipboard widgets use unless given one of their own: the
+/// [`System`] clipboard with the `clipboard-support` feature, otherwise a
+/// [`Memory`] clipboard shared by the whole process.
+impl Default for Clipboard {
+    fn default() -> Self {
+        DEFAULT
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+/// Replaces the default clipboard for widgets created from now on.
+pub fn set_default(clipboard: Clipboard) {
+    *DEFAULT.write().unwrap_or_else(PoisonError::into_inner) = clipboard;
+}
+
+impl Clipboard {
+    /// Wraps `provider` in a handle.
+    pub fn new(provider: impl ClipboardProvider + 'static) -> Self {
+        Self {
+            provider: Arc::new(provider),
+        }
+    }
+
+    /// Returns the text on the clipboard.
+    pub fn read(&self) -> Result<String> {
+        self.provider.read()
+    }
+
+    /// Puts `text` on the clipboard.
+    pub fn write(&self, text: &str) -> Result<()> {
+        self.provider.write(text)
+    }
+
+    /// Returns a command that reads the clipboard and turns the result into
+    /// a message with `into_msg`, keeping slow backends off the update
+    /// loop.
+    pub fn read_cmd<F>(&self, into_msg: F) -> Cmd
+    where
+        F: Fn(Result<String>) -> Msg + Send + 'static,
+    {
+        let clipboard = self.clone();
+        bubbletea_tick(Duration::from_nanos(1), move |_| into_msg(clipboard.read()))
+    }
+
+    /// Returns a command that puts `text` on the clipboard and reports the
+    /// outcome as a [`CopiedMsg`] or a [`CopyErrMsg`].
+    pub fn write_cmd(&self, text: String) -> Cmd {
+        let clipboard = self.clone();
+        bubbletea_tick(Duration::from_nanos(1), move |_| {
+            match clipboard.write(&text) {
+                Ok(()) => Box::new(CopiedMsg(text.clone())) as Msg,
+                Err(error) => Box::new(CopyErrMsg(error)) as Msg,
+            }
+        })
+    }
+}
+
+/// Sent after [`Clipboard::write_cmd`] put its text on the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopiedMsg(pub String);
+
+/// Sent when [`Clipboard::write_cmd`] could not write the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyErrMsg(pub Error);
+
+/// The operating system clipboard.
+#[cfg(feature = "clipboard-support")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct System;
+
+#[cfg(feature = "clipboard-support")]
+impl ClipboardProvider for System {
+    fn read(&self) -> Result<String> {
+        use ::clipboard::{ClipboardContext, ClipboardProvider as _};
+        let mut ctx: ClipboardContext = ClipboardContext::new()
+            .map_err(|e| Error::Clipboard(format!("Failed to create clipboard context: {}", e)))?;
+        ctx.get_contents()
+            .map_err(|e| Error::Clipboard(format!("Failed to read clipboard: {}", e)))
+    }
+
+    fn write(&self, text: &str) -> Result<()> {
+        use ::clipboard::{ClipboardContext, ClipboardProvider as _};
+        let mut ctx: ClipboardContext = ClipboardContext::new()
+            .map_err(|e| Error::Clipboard(format!("Failed to create clipboard context: {}", e)))?;
+        ctx.set_contents(text.to_string())
+            .map_err(|e| Error::Clipboard(format!("Failed to write clipboard: {}", e)))
+    }
+}
+
+/// The terminal's clipboard, set through the OSC 52 escape sequence.
+///
+/// Writing prints the sequence to standard output; the terminal, not the
+/// machine the program runs on, receives the text. Reading is not
+/// possible and always fails.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Osc52;
+
+impl Osc52 {
+    /// Creates the backend.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns the escape sequence that sets the terminal clipboard to
+    /// `text`.
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::clipboard::Osc52;
+    ///
+    /// assert_eq!(Osc52::sequence("hi"), "\x1b]52;c;aGk=\x07");
+    /// ```
+    pub fn sequence(text: &str) -> String {
+        format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+    }
+}
+
+impl ClipboardProvider for Osc52 {
+    fn read(&self) -> Result<String> {
+        Err(Error::Clipboard(
+            "The terminal clipboard cannot be read over OSC 52".to_string(),
+        ))
+    }
+
+    fn write(&self, text: &str) -> Result<()> {
+        let mut out = std::io::stdout().lock();
+        out.write_all(Self::sequence(text).as_bytes())
+            .and_then(|()| out.flush())
+            .map_err(|e| Error::Clipboard(format!("Failed to write clipboard: {}", e)))
+    }
+}
+
+/// A clipboard that keeps its text in memory. Clones share the text.
+#[derive(Debug, Clone, Default)]
+pub struct Memory {
+    text: Arc<Mutex<String>>,
+}
+
+impl Memory {
+    /// Creates an empty clipboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a clipboard holding `text`.
+    pub fn with_text(text: &str) -> Self {
+        Self {
+            text: Arc::new(Mutex::new(text.to_string())),
+        }
+    }
+
+    /// Returns the text on the clipboard.
+    pub fn text(&self) -> String {
+        self.text
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl ClipboardProvider for Memory {
+    fn read(&self) -> Result<String> {
+        Ok(self.text())
+    }
+
+    fn write(&self, text: &str) -> Result<()> {
+        *self.text.lock().unwrap_or_else(PoisonError::into_inner) = text.to_string();
+        Ok(())
+    }
+}
+
+/// Standard base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64_matches_rfc_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(base64(plain.as_bytes()), encoded);
+        }
+        assert_eq!(base64("日本".as_bytes()), "5pel5pys");
+    }
+
+    #[test]
+    fn test_memory_clones_share_text() {
+        let memory = Memory::with_text("a");
+        let clipboard = Clipboard::new(memory.clone());
+        assert_eq!(clipboard.read().unwrap(), "a");
+        clipboard.write("b").unwrap();
+        assert_eq!(memory.text(), "b");
+        assert!(Clipboard::new(Osc52::new()).read().is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! Rate-limiting commands: debounce and throttle.
+//!
+//! Both helpers key their state by an `id` string, so calls for the same
+//! piece of work share one limit wherever they come from, and unrelated
+//! work is limited separately.
+//!
+//! - [`debounce`] delivers its message once the calls for an `id` have
+//!   paused for the given delay. Every call supersedes the ones before it:
+//!   typing a query delivers one message after the last keystroke instead
+//!   of one per keystroke.
+//! - [`throttle`] delivers at most one message per interval for an `id`.
+//!   The first call is delivered at once; the last call made during the
+//!   interval is delivered when it ends, so the final state is never lost.
+//!
+//! Superseded commands still run to the end of their delay, then resolve
+//! to no message at all, so they can be returned from `update` like any
+//! other command.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_rs::{Cmd, KeyMsg, Msg, WindowSizeMsg};
+//! use bubbletea_widgets::cmd_util;
+//! use std::time::Duration;
+//!
+//! struct RunQuery;
+//! struct Relayout { width: u16, height: u16 }
+//!
+//! fn update(msg: Msg) -> Option<Cmd> {
+//!     if msg.downcast_ref::<KeyMsg>().is_some() {
+//!         // Query once typing pauses for 300ms
+//!         return Some(cmd_util::debounce("query", Duration::from_millis(300), RunQuery));
+//!     }
+//!     if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+//!         // Lay out at most every 50ms while the terminal is dragged
+//!         let relayout = Relayout { width: size.width, height: size.height };
+//!         return cmd_util::throttle("relayout", Duration::from_millis(50), relayout);
+//!     }
+//!     if msg.downcast_ref::<RunQuery>().is_some() {
+//!         // ... run the query
+//!     }
+//!     None
+//! }
+//! ```
+
+use bubbletea_rs::{Cmd, Msg};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+/// Generations are never reused, so a superseded command can never match
+/// a later call that happens to reuse its `id`.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// Latest generation of each pending debounce.
+static DEBOUNCES: Lazy<Mutex<HashMap<String, u64>>> = Lazy::new(Default::default);
+
+/// State of each throttled `id`.
+static THROTTLES: Lazy<Mutex<HashMap<String, Throttle>>> = Lazy::new(Default::default);
+
+struct Throttle {
+    /// End of the current interval.
+    until: Instant,
+    /// The last message sent during the interval, delivered when it ends.
+    pending: Option<Msg>,
+    /// Whether a command is waiting to deliver `pending`.
+    trailing: bool,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns a command that delivers `msg` after `delay`, unless another
+/// `debounce` with the same `id` is made first; then that one takes over.
+///
+/// `msg` is delivered as sent, as a [`Msg`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::cmd_util::debounce;
+/// use std::time::Duration;
+///
+/// struct Save;
+///
+/// // Only the last of a burst of edits triggers a save
+/// let first = debounce("autosave", Duration::from_secs(1), Save);
+/// let last = debounce("autosave", Duration::from_secs(1), Save);
+/// # let _ = (first, last);
+/// ```
+pub fn debounce<M: Send + 'static>(id: &str, delay: Duration, msg: M) -> Cmd {
+    let generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+    lock(&DEBOUNCES).insert(id.to_string(), generation);
+    let id = id.to_string();
+    Box::pin(async move {
+        sleep(delay).await;
+        let mut debounces = lock(&DEBOUNCES);
+        if debounces.get(&id) != Some(&generation) {
+            return None;
+        }
+        debounces.remove(&id);
+        Some(Box::new(msg) as Msg)
+    })
+}
+
+/// Returns a command that delivers `msg` at most once per `interval` for
+/// `id`.
+///
+/// The first call of an interval is delivered at once. Later calls during
+/// the interval replace each other; the last one is delivered when the
+/// interval ends, starting the next one. Calls absorbed into an already
+/// scheduled delivery return `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::cmd_util::throttle;
+/// use std::time::Duration;
+///
+/// struct Redraw;
+///
+/// let interval = Duration::from_millis(100);
+/// assert!(throttle("redraw", interval, Redraw).is_some()); // delivered now
+/// assert!(throttle("redraw", interval, Redraw).is_some()); // at the end of the interval
+/// assert!(throttle("redraw", interval, Redraw).is_none()); // replaces the one above
+/// ```
+pub fn throttle<M: Send + 'static>(id: &str, interval: Duration, msg: M) -> Option<Cmd> {
+    let now = Instant::now();
+    let mut throttles = lock(&THROTTLES);
+    if let Some(state) = throttles.get_mut(id).filter(|state| now < state.until) {
+        state.pending = Some(Box::new(msg));
+        if state.trailing {
+            return None;
+        }
+        state.trailing = true;
+        let wait = state.until - now;
+        let id = id.to_string();
+        return Some(Box::pin(async move {
+            sleep(wait).await;
+            let mut throttles = lock(&THROTTLES);
+            let state = throttles.get_mut(&id)?;
+            state.trailing = false;
+            let msg = state.pending.take()?;
+            state.until = Instant::now() + interval;
+            Some(msg)
+        }));
+    }
+    throttles.insert(
+        id.to_string(),
+        Throttle {
+            until: now + interval,
+            pending: None,
+            trailing: false,
+        },
+    );
+    Some(Box::pin(async move { Some(Box::new(msg) as Msg) }))
+}
+
+/// Drops whatever [`debounce`] or [`throttle`] is waiting to deliver for
+/// `id`. The throttle interval starts afresh with the next call.
+pub fn cancel(id: &str) {
+    lock(&DEBOUNCES).remove(id);
+    lock(&THROTTLES).remove(id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Hit(u32);
+
+    fn run(cmds: Vec<Cmd>) -> Vec<u32> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let tasks: Vec<_> = cmds.into_iter().map(tokio::spawn).collect();
+            let mut out = Vec::new();
+            for task in tasks {
+                if let Some(msg) = task.await.unwrap() {
+                    out.push(msg.downcast::<Hit>().unwrap().0);
+                }
+            }
+            out
+        })
+    }
+
+    #[test]
+    fn test_debounce_delivers_only_the_last_call() {
+        let delay = Duration::from_millis(20);
+        let cmds = (1..=3)
+            .map(|n| debounce("test-debounce", delay, Hit(n)))
+            .collect();
+        assert_eq!(run(cmds), [3]);
+
+        // A cancelled debounce delivers nothing
+        let cmd = debounce("test-debounce-cancel", delay, Hit(1));
+        cancel("test-debounce-cancel");
+        assert!(run(vec![cmd]).is_empty());
+    }
+
+    #[test]
+    fn test_throttle_delivers_first_and_last() {
+        let interval = Duration::from_millis(50);
+        let mut cmds = Vec::new();
+        for n in 1..=4 {
+            cmds.extend(throttle("test-throttle", interval, Hit(n)));
+        }
+        assert_eq!(cmds.len(), 2);
+        assert_eq!(run(cmds), [1, 4]);
+        cancel("test-throttle");
+    }
+}
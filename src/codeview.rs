@@ -0,0 +1,595 @@
+//! Read-only source code viewer with line numbers, goto-line and selections.
+//!
+//! The viewer displays source text inside an embedded
+//! [`viewport`](crate::viewport) with a line-number gutter. A line cursor can
+//! be moved through the file, jumped to a specific line with
+//! [`Model::goto_line`], and extended into a selected range of lines — handy
+//! for diff viewers, CI log explorers and code review tools.
+//!
+//! Syntax highlighting is provided by [syntect](https://docs.rs/syntect) when
+//! the `syntax-highlighting` feature is enabled. Without the feature, the
+//! language set with [`Model::set_language`] is remembered but text is shown
+//! with the plain [`Styles::text`] style.
+//!
+//! # Navigation Controls
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `↑`/`k`, `↓`/`j` | Move the line cursor |
+//! | `PgUp`/`b`, `PgDn`/`f` | Move by a page |
+//! | `g`/`Home`, `G`/`End` | Jump to the first / last line |
+//! | `v` | Start or stop selecting from the cursor line |
+//! | `esc` | Clear the selection |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::codeview;
+//!
+//! let mut view = codeview::new(60, 10);
+//! view.set_language("rs");
+//! view.set_content("fn main() {\n    println!(\"hi\");\n}\n");
+//! view.goto_line(2);
+//! assert_eq!(view.cursor_line(), 1);
+//!
+//! view.select_lines(0, 1);
+//! assert_eq!(view.selected_text().unwrap(), "fn main() {\n    println!(\"hi\");");
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::theme::Theme;
+use crate::viewport;
+use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+
+/// Visual styles for the code viewer.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Line numbers.
+    pub line_number: Style,
+    /// The line number of the cursor line.
+    pub cursor_line_number: Style,
+    /// Source text when no highlighting is applied.
+    pub text: Style,
+    /// Lines inside the selection.
+    pub selection: Style,
+    /// The separator between gutter and text.
+    pub gutter: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            line_number: Style::new().foreground(Color::from("#626262")),
+            cursor_line_number: Style::new().foreground(Color::from("#EE6FF8")).bold(true),
+            text: Style::new(),
+            selection: Style::new()
+                .foreground(Color::from("#FFFDF5"))
+                .background(Color::from("#44475A")),
+            gutter: Style::new().foreground(Color::from("#3C3C3C")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates code viewer styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            line_number: theme.muted.clone(),
+            cursor_line_number: theme.selected.clone(),
+            text: theme.text.clone(),
+            selection: Style::new()
+                .foreground(p.foreground.clone())
+                .background(p.selection.clone()),
+            gutter: theme.subtle.clone(),
+        }
+    }
+}
+
+/// Key bindings for the code viewer.
+#[derive(Debug, Clone)]
+pub struct CodeViewKeyMap {
+    /// Moves the cursor up one line. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves the cursor down one line. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Moves the cursor up one page. Default: `pgup`, `b`.
+    pub page_up: key::Binding,
+    /// Moves the cursor down one page. Default: `pgdown`, `f`.
+    pub page_down: key::Binding,
+    /// Jumps to the first line. Default: `g`, `home`.
+    pub top: key::Binding,
+    /// Jumps to the last line. Default: `G`, `end`.
+    pub bottom: key::Binding,
+    /// Toggles line selection. Default: `v`.
+    pub select: key::Binding,
+    /// Clears the selection. Default: `esc`.
+    pub clear: key::Binding,
+}
+
+impl Default for CodeViewKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "k"]),
+                key::with_help("↑/k", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "j"]),
+                key::with_help("↓/j", "down"),
+            ]),
+            page_up: key::new_binding(vec![
+                key::with_keys_str(&["pgup", "b"]),
+                key::with_help("b/pgup", "page up"),
+            ]),
+            page_down: key::new_binding(vec![
+                key::with_keys_str(&["pgdown", "f"]),
+                key::with_help("f/pgdn", "page down"),
+            ]),
+            top: key::new_binding(vec![
+                key::with_keys_str(&["g", "home"]),
+                key::with_help("g/home", "go to start"),
+            ]),
+            bottom: key::new_binding(vec![
+                key::with_keys_str(&["G", "end"]),
+                key::with_help("G/end", "go to end"),
+            ]),
+            select: key::new_binding(vec![
+                key::with_keys_str(&["v"]),
+                key::with_help("v", "select lines"),
+            ]),
+            clear: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "clear selection"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for CodeViewKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.up, &self.down, &self.select]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.page_up, &self.page_down],
+            vec![&self.top, &self.bottom, &self.select, &self.clear],
+        ]
+    }
+}
+
+/// A read-only, scrollable source code view.
+#[derive(Debug, Clone)]
+pub struct Model {
+    lines: Vec<String>,
+    highlighted: Vec<String>,
+    language: Option<String>,
+    cursor: usize,
+    anchor: Option<usize>,
+    selecting: bool,
+    selected: Option<(usize, usize)>,
+    /// Whether the line-number gutter is shown.
+    pub show_line_numbers: bool,
+    /// Name of the syntect theme used when highlighting is enabled.
+    pub theme: String,
+    /// The viewport displaying the rendered lines.
+    pub viewport: viewport::Model,
+    /// Visual styles.
+    pub styles: Styles,
+    /// Key bindings.
+    pub keymap: CodeViewKeyMap,
+}
+
+/// Creates a code viewer with the given viewport size.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty code viewer with the given viewport size.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            lines: Vec::new(),
+            highlighted: Vec::new(),
+            language: None,
+            cursor: 0,
+            anchor: None,
+            selecting: false,
+            selected: None,
+            show_line_numbers: true,
+            theme: "base16-ocean.dark".to_string(),
+            viewport: viewport::new(width, height),
+            styles: Styles::default(),
+            keymap: CodeViewKeyMap::default(),
+        }
+    }
+
+    /// Sets the language used for highlighting, given as a file extension
+    /// or syntax name (e.g. `"rs"`, `"py"`, `"Rust"`).
+    pub fn set_language(&mut self, language: &str) {
+        self.language = Some(language.to_string());
+        self.rehighlight();
+    }
+
+    /// Returns the language set for highlighting.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Replaces the displayed source, resetting cursor and selection.
+    pub fn set_content(&mut self, source: &str) {
+        let source = source.replace("\r\n", "\n");
+        let source = source.strip_suffix('\n').unwrap_or(&source);
+        self.lines = source.split('\n').map(str::to_string).collect();
+        self.cursor = 0;
+        self.anchor = None;
+        self.selecting = false;
+        self.selected = None;
+        self.viewport.set_y_offset(0);
+        self.rehighlight();
+    }
+
+    /// Returns the source lines.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the number of source lines.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the zero-based cursor line.
+    pub fn cursor_line(&self) -> usize {
+        self.cursor
+    }
+
+    /// Resizes the viewer, keeping the cursor line in view.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.viewport.width = width;
+        self.viewport.height = height;
+        self.viewport.set_y_offset(self.viewport.y_offset);
+        self.scroll_to_cursor();
+    }
+
+    /// Moves the cursor to a one-based line number and scrolls it into the
+    /// middle of the view. Out-of-range numbers are clamped.
+    pub fn goto_line(&mut self, line: usize) {
+        self.set_cursor(line.saturating_sub(1));
+        let offset = self.cursor.saturating_sub(self.viewport.height / 2);
+        self.viewport.set_y_offset(offset);
+        self.refresh();
+    }
+
+    /// Selects the zero-based, inclusive line range `start..=end`.
+    pub fn select_lines(&mut self, start: usize, end: usize) {
+        let last = self.lines.len().saturating_sub(1);
+        let (start, end) = (start.min(end).min(last), start.max(end).min(last));
+        self.selecting = false;
+        self.selected = Some((start, end));
+        self.set_cursor(end);
+        self.refresh();
+    }
+
+    /// Clears the selection.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+        self.selecting = false;
+        self.selected = None;
+        self.refresh();
+    }
+
+    /// Returns the selected zero-based, inclusive line range.
+    ///
+    /// While a keyboard selection is in progress the range follows the
+    /// cursor; once finished it stays fixed.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        match (self.selecting, self.anchor) {
+            (true, Some(a)) => Some((a.min(self.cursor), a.max(self.cursor))),
+            _ => self.selected,
+        }
+    }
+
+    /// Returns the text of the selected lines joined with newlines.
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        Some(self.lines[start..=end].join("\n"))
+    }
+
+    /// Moves the cursor by `delta` lines, extending the selection while
+    /// selecting, and keeps it visible.
+    pub fn move_cursor(&mut self, delta: isize) {
+        let target = (self.cursor as isize + delta).max(0) as usize;
+        self.set_cursor(target);
+        self.scroll_to_cursor();
+        self.refresh();
+    }
+
+    fn set_cursor(&mut self, line: usize) {
+        self.cursor = line.min(self.lines.len().saturating_sub(1));
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let top = self.viewport.y_offset;
+        let height = self.viewport.height.max(1);
+        if self.cursor < top {
+            self.viewport.set_y_offset(self.cursor);
+        } else if self.cursor >= top + height {
+            self.viewport.set_y_offset(self.cursor + 1 - height);
+        }
+    }
+
+    fn rehighlight(&mut self) {
+        self.highlighted = self.highlight();
+        self.refresh();
+    }
+
+    #[cfg(feature = "syntax-highlighting")]
+    fn highlight(&self) -> Vec<String> {
+        match &self.language {
+            Some(lang) => syntax::highlight(&self.lines, lang, &self.theme)
+                .unwrap_or_else(|| self.plain_lines()),
+            None => self.plain_lines(),
+        }
+    }
+
+    #[cfg(not(feature = "syntax-highlighting"))]
+    fn highlight(&self) -> Vec<String> {
+        self.plain_lines()
+    }
+
+    fn plain_lines(&self) -> Vec<String> {
+        self.lines
+            .iter()
+            .map(|l| self.styles.text.render(l))
+            .collect()
+    }
+
+    /// Rebuilds the viewport content from the highlighted lines.
+    fn refresh(&mut self) {
+        let digits = self.lines.len().max(1).to_string().len();
+        let selection = self.selection();
+        let sep = self.styles.gutter.render("│ ");
+        let rendered: Vec<String> = self
+            .highlighted
+            .iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let selected = selection.is_some_and(|(s, e)| (s..=e).contains(&i));
+                let body = if selected {
+                    self.styles.selection.render(&self.lines[i])
+                } else {
+                    text.clone()
+                };
+                if !self.show_line_numbers {
+                    return body;
+                }
+                let number = format!("{:>width$} ", i + 1, width = digits);
+                let number = if i == self.cursor {
+                    self.styles.cursor_line_number.render(&number)
+                } else {
+                    self.styles.line_number.render(&number)
+                };
+                format!("{}{}{}", number, sep, body)
+            })
+            .collect();
+        self.viewport.set_content_lines(rendered);
+    }
+
+    /// Handles navigation and selection keys.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let page = self.viewport.height.max(1) as isize;
+        if self.keymap.up.matches(key_msg) {
+            self.move_cursor(-1);
+        } else if self.keymap.down.matches(key_msg) {
+            self.move_cursor(1);
+        } else if self.keymap.page_up.matches(key_msg) {
+            self.move_cursor(-page);
+        } else if self.keymap.page_down.matches(key_msg) {
+            self.move_cursor(page);
+        } else if self.keymap.top.matches(key_msg) {
+            self.move_cursor(-(self.cursor as isize));
+        } else if self.keymap.bottom.matches(key_msg) {
+            self.move_cursor(self.lines.len() as isize);
+        } else if self.keymap.select.matches(key_msg) {
+            if self.selecting {
+                self.selected = self.selection();
+                self.selecting = false;
+            } else {
+                self.selecting = true;
+                self.anchor = Some(self.cursor);
+            }
+            self.refresh();
+        } else if self.keymap.clear.matches(key_msg) {
+            self.clear_selection();
+        }
+        None
+    }
+
+    /// Renders the visible lines.
+    pub fn view(&self) -> String {
+        self.viewport.view()
+    }
+
+    /// Returns the width of the line-number gutter in columns.
+    pub fn gutter_width(&self) -> usize {
+        if self.show_line_numbers {
+            text::width(&self.lines.len().max(1).to_string()) + 3
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(feature = "syntax-highlighting")]
+mod syntax {
+    use once_cell::sync::Lazy;
+    use syntect::easy::HighlightLines;
+    use syntect::highlighting::ThemeSet;
+    use syntect::parsing::SyntaxSet;
+    use syntect::util::as_24_bit_terminal_escaped;
+
+    static SYNTAXES: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+    static THEMES: Lazy<ThemeSet> = Lazy::new(ThemeSet::load_defaults);
+
+    /// Highlights `lines` as `language`, returning `None` for unknown
+    /// languages or themes.
+    pub(super) fn highlight(lines: &[String], language: &str, theme: &str) -> Option<Vec<String>> {
+        let syntax = SYNTAXES.find_syntax_by_token(language)?;
+        let theme = THEMES.themes.get(theme)?;
+        let mut h = HighlightLines::new(syntax, theme);
+        lines
+            .iter()
+            .map(|line| {
+                let with_newline = format!("{}\n", line);
+                let ranges = h.highlight_line(&with_newline, &SYNTAXES).ok()?;
+                let escaped = as_24_bit_terminal_escaped(&ranges, false);
+                Some(format!("{}\x1b[0m", escaped.trim_end_matches('\n')))
+            })
+            .collect()
+    }
+}
+
+/// Saved cursor, selection and scroll position of a code view; see
+/// [`Stateful`](crate::state::Stateful). The source is not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Zero-based cursor line.
+    pub cursor: usize,
+    /// Selected zero-based, inclusive line range.
+    pub selection: Option<(usize, usize)>,
+    /// Lines scrolled from the top.
+    pub scroll: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            cursor: self.cursor,
+            selection: self.selection(),
+            scroll: self.viewport.y_offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        let last = self.lines.len().saturating_sub(1);
+        self.anchor = None;
+        self.selecting = false;
+        self.selected = state
+            .selection
+            .map(|(start, end)| (start.min(end).min(last), start.max(end).min(last)));
+        self.set_cursor(state.cursor);
+        self.refresh();
+        self.viewport.set_y_offset(state.scroll);
+        self.scroll_to_cursor();
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(80, 24), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(c: char) -> Msg {
+        Box::new(KeyMsg {
+            key: KeyCode::Char(c),
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn sample(lines: usize) -> Model {
+        let mut m = Model::new(40, 5);
+        let src: Vec<String> = (1..=lines).map(|i| format!("line {}", i)).collect();
+        m.set_content(&src.join("\n"));
+        m
+    }
+
+    #[test]
+    fn test_line_numbers_are_right_aligned() {
+        let m = sample(12);
+        let first = lipgloss::strip_ansi(m.view().lines().next().unwrap());
+        assert_eq!(first, " 1 │ line 1");
+        assert_eq!(m.gutter_width(), 5);
+    }
+
+    #[test]
+    fn test_goto_line_clamps_and_scrolls() {
+        let mut m = sample(30);
+        m.goto_line(20);
+        assert_eq!(m.cursor_line(), 19);
+        assert_eq!(m.viewport.y_offset, 17);
+        m.goto_line(500);
+        assert_eq!(m.cursor_line(), 29);
+    }
+
+    #[test]
+    fn test_keyboard_selection() {
+        let mut m = sample(10);
+        m.update(key('j'));
+        m.update(key('v'));
+        m.update(key('j'));
+        m.update(key('j'));
+        assert_eq!(m.selection(), Some((1, 3)));
+        assert_eq!(m.selected_text().unwrap(), "line 2\nline 3\nline 4");
+        m.update(key('v'));
+        m.update(key('j'));
+        assert_eq!(
+            m.selection(),
+            Some((1, 3)),
+            "finished selections stay fixed"
+        );
+        m.update(Box::new(KeyMsg {
+            key: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+        }));
+        assert_eq!(m.selection(), None);
+    }
+
+    #[test]
+    fn test_cursor_keeps_viewport_in_range() {
+        let mut m = sample(20);
+        for _ in 0..7 {
+            m.update(key('j'));
+        }
+        assert_eq!(m.viewport.y_offset, 3);
+        m.update(key('g'));
+        assert_eq!(m.viewport.y_offset, 0);
+    }
+
+    #[cfg(feature = "syntax-highlighting")]
+    #[test]
+    fn test_highlighting_emits_truecolor_escapes() {
+        let mut m = Model::new(40, 5);
+        m.set_language("rs");
+        m.set_content("fn main() {}");
+        assert!(m.highlighted[0].contains("\x1b[38;2;"));
+        assert_eq!(lipgloss::strip_ansi(&m.highlighted[0]), "fn main() {}");
+    }
+}
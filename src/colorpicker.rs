@@ -0,0 +1,798 @@
+//! Color picker component for choosing ANSI 256 or truecolor values.
+//!
+//! The picker shows a grid of swatches, a hex entry field, a live preview of
+//! the highlighted color and a palette of recently chosen colors. Choosing a
+//! color emits a [`SelectedMsg`] carrying a lipgloss [`Color`], so the result
+//! can be dropped straight into a [`Style`].
+//!
+//! # Palettes
+//!
+//! | Palette | Grid | Color values |
+//! |---------|------|--------------|
+//! | [`Palette::Ansi256`] | 16 x 16 | ANSI indices such as `"196"` |
+//! | [`Palette::TrueColor`] | 24 hues x 10 lightness steps | Hex strings such as `"#ff0000"` |
+//!
+//! # Key Bindings
+//!
+//! Arrow keys (or `h`/`j`/`k`/`l`) move through the grid and `enter` picks
+//! the highlighted swatch. `tab` switches between the grid and the hex field,
+//! `ctrl+t` switches palettes and `1`-`9` re-pick a recently used color.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::colorpicker::{self, Palette};
+//! use lipgloss_extras::prelude::Color;
+//!
+//! let mut picker = colorpicker::new().with_palette(Palette::Ansi256);
+//! picker.set_cursor(196);
+//! assert_eq!(picker.current(), Color::from("196"));
+//!
+//! picker.select();
+//! assert_eq!(picker.selected(), Some(&Color::from("196")));
+//! assert_eq!(picker.recent(), &[Color::from("196")]);
+//! ```
+
+use crate::error::Error;
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use crossterm::event::KeyCode;
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+const TRUECOLOR_HUES: usize = 24;
+const TRUECOLOR_STEPS: usize = 10;
+
+/// The set of colors offered by the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Palette {
+    /// The 256 indexed terminal colors.
+    Ansi256,
+    /// A hue/lightness sweep of 24-bit colors.
+    TrueColor,
+}
+
+impl Palette {
+    /// Returns the number of grid columns.
+    pub fn columns(self) -> usize {
+        match self {
+            Palette::Ansi256 => 16,
+            Palette::TrueColor => TRUECOLOR_HUES,
+        }
+    }
+
+    /// Returns the number of swatches in the grid.
+    pub fn len(self) -> usize {
+        match self {
+            Palette::Ansi256 => 256,
+            Palette::TrueColor => TRUECOLOR_HUES * TRUECOLOR_STEPS,
+        }
+    }
+
+    /// Always `false`; every palette has swatches.
+    pub fn is_empty(self) -> bool {
+        false
+    }
+
+    /// Returns the color of the swatch at `index`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::colorpicker::Palette;
+    /// use lipgloss_extras::prelude::Color;
+    ///
+    /// assert_eq!(Palette::Ansi256.color(21), Color::from("21"));
+    /// assert_eq!(Palette::TrueColor.color(120), Color::from("#ff0000"));
+    /// ```
+    pub fn color(self, index: usize) -> Color {
+        let index = index.min(self.len() - 1);
+        match self {
+            Palette::Ansi256 => Color::from(index.to_string().as_str()),
+            Palette::TrueColor => {
+                let hue = (index % TRUECOLOR_HUES) as f64 * 360.0 / TRUECOLOR_HUES as f64;
+                let step = index / TRUECOLOR_HUES;
+                let lightness = 0.9 - step as f64 * 0.08;
+                let (r, g, b) = hsl_to_rgb(hue, 1.0, lightness);
+                Color::from_rgb(r, g, b)
+            }
+        }
+    }
+}
+
+/// Which part of the picker receives key input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    /// The swatch grid.
+    Grid,
+    /// The hex entry field.
+    Hex,
+}
+
+/// Message emitted when a color is picked.
+#[derive(Debug, Clone)]
+pub struct SelectedMsg {
+    /// The ID of the picker that emitted the message.
+    pub id: i64,
+    /// The chosen color.
+    pub color: Color,
+}
+
+/// Key bindings for the color picker.
+#[derive(Debug, Clone)]
+pub struct ColorPickerKeyMap {
+    /// Moves up one row. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves down one row. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Moves left one swatch. Default: `←`, `h`.
+    pub left: key::Binding,
+    /// Moves right one swatch. Default: `→`, `l`.
+    pub right: key::Binding,
+    /// Picks the highlighted swatch or the typed hex value. Default: `enter`.
+    pub select: key::Binding,
+    /// Switches between the grid and the hex field. Default: `tab`.
+    pub toggle_focus: key::Binding,
+    /// Switches between the ANSI and truecolor palettes. Default: `ctrl+t`.
+    pub toggle_palette: key::Binding,
+    /// Re-picks a recently used color by position. Default: `1`-`9`.
+    pub recent: key::Binding,
+    /// Leaves the hex field without picking. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for ColorPickerKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "k"]),
+                key::with_help("↑/k", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "j"]),
+                key::with_help("↓/j", "down"),
+            ]),
+            left: key::new_binding(vec![
+                key::with_keys_str(&["left", "h"]),
+                key::with_help("←/h", "left"),
+            ]),
+            right: key::new_binding(vec![
+                key::with_keys_str(&["right", "l"]),
+                key::with_help("→/l", "right"),
+            ]),
+            select: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "pick"),
+            ]),
+            toggle_focus: key::new_binding(vec![
+                key::with_keys_str(&["tab"]),
+                key::with_help("tab", "hex entry"),
+            ]),
+            toggle_palette: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+t"]),
+                key::with_help("ctrl+t", "palette"),
+            ]),
+            recent: key::new_binding(vec![
+                key::with_keys_str(&["1", "2", "3", "4", "5", "6", "7", "8", "9"]),
+                key::with_help("1-9", "recent"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "back"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for ColorPickerKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.select, &self.toggle_focus, &self.toggle_palette]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.left, &self.right],
+            vec![&self.select, &self.recent],
+            vec![&self.toggle_focus, &self.toggle_palette, &self.cancel],
+        ]
+    }
+}
+
+/// Visual styles for the color picker.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of section labels.
+    pub label: Style,
+    /// Style of the frame around the preview swatch.
+    pub preview: Style,
+    /// Style of the hex validation error.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            label: Style::new().foreground(Color::from("240")),
+            preview: Style::new().border(rounded_border()),
+            error: Style::new().foreground(Color::from("196")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates color picker styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            label: theme.muted.clone(),
+            preview: theme.frame.clone(),
+            error: theme.error.clone(),
+        }
+    }
+}
+
+/// A color picker.
+pub struct Model {
+    id: i64,
+    palette: Palette,
+    focus: Focus,
+    cursor: usize,
+    selected: Option<Color>,
+    recent: Vec<Color>,
+    err: Option<Error>,
+    /// Maximum number of recently used colors to remember.
+    pub max_recent: usize,
+    /// Hex entry field.
+    pub hex_input: textinput::Model,
+    /// Key bindings.
+    pub keymap: ColorPickerKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a color picker showing the ANSI 256 palette.
+pub fn new() -> Model {
+    Model::new()
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model {
+    /// Creates a color picker showing the ANSI 256 palette.
+    pub fn new() -> Self {
+        let mut hex_input = textinput::new();
+        hex_input.prompt = "# ".to_string();
+        hex_input.set_placeholder("rrggbb");
+        hex_input.set_char_limit(7);
+        hex_input.set_width(8);
+        Self {
+            id: next_id(),
+            palette: Palette::Ansi256,
+            focus: Focus::Grid,
+            cursor: 0,
+            selected: None,
+            recent: Vec::new(),
+            err: None,
+            max_recent: 8,
+            hex_input,
+            keymap: ColorPickerKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the palette shown by the grid.
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.set_palette(palette);
+        self
+    }
+
+    /// Seeds the recently used palette, most recent first.
+    pub fn with_recent(mut self, colors: Vec<Color>) -> Self {
+        self.recent = colors;
+        self.recent.truncate(self.max_recent);
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this picker.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the palette shown by the grid.
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    /// Switches palettes, keeping the cursor inside the grid.
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+        self.cursor = self.cursor.min(palette.len() - 1);
+    }
+
+    /// Returns which part of the picker has focus.
+    pub fn focus(&self) -> Focus {
+        self.focus
+    }
+
+    /// Returns the index of the highlighted swatch.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Highlights the swatch at `index`, clamped to the grid.
+    pub fn set_cursor(&mut self, index: usize) {
+        self.cursor = index.min(self.palette.len() - 1);
+    }
+
+    /// Returns the most recently picked color.
+    pub fn selected(&self) -> Option<&Color> {
+        self.selected.as_ref()
+    }
+
+    /// Returns the recently used colors, most recent first.
+    pub fn recent(&self) -> &[Color] {
+        &self.recent
+    }
+
+    /// Returns the hex validation error, if the last entry was rejected.
+    pub fn error(&self) -> Option<&Error> {
+        self.err.as_ref()
+    }
+
+    /// Returns the color being previewed.
+    ///
+    /// While the hex field has focus and holds a valid value, that value is
+    /// previewed; otherwise the highlighted swatch is.
+    pub fn current(&self) -> Color {
+        if self.focus == Focus::Hex {
+            if let Some(color) = parse_hex_color(&self.hex_input.value()) {
+                return color;
+            }
+        }
+        self.palette.color(self.cursor)
+    }
+
+    /// Picks the previewed color and returns a command emitting [`SelectedMsg`].
+    ///
+    /// In the hex field an invalid value is rejected: the error is recorded
+    /// and no command is returned.
+    pub fn select(&mut self) -> Option<Cmd> {
+        let color = match self.focus {
+            Focus::Grid => self.palette.color(self.cursor),
+            Focus::Hex => match parse_hex_color(&self.hex_input.value()) {
+                Some(color) => color,
+                None => {
+                    self.err = Some(Error::Validation(format!(
+                        "invalid hex color: {}",
+                        self.hex_input.value()
+                    )));
+                    return None;
+                }
+            },
+        };
+        Some(self.pick(color))
+    }
+
+    fn pick(&mut self, color: Color) -> Cmd {
+        self.err = None;
+        self.recent.retain(|c| c != &color);
+        self.recent.insert(0, color.clone());
+        self.recent.truncate(self.max_recent);
+        self.selected = Some(color.clone());
+        let id = self.id;
+        bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(SelectedMsg {
+                id,
+                color: color.clone(),
+            }) as Msg
+        })
+    }
+
+    fn set_focus(&mut self, focus: Focus) -> Option<Cmd> {
+        self.focus = focus;
+        self.err = None;
+        match focus {
+            Focus::Hex => {
+                let (r, g, b) = rgb(&self.palette.color(self.cursor));
+                self.hex_input
+                    .set_value(&format!("{:02x}{:02x}{:02x}", r, g, b));
+                Some(self.hex_input.focus())
+            }
+            Focus::Grid => {
+                self.hex_input.blur();
+                None
+            }
+        }
+    }
+
+    fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let cols = self.palette.columns() as isize;
+        let rows = (self.palette.len() as isize + cols - 1) / cols;
+        let x = (self.cursor as isize % cols + dx).clamp(0, cols - 1);
+        let y = (self.cursor as isize / cols + dy).clamp(0, rows - 1);
+        self.set_cursor((y * cols + x) as usize);
+    }
+
+    /// Handles key input for the focused part of the picker.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.keymap.toggle_focus.matches(key_msg) {
+                let next = match self.focus {
+                    Focus::Grid => Focus::Hex,
+                    Focus::Hex => Focus::Grid,
+                };
+                return self.set_focus(next);
+            }
+            if self.keymap.select.matches(key_msg) {
+                return self.select();
+            }
+            if self.focus == Focus::Hex {
+                if self.keymap.cancel.matches(key_msg) {
+                    return self.set_focus(Focus::Grid);
+                }
+                self.err = None;
+                return self.hex_input.update(msg);
+            }
+            if self.keymap.up.matches(key_msg) {
+                self.move_cursor(0, -1);
+            } else if self.keymap.down.matches(key_msg) {
+                self.move_cursor(0, 1);
+            } else if self.keymap.left.matches(key_msg) {
+                self.move_cursor(-1, 0);
+            } else if self.keymap.right.matches(key_msg) {
+                self.move_cursor(1, 0);
+            } else if self.keymap.toggle_palette.matches(key_msg) {
+                self.set_palette(match self.palette {
+                    Palette::Ansi256 => Palette::TrueColor,
+                    Palette::TrueColor => Palette::Ansi256,
+                });
+            } else if self.keymap.recent.matches(key_msg) {
+                if let KeyCode::Char(c) = key_msg.key {
+                    let slot = c.to_digit(10).unwrap_or(0) as usize;
+                    if let Some(color) = slot.checked_sub(1).and_then(|i| self.recent.get(i)) {
+                        return Some(self.pick(color.clone()));
+                    }
+                }
+            }
+            return None;
+        }
+        if self.focus == Focus::Hex {
+            return self.hex_input.update(msg);
+        }
+        None
+    }
+
+    fn swatch(color: &Color, marker: &str) -> String {
+        let (r, g, b) = rgb(color);
+        let luminance = 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64;
+        let fg = if luminance > 128.0 {
+            "#000000"
+        } else {
+            "#ffffff"
+        };
+        Style::new()
+            .background(color.clone())
+            .foreground(Color::from(fg))
+            .render(marker)
+    }
+
+    fn grid_view(&self) -> String {
+        let cols = self.palette.columns();
+        (0..self.palette.len())
+            .collect::<Vec<_>>()
+            .chunks(cols)
+            .map(|row| {
+                row.iter()
+                    .map(|&i| {
+                        let marker = if i == self.cursor && self.focus == Focus::Grid {
+                            "<>"
+                        } else {
+                            "  "
+                        };
+                        Self::swatch(&self.palette.color(i), marker)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders the grid, preview, hex field and recent colors.
+    pub fn view(&self) -> String {
+        let current = self.current();
+        let (r, g, b) = rgb(&current);
+        let swatch = Self::swatch(&current, &" ".repeat(10));
+        let preview = self
+            .styles
+            .preview
+            .render(&format!("{}\n{}", swatch, swatch));
+        let mut side = vec![
+            preview,
+            self.styles.label.render(&current.0),
+            format!("#{:02x}{:02x}{:02x}", r, g, b),
+            String::new(),
+            self.hex_input.view(),
+        ];
+        if let Some(err) = &self.err {
+            side.push(self.styles.error.render(&err.to_string()));
+        }
+        if !self.recent.is_empty() {
+            side.push(String::new());
+            side.push(self.styles.label.render("Recent"));
+            side.push(
+                self.recent
+                    .iter()
+                    .map(|c| Self::swatch(c, "  "))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+        }
+        let side = side.join("\n");
+        lipgloss::join_horizontal(lipgloss::TOP, &[&self.grid_view(), "  ", &side])
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        if self.focus == Focus::Hex {
+            return Some(self.hex_input.focus());
+        }
+        None
+    }
+
+    fn blur(&mut self) {
+        self.hex_input.blur();
+    }
+
+    fn focused(&self) -> bool {
+        self.focus == Focus::Grid || self.hex_input.focused()
+    }
+}
+
+/// Saved palette, cursor and picks of a color picker; see
+/// [`Stateful`](crate::state::Stateful). Colors are kept as the strings
+/// lipgloss [`Color`]s wrap, such as `"196"` or `"#ff0000"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The palette shown by the grid.
+    pub palette: Palette,
+    /// Index of the highlighted swatch.
+    pub cursor: usize,
+    /// The most recently picked color.
+    pub selected: Option<String>,
+    /// Recently used colors, most recent first.
+    pub recent: Vec<String>,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            palette: self.palette,
+            cursor: self.cursor,
+            selected: self.selected.as_ref().map(|c| c.0.clone()),
+            recent: self.recent.iter().map(|c| c.0.clone()).collect(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_palette(state.palette);
+        self.set_cursor(state.cursor);
+        self.selected = state.selected.as_deref().map(Color::from);
+        self.recent = state
+            .recent
+            .iter()
+            .map(|c| Color::from(c.as_str()))
+            .collect();
+        self.recent.truncate(self.max_recent);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+/// Parses `rrggbb` or `rgb`, with or without a leading `#`, into a [`Color`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::colorpicker::parse_hex_color;
+/// use lipgloss_extras::prelude::Color;
+///
+/// assert_eq!(parse_hex_color("#FF8000"), Some(Color::from("#ff8000")));
+/// assert_eq!(parse_hex_color("f80"), Some(Color::from("#ff8800")));
+/// assert_eq!(parse_hex_color("nope"), None);
+/// ```
+pub fn parse_hex_color(s: &str) -> Option<Color> {
+    let s = s.trim();
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) || (hex.len() != 3 && hex.len() != 6) {
+        return None;
+    }
+    let (r, g, b, _) = lipgloss::parse_hex(&format!("#{}", hex))?;
+    Some(Color::from_rgb(r, g, b))
+}
+
+/// Returns the RGB components of a hex or ANSI 256 color.
+fn rgb(color: &Color) -> (u8, u8, u8) {
+    if let Some((r, g, b, _)) = lipgloss::parse_hex(&color.0) {
+        return (r, g, b);
+    }
+    match color.0.parse::<u8>() {
+        Ok(index) => ansi_to_rgb(index),
+        Err(_) => (0, 0, 0),
+    }
+}
+
+fn ansi_to_rgb(index: u8) -> (u8, u8, u8) {
+    const BASE: [(u8, u8, u8); 16] = [
+        (0x00, 0x00, 0x00),
+        (0x80, 0x00, 0x00),
+        (0x00, 0x80, 0x00),
+        (0x80, 0x80, 0x00),
+        (0x00, 0x00, 0x80),
+        (0x80, 0x00, 0x80),
+        (0x00, 0x80, 0x80),
+        (0xc0, 0xc0, 0xc0),
+        (0x80, 0x80, 0x80),
+        (0xff, 0x00, 0x00),
+        (0x00, 0xff, 0x00),
+        (0xff, 0xff, 0x00),
+        (0x00, 0x00, 0xff),
+        (0xff, 0x00, 0xff),
+        (0x00, 0xff, 0xff),
+        (0xff, 0xff, 0xff),
+    ];
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match index {
+        0..=15 => BASE[index as usize],
+        16..=231 => {
+            let i = (index - 16) as usize;
+            (LEVELS[i / 36], LEVELS[(i / 6) % 6], LEVELS[i % 6])
+        }
+        _ => {
+            let v = 8 + (index - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match h as u32 {
+        0..=59 => (c, x, 0.0),
+        60..=119 => (x, c, 0.0),
+        120..=179 => (0.0, c, x),
+        180..=239 => (0.0, x, c),
+        240..=299 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let to_u8 = |v: f64| ((v + m) * 255.0).round() as u8;
+    (to_u8(r), to_u8(g), to_u8(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_grid_navigation_clamps_to_edges() {
+        let mut m = Model::new();
+        m.update(key(KeyCode::Left));
+        m.update(key(KeyCode::Up));
+        assert_eq!(m.cursor(), 0);
+        m.update(key(KeyCode::Down));
+        m.update(key(KeyCode::Right));
+        assert_eq!(m.cursor(), 17);
+        m.set_cursor(1000);
+        assert_eq!(m.cursor(), 255);
+    }
+
+    #[test]
+    fn test_hex_entry_validates_and_picks() {
+        let mut m = Model::new();
+        m.update(key(KeyCode::Tab));
+        assert_eq!(m.focus(), Focus::Hex);
+        m.hex_input.set_value("zz");
+        assert!(m.update(key(KeyCode::Enter)).is_none());
+        assert!(m.error().is_some());
+
+        m.hex_input.set_value("#00ff80");
+        assert!(m.update(key(KeyCode::Enter)).is_some());
+        assert_eq!(m.selected(), Some(&Color::from("#00ff80")));
+        assert!(m.error().is_none());
+    }
+
+    #[test]
+    fn test_hex_entry_rejects_multibyte_text() {
+        assert_eq!(parse_hex_color("漢"), None);
+        assert_eq!(parse_hex_color("#é1"), None);
+
+        let mut m = Model::new();
+        m.update(key(KeyCode::Tab));
+        m.hex_input.set_value("");
+        for ch in "é1".chars() {
+            m.update(key(KeyCode::Char(ch)));
+        }
+        m.view();
+        assert!(m.update(key(KeyCode::Enter)).is_none());
+        assert!(m.error().is_some());
+    }
+
+    #[test]
+    fn test_recent_palette_dedupes_and_caps() {
+        let mut m = Model::new();
+        m.max_recent = 2;
+        for i in [1, 2, 1, 3] {
+            m.set_cursor(i);
+            m.select();
+        }
+        assert_eq!(m.recent(), &[Color::from("3"), Color::from("1")]);
+
+        m.update(key(KeyCode::Char('2')));
+        assert_eq!(m.selected(), Some(&Color::from("1")));
+        assert_eq!(m.recent()[0], Color::from("1"));
+    }
+
+    #[test]
+    fn test_color_conversions() {
+        assert_eq!(ansi_to_rgb(196), (255, 0, 0));
+        assert_eq!(ansi_to_rgb(232), (8, 8, 8));
+        assert_eq!(rgb(&Palette::TrueColor.color(128)), (0, 255, 0));
+        let mut m = Model::new();
+        m.update(Box::new(KeyMsg {
+            key: KeyCode::Char('t'),
+            modifiers: crossterm::event::KeyModifiers::CONTROL,
+        }));
+        assert_eq!(m.palette(), Palette::TrueColor);
+        assert!(m.view().contains("#"));
+    }
+}
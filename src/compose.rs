@@ -0,0 +1,160 @@
+//! Layering rendered views.
+//!
+//! Popups such as dialogs, command palettes, tooltips and which-key hints
+//! are rendered as strings of their own and then need to be drawn on top of
+//! the rest of the screen. [`overlay`] does that: it replaces the cells of
+//! the base view under the popup and leaves everything else alone.
+//!
+//! Both strings may contain ANSI styling. Columns are measured by display
+//! width, so wide characters and escape sequences are handled, and the
+//! base keeps its styling on either side of the popup. A wide character cut
+//! by the popup's edge is replaced with spaces.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::compose;
+//!
+//! let base = "..........\n..........\n..........";
+//! assert_eq!(
+//!     compose::overlay(base, "[ok]", 3, 1),
+//!     "..........\n...[ok]...\n.........."
+//! );
+//! assert_eq!(
+//!     compose::overlay_centered(base, "ab\ncd"),
+//!     "....ab....\n....cd....\n.........."
+//! );
+//! ```
+
+use crate::text::{self, read_escape};
+use unicode_width::UnicodeWidthChar;
+
+const RESET: &str = "\x1b[0m";
+
+/// Draws `popup` over `base` with its top-left corner at column `x` and row
+/// `y`. Lines of the popup narrower than its widest line are padded so the
+/// popup covers a rectangle. Where the popup reaches past the base, the
+/// base is extended with blank cells.
+pub fn overlay(base: &str, popup: &str, x: usize, y: usize) -> String {
+    let mut lines: Vec<String> = base.lines().map(str::to_string).collect();
+    let popup_lines: Vec<&str> = popup.lines().collect();
+    let popup_width = popup_lines
+        .iter()
+        .map(|l| text::width(l))
+        .max()
+        .unwrap_or(0);
+    if lines.len() < y + popup_lines.len() {
+        lines.resize(y + popup_lines.len(), String::new());
+    }
+    for (i, fg) in popup_lines.iter().enumerate() {
+        let (left, right) = split_columns(&lines[y + i], x, x + popup_width);
+        let mut row = left;
+        row.push_str(fg);
+        if fg.contains('\x1b') {
+            row.push_str(RESET);
+        }
+        if !right.is_empty() {
+            row.push_str(&" ".repeat(popup_width - text::width(fg)));
+            row.push_str(&right);
+        }
+        lines[y + i] = row;
+    }
+    lines.join("\n")
+}
+
+/// Draws `popup` centered over `base`.
+pub fn overlay_centered(base: &str, popup: &str) -> String {
+    let (base_w, base_h) = size(base);
+    let (popup_w, popup_h) = size(popup);
+    overlay(
+        base,
+        popup,
+        base_w.saturating_sub(popup_w) / 2,
+        base_h.saturating_sub(popup_h) / 2,
+    )
+}
+
+/// Returns the width of the widest line and the number of lines of a
+/// rendered view.
+pub fn size(view: &str) -> (usize, usize) {
+    let width = view.lines().map(text::width).max().unwrap_or(0);
+    (width, view.lines().count())
+}
+
+/// Splits a possibly styled line around the columns `start..end`. The left
+/// part is padded to `start` columns and reset if it carries styling; the
+/// right part starts with every escape sequence seen before it, so it keeps
+/// the style it had. Wide characters cut by the range become spaces.
+fn split_columns(line: &str, start: usize, end: usize) -> (String, String) {
+    let mut left = String::new();
+    let mut right = String::new();
+    let mut left_styled = false;
+    let mut col = 0;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let seq = read_escape(&mut chars);
+            if col < start {
+                left.push_str(&seq);
+                left_styled = true;
+            }
+            right.push_str(&seq);
+            continue;
+        }
+        let w = c.width().unwrap_or(0);
+        if col + w <= start {
+            left.push(c);
+        } else if col >= end {
+            right.push(c);
+        } else {
+            if col < start {
+                left.push_str(&" ".repeat(start - col));
+            }
+            if col + w > end {
+                right.push_str(&" ".repeat(col + w - end));
+            }
+        }
+        col += w;
+    }
+    if col < start {
+        left.push_str(&" ".repeat(start - col));
+    }
+    if left_styled {
+        left.push_str(RESET);
+    }
+    // Escape sequences alone leave nothing visible to draw.
+    if text::width(&right) == 0 {
+        right.clear();
+    }
+    (left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_split_keeps_styles_around_the_hole() {
+        let (left, right) = split_columns("\x1b[31mred\x1b[0m plain", 1, 5);
+        assert_eq!(left, "\x1b[31mr\x1b[0m");
+        assert_eq!(right, "\x1b[31m\x1b[0mlain");
+        let (left, right) = split_columns("\x1b[1mbold text", 2, 4);
+        assert_eq!(right, "\x1b[1m text");
+        assert_eq!(strip_ansi(&left), "bo");
+        let (left, right) = split_columns("日本", 1, 3);
+        assert_eq!((left.as_str(), right.as_str()), (" ", " "));
+    }
+
+    #[test]
+    fn test_overlay_extends_base_and_pads_popup() {
+        assert_eq!(overlay("ab", "X", 4, 1), "ab\n    X");
+        assert_eq!(overlay("abcdef\nghijkl", "XY\nZ", 2, 0), "abXYef\nghZ kl");
+    }
+
+    #[test]
+    fn test_styled_popup_is_reset_before_the_base_resumes() {
+        let out = overlay("..........", "\x1b[7mhi", 2, 0);
+        assert_eq!(out, "..\x1b[7mhi\x1b[0m......");
+    }
+}
@@ -0,0 +1,457 @@
+//! Inline yes/no confirmation prompt.
+//!
+//! A confirm prompt asks a single question such as "Proceed?" and resolves
+//! to a [`ConfirmMsg`] carrying a boolean. It can render either as a pair of
+//! buttons or in the compact terminal style `Proceed? [y/N]`, where the
+//! capitalised letter marks the default answer.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `←`/`→`, `h`/`l`, `tab` | Toggle the highlighted answer |
+//! | `y` | Answer yes immediately |
+//! | `n` | Answer no immediately |
+//! | `enter` | Submit the highlighted answer |
+//! | `esc` | Submit the default answer |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::confirm;
+//!
+//! let prompt = confirm::new("Proceed?").with_inline(true);
+//! assert_eq!(prompt.view(), "Proceed? [y/N]");
+//!
+//! let prompt = confirm::new("Overwrite file?")
+//!     .with_labels("Overwrite", "Keep")
+//!     .with_default(true);
+//! assert!(prompt.value());
+//! assert!(prompt.view().contains("Overwrite"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted when the prompt is answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfirmMsg {
+    /// The ID of the prompt that was answered.
+    pub id: i64,
+    /// `true` for the affirmative answer.
+    pub value: bool,
+}
+
+/// Key bindings for the confirm prompt.
+#[derive(Debug, Clone)]
+pub struct ConfirmKeyMap {
+    /// Toggles the highlighted answer. Default: `←`, `→`, `h`, `l`, `tab`.
+    pub toggle: key::Binding,
+    /// Answers yes. Default: `y`, `Y`.
+    pub accept: key::Binding,
+    /// Answers no. Default: `n`, `N`.
+    pub reject: key::Binding,
+    /// Submits the highlighted answer. Default: `enter`.
+    pub submit: key::Binding,
+    /// Submits the default answer. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for ConfirmKeyMap {
+    fn default() -> Self {
+        Self {
+            toggle: key::new_binding(vec![
+                key::with_keys_str(&["left", "right", "h", "l", "tab"]),
+                key::with_help("←/→", "toggle"),
+            ]),
+            accept: key::new_binding(vec![
+                key::with_keys_str(&["y", "Y"]),
+                key::with_help("y", "yes"),
+            ]),
+            reject: key::new_binding(vec![
+                key::with_keys_str(&["n", "N"]),
+                key::with_help("n", "no"),
+            ]),
+            submit: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "submit"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "default"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for ConfirmKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.accept, &self.reject, &self.toggle, &self.submit]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.accept, &self.reject],
+            vec![&self.toggle, &self.submit, &self.cancel],
+        ]
+    }
+}
+
+/// Visual styles for the confirm prompt.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the question text.
+    pub prompt: Style,
+    /// Style of the highlighted answer.
+    pub selected: Style,
+    /// Style of the other answer.
+    pub unselected: Style,
+    /// Style of the `[y/N]` hint in inline mode.
+    pub hint: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            prompt: Style::new(),
+            selected: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#F25D94"))
+                .padding(0, 1, 0, 1),
+            unselected: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#888B7E"))
+                .padding(0, 1, 0, 1),
+            hint: Style::new(),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates confirm prompt styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let button = Style::new()
+            .foreground(p.background.clone())
+            .padding(0, 1, 0, 1);
+        Self {
+            prompt: theme.text.clone(),
+            selected: button.clone().background(p.primary.clone()),
+            unselected: button.background(p.muted.clone()),
+            hint: theme.muted.clone(),
+        }
+    }
+}
+
+/// A yes/no confirmation prompt.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    value: bool,
+    default: bool,
+    answer: Option<bool>,
+    focus: bool,
+    /// The question being asked.
+    pub prompt: String,
+    /// Label of the affirmative answer.
+    pub affirmative: String,
+    /// Label of the negative answer.
+    pub negative: String,
+    /// Whether to render as `Prompt [y/N]` instead of buttons.
+    pub inline: bool,
+    /// Key bindings.
+    pub keymap: ConfirmKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused confirm prompt that defaults to "No".
+pub fn new(prompt: &str) -> Model {
+    Model::new(prompt)
+}
+
+impl Model {
+    /// Creates a focused confirm prompt that defaults to "No".
+    pub fn new(prompt: &str) -> Self {
+        Self {
+            id: next_id(),
+            value: false,
+            default: false,
+            answer: None,
+            focus: true,
+            prompt: prompt.to_string(),
+            affirmative: "Yes".to_string(),
+            negative: "No".to_string(),
+            inline: false,
+            keymap: ConfirmKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the affirmative and negative labels.
+    pub fn with_labels(mut self, affirmative: &str, negative: &str) -> Self {
+        self.affirmative = affirmative.to_string();
+        self.negative = negative.to_string();
+        self
+    }
+
+    /// Sets the default answer, which is also initially highlighted.
+    pub fn with_default(mut self, value: bool) -> Self {
+        self.default = value;
+        self.value = value;
+        self
+    }
+
+    /// Enables the compact `Prompt [y/N]` rendering.
+    pub fn with_inline(mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this prompt.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the highlighted answer.
+    pub fn value(&self) -> bool {
+        self.value
+    }
+
+    /// Returns the submitted answer, if the prompt has been answered.
+    pub fn answer(&self) -> Option<bool> {
+        self.answer
+    }
+
+    /// Clears the answer and highlights the default again.
+    pub fn reset(&mut self) {
+        self.answer = None;
+        self.value = self.default;
+    }
+
+    /// Answers the prompt and returns a command emitting [`ConfirmMsg`].
+    pub fn submit(&mut self, value: bool) -> Cmd {
+        self.value = value;
+        self.answer = Some(value);
+        let id = self.id;
+        bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ConfirmMsg { id, value }) as Msg
+        })
+    }
+
+    /// Handles key input. Answered or blurred prompts ignore messages.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus || self.answer.is_some() {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if self.keymap.accept.matches(key_msg) {
+            return Some(self.submit(true));
+        }
+        if self.keymap.reject.matches(key_msg) {
+            return Some(self.submit(false));
+        }
+        if self.keymap.submit.matches(key_msg) {
+            return Some(self.submit(self.value));
+        }
+        if self.keymap.cancel.matches(key_msg) {
+            return Some(self.submit(self.default));
+        }
+        if self.keymap.toggle.matches(key_msg) {
+            self.value = !self.value;
+        }
+        None
+    }
+
+    /// Renders the prompt.
+    ///
+    /// Once answered, the prompt renders as the question followed by the
+    /// chosen label. In accessible mode this is the
+    /// [`accessible_view`](Self::accessible_view).
+    pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        let prompt = self.styles.prompt.render(&self.prompt);
+        if let Some(answer) = self.answer {
+            let label = if answer {
+                &self.affirmative
+            } else {
+                &self.negative
+            };
+            return format!("{} {}", prompt, label);
+        }
+        if self.inline {
+            let hint = if self.default { "[Y/n]" } else { "[y/N]" };
+            return format!("{} {}", prompt, self.styles.hint.render(hint));
+        }
+        let (yes, no) = if self.value {
+            (&self.styles.selected, &self.styles.unselected)
+        } else {
+            (&self.styles.unselected, &self.styles.selected)
+        };
+        lipgloss::join_horizontal(
+            lipgloss::CENTER,
+            &[
+                &prompt,
+                "  ",
+                &yes.render(&self.affirmative),
+                " ",
+                &no.render(&self.negative),
+            ],
+        )
+    }
+
+    /// Renders the prompt as plain text: the question and the highlighted
+    /// answer, or the submitted answer once there is one.
+    pub fn accessible_view(&self) -> String {
+        let label = |value: bool| {
+            if value {
+                &self.affirmative
+            } else {
+                &self.negative
+            }
+        };
+        match self.answer {
+            Some(answer) => format!("{} Answered: {}", self.prompt, label(answer)),
+            None => format!(
+                "{} {} or {}, {} selected",
+                self.prompt,
+                self.affirmative,
+                self.negative,
+                label(self.value)
+            ),
+        }
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved highlight and answer of a prompt; see
+/// [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The highlighted answer.
+    pub value: bool,
+    /// The submitted answer, if the prompt has been answered.
+    pub answer: Option<bool>,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            value: self.value,
+            answer: self.answer,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.value = state.value;
+        self.answer = state.answer;
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new("Proceed?"), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_toggle_and_submit() {
+        let mut m = Model::new("Proceed?");
+        assert!(!m.value());
+        m.update(key(KeyCode::Left));
+        assert!(m.value());
+        assert!(m.update(key(KeyCode::Enter)).is_some());
+        assert_eq!(m.answer(), Some(true));
+        // Answered prompts ignore further input.
+        assert!(m.update(key(KeyCode::Char('n'))).is_none());
+        assert_eq!(m.view(), "Proceed? Yes");
+    }
+
+    #[test]
+    fn test_shortcuts_answer_immediately() {
+        let mut m = Model::new("Delete?").with_default(true);
+        m.update(key(KeyCode::Char('n')));
+        assert_eq!(m.answer(), Some(false));
+        m.reset();
+        assert!(m.value());
+        m.update(key(KeyCode::Char('Y')));
+        assert_eq!(m.answer(), Some(true));
+    }
+
+    #[test]
+    fn test_escape_uses_default() {
+        let mut m = Model::new("Continue?").with_default(true);
+        m.update(key(KeyCode::Right));
+        assert!(!m.value());
+        m.update(key(KeyCode::Esc));
+        assert_eq!(m.answer(), Some(true));
+    }
+
+    #[test]
+    fn test_inline_hint_reflects_default() {
+        let m = Model::new("Save?").with_inline(true).with_default(true);
+        assert_eq!(m.view(), "Save? [Y/n]");
+    }
+}
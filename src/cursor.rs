@@ -23,7 +23,8 @@
 //! assert!(!view.is_empty());
 //! ```
 
-use bubbletea_rs::{tick, Cmd, Model as BubbleTeaModel, Msg};
+use bubbletea_rs::{tick, BlurMsg, Cmd, FocusMsg, Model as BubbleTeaModel, Msg};
+use crossterm::cursor::SetCursorStyle;
 use lipgloss_extras::prelude::*;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
@@ -56,9 +57,24 @@ pub struct BlinkMsg {
 // --- Mode ---
 
 /// Describes the behavior of the cursor.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Matches Go's `cursor.Mode`. The mode can be changed at runtime with
+/// [`Model::set_mode`]; switching to `Blink` returns a command that restarts
+/// the blink loop.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::cursor::Mode;
+///
+/// assert_eq!(Mode::default(), Mode::Blink);
+/// assert_eq!(Mode::Static.to_string(), "static");
+/// assert_eq!(Mode::Hide.to_string(), "hidden");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Mode {
     /// The cursor blinks.
+    #[default]
     Blink,
     /// The cursor is static.
     Static,
@@ -80,6 +96,57 @@ impl std::fmt::Display for Mode {
     }
 }
 
+// --- Shape ---
+
+/// Describes how the cursor is drawn.
+///
+/// Because the cursor is rendered as styled text rather than with the
+/// terminal's own caret, each shape maps to a styling fallback:
+///
+/// - `Block` reverses the character under the cursor.
+/// - `Underline` underlines the character under the cursor.
+/// - `Bar` draws a thin `▏` when the cursor sits on blank space and falls
+///   back to underlining when it sits on text, so the line never shifts.
+///
+/// Editors commonly use `Bar` for insert mode and `Block` for overwrite mode.
+/// Applications that drive the real terminal cursor can use
+/// [`Model::terminal_cursor_style`] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::cursor::Shape;
+///
+/// assert_eq!(Shape::default(), Shape::Block);
+/// assert_eq!(Shape::Bar.to_string(), "bar");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Shape {
+    /// A full-cell block (reverse video).
+    #[default]
+    Block,
+    /// An underline beneath the character.
+    Underline,
+    /// A thin vertical bar at the left edge of the cell.
+    Bar,
+}
+
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Shape::Block => "block",
+                Shape::Underline => "underline",
+                Shape::Bar => "bar",
+            }
+        )
+    }
+}
+
+const BAR_CHAR: &str = "▏";
+
 // --- Model ---
 
 /// Model is the Bubble Tea model for this cursor element.
@@ -98,6 +165,9 @@ pub struct Model {
     is_off_phase: bool, // When true, cursor is in "off" phase (hidden/showing text style)
     blink_tag: usize,
     mode: Mode,
+    shape: Shape,
+    overwrite_shape: Shape,
+    overwrite: bool,
 }
 
 impl Default for Model {
@@ -113,6 +183,9 @@ impl Default for Model {
             is_off_phase: true, // Start in off phase (showing text style)
             blink_tag: 0,
             mode: Mode::Blink,
+            shape: Shape::Block,
+            overwrite_shape: Shape::Underline,
+            overwrite: false,
         }
     }
 }
@@ -131,7 +204,19 @@ impl Model {
     /// Update is the Bubble Tea update loop. It handles cursor-related messages.
     /// This is not a `bubbletea_rs::Model` implementation because the cursor is
     /// a sub-component managed by another model.
+    ///
+    /// Terminal focus changes (`FocusMsg`/`BlurMsg`) focus and blur the cursor,
+    /// matching Go's behavior when focus reporting is enabled.
     pub fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        if msg.downcast_ref::<FocusMsg>().is_some() {
+            return self.focus();
+        }
+
+        if msg.downcast_ref::<BlurMsg>().is_some() {
+            self.blur();
+            return None;
+        }
+
         if msg.downcast_ref::<InitialBlinkMsg>().is_some() {
             if self.mode != Mode::Blink || !self.focus {
                 return None;
@@ -158,22 +243,201 @@ impl Model {
     }
 
     /// Returns the model's cursor mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::{new, Mode};
+    ///
+    /// let cur = new();
+    /// assert_eq!(cur.mode(), Mode::Blink);
+    /// ```
     pub fn mode(&self) -> Mode {
         self.mode
     }
 
-    /// Sets the model's cursor mode. This method returns a command.
+    /// Sets the model's cursor mode.
+    ///
+    /// Switching to [`Mode::Blink`] while focused returns a command that starts
+    /// the blink loop for this cursor only; the other modes return `None`.
+    /// `Hide` keeps the cursor in its "off" phase regardless of focus, and
+    /// `Static` shows it steadily while focused. An unfocused cursor starts
+    /// blinking when it is next focused.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::{new, Mode};
+    ///
+    /// let mut cur = new();
+    /// let _ = cur.focus();
+    ///
+    /// assert!(cur.set_mode(Mode::Static).is_none());
+    /// assert_eq!(cur.mode(), Mode::Static);
+    ///
+    /// // Enabling blink hands back the command that drives it
+    /// assert!(cur.set_mode(Mode::Blink).is_some());
+    /// ```
     pub fn set_mode(&mut self, mode: Mode) -> Option<Cmd> {
         self.mode = mode;
         self.is_off_phase = self.mode == Mode::Hide || !self.focus;
-        if mode == Mode::Blink {
-            return Some(blink());
+        if mode == Mode::Blink && self.focus {
+            return self.blink_cmd();
+        }
+        None
+    }
+
+    /// Returns the shape used to draw the cursor.
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Sets the shape used to draw the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::{new, Shape};
+    ///
+    /// let mut cur = new();
+    /// cur.set_shape(Shape::Underline);
+    /// assert_eq!(cur.shape(), Shape::Underline);
+    /// ```
+    pub fn set_shape(&mut self, shape: Shape) {
+        self.shape = shape;
+    }
+
+    /// Returns whether the cursor is indicating overwrite mode.
+    pub fn overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Switches the cursor between its insert and overwrite indicators.
+    ///
+    /// Editing components call this when their overwrite mode is toggled. In
+    /// overwrite mode the cursor is drawn with the overwrite shape
+    /// (`Shape::Underline` by default) instead of its regular shape.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::{new, Shape};
+    ///
+    /// let mut cur = new();
+    /// assert_eq!(cur.current_shape(), Shape::Block);
+    ///
+    /// cur.set_overwrite(true);
+    /// assert_eq!(cur.current_shape(), Shape::Underline);
+    /// ```
+    pub fn set_overwrite(&mut self, overwrite: bool) {
+        self.overwrite = overwrite;
+    }
+
+    /// Returns the shape drawn while in overwrite mode.
+    pub fn overwrite_shape(&self) -> Shape {
+        self.overwrite_shape
+    }
+
+    /// Sets the shape drawn while in overwrite mode.
+    pub fn set_overwrite_shape(&mut self, shape: Shape) {
+        self.overwrite_shape = shape;
+    }
+
+    /// Returns the shape currently used for rendering, taking overwrite mode
+    /// into account.
+    pub fn current_shape(&self) -> Shape {
+        if self.overwrite {
+            self.overwrite_shape
+        } else {
+            self.shape
+        }
+    }
+
+    /// Returns the crossterm command that sets the real terminal cursor to
+    /// match this cursor's shape and blink mode.
+    ///
+    /// This is for applications that position and show the terminal's own
+    /// caret instead of relying on the styled rendering from [`Model::view`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::{new, Mode, Shape};
+    /// use crossterm::cursor::SetCursorStyle;
+    ///
+    /// let mut cur = new();
+    /// cur.set_shape(Shape::Bar);
+    /// let _ = cur.set_mode(Mode::Static);
+    /// assert!(matches!(cur.terminal_cursor_style(), SetCursorStyle::SteadyBar));
+    /// ```
+    pub fn terminal_cursor_style(&self) -> SetCursorStyle {
+        let blinking = self.mode == Mode::Blink;
+        match (self.current_shape(), blinking) {
+            (Shape::Block, true) => SetCursorStyle::BlinkingBlock,
+            (Shape::Block, false) => SetCursorStyle::SteadyBlock,
+            (Shape::Underline, true) => SetCursorStyle::BlinkingUnderScore,
+            (Shape::Underline, false) => SetCursorStyle::SteadyUnderScore,
+            (Shape::Bar, true) => SetCursorStyle::BlinkingBar,
+            (Shape::Bar, false) => SetCursorStyle::SteadyBar,
+        }
+    }
+
+    /// Returns the unique id of this cursor.
+    ///
+    /// Blink messages carry the id of the cursor that scheduled them, so
+    /// several cursors can share one update loop without consuming each
+    /// other's ticks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::new;
+    ///
+    /// let a = new();
+    /// let b = new();
+    /// assert_ne!(a.id(), b.id());
+    /// ```
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the interval between blink phases.
+    pub fn blink_speed(&self) -> Duration {
+        self.blink_speed
+    }
+
+    /// Sets the interval between blink phases.
+    ///
+    /// If the cursor is currently blinking, the pending tick is invalidated and
+    /// a command scheduling the next blink at the new speed is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::cursor::new;
+    /// use std::time::Duration;
+    ///
+    /// let mut cur = new();
+    /// assert!(cur.set_blink_speed(Duration::from_millis(250)).is_none()); // Not focused yet
+    /// assert_eq!(cur.blink_speed(), Duration::from_millis(250));
+    ///
+    /// let _ = cur.focus();
+    /// assert!(cur.set_blink_speed(Duration::from_millis(800)).is_some());
+    /// ```
+    pub fn set_blink_speed(&mut self, speed: Duration) -> Option<Cmd> {
+        self.blink_speed = speed;
+        if self.mode == Mode::Blink && self.focus {
+            return self.blink_cmd();
         }
         None
     }
 
     /// Creates a command to schedule the next blink.
-    fn blink_cmd(&mut self) -> Option<Cmd> {
+    ///
+    /// Each call bumps the internal blink tag so that any blink already in
+    /// flight is ignored when it arrives. Returns `None` unless the cursor is
+    /// in [`Mode::Blink`]. This is Go's `BlinkCmd`.
+    pub fn blink_cmd(&mut self) -> Option<Cmd> {
         if self.mode != Mode::Blink {
             return None;
         }
@@ -213,17 +477,22 @@ impl Model {
     }
 
     /// Renders the cursor.
+    ///
+    /// The "on" phase is drawn according to the cursor's [`Shape`] (or its
+    /// overwrite shape while in overwrite mode).
     pub fn view(&self) -> String {
         if self.mode == Mode::Hide || self.is_off_phase {
             // When in off phase, we show the text style (cursor is hidden)
             return self.text_style.clone().inline(true).render(&self.char);
         }
-        // When in on phase, we show the cursor style (reversed)
-        self.style
-            .clone()
-            .inline(true)
-            .reverse(true)
-            .render(&self.char)
+        let style = self.style.clone().inline(true);
+        match self.current_shape() {
+            // When in on phase, we show the cursor style (reversed)
+            Shape::Block => style.reverse(true).render(&self.char),
+            Shape::Underline => style.underline(true).render(&self.char),
+            Shape::Bar if self.char.trim().is_empty() => style.render(BAR_CHAR),
+            Shape::Bar => style.underline(true).render(&self.char),
+        }
     }
 }
 
@@ -244,6 +513,11 @@ impl BubbleTeaModel for Model {
 }
 
 /// A command to initialize cursor blinking.
+///
+/// The resulting [`InitialBlinkMsg`] is not addressed to a particular cursor:
+/// every focused, blinking cursor that receives it (re)starts its blink loop.
+/// Prefer [`Model::focus`] or [`Model::set_mode`], which return commands
+/// targeted at a single cursor.
 pub fn blink() -> Cmd {
     tick(Duration::from_millis(0), |_| {
         Box::new(InitialBlinkMsg) as Msg
@@ -291,4 +565,125 @@ mod tests {
         // but we've verified the key property: that the tag is captured at creation time
         // The actual message would have id=expected_id and tag=expected_tag when executed
     }
+
+    #[test]
+    fn test_set_mode_returns_blink_cmd_only_for_blink() {
+        let mut m = Model::new();
+        let _ = m.focus();
+        assert!(m.set_mode(Mode::Static).is_none());
+        assert!(m.set_mode(Mode::Hide).is_none());
+        assert!(m.set_mode(Mode::Blink).is_some());
+        assert_eq!(m.mode(), Mode::Blink);
+
+        // Unfocused cursors start blinking on their next focus instead
+        m.blur();
+        assert!(m.set_mode(Mode::Blink).is_none());
+        assert!(m.focus().is_some());
+    }
+
+    #[test]
+    fn test_hide_mode_renders_text_style_even_when_focused() {
+        let mut m = Model::new();
+        m.set_char("x");
+        let _ = m.set_mode(Mode::Hide);
+        let _ = m.focus();
+        assert!(m.is_off_phase);
+        assert_eq!(m.view(), m.text_style.clone().inline(true).render("x"));
+    }
+
+    #[test]
+    fn test_static_mode_shows_cursor_when_focused() {
+        let mut m = Model::new();
+        let _ = m.set_mode(Mode::Static);
+        assert!(m.focus().is_none());
+        assert!(!m.is_off_phase);
+    }
+
+    #[test]
+    fn test_focus_and_blur_messages() {
+        let mut m = Model::new();
+        m.update(&(Box::new(FocusMsg) as Msg));
+        assert!(m.focused());
+        m.update(&(Box::new(BlurMsg) as Msg));
+        assert!(!m.focused());
+        assert!(m.is_off_phase);
+    }
+
+    #[test]
+    fn test_blink_msgs_are_filtered_by_id() {
+        let mut a = Model::new();
+        let mut b = Model::new();
+        let _ = a.focus();
+        let _ = b.focus();
+
+        let a_phase = a.is_off_phase;
+        let b_phase = b.is_off_phase;
+
+        // A tick scheduled by `a` must not toggle or reschedule `b`
+        let for_a: Msg = Box::new(BlinkMsg {
+            id: a.id(),
+            tag: a.blink_tag,
+        });
+        assert!(b.update(&for_a).is_none());
+        assert_eq!(b.is_off_phase, b_phase);
+
+        assert!(a.update(&for_a).is_some());
+        assert_ne!(a.is_off_phase, a_phase);
+    }
+
+    #[test]
+    fn test_set_blink_speed_invalidates_pending_tick() {
+        let mut m = Model::new();
+        let _ = m.focus();
+        let stale_tag = m.blink_tag;
+
+        let _ = m.set_blink_speed(Duration::from_millis(100));
+        assert_eq!(m.blink_speed(), Duration::from_millis(100));
+        assert_ne!(m.blink_tag, stale_tag);
+
+        let stale: Msg = Box::new(BlinkMsg {
+            id: m.id(),
+            tag: stale_tag,
+        });
+        assert!(m.update(&stale).is_none());
+    }
+
+    #[test]
+    fn test_shapes_render_without_changing_width() {
+        let mut m = Model::new();
+        let _ = m.set_mode(Mode::Static);
+        let _ = m.focus();
+
+        for shape in [Shape::Block, Shape::Underline, Shape::Bar] {
+            m.set_shape(shape);
+            for ch in [" ", "x"] {
+                m.set_char(ch);
+                assert_eq!(lipgloss::width(&m.view()), 1, "{shape} on {ch:?}");
+            }
+        }
+
+        m.set_shape(Shape::Bar);
+        m.set_char(" ");
+        assert!(lipgloss::strip_ansi(&m.view()).contains(BAR_CHAR));
+        m.set_char("x");
+        assert_eq!(lipgloss::strip_ansi(&m.view()), "x");
+    }
+
+    #[test]
+    fn test_overwrite_switches_rendered_shape() {
+        let mut m = Model::new();
+        let _ = m.set_mode(Mode::Static);
+        let _ = m.focus();
+        m.set_char("x");
+
+        let insert_view = m.view();
+        m.set_overwrite(true);
+        assert!(m.overwrite());
+        assert_eq!(m.current_shape(), Shape::Underline);
+        let overwrite_view = m.view();
+        assert_eq!(lipgloss::strip_ansi(&overwrite_view), "x");
+
+        m.set_overwrite_shape(Shape::Block);
+        assert_eq!(m.view(), insert_view);
+    }
 }
@@ -0,0 +1,600 @@
+//! Modal dialog boxes: alerts, confirmations and text prompts.
+//!
+//! A dialog captures focus while it is open and resolves to a typed
+//! [`DialogResultMsg`] once the user makes a choice. The [`overlay`] helper
+//! composites the rendered dialog over the application's normal view, dimming
+//! the content underneath so it reads as disabled.
+//!
+//! # Dialog Kinds
+//!
+//! | Constructor | Buttons | Result |
+//! |-------------|---------|--------|
+//! | [`Model::alert`] | OK | `DialogResult::Acknowledged` |
+//! | [`Model::confirm`] | Yes / No | `DialogResult::Confirmed(bool)` |
+//! | [`Model::prompt`] | OK / Cancel + text input | `DialogResult::Submitted(String)` |
+//!
+//! `Esc` closes any dialog with `DialogResult::Cancelled`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::dialog::{self, DialogResult, DialogResultMsg};
+//! use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+//!
+//! struct App {
+//!     dialog: dialog::Model,
+//!     status: String,
+//! }
+//!
+//! impl BubbleTeaModel for App {
+//!     fn init() -> (Self, Option<Cmd>) {
+//!         let mut dialog = dialog::Model::confirm("Quit", "Discard unsaved changes?");
+//!         let cmd = dialog.open();
+//!         (App { dialog, status: String::new() }, cmd)
+//!     }
+//!
+//!     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+//!         if let Some(res) = msg.downcast_ref::<DialogResultMsg>() {
+//!             if res.result == DialogResult::Confirmed(true) {
+//!                 return Some(bubbletea_rs::quit());
+//!             }
+//!             return None;
+//!         }
+//!         if self.dialog.is_open() {
+//!             // The dialog captures all input while open.
+//!             return self.dialog.update(msg);
+//!         }
+//!         None
+//!     }
+//!
+//!     fn view(&self) -> String {
+//!         let body = format!("Editor\n{}", self.status);
+//!         if self.dialog.is_open() {
+//!             dialog::overlay(&body, &self.dialog.view(), 60, 20)
+//!         } else {
+//!             body
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::compose;
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, Align};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use crossterm::event::KeyCode;
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// The kind of dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A message with a single acknowledgement button.
+    Alert,
+    /// A yes/no question.
+    Confirm,
+    /// A question answered with free text.
+    Prompt,
+}
+
+/// The outcome of a dialog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogResult {
+    /// An alert was acknowledged.
+    Acknowledged,
+    /// A confirmation was answered; `true` for the affirmative button.
+    Confirmed(bool),
+    /// A prompt was submitted with the given text.
+    Submitted(String),
+    /// The dialog was dismissed with `Esc` or a prompt's cancel button.
+    Cancelled,
+}
+
+/// Message emitted when a dialog resolves.
+#[derive(Debug, Clone)]
+pub struct DialogResultMsg {
+    /// The ID of the dialog that resolved.
+    pub id: i64,
+    /// The user's choice.
+    pub result: DialogResult,
+}
+
+/// Key bindings for dialogs.
+#[derive(Debug, Clone)]
+pub struct DialogKeyMap {
+    /// Focuses the next button. Default: `tab`, `→`.
+    pub next: key::Binding,
+    /// Focuses the previous button. Default: `shift+tab`, `←`.
+    pub prev: key::Binding,
+    /// Activates the focused button. Default: `enter`.
+    pub confirm: key::Binding,
+    /// Dismisses the dialog. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for DialogKeyMap {
+    fn default() -> Self {
+        Self {
+            next: key::new_binding(vec![
+                key::with_keys_str(&["tab", "right"]),
+                key::with_help("tab", "next button"),
+            ]),
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["shift+tab", "left"]),
+                key::with_help("shift+tab", "previous button"),
+            ]),
+            confirm: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "confirm"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "cancel"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for DialogKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.next, &self.confirm, &self.cancel]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.next, &self.prev],
+            vec![&self.confirm, &self.cancel],
+        ]
+    }
+}
+
+/// Visual styles for dialogs.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the surrounding box.
+    pub frame: Style,
+    /// Style of the title line.
+    pub title: Style,
+    /// Style of the message text.
+    pub message: Style,
+    /// Style of unfocused buttons.
+    pub button: Style,
+    /// Style of the focused button.
+    pub active_button: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            frame: Style::new()
+                .border(rounded_border())
+                .border_foreground(Color::from("#874BFD"))
+                .padding(1, 2, 1, 2),
+            title: Style::new().bold(true),
+            message: Style::new(),
+            button: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#888B7E"))
+                .padding(0, 2, 0, 2),
+            active_button: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#F25D94"))
+                .padding(0, 2, 0, 2)
+                .underline(true),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates dialog styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let button = Style::new()
+            .foreground(p.background.clone())
+            .padding(0, 2, 0, 2);
+        Self {
+            frame: theme.frame.clone().padding(1, 2, 1, 2),
+            title: theme.title.clone(),
+            message: theme.text.clone(),
+            button: button.clone().background(p.muted.clone()),
+            active_button: button.background(p.primary.clone()).underline(true),
+        }
+    }
+}
+
+/// A modal dialog.
+pub struct Model {
+    id: i64,
+    kind: Kind,
+    open: bool,
+    button: usize,
+    buttons: Vec<String>,
+    /// Title shown at the top of the dialog.
+    pub title: String,
+    /// Body text.
+    pub message: String,
+    /// Width of the dialog content, excluding frame padding and border.
+    pub width: usize,
+    /// Text input used by prompt dialogs.
+    pub input: textinput::Model,
+    /// Key bindings.
+    pub keymap: DialogKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+impl Model {
+    fn with_kind(kind: Kind, title: &str, message: &str) -> Self {
+        let buttons = match kind {
+            Kind::Alert => vec!["OK".to_string()],
+            Kind::Confirm => vec!["Yes".to_string(), "No".to_string()],
+            Kind::Prompt => vec!["OK".to_string(), "Cancel".to_string()],
+        };
+        let mut input = textinput::new();
+        input.set_width(36);
+        Self {
+            id: next_id(),
+            kind,
+            open: false,
+            button: 0,
+            buttons,
+            title: title.to_string(),
+            message: message.to_string(),
+            width: 40,
+            input,
+            keymap: DialogKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Creates an alert with a single OK button.
+    pub fn alert(title: &str, message: &str) -> Self {
+        Self::with_kind(Kind::Alert, title, message)
+    }
+
+    /// Creates a yes/no confirmation.
+    pub fn confirm(title: &str, message: &str) -> Self {
+        Self::with_kind(Kind::Confirm, title, message)
+    }
+
+    /// Creates a prompt with an embedded text input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::dialog::Model;
+    ///
+    /// let mut dialog = Model::prompt("Rename", "New file name:").with_placeholder("name.txt");
+    /// dialog.open();
+    /// assert!(dialog.is_open());
+    /// assert!(dialog.view().contains("Rename"));
+    /// ```
+    pub fn prompt(title: &str, message: &str) -> Self {
+        Self::with_kind(Kind::Prompt, title, message)
+    }
+
+    /// Replaces the button labels.
+    ///
+    /// For confirm dialogs the first label is the affirmative answer. For
+    /// prompts the first label submits and the second cancels. Alerts use
+    /// only the first label.
+    pub fn with_buttons(mut self, labels: &[&str]) -> Self {
+        for (slot, label) in self.buttons.iter_mut().zip(labels) {
+            *slot = label.to_string();
+        }
+        self
+    }
+
+    /// Sets the placeholder of a prompt's text input.
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.input.set_placeholder(placeholder);
+        self
+    }
+
+    /// Sets the content width.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self.input.set_width(width.saturating_sub(4) as i32);
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Selects the initially focused button.
+    pub fn with_default_button(mut self, index: usize) -> Self {
+        self.button = index.min(self.buttons.len() - 1);
+        self
+    }
+
+    /// Returns the unique ID of this dialog.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the kind of dialog.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the index of the focused button.
+    pub fn focused_button(&self) -> usize {
+        self.button
+    }
+
+    /// Returns whether the dialog is currently shown.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Shows the dialog and captures focus.
+    ///
+    /// For prompts this focuses the text input and returns its cursor blink
+    /// command.
+    pub fn open(&mut self) -> Option<Cmd> {
+        self.open = true;
+        if self.kind == Kind::Prompt {
+            self.input.reset();
+            return Some(self.input.focus());
+        }
+        None
+    }
+
+    /// Hides the dialog without emitting a result.
+    pub fn close(&mut self) {
+        self.open = false;
+        self.input.blur();
+    }
+
+    /// Closes the dialog and returns a command emitting its result.
+    pub fn resolve(&mut self, result: DialogResult) -> Cmd {
+        self.close();
+        let id = self.id;
+        bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(DialogResultMsg {
+                id,
+                result: result.clone(),
+            }) as Msg
+        })
+    }
+
+    fn activate(&mut self) -> Cmd {
+        let result = match (self.kind, self.button) {
+            (Kind::Alert, _) => DialogResult::Acknowledged,
+            (Kind::Confirm, b) => DialogResult::Confirmed(b == 0),
+            (Kind::Prompt, 0) => DialogResult::Submitted(self.input.value()),
+            (Kind::Prompt, _) => DialogResult::Cancelled,
+        };
+        self.resolve(result)
+    }
+
+    /// Handles input while the dialog is open.
+    ///
+    /// Messages are ignored while the dialog is closed. In prompt dialogs,
+    /// `←`/`→` edit the text rather than switching buttons.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.open {
+            return None;
+        }
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            let editing_keys = matches!(key_msg.key, KeyCode::Left | KeyCode::Right);
+            if self.keymap.cancel.matches(key_msg) {
+                return Some(self.resolve(DialogResult::Cancelled));
+            }
+            if self.keymap.confirm.matches(key_msg) {
+                return Some(self.activate());
+            }
+            if !(self.kind == Kind::Prompt && editing_keys) {
+                let n = self.buttons.len();
+                if self.keymap.next.matches(key_msg) {
+                    self.button = (self.button + 1) % n;
+                    return None;
+                }
+                if self.keymap.prev.matches(key_msg) {
+                    self.button = (self.button + n - 1) % n;
+                    return None;
+                }
+            }
+        }
+        if self.kind == Kind::Prompt {
+            return self.input.update(msg);
+        }
+        None
+    }
+
+    /// Renders the dialog box. Closed dialogs render as an empty string.
+    pub fn view(&self) -> String {
+        if !self.open {
+            return String::new();
+        }
+        let width = self.width as i32;
+        let mut parts = Vec::new();
+        if !self.title.is_empty() {
+            parts.push(self.styles.title.clone().width(width).render(&self.title));
+        }
+        if !self.message.is_empty() {
+            parts.push(
+                self.styles
+                    .message
+                    .clone()
+                    .width(width)
+                    .render(&self.message),
+            );
+        }
+        if self.kind == Kind::Prompt {
+            parts.push(self.input.view());
+        }
+        let buttons: Vec<String> = self
+            .buttons
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                if i == self.button {
+                    self.styles.active_button.render(label)
+                } else {
+                    self.styles.button.render(label)
+                }
+            })
+            .collect();
+        let button_refs: Vec<&str> = buttons
+            .iter()
+            .flat_map(|b| [b.as_str(), "  "])
+            .take(buttons.len() * 2 - 1)
+            .collect();
+        let row = lipgloss::join_horizontal(lipgloss::TOP, &button_refs);
+        parts.push(lipgloss::place_horizontal(
+            width,
+            lipgloss::CENTER,
+            &row,
+            &[],
+        ));
+        self.styles.frame.render(&parts.join("\n\n"))
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.open()
+    }
+
+    fn blur(&mut self) {
+        self.close();
+    }
+
+    fn focused(&self) -> bool {
+        self.open
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        let mut m = Self::alert("", "");
+        let cmd = m.open();
+        (m, cmd)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+/// Composites `foreground` centered over a dimmed `background`.
+///
+/// The background is stripped of its own styling, padded or truncated to
+/// `width` x `height` and rendered faint, so the underlying view reads as
+/// disabled while a dialog is shown. Use [`compose::overlay`] instead to
+/// keep the background as it is.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::dialog::overlay;
+///
+/// let out = overlay("line one\nline two\nline three", "[box]", 20, 3);
+/// assert_eq!(out.lines().count(), 3);
+/// assert!(out.contains("[box]"));
+/// ```
+pub fn overlay(background: &str, foreground: &str, width: usize, height: usize) -> String {
+    let dim = Style::new().faint(true);
+    let bg_lines: Vec<String> = background.lines().map(lipgloss::strip_ansi).collect();
+    let dimmed = (0..height)
+        .map(|row| {
+            let bg = bg_lines.get(row).map(String::as_str).unwrap_or("");
+            dim.render(&slice_columns(bg, 0, width))
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let (fg_width, fg_height) = compose::size(foreground);
+    let x = width.saturating_sub(fg_width) / 2;
+    let y = height.saturating_sub(fg_height) / 2;
+    compose::overlay(&dimmed, foreground, x, y)
+}
+
+/// Returns the display columns `[start, end)` of an unstyled string, padding
+/// with spaces and replacing wide characters cut by either edge.
+fn slice_columns(s: &str, start: usize, end: usize) -> String {
+    let cut = text::slice(s, start, end);
+    text::pad(&cut, end.saturating_sub(start), Align::Left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_closed_dialog_ignores_input() {
+        let mut d = Model::alert("Hi", "There");
+        assert!(d.update(key(KeyCode::Enter)).is_none());
+        assert!(d.view().is_empty());
+    }
+
+    #[test]
+    fn test_confirm_button_navigation() {
+        let mut d = Model::confirm("Delete", "Really?");
+        d.open();
+        assert_eq!(d.focused_button(), 0);
+        d.update(key(KeyCode::Tab));
+        assert_eq!(d.focused_button(), 1);
+        d.update(key(KeyCode::Left));
+        assert_eq!(d.focused_button(), 0);
+        assert!(d.update(key(KeyCode::Enter)).is_some());
+        assert!(!d.is_open());
+    }
+
+    #[test]
+    fn test_prompt_forwards_typing_to_input() {
+        let mut d = Model::prompt("Name", "Who?");
+        d.open();
+        d.update(key(KeyCode::Char('a')));
+        d.update(key(KeyCode::Char('b')));
+        d.update(key(KeyCode::Left));
+        assert_eq!(d.input.value(), "ab");
+        assert_eq!(d.focused_button(), 0, "arrows edit text in prompts");
+    }
+
+    #[test]
+    fn test_slice_columns_pads_and_splits_wide_chars() {
+        assert_eq!(slice_columns("abc", 0, 5), "abc  ");
+        assert_eq!(slice_columns("abcdef", 2, 4), "cd");
+        assert_eq!(slice_columns("a日b", 2, 4), " b");
+    }
+
+    #[test]
+    fn test_overlay_centers_foreground() {
+        let out = overlay("", "XX", 6, 3);
+        let lines: Vec<String> = out.lines().map(lipgloss::strip_ansi).collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1], "  XX  ");
+    }
+}
@@ -0,0 +1,106 @@
+//! The error type shared by the widgets.
+//!
+//! Every fallible method in this crate returns [`Error`], and widgets that
+//! keep their last error in a field (`textinput::Model::err`,
+//! `textarea::Model::err`, `filepicker::Model::error`) keep an [`Error`]
+//! too. Applications can match on the kind to decide what to do, or print
+//! it, since its `Display` form is meant for users.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::textinput;
+//! use bubbletea_widgets::Error;
+//!
+//! let mut input = textinput::new();
+//! input.set_validate(Box::new(|s: &str| {
+//!     if s.contains('@') {
+//!         Ok(())
+//!     } else {
+//!         Err("missing @".to_string())
+//!     }
+//! }));
+//! input.set_value("nobody");
+//!
+//! match &input.err {
+//!     Some(Error::Validation(message)) => assert_eq!(message, "missing @"),
+//!     other => panic!("unexpected {:?}", other),
+//! }
+//! ```
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An error raised by a widget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing a clipboard failed.
+    Clipboard(String),
+    /// A file system operation failed.
+    Filesystem {
+        /// The file or directory involved.
+        path: PathBuf,
+        /// What went wrong, as reported by the operating system.
+        kind: io::ErrorKind,
+        /// The operating system's description of the failure.
+        message: String,
+    },
+    /// Input was rejected: a validator refused it or it could not be
+    /// parsed.
+    Validation(String),
+    /// Content could not be rendered, such as image data that does not
+    /// match its stated size.
+    Render(String),
+}
+
+/// Shorthand for results whose error is [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// Creates a [`Filesystem`](Error::Filesystem) error for `path` from an
+    /// I/O error.
+    pub fn filesystem(path: impl AsRef<Path>, error: &io::Error) -> Self {
+        Self::Filesystem {
+            path: path.as_ref().to_path_buf(),
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Clipboard(message) | Self::Validation(message) | Self::Render(message) => {
+                f.write_str(message)
+            }
+            Self::Filesystem { path, message, .. } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filesystem_error_keeps_kind_and_path() {
+        let io_error = io::Error::new(io::ErrorKind::NotFound, "no such directory");
+        let error = Error::filesystem("/missing", &io_error);
+        assert!(matches!(
+            error,
+            Error::Filesystem {
+                kind: io::ErrorKind::NotFound,
+                ..
+            }
+        ));
+        assert_eq!(error.to_string(), "/missing: no such directory");
+        assert_eq!(Error::Validation("too long".into()).to_string(), "too long");
+    }
+}
@@ -65,7 +65,10 @@
 //! - `PageUp`/`b`: Page up
 //! - `PageDown`/`f`: Page down
 
+use crate::error::Error;
 use crate::key::{self, KeyMap};
+use crate::theme::Theme;
+use crate::widget::Margins;
 use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
 use lipgloss_extras::prelude::*;
 use std::path::{Path, PathBuf};
@@ -89,21 +92,29 @@ fn next_id() -> i64 {
 /// Message type for handling errors during file system operations.
 ///
 /// This message is sent when file system operations like reading directories fail.
-/// It contains the error description that can be displayed to the user.
+/// The picker that requested the read shows the error in place of the listing.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use bubbletea_widgets::filepicker::ErrorMsg;
+/// use bubbletea_widgets::Error;
+/// use std::io;
 ///
 /// let error_msg = ErrorMsg {
-///     err: "Permission denied".to_string(),
+///     id: 1,
+///     err: Error::filesystem(
+///         "/root",
+///         &io::Error::new(io::ErrorKind::PermissionDenied, "Permission denied"),
+///     ),
 /// };
 /// ```
 #[derive(Debug, Clone)]
 pub struct ErrorMsg {
-    /// The error message string.
-    pub err: String,
+    /// The ID of the filepicker instance that requested the operation.
+    pub id: i64,
+    /// What went wrong.
+    pub err: Error,
 }
 
 /// Message type for handling successful directory reads.
@@ -354,6 +365,27 @@ impl Default for Styles {
     }
 }
 
+impl Styles {
+    /// Creates file picker styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let defaults = Self::default();
+        Self {
+            disabled_cursor: Style::new().foreground(p.subtle.clone()),
+            cursor: Style::new().foreground(p.primary.clone()),
+            symlink: Style::new().foreground(p.accent.clone()),
+            directory: Style::new().foreground(p.secondary.clone()),
+            file: theme.text.clone(),
+            disabled_file: Style::new().foreground(p.subtle.clone()),
+            permission: Style::new().foreground(p.muted.clone()),
+            selected: theme.selected.clone(),
+            disabled_selected: Style::new().foreground(p.subtle.clone()),
+            file_size: defaults.file_size.foreground(p.muted.clone()),
+            empty_directory: defaults.empty_directory.foreground(p.muted.clone()),
+        }
+    }
+}
+
 /// Represents a single file or directory entry in the file picker.
 ///
 /// This struct contains all the information needed to display and interact with
@@ -469,12 +501,17 @@ pub struct Model {
     pub height: usize,
     /// Whether height should automatically adjust to terminal size.
     pub auto_height: bool,
+    /// Margins kept when following the terminal size; replaces the fixed
+    /// bottom margin used by `auto_height` when set.
+    resize_margins: Option<Margins>,
+    #[cfg(feature = "mouse")]
+    mouse: crate::mouse::Tracker,
 
     /// The cursor string to display (e.g., "> ").
     pub cursor: String,
 
-    /// Error message to display when directory operations fail.
-    pub error: Option<String>,
+    /// Error to display when directory operations fail.
+    pub error: Option<Error>,
 
     /// Visual styling configuration for different UI elements.
     /// Can be customized to change colors and appearance.
@@ -503,6 +540,166 @@ pub fn new() -> Model {
     Model::new()
 }
 
+/// Builds a [`Model`] from a chain of settings. Start one with
+/// [`Model::builder`]; anything left unset keeps the default of
+/// [`Model::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::filepicker::Model;
+///
+/// let picker = Model::builder()
+///     .current_directory("/tmp")
+///     .allowed_types(&[".txt", ".md"])
+///     .show_hidden(true)
+///     .height(10)
+///     .build();
+/// assert_eq!(picker.current_directory.as_os_str(), "/tmp");
+/// assert_eq!(picker.height, 10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    current_directory: Option<PathBuf>,
+    allowed_types: Option<Vec<String>>,
+    show_hidden: Option<bool>,
+    show_permissions: Option<bool>,
+    show_size: Option<bool>,
+    dir_allowed: Option<bool>,
+    file_allowed: Option<bool>,
+    height: Option<usize>,
+    auto_height: Option<bool>,
+    cursor: Option<String>,
+    styles: Option<Styles>,
+    keymap: Option<FilepickerKeyMap>,
+    resize_margins: Option<Margins>,
+}
+
+impl Builder {
+    /// Sets the directory the picker starts in.
+    pub fn current_directory(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_directory = Some(dir.into());
+        self
+    }
+
+    /// Limits selection to files with these extensions, such as `".txt"`.
+    pub fn allowed_types(mut self, types: &[&str]) -> Self {
+        self.allowed_types = Some(types.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Shows or hides hidden files.
+    pub fn show_hidden(mut self, show: bool) -> Self {
+        self.show_hidden = Some(show);
+        self
+    }
+
+    /// Shows or hides the permissions column.
+    pub fn show_permissions(mut self, show: bool) -> Self {
+        self.show_permissions = Some(show);
+        self
+    }
+
+    /// Shows or hides the size column.
+    pub fn show_size(mut self, show: bool) -> Self {
+        self.show_size = Some(show);
+        self
+    }
+
+    /// Sets whether directories can be selected.
+    pub fn dir_allowed(mut self, allowed: bool) -> Self {
+        self.dir_allowed = Some(allowed);
+        self
+    }
+
+    /// Sets whether files can be selected.
+    pub fn file_allowed(mut self, allowed: bool) -> Self {
+        self.file_allowed = Some(allowed);
+        self
+    }
+
+    /// Sets how many entries are visible at once.
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets whether the height follows the terminal size.
+    pub fn auto_height(mut self, auto: bool) -> Self {
+        self.auto_height = Some(auto);
+        self
+    }
+
+    /// Sets the marker drawn next to the selected entry.
+    pub fn cursor(mut self, cursor: &str) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    /// Sets the styles.
+    pub fn styles(mut self, styles: Styles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    /// Sets the key bindings.
+    pub fn key_map(mut self, keymap: FilepickerKeyMap) -> Self {
+        self.keymap = Some(keymap);
+        self
+    }
+
+    /// Makes the height follow the terminal size minus `margins`; see
+    /// [`Model::auto_resize`].
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Creates the picker. The directory is read when the program runs its
+    /// `init` command, as with [`Model::new`].
+    pub fn build(self) -> Model {
+        let mut picker = Model::new();
+        if let Some(dir) = self.current_directory {
+            picker.current_directory = dir;
+        }
+        if let Some(types) = self.allowed_types {
+            picker.allowed_types = types;
+        }
+        if let Some(show) = self.show_hidden {
+            picker.show_hidden = show;
+        }
+        if let Some(show) = self.show_permissions {
+            picker.show_permissions = show;
+        }
+        if let Some(show) = self.show_size {
+            picker.show_size = show;
+        }
+        if let Some(allowed) = self.dir_allowed {
+            picker.dir_allowed = allowed;
+        }
+        if let Some(allowed) = self.file_allowed {
+            picker.file_allowed = allowed;
+        }
+        if let Some(height) = self.height {
+            picker.set_height(height);
+        }
+        if let Some(auto) = self.auto_height {
+            picker.auto_height = auto;
+        }
+        if let Some(cursor) = self.cursor {
+            picker.cursor = cursor;
+        }
+        if let Some(styles) = self.styles {
+            picker.styles = styles;
+        }
+        if let Some(keymap) = self.keymap {
+            picker.keymap = keymap;
+        }
+        picker.resize_margins = self.resize_margins;
+        picker
+    }
+}
+
 impl Model {
     /// Creates a new file picker model with default settings.
     ///
@@ -545,12 +742,20 @@ impl Model {
             min_stack: Stack::new(),
             height: 0,
             auto_height: true,
+            resize_margins: None,
+            #[cfg(feature = "mouse")]
+            mouse: crate::mouse::Tracker::new(),
             cursor: ">".to_string(),
             error: None,
             styles: Styles::default(),
         }
     }
 
+    /// Starts a [`Builder`] for a file picker.
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Sets the height of the filepicker viewport.
     ///
     /// This controls how many file entries are visible at once. The viewport
@@ -575,6 +780,85 @@ impl Model {
         }
     }
 
+    /// Makes the picker height follow the terminal size minus `margins`.
+    ///
+    /// Without this, `auto_height` keeps a fixed five-line margin below the
+    /// list. Only the top and bottom margins matter since the list does not
+    /// wrap or truncate entries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::filepicker::Model;
+    /// use bubbletea_widgets::widget::Margins;
+    /// use bubbletea_rs::{Model as BubbleTeaModel, WindowSizeMsg};
+    ///
+    /// let mut picker = Model::new().auto_resize(Margins::new(2, 0, 1, 0));
+    /// picker.update(Box::new(WindowSizeMsg { width: 80, height: 20 }));
+    /// assert_eq!(picker.height, 17);
+    /// ```
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Sets the screen cell where the picker is drawn, so clicks can select
+    /// entries. Once set, the wheel only moves the selection while the
+    /// pointer is over the picker.
+    #[cfg(feature = "mouse")]
+    pub fn with_mouse_origin(mut self, x: u16, y: u16) -> Self {
+        self.set_mouse_origin(x, y);
+        self
+    }
+
+    /// Sets the screen cell where the picker is drawn (mutable version).
+    #[cfg(feature = "mouse")]
+    pub fn set_mouse_origin(&mut self, x: u16, y: u16) {
+        self.mouse.set_origin(x, y);
+    }
+
+    fn select_next(&mut self) {
+        if self.selected < self.files.len().saturating_sub(1) {
+            self.selected += 1;
+        }
+        if self.selected > self.max {
+            self.min += 1;
+            self.max += 1;
+        }
+    }
+
+    fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+        if self.selected < self.min {
+            self.min = self.min.saturating_sub(1);
+            self.max = self.max.saturating_sub(1);
+        }
+    }
+
+    #[cfg(feature = "mouse")]
+    fn handle_mouse(&mut self, msg: &Msg) {
+        use crate::mouse::Gesture;
+        // Entries are not truncated, so any column of a row counts
+        match self.mouse.handle(msg, usize::MAX, self.height) {
+            Some(Gesture::Wheel { dy, .. }) => {
+                for _ in 0..dy.unsigned_abs() {
+                    if dy > 0 {
+                        self.select_next();
+                    } else {
+                        self.select_prev();
+                    }
+                }
+            }
+            Some(Gesture::Press { row, .. }) | Some(Gesture::Drag { row, .. }) => {
+                let index = self.min + row;
+                if index <= self.max && index < self.files.len() {
+                    self.selected = index;
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn push_view(&mut self, selected: usize, minimum: usize, maximum: usize) {
         self.selected_stack.push(selected);
         self.min_stack.push(minimum);
@@ -781,7 +1065,7 @@ impl Model {
                 self.max = std::cmp::max(self.max, self.height.saturating_sub(1));
             }
             Err(err) => {
-                self.error = Some(format!("Failed to read directory: {}", err));
+                self.error = Some(Error::filesystem(&self.current_directory, &err));
             }
         }
     }
@@ -814,68 +1098,73 @@ impl Model {
         bubbletea_rs::tick(std::time::Duration::from_nanos(1), move |_| {
             let mut entries = Vec::new();
 
-            if let Ok(dir_entries) = std::fs::read_dir(&current_dir) {
-                for entry in dir_entries.flatten() {
-                    let path = entry.path();
-                    let name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("?")
-                        .to_string();
-
-                    // Skip hidden files by default (can be configured later)
-                    if name.starts_with('.') {
-                        continue;
-                    }
-
-                    // Get file metadata
-                    let (is_dir, is_symlink, size, mode, symlink_target) =
-                        if let Ok(metadata) = entry.metadata() {
-                            let is_symlink = metadata.file_type().is_symlink();
-                            let mut is_dir = metadata.is_dir();
-                            let size = metadata.len();
-
-                            #[cfg(unix)]
-                            let mode = {
-                                use std::os::unix::fs::PermissionsExt;
-                                metadata.permissions().mode()
-                            };
-                            #[cfg(not(unix))]
-                            let mode = 0;
+            let dir_entries = match std::fs::read_dir(&current_dir) {
+                Ok(dir_entries) => dir_entries,
+                Err(err) => {
+                    let err = Error::filesystem(&current_dir, &err);
+                    return Box::new(ErrorMsg { id, err }) as Msg;
+                }
+            };
+            for entry in dir_entries.flatten() {
+                let path = entry.path();
+                let name = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("?")
+                    .to_string();
+
+                // Skip hidden files by default (can be configured later)
+                if name.starts_with('.') {
+                    continue;
+                }
 
-                            // Handle symlink resolution
-                            let symlink_target = if is_symlink {
-                                match std::fs::canonicalize(&path) {
-                                    Ok(target) => {
-                                        // Check if symlink points to a directory
-                                        if let Ok(target_meta) = std::fs::metadata(&target) {
-                                            if target_meta.is_dir() {
-                                                is_dir = true;
-                                            }
+                // Get file metadata
+                let (is_dir, is_symlink, size, mode, symlink_target) =
+                    if let Ok(metadata) = entry.metadata() {
+                        let is_symlink = metadata.file_type().is_symlink();
+                        let mut is_dir = metadata.is_dir();
+                        let size = metadata.len();
+
+                        #[cfg(unix)]
+                        let mode = {
+                            use std::os::unix::fs::PermissionsExt;
+                            metadata.permissions().mode()
+                        };
+                        #[cfg(not(unix))]
+                        let mode = 0;
+
+                        // Handle symlink resolution
+                        let symlink_target = if is_symlink {
+                            match std::fs::canonicalize(&path) {
+                                Ok(target) => {
+                                    // Check if symlink points to a directory
+                                    if let Ok(target_meta) = std::fs::metadata(&target) {
+                                        if target_meta.is_dir() {
+                                            is_dir = true;
                                         }
-                                        Some(target)
                                     }
-                                    Err(_) => None,
+                                    Some(target)
                                 }
-                            } else {
-                                None
-                            };
-
-                            (is_dir, is_symlink, size, mode, symlink_target)
+                                Err(_) => None,
+                            }
                         } else {
-                            (path.is_dir(), false, 0, 0, None)
+                            None
                         };
 
-                    entries.push(FileEntry {
-                        name,
-                        path,
-                        is_dir,
-                        is_symlink,
-                        size,
-                        mode,
-                        symlink_target,
-                    });
-                }
+                        (is_dir, is_symlink, size, mode, symlink_target)
+                    } else {
+                        (path.is_dir(), false, 0, 0, None)
+                    };
+
+                entries.push(FileEntry {
+                    name,
+                    path,
+                    is_dir,
+                    is_symlink,
+                    size,
+                    mode,
+                    symlink_target,
+                });
             }
 
             // Sort directories first, then files, then alphabetically
@@ -884,6 +1173,47 @@ impl Model {
             Box::new(ReadDirMsg { id, entries }) as Msg
         })
     }
+
+    /// Renders the picker as plain text: the current directory, the
+    /// visible entries marked as directories or links, and which entry has
+    /// the cursor. Entries that cannot be picked are marked unavailable.
+    pub fn accessible_view(&self) -> String {
+        let mut lines = vec![format!("Directory: {}", self.current_directory.display())];
+        if let Some(error) = &self.error {
+            lines.push(format!("Error: {}", error));
+            return lines.join("\n");
+        }
+        if self.files.is_empty() {
+            lines.push("No files found.".to_string());
+            return lines.join("\n");
+        }
+        let describe = |f: &FileEntry| {
+            let mut line = f.name.clone();
+            if f.is_dir {
+                line.push_str(", directory");
+            } else if f.is_symlink {
+                line.push_str(", link");
+            }
+            if !f.is_dir && !self.can_select(&f.name) {
+                line.push_str(", unavailable");
+            }
+            line
+        };
+        let end = self.max.min(self.files.len() - 1);
+        lines.extend(self.files[self.min.min(end)..=end].iter().map(describe));
+        let label = self
+            .files
+            .get(self.selected)
+            .map(describe)
+            .unwrap_or_default();
+        lines.push(crate::a11y::selected(
+            "entry",
+            self.selected,
+            self.files.len(),
+            &label,
+        ));
+        lines.join("\n")
+    }
 }
 
 impl Default for Model {
@@ -971,6 +1301,54 @@ fn is_hidden_impl(path: &Path, name: &str) -> bool {
     }
 }
 
+/// Saved location of a file picker; see
+/// [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The directory being browsed.
+    pub current_directory: PathBuf,
+    /// Index of the highlighted entry.
+    pub selected: usize,
+    /// The path the user selected, if any.
+    pub path: String,
+}
+
+/// Restoring reads the saved directory right away, so there is no need to
+/// run [`read_dir_cmd`](Model::read_dir_cmd) afterwards; doing so would
+/// move the highlight back to the first entry.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            current_directory: self.current_directory.clone(),
+            selected: self.selected,
+            path: self.path.clone(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.current_directory = state.current_directory;
+        self.path = state.path;
+        self.min = 0;
+        self.max = self.height.saturating_sub(1);
+        self.read_dir();
+        self.selected = state.selected.min(self.files.len().saturating_sub(1));
+        if self.selected > self.max {
+            let shift = self.selected - self.max;
+            self.min += shift;
+            self.max += shift;
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
 impl BubbleTeaModel for Model {
     fn init() -> (Self, Option<Cmd>) {
         let mut model = Self::new();
@@ -981,7 +1359,9 @@ impl BubbleTeaModel for Model {
     fn update(&mut self, msg: Msg) -> Option<Cmd> {
         // Handle window size messages FIRST to ensure height is set correctly
         if let Some(_window_msg) = msg.downcast_ref::<bubbletea_rs::WindowSizeMsg>() {
-            if self.auto_height {
+            if let Some((_, height)) = crate::widget::resized(&msg, self.resize_margins) {
+                self.height = height;
+            } else if self.auto_height {
                 self.height = (_window_msg.height as usize).saturating_sub(MARGIN_BOTTOM);
             }
             // Update max based on new height, but ensure it doesn't exceed file count
@@ -1002,7 +1382,15 @@ impl BubbleTeaModel for Model {
             return None;
         }
 
-        // Handle readDirMsg and errorMsg (would be async in real implementation)
+        if let Some(error_msg) = msg.downcast_ref::<ErrorMsg>() {
+            if error_msg.id == self.id {
+                self.files.clear();
+                self.error = Some(error_msg.err.clone());
+            }
+            return None;
+        }
+
+        // Handle readDirMsg (would be async in real implementation)
         if let Some(read_dir_msg) = msg.downcast_ref::<ReadDirMsg>() {
             if read_dir_msg.id == self.id {
                 self.files = read_dir_msg.entries.clone();
@@ -1034,6 +1422,12 @@ impl BubbleTeaModel for Model {
             return None;
         }
 
+        #[cfg(feature = "mouse")]
+        if crate::mouse::event(&msg).is_some() {
+            self.handle_mouse(&msg);
+            return None;
+        }
+
         if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
             match key_msg {
                 key_msg if self.keymap.go_to_top.matches(key_msg) => {
@@ -1046,22 +1440,8 @@ impl BubbleTeaModel for Model {
                     self.min = self.files.len().saturating_sub(self.height);
                     self.max = self.files.len().saturating_sub(1);
                 }
-                key_msg if self.keymap.down.matches(key_msg) => {
-                    if self.selected < self.files.len().saturating_sub(1) {
-                        self.selected += 1;
-                    }
-                    if self.selected > self.max {
-                        self.min += 1;
-                        self.max += 1;
-                    }
-                }
-                key_msg if self.keymap.up.matches(key_msg) => {
-                    self.selected = self.selected.saturating_sub(1);
-                    if self.selected < self.min {
-                        self.min = self.min.saturating_sub(1);
-                        self.max = self.max.saturating_sub(1);
-                    }
-                }
+                key_msg if self.keymap.down.matches(key_msg) => self.select_next(),
+                key_msg if self.keymap.up.matches(key_msg) => self.select_prev(),
                 key_msg if self.keymap.page_down.matches(key_msg) => {
                     self.selected += self.height;
                     if self.selected >= self.files.len() {
@@ -1142,6 +1522,10 @@ impl BubbleTeaModel for Model {
     }
 
     fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+
         // Display error if present
         if let Some(error) = &self.error {
             return self
@@ -1150,7 +1534,7 @@ impl BubbleTeaModel for Model {
                 .clone()
                 .height(self.height as i32)
                 .max_height(self.height as i32)
-                .render(error);
+                .render(&error.to_string());
         }
 
         if self.files.is_empty() {
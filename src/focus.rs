@@ -0,0 +1,349 @@
+//! Focus management for applications with several focusable components.
+//!
+//! A [`FocusRing`] tracks which of a set of [`Component`]s has keyboard
+//! focus. Components are registered by id in tab order; the ring itself does
+//! not own them; instead, each call that changes focus receives the components
+//! as a slice in the same order, blurs the previous one and returns the focus
+//! command of the next one. This replaces the hand-written
+//! `focused_component: usize` bookkeeping otherwise needed in every app.
+//!
+//! Entries may belong to a named group. While a group is active, `tab` and
+//! `shift+tab` cycle only within that group, which is useful for modal
+//! panels or multi-pane layouts; the ring remembers the last focused entry of
+//! each group.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::focus::FocusRing;
+//! use bubbletea_widgets::{textinput, Component};
+//!
+//! let mut name = textinput::new();
+//! let mut email = textinput::new();
+//! let mut ring = FocusRing::new().with_entry("name").with_entry("email");
+//!
+//! let _cmd = ring.init(&mut [&mut name, &mut email]);
+//! assert!(name.focused());
+//!
+//! let _cmd = ring.focus_next(&mut [&mut name, &mut email]);
+//! assert_eq!(ring.current_id(), Some("email"));
+//! assert!(!name.focused());
+//! assert!(email.focused());
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::Component;
+use bubbletea_rs::{Cmd, KeyMsg, Msg};
+use std::collections::HashMap;
+
+/// Key bindings for moving focus.
+#[derive(Debug, Clone)]
+pub struct FocusKeyMap {
+    /// Focuses the next entry. Default: `tab`.
+    pub next: key::Binding,
+    /// Focuses the previous entry. Default: `shift+tab`.
+    pub prev: key::Binding,
+}
+
+impl Default for FocusKeyMap {
+    fn default() -> Self {
+        Self {
+            next: key::new_binding(vec![
+                key::with_keys_str(&["tab"]),
+                key::with_help("tab", "next"),
+            ]),
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["shift+tab"]),
+                key::with_help("shift+tab", "previous"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for FocusKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.next, &self.prev]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![vec![&self.next, &self.prev]]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    id: String,
+    group: Option<String>,
+}
+
+/// Tracks keyboard focus across a fixed, ordered set of components.
+#[derive(Debug, Clone, Default)]
+pub struct FocusRing {
+    entries: Vec<Entry>,
+    current: usize,
+    group: Option<String>,
+    last_in_group: HashMap<String, usize>,
+    /// Key bindings.
+    pub keymap: FocusKeyMap,
+}
+
+impl FocusRing {
+    /// Creates an empty focus ring.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an entry that belongs to no group.
+    pub fn with_entry(mut self, id: &str) -> Self {
+        self.register(id, None);
+        self
+    }
+
+    /// Registers an entry that belongs to `group`.
+    pub fn with_group_entry(mut self, id: &str, group: &str) -> Self {
+        self.register(id, Some(group));
+        self
+    }
+
+    /// Registers an entry and returns its index, which is also the position
+    /// its component must occupy in the slices passed to this ring.
+    pub fn register(&mut self, id: &str, group: Option<&str>) -> usize {
+        self.entries.push(Entry {
+            id: id.to_string(),
+            group: group.map(str::to_string),
+        });
+        self.entries.len() - 1
+    }
+
+    /// Returns the number of registered entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no entries are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the index of the focused entry.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the id of the focused entry.
+    pub fn current_id(&self) -> Option<&str> {
+        self.entries.get(self.current).map(|e| e.id.as_str())
+    }
+
+    /// Returns whether the entry `id` has focus.
+    pub fn is_focused(&self, id: &str) -> bool {
+        self.current_id() == Some(id)
+    }
+
+    /// Returns the active group, if cycling is restricted to one.
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Returns the index of the entry `id`.
+    pub fn index_of(&self, id: &str) -> Option<usize> {
+        self.entries.iter().position(|e| e.id == id)
+    }
+
+    /// Focuses the current entry and blurs every other component.
+    ///
+    /// Call this once after constructing the components.
+    pub fn init(&mut self, components: &mut [&mut dyn Component]) -> Option<Cmd> {
+        let mut cmd = None;
+        for (i, c) in components.iter_mut().enumerate() {
+            if i == self.current {
+                cmd = c.focus();
+            } else {
+                c.blur();
+            }
+        }
+        cmd
+    }
+
+    /// Blurs the focused component, focuses the entry at `index` and returns
+    /// its focus command. Out-of-range indices are ignored.
+    pub fn focus_index(
+        &mut self,
+        index: usize,
+        components: &mut [&mut dyn Component],
+    ) -> Option<Cmd> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        if let Some(c) = components.get_mut(self.current) {
+            c.blur();
+        }
+        self.current = index;
+        if let Some(group) = &self.entries[index].group {
+            self.last_in_group.insert(group.clone(), index);
+        }
+        components.get_mut(index)?.focus()
+    }
+
+    /// Focuses the entry registered as `id`.
+    pub fn focus_id(&mut self, id: &str, components: &mut [&mut dyn Component]) -> Option<Cmd> {
+        let index = self.index_of(id)?;
+        self.focus_index(index, components)
+    }
+
+    /// Restricts cycling to `group` and focuses the entry last focused in
+    /// it, or its first entry. `None` lifts the restriction.
+    pub fn focus_group(
+        &mut self,
+        group: Option<&str>,
+        components: &mut [&mut dyn Component],
+    ) -> Option<Cmd> {
+        self.group = group.map(str::to_string);
+        let group = group?;
+        let index = self
+            .last_in_group
+            .get(group)
+            .copied()
+            .or_else(|| self.members().first().copied())?;
+        self.focus_index(index, components)
+    }
+
+    fn members(&self) -> Vec<usize> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| self.group.is_none() || e.group == self.group)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    fn step(&mut self, forward: bool, components: &mut [&mut dyn Component]) -> Option<Cmd> {
+        let members = self.members();
+        if members.is_empty() {
+            return None;
+        }
+        let next = match members.iter().position(|&i| i == self.current) {
+            Some(pos) if forward => members[(pos + 1) % members.len()],
+            Some(pos) => members[(pos + members.len() - 1) % members.len()],
+            None => members[0],
+        };
+        self.focus_index(next, components)
+    }
+
+    /// Moves focus to the next entry, wrapping around.
+    pub fn focus_next(&mut self, components: &mut [&mut dyn Component]) -> Option<Cmd> {
+        self.step(true, components)
+    }
+
+    /// Moves focus to the previous entry, wrapping around.
+    pub fn focus_prev(&mut self, components: &mut [&mut dyn Component]) -> Option<Cmd> {
+        self.step(false, components)
+    }
+
+    /// Returns whether `msg` is one of the ring's navigation keys.
+    ///
+    /// Use this to decide whether a message should go to [`FocusRing::update`]
+    /// or to the focused component.
+    pub fn handles(&self, msg: &Msg) -> bool {
+        msg.downcast_ref::<KeyMsg>()
+            .is_some_and(|k| self.keymap.next.matches(k) || self.keymap.prev.matches(k))
+    }
+
+    /// Moves focus in response to `tab`/`shift+tab`; other messages are
+    /// ignored.
+    pub fn update(&mut self, msg: &Msg, components: &mut [&mut dyn Component]) -> Option<Cmd> {
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if self.keymap.next.matches(key_msg) {
+            return self.focus_next(components);
+        }
+        if self.keymap.prev.matches(key_msg) {
+            return self.focus_prev(components);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[derive(Default)]
+    struct Dummy(bool);
+
+    impl Component for Dummy {
+        fn focus(&mut self) -> Option<Cmd> {
+            self.0 = true;
+            None
+        }
+
+        fn blur(&mut self) {
+            self.0 = false;
+        }
+
+        fn focused(&self) -> bool {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_cycles_and_wraps() {
+        let (mut a, mut b, mut c) = (Dummy::default(), Dummy::default(), Dummy::default());
+        let mut ring = FocusRing::new()
+            .with_entry("a")
+            .with_entry("b")
+            .with_entry("c");
+        ring.init(&mut [&mut a, &mut b, &mut c]);
+        assert!(a.0);
+        ring.focus_prev(&mut [&mut a, &mut b, &mut c]);
+        assert_eq!(ring.current_id(), Some("c"));
+        assert!(!a.0 && c.0);
+        ring.focus_next(&mut [&mut a, &mut b, &mut c]);
+        assert!(ring.is_focused("a"));
+    }
+
+    #[test]
+    fn test_tab_keys() {
+        let (mut a, mut b) = (Dummy::default(), Dummy::default());
+        let mut ring = FocusRing::new().with_entry("a").with_entry("b");
+        let tab: Msg = Box::new(KeyMsg {
+            key: KeyCode::Tab,
+            modifiers: KeyModifiers::NONE,
+        });
+        let other: Msg = Box::new(KeyMsg {
+            key: KeyCode::Char('x'),
+            modifiers: KeyModifiers::NONE,
+        });
+        assert!(ring.handles(&tab));
+        assert!(!ring.handles(&other));
+        ring.update(&tab, &mut [&mut a, &mut b]);
+        assert!(b.0);
+        ring.update(&other, &mut [&mut a, &mut b]);
+        assert!(b.0);
+    }
+
+    #[test]
+    fn test_groups_restrict_cycling_and_remember_focus() {
+        let mut d: Vec<Dummy> = (0..4).map(|_| Dummy::default()).collect();
+        let mut ring = FocusRing::new()
+            .with_entry("search")
+            .with_group_entry("ok", "dialog")
+            .with_group_entry("cancel", "dialog")
+            .with_entry("list");
+        let [a, b, c, e] = d.as_mut_slice() else {
+            unreachable!()
+        };
+        ring.focus_group(Some("dialog"), &mut [a, b, c, e]);
+        assert_eq!(ring.current_id(), Some("ok"));
+        ring.focus_next(&mut [a, b, c, e]);
+        ring.focus_next(&mut [a, b, c, e]);
+        assert_eq!(ring.current_id(), Some("ok"));
+        ring.focus_next(&mut [a, b, c, e]);
+
+        ring.focus_group(None, &mut [a, b, c, e]);
+        ring.focus_id("list", &mut [a, b, c, e]);
+        assert!(e.0 && !c.0);
+        ring.focus_group(Some("dialog"), &mut [a, b, c, e]);
+        assert_eq!(ring.current_id(), Some("cancel"));
+    }
+}
@@ -0,0 +1,791 @@
+//! Declarative multi-field forms built from the crate's input widgets.
+//!
+//! A [`Model`] is a vertical sequence of [`Field`]s. Each field wraps one of
+//! the existing widgets — a single-line `TextInput`, a multi-line `TextArea`,
+//! a select list or a yes/no confirm toggle — together with a title, optional
+//! description and an optional validator. Focus moves between fields through
+//! the [`Component`](crate::Component) trait, validation errors are shown
+//! below the offending field, and a successful submission produces a
+//! [`SubmitMsg`] carrying every answer as a typed [`Value`].
+//!
+//! # Navigation Controls
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `tab` | Validate the field and move to the next one |
+//! | `shift+tab` | Move to the previous field |
+//! | `enter` | Next field, or submit on the last one (text areas insert a newline) |
+//! | `ctrl+s` | Submit from anywhere |
+//! | `↑`/`↓` | Change the choice of a select field |
+//! | `←`/`→`, `y`/`n` | Toggle a confirm field |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::form::{Field, Model, Value};
+//!
+//! let mut form = Model::new(vec![
+//!     Field::input("name", "Name")
+//!         .with_placeholder("Jane Doe")
+//!         .with_validate(Box::new(|v| match v.as_str() {
+//!             Some("") => Err("name is required".to_string()),
+//!             _ => Ok(()),
+//!         })),
+//!     Field::select("lang", "Language", &["Rust", "Go", "Zig"]),
+//!     Field::text_area("bio", "Bio"),
+//!     Field::confirm("newsletter", "Subscribe?").with_default_bool(true),
+//! ]);
+//! let _cmd = form.init();
+//!
+//! let values = form.values();
+//! assert_eq!(values["lang"], Value::Choice("Rust".to_string()));
+//! assert_eq!(values["newsletter"].as_bool(), Some(true));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use crate::{textarea, textinput, Component};
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use crossterm::event::KeyCode;
+use lipgloss_extras::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// A typed field value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    /// Text from an input or text area field.
+    Text(String),
+    /// The chosen option of a select field.
+    Choice(String),
+    /// The answer of a confirm field.
+    Bool(bool),
+}
+
+impl Value {
+    /// Returns the text of a `Text` or `Choice` value.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Text(s) | Value::Choice(s) => Some(s),
+            Value::Bool(_) => None,
+        }
+    }
+
+    /// Returns the answer of a `Bool` value.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+/// Validation function for a field. Returns an error message on failure.
+pub type FieldValidateFunc = Box<dyn Fn(&Value) -> Result<(), String> + Send>;
+
+/// Message emitted when the form is submitted and every field is valid.
+#[derive(Debug, Clone)]
+pub struct SubmitMsg {
+    /// The ID of the submitted form.
+    pub id: i64,
+    /// Field values keyed by field key.
+    pub values: HashMap<String, Value>,
+}
+
+/// The widget backing a field.
+pub enum FieldInput {
+    /// Single-line text input.
+    Text(Box<textinput::Model>),
+    /// Multi-line text area.
+    TextArea(Box<textarea::Model>),
+    /// A list of options with the index of the chosen one.
+    Select {
+        /// Available options.
+        options: Vec<String>,
+        /// Index of the chosen option.
+        selected: usize,
+    },
+    /// A yes/no toggle.
+    Confirm(bool),
+}
+
+/// A single form field.
+pub struct Field {
+    key: String,
+    title: String,
+    description: String,
+    input: FieldInput,
+    validate: Option<FieldValidateFunc>,
+    error: Option<String>,
+    focus: bool,
+}
+
+impl Field {
+    fn with_input(key: &str, title: &str, input: FieldInput) -> Self {
+        Self {
+            key: key.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            input,
+            validate: None,
+            error: None,
+            focus: false,
+        }
+    }
+
+    /// Creates a single-line text field.
+    pub fn input(key: &str, title: &str) -> Self {
+        Self::with_input(key, title, FieldInput::Text(Box::default()))
+    }
+
+    /// Creates a multi-line text field.
+    pub fn text_area(key: &str, title: &str) -> Self {
+        let mut ta = textarea::new();
+        ta.set_height(4);
+        Self::with_input(key, title, FieldInput::TextArea(Box::new(ta)))
+    }
+
+    /// Creates a select field choosing one of `options`.
+    pub fn select(key: &str, title: &str, options: &[&str]) -> Self {
+        Self::with_input(
+            key,
+            title,
+            FieldInput::Select {
+                options: options.iter().map(|o| o.to_string()).collect(),
+                selected: 0,
+            },
+        )
+    }
+
+    /// Creates a yes/no confirm field, defaulting to "no".
+    pub fn confirm(key: &str, title: &str) -> Self {
+        Self::with_input(key, title, FieldInput::Confirm(false))
+    }
+
+    /// Sets a description shown under the title.
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Sets the validator run when leaving the field and on submit.
+    pub fn with_validate(mut self, f: FieldValidateFunc) -> Self {
+        self.validate = Some(f);
+        self
+    }
+
+    /// Sets the placeholder of a text or text area field.
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        match &mut self.input {
+            FieldInput::Text(ti) => ti.set_placeholder(placeholder),
+            FieldInput::TextArea(ta) => ta.placeholder = placeholder.to_string(),
+            _ => {}
+        }
+        self
+    }
+
+    /// Sets the initial text of a text or text area field, or the initial
+    /// choice of a select field when `value` matches one of its options.
+    pub fn with_default(mut self, value: &str) -> Self {
+        self.set_value(&Value::Text(value.to_string()));
+        self
+    }
+
+    /// Sets the initial answer of a confirm field.
+    pub fn with_default_bool(mut self, value: bool) -> Self {
+        self.set_value(&Value::Bool(value));
+        self
+    }
+
+    /// Sets the current value. Text goes into text fields and picks the
+    /// matching option of a select field; a `Bool` answers a confirm field.
+    /// Values that do not fit the field are ignored.
+    pub fn set_value(&mut self, value: &Value) {
+        match (&mut self.input, value) {
+            (FieldInput::Text(ti), Value::Text(s) | Value::Choice(s)) => ti.set_value(s),
+            (FieldInput::TextArea(ta), Value::Text(s) | Value::Choice(s)) => ta.set_value(s),
+            (FieldInput::Select { options, selected }, Value::Text(s) | Value::Choice(s)) => {
+                if let Some(i) = options.iter().position(|o| o == s) {
+                    *selected = i;
+                }
+            }
+            (FieldInput::Confirm(b), Value::Bool(v)) => *b = *v,
+            _ => {}
+        }
+    }
+
+    /// Returns the field key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the field title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the backing widget.
+    pub fn input_widget(&self) -> &FieldInput {
+        &self.input
+    }
+
+    /// Returns the backing widget mutably.
+    pub fn input_widget_mut(&mut self) -> &mut FieldInput {
+        &mut self.input
+    }
+
+    /// Returns the last validation error, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> Value {
+        match &self.input {
+            FieldInput::Text(ti) => Value::Text(ti.value()),
+            FieldInput::TextArea(ta) => Value::Text(ta.value()),
+            FieldInput::Select { options, selected } => {
+                Value::Choice(options.get(*selected).cloned().unwrap_or_default())
+            }
+            FieldInput::Confirm(b) => Value::Bool(*b),
+        }
+    }
+
+    /// Runs the validator, storing and returning its outcome.
+    pub fn validate(&mut self) -> bool {
+        self.error = match &self.validate {
+            Some(f) => f(&self.value()).err(),
+            None => None,
+        };
+        self.error.is_none()
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        match &mut self.input {
+            FieldInput::Text(ti) => ti.update(msg),
+            FieldInput::TextArea(ta) => ta.update(Some(msg)),
+            FieldInput::Select { options, selected } => {
+                let key_msg = msg.downcast_ref::<KeyMsg>()?;
+                match key_msg.key {
+                    KeyCode::Up | KeyCode::Char('k') => *selected = selected.saturating_sub(1),
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        *selected = (*selected + 1).min(options.len().saturating_sub(1))
+                    }
+                    _ => {}
+                }
+                None
+            }
+            FieldInput::Confirm(b) => {
+                let key_msg = msg.downcast_ref::<KeyMsg>()?;
+                match key_msg.key {
+                    KeyCode::Left | KeyCode::Right | KeyCode::Char('h') | KeyCode::Char('l') => {
+                        *b = !*b
+                    }
+                    KeyCode::Char('y') | KeyCode::Char('Y') => *b = true,
+                    KeyCode::Char('n') | KeyCode::Char('N') => *b = false,
+                    _ => {}
+                }
+                None
+            }
+        }
+    }
+
+    fn view(&self, styles: &Styles) -> String {
+        let mut lines = Vec::new();
+        let title_style = if self.focus {
+            &styles.focused_title
+        } else {
+            &styles.title
+        };
+        lines.push(title_style.render(&self.title));
+        if !self.description.is_empty() {
+            lines.push(styles.description.render(&self.description));
+        }
+        lines.push(match &self.input {
+            FieldInput::Text(ti) => ti.view(),
+            // The text area renders through `&mut self`, so render a copy.
+            FieldInput::TextArea(ta) => ta.clone().view(),
+            FieldInput::Select { options, selected } => options
+                .iter()
+                .enumerate()
+                .map(|(i, o)| {
+                    if i == *selected {
+                        styles.selected_option.render(&format!("> {}", o))
+                    } else {
+                        styles.option.render(&format!("  {}", o))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            FieldInput::Confirm(b) => {
+                let (yes, no) = if *b {
+                    (&styles.selected_option, &styles.option)
+                } else {
+                    (&styles.option, &styles.selected_option)
+                };
+                format!("{}  {}", yes.render("Yes"), no.render("No"))
+            }
+        });
+        if let Some(err) = &self.error {
+            lines.push(styles.error.render(&format!("* {}", err)));
+        }
+        lines.join("\n")
+    }
+}
+
+impl Component for Field {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        match &mut self.input {
+            FieldInput::Text(ti) => Some(ti.focus()),
+            FieldInput::TextArea(ta) => ta.focus(),
+            _ => None,
+        }
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+        match &mut self.input {
+            FieldInput::Text(ti) => ti.blur(),
+            FieldInput::TextArea(ta) => ta.blur(),
+            _ => {}
+        }
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Key bindings for moving through a form.
+#[derive(Debug, Clone)]
+pub struct FormKeyMap {
+    /// Moves to the next field. Default: `tab`.
+    pub next: key::Binding,
+    /// Moves to the previous field. Default: `shift+tab`.
+    pub prev: key::Binding,
+    /// Advances or submits from non-text-area fields. Default: `enter`.
+    pub advance: key::Binding,
+    /// Submits the form from any field. Default: `ctrl+s`.
+    pub submit: key::Binding,
+}
+
+impl Default for FormKeyMap {
+    fn default() -> Self {
+        Self {
+            next: key::new_binding(vec![
+                key::with_keys_str(&["tab"]),
+                key::with_help("tab", "next field"),
+            ]),
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["shift+tab"]),
+                key::with_help("shift+tab", "previous field"),
+            ]),
+            advance: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "next"),
+            ]),
+            submit: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+s"]),
+                key::with_help("ctrl+s", "submit"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for FormKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.next, &self.prev, &self.submit]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.next, &self.prev],
+            vec![&self.advance, &self.submit],
+        ]
+    }
+}
+
+/// Visual styles for forms.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Title of unfocused fields.
+    pub title: Style,
+    /// Title of the focused field.
+    pub focused_title: Style,
+    /// Field descriptions.
+    pub description: Style,
+    /// Unselected select/confirm options.
+    pub option: Style,
+    /// The selected select/confirm option.
+    pub selected_option: Style,
+    /// Validation errors.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            title: Style::new().bold(true),
+            focused_title: Style::new().bold(true).foreground(Color::from("#EE6FF8")),
+            description: Style::new().foreground(Color::from("#777777")),
+            option: Style::new(),
+            selected_option: Style::new().foreground(Color::from("#EE6FF8")),
+            error: Style::new().foreground(Color::from("#FF5F87")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates form styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            title: theme.text.clone().bold(true),
+            focused_title: theme.title.clone(),
+            description: theme.muted.clone(),
+            option: theme.text.clone(),
+            selected_option: theme.selected.clone(),
+            error: theme.error.clone(),
+        }
+    }
+}
+
+/// A multi-field form.
+pub struct Model {
+    id: i64,
+    fields: Vec<Field>,
+    focused: usize,
+    submitted: bool,
+    /// Key bindings.
+    pub keymap: FormKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+impl Model {
+    /// Creates a form from the given fields.
+    ///
+    /// Call [`Model::init`] to focus the first field.
+    pub fn new(fields: Vec<Field>) -> Self {
+        Self {
+            id: next_id(),
+            fields,
+            focused: 0,
+            submitted: false,
+            keymap: FormKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Focuses the first field and returns its focus command.
+    pub fn init(&mut self) -> Option<Cmd> {
+        self.focus_field(0)
+    }
+
+    /// Returns the unique ID of this form.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the fields.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Returns the field with the given key.
+    pub fn field(&self, key: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.key == key)
+    }
+
+    /// Returns the field with the given key mutably.
+    pub fn field_mut(&mut self, key: &str) -> Option<&mut Field> {
+        self.fields.iter_mut().find(|f| f.key == key)
+    }
+
+    /// Returns the index of the focused field.
+    pub fn focused_index(&self) -> usize {
+        self.focused
+    }
+
+    /// Returns whether the form has been submitted successfully.
+    pub fn submitted(&self) -> bool {
+        self.submitted
+    }
+
+    /// Returns all field values keyed by field key.
+    pub fn values(&self) -> HashMap<String, Value> {
+        self.fields
+            .iter()
+            .map(|f| (f.key.clone(), f.value()))
+            .collect()
+    }
+
+    /// Moves focus to the field at `index`.
+    pub fn focus_field(&mut self, index: usize) -> Option<Cmd> {
+        if index >= self.fields.len() {
+            return None;
+        }
+        if let Some(current) = self.fields.get_mut(self.focused) {
+            current.blur();
+        }
+        self.focused = index;
+        self.fields[index].focus()
+    }
+
+    /// Validates the focused field and moves to the next one.
+    ///
+    /// Focus stays put when validation fails.
+    pub fn next_field(&mut self) -> Option<Cmd> {
+        let current = self.fields.get_mut(self.focused)?;
+        if !current.validate() {
+            return None;
+        }
+        self.focus_field(self.focused + 1)
+    }
+
+    /// Moves to the previous field without validating.
+    pub fn prev_field(&mut self) -> Option<Cmd> {
+        self.focus_field(self.focused.checked_sub(1)?)
+    }
+
+    /// Validates every field, returning the index of the first invalid one.
+    pub fn validate(&mut self) -> Result<(), usize> {
+        let mut first_invalid = None;
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            if !field.validate() && first_invalid.is_none() {
+                first_invalid = Some(i);
+            }
+        }
+        match first_invalid {
+            Some(i) => Err(i),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates every field and, if all pass, returns a command emitting
+    /// [`SubmitMsg`]. On failure focus moves to the first invalid field.
+    pub fn submit(&mut self) -> Option<Cmd> {
+        if let Err(i) = self.validate() {
+            return self.focus_field(i);
+        }
+        self.submitted = true;
+        let id = self.id;
+        let values = self.values();
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(SubmitMsg {
+                id,
+                values: values.clone(),
+            }) as Msg
+        }))
+    }
+
+    /// Routes a message to the focused field, handling form navigation keys.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.keymap.submit.matches(key_msg) {
+                return self.submit();
+            }
+            if self.keymap.next.matches(key_msg) {
+                return self.next_field();
+            }
+            if self.keymap.prev.matches(key_msg) {
+                return self.prev_field();
+            }
+            let in_text_area = matches!(
+                self.fields.get(self.focused).map(|f| &f.input),
+                Some(FieldInput::TextArea(_))
+            );
+            if !in_text_area && self.keymap.advance.matches(key_msg) {
+                if self.focused + 1 >= self.fields.len() {
+                    return self.submit();
+                }
+                return self.next_field();
+            }
+        }
+        self.fields.get_mut(self.focused)?.update(msg)
+    }
+
+    /// Renders all fields separated by blank lines.
+    pub fn view(&self) -> String {
+        self.fields
+            .iter()
+            .map(|f| f.view(&self.styles))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Saved answers of a form; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Field values keyed by field key, as returned by [`Model::values`].
+    pub values: HashMap<String, Value>,
+    /// Index of the focused field.
+    pub focused: usize,
+}
+
+/// Restoring sets each field whose key is in the state, leaving the others
+/// alone, and moves focus without returning the field's focus command.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            values: self.values(),
+            focused: self.focused,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        for field in &mut self.fields {
+            if let Some(value) = state.values.get(&field.key) {
+                field.set_value(value);
+            }
+        }
+        std::mem::drop(self.focus_field(state.focused));
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new()), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn required() -> FieldValidateFunc {
+        Box::new(|v| match v.as_str() {
+            Some("") => Err("required".to_string()),
+            _ => Ok(()),
+        })
+    }
+
+    #[test]
+    fn test_tab_is_blocked_by_failed_validation() {
+        let mut form = Model::new(vec![
+            Field::input("name", "Name").with_validate(required()),
+            Field::confirm("ok", "OK?"),
+        ]);
+        form.init();
+        form.update(key(KeyCode::Tab));
+        assert_eq!(form.focused_index(), 0);
+        assert_eq!(form.fields()[0].error(), Some("required"));
+        assert!(form.view().contains("required"));
+
+        form.update(key(KeyCode::Char('x')));
+        form.update(key(KeyCode::Tab));
+        assert_eq!(form.focused_index(), 1);
+        assert!(form.fields()[0].error().is_none());
+        assert!(form.fields()[1].focused());
+        assert!(!form.fields()[0].focused());
+    }
+
+    #[test]
+    fn test_select_and_confirm_values() {
+        let mut form = Model::new(vec![
+            Field::select("color", "Color", &["red", "green"]),
+            Field::confirm("sure", "Sure?"),
+        ]);
+        form.init();
+        form.update(key(KeyCode::Down));
+        form.update(key(KeyCode::Enter));
+        form.update(key(KeyCode::Char('y')));
+        let values = form.values();
+        assert_eq!(values["color"], Value::Choice("green".into()));
+        assert_eq!(values["sure"], Value::Bool(true));
+    }
+
+    #[test]
+    fn test_enter_on_last_field_submits() {
+        let mut form = Model::new(vec![Field::input("a", "A").with_default("hi")]);
+        form.init();
+        assert!(form.update(key(KeyCode::Enter)).is_some());
+        assert!(form.submitted());
+    }
+
+    #[test]
+    fn test_submit_focuses_first_invalid_field() {
+        let mut form = Model::new(vec![
+            Field::input("a", "A").with_default("ok"),
+            Field::input("b", "B").with_validate(required()),
+        ]);
+        form.init();
+        assert!(form.submit().is_some(), "returns the focus command");
+        assert!(!form.submitted());
+        assert_eq!(form.focused_index(), 1);
+    }
+
+    #[test]
+    fn test_enter_in_text_area_inserts_newline() {
+        let mut form = Model::new(vec![Field::text_area("bio", "Bio"), Field::input("x", "X")]);
+        form.init();
+        form.update(key(KeyCode::Char('a')));
+        form.update(key(KeyCode::Enter));
+        form.update(key(KeyCode::Char('b')));
+        assert_eq!(form.focused_index(), 0);
+        assert_eq!(form.values()["bio"], Value::Text("a\nb".into()));
+    }
+
+    #[test]
+    fn test_state_restores_values_and_focus() {
+        use crate::state::Stateful;
+
+        let fields = || {
+            vec![
+                Field::input("name", "Name"),
+                Field::select("color", "Color", &["red", "green"]),
+                Field::confirm("sure", "Sure?"),
+            ]
+        };
+        let mut form = Model::new(fields());
+        form.field_mut("name")
+            .unwrap()
+            .set_value(&Value::Text("Ada".into()));
+        form.field_mut("color")
+            .unwrap()
+            .set_value(&Value::Choice("green".into()));
+        form.field_mut("sure")
+            .unwrap()
+            .set_value(&Value::Bool(true));
+        form.focus_field(2);
+
+        let mut restored = Model::new(fields());
+        restored.restore(form.state());
+        assert_eq!(restored.values(), form.values());
+        assert_eq!(restored.focused_index(), 2);
+    }
+}
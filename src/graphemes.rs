@@ -0,0 +1,88 @@
+//! Grapheme cluster helpers for the text area and text input.
+//!
+//! The text is stored as `char`s, but the cursor moves, deletes and
+//! measures by grapheme cluster, so an emoji ZWJ sequence or a letter with
+//! combining marks behaves as the one character it is drawn as.
+
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Display width of a character, counting a tab as `tab_width` columns.
+pub(crate) fn rune_width(ch: char, tab_width: usize) -> usize {
+    if ch == '\t' {
+        tab_width
+    } else {
+        UnicodeWidthChar::width(ch).unwrap_or(0)
+    }
+}
+
+/// Lengths, in chars, of the grapheme clusters that make up `runes`.
+fn cluster_lens(runes: &[char]) -> Vec<usize> {
+    // Printable ASCII never combines, so each char is a cluster of its own
+    if runes
+        .iter()
+        .all(|&ch| ch.is_ascii_graphic() || ch == ' ' || ch == '\t')
+    {
+        return vec![1; runes.len()];
+    }
+    let text: String = runes.iter().collect();
+    text.graphemes(true).map(|g| g.chars().count()).collect()
+}
+
+/// The positions of the grapheme clusters that make up `runes`.
+pub(crate) fn clusters(runes: &[char]) -> Vec<Range<usize>> {
+    let mut start = 0;
+    cluster_lens(runes)
+        .into_iter()
+        .map(|len| {
+            start += len;
+            start - len..start
+        })
+        .collect()
+}
+
+/// The number of grapheme clusters in `runes`.
+#[cfg(feature = "textarea")]
+pub(crate) fn count(runes: &[char]) -> usize {
+    cluster_lens(runes).len()
+}
+
+/// The display width of `runes`, with tabs `tab_width` columns wide.
+pub(crate) fn width(runes: &[char], tab_width: usize) -> usize {
+    let mut start = 0;
+    let mut total = 0;
+    for len in cluster_lens(runes) {
+        let cluster = &runes[start..start + len];
+        total += match cluster {
+            [ch] => rune_width(*ch, tab_width),
+            _ => cluster.iter().collect::<String>().width(),
+        };
+        start += len;
+    }
+    total
+}
+
+/// Start of the cluster before position `col` of `line`.
+pub(crate) fn prev_boundary(line: &[char], col: usize) -> usize {
+    let mut start = 0;
+    for len in cluster_lens(line) {
+        if start + len >= col {
+            return start;
+        }
+        start += len;
+    }
+    start
+}
+
+/// End of the cluster that starts at position `col` of `line`.
+pub(crate) fn next_boundary(line: &[char], col: usize) -> usize {
+    let mut end = 0;
+    for len in cluster_lens(line) {
+        end += len;
+        if end > col {
+            return end;
+        }
+    }
+    line.len()
+}
@@ -0,0 +1,1124 @@
+//! Editable grid of typed cells.
+//!
+//! A grid is a small spreadsheet: column headers across the top, row
+//! headers down the side and a cell cursor that moves with the arrow keys.
+//! Every [`Column`] has a [`Kind`] that decides how its cells are edited
+//! and shown: text cells open an inline editor, number cells open the same
+//! editor but only accept values that parse as numbers, and bool cells
+//! toggle in place.
+//!
+//! Holding shift while moving extends a rectangular selection. The
+//! selection can be copied and pasted elsewhere in the grid; ranges travel
+//! as tab-separated text, so [`Model::copy`] and [`Model::paste`] also work
+//! with the system clipboard. Values pasted into a column are converted to
+//! its kind, and cells that do not convert are left alone.
+//!
+//! Every edit, toggle, clear and paste emits one [`ChangeMsg`] listing the
+//! cells that changed with their old and new values.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `↑`, `↓`, `←`, `→` | Move the cursor |
+//! | `shift+↑`, `shift+↓`, `shift+←`, `shift+→` | Extend the selection |
+//! | `tab`, `shift+tab` | Next / previous cell |
+//! | `enter`, `f2` | Edit the cell, or toggle a bool cell |
+//! | `space` | Toggle a bool cell |
+//! | any character | Start editing with that character |
+//! | `delete`, `backspace` | Clear the selection |
+//! | `ctrl+c`, `ctrl+v` | Copy / paste the selection |
+//! | `esc` | Drop the selection |
+//!
+//! While editing, `enter` commits, `tab` commits and moves right and `esc`
+//! cancels.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::grid::{self, Column, Kind, Value};
+//!
+//! let mut limits = grid::new(
+//!     vec![
+//!         Column::new("CPU", 6, Kind::Number),
+//!         Column::new("Memory", 8, Kind::Text),
+//!         Column::new("Burst", 5, Kind::Bool),
+//!     ],
+//!     40,
+//!     5,
+//! )
+//! .with_rows(vec![
+//!     vec![Value::Number(2.0), Value::from("512Mi"), Value::Bool(false)],
+//!     vec![Value::Number(0.5), Value::from("128Mi"), Value::Bool(true)],
+//! ])
+//! .with_row_titles(&["api", "worker"]);
+//!
+//! limits.set_cell(1, 0, Value::from("1.5")).unwrap();
+//! assert_eq!(limits.cell(1, 0), Some(&Value::Number(1.5)));
+//! assert!(limits.set_cell(1, 0, Value::from("lots")).is_err());
+//! assert!(limits.view().contains("worker"));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, Align};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use crossterm::event::{KeyCode, KeyModifiers};
+use lipgloss_extras::prelude::*;
+use std::fmt;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// The type of the cells in a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kind {
+    /// Free text.
+    #[default]
+    Text,
+    /// Numbers; edits that do not parse are rejected.
+    Number,
+    /// Checkboxes toggled in place.
+    Bool,
+}
+
+impl Kind {
+    /// Parses `text` into a value of this kind. Blank text gives
+    /// [`Value::Empty`] for text and number columns and `false` for bool
+    /// columns. Bools accept `true`/`false`, `yes`/`no`, `on`/`off`,
+    /// `1`/`0` and `x`.
+    pub fn parse(self, text: &str) -> Result<Value> {
+        let text = text.trim();
+        match self {
+            Kind::Text if text.is_empty() => Ok(Value::Empty),
+            Kind::Text => Ok(Value::Text(text.to_string())),
+            Kind::Number if text.is_empty() => Ok(Value::Empty),
+            Kind::Number => text
+                .parse::<f64>()
+                .map(Value::Number)
+                .map_err(|_| Error::Validation(format!("not a number: {}", text))),
+            Kind::Bool => match text.to_lowercase().as_str() {
+                "true" | "yes" | "y" | "on" | "1" | "x" => Ok(Value::Bool(true)),
+                "false" | "no" | "n" | "off" | "0" | "" => Ok(Value::Bool(false)),
+                _ => Err(Error::Validation(format!("not a bool: {}", text))),
+            },
+        }
+    }
+
+    /// Returns the value of a cleared cell of this kind.
+    pub fn blank(self) -> Value {
+        match self {
+            Kind::Bool => Value::Bool(false),
+            _ => Value::Empty,
+        }
+    }
+
+    fn accepts(self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (Kind::Text, Value::Text(_) | Value::Empty)
+                | (Kind::Number, Value::Number(_) | Value::Empty)
+                | (Kind::Bool, Value::Bool(_))
+        )
+    }
+}
+
+/// The content of a cell.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    /// No value.
+    #[default]
+    Empty,
+    /// A text value.
+    Text(String),
+    /// A numeric value.
+    Number(f64),
+    /// A checkbox value.
+    Bool(bool),
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Value::Text(text.to_string())
+    }
+}
+
+/// Values display the way they are copied: numbers without trailing
+/// zeros and bools as `true`/`false`.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Empty => Ok(()),
+            Value::Text(text) => f.write_str(text),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
+
+/// A column of the grid.
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// Header text.
+    pub title: String,
+    /// Width of the column's cells in terminal cells.
+    pub width: usize,
+    /// Type of the column's cells.
+    pub kind: Kind,
+}
+
+impl Column {
+    /// Creates a column.
+    pub fn new(title: &str, width: usize, kind: Kind) -> Self {
+        Self {
+            title: title.to_string(),
+            width,
+            kind,
+        }
+    }
+}
+
+/// One cell that changed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Row of the cell.
+    pub row: usize,
+    /// Column of the cell.
+    pub col: usize,
+    /// The value before the change.
+    pub old: Value,
+    /// The value after the change.
+    pub new: Value,
+}
+
+/// Message emitted after cells were edited, toggled, cleared or pasted.
+#[derive(Debug, Clone)]
+pub struct ChangeMsg {
+    /// The ID of the grid that changed.
+    pub id: i64,
+    /// The cells that changed, in row-major order.
+    pub changes: Vec<Change>,
+}
+
+/// Key bindings for the grid.
+#[derive(Debug, Clone)]
+pub struct GridKeyMap {
+    /// Moves up. Default: `↑`.
+    pub up: key::Binding,
+    /// Moves down. Default: `↓`.
+    pub down: key::Binding,
+    /// Moves left. Default: `←`.
+    pub left: key::Binding,
+    /// Moves right. Default: `→`.
+    pub right: key::Binding,
+    /// Extends the selection up. Default: `shift+↑`.
+    pub select_up: key::Binding,
+    /// Extends the selection down. Default: `shift+↓`.
+    pub select_down: key::Binding,
+    /// Extends the selection left. Default: `shift+←`.
+    pub select_left: key::Binding,
+    /// Extends the selection right. Default: `shift+→`.
+    pub select_right: key::Binding,
+    /// Moves to the next cell, wrapping to the next row. While editing,
+    /// commits first. Default: `tab`.
+    pub next: key::Binding,
+    /// Moves to the previous cell, wrapping to the previous row.
+    /// Default: `shift+tab`.
+    pub prev: key::Binding,
+    /// Edits the cell, or toggles a bool cell. Default: `enter`, `f2`.
+    pub edit: key::Binding,
+    /// Toggles a bool cell. Default: `space`.
+    pub toggle: key::Binding,
+    /// Clears the selected cells. Default: `delete`, `backspace`.
+    pub clear: key::Binding,
+    /// Copies the selected cells. Default: `ctrl+c`.
+    pub copy: key::Binding,
+    /// Pastes at the cursor. Default: `ctrl+v`.
+    pub paste: key::Binding,
+    /// Commits an edit. Default: `enter`.
+    pub commit: key::Binding,
+    /// Cancels an edit or drops the selection. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for GridKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::new_binding(vec![key::with_keys_str(&["up"]), key::with_help("↑", "up")]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down"]),
+                key::with_help("↓", "down"),
+            ]),
+            left: key::new_binding(vec![
+                key::with_keys_str(&["left"]),
+                key::with_help("←", "left"),
+            ]),
+            right: key::new_binding(vec![
+                key::with_keys_str(&["right"]),
+                key::with_help("→", "right"),
+            ]),
+            select_up: key::new_binding(vec![
+                key::with_keys_str(&["shift+up"]),
+                key::with_help("shift+↑", "select up"),
+            ]),
+            select_down: key::new_binding(vec![
+                key::with_keys_str(&["shift+down"]),
+                key::with_help("shift+↓", "select down"),
+            ]),
+            select_left: key::new_binding(vec![
+                key::with_keys_str(&["shift+left"]),
+                key::with_help("shift+←", "select left"),
+            ]),
+            select_right: key::new_binding(vec![
+                key::with_keys_str(&["shift+right"]),
+                key::with_help("shift+→", "select right"),
+            ]),
+            next: key::new_binding(vec![
+                key::with_keys_str(&["tab"]),
+                key::with_help("tab", "next cell"),
+            ]),
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["shift+tab"]),
+                key::with_help("shift+tab", "previous cell"),
+            ]),
+            edit: key::new_binding(vec![
+                key::with_keys_str(&["enter", "f2"]),
+                key::with_help("enter", "edit"),
+            ]),
+            toggle: key::new_binding(vec![
+                key::with_keys_str(&["space"]),
+                key::with_help("space", "toggle"),
+            ]),
+            clear: key::new_binding(vec![
+                key::with_keys_str(&["delete", "backspace"]),
+                key::with_help("del", "clear"),
+            ]),
+            copy: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+c"]),
+                key::with_help("ctrl+c", "copy"),
+            ]),
+            paste: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+v"]),
+                key::with_help("ctrl+v", "paste"),
+            ]),
+            commit: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "commit"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "cancel"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for GridKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.edit, &self.select_right, &self.copy, &self.paste]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.left, &self.right],
+            vec![
+                &self.select_up,
+                &self.select_down,
+                &self.select_left,
+                &self.select_right,
+            ],
+            vec![&self.edit, &self.toggle, &self.clear],
+            vec![&self.copy, &self.paste, &self.cancel],
+        ]
+    }
+}
+
+/// Visual styles for the grid.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Column headers.
+    pub header: Style,
+    /// Row headers.
+    pub row_header: Style,
+    /// Ordinary cells.
+    pub cell: Style,
+    /// The cell under the cursor.
+    pub cursor: Style,
+    /// Selected cells other than the cursor.
+    pub selection: Style,
+    /// The validation error shown below the grid.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            header: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#1A1A1A",
+                    Dark: "#DDDDDD",
+                })
+                .bold(true),
+            row_header: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            cell: Style::new().foreground(AdaptiveColor {
+                Light: "#1A1A1A",
+                Dark: "#DDDDDD",
+            }),
+            cursor: Style::new()
+                .foreground(Color::from("#FFFDF5"))
+                .background(Color::from("#5A56E0")),
+            selection: Style::new()
+                .foreground(Color::from("#FFFDF5"))
+                .background(Color::from("#3C3A8F")),
+            error: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates grid styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            header: theme.title.clone(),
+            row_header: theme.muted.clone(),
+            cell: theme.text.clone(),
+            cursor: theme.selected.clone(),
+            selection: Style::new()
+                .foreground(theme.palette.foreground.clone())
+                .background(theme.palette.selection.clone()),
+            error: theme.error.clone(),
+        }
+    }
+}
+
+/// An editable grid of typed cells.
+pub struct Model {
+    id: i64,
+    columns: Vec<Column>,
+    row_titles: Vec<String>,
+    cells: Vec<Vec<Value>>,
+    cursor: (usize, usize),
+    anchor: Option<(usize, usize)>,
+    offset: usize,
+    col_offset: usize,
+    editor: Option<textinput::Model>,
+    error: Option<Error>,
+    register: String,
+    focus: bool,
+    /// Width available to the grid.
+    pub width: usize,
+    /// Height available to the grid, including the header row.
+    pub height: usize,
+    /// Key bindings.
+    pub keymap: GridKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an empty grid with the given columns and size.
+pub fn new(columns: Vec<Column>, width: usize, height: usize) -> Model {
+    Model::new(columns, width, height)
+}
+
+impl Model {
+    /// Creates an empty grid with the given columns and size. The grid
+    /// starts focused.
+    pub fn new(columns: Vec<Column>, width: usize, height: usize) -> Self {
+        Self {
+            id: next_id(),
+            columns,
+            row_titles: Vec::new(),
+            cells: Vec::new(),
+            cursor: (0, 0),
+            anchor: None,
+            offset: 0,
+            col_offset: 0,
+            editor: None,
+            error: None,
+            register: String::new(),
+            focus: true,
+            width,
+            height,
+            keymap: GridKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the rows.
+    pub fn with_rows(mut self, rows: Vec<Vec<Value>>) -> Self {
+        self.set_rows(rows);
+        self
+    }
+
+    /// Sets the row headers. Rows without a title are numbered from 1.
+    pub fn with_row_titles(mut self, titles: &[&str]) -> Self {
+        self.row_titles = titles.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this grid.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the columns.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns the rows.
+    pub fn rows(&self) -> &[Vec<Value>] {
+        &self.cells
+    }
+
+    /// Replaces the rows. Each row is padded or cut to the number of
+    /// columns, and values that do not fit their column are converted to
+    /// its kind or blanked. The cursor and selection are reset.
+    pub fn set_rows(&mut self, rows: Vec<Vec<Value>>) {
+        self.cells = rows
+            .into_iter()
+            .map(|row| {
+                let mut row = row.into_iter();
+                self.columns
+                    .iter()
+                    .map(|c| {
+                        let value = row.next().unwrap_or_default();
+                        coerce(c.kind, value).unwrap_or_else(|_| c.kind.blank())
+                    })
+                    .collect()
+            })
+            .collect();
+        self.cursor = (0, 0);
+        self.anchor = None;
+        self.offset = 0;
+        self.col_offset = 0;
+        self.editor = None;
+        self.error = None;
+    }
+
+    /// Appends a row. Missing cells are blank.
+    pub fn push_row(&mut self, row: Vec<Value>) {
+        let mut row = row.into_iter();
+        let row = self
+            .columns
+            .iter()
+            .map(|c| {
+                let value = row.next().unwrap_or_default();
+                coerce(c.kind, value).unwrap_or_else(|_| c.kind.blank())
+            })
+            .collect();
+        self.cells.push(row);
+    }
+
+    /// Returns the value of a cell.
+    pub fn cell(&self, row: usize, col: usize) -> Option<&Value> {
+        self.cells.get(row)?.get(col)
+    }
+
+    /// Sets a cell, converting the value to the column's kind. Fails if
+    /// the cell does not exist or the value does not convert.
+    pub fn set_cell(&mut self, row: usize, col: usize, value: Value) -> Result<()> {
+        let kind = self
+            .columns
+            .get(col)
+            .ok_or_else(|| Error::Validation("no such column".to_string()))?
+            .kind;
+        let value = coerce(kind, value)?;
+        let cell = self
+            .cells
+            .get_mut(row)
+            .and_then(|r| r.get_mut(col))
+            .ok_or_else(|| Error::Validation("no such row".to_string()))?;
+        *cell = value;
+        Ok(())
+    }
+
+    /// Returns the cursor as `(row, column)`.
+    pub fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Moves the cursor, clamped to the grid, and drops the selection.
+    pub fn set_cursor(&mut self, row: usize, col: usize) {
+        self.anchor = None;
+        self.move_to(row, col);
+    }
+
+    /// Returns the selected range as `(top, left, bottom, right)`,
+    /// inclusive. Without a selection this is the cursor cell.
+    pub fn selection(&self) -> (usize, usize, usize, usize) {
+        let (r, c) = self.cursor;
+        let (ar, ac) = self.anchor.unwrap_or(self.cursor);
+        (r.min(ar), c.min(ac), r.max(ar), c.max(ac))
+    }
+
+    /// Returns whether a cell editor is open.
+    pub fn is_editing(&self) -> bool {
+        self.editor.is_some()
+    }
+
+    /// Returns the error from the last rejected edit.
+    pub fn error(&self) -> Option<&Error> {
+        self.error.as_ref()
+    }
+
+    /// Returns the selected cells as tab-separated lines and keeps them for
+    /// the paste binding.
+    pub fn copy(&mut self) -> String {
+        if self.cells.is_empty() {
+            return String::new();
+        }
+        let (top, left, bottom, right) = self.selection();
+        self.register = self.cells[top..=bottom]
+            .iter()
+            .map(|row| {
+                row[left..=right]
+                    .iter()
+                    .map(|v| v.to_string().replace(['\t', '\n'], " "))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.register.clone()
+    }
+
+    /// Pastes tab-separated lines with their top-left corner at the cursor.
+    /// Cells past the edge of the grid and values that do not convert to
+    /// their column's kind are skipped. Returns the cells that changed.
+    pub fn paste(&mut self, text: &str) -> Vec<Change> {
+        let (row, col) = self.cursor;
+        let mut changes = Vec::new();
+        for (r, line) in text.lines().enumerate() {
+            for (c, field) in line.split('\t').enumerate() {
+                let (r, c) = (row + r, col + c);
+                if r >= self.cells.len() || c >= self.columns.len() {
+                    continue;
+                }
+                if let Ok(value) = self.columns[c].kind.parse(field) {
+                    changes.extend(self.replace(r, c, value));
+                }
+            }
+        }
+        changes
+    }
+
+    /// Blanks the selected cells. Returns the cells that changed.
+    pub fn clear_selection(&mut self) -> Vec<Change> {
+        if self.cells.is_empty() {
+            return Vec::new();
+        }
+        let (top, left, bottom, right) = self.selection();
+        let mut changes = Vec::new();
+        for r in top..=bottom {
+            for c in left..=right {
+                changes.extend(self.replace(r, c, self.columns[c].kind.blank()));
+            }
+        }
+        changes
+    }
+
+    fn replace(&mut self, row: usize, col: usize, value: Value) -> Option<Change> {
+        let cell = &mut self.cells[row][col];
+        if *cell == value {
+            return None;
+        }
+        let old = std::mem::replace(cell, value.clone());
+        Some(Change {
+            row,
+            col,
+            old,
+            new: value,
+        })
+    }
+
+    fn emit(&self, changes: Vec<Change>) -> Option<Cmd> {
+        if changes.is_empty() {
+            return None;
+        }
+        let id = self.id;
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ChangeMsg {
+                id,
+                changes: changes.clone(),
+            }) as Msg
+        }))
+    }
+
+    fn move_to(&mut self, row: usize, col: usize) {
+        self.cursor = (
+            row.min(self.cells.len().saturating_sub(1)),
+            col.min(self.columns.len().saturating_sub(1)),
+        );
+        self.scroll_to_cursor();
+    }
+
+    fn step(&mut self, forward: bool) {
+        let cols = self.columns.len().max(1);
+        let index = self.cursor.0 * cols + self.cursor.1;
+        let last = (self.cells.len() * cols).saturating_sub(1);
+        let index = if forward {
+            (index + 1).min(last)
+        } else {
+            index.saturating_sub(1)
+        };
+        self.anchor = None;
+        self.move_to(index / cols, index % cols);
+    }
+
+    fn visible_rows(&self) -> usize {
+        let error = usize::from(self.error.is_some());
+        self.height.saturating_sub(1 + error).max(1)
+    }
+
+    fn row_title(&self, row: usize) -> String {
+        self.row_titles
+            .get(row)
+            .cloned()
+            .unwrap_or_else(|| (row + 1).to_string())
+    }
+
+    fn row_header_width(&self) -> usize {
+        (0..self.cells.len())
+            .map(|r| text::width(&self.row_title(r)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Number of columns from `col_offset` that fit in the width.
+    fn visible_cols(&self) -> usize {
+        let mut used = self.row_header_width();
+        let mut count = 0;
+        for column in &self.columns[self.col_offset.min(self.columns.len())..] {
+            used += 1 + column.width;
+            if used > self.width && count > 0 {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let (row, col) = self.cursor;
+        let rows = self.visible_rows();
+        if row < self.offset {
+            self.offset = row;
+        } else if row >= self.offset + rows {
+            self.offset = row + 1 - rows;
+        }
+        if col < self.col_offset {
+            self.col_offset = col;
+        }
+        while col >= self.col_offset + self.visible_cols() {
+            self.col_offset += 1;
+        }
+    }
+
+    fn start_editing(&mut self, text: &str) -> Cmd {
+        let column = &self.columns[self.cursor.1];
+        let mut input = textinput::new();
+        input.prompt = String::new();
+        input.set_width(column.width.saturating_sub(1) as i32);
+        input.set_value(text);
+        input.cursor_end();
+        let cmd = input.focus();
+        self.editor = Some(input);
+        cmd
+    }
+
+    fn commit(&mut self) -> Option<Cmd> {
+        let text = self.editor.as_ref()?.value();
+        let (row, col) = self.cursor;
+        match self.columns[col].kind.parse(&text) {
+            Ok(value) => {
+                self.editor = None;
+                self.error = None;
+                let change = self.replace(row, col, value);
+                self.emit(change.into_iter().collect())
+            }
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    fn toggle(&mut self) -> Option<Cmd> {
+        let (row, col) = self.cursor;
+        let Value::Bool(b) = self.cells[row][col] else {
+            return None;
+        };
+        let change = self.replace(row, col, Value::Bool(!b));
+        self.emit(change.into_iter().collect())
+    }
+
+    /// Handles navigation, selection, editing and clipboard keys while
+    /// focused.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus || self.cells.is_empty() || self.columns.is_empty() {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if let Some(editor) = self.editor.as_mut() {
+            if self.keymap.commit.matches(key_msg) {
+                return self.commit();
+            } else if self.keymap.next.matches(key_msg) {
+                let cmd = self.commit();
+                if self.editor.is_none() {
+                    self.step(true);
+                }
+                return cmd;
+            } else if self.keymap.cancel.matches(key_msg) {
+                self.editor = None;
+                self.error = None;
+                return None;
+            }
+            return editor.update(msg);
+        }
+
+        let (row, col) = self.cursor;
+        let km = &self.keymap;
+        let moves = [
+            (&km.up, &km.select_up, -1, 0),
+            (&km.down, &km.select_down, 1, 0),
+            (&km.left, &km.select_left, 0, -1),
+            (&km.right, &km.select_right, 0, 1),
+        ];
+        let step = moves.iter().find_map(|(plain, extend, dr, dc)| {
+            let extending = extend.matches(key_msg);
+            (plain.matches(key_msg) || extending).then_some((extending, *dr, *dc))
+        });
+        if let Some((extending, dr, dc)) = step {
+            if !extending {
+                self.anchor = None;
+            } else if self.anchor.is_none() {
+                self.anchor = Some(self.cursor);
+            }
+            self.move_to(row.saturating_add_signed(dr), col.saturating_add_signed(dc));
+            return None;
+        }
+
+        let kind = self.columns[col].kind;
+        if self.keymap.next.matches(key_msg) {
+            self.step(true);
+        } else if self.keymap.prev.matches(key_msg) {
+            self.step(false);
+        } else if self.keymap.cancel.matches(key_msg) {
+            self.anchor = None;
+        } else if self.keymap.copy.matches(key_msg) {
+            self.copy();
+        } else if self.keymap.paste.matches(key_msg) {
+            let changes = self.paste(&self.register.clone());
+            return self.emit(changes);
+        } else if self.keymap.clear.matches(key_msg) {
+            let changes = self.clear_selection();
+            return self.emit(changes);
+        } else if kind == Kind::Bool
+            && (self.keymap.edit.matches(key_msg) || self.keymap.toggle.matches(key_msg))
+        {
+            return self.toggle();
+        } else if self.keymap.edit.matches(key_msg) {
+            self.anchor = None;
+            let text = self.cells[row][col].to_string();
+            return Some(self.start_editing(&text));
+        } else if let KeyCode::Char(c) = key_msg.key {
+            let plain = key_msg.modifiers - KeyModifiers::SHIFT == KeyModifiers::NONE;
+            if plain && kind != Kind::Bool {
+                self.anchor = None;
+                return Some(self.start_editing(&c.to_string()));
+            }
+        }
+        None
+    }
+
+    /// Renders the headers, the visible cells and any validation error.
+    pub fn view(&self) -> String {
+        let s = &self.styles;
+        let header_width = self.row_header_width();
+        let first = self.col_offset.min(self.columns.len());
+        let columns = &self.columns[first..first + self.visible_cols()];
+        let (top, left, bottom, right) = self.selection();
+
+        let mut header = " ".repeat(header_width);
+        for column in columns {
+            header.push(' ');
+            header.push_str(
+                &s.header
+                    .render(&text::fit(&column.title, column.width, Align::Left)),
+            );
+        }
+        let mut lines = vec![header];
+
+        let end = (self.offset + self.visible_rows()).min(self.cells.len());
+        for r in self.offset..end {
+            let title = self.row_title(r);
+            let mut line = s
+                .row_header
+                .render(&text::fit(&title, header_width, Align::Right))
+                .to_string();
+            for (i, column) in columns.iter().enumerate() {
+                let c = first + i;
+                line.push(' ');
+                if (r, c) == self.cursor {
+                    if let Some(editor) = &self.editor {
+                        line.push_str(&text::pad(&editor.view(), column.width, Align::Left));
+                        continue;
+                    }
+                }
+                let cell = match &self.cells[r][c] {
+                    Value::Bool(true) => "[x]".to_string(),
+                    Value::Bool(false) => "[ ]".to_string(),
+                    value => value.to_string(),
+                };
+                let align = if column.kind == Kind::Number {
+                    Align::Right
+                } else {
+                    Align::Left
+                };
+                let cell = text::fit(&cell, column.width, align);
+                let style = if (r, c) == self.cursor && self.focus {
+                    &s.cursor
+                } else if self.anchor.is_some()
+                    && (top..=bottom).contains(&r)
+                    && (left..=right).contains(&c)
+                {
+                    &s.selection
+                } else {
+                    &s.cell
+                };
+                line.push_str(&style.render(&cell));
+            }
+            lines.push(line);
+        }
+        if let Some(err) = &self.error {
+            lines.push(s.error.render(&err.to_string()));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Converts a value to `kind`, going through its text form when the kinds
+/// differ.
+fn coerce(kind: Kind, value: Value) -> Result<Value> {
+    if kind.accepts(&value) {
+        Ok(value)
+    } else {
+        kind.parse(&value.to_string())
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved cells, cursor and selection of a grid; see
+/// [`Stateful`](crate::state::Stateful). Since the grid edits its cells,
+/// they are part of the state; an open cell editor is not.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Cell values, row by row.
+    pub cells: Vec<Vec<Value>>,
+    /// Cursor as `(row, column)`.
+    pub cursor: (usize, usize),
+    /// The other corner of the selection, if there is one.
+    pub anchor: Option<(usize, usize)>,
+}
+
+/// Restoring goes through [`Model::set_rows`], so cells are fitted to the
+/// current columns.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            cells: self.cells.clone(),
+            cursor: self.cursor,
+            anchor: self.anchor,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_rows(state.cells);
+        self.set_cursor(state.cursor.0, state.cursor.1);
+        let last_row = self.cells.len().saturating_sub(1);
+        let last_col = self.columns.len().saturating_sub(1);
+        self.anchor = state
+            .anchor
+            .map(|(row, col)| (row.min(last_row), col.min(last_col)));
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new(), 60, 10), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers,
+        })
+    }
+
+    fn press(m: &mut Model, code: KeyCode) -> Option<Cmd> {
+        m.update(key(code, KeyModifiers::NONE))
+    }
+
+    fn sample() -> Model {
+        Model::new(
+            vec![
+                Column::new("Name", 6, Kind::Text),
+                Column::new("Qty", 4, Kind::Number),
+                Column::new("On", 3, Kind::Bool),
+            ],
+            40,
+            6,
+        )
+        .with_rows(vec![
+            vec![Value::from("a"), Value::Number(1.0), Value::Bool(true)],
+            vec![Value::from("b"), Value::from("2"), Value::Bool(false)],
+            vec![Value::from("c")],
+        ])
+    }
+
+    #[test]
+    fn test_rows_are_coerced_and_rendered() {
+        let m = sample();
+        assert_eq!(m.cell(1, 1), Some(&Value::Number(2.0)));
+        assert_eq!(m.cell(2, 1), Some(&Value::Empty));
+        assert_eq!(m.cell(2, 2), Some(&Value::Bool(false)));
+        let view = strip_ansi(&m.view());
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines[0], "  Name   Qty  On ");
+        assert_eq!(lines[1], "1 a         1 [x]");
+        assert_eq!(lines[3], "3 c           [ ]");
+    }
+
+    #[test]
+    fn test_number_editor_rejects_text() {
+        let mut m = sample();
+        press(&mut m, KeyCode::Right);
+        assert!(press(&mut m, KeyCode::Char('x')).is_some());
+        assert!(m.is_editing());
+        assert!(press(&mut m, KeyCode::Enter).is_none());
+        assert_eq!(
+            m.error(),
+            Some(&Error::Validation("not a number: x".into()))
+        );
+        press(&mut m, KeyCode::Backspace);
+        press(&mut m, KeyCode::Char('7'));
+        assert!(press(&mut m, KeyCode::Tab).is_some());
+        assert!(!m.is_editing());
+        assert_eq!(m.cell(0, 1), Some(&Value::Number(7.0)));
+        assert_eq!(m.cursor(), (0, 2));
+        assert!(press(&mut m, KeyCode::Char(' ')).is_some());
+        assert_eq!(m.cell(0, 2), Some(&Value::Bool(false)));
+    }
+
+    #[test]
+    fn test_copy_and_paste_range() {
+        let mut m = sample();
+        m.update(key(KeyCode::Right, KeyModifiers::SHIFT));
+        m.update(key(KeyCode::Down, KeyModifiers::SHIFT));
+        assert_eq!(m.selection(), (0, 0, 1, 1));
+        m.update(key(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(m.copy(), "a\t1\nb\t2");
+
+        m.set_cursor(1, 1);
+        let changes = m.paste("5\tyes\tspill\nsix\tno");
+        // "spill" falls off the right edge, "six" is not a number and the
+        // last bool is already false.
+        assert_eq!(changes.len(), 2);
+        assert_eq!(m.cell(1, 1), Some(&Value::Number(5.0)));
+        assert_eq!(m.cell(1, 2), Some(&Value::Bool(true)));
+        assert_eq!(m.cell(2, 1), Some(&Value::Empty));
+
+        m.set_cursor(0, 0);
+        m.update(key(KeyCode::Down, KeyModifiers::SHIFT));
+        let changes = m.clear_selection();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(m.cell(1, 0), Some(&Value::Empty));
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        use crate::state::Stateful;
+
+        let mut m = sample();
+        m.set_cursor(1, 1);
+        m.update(key(KeyCode::Up, KeyModifiers::SHIFT));
+        m.set_cell(0, 0, Value::from("z")).unwrap();
+        let saved = m.state();
+        assert_eq!(saved.anchor, Some((1, 1)));
+
+        let mut restored = sample();
+        restored.restore(saved.clone());
+        assert_eq!(restored.state(), saved);
+        assert_eq!(restored.selection(), (0, 1, 1, 1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_state_serde_round_trip() {
+        use crate::state::Stateful;
+
+        let m = sample();
+        let json = serde_json::to_string(&m.state()).unwrap();
+        assert!(json.contains(r#"{"Number":1.0}"#), "{}", json);
+        let state: State = serde_json::from_str(&json).unwrap();
+        assert_eq!(state, m.state());
+    }
+}
@@ -59,6 +59,9 @@
 //! ```
 
 use crate::key;
+use crate::text;
+use crate::theme::Theme;
+use crate::widget::Margins;
 use bubbletea_rs::{Cmd, Msg};
 use lipgloss_extras::lipgloss;
 use lipgloss_extras::prelude::*;
@@ -312,6 +315,24 @@ impl Default for Styles {
     }
 }
 
+impl Styles {
+    /// Creates help styles from a theme's muted and subtle colors.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let key_style = theme.muted.clone();
+        let desc_style = theme.subtle.clone();
+        let sep_style = theme.subtle.clone();
+        Self {
+            ellipsis: sep_style.clone(),
+            short_key: key_style.clone(),
+            short_desc: desc_style.clone(),
+            short_separator: sep_style.clone(),
+            full_key: key_style,
+            full_desc: desc_style,
+            full_separator: sep_style,
+        }
+    }
+}
+
 /// The help model that manages help view state and rendering.
 ///
 /// This is the main component for displaying help information in terminal applications.
@@ -385,7 +406,7 @@ impl Default for Styles {
 ///     short_separator: " | ".to_string(),
 ///     full_separator: "      ".to_string(),
 ///     ellipsis: "...".to_string(),
-///     styles: Default::default(),
+///     ..Default::default()
 /// };
 /// ```
 ///
@@ -433,6 +454,10 @@ pub struct Model {
 
     /// The styling configuration for all visual elements of the help view.
     pub styles: Styles,
+
+    /// Margins subtracted from the terminal width on `WindowSizeMsg`.
+    /// When `None` (the default), resize messages are ignored.
+    pub resize_margins: Option<Margins>,
 }
 
 impl Default for Model {
@@ -445,6 +470,7 @@ impl Default for Model {
     /// - `full_separator`: "    " (4 spaces)
     /// - `ellipsis`: "…"
     /// - `styles`: Default styles
+    /// - `resize_margins`: None (width is not tied to the terminal)
     ///
     /// # Examples
     ///
@@ -461,8 +487,9 @@ impl Default for Model {
             width: 0,
             short_separator: " • ".to_string(),
             full_separator: "    ".to_string(),
-            ellipsis: "…".to_string(),
+            ellipsis: text::ELLIPSIS.to_string(),
             styles: Styles::default(),
+            resize_margins: None,
         }
     }
 }
@@ -543,12 +570,34 @@ impl Model {
         self
     }
 
+    /// Makes the help view follow the terminal width.
+    ///
+    /// Every `WindowSizeMsg` passed to [`update`](Self::update) then sets
+    /// `width` to the terminal width minus the left and right `margins`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::help::Model;
+    /// use bubbletea_widgets::widget::Margins;
+    /// use bubbletea_rs::WindowSizeMsg;
+    ///
+    /// let help = Model::new().auto_resize(Margins::symmetric(0, 2));
+    /// let (help, _) = help.update(Box::new(WindowSizeMsg { width: 80, height: 24 }));
+    /// assert_eq!(help.width, 76);
+    /// ```
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
     /// Updates the help model in response to a message.
     ///
     /// This method provides compatibility with the bubbletea-rs architecture,
     /// matching the Go implementation's Update method. Since the help component
     /// is primarily a view component that doesn't handle user input directly,
-    /// this is a no-op method that simply returns the model unchanged.
+    /// the only message it reacts to is `WindowSizeMsg`, and only after
+    /// [`auto_resize`](Self::auto_resize) has been called.
     ///
     /// # Design Rationale
     ///
@@ -559,17 +608,18 @@ impl Model {
     ///
     /// Parent applications typically control help display by:
     /// - Toggling `show_all` based on key presses (e.g., '?' key)
-    /// - Adjusting `width` in response to terminal resize events
+    /// - Adjusting `width` in response to terminal resize events, or
+    ///   letting `auto_resize` do it
     /// - Updating styling based on theme changes
     ///
     /// # Arguments
     ///
-    /// * `_msg` - The message to handle (unused for help component)
+    /// * `msg` - The message to handle
     ///
     /// # Returns
     ///
     /// A tuple containing:
-    /// - The unchanged model
+    /// - The model, with `width` updated on resize when auto-resize is on
     /// - `None` for the command (no side effects needed)
     ///
     /// # Examples
@@ -579,7 +629,7 @@ impl Model {
     /// use bubbletea_rs::Msg;
     ///
     /// let help = Model::new();
-    /// // Any message can be passed, the help component ignores all messages
+    /// // Any message can be passed, without auto-resize the help component ignores all messages
     /// let msg = Box::new(42); // Example message
     /// let (updated_help, cmd) = help.update(msg);
     /// assert!(cmd.is_none()); // Help component doesn't generate commands
@@ -612,7 +662,10 @@ impl Model {
     /// #   fn view(&self) -> String { String::new() }
     /// # }
     /// ```
-    pub fn update(self, _msg: Msg) -> (Self, Option<Cmd>) {
+    pub fn update(mut self, msg: Msg) -> (Self, Option<Cmd>) {
+        if let Some((width, _)) = crate::widget::resized(&msg, self.resize_margins) {
+            self.width = width;
+        }
         (self, None)
     }
 
@@ -814,7 +867,7 @@ impl Model {
                 .render(&help.desc);
             let item_str = format!("{}{} {}", sep, key_part, desc_part);
 
-            let item_width = lipgloss::width_visible(&item_str);
+            let item_width = text::width(&item_str);
 
             if let Some(tail) = self.should_add_item(total_width, item_width) {
                 if !tail.is_empty() {
@@ -979,7 +1032,7 @@ impl Model {
             // For subsequent columns, we'll add them during horizontal joining
             let col_str = col_content;
 
-            let col_width = lipgloss::width_visible(&col_str);
+            let col_width = text::width(&col_str);
 
             if let Some(tail) = self.should_add_item(total_width, col_width) {
                 if !tail.is_empty() {
@@ -1034,7 +1087,7 @@ impl Model {
     ///
     /// # Width Calculation
     ///
-    /// Width calculations use `lipgloss::width_visible()` to properly handle:
+    /// Width calculations use `text::width()` to properly handle:
     /// - ANSI color codes (don't count toward width)
     /// - Unicode characters (count as their display width)
     /// - Multi-byte characters (count correctly)
@@ -1069,7 +1122,7 @@ impl Model {
                     .inline(true)
                     .render(&self.ellipsis)
             );
-            if total_width + lipgloss::width_visible(&tail) < self.width {
+            if total_width + text::width(&tail) < self.width {
                 return Some(tail);
             }
             return Some("".to_string());
@@ -1100,6 +1153,12 @@ impl Model {
     }
 }
 
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
 /// Determines if a column of key bindings should be rendered.
 ///
 /// A column should be rendered if it contains at least one enabled binding.
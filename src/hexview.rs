@@ -0,0 +1,711 @@
+//! Hex viewer for binary data.
+//!
+//! The hex view renders bytes the way `hexdump -C` does: an offset column,
+//! a hex pane and an ASCII pane, with non-printable bytes shown as `.`. A
+//! cursor selects one byte and is drawn in both panes; `tab` switches which
+//! pane it is focused in. Scrolling is handled by an embedded
+//! [`viewport`](crate::viewport) holding one line per row, and only the rows
+//! on screen are styled, so large files stay cheap to display.
+//!
+//! [`Model::goto`] moves the cursor to an offset and [`Model::find`] searches
+//! for a byte pattern, wrapping around the end of the data. Patterns can be
+//! written as hex with [`parse_hex`], e.g. `"de ad be ef"`.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `←`/`h`, `→`/`l` | Previous / next byte |
+//! | `↑`/`k`, `↓`/`j` | Previous / next row |
+//! | `pgup`, `pgdown` | Previous / next page |
+//! | `home`, `end` | Start / end of the row |
+//! | `g`, `G` | Start / end of the data |
+//! | `tab` | Switch between the hex and ASCII panes |
+//! | `n`, `N` | Next / previous match of the last search |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::hexview::{self, parse_hex};
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut hex = hexview::new(b"Hello, world!\n".to_vec(), 80, 10);
+//! assert!(strip_ansi(&hex.view()).starts_with("00000000  48 65 6c 6c 6f 2c 20 77  6f 72"));
+//!
+//! assert_eq!(hex.find(&parse_hex("77 6f").unwrap()), Some(7));
+//! assert_eq!(hex.cursor(), 7);
+//! hex.goto(0x0d);
+//! assert_eq!(hex.byte(), Some(b'\n'));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use crate::viewport;
+use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+
+/// The pane the cursor is focused in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Pane {
+    /// The hex pane.
+    #[default]
+    Hex,
+    /// The ASCII pane.
+    Ascii,
+}
+
+/// Parses a byte pattern written in hex, such as `"de ad be ef"` or
+/// `"0xDEADBEEF"`. Whitespace between bytes is optional.
+pub fn parse_hex(s: &str) -> Result<Vec<u8>> {
+    let digits: String = s
+        .split_whitespace()
+        .map(|part| {
+            part.strip_prefix("0x")
+                .or_else(|| part.strip_prefix("0X"))
+                .unwrap_or(part)
+        })
+        .collect();
+    if digits.is_empty() {
+        return Err(Error::Validation("empty pattern".to_string()));
+    }
+    if !digits.len().is_multiple_of(2) {
+        return Err(Error::Validation(format!(
+            "odd number of hex digits in {:?}",
+            s
+        )));
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&digits[i..i + 2], 16)
+                .map_err(|_| Error::Validation(format!("invalid hex byte {:?}", &digits[i..i + 2])))
+        })
+        .collect()
+}
+
+/// Key bindings for the hex view.
+#[derive(Debug, Clone)]
+pub struct HexViewKeyMap {
+    /// Moves to the previous byte. Default: `←`, `h`.
+    pub left: key::Binding,
+    /// Moves to the next byte. Default: `→`, `l`.
+    pub right: key::Binding,
+    /// Moves up one row. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves down one row. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Moves up one page. Default: `pgup`.
+    pub page_up: key::Binding,
+    /// Moves down one page. Default: `pgdown`.
+    pub page_down: key::Binding,
+    /// Moves to the start of the row. Default: `home`.
+    pub row_start: key::Binding,
+    /// Moves to the end of the row. Default: `end`.
+    pub row_end: key::Binding,
+    /// Moves to the first byte. Default: `g`.
+    pub top: key::Binding,
+    /// Moves to the last byte. Default: `G`.
+    pub bottom: key::Binding,
+    /// Switches between the hex and ASCII panes. Default: `tab`.
+    pub switch_pane: key::Binding,
+    /// Moves to the next match. Default: `n`.
+    pub next_match: key::Binding,
+    /// Moves to the previous match. Default: `N`.
+    pub prev_match: key::Binding,
+}
+
+impl Default for HexViewKeyMap {
+    fn default() -> Self {
+        Self {
+            left: key::new_binding(vec![
+                key::with_keys_str(&["left", "h"]),
+                key::with_help("←/h", "left"),
+            ]),
+            right: key::new_binding(vec![
+                key::with_keys_str(&["right", "l"]),
+                key::with_help("→/l", "right"),
+            ]),
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "k"]),
+                key::with_help("↑/k", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "j"]),
+                key::with_help("↓/j", "down"),
+            ]),
+            page_up: key::new_binding(vec![
+                key::with_keys_str(&["pgup"]),
+                key::with_help("pgup", "page up"),
+            ]),
+            page_down: key::new_binding(vec![
+                key::with_keys_str(&["pgdown"]),
+                key::with_help("pgdn", "page down"),
+            ]),
+            row_start: key::new_binding(vec![
+                key::with_keys_str(&["home"]),
+                key::with_help("home", "row start"),
+            ]),
+            row_end: key::new_binding(vec![
+                key::with_keys_str(&["end"]),
+                key::with_help("end", "row end"),
+            ]),
+            top: key::new_binding(vec![key::with_keys_str(&["g"]), key::with_help("g", "top")]),
+            bottom: key::new_binding(vec![
+                key::with_keys_str(&["G"]),
+                key::with_help("G", "bottom"),
+            ]),
+            switch_pane: key::new_binding(vec![
+                key::with_keys_str(&["tab"]),
+                key::with_help("tab", "hex/ascii"),
+            ]),
+            next_match: key::new_binding(vec![
+                key::with_keys_str(&["n"]),
+                key::with_help("n", "next match"),
+            ]),
+            prev_match: key::new_binding(vec![
+                key::with_keys_str(&["N"]),
+                key::with_help("N", "prev match"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for HexViewKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.up, &self.down, &self.switch_pane, &self.next_match]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.left, &self.right, &self.up, &self.down],
+            vec![
+                &self.page_up,
+                &self.page_down,
+                &self.row_start,
+                &self.row_end,
+            ],
+            vec![&self.top, &self.bottom, &self.switch_pane],
+            vec![&self.next_match, &self.prev_match],
+        ]
+    }
+}
+
+/// Visual styles for the hex view.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The offset column.
+    pub offset: Style,
+    /// Bytes in the hex pane and printable characters in the ASCII pane.
+    pub byte: Style,
+    /// Zero bytes in the hex pane.
+    pub zero: Style,
+    /// The `.` standing in for non-printable bytes in the ASCII pane.
+    pub non_printable: Style,
+    /// The `|` borders of the ASCII pane.
+    pub separator: Style,
+    /// The cursor in the focused pane.
+    pub cursor: Style,
+    /// The cursor in the other pane.
+    pub cursor_shadow: Style,
+    /// Bytes of the current search match.
+    pub matched: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            offset: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            byte: Style::new(),
+            zero: Style::new().foreground(AdaptiveColor {
+                Light: "#B2B2B2",
+                Dark: "#4A4A4A",
+            }),
+            non_printable: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            separator: Style::new().foreground(AdaptiveColor {
+                Light: "#B2B2B2",
+                Dark: "#4A4A4A",
+            }),
+            cursor: Style::new()
+                .foreground(Color::from("#FFFDF5"))
+                .background(Color::from("#7571F9"))
+                .bold(true),
+            cursor_shadow: Style::new().underline(true),
+            matched: Style::new()
+                .foreground(Color::from("#1A1A1A"))
+                .background(Color::from("#ECFD65")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates hex view styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            offset: theme.muted.clone(),
+            byte: theme.text.clone(),
+            zero: theme.subtle.clone(),
+            non_printable: theme.muted.clone(),
+            separator: theme.subtle.clone(),
+            cursor: theme.selected.clone(),
+            cursor_shadow: Style::new().underline(true),
+            matched: Style::new()
+                .foreground(p.background.clone())
+                .background(p.warning.clone()),
+        }
+    }
+}
+
+/// A hex viewer.
+#[derive(Debug, Clone)]
+pub struct Model {
+    data: Vec<u8>,
+    cursor: usize,
+    pane: Pane,
+    pattern: Vec<u8>,
+    matched: Option<usize>,
+    focus: bool,
+    /// Number of bytes shown per row.
+    pub bytes_per_row: usize,
+    /// The viewport scrolling the rows.
+    pub viewport: viewport::Model,
+    /// Visual styles.
+    pub styles: Styles,
+    /// Key bindings.
+    pub keymap: HexViewKeyMap,
+}
+
+/// Creates a hex view of `data` with the given viewport size.
+pub fn new(data: Vec<u8>, width: usize, height: usize) -> Model {
+    Model::new(data, width, height)
+}
+
+impl Model {
+    /// Creates a focused hex view of `data` showing 16 bytes per row.
+    pub fn new(data: Vec<u8>, width: usize, height: usize) -> Self {
+        let mut model = Self {
+            data: Vec::new(),
+            cursor: 0,
+            pane: Pane::Hex,
+            pattern: Vec::new(),
+            matched: None,
+            focus: true,
+            bytes_per_row: 16,
+            viewport: viewport::new(width, height),
+            styles: Styles::default(),
+            keymap: HexViewKeyMap::default(),
+        };
+        model.set_data(data);
+        model
+    }
+
+    /// Sets the number of bytes per row.
+    pub fn with_bytes_per_row(mut self, n: usize) -> Self {
+        self.bytes_per_row = n.max(1);
+        self.refresh();
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Replaces the data, moving the cursor back to the start.
+    pub fn set_data(&mut self, data: Vec<u8>) {
+        self.data = data;
+        self.cursor = 0;
+        self.matched = None;
+        self.viewport.set_y_offset(0);
+        self.refresh();
+    }
+
+    /// Returns the data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the offset of the byte under the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the byte under the cursor, or `None` if there is no data.
+    pub fn byte(&self) -> Option<u8> {
+        self.data.get(self.cursor).copied()
+    }
+
+    /// Returns the pane the cursor is focused in.
+    pub fn pane(&self) -> Pane {
+        self.pane
+    }
+
+    /// Focuses the cursor in the given pane.
+    pub fn set_pane(&mut self, pane: Pane) {
+        self.pane = pane;
+    }
+
+    /// Resizes the view, keeping the cursor in view.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.viewport.width = width;
+        self.viewport.height = height;
+        self.viewport.set_y_offset(self.viewport.y_offset);
+        self.goto(self.cursor);
+    }
+
+    /// Moves the cursor to `offset`, clamped to the data, and scrolls it
+    /// into view.
+    pub fn goto(&mut self, offset: usize) {
+        self.cursor = offset.min(self.data.len().saturating_sub(1));
+        let row = self.cursor / self.bytes_per_row;
+        let height = self.rows_visible().max(1);
+        if row < self.viewport.y_offset {
+            self.viewport.set_y_offset(row);
+        } else if row >= self.viewport.y_offset + height {
+            self.viewport.set_y_offset(row + 1 - height);
+        }
+    }
+
+    /// Searches for `pattern` after the cursor, wrapping around the end of
+    /// the data. On a match the cursor moves to its first byte, which is
+    /// returned. The pattern is remembered for [`find_next`](Self::find_next)
+    /// and [`find_prev`](Self::find_prev).
+    pub fn find(&mut self, pattern: &[u8]) -> Option<usize> {
+        self.pattern = pattern.to_vec();
+        self.matched = None;
+        // Search from the cursor itself so that a fresh search can match
+        // the byte it is on.
+        self.search(self.cursor, true)
+    }
+
+    /// Moves to the next match of the last pattern.
+    pub fn find_next(&mut self) -> Option<usize> {
+        self.search(self.cursor + 1, true)
+    }
+
+    /// Moves to the previous match of the last pattern.
+    pub fn find_prev(&mut self) -> Option<usize> {
+        let from = self.cursor.checked_sub(1).unwrap_or(self.data.len());
+        self.search(from, false)
+    }
+
+    /// Returns the offset of the current match, if any.
+    pub fn matched(&self) -> Option<usize> {
+        self.matched
+    }
+
+    fn search(&mut self, from: usize, forward: bool) -> Option<usize> {
+        let n = self.pattern.len();
+        if n == 0 || n > self.data.len() {
+            return None;
+        }
+        let last = self.data.len() - n;
+        let is_match = |i: usize| self.data[i..i + n] == self.pattern[..];
+        let found = if forward {
+            let from = if from > last { 0 } else { from };
+            (from..=last).chain(0..from).find(|&i| is_match(i))
+        } else {
+            let from = from.min(last);
+            (0..=from)
+                .rev()
+                .chain((from + 1..=last).rev())
+                .find(|&i| is_match(i))
+        };
+        if let Some(i) = found {
+            self.matched = Some(i);
+            self.goto(i);
+        }
+        found
+    }
+
+    fn rows(&self) -> usize {
+        self.data.len().div_ceil(self.bytes_per_row)
+    }
+
+    fn rows_visible(&self) -> usize {
+        let frame = self.viewport.style.get_vertical_frame_size() as usize;
+        self.viewport.height.saturating_sub(frame)
+    }
+
+    fn offset_digits(&self) -> usize {
+        let mut digits = 8;
+        while digits < 16 && self.data.len() >> (digits * 4) > 0 {
+            digits += 2;
+        }
+        digits
+    }
+
+    /// Rebuilds the viewport content with one plain line per row.
+    fn refresh(&mut self) {
+        let lines = (0..self.rows())
+            .map(|r| self.render_row(r, false))
+            .collect();
+        self.viewport.set_content_lines(lines);
+        self.goto(self.cursor);
+    }
+
+    /// Renders one row, styled or as plain text.
+    fn render_row(&self, row: usize, styled: bool) -> String {
+        let s = &self.styles;
+        let paint = |style: &Style, text: &str| -> String {
+            if styled {
+                style.render(text)
+            } else {
+                text.to_string()
+            }
+        };
+        let bpr = self.bytes_per_row;
+        let start = row * bpr;
+        let end = (start + bpr).min(self.data.len());
+        let in_match = |i: usize| {
+            self.matched
+                .is_some_and(|m| (m..m + self.pattern.len()).contains(&i))
+        };
+        let cell_style = |i: usize, pane: Pane, plain: &Style| -> Style {
+            if styled && i == self.cursor && self.focus {
+                if pane == self.pane {
+                    s.cursor.clone()
+                } else {
+                    s.cursor_shadow.clone()
+                }
+            } else if in_match(i) {
+                s.matched.clone()
+            } else {
+                plain.clone()
+            }
+        };
+
+        let mut out = paint(
+            &s.offset,
+            &format!("{:0w$x}", start, w = self.offset_digits()),
+        );
+        out.push_str("  ");
+        for col in 0..bpr {
+            if col > 0 {
+                out.push(' ');
+                if col % 8 == 0 {
+                    out.push(' ');
+                }
+            }
+            let i = start + col;
+            if i < end {
+                let b = self.data[i];
+                let plain = if b == 0 { &s.zero } else { &s.byte };
+                out.push_str(&paint(
+                    &cell_style(i, Pane::Hex, plain),
+                    &format!("{:02x}", b),
+                ));
+            } else {
+                out.push_str("  ");
+            }
+        }
+        out.push_str("  ");
+        out.push_str(&paint(&s.separator, "|"));
+        for i in start..end {
+            let b = self.data[i];
+            let (ch, plain) = if b.is_ascii_graphic() || b == b' ' {
+                (b as char, &s.byte)
+            } else {
+                ('.', &s.non_printable)
+            };
+            out.push_str(&paint(&cell_style(i, Pane::Ascii, plain), &ch.to_string()));
+        }
+        out.push_str(&paint(&s.separator, "|"));
+        out
+    }
+
+    /// Handles cursor movement, pane switching and match navigation.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus || self.data.is_empty() {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let bpr = self.bytes_per_row;
+        let page = self.rows_visible().max(1) * bpr;
+        let k = &self.keymap;
+        if k.left.matches(key_msg) {
+            self.goto(self.cursor.saturating_sub(1));
+        } else if k.right.matches(key_msg) {
+            self.goto(self.cursor + 1);
+        } else if k.up.matches(key_msg) {
+            if self.cursor >= bpr {
+                self.goto(self.cursor - bpr);
+            }
+        } else if k.down.matches(key_msg) {
+            if self.cursor + bpr < self.data.len() {
+                self.goto(self.cursor + bpr);
+            }
+        } else if k.page_up.matches(key_msg) {
+            self.goto(self.cursor.saturating_sub(page));
+        } else if k.page_down.matches(key_msg) {
+            self.goto(self.cursor + page);
+        } else if k.row_start.matches(key_msg) {
+            self.goto(self.cursor - self.cursor % bpr);
+        } else if k.row_end.matches(key_msg) {
+            self.goto(self.cursor - self.cursor % bpr + bpr - 1);
+        } else if k.top.matches(key_msg) {
+            self.goto(0);
+        } else if k.bottom.matches(key_msg) {
+            self.goto(self.data.len());
+        } else if k.switch_pane.matches(key_msg) {
+            self.pane = match self.pane {
+                Pane::Hex => Pane::Ascii,
+                Pane::Ascii => Pane::Hex,
+            };
+        } else if k.next_match.matches(key_msg) {
+            self.find_next();
+        } else if k.prev_match.matches(key_msg) {
+            self.find_prev();
+        }
+        None
+    }
+
+    /// Renders the rows visible in the viewport.
+    pub fn view(&self) -> String {
+        let top = self.viewport.y_offset;
+        let bottom = (top + self.rows_visible()).min(self.rows());
+        let rows: Vec<String> = (top..bottom).map(|r| self.render_row(r, true)).collect();
+        self.viewport.style.render(&rows.join("\n"))
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved cursor and scroll position of a hex view; see
+/// [`Stateful`](crate::state::Stateful). The data is not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Byte offset of the cursor.
+    pub cursor: usize,
+    /// The pane the cursor is focused in.
+    pub pane: Pane,
+    /// Rows scrolled from the top.
+    pub scroll: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            cursor: self.cursor,
+            pane: self.pane,
+            scroll: self.viewport.y_offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.pane = state.pane;
+        self.viewport.set_y_offset(state.scroll);
+        self.goto(state.cursor);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new(), 80, 24), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_row_layout() {
+        let hex = Model::new(b"ABCDEFGHIJ\x00\x01".to_vec(), 80, 10).with_bytes_per_row(8);
+        assert_eq!(
+            hex.viewport.lines(),
+            [
+                "00000000  41 42 43 44 45 46 47 48  |ABCDEFGH|",
+                "00000008  49 4a 00 01              |IJ..|",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("de ad BEEF"), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert_eq!(parse_hex("0x0a0b"), Ok(vec![0x0a, 0x0b]));
+        assert!(parse_hex("abc").is_err());
+        assert!(parse_hex("zz").is_err());
+        assert!(parse_hex(" ").is_err());
+    }
+
+    #[test]
+    fn test_search_wraps_in_both_directions() {
+        let mut hex = Model::new(b"ab..ab..ab".to_vec(), 80, 10);
+        hex.goto(1);
+        assert_eq!(hex.find(b"ab"), Some(4));
+        assert_eq!(hex.find_next(), Some(8));
+        assert_eq!(hex.find_next(), Some(0));
+        assert_eq!(hex.find_prev(), Some(8));
+        assert_eq!(hex.find(b"zz"), None);
+        assert_eq!(hex.cursor(), 8);
+    }
+
+    #[test]
+    fn test_cursor_keys_scroll_the_viewport() {
+        let mut hex = Model::new(vec![0; 64], 80, 4).with_bytes_per_row(4);
+        let visible = hex.rows_visible();
+        for _ in 0..visible {
+            hex.update(key(KeyCode::Down));
+        }
+        assert_eq!(hex.cursor(), visible * 4);
+        assert_eq!(hex.viewport.y_offset, 1);
+        hex.update(key(KeyCode::End));
+        assert_eq!(hex.cursor(), visible * 4 + 3);
+        hex.update(key(KeyCode::Char('G')));
+        assert_eq!(hex.cursor(), 63);
+        hex.update(key(KeyCode::Tab));
+        assert_eq!(hex.pane(), Pane::Ascii);
+        let view = strip_ansi(&hex.view());
+        assert!(view.lines().last().unwrap().starts_with("0000003c"));
+    }
+}
@@ -0,0 +1,528 @@
+//! Images in the terminal.
+//!
+//! Available with the `image` feature. The widget shows an RGBA or RGB
+//! pixel buffer, resized to fit inside `width` x `height` cells with its
+//! aspect ratio kept. Decoding files is left to the application; any
+//! decoder that yields raw pixels (such as the `image` crate's
+//! `to_rgba8()`) works.
+//!
+//! Three [`Protocol`]s are supported:
+//!
+//! - [`Protocol::Kitty`] sends the pixels with the kitty graphics protocol
+//!   (kitty, WezTerm, Ghostty).
+//! - [`Protocol::Sixel`] encodes them as sixels (foot, mlterm, xterm with
+//!   sixel support, …) using a 216-color palette.
+//! - [`Protocol::HalfBlock`] draws two pixels per cell with `▀` and
+//!   foreground/background colors, and works in any truecolor terminal.
+//!
+//! [`Protocol::detect`] picks one from the environment and is the default.
+//!
+//! Layout helpers measure the graphics escape sequences as text, so keep
+//! kitty and sixel images on lines of their own, or use half blocks when the
+//! image sits beside other content.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::image::{self, Protocol};
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! // A 4x4 red square, shown in a 10x2 box.
+//! let mut img = image::new(10, 2).with_protocol(Protocol::HalfBlock);
+//! img.set_rgba(4, 4, [255, 0, 0, 255].repeat(16)).unwrap();
+//!
+//! assert_eq!(img.fit(), (4, 2));
+//! assert_eq!(strip_ansi(&img.view()), "▀▀▀▀\n▀▀▀▀");
+//! ```
+
+use crate::error::{Error, Result};
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+
+/// Alpha below which a pixel counts as transparent.
+const ALPHA_THRESHOLD: u8 = 128;
+
+/// Largest payload sent in one kitty graphics escape sequence.
+const KITTY_CHUNK: usize = 4096;
+
+/// How the image reaches the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    /// The kitty graphics protocol.
+    Kitty,
+    /// DEC sixel graphics.
+    Sixel,
+    /// Unicode half blocks colored with truecolor escapes.
+    HalfBlock,
+}
+
+impl Protocol {
+    /// Guesses the best protocol from the environment: `KITTY_WINDOW_ID`,
+    /// `TERM` and `TERM_PROGRAM`. Falls back to half blocks.
+    pub fn detect() -> Self {
+        Self::detect_from(|name| std::env::var(name).ok())
+    }
+
+    fn detect_from(var: impl Fn(&str) -> Option<String>) -> Self {
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        if var("KITTY_WINDOW_ID").is_some()
+            || term.contains("kitty")
+            || term.contains("ghostty")
+            || matches!(program.as_str(), "WezTerm" | "ghostty")
+        {
+            Protocol::Kitty
+        } else if term.contains("sixel")
+            || term.starts_with("foot")
+            || term.starts_with("mlterm")
+            || term.starts_with("contour")
+        {
+            Protocol::Sixel
+        } else {
+            Protocol::HalfBlock
+        }
+    }
+}
+
+/// A decoded image.
+#[derive(Debug, Clone, Default)]
+struct Pixels {
+    width: usize,
+    height: usize,
+    /// RGBA, row by row.
+    data: Vec<u8>,
+}
+
+impl Pixels {
+    /// Resizes to `width` x `height` by averaging the source pixels each
+    /// target pixel covers.
+    fn resize(&self, width: usize, height: usize) -> Pixels {
+        let mut data = Vec::with_capacity(width * height * 4);
+        for ty in 0..height {
+            let y0 = ty * self.height / height;
+            let y1 = ((ty + 1) * self.height / height).max(y0 + 1);
+            for tx in 0..width {
+                let x0 = tx * self.width / width;
+                let x1 = ((tx + 1) * self.width / width).max(x0 + 1);
+                let mut sum = [0usize; 4];
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        let i = (y * self.width + x) * 4;
+                        for (s, v) in sum.iter_mut().zip(&self.data[i..i + 4]) {
+                            *s += *v as usize;
+                        }
+                    }
+                }
+                let n = (y1 - y0) * (x1 - x0);
+                data.extend(sum.iter().map(|s| (s / n) as u8));
+            }
+        }
+        Pixels {
+            width,
+            height,
+            data,
+        }
+    }
+
+    fn get(&self, x: usize, y: usize) -> [u8; 4] {
+        let i = (y * self.width + x) * 4;
+        [
+            self.data[i],
+            self.data[i + 1],
+            self.data[i + 2],
+            self.data[i + 3],
+        ]
+    }
+}
+
+/// An image drawn inside a box of terminal cells.
+#[derive(Debug, Clone)]
+pub struct Model {
+    pixels: Pixels,
+    /// Width of the box in columns.
+    pub width: usize,
+    /// Height of the box in rows.
+    pub height: usize,
+    /// How the image reaches the terminal.
+    pub protocol: Protocol,
+    /// Size of a terminal cell in pixels, used to keep the aspect ratio
+    /// and to size kitty and sixel images. Default: 10 x 20.
+    pub cell_size: (usize, usize),
+}
+
+/// Creates an empty image box `width` columns by `height` rows.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty image box `width` columns by `height` rows using
+    /// the detected protocol.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixels: Pixels::default(),
+            width,
+            height,
+            protocol: Protocol::detect(),
+            cell_size: (10, 20),
+        }
+    }
+
+    /// Sets the protocol.
+    pub fn with_protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Sets the size of a terminal cell in pixels.
+    pub fn with_cell_size(mut self, width: usize, height: usize) -> Self {
+        self.cell_size = (width.max(1), height.max(1));
+        self
+    }
+
+    /// Sets the box size in cells.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Shows an RGBA image `width` x `height` pixels large. Fails when
+    /// `data` is not `width * height * 4` bytes long.
+    pub fn set_rgba(&mut self, width: usize, height: usize, data: Vec<u8>) -> Result<()> {
+        if data.len() != width * height * 4 {
+            return Err(Error::Render(format!(
+                "expected {} bytes for a {}x{} RGBA image, got {}",
+                width * height * 4,
+                width,
+                height,
+                data.len()
+            )));
+        }
+        self.pixels = Pixels {
+            width,
+            height,
+            data,
+        };
+        Ok(())
+    }
+
+    /// Shows an opaque RGB image `width` x `height` pixels large. Fails when
+    /// `data` is not `width * height * 3` bytes long.
+    pub fn set_rgb(&mut self, width: usize, height: usize, data: &[u8]) -> Result<()> {
+        if data.len() != width * height * 3 {
+            return Err(Error::Render(format!(
+                "expected {} bytes for a {}x{} RGB image, got {}",
+                width * height * 3,
+                width,
+                height,
+                data.len()
+            )));
+        }
+        let rgba = data
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect();
+        self.set_rgba(width, height, rgba)
+    }
+
+    /// Removes the image.
+    pub fn clear(&mut self) {
+        self.pixels = Pixels::default();
+    }
+
+    /// Returns whether there is no image.
+    pub fn is_empty(&self) -> bool {
+        self.pixels.width == 0 || self.pixels.height == 0
+    }
+
+    /// Returns the columns and rows the image takes when fitted into the
+    /// box with its aspect ratio kept.
+    pub fn fit(&self) -> (usize, usize) {
+        if self.is_empty() || self.width == 0 || self.height == 0 {
+            return (0, 0);
+        }
+        let (cw, ch) = (self.cell_size.0 as f64, self.cell_size.1 as f64);
+        let (w, h) = (self.pixels.width as f64, self.pixels.height as f64);
+        let scale = (self.width as f64 * cw / w).min(self.height as f64 * ch / h);
+        let cols = ((w * scale / cw).round() as usize).clamp(1, self.width);
+        let rows = ((h * scale / ch).round() as usize).clamp(1, self.height);
+        (cols, rows)
+    }
+
+    /// Images are static and ignore messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the image with the configured protocol, or an empty string
+    /// when there is no image.
+    pub fn view(&self) -> String {
+        let (cols, rows) = self.fit();
+        if cols == 0 {
+            return String::new();
+        }
+        match self.protocol {
+            Protocol::HalfBlock => self.half_blocks(cols, rows),
+            Protocol::Kitty => {
+                let px = self.fitted_pixels(cols, rows);
+                reserve(kitty(&px, cols, rows), cols, rows)
+            }
+            Protocol::Sixel => {
+                let px = self.fitted_pixels(cols, rows);
+                reserve(sixel(&px), cols, rows)
+            }
+        }
+    }
+
+    /// The image resized to the pixel size of `cols` x `rows` cells, or
+    /// left alone when that would enlarge it.
+    fn fitted_pixels(&self, cols: usize, rows: usize) -> Pixels {
+        let (w, h) = (cols * self.cell_size.0, rows * self.cell_size.1);
+        if w >= self.pixels.width && h >= self.pixels.height {
+            self.pixels.clone()
+        } else {
+            self.pixels.resize(w.max(1), h.max(1))
+        }
+    }
+
+    fn half_blocks(&self, cols: usize, rows: usize) -> String {
+        let px = self.pixels.resize(cols, rows * 2);
+        let color =
+            |p: [u8; 4]| Color::from(format!("#{:02X}{:02X}{:02X}", p[0], p[1], p[2]).as_str());
+        (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|x| {
+                        let top = px.get(x, row * 2);
+                        let bottom = px.get(x, row * 2 + 1);
+                        match (top[3] >= ALPHA_THRESHOLD, bottom[3] >= ALPHA_THRESHOLD) {
+                            (true, true) => Style::new()
+                                .foreground(color(top))
+                                .background(color(bottom))
+                                .render("▀"),
+                            (true, false) => Style::new().foreground(color(top)).render("▀"),
+                            (false, true) => Style::new().foreground(color(bottom)).render("▄"),
+                            (false, false) => " ".to_string(),
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Puts a graphics sequence on the first of `rows` lines of `cols` spaces,
+/// so the surrounding layout leaves room for the image.
+fn reserve(sequence: String, cols: usize, rows: usize) -> String {
+    let blank = " ".repeat(cols);
+    let mut lines = vec![blank.clone(); rows];
+    lines[0] = format!("{}{}", sequence, blank);
+    lines.join("\n")
+}
+
+/// Encodes pixels as kitty graphics escapes that scale the image to
+/// `cols` x `rows` cells without moving the cursor.
+fn kitty(px: &Pixels, cols: usize, rows: usize) -> String {
+    let payload = base64(&px.data);
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = usize::from(i + 1 < chunks.len());
+        let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+        if i == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=32,s={},v={},c={},r={},C=1,q=2,m={};{}\x1b\\",
+                px.width, px.height, cols, rows, more, chunk
+            ));
+        } else {
+            out.push_str(&format!("\x1b_Gm={};{}\x1b\\", more, chunk));
+        }
+    }
+    out
+}
+
+/// Maps a channel to one of the six levels of the color cube.
+fn cube_level(v: u8) -> usize {
+    (v as usize * 5 + 127) / 255
+}
+
+/// Encodes pixels as a sixel image with a 6x6x6 color cube palette.
+/// Transparent pixels are left undrawn.
+fn sixel(px: &Pixels) -> String {
+    let index = |p: [u8; 4]| -> Option<usize> {
+        (p[3] >= ALPHA_THRESHOLD)
+            .then(|| cube_level(p[0]) * 36 + cube_level(p[1]) * 6 + cube_level(p[2]))
+    };
+    let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", px.width, px.height);
+    let mut used = [false; 216];
+    for y in 0..px.height {
+        for x in 0..px.width {
+            if let Some(i) = index(px.get(x, y)) {
+                used[i] = true;
+            }
+        }
+    }
+    for (i, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+        let percent = |level: usize| level * 100 / 5;
+        out.push_str(&format!(
+            "#{};2;{};{};{}",
+            i,
+            percent(i / 36),
+            percent(i / 6 % 6),
+            percent(i % 6)
+        ));
+    }
+    for band in (0..px.height).step_by(6) {
+        let mut colors: Vec<usize> = Vec::new();
+        let mut bits = vec![[0u8; 216]; px.width];
+        for (x, column) in bits.iter_mut().enumerate() {
+            for dy in 0..6.min(px.height - band) {
+                if let Some(i) = index(px.get(x, band + dy)) {
+                    if !colors.contains(&i) {
+                        colors.push(i);
+                    }
+                    column[i] |= 1 << dy;
+                }
+            }
+        }
+        for (n, &color) in colors.iter().enumerate() {
+            if n > 0 {
+                out.push('$');
+            }
+            out.push_str(&format!("#{}", color));
+            let row: Vec<u8> = bits.iter().map(|column| column[color]).collect();
+            let mut x = 0;
+            while x < row.len() {
+                let run = row[x..].iter().take_while(|&&b| b == row[x]).count();
+                let c = (63 + row[x]) as char;
+                if run > 3 {
+                    out.push_str(&format!("!{}{}", run, c));
+                } else {
+                    out.extend(std::iter::repeat_n(c, run));
+                }
+                x += run;
+            }
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Standard base64 with padding.
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(40, 20), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checker() -> Model {
+        let mut m = Model::new(4, 8).with_protocol(Protocol::HalfBlock);
+        let data = (0..16)
+            .flat_map(|i| {
+                if (i % 4 + i / 4) % 2 == 0 {
+                    [255, 255, 255, 255]
+                } else {
+                    [0, 0, 0, 0]
+                }
+            })
+            .collect();
+        m.set_rgba(4, 4, data).unwrap();
+        m
+    }
+
+    #[test]
+    fn test_fit_keeps_aspect_ratio() {
+        let mut m = Model::new(20, 5);
+        m.set_rgb(100, 50, &vec![0; 100 * 50 * 3]).unwrap();
+        // 2:1 pixels in cells twice as tall as wide: 4 columns per row.
+        assert_eq!(m.fit(), (20, 5));
+        m.set_size(8, 10);
+        assert_eq!(m.fit(), (8, 2));
+        assert!(m.set_rgba(2, 2, vec![0; 3]).is_err());
+        m.clear();
+        assert_eq!(m.view(), "");
+    }
+
+    #[test]
+    fn test_half_blocks_leave_transparent_pixels_blank() {
+        let m = checker().with_cell_size(1, 2);
+        let view = lipgloss_extras::lipgloss::strip_ansi(&m.view());
+        assert_eq!(view, "▀▄▀▄\n▀▄▀▄");
+    }
+
+    #[test]
+    fn test_graphics_protocols() {
+        let kitty = checker().with_protocol(Protocol::Kitty).view();
+        assert!(kitty.starts_with("\x1b_Ga=T,f=32,s=4,v=4,c=4,r=2,C=1,q=2,m=0;"));
+        assert_eq!(kitty.lines().count(), 2);
+        let sixel = checker().with_protocol(Protocol::Sixel).view();
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;4;4#215;2;100;100;100#215"));
+        assert!(sixel.contains("\x1b\\"));
+        assert_eq!(base64(b"Man"), "TWFu");
+        assert_eq!(base64(b"Ma"), "TWE=");
+        assert_eq!(base64(b"M"), "TQ==");
+    }
+
+    #[test]
+    fn test_detect() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+        assert_eq!(
+            Protocol::detect_from(env(&[("TERM", "xterm-kitty")])),
+            Protocol::Kitty
+        );
+        assert_eq!(
+            Protocol::detect_from(env(&[("TERM_PROGRAM", "WezTerm")])),
+            Protocol::Kitty
+        );
+        assert_eq!(
+            Protocol::detect_from(env(&[("TERM", "foot")])),
+            Protocol::Sixel
+        );
+        assert_eq!(
+            Protocol::detect_from(env(&[("TERM", "xterm-256color")])),
+            Protocol::HalfBlock
+        );
+    }
+}
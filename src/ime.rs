@@ -0,0 +1,95 @@
+//! Input method composition for the text input and text area.
+//!
+//! An input method builds a character from several keystrokes: a dead key
+//! followed by a letter makes an accented letter, and a CJK input method
+//! turns romanized keystrokes into candidates to pick from. Until the
+//! result is committed those keystrokes must not reach the text.
+//!
+//! Most terminals run the input method themselves and send only the
+//! committed text, which the widgets handle as ordinary keys or a paste.
+//! Hosts that see the composition, such as an embedding GUI, forward it as
+//! [`ImeMsg`]s instead. The text being composed is then drawn underlined at
+//! the cursor without being inserted, character keys are ignored while it
+//! is, and the committed text goes in as one undo step.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::ime::ImeMsg;
+//! use bubbletea_widgets::textinput;
+//!
+//! let mut input = textinput::new();
+//! input.focus();
+//!
+//! input.update(Box::new(ImeMsg::Preedit("にほ".into())));
+//! assert_eq!(input.value(), "");
+//! assert!(input.view().contains("にほ"));
+//!
+//! input.update(Box::new(ImeMsg::Commit("日本".into())));
+//! assert_eq!(input.value(), "日本");
+//! ```
+
+use bubbletea_rs::KeyMsg;
+use crossterm::event::{KeyCode, KeyModifiers};
+use lipgloss_extras::prelude::Style;
+
+/// A composition event from an input method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImeMsg {
+    /// The text being composed changed. It is shown at the cursor but not
+    /// inserted; empty text ends the composition without inserting
+    /// anything.
+    Preedit(String),
+    /// The composition finished with this text, which is inserted.
+    Commit(String),
+}
+
+/// The composition in progress in a widget.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Composition {
+    preedit: String,
+}
+
+impl Composition {
+    /// Applies `msg`, returning the text to insert once it is committed.
+    pub(crate) fn update(&mut self, msg: &ImeMsg) -> Option<String> {
+        match msg {
+            ImeMsg::Preedit(text) => {
+                self.preedit = text.clone();
+                None
+            }
+            ImeMsg::Commit(text) => {
+                self.preedit.clear();
+                Some(text.clone())
+            }
+        }
+    }
+
+    /// Whether `key` belongs to the input method: a character typed while
+    /// composing.
+    pub(crate) fn swallows(&self, key: &KeyMsg) -> bool {
+        !self.preedit.is_empty()
+            && matches!(key.key, KeyCode::Char(_))
+            && !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+    }
+
+    /// The text being composed, underlined, or nothing.
+    pub(crate) fn view(&self) -> String {
+        if self.preedit.is_empty() {
+            return String::new();
+        }
+        Style::new().underline(true).render(&self.preedit)
+    }
+
+    /// The display width of the text being composed.
+    pub(crate) fn width(&self) -> usize {
+        crate::text::width(&self.preedit)
+    }
+
+    /// Ends the composition without inserting anything.
+    pub(crate) fn cancel(&mut self) {
+        self.preedit.clear();
+    }
+}
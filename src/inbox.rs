@@ -0,0 +1,655 @@
+//! Notification center.
+//!
+//! Where [toasts](crate::toast) disappear after a few seconds, the inbox
+//! keeps notifications until they are dismissed. Each [`Notification`] has a
+//! severity, a timestamp and a read flag. The list shows unread items with
+//! a dot and the header carries a badge with the unread count;
+//! [`Model::unread_count`] and [`Model::badge`] expose the same information
+//! for a status bar.
+//!
+//! Notifications are added with [`Model::push`]. The inbox also records every
+//! [`toast::ShowMsg`](crate::toast::ShowMsg) passed to [`Model::update`], so
+//! forwarding messages to both a toast stack and an inbox keeps a history
+//! of everything that was shown. Turn this off with `capture_toasts`.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `↑`/`k`, `↓`/`j` | Move between notifications |
+//! | `enter` | Mark read and emit an [`OpenMsg`] |
+//! | `d`, `delete` | Dismiss the selected notification |
+//! | `R` | Mark everything read |
+//! | `D` | Dismiss everything |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::inbox;
+//! use bubbletea_widgets::toast::Level;
+//!
+//! let mut inbox = inbox::new(50, 10);
+//! let id = inbox.push(Level::Error, "Build failed");
+//! inbox.push(Level::Info, "Deploy finished");
+//! assert_eq!(inbox.unread_count(), 2);
+//!
+//! inbox.mark_read(id);
+//! assert_eq!(inbox.unread_count(), 1);
+//! assert!(inbox.view().contains("Deploy finished"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, format_time};
+use crate::theme::Theme;
+use crate::toast::{Level, ShowMsg};
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, SystemTime};
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// A stored notification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Identifier unique within the owning inbox.
+    pub id: u64,
+    /// Severity.
+    pub level: Level,
+    /// Text of the notification.
+    pub text: String,
+    /// When the notification arrived.
+    pub time: SystemTime,
+    /// Whether the notification has been read.
+    pub read: bool,
+}
+
+/// Message emitted when the user opens a notification with `enter`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenMsg {
+    /// The ID of the inbox.
+    pub id: i64,
+    /// The opened notification.
+    pub notification: Notification,
+}
+
+/// Key bindings for the inbox.
+#[derive(Debug, Clone)]
+pub struct InboxKeyMap {
+    /// Moves to the previous notification. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves to the next notification. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Marks the selected notification read and opens it. Default: `enter`.
+    pub open: key::Binding,
+    /// Dismisses the selected notification. Default: `d`, `delete`.
+    pub dismiss: key::Binding,
+    /// Marks every notification read. Default: `R`.
+    pub read_all: key::Binding,
+    /// Dismisses every notification. Default: `D`.
+    pub dismiss_all: key::Binding,
+}
+
+impl Default for InboxKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "k"]),
+                key::with_help("↑/k", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "j"]),
+                key::with_help("↓/j", "down"),
+            ]),
+            open: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "open"),
+            ]),
+            dismiss: key::new_binding(vec![
+                key::with_keys_str(&["d", "delete"]),
+                key::with_help("d", "dismiss"),
+            ]),
+            read_all: key::new_binding(vec![
+                key::with_keys_str(&["R"]),
+                key::with_help("R", "mark all read"),
+            ]),
+            dismiss_all: key::new_binding(vec![
+                key::with_keys_str(&["D"]),
+                key::with_help("D", "dismiss all"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for InboxKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.up, &self.down, &self.open, &self.dismiss]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.open],
+            vec![&self.dismiss, &self.read_all, &self.dismiss_all],
+        ]
+    }
+}
+
+/// Visual styles for the inbox.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The header title.
+    pub title: Style,
+    /// The unread count badge.
+    pub badge: Style,
+    /// Text of unread notifications.
+    pub unread: Style,
+    /// Text of read notifications.
+    pub read: Style,
+    /// The selected notification's text.
+    pub selected: Style,
+    /// Timestamps.
+    pub timestamp: Style,
+    /// Icon and unread dot of informational notifications.
+    pub info: Style,
+    /// Icon and unread dot of warnings.
+    pub warn: Style,
+    /// Icon and unread dot of errors.
+    pub error: Style,
+    /// The text shown when there are no notifications.
+    pub empty: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            title: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#1A1A1A",
+                    Dark: "#DDDDDD",
+                })
+                .bold(true),
+            badge: Style::new()
+                .foreground(Color::from("#FFFDF5"))
+                .background(Color::from("#FF5F87"))
+                .bold(true),
+            unread: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#1A1A1A",
+                    Dark: "#DDDDDD",
+                })
+                .bold(true),
+            read: Style::new().foreground(AdaptiveColor {
+                Light: "#847A85",
+                Dark: "#A49FA5",
+            }),
+            selected: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            timestamp: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            info: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#5A56E0",
+            }),
+            warn: Style::new().foreground(AdaptiveColor {
+                Light: "#B7791F",
+                Dark: "#F2C94C",
+            }),
+            error: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+            empty: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates inbox styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            title: theme.title.clone(),
+            badge: Style::new()
+                .foreground(p.background.clone())
+                .background(p.error.clone())
+                .bold(true),
+            unread: theme.text.clone().bold(true),
+            read: theme.muted.clone(),
+            selected: theme.selected.clone(),
+            timestamp: theme.subtle.clone(),
+            info: Style::new().foreground(p.secondary.clone()),
+            warn: theme.warning.clone(),
+            error: theme.error.clone(),
+            empty: theme.muted.clone(),
+        }
+    }
+
+    fn for_level(&self, level: Level) -> &Style {
+        match level {
+            Level::Info => &self.info,
+            Level::Warn => &self.warn,
+            Level::Error => &self.error,
+        }
+    }
+}
+
+/// A list of notifications, newest first.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    next_notification_id: u64,
+    items: Vec<Notification>,
+    cursor: usize,
+    offset: usize,
+    focus: bool,
+    /// Header title; empty for no header.
+    pub title: String,
+    /// Most notifications kept; the oldest are dropped beyond this.
+    pub max_items: usize,
+    /// Whether [`toast::ShowMsg`](crate::toast::ShowMsg)s are recorded.
+    pub capture_toasts: bool,
+    /// Width in columns.
+    pub width: usize,
+    /// Height in rows, including the header.
+    pub height: usize,
+    /// Key bindings.
+    pub keymap: InboxKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an empty, focused inbox.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty, focused inbox keeping up to 100 notifications.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            id: next_id(),
+            next_notification_id: 0,
+            items: Vec::new(),
+            cursor: 0,
+            offset: 0,
+            focus: true,
+            title: "Notifications".to_string(),
+            max_items: 100,
+            capture_toasts: true,
+            width,
+            height,
+            keymap: InboxKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the header title.
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    /// Sets the most notifications kept.
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items;
+        self.items.truncate(max_items);
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this inbox.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Adds an unread notification at the top and returns its ID.
+    pub fn push(&mut self, level: Level, text: &str) -> u64 {
+        self.next_notification_id += 1;
+        let id = self.next_notification_id;
+        self.items.insert(
+            0,
+            Notification {
+                id,
+                level,
+                text: text.to_string(),
+                time: SystemTime::now(),
+                read: false,
+            },
+        );
+        self.items.truncate(self.max_items);
+        // Keep the same notification selected as the list grows.
+        if self.cursor > 0 || self.offset > 0 {
+            self.cursor = (self.cursor + 1).min(self.items.len() - 1);
+            self.offset += 1;
+        }
+        id
+    }
+
+    /// Returns the notifications, newest first.
+    pub fn notifications(&self) -> &[Notification] {
+        &self.items
+    }
+
+    /// Returns the number of notifications.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether there are no notifications.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the number of unread notifications.
+    pub fn unread_count(&self) -> usize {
+        self.items.iter().filter(|n| !n.read).count()
+    }
+
+    /// Returns a styled unread count for a status bar, or an empty string
+    /// when everything is read.
+    pub fn badge(&self) -> String {
+        match self.unread_count() {
+            0 => String::new(),
+            n => self.styles.badge.render(&format!(" {} ", n)),
+        }
+    }
+
+    /// Returns the selected notification.
+    pub fn selected(&self) -> Option<&Notification> {
+        self.items.get(self.cursor)
+    }
+
+    /// Marks a notification read. Returns whether it was found.
+    pub fn mark_read(&mut self, id: u64) -> bool {
+        match self.items.iter_mut().find(|n| n.id == id) {
+            Some(n) => {
+                n.read = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Marks every notification read.
+    pub fn mark_all_read(&mut self) {
+        for n in &mut self.items {
+            n.read = true;
+        }
+    }
+
+    /// Removes a notification. Returns whether it was found.
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let Some(index) = self.items.iter().position(|n| n.id == id) else {
+            return false;
+        };
+        self.items.remove(index);
+        if index < self.cursor || self.cursor >= self.items.len() {
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+        self.scroll_to_cursor();
+        true
+    }
+
+    /// Removes every notification.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.cursor = 0;
+        self.offset = 0;
+    }
+
+    fn rows(&self) -> usize {
+        let header = usize::from(!self.title.is_empty());
+        self.height.saturating_sub(header).max(1)
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let rows = self.rows();
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        } else if self.cursor >= self.offset + rows {
+            self.offset = self.cursor + 1 - rows;
+        }
+        self.offset = self.offset.min(self.items.len().saturating_sub(rows));
+    }
+
+    /// Records toasts and, while focused, handles navigation and dismissal.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(show) = msg.downcast_ref::<ShowMsg>() {
+            if self.capture_toasts {
+                self.push(show.level, &show.text);
+            }
+            return None;
+        }
+        if !self.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if self.keymap.read_all.matches(key_msg) {
+            self.mark_all_read();
+        } else if self.keymap.dismiss_all.matches(key_msg) {
+            self.clear();
+        }
+        if self.items.is_empty() {
+            return None;
+        }
+        if self.keymap.up.matches(key_msg) {
+            self.cursor = self.cursor.saturating_sub(1);
+            self.scroll_to_cursor();
+        } else if self.keymap.down.matches(key_msg) {
+            self.cursor = (self.cursor + 1).min(self.items.len() - 1);
+            self.scroll_to_cursor();
+        } else if self.keymap.dismiss.matches(key_msg) {
+            let id = self.items[self.cursor].id;
+            self.dismiss(id);
+        } else if self.keymap.open.matches(key_msg) {
+            let item = &mut self.items[self.cursor];
+            item.read = true;
+            let (id, notification) = (self.id, item.clone());
+            return Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+                Box::new(OpenMsg {
+                    id,
+                    notification: notification.clone(),
+                }) as Msg
+            }));
+        }
+        None
+    }
+
+    /// Renders the header and the visible notifications.
+    pub fn view(&self) -> String {
+        let s = &self.styles;
+        let mut lines = Vec::new();
+        if !self.title.is_empty() {
+            let badge = self.badge();
+            lines.push(if badge.is_empty() {
+                s.title.render(&self.title)
+            } else {
+                format!("{} {}", s.title.render(&self.title), badge)
+            });
+        }
+        if self.items.is_empty() {
+            lines.push(s.empty.render("No notifications"));
+            return lines.join("\n");
+        }
+        let end = (self.offset + self.rows()).min(self.items.len());
+        for (i, n) in self.items.iter().enumerate().take(end).skip(self.offset) {
+            let level_style = s.for_level(n.level);
+            let dot = if n.read {
+                " ".to_string()
+            } else {
+                level_style.render("●")
+            };
+            let icon = level_style.render(match n.level {
+                Level::Info => "i",
+                Level::Warn => "!",
+                Level::Error => "✗",
+            });
+            let time = format_time(n.time);
+            // Dot, icon, the spaces around them and before the time.
+            let avail = self.width.saturating_sub(text::width(&time) + 5);
+            let summary = first_line(&n.text, avail);
+            let text_style = if i == self.cursor && self.focus {
+                &s.selected
+            } else if n.read {
+                &s.read
+            } else {
+                &s.unread
+            };
+            let pad = avail.saturating_sub(text::width(&summary));
+            lines.push(format!(
+                "{} {} {}{} {}",
+                dot,
+                icon,
+                text_style.render(&summary),
+                " ".repeat(pad),
+                s.timestamp.render(&time)
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Keeps the first line of `s`, truncated to `width` columns. Multi-line
+/// text always ends with `…` to show that there is more.
+fn first_line(s: &str, width: usize) -> String {
+    let first = s.lines().next().unwrap_or("");
+    if first.len() == s.trim_end().len() {
+        text::truncate(first, width)
+    } else {
+        text::truncate(&format!("{}{}", first, text::ELLIPSIS), width)
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(60, 10), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_read_state_and_badge() {
+        let mut inbox = Model::new(40, 5);
+        assert_eq!(inbox.badge(), "");
+        let first = inbox.push(Level::Warn, "Disk 90% full");
+        inbox.push(Level::Info, "Backup done");
+        assert_eq!(strip_ansi(&inbox.badge()), " 2 ");
+        assert_eq!(inbox.notifications()[0].text, "Backup done");
+        assert!(inbox.mark_read(first));
+        assert!(!inbox.mark_read(999));
+        assert_eq!(inbox.unread_count(), 1);
+        inbox.update(key(KeyCode::Char('R')));
+        assert_eq!(inbox.unread_count(), 0);
+    }
+
+    #[test]
+    fn test_keys_open_and_dismiss() {
+        let mut inbox = Model::new(40, 5);
+        inbox.push(Level::Info, "a");
+        inbox.push(Level::Info, "b");
+        inbox.push(Level::Info, "c");
+        inbox.update(key(KeyCode::Down));
+        assert!(inbox.update(key(KeyCode::Enter)).is_some());
+        assert!(inbox.selected().unwrap().read);
+        inbox.update(key(KeyCode::Char('d')));
+        let texts: Vec<&str> = inbox
+            .notifications()
+            .iter()
+            .map(|n| n.text.as_str())
+            .collect();
+        assert_eq!(texts, ["c", "a"]);
+        assert_eq!(inbox.selected().unwrap().text, "a");
+        inbox.update(key(KeyCode::Char('D')));
+        assert!(inbox.is_empty());
+        assert!(strip_ansi(&inbox.view()).ends_with("No notifications"));
+    }
+
+    #[test]
+    fn test_captures_toasts_and_caps_history() {
+        let mut inbox = Model::new(40, 5).with_max_items(2);
+        for text in ["one", "two", "three"] {
+            inbox.update(Box::new(ShowMsg {
+                level: Level::Error,
+                text: text.to_string(),
+                duration: None,
+            }));
+        }
+        assert_eq!(inbox.len(), 2);
+        assert_eq!(inbox.notifications()[1].text, "two");
+        inbox.capture_toasts = false;
+        inbox.update(Box::new(ShowMsg {
+            level: Level::Info,
+            text: "ignored".to_string(),
+            duration: None,
+        }));
+        assert_eq!(inbox.len(), 2);
+    }
+
+    #[test]
+    fn test_row_layout() {
+        let mut inbox = Model::new(30, 5).with_title("");
+        inbox.push(Level::Error, "A very long notification text");
+        let view = strip_ansi(&inbox.view());
+        assert!(view.starts_with("● ✗ A very long noti… "));
+        assert_eq!(text::width(&view), 30);
+    }
+}
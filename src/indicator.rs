@@ -0,0 +1,355 @@
+//! Status indicator.
+//!
+//! An indicator is the colored dot that dashboards put next to a service,
+//! job or connection: green for ok, yellow for a warning, red for an error
+//! and grey when the state is unknown. An optional label follows the dot.
+//!
+//! While the [`Status`] is [`Busy`](Status::Busy) the dot blinks. The blink
+//! follows the same pattern as the spinner: [`Model::set_status`] returns
+//! the command that starts it, and [`TickMsg`]s forwarded to
+//! [`Model::update`] keep it going until the status changes.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::indicator::{self, Status};
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut db = indicator::new(Status::Ok).with_label("postgres");
+//! assert_eq!(strip_ansi(&db.view()), "● postgres");
+//!
+//! let blink = db.set_status(Status::Busy);
+//! assert!(blink.is_some());
+//! ```
+
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// The state an indicator shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// Everything is fine.
+    Ok,
+    /// Something needs attention.
+    Warn,
+    /// Something is broken.
+    Error,
+    /// The state is not known yet.
+    #[default]
+    Unknown,
+    /// Work is in progress; the glyph blinks.
+    Busy,
+}
+
+/// Message toggling the blink of a busy indicator.
+#[derive(Debug, Clone, Copy)]
+pub struct TickMsg {
+    /// The ID of the indicator this tick belongs to.
+    pub id: i64,
+    tag: i64,
+}
+
+/// The glyph drawn for each status.
+#[derive(Debug, Clone)]
+pub struct Glyphs {
+    /// Glyph for [`Status::Ok`]. Default: `●`.
+    pub ok: String,
+    /// Glyph for [`Status::Warn`]. Default: `●`.
+    pub warn: String,
+    /// Glyph for [`Status::Error`]. Default: `●`.
+    pub error: String,
+    /// Glyph for [`Status::Unknown`]. Default: `○`.
+    pub unknown: String,
+    /// Glyph for [`Status::Busy`]. Default: `●`.
+    pub busy: String,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self {
+            ok: "●".to_string(),
+            warn: "●".to_string(),
+            error: "●".to_string(),
+            unknown: "○".to_string(),
+            busy: "●".to_string(),
+        }
+    }
+}
+
+impl Glyphs {
+    fn for_status(&self, status: Status) -> &str {
+        match status {
+            Status::Ok => &self.ok,
+            Status::Warn => &self.warn,
+            Status::Error => &self.error,
+            Status::Unknown => &self.unknown,
+            Status::Busy => &self.busy,
+        }
+    }
+}
+
+/// Visual styles for the indicator.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The glyph for [`Status::Ok`].
+    pub ok: Style,
+    /// The glyph for [`Status::Warn`].
+    pub warn: Style,
+    /// The glyph for [`Status::Error`].
+    pub error: Style,
+    /// The glyph for [`Status::Unknown`].
+    pub unknown: Style,
+    /// The glyph for [`Status::Busy`].
+    pub busy: Style,
+    /// The label.
+    pub label: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            ok: Style::new().foreground(AdaptiveColor {
+                Light: "#2E8B57",
+                Dark: "#04B575",
+            }),
+            warn: Style::new().foreground(AdaptiveColor {
+                Light: "#B7791F",
+                Dark: "#F2C94C",
+            }),
+            error: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+            unknown: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            busy: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#5A56E0",
+            }),
+            label: Style::new().foreground(AdaptiveColor {
+                Light: "#1A1A1A",
+                Dark: "#DDDDDD",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates indicator styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            ok: theme.success.clone(),
+            warn: theme.warning.clone(),
+            error: theme.error.clone(),
+            unknown: theme.muted.clone(),
+            busy: Style::new().foreground(theme.palette.secondary.clone()),
+            label: theme.text.clone(),
+        }
+    }
+
+    fn for_status(&self, status: Status) -> &Style {
+        match status {
+            Status::Ok => &self.ok,
+            Status::Warn => &self.warn,
+            Status::Error => &self.error,
+            Status::Unknown => &self.unknown,
+            Status::Busy => &self.busy,
+        }
+    }
+}
+
+/// A colored status glyph with an optional label.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    tag: i64,
+    status: Status,
+    lit: bool,
+    /// Text after the glyph; empty for none.
+    pub label: String,
+    /// Time between blinks while busy.
+    pub interval: Duration,
+    /// Glyphs for each status.
+    pub glyphs: Glyphs,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an indicator showing `status`.
+pub fn new(status: Status) -> Model {
+    Model::new(status)
+}
+
+impl Model {
+    /// Creates an indicator showing `status`. A busy indicator starts
+    /// blinking once the command from [`tick`](Self::tick) is run.
+    pub fn new(status: Status) -> Self {
+        Self {
+            id: next_id(),
+            tag: 0,
+            status,
+            lit: true,
+            label: String::new(),
+            interval: Duration::from_millis(500),
+            glyphs: Glyphs::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the label.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Sets the time between blinks while busy.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the glyphs.
+    pub fn with_glyphs(mut self, glyphs: Glyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this indicator.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the status shown.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Changes the status. Returns the command that starts blinking when
+    /// the indicator becomes busy; any other change stops the blink.
+    pub fn set_status(&mut self, status: Status) -> Option<Cmd> {
+        if status == self.status {
+            return None;
+        }
+        self.status = status;
+        self.lit = true;
+        // Invalidate ticks already in flight.
+        self.tag += 1;
+        (status == Status::Busy).then(|| self.tick())
+    }
+
+    /// Sets the label.
+    pub fn set_label(&mut self, label: &str) {
+        self.label = label.to_string();
+    }
+
+    /// Returns a command that delivers the next [`TickMsg`].
+    pub fn tick(&self) -> Cmd {
+        let (id, tag) = (self.id, self.tag);
+        bubbletea_tick(self.interval, move |_| Box::new(TickMsg { id, tag }) as Msg)
+    }
+
+    /// Toggles the blink on this indicator's [`TickMsg`]s while busy and
+    /// schedules the next one.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        let tick = msg.downcast_ref::<TickMsg>()?;
+        if tick.id != self.id || tick.tag != self.tag || self.status != Status::Busy {
+            return None;
+        }
+        self.tag += 1;
+        self.lit = !self.lit;
+        Some(self.tick())
+    }
+
+    /// Renders the glyph, or a blank of the same width while blinked off,
+    /// followed by the label.
+    pub fn view(&self) -> String {
+        let glyph = self.glyphs.for_status(self.status);
+        let glyph = if self.lit {
+            self.styles.for_status(self.status).render(glyph)
+        } else {
+            " ".repeat(text::width(glyph))
+        };
+        if self.label.is_empty() {
+            glyph
+        } else {
+            format!("{} {}", glyph, self.styles.label.render(&self.label))
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Status::Unknown), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_glyph_per_status() {
+        let mut m = Model::new(Status::Unknown);
+        assert_eq!(strip_ansi(&m.view()), "○");
+        assert!(m.set_status(Status::Error).is_none());
+        assert_eq!(m.status(), Status::Error);
+        m.glyphs.error = "✗".to_string();
+        m.set_label("api");
+        assert_eq!(strip_ansi(&m.view()), "✗ api");
+    }
+
+    #[test]
+    fn test_busy_blinks_until_status_changes() {
+        let mut m = Model::new(Status::Ok).with_label("sync");
+        assert!(m.set_status(Status::Busy).is_some());
+        let tick = TickMsg {
+            id: m.id(),
+            tag: m.tag,
+        };
+        assert!(m.update(Box::new(tick)).is_some());
+        assert_eq!(strip_ansi(&m.view()), "  sync");
+        // A stale tick is ignored.
+        assert!(m.update(Box::new(tick)).is_none());
+
+        m.set_status(Status::Ok);
+        let tick = TickMsg {
+            id: m.id(),
+            tag: m.tag,
+        };
+        assert!(m.update(Box::new(tick)).is_none());
+        assert_eq!(strip_ansi(&m.view()), "● sync");
+    }
+}
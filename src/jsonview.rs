@@ -0,0 +1,880 @@
+//! Collapsible JSON tree viewer.
+//!
+//! Available with the `json` feature. The viewer renders a
+//! [`serde_json::Value`] as an indented tree whose objects and arrays can be
+//! expanded and collapsed, colors scalars by type, and shows the path of the
+//! node under the cursor (for example `$.users[0].name`) as a breadcrumb.
+//! The path or value of the current node can be copied to the system
+//! clipboard.
+//!
+//! Object keys are shown in `serde_json`'s map order, which is sorted unless
+//! `serde_json`'s `preserve_order` feature is enabled.
+//!
+//! # Navigation Controls
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `↑`/`k`, `↓`/`j` | Move the cursor |
+//! | `→`/`l` | Expand the node |
+//! | `←`/`h` | Collapse the node, or move to its parent |
+//! | `enter`, `space` | Toggle the node |
+//! | `E` / `C` | Expand / collapse everything |
+//! | `PgUp`, `PgDn`, `g`/`Home`, `G`/`End` | Move by a page / to either end |
+//! | `y` / `Y` | Copy the path / value of the node |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::jsonview;
+//!
+//! let mut tree = jsonview::new(40, 10);
+//! tree.set_json(r#"{"users": [{"name": "Ada"}, {"name": "Linus"}]}"#).unwrap();
+//!
+//! tree.expand_all();
+//! tree.select_path("/users/1/name");
+//! assert_eq!(tree.path(), "$.users[1].name");
+//! assert_eq!(tree.current_value().unwrap(), "Linus");
+//! ```
+
+use crate::clipboard::Clipboard;
+use crate::error::{Error, Result};
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// What a copy command placed on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyTarget {
+    /// The path of the node, such as `$.users[0].name`.
+    Path,
+    /// The node's value as JSON.
+    Value,
+}
+
+/// Message emitted after text was copied to the clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopiedMsg {
+    /// The ID of the viewer that copied.
+    pub id: i64,
+    /// What was copied.
+    pub target: CopyTarget,
+    /// The copied text.
+    pub text: String,
+}
+
+/// Message emitted when copying to the clipboard failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyErrMsg {
+    /// The ID of the viewer that tried to copy.
+    pub id: i64,
+    /// Why copying failed.
+    pub error: Error,
+}
+
+/// Key bindings for the JSON viewer.
+#[derive(Debug, Clone)]
+pub struct JsonViewKeyMap {
+    /// Moves up. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves down. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Expands the node. Default: `→`, `l`.
+    pub expand: key::Binding,
+    /// Collapses the node or moves to its parent. Default: `←`, `h`.
+    pub collapse: key::Binding,
+    /// Toggles the node. Default: `enter`, `space`.
+    pub toggle: key::Binding,
+    /// Expands every node. Default: `E`.
+    pub expand_all: key::Binding,
+    /// Collapses every node. Default: `C`.
+    pub collapse_all: key::Binding,
+    /// Moves up a page. Default: `pgup`.
+    pub page_up: key::Binding,
+    /// Moves down a page. Default: `pgdown`.
+    pub page_down: key::Binding,
+    /// Jumps to the first node. Default: `g`, `home`.
+    pub top: key::Binding,
+    /// Jumps to the last node. Default: `G`, `end`.
+    pub bottom: key::Binding,
+    /// Copies the path of the node. Default: `y`.
+    pub copy_path: key::Binding,
+    /// Copies the value of the node. Default: `Y`.
+    pub copy_value: key::Binding,
+}
+
+impl Default for JsonViewKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "k"]),
+                key::with_help("↑/k", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "j"]),
+                key::with_help("↓/j", "down"),
+            ]),
+            expand: key::new_binding(vec![
+                key::with_keys_str(&["right", "l"]),
+                key::with_help("→/l", "expand"),
+            ]),
+            collapse: key::new_binding(vec![
+                key::with_keys_str(&["left", "h"]),
+                key::with_help("←/h", "collapse"),
+            ]),
+            toggle: key::new_binding(vec![
+                key::with_keys_str(&["enter", "space"]),
+                key::with_help("enter", "toggle"),
+            ]),
+            expand_all: key::new_binding(vec![
+                key::with_keys_str(&["E"]),
+                key::with_help("E", "expand all"),
+            ]),
+            collapse_all: key::new_binding(vec![
+                key::with_keys_str(&["C"]),
+                key::with_help("C", "collapse all"),
+            ]),
+            page_up: key::new_binding(vec![
+                key::with_keys_str(&["pgup"]),
+                key::with_help("pgup", "page up"),
+            ]),
+            page_down: key::new_binding(vec![
+                key::with_keys_str(&["pgdown"]),
+                key::with_help("pgdown", "page down"),
+            ]),
+            top: key::new_binding(vec![
+                key::with_keys_str(&["g", "home"]),
+                key::with_help("g", "top"),
+            ]),
+            bottom: key::new_binding(vec![
+                key::with_keys_str(&["G", "end"]),
+                key::with_help("G", "bottom"),
+            ]),
+            copy_path: key::new_binding(vec![
+                key::with_keys_str(&["y"]),
+                key::with_help("y", "copy path"),
+            ]),
+            copy_value: key::new_binding(vec![
+                key::with_keys_str(&["Y"]),
+                key::with_help("Y", "copy value"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for JsonViewKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.up, &self.down, &self.toggle, &self.copy_path]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.page_up, &self.page_down],
+            vec![&self.top, &self.bottom],
+            vec![&self.expand, &self.collapse, &self.toggle],
+            vec![&self.expand_all, &self.collapse_all],
+            vec![&self.copy_path, &self.copy_value],
+        ]
+    }
+}
+
+/// Visual styles for the JSON viewer.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The path breadcrumb above the tree.
+    pub path: Style,
+    /// The cursor marker.
+    pub cursor: Style,
+    /// Object keys.
+    pub key: Style,
+    /// Array indices.
+    pub index: Style,
+    /// String values.
+    pub string: Style,
+    /// Number values.
+    pub number: Style,
+    /// `true` and `false`.
+    pub boolean: Style,
+    /// `null`.
+    pub null: Style,
+    /// Brackets, expand markers and item counts.
+    pub punctuation: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            path: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+            cursor: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            key: Style::new().foreground(AdaptiveColor {
+                Light: "#0077B6",
+                Dark: "#00AFFF",
+            }),
+            index: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            string: Style::new().foreground(AdaptiveColor {
+                Light: "#2E8B57",
+                Dark: "#04B575",
+            }),
+            number: Style::new().foreground(AdaptiveColor {
+                Light: "#B7791F",
+                Dark: "#F2C94C",
+            }),
+            boolean: Style::new().foreground(AdaptiveColor {
+                Light: "#D75F00",
+                Dark: "#FF875F",
+            }),
+            null: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#909090",
+                    Dark: "#626262",
+                })
+                .italic(true),
+            punctuation: Style::new().foreground(AdaptiveColor {
+                Light: "#6E6E6E",
+                Dark: "#808080",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates JSON viewer styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            path: Style::new().foreground(theme.palette.secondary.clone()),
+            cursor: theme.selected.clone(),
+            key: theme.accent.clone(),
+            index: theme.subtle.clone(),
+            string: theme.success.clone(),
+            number: theme.warning.clone(),
+            boolean: theme.error.clone(),
+            null: theme.muted.clone().italic(true),
+            punctuation: theme.muted.clone(),
+        }
+    }
+}
+
+/// A visible line of the tree.
+#[derive(Debug, Clone)]
+struct Row {
+    /// JSON Pointer of the node, `""` for the root.
+    pointer: String,
+    /// Human-readable path, such as `$.users[0]`.
+    path: String,
+    /// Object key or array index leading to the node.
+    label: Option<Label>,
+    depth: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Label {
+    Key(String),
+    Index(usize),
+}
+
+/// A collapsible JSON tree viewer.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    value: Value,
+    expanded: HashSet<String>,
+    rows: Vec<Row>,
+    cursor: usize,
+    offset: usize,
+    /// Width in columns; long strings are shortened to fit. Zero disables
+    /// shortening.
+    pub width: usize,
+    /// Number of tree rows shown, excluding the breadcrumb.
+    pub height: usize,
+    /// Whether the path breadcrumb is shown above the tree.
+    pub show_path: bool,
+    /// Key bindings.
+    pub keymap: JsonViewKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+    clipboard: Clipboard,
+}
+
+/// Creates a viewer with the given size showing `null`.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates a viewer with the given size showing `null`.
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut m = Self {
+            id: next_id(),
+            value: Value::Null,
+            expanded: HashSet::new(),
+            rows: Vec::new(),
+            cursor: 0,
+            offset: 0,
+            width,
+            height,
+            show_path: true,
+            keymap: JsonViewKeyMap::default(),
+            styles: Styles::default(),
+            clipboard: Clipboard::default(),
+        };
+        m.set_value(Value::Null);
+        m
+    }
+
+    /// Returns the unique ID of this viewer.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Sets the clipboard copies go to, in place of the default one.
+    pub fn with_clipboard(mut self, clipboard: Clipboard) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
+    /// Replaces the document. Only the root starts expanded.
+    pub fn set_value(&mut self, value: Value) {
+        self.value = value;
+        self.expanded.clear();
+        self.expanded.insert(String::new());
+        self.cursor = 0;
+        self.offset = 0;
+        self.rebuild();
+    }
+
+    /// Parses `text` as JSON and displays it.
+    pub fn set_json(&mut self, text: &str) -> Result<()> {
+        let value = serde_json::from_str(text)
+            .map_err(|e| Error::Validation(format!("invalid JSON: {}", e)))?;
+        self.set_value(value);
+        Ok(())
+    }
+
+    /// Returns the document.
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Returns the index of the cursor among the visible rows.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the number of visible rows.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the JSON Pointer of the node under the cursor, such as
+    /// `/users/0/name`.
+    pub fn pointer(&self) -> &str {
+        self.rows
+            .get(self.cursor)
+            .map_or("", |r| r.pointer.as_str())
+    }
+
+    /// Returns the readable path of the node under the cursor, such as
+    /// `$.users[0].name`.
+    pub fn path(&self) -> &str {
+        self.rows.get(self.cursor).map_or("$", |r| r.path.as_str())
+    }
+
+    /// Returns the node under the cursor.
+    pub fn current_value(&self) -> Option<&Value> {
+        self.value.pointer(self.pointer())
+    }
+
+    /// Returns whether the node at `pointer` is expanded.
+    pub fn is_expanded(&self, pointer: &str) -> bool {
+        self.expanded.contains(pointer)
+    }
+
+    /// Moves the cursor to the node at `pointer`, expanding its ancestors.
+    /// Returns `false` if no such node exists.
+    pub fn select_path(&mut self, pointer: &str) -> bool {
+        if self.value.pointer(pointer).is_none() {
+            return false;
+        }
+        let mut ancestor = pointer;
+        while let Some(pos) = ancestor.rfind('/') {
+            ancestor = &ancestor[..pos];
+            self.expanded.insert(ancestor.to_string());
+        }
+        self.rebuild();
+        if let Some(i) = self.rows.iter().position(|r| r.pointer == pointer) {
+            self.cursor = i;
+            self.scroll_to_cursor();
+        }
+        true
+    }
+
+    /// Expands the node under the cursor.
+    pub fn expand(&mut self) {
+        let pointer = self.pointer().to_string();
+        if self.value.pointer(&pointer).is_some_and(is_container) {
+            self.expanded.insert(pointer);
+            self.rebuild();
+        }
+    }
+
+    /// Collapses the node under the cursor, or moves to its parent if it is
+    /// already collapsed or a scalar.
+    pub fn collapse(&mut self) {
+        let pointer = self.pointer().to_string();
+        if self.expanded.remove(&pointer) {
+            self.rebuild();
+            return;
+        }
+        if let Some(pos) = pointer.rfind('/') {
+            let parent = &pointer[..pos];
+            if let Some(i) = self.rows.iter().position(|r| r.pointer == parent) {
+                self.cursor = i;
+                self.scroll_to_cursor();
+            }
+        }
+    }
+
+    /// Expands or collapses the node under the cursor.
+    pub fn toggle(&mut self) {
+        if self.is_expanded(self.pointer()) {
+            self.collapse();
+        } else {
+            self.expand();
+        }
+    }
+
+    /// Expands every object and array.
+    pub fn expand_all(&mut self) {
+        let mut pointers = Vec::new();
+        collect_containers(&self.value, String::new(), &mut pointers);
+        self.expanded.extend(pointers);
+        self.rebuild();
+    }
+
+    /// Collapses everything except the root and moves to the top.
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+        self.expanded.insert(String::new());
+        self.cursor = 0;
+        self.rebuild();
+    }
+
+    /// Moves the cursor by `delta` rows, clamped to the tree.
+    pub fn move_cursor(&mut self, delta: isize) {
+        let last = self.rows.len().saturating_sub(1) as isize;
+        self.cursor = (self.cursor as isize + delta).clamp(0, last) as usize;
+        self.scroll_to_cursor();
+    }
+
+    /// Returns a command copying the path of the node to the clipboard.
+    pub fn copy_path(&self) -> Cmd {
+        copy(
+            &self.clipboard,
+            self.id,
+            CopyTarget::Path,
+            self.path().to_string(),
+        )
+    }
+
+    /// Returns a command copying the value of the node, pretty-printed as
+    /// JSON, to the clipboard.
+    pub fn copy_value(&self) -> Cmd {
+        let text = self
+            .current_value()
+            .and_then(|v| serde_json::to_string_pretty(v).ok())
+            .unwrap_or_default();
+        copy(&self.clipboard, self.id, CopyTarget::Value, text)
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let height = self.height.max(1);
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        } else if self.cursor >= self.offset + height {
+            self.offset = self.cursor + 1 - height;
+        }
+    }
+
+    /// Recomputes the visible rows, keeping the cursor on the same node
+    /// when it is still visible.
+    fn rebuild(&mut self) {
+        let current = self.rows.get(self.cursor).map(|r| r.pointer.clone());
+        let mut rows = Vec::new();
+        self.walk(
+            &self.value,
+            String::new(),
+            "$".to_string(),
+            None,
+            0,
+            &mut rows,
+        );
+        self.rows = rows;
+        if let Some(i) = current.and_then(|p| self.rows.iter().position(|r| r.pointer == p)) {
+            self.cursor = i;
+        }
+        self.cursor = self.cursor.min(self.rows.len().saturating_sub(1));
+        self.scroll_to_cursor();
+    }
+
+    fn walk(
+        &self,
+        value: &Value,
+        pointer: String,
+        path: String,
+        label: Option<Label>,
+        depth: usize,
+        rows: &mut Vec<Row>,
+    ) {
+        let expanded = self.expanded.contains(&pointer);
+        rows.push(Row {
+            pointer: pointer.clone(),
+            path: path.clone(),
+            label,
+            depth,
+        });
+        if !expanded {
+            return;
+        }
+        match value {
+            Value::Object(map) => {
+                for (k, v) in map {
+                    let child_pointer = format!("{}/{}", pointer, escape_pointer(k));
+                    let child_path = if is_identifier(k) {
+                        format!("{}.{}", path, k)
+                    } else {
+                        format!("{}[{}]", path, Value::String(k.clone()))
+                    };
+                    let label = Some(Label::Key(k.clone()));
+                    self.walk(v, child_pointer, child_path, label, depth + 1, rows);
+                }
+            }
+            Value::Array(items) => {
+                for (i, v) in items.iter().enumerate() {
+                    let child_pointer = format!("{}/{}", pointer, i);
+                    let child_path = format!("{}[{}]", path, i);
+                    self.walk(
+                        v,
+                        child_pointer,
+                        child_path,
+                        Some(Label::Index(i)),
+                        depth + 1,
+                        rows,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn render_row(&self, index: usize, row: &Row) -> String {
+        let s = &self.styles;
+        let Some(value) = self.value.pointer(&row.pointer) else {
+            return String::new();
+        };
+        let mut out = String::new();
+        out.push_str(&if index == self.cursor {
+            s.cursor.render("> ")
+        } else {
+            "  ".to_string()
+        });
+        out.push_str(&"  ".repeat(row.depth));
+        if is_container(value) {
+            let marker = if self.is_expanded(&row.pointer) {
+                "▾ "
+            } else {
+                "▸ "
+            };
+            out.push_str(&s.punctuation.render(marker));
+        } else {
+            out.push_str("  ");
+        }
+        let label = match &row.label {
+            Some(Label::Key(k)) => format!("{}: ", Value::String(k.clone())),
+            Some(Label::Index(i)) => format!("{}: ", i),
+            None => String::new(),
+        };
+        match &row.label {
+            Some(Label::Key(_)) => {
+                out.push_str(&s.key.render(label.trim_end_matches(": ")));
+                out.push_str(&s.punctuation.render(": "));
+            }
+            Some(Label::Index(_)) => out.push_str(&s.index.render(&label)),
+            None => {}
+        }
+        let used = 4 + row.depth * 2 + text::width(&label);
+        out.push_str(&match value {
+            Value::Object(map) => s.punctuation.render(&format!("{{…}} {} keys", map.len())),
+            Value::Array(items) => s.punctuation.render(&format!("[…] {} items", items.len())),
+            Value::String(_) => s.string.render(&fit(&value.to_string(), self.width, used)),
+            Value::Number(n) => s.number.render(&n.to_string()),
+            Value::Bool(b) => s.boolean.render(&b.to_string()),
+            Value::Null => s.null.render("null"),
+        });
+        out
+    }
+
+    /// Handles navigation, folding and copy keys.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let page = self.height.max(1) as isize;
+        if self.keymap.up.matches(key_msg) {
+            self.move_cursor(-1);
+        } else if self.keymap.down.matches(key_msg) {
+            self.move_cursor(1);
+        } else if self.keymap.page_up.matches(key_msg) {
+            self.move_cursor(-page);
+        } else if self.keymap.page_down.matches(key_msg) {
+            self.move_cursor(page);
+        } else if self.keymap.top.matches(key_msg) {
+            self.move_cursor(-(self.cursor as isize));
+        } else if self.keymap.bottom.matches(key_msg) {
+            self.move_cursor(self.rows.len() as isize);
+        } else if self.keymap.expand.matches(key_msg) {
+            self.expand();
+        } else if self.keymap.collapse.matches(key_msg) {
+            self.collapse();
+        } else if self.keymap.toggle.matches(key_msg) {
+            self.toggle();
+        } else if self.keymap.expand_all.matches(key_msg) {
+            self.expand_all();
+        } else if self.keymap.collapse_all.matches(key_msg) {
+            self.collapse_all();
+        } else if self.keymap.copy_path.matches(key_msg) {
+            return Some(self.copy_path());
+        } else if self.keymap.copy_value.matches(key_msg) {
+            return Some(self.copy_value());
+        }
+        None
+    }
+
+    /// Renders the breadcrumb and the visible part of the tree.
+    pub fn view(&self) -> String {
+        let mut lines = Vec::new();
+        if self.show_path {
+            lines.push(self.styles.path.render(self.path()));
+        }
+        let end = (self.offset + self.height.max(1)).min(self.rows.len());
+        for (i, row) in self.rows[self.offset..end].iter().enumerate() {
+            lines.push(self.render_row(self.offset + i, row));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Shortens `s` with an ellipsis so that it fits in `width` columns after
+/// `used` columns of prefix. A `width` of zero disables shortening.
+fn fit(s: &str, width: usize, used: usize) -> String {
+    if width == 0 {
+        return s.to_string();
+    }
+    text::truncate(s, width.saturating_sub(used))
+}
+
+fn is_container(value: &Value) -> bool {
+    matches!(value, Value::Object(_) | Value::Array(_))
+}
+
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Escapes a key for use in a JSON Pointer (RFC 6901).
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+fn collect_containers(value: &Value, pointer: String, out: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                collect_containers(v, format!("{}/{}", pointer, escape_pointer(k)), out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                collect_containers(v, format!("{}/{}", pointer, i), out);
+            }
+        }
+        _ => return,
+    }
+    out.push(pointer);
+}
+
+/// Returns a command writing `text` to `clipboard`.
+fn copy(clipboard: &Clipboard, id: i64, target: CopyTarget, text: String) -> Cmd {
+    let clipboard = clipboard.clone();
+    bubbletea_tick(Duration::from_nanos(1), move |_| {
+        match clipboard.write(&text) {
+            Ok(()) => Box::new(CopiedMsg {
+                id,
+                target,
+                text: text.clone(),
+            }) as Msg,
+            Err(error) => Box::new(CopyErrMsg { id, error }) as Msg,
+        }
+    })
+}
+
+/// Saved tree layout and position of a JSON viewer; see
+/// [`Stateful`](crate::state::Stateful). The document is not included.
+/// Nodes are identified by JSON pointer, so the state survives edits to
+/// the document that leave those nodes in place.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Pointers of the expanded nodes, sorted.
+    pub expanded: Vec<String>,
+    /// Pointer of the node under the cursor.
+    pub cursor: String,
+    /// Rows scrolled from the top.
+    pub scroll: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        let mut expanded: Vec<String> = self.expanded.iter().cloned().collect();
+        expanded.sort();
+        State {
+            expanded,
+            cursor: self.pointer().to_string(),
+            scroll: self.offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.expanded = state
+            .expanded
+            .into_iter()
+            .filter(|p| self.value.pointer(p).is_some_and(is_container))
+            .collect();
+        self.expanded.insert(String::new());
+        self.cursor = 0;
+        self.rebuild();
+        self.offset = state
+            .scroll
+            .min(self.rows.len().saturating_sub(self.height.max(1)));
+        self.select_path(&state.cursor);
+        self.scroll_to_cursor();
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(80, 24), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn sample() -> Model {
+        let mut m = Model::new(60, 20);
+        m.set_json(r#"{"a": [1, {"b": null}], "odd key": true}"#)
+            .unwrap();
+        m
+    }
+
+    #[test]
+    fn test_expand_collapse_and_parent() {
+        let mut m = sample();
+        assert_eq!(m.row_count(), 3);
+        m.update(key(KeyCode::Down));
+        assert_eq!(m.path(), "$.a");
+        m.update(key(KeyCode::Right));
+        assert_eq!(m.row_count(), 5);
+        m.update(key(KeyCode::Down));
+        assert_eq!(m.path(), "$.a[0]");
+        m.update(key(KeyCode::Left));
+        assert_eq!(m.path(), "$.a");
+        m.update(key(KeyCode::Enter));
+        assert_eq!(m.row_count(), 3);
+    }
+
+    #[test]
+    fn test_expand_all_and_paths() {
+        let mut m = sample();
+        m.update(key(KeyCode::Char('E')));
+        assert_eq!(m.row_count(), 6);
+        assert!(m.select_path("/a/1/b"));
+        assert_eq!(m.path(), "$.a[1].b");
+        assert!(m.select_path("/odd key"));
+        assert_eq!(m.path(), "$[\"odd key\"]");
+        m.update(key(KeyCode::Char('C')));
+        assert_eq!(m.row_count(), 3);
+        assert_eq!(m.cursor(), 0);
+        assert!(!m.select_path("/missing"));
+    }
+
+    #[test]
+    fn test_rendering_and_copy_commands() {
+        let mut m = sample();
+        let view = strip_ansi(&m.view());
+        assert_eq!(
+            view,
+            "$\n> ▾ {…} 2 keys\n    ▸ \"a\": […] 2 items\n      \"odd key\": true"
+        );
+        m.select_path("/a/0");
+        assert_eq!(m.current_value(), Some(&Value::from(1)));
+        assert!(m.update(key(KeyCode::Char('y'))).is_some());
+        assert!(m.update(key(KeyCode::Char('Y'))).is_some());
+    }
+
+    #[test]
+    fn test_invalid_json_is_rejected() {
+        let mut m = Model::new(40, 5);
+        assert!(m.set_json("{").is_err());
+        assert_eq!(m.value(), &Value::Null);
+    }
+}
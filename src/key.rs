@@ -858,6 +858,8 @@ pub fn parse_key_string(s: &str) -> KeyPress {
         "f10" => KeyPress::from(KeyCode::F(10)),
         "f11" => KeyPress::from(KeyCode::F(11)),
         "f12" => KeyPress::from(KeyCode::F(12)),
+        // A lone plus is the key itself, not a modifier separator
+        "+" => KeyPress::from(KeyCode::Char('+')),
         // Handle compound key combinations
         key if key.contains('+') => {
             let parts: Vec<&str> = key.split('+').collect();
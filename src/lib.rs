@@ -22,15 +22,40 @@
 //! - **Type-safe key bindings** with comprehensive key combination support
 //! - **Focus management** system for keyboard navigation between components
 //! - **Responsive design** with automatic width/height handling
-//! - **Theming support** through customizable styles
+//! - **Theming support** through customizable styles and shared [`theme::Theme`]s
+//! - **Unicode-aware layout**: widths are counted in terminal columns, so CJK
+//!   text, emoji and styled strings line up (see the [`text`] module)
+//! - **Animations** driven by springs and easing curves, shared by the
+//!   widgets and available to applications (see the [`anim`] module)
+//! - **Debounce and throttle** commands to rate-limit work (see the
+//!   [`cmd_util`] module)
+//! - **Pluggable clipboard**: copy and paste through the system clipboard,
+//!   the terminal (OSC 52, works over SSH) or memory (see the [`clipboard`]
+//!   module)
+//! - **Typed errors**: fallible methods and widget error fields use one
+//!   [`Error`] enum, so applications can match on the kind of failure
+//! - **Accessible mode**: widgets render plain, screen-reader-friendly text
+//!   that announces state, such as "item 3 of 10 selected" (see the [`a11y`]
+//!   module)
+//! - **Input method composition**: dead keys and CJK input methods compose
+//!   in place in the text input and text area (see the [`ime`] module)
+//! - **Mouse support** for wheel, click and drag in lists, tables, viewports and
+//!   the file picker (`mouse` feature, see the `mouse` module)
+//! - **Saved state** through the [`state::Stateful`] trait, serializable with
+//!   the `serde` feature
+//! - **Headless testing** with scripted input and golden frames (`testutil`
+//!   feature, see the `testutil` module)
+//! - **Per-widget features**: every widget is built by default (`full`), and
+//!   each can be enabled on its own, such as `list`, `table` or `charts`, to
+//!   cut compile time and binary size
 //! - **Go compatibility** for easy migration from charmbracelet/bubbles
 //! - **Performance optimized** with efficient rendering and state management
 //!
 //! ## Components
 //!
-//! - **Input Components**: `TextInput`, `TextArea`, `FilePicker`
-//! - **Display Components**: `List`, `Table`, `Progress`, `Spinner`, `Help`
-//! - **Utility Components**: `Cursor`, `Viewport`, `Paginator`, `Timer`, `Stopwatch`
+//! - **Input Components**: `TextInput`, `TextArea`, `FilePicker`, `Menu`, `Form`, `ColorPicker`, `Confirm`, `Slider`, `Stepper`, `RadioGroup`, `CheckboxGroup`, `Toggle`, `TagInput`, `Rating`, `SearchBar`, `Grid`, `Select`
+//! - **Display Components**: `List`, `Table`, `Progress`, `Spinner`, `Help`, `Toast`, `Markdown`, `CodeView`, `LogView`, `JsonView` (`json` feature), `Sparkline`, `BarChart`, `Chart`, `Skeleton`, `Chat`, `HexView`, `Ring`, `Inbox`, `Indicator`, `Banner`, `Image` (`image` feature)
+//! - **Utility Components**: `Cursor`, `Viewport`, `Paginator`, `Timer`, `Stopwatch`, `Dialog`, `Wizard`, `WhichKey`, `Breadcrumb`, `Scrollbar`, `Outline`, `Tooltip`
 //!
 //! ## Focus Management
 //!
@@ -52,6 +77,17 @@
 //! handle_focus(&mut textarea);
 //! ```
 //!
+//! ## Widgets
+//!
+//! Every component also implements the [`Widget`] trait, a common `update`,
+//! `view` and `set_size` interface that lets containers hold children as
+//! `Box<dyn Widget>` and pass resizes down uniformly. See the
+//! [`widget`] module.
+//!
+//! Full-screen components (list, table, viewport, text area, help, progress
+//! and file picker) can instead follow the terminal size on their own: opt in
+//! with `auto_resize` and a [`widget::Margins`].
+//!
 //! ## Key Bindings
 //!
 //! Components use the type-safe key binding system from the `key` module:
@@ -148,29 +184,163 @@
 //! |-----------|-------------|----------|
 //! | `TextInput` | Single-line text input | Forms, search boxes |
 //! | `TextArea` | Multi-line text editor | Code editing, long text |
+//! | `SearchBar` | `/`-search over viewport, list, table and textarea | Pagers, data browsers |
 //! | `List` | Scrollable item list | Menus, file browsers |
+//! | `Menu` | Action menu with submenus | Main menus, context menus |
+//! | `Markdown` | Scrollable rendered Markdown | READMEs, help screens |
+//! | `CodeView` | Source viewer with line numbers | Diffs, CI logs |
+//! | `LogView` | Tailing log viewer with levels and search | Ops dashboards, build output |
+//! | `Chat` | Message scrollback with aligned bubbles | Chat clients, LLM front-ends |
+//! | `JsonView` | Collapsible JSON tree (`json` feature) | API responses, config inspection |
+//! | `HexView` | Hex and ASCII dump with search | Binary files, protocol debugging |
+//! | `Image` | Kitty, sixel or half-block images (`image` feature) | Previews, logos |
 //! | `Table` | Tabular data display | Data tables, spreadsheets |
+//! | `Grid` | Spreadsheet-style editable cells with copy/paste | Config matrices, quotas |
 //! | `Progress` | Progress bar with animation | Loading indicators |
+//! | `Ring` | Circular progress with centered text | Pomodoro timers, dashboard tiles |
+//! | `Sparkline` | One-line block chart of a streaming series | Dashboard rows, throughput |
+//! | `BarChart` | Horizontal or vertical bars with an axis | Comparisons, histograms |
+//! | `Chart` | Braille line chart of streaming series | Monitoring, metrics |
+//! | `Banner` | Large block-letter text with gradients | Splash screens, section headers |
 //! | `Spinner` | Animated loading spinner | Background operations |
+//! | `Indicator` | Colored status dot with label, blinking while busy | Service health, job state |
+//! | `Skeleton` | Shimmering placeholder blocks | Loading states |
 //! | `Help` | Key binding help display | User guidance |
+//! | `WhichKey` | Popup listing keys that follow a leader | Vim-style leader menus |
+//! | `Breadcrumb` | Path trail with segment navigation | File managers, nested views |
+//! | `Outline` | Table of contents tree with jump messages | Document sidebars |
+//! | `Scrollbar` | Track and thumb for any scrollable view | Lists, viewports, custom views |
 //! | `FilePicker` | File system navigator | File selection |
+//! | `Form` | Multi-field form with validation | Settings, sign-up flows |
+//! | `ColorPicker` | ANSI/truecolor grid with hex entry | Theme configuration |
+//! | `Confirm` | Yes/no prompt | Destructive actions |
+//! | `Slider` | Numeric range selector | Volume, thresholds |
+//! | `Stepper` | Number input with `‹ 42 ›` arrows | Quantities, counts |
+//! | `RadioGroup` / `CheckboxGroup` | Typed single/multi choice | Simple form options |
+//! | `Select` | Inline choice that opens a filtered popup | Toolbars, compact form fields |
+//! | `Toggle` | On/off switch | Settings screens |
+//! | `Rating` | Star rating with half steps | Reviews, feedback forms |
+//! | `TagInput` | Text entry that builds removable chips | Labels, recipients |
+//! | `Wizard` | Multi-step flow with validation gates | Installers, onboarding |
 //! | `Timer` | Countdown timer | Time-based operations |
 //! | `Stopwatch` | Elapsed time tracker | Performance monitoring |
+//! | `Toast` | Auto-dismissing notifications | Status feedback |
+//! | `Inbox` | Notification history with unread badges | Notification centers, status bars |
+//! | `Dialog` | Modal alert/confirm/prompt | Confirmations, quick input |
+//! | `Tooltip` | Hint box anchored over another view | Validation errors, field hints |
 
+pub mod a11y;
+pub mod anim;
+#[cfg(feature = "banner")]
+pub mod banner;
+#[cfg(feature = "charts")]
+pub mod barchart;
+#[cfg(feature = "breadcrumb")]
+pub mod breadcrumb;
+#[cfg(feature = "charts")]
+pub mod chart;
+#[cfg(feature = "chat")]
+pub mod chat;
+#[cfg(feature = "choice")]
+pub mod choice;
+pub mod clipboard;
+pub mod cmd_util;
+#[cfg(feature = "codeview")]
+pub mod codeview;
+#[cfg(feature = "colorpicker")]
+pub mod colorpicker;
+pub mod compose;
+#[cfg(feature = "confirm")]
+pub mod confirm;
 pub mod cursor;
+#[cfg(feature = "dialog")]
+pub mod dialog;
+pub mod error;
+#[cfg(feature = "filepicker")]
 pub mod filepicker;
+pub mod focus;
+#[cfg(feature = "form")]
+pub mod form;
+mod graphemes;
+#[cfg(feature = "grid")]
+pub mod grid;
 pub mod help;
+#[cfg(feature = "hexview")]
+pub mod hexview;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod ime;
+#[cfg(feature = "inbox")]
+pub mod inbox;
+#[cfg(feature = "indicator")]
+pub mod indicator;
+#[cfg(feature = "json")]
+pub mod jsonview;
 pub mod key;
+#[cfg(feature = "list")]
 pub mod list;
+#[cfg(feature = "logview")]
+pub mod logview;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+#[cfg(feature = "menu")]
+pub mod menu;
+#[cfg(feature = "mouse")]
+pub mod mouse;
+#[cfg(feature = "outline")]
+pub mod outline;
+#[cfg(feature = "paginator")]
 pub mod paginator;
+#[cfg(feature = "progress")]
 pub mod progress;
+#[cfg(feature = "rating")]
+pub mod rating;
+#[cfg(feature = "charts")]
+pub mod ring;
+#[cfg(feature = "scrollbar")]
+pub mod scrollbar;
+pub mod search;
+#[cfg(feature = "select")]
+pub mod select;
+#[cfg(feature = "skeleton")]
+pub mod skeleton;
+#[cfg(feature = "slider")]
+pub mod slider;
+#[cfg(feature = "charts")]
+pub mod sparkline;
 pub mod spinner;
+pub mod state;
+#[cfg(feature = "stepper")]
+pub mod stepper;
+#[cfg(feature = "stopwatch")]
 pub mod stopwatch;
+#[cfg(feature = "table")]
 pub mod table;
+#[cfg(feature = "taginput")]
+pub mod taginput;
+#[cfg(feature = "testutil")]
+pub mod testutil;
+pub mod text;
+#[cfg(feature = "textarea")]
 pub mod textarea;
 pub mod textinput;
+pub mod theme;
+#[cfg(feature = "timer")]
 pub mod timer;
+#[cfg(feature = "toast")]
+pub mod toast;
+#[cfg(feature = "toggle")]
+pub mod toggle;
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
+mod undo;
+#[cfg(feature = "viewport")]
 pub mod viewport;
+#[cfg(feature = "whichkey")]
+pub mod whichkey;
+pub mod widget;
+#[cfg(feature = "wizard")]
+pub mod wizard;
 
 use bubbletea_rs::Cmd;
 
@@ -215,34 +385,36 @@ use bubbletea_rs::Cmd;
 ///
 /// ### Focus Management in Applications
 ///
+/// [`focus::FocusRing`] handles the bookkeeping of which component is
+/// focused and cycles focus on `tab`/`shift+tab`:
+///
 /// ```rust
+/// use bubbletea_widgets::focus::FocusRing;
 /// use bubbletea_widgets::prelude::*;
-/// use bubbletea_rs::Cmd;
+/// use bubbletea_rs::{Cmd, Msg};
 ///
 /// struct App {
 ///     input: TextInput,
 ///     textarea: TextArea,
-///     focused_component: usize,
+///     ring: FocusRing,
 /// }
 ///
 /// impl App {
-///     fn focus_next(&mut self) -> Option<Cmd> {
-///         // Blur current component
-///         match self.focused_component {
-///             0 => self.input.blur(),
-///             1 => self.textarea.blur(),
-///             _ => {}
+///     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+///         if self.ring.handles(&msg) {
+///             return self
+///                 .ring
+///                 .update(&msg, &mut [&mut self.input, &mut self.textarea]);
 ///         }
-///
-///         // Focus next component
-///         self.focused_component = (self.focused_component + 1) % 2;
-///         match self.focused_component {
-///             0 => Some(self.input.focus()),
-///             1 => self.textarea.focus(),
-///             _ => None,
+///         if self.ring.is_focused("input") {
+///             self.input.update(msg)
+///         } else {
+///             self.textarea.update(Some(msg))
 ///         }
 ///     }
 /// }
+///
+/// let ring = FocusRing::new().with_entry("input").with_entry("textarea");
 /// ```
 pub trait Component {
     /// Sets the component to focused state.
@@ -310,28 +482,100 @@ pub trait Component {
     fn focused(&self) -> bool;
 }
 
+#[cfg(feature = "banner")]
+pub use banner::Model as Banner;
+#[cfg(feature = "charts")]
+pub use barchart::{Bar, Model as BarChart};
+#[cfg(feature = "breadcrumb")]
+pub use breadcrumb::Model as Breadcrumb;
+#[cfg(feature = "charts")]
+pub use chart::{Model as Chart, Series as ChartSeries};
+#[cfg(feature = "chat")]
+pub use chat::{Message as ChatMessage, Model as Chat};
+#[cfg(feature = "choice")]
+pub use choice::{CheckboxGroup, Choice, RadioGroup};
+#[cfg(feature = "codeview")]
+pub use codeview::Model as CodeView;
+#[cfg(feature = "colorpicker")]
+pub use colorpicker::Model as ColorPicker;
+#[cfg(feature = "confirm")]
+pub use confirm::{ConfirmMsg, Model as Confirm};
 pub use cursor::Model as Cursor;
+#[cfg(feature = "dialog")]
+pub use dialog::{DialogResult, DialogResultMsg, Model as Dialog};
+pub use error::Error;
+#[cfg(feature = "filepicker")]
 pub use filepicker::Model as FilePicker;
+pub use focus::FocusRing;
+#[cfg(feature = "form")]
+pub use form::{Field as FormField, Model as Form, Value as FormValue};
+#[cfg(feature = "grid")]
+pub use grid::{Column as GridColumn, Kind as GridKind, Model as Grid, Value as GridValue};
 pub use help::Model as HelpModel;
+#[cfg(feature = "hexview")]
+pub use hexview::Model as HexView;
+#[cfg(feature = "image")]
+pub use image::{Model as Image, Protocol as ImageProtocol};
+#[cfg(feature = "inbox")]
+pub use inbox::{Model as Inbox, Notification};
+#[cfg(feature = "indicator")]
+pub use indicator::{Model as Indicator, Status as IndicatorStatus};
+#[cfg(feature = "json")]
+pub use jsonview::Model as JsonView;
 pub use key::{
     matches, matches_binding, new_binding, with_disabled, with_help, with_keys, Binding,
     Help as KeyHelp, KeyMap, KeyPress,
 };
+#[cfg(feature = "list")]
 pub use list::Model as List;
+#[cfg(feature = "list")]
 pub use list::{
     DefaultDelegate as ListDefaultDelegate, DefaultItem as ListDefaultItem,
     DefaultItemStyles as ListDefaultItemStyles, FilterState, FilterStateInfo, ListKeyMap,
     ListStyles,
 };
+#[cfg(feature = "logview")]
+pub use logview::Model as LogView;
+#[cfg(feature = "markdown")]
+pub use markdown::Model as Markdown;
+#[cfg(feature = "menu")]
+pub use menu::{Item as MenuItem, Model as Menu};
+#[cfg(feature = "outline")]
+pub use outline::{Entry as OutlineEntry, Model as Outline};
+#[cfg(feature = "paginator")]
 pub use paginator::Model as Paginator;
+#[cfg(feature = "progress")]
 pub use progress::Model as Progress;
+#[cfg(feature = "rating")]
+pub use rating::Model as Rating;
+#[cfg(feature = "charts")]
+pub use ring::Model as Ring;
+#[cfg(feature = "scrollbar")]
+pub use scrollbar::Model as Scrollbar;
+pub use search::{Hit as SearchHit, Model as SearchBar, Searchable};
+#[cfg(feature = "select")]
+pub use select::Model as Select;
+#[cfg(feature = "skeleton")]
+pub use skeleton::Model as Skeleton;
+#[cfg(feature = "slider")]
+pub use slider::Model as Slider;
+#[cfg(feature = "charts")]
+pub use sparkline::Model as Sparkline;
 pub use spinner::{
     new as spinner_new, with_spinner, with_style, Model as Spinner, SpinnerOption,
     TickMsg as SpinnerTickMsg, DOT, ELLIPSIS, GLOBE, HAMBURGER, JUMP, LINE, METER, MINI_DOT,
     MONKEY, MOON, POINTS, PULSE,
 };
+pub use state::Stateful;
+#[cfg(feature = "stepper")]
+pub use stepper::Model as Stepper;
+#[cfg(feature = "stopwatch")]
 pub use stopwatch::Model as Stopwatch;
+#[cfg(feature = "table")]
 pub use table::Model as Table;
+#[cfg(feature = "taginput")]
+pub use taginput::Model as TagInput;
+#[cfg(feature = "textarea")]
 pub use textarea::{
     default_styles as textarea_default_styles, new as textarea_new, LineInfo, Model as TextArea,
     PasteErrMsg as TextAreaPasteErrMsg, PasteMsg as TextAreaPasteMsg,
@@ -340,11 +584,25 @@ pub use textinput::{
     blink, default_key_map as textinput_default_key_map, new as textinput_new, paste, EchoMode,
     KeyMap as TextInputKeyMap, Model as TextInput, PasteErrMsg, PasteMsg, ValidateFunc,
 };
+pub use theme::{Theme, Themeable};
+#[cfg(feature = "timer")]
 pub use timer::{
     new as timer_new, new_with_interval as timer_new_with_interval, Model as Timer,
     StartStopMsg as TimerStartStopMsg, TickMsg as TimerTickMsg, TimeoutMsg as TimerTimeoutMsg,
 };
+#[cfg(feature = "toast")]
+pub use toast::Model as Toasts;
+#[cfg(feature = "toggle")]
+pub use toggle::{Model as Toggle, ToggleMsg};
+#[cfg(feature = "tooltip")]
+pub use tooltip::Model as Tooltip;
+#[cfg(feature = "viewport")]
 pub use viewport::Model as Viewport;
+#[cfg(feature = "whichkey")]
+pub use whichkey::Model as WhichKey;
+pub use widget::Widget;
+#[cfg(feature = "wizard")]
+pub use wizard::Model as Wizard;
 
 /// Prelude module for convenient imports.
 ///
@@ -413,26 +671,95 @@ pub use viewport::Model as Viewport;
 /// }
 /// ```
 pub mod prelude {
+    #[cfg(feature = "banner")]
+    pub use crate::banner::Model as Banner;
+    #[cfg(feature = "charts")]
+    pub use crate::barchart::{Bar, Model as BarChart};
+    #[cfg(feature = "breadcrumb")]
+    pub use crate::breadcrumb::Model as Breadcrumb;
+    #[cfg(feature = "charts")]
+    pub use crate::chart::{Model as Chart, Series as ChartSeries};
+    #[cfg(feature = "chat")]
+    pub use crate::chat::{Message as ChatMessage, Model as Chat};
+    #[cfg(feature = "choice")]
+    pub use crate::choice::{CheckboxGroup, Choice, RadioGroup};
+    #[cfg(feature = "codeview")]
+    pub use crate::codeview::Model as CodeView;
+    #[cfg(feature = "colorpicker")]
+    pub use crate::colorpicker::Model as ColorPicker;
+    #[cfg(feature = "confirm")]
+    pub use crate::confirm::{ConfirmMsg, Model as Confirm};
     pub use crate::cursor::Model as Cursor;
+    #[cfg(feature = "dialog")]
+    pub use crate::dialog::{DialogResult, DialogResultMsg, Model as Dialog};
+    #[cfg(feature = "form")]
+    pub use crate::form::{Field as FormField, Model as Form, Value as FormValue};
+    #[cfg(feature = "grid")]
+    pub use crate::grid::{
+        Column as GridColumn, Kind as GridKind, Model as Grid, Value as GridValue,
+    };
     pub use crate::help::Model as HelpModel;
+    #[cfg(feature = "hexview")]
+    pub use crate::hexview::Model as HexView;
+    #[cfg(feature = "image")]
+    pub use crate::image::{Model as Image, Protocol as ImageProtocol};
+    #[cfg(feature = "inbox")]
+    pub use crate::inbox::{Model as Inbox, Notification};
+    #[cfg(feature = "indicator")]
+    pub use crate::indicator::{Model as Indicator, Status as IndicatorStatus};
+    #[cfg(feature = "json")]
+    pub use crate::jsonview::Model as JsonView;
     pub use crate::key::{
         matches, matches_binding, new_binding, with_disabled, with_help, with_keys, Binding,
         Help as KeyHelp, KeyMap, KeyPress,
     };
+    #[cfg(feature = "list")]
     pub use crate::list::Model as List;
+    #[cfg(feature = "list")]
     pub use crate::list::{
         DefaultDelegate as ListDefaultDelegate, DefaultItem as ListDefaultItem,
         DefaultItemStyles as ListDefaultItemStyles, FilterState, FilterStateInfo, ListKeyMap,
         ListStyles,
     };
+    #[cfg(feature = "logview")]
+    pub use crate::logview::Model as LogView;
+    #[cfg(feature = "markdown")]
+    pub use crate::markdown::Model as Markdown;
+    #[cfg(feature = "menu")]
+    pub use crate::menu::{Item as MenuItem, Model as Menu};
+    #[cfg(feature = "outline")]
+    pub use crate::outline::{Entry as OutlineEntry, Model as Outline};
+    #[cfg(feature = "paginator")]
     pub use crate::paginator::Model as Paginator;
+    #[cfg(feature = "progress")]
     pub use crate::progress::Model as Progress;
+    #[cfg(feature = "rating")]
+    pub use crate::rating::Model as Rating;
+    #[cfg(feature = "charts")]
+    pub use crate::ring::Model as Ring;
+    #[cfg(feature = "scrollbar")]
+    pub use crate::scrollbar::Model as Scrollbar;
+    pub use crate::search::{Hit as SearchHit, Model as SearchBar, Searchable};
+    #[cfg(feature = "select")]
+    pub use crate::select::Model as Select;
+    #[cfg(feature = "skeleton")]
+    pub use crate::skeleton::Model as Skeleton;
+    #[cfg(feature = "slider")]
+    pub use crate::slider::Model as Slider;
+    #[cfg(feature = "charts")]
+    pub use crate::sparkline::Model as Sparkline;
     pub use crate::spinner::{
         new as spinner_new, with_spinner, with_style, Model as Spinner, SpinnerOption,
         TickMsg as SpinnerTickMsg, DOT, ELLIPSIS, GLOBE, HAMBURGER, JUMP, LINE, METER, MINI_DOT,
         MONKEY, MOON, POINTS, PULSE,
     };
+    #[cfg(feature = "stepper")]
+    pub use crate::stepper::Model as Stepper;
+    #[cfg(feature = "table")]
     pub use crate::table::Model as Table;
+    #[cfg(feature = "taginput")]
+    pub use crate::taginput::Model as TagInput;
+    #[cfg(feature = "textarea")]
     pub use crate::textarea::{
         default_styles as textarea_default_styles, new as textarea_new, LineInfo,
         Model as TextArea, PasteErrMsg as TextAreaPasteErrMsg, PasteMsg as TextAreaPasteMsg,
@@ -441,10 +768,23 @@ pub mod prelude {
         blink, default_key_map as textinput_default_key_map, new as textinput_new, paste, EchoMode,
         KeyMap as TextInputKeyMap, Model as TextInput, PasteErrMsg, PasteMsg, ValidateFunc,
     };
+    pub use crate::theme::{Theme, Themeable};
+    #[cfg(feature = "timer")]
     pub use crate::timer::{
         new as timer_new, new_with_interval as timer_new_with_interval, Model as Timer,
         StartStopMsg as TimerStartStopMsg, TickMsg as TimerTickMsg, TimeoutMsg as TimerTimeoutMsg,
     };
+    #[cfg(feature = "toast")]
+    pub use crate::toast::Model as Toasts;
+    #[cfg(feature = "toggle")]
+    pub use crate::toggle::{Model as Toggle, ToggleMsg};
+    #[cfg(feature = "tooltip")]
+    pub use crate::tooltip::Model as Tooltip;
+    #[cfg(feature = "viewport")]
     pub use crate::viewport::Model as Viewport;
+    #[cfg(feature = "whichkey")]
+    pub use crate::whichkey::Model as WhichKey;
+    #[cfg(feature = "wizard")]
+    pub use crate::wizard::Model as Wizard;
     pub use crate::Component;
 }
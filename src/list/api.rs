@@ -84,6 +84,7 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         self.filtered_items.clear();
         self.cursor = 0;
         self.update_pagination();
+        self.skip_collapsed(true);
         None
     }
 
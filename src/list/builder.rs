@@ -0,0 +1,208 @@
+//! Fluent construction of a list.
+
+use super::{DefaultDelegate, Item, ItemDelegate, ListKeyMap, ListStyles, Model};
+use crate::paginator;
+use crate::widget::Margins;
+
+/// Builds a [`Model`] from a chain of settings, the counterpart of Go's
+/// functional options. Start one with [`Model::builder`].
+///
+/// Everything left unset keeps the default of [`Model::new`]: the
+/// [`DefaultDelegate`], an 80x24 size, the title "List" and every part of
+/// the chrome shown.
+///
+/// # Examples
+///
+/// ```
+/// use bubbletea_widgets::list::{DefaultItem, Model};
+///
+/// let list = Model::builder()
+///     .items(vec![
+///         DefaultItem::new("Apple", "Red fruit"),
+///         DefaultItem::new("Banana", "Yellow fruit"),
+///     ])
+///     .title("Fruit")
+///     .height(12)
+///     .show_status_bar(false)
+///     .build();
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list.height(), 12);
+/// ```
+pub struct Builder<I: Item> {
+    items: Vec<I>,
+    delegate: Option<Box<dyn ItemDelegate<I> + Send + Sync>>,
+    width: usize,
+    height: usize,
+    title: Option<String>,
+    show_title: Option<bool>,
+    show_status_bar: Option<bool>,
+    show_pagination: Option<bool>,
+    pagination_type: Option<paginator::Type>,
+    show_help: Option<bool>,
+    show_spinner: Option<bool>,
+    item_name: Option<(String, String)>,
+    styles: Option<ListStyles>,
+    key_map: Option<ListKeyMap>,
+    resize_margins: Option<Margins>,
+}
+
+impl<I: Item + Send + Sync + 'static> Model<I> {
+    /// Starts a [`Builder`] for a list.
+    pub fn builder() -> Builder<I> {
+        Builder {
+            items: Vec::new(),
+            delegate: None,
+            width: 80,
+            height: 24,
+            title: None,
+            show_title: None,
+            show_status_bar: None,
+            show_pagination: None,
+            pagination_type: None,
+            show_help: None,
+            show_spinner: None,
+            item_name: None,
+            styles: None,
+            key_map: None,
+            resize_margins: None,
+        }
+    }
+}
+
+impl<I: Item + Send + Sync + 'static> Builder<I> {
+    /// Sets the items shown.
+    pub fn items(mut self, items: Vec<I>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Sets the delegate that renders the items.
+    pub fn delegate<D>(mut self, delegate: D) -> Self
+    where
+        D: ItemDelegate<I> + Send + Sync + 'static,
+    {
+        self.delegate = Some(Box::new(delegate));
+        self
+    }
+
+    /// Sets the width in columns.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height in rows.
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the title shown above the items.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// Shows or hides the title bar.
+    pub fn show_title(mut self, show: bool) -> Self {
+        self.show_title = Some(show);
+        self
+    }
+
+    /// Shows or hides the status bar.
+    pub fn show_status_bar(mut self, show: bool) -> Self {
+        self.show_status_bar = Some(show);
+        self
+    }
+
+    /// Shows or hides the paginator.
+    pub fn show_pagination(mut self, show: bool) -> Self {
+        self.show_pagination = Some(show);
+        self
+    }
+
+    /// Sets whether the paginator shows dots or page numbers.
+    pub fn pagination_type(mut self, pagination_type: paginator::Type) -> Self {
+        self.pagination_type = Some(pagination_type);
+        self
+    }
+
+    /// Shows or hides the help line.
+    pub fn show_help(mut self, show: bool) -> Self {
+        self.show_help = Some(show);
+        self
+    }
+
+    /// Shows or hides the spinner next to the title.
+    pub fn show_spinner(mut self, show: bool) -> Self {
+        self.show_spinner = Some(show);
+        self
+    }
+
+    /// Sets the words the status bar uses for one item and for several.
+    pub fn item_name(mut self, singular: &str, plural: &str) -> Self {
+        self.item_name = Some((singular.to_string(), plural.to_string()));
+        self
+    }
+
+    /// Sets the styles.
+    pub fn styles(mut self, styles: ListStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    /// Sets the key bindings.
+    pub fn key_map(mut self, key_map: ListKeyMap) -> Self {
+        self.key_map = Some(key_map);
+        self
+    }
+
+    /// Makes the list follow the terminal size minus `margins`; see
+    /// [`Model::auto_resize`].
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Creates the list.
+    pub fn build(self) -> Model<I> {
+        let mut list = Model::new(self.items, DefaultDelegate::new(), self.width, self.height);
+        if let Some(delegate) = self.delegate {
+            list.delegate = delegate;
+        }
+        if let Some(title) = self.title {
+            list.title = title;
+        }
+        if let Some(styles) = self.styles {
+            list.set_styles(styles);
+        }
+        if let Some(key_map) = self.key_map {
+            list.keymap = key_map;
+        }
+        if let Some((singular, plural)) = self.item_name {
+            list.set_status_bar_item_name(&singular, &plural);
+        }
+        if let Some(pagination_type) = self.pagination_type {
+            list.set_pagination_type(pagination_type);
+        }
+        if let Some(show) = self.show_title {
+            list.set_show_title(show);
+        }
+        if let Some(show) = self.show_status_bar {
+            list.set_show_status_bar(show);
+        }
+        if let Some(show) = self.show_pagination {
+            list.set_show_pagination(show);
+        }
+        if let Some(show) = self.show_help {
+            list.set_show_help(show);
+        }
+        if let Some(show) = self.show_spinner {
+            list.set_show_spinner(show);
+        }
+        list.resize_margins = self.resize_margins;
+        // The delegate and the chrome decide how many items fit a page.
+        list.update_pagination();
+        list
+    }
+}
@@ -60,6 +60,7 @@
 //! ```
 
 use super::{Item, ItemDelegate, Model};
+use crate::theme::Theme;
 use bubbletea_rs::{Cmd, Msg};
 use lipgloss_extras::prelude::*;
 
@@ -300,6 +301,27 @@ impl Default for DefaultItemStyles {
     }
 }
 
+impl DefaultItemStyles {
+    /// Creates item styles from a theme, keeping the default layout.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let defaults = Self::default();
+        let selected_title = defaults
+            .selected_title
+            .border_left_foreground(p.secondary.clone())
+            .foreground(p.primary.clone());
+        Self {
+            normal_title: defaults.normal_title.foreground(p.foreground.clone()),
+            normal_desc: defaults.normal_desc.foreground(p.muted.clone()),
+            selected_desc: selected_title.clone().foreground(p.secondary.clone()),
+            selected_title,
+            dimmed_title: defaults.dimmed_title.foreground(p.muted.clone()),
+            dimmed_desc: defaults.dimmed_desc.foreground(p.subtle.clone()),
+            filter_match: defaults.filter_match,
+        }
+    }
+}
+
 /// A simple list item with title and description text.
 ///
 /// This struct represents a basic list item that can be used with the `DefaultDelegate`
@@ -343,6 +365,8 @@ pub struct DefaultItem {
     pub title: String,
     /// Secondary item text (optional display).
     pub desc: String,
+    /// Section the item is listed under; see [`Item::group`].
+    pub group: Option<String>,
 }
 
 impl DefaultItem {
@@ -380,8 +404,24 @@ impl DefaultItem {
         Self {
             title: title.to_string(),
             desc: desc.to_string(),
+            group: None,
         }
     }
+
+    /// Puts the item in the section named `group` (builder pattern).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::list::{DefaultItem, Item};
+    ///
+    /// let item = DefaultItem::new("notes.md", "").with_group("Pinned");
+    /// assert_eq!(item.group().as_deref(), Some("Pinned"));
+    /// ```
+    pub fn with_group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
 }
 
 impl std::fmt::Display for DefaultItem {
@@ -431,6 +471,10 @@ impl Item for DefaultItem {
     fn filter_value(&self) -> String {
         self.title.clone()
     }
+
+    fn group(&self) -> Option<String> {
+        self.group.clone()
+    }
 }
 
 /// A delegate for rendering `DefaultItem` instances in list components.
@@ -603,7 +647,7 @@ impl<I: Item + 'static> ItemDelegate<I> for DefaultDelegate {
     /// The `index` parameter represents the **original item index** in the full items list,
     /// NOT a viewport-relative or filtered-relative position. This design is crucial for:
     ///
-    /// 1. **Cursor Highlighting**: `index == m.cursor` comparison works correctly
+    /// 1. **Cursor Highlighting**: comparing with `m.global_index()` works while filtered
     /// 2. **Filter Highlighting**: We can find matches by searching filtered_items
     /// 3. **Viewport Scrolling**: Highlighting persists across viewport changes
     ///
@@ -633,7 +677,7 @@ impl<I: Item + 'static> ItemDelegate<I> for DefaultDelegate {
         }
 
         let s = &self.styles;
-        let is_selected = index == m.cursor;
+        let is_selected = Some(index) == m.global_index();
 
         // Check if we're in the special "empty filter" dimmed state
         // This happens when user has pressed '/' to filter but hasn't typed anything yet
@@ -1,27 +1,81 @@
 //! Filter operations and state management for list components.
 //!
 //! This module handles all fuzzy filtering functionality including:
-//! - Filter application and fuzzy matching
+//! - Filter application through the list's filter function
+//! - The built-in fuzzy filters, ranked and unranked
 //! - Character-level match index tracking
 //! - Filter state transitions
 //! - Match index retrieval for highlighting
 
-use super::types::{FilterState, FilteredItem, Item};
+use super::types::{FilterFunc, FilterState, FilteredItem, Item, Rank};
 use super::Model;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 
+/// Fuzzy matches `term` against `targets` and ranks the matches by score,
+/// best first, as fzf does. Ties keep the items' order. This is the list's
+/// filter unless another is set, and matches Go's `list.DefaultFilter`.
+///
+/// # Examples
+///
+/// ```
+/// use bubbletea_widgets::list::default_filter;
+///
+/// let targets = vec!["Save as".to_string(), "Save".to_string(), "Open".to_string()];
+/// let ranks = default_filter("save", &targets);
+/// assert_eq!(ranks.len(), 2);
+/// assert_eq!(ranks[0].matched_indexes, vec![0, 1, 2, 3]);
+/// ```
+pub fn default_filter(term: &str, targets: &[String]) -> Vec<Rank> {
+    let matcher = SkimMatcherV2::default();
+    let mut scored: Vec<(i64, Rank)> = targets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, target)| {
+            let (score, matched_indexes) = matcher.fuzzy_indices(target, term)?;
+            Some((
+                score,
+                Rank {
+                    index,
+                    matched_indexes,
+                },
+            ))
+        })
+        .collect();
+    scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    scored.into_iter().map(|(_, rank)| rank).collect()
+}
+
+/// Fuzzy matches `term` against `targets`, keeping the matches in the
+/// items' order. Matches Go's `list.UnsortedFilter`.
+pub fn unsorted_filter(term: &str, targets: &[String]) -> Vec<Rank> {
+    let matcher = SkimMatcherV2::default();
+    targets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, target)| {
+            let (_, matched_indexes) = matcher.fuzzy_indices(target, term)?;
+            Some(Rank {
+                index,
+                matched_indexes,
+            })
+        })
+        .collect()
+}
+
 impl<I: Item + Send + Sync + 'static> Model<I> {
     /// Applies the current filter text to all items and updates the filtered results.
     ///
-    /// This method performs fuzzy matching against all items using the current filter
+    /// This method runs the filter function against all items using the current filter
     /// input text. It updates the `filtered_items` list with matching items and their
-    /// character match indices, then resets navigation state appropriately.
+    /// character match indices, in the order the filter ranked them, then resets
+    /// navigation state appropriately.
     ///
     /// # Filter Application Process
     ///
     /// 1. **Empty Filter Handling**: If filter text is empty, clears all filtering
-    /// 2. **Fuzzy Matching**: Uses SkimMatcherV2 for fuzzy string matching
+    /// 2. **Matching**: Runs the filter function, by default [`default_filter`]'s
+    ///    fuzzy matching ranked by score
     /// 3. **Index Preservation**: Maintains original item indices for cursor highlighting
     /// 4. **Match Tracking**: Stores character indices for rendering highlights
     /// 5. **State Reset**: Resets cursor and viewport to show filtered results from the beginning
@@ -78,26 +132,21 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
             self.filter_state = FilterState::Unfiltered;
             self.cursor = 0;
             self.update_pagination();
+            self.skip_collapsed(true);
             return;
         }
 
-        // Create fuzzy matcher for filtering
-        let matcher = SkimMatcherV2::default();
-
-        // Apply fuzzy filter to all items
-        self.filtered_items = self
-            .items
-            .iter()
-            .enumerate()
-            .filter_map(|(index, item)| {
-                let filter_value = item.filter_value();
-                matcher
-                    .fuzzy_indices(&filter_value, &filter_text)
-                    .map(|(_, indices)| FilteredItem {
-                        index,
-                        item: item.clone(),
-                        matches: indices,
-                    })
+        let targets: Vec<String> = self.items.iter().map(Item::filter_value).collect();
+        let ranks = (self.filter_func)(&filter_text, &targets);
+        self.filtered_items = ranks
+            .into_iter()
+            .filter_map(|rank| {
+                let item = self.items.get(rank.index)?;
+                Some(FilteredItem {
+                    index: rank.index,
+                    item: item.clone(),
+                    matches: rank.matched_indexes,
+                })
             })
             .collect();
 
@@ -111,6 +160,52 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
 
         // Update pagination to reflect filtered item count
         self.update_pagination();
+        self.skip_collapsed(true);
+    }
+
+    /// Replaces the function that decides which items match the filter and
+    /// in what order, for case-sensitive, substring, regex or frecency
+    /// matching. A filter already applied is run again with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bubbletea_widgets::list::{DefaultDelegate, DefaultItem, FilterState, Model, Rank};
+    ///
+    /// let items = vec![DefaultItem::new("Cargo.toml", ""), DefaultItem::new("cargo.lock", "")];
+    /// let mut list = Model::new(items, DefaultDelegate::new(), 80, 24);
+    /// list.set_filter_text("Cargo");
+    /// list.set_filter_state(FilterState::FilterApplied);
+    ///
+    /// // Case-sensitive substring matching
+    /// list.set_filter_func(Box::new(|term: &str, targets: &[String]| {
+    ///     targets
+    ///         .iter()
+    ///         .enumerate()
+    ///         .filter_map(|(index, target)| {
+    ///             let start = target.find(term)?;
+    ///             let start = target[..start].chars().count();
+    ///             Some(Rank {
+    ///                 index,
+    ///                 matched_indexes: (start..start + term.chars().count()).collect(),
+    ///             })
+    ///         })
+    ///         .collect()
+    /// }));
+    /// assert_eq!(list.visible_items().len(), 1);
+    /// ```
+    pub fn set_filter_func(&mut self, filter: FilterFunc) {
+        self.filter_func = filter;
+        if self.filter_state != FilterState::Unfiltered {
+            self.apply_filter();
+        }
+    }
+
+    /// Sets the filter function (builder pattern); see
+    /// [`set_filter_func`](Self::set_filter_func).
+    pub fn with_filter_func(mut self, filter: FilterFunc) -> Self {
+        self.set_filter_func(filter);
+        self
     }
 
     /// Synchronizes the viewport position to keep the cursor visible.
@@ -210,6 +305,16 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         }
     }
 
+    /// Moves the cursor to a visible index and keeps the viewport and
+    /// paginator in step.
+    pub(super) fn move_cursor_to(&mut self, index: usize) {
+        self.cursor = index.min(self.len().saturating_sub(1));
+        self.sync_viewport_with_cursor();
+        if let Some(page) = self.cursor.checked_div(self.per_page) {
+            self.paginator.page = page;
+        }
+    }
+
     /// Calculates how many items can fit in the current viewport.
     ///
     /// This is a helper method that encapsulates the viewport size calculation
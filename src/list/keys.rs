@@ -17,6 +17,13 @@
 //! - **Cancel Filter**: `esc` (cancel filter input)
 //! - **Accept Filter**: `enter/tab/↑/↓` (apply filter and continue)
 //!
+//! ## Section Keys
+//!
+//! - **Fold Section**: `tab` (collapse or expand the selected item's section)
+//! - **Unfold All**: `shift+tab` (expand every section)
+//!
+//! These only act once the list has a section delegate.
+//!
 //! ## Help and Quit Keys
 //!
 //! - **Help**: `?` (show/hide help)
@@ -59,6 +66,10 @@ pub struct ListKeyMap {
     pub cancel_filter: key::Binding,
     /// Accept/apply the current filter input.
     pub accept_filter: key::Binding,
+    /// Collapse or expand the section of the selected item.
+    pub toggle_group: key::Binding,
+    /// Expand every section.
+    pub expand_all_groups: key::Binding,
     /// Show the full help panel.
     pub show_full_help: key::Binding,
     /// Close the full help panel.
@@ -107,6 +118,9 @@ impl Default for ListKeyMap {
                 KeyCode::Down,
             ])
             .with_help("enter", "apply filter"),
+            toggle_group: key::Binding::new(vec![KeyCode::Tab]).with_help("tab", "fold section"),
+            expand_all_groups: key::Binding::new(vec![KeyCode::BackTab])
+                .with_help("shift+tab", "unfold all"),
             show_full_help: key::Binding::new(vec![KeyCode::Char('?')]).with_help("?", "more"),
             close_full_help: key::Binding::new(vec![KeyCode::Char('?')])
                 .with_help("?", "close help"),
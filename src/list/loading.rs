@@ -0,0 +1,98 @@
+//! Asynchronous item loading for list components.
+//!
+//! A list can fetch its items in a command instead of being handed them up
+//! front. While the fetch runs the list shows its spinner and a loading
+//! placeholder in place of the items; the [`ItemsLoadedMsg`] the command
+//! sends back replaces the items and ends the loading state.
+
+use super::types::{Item, ItemsLoadedMsg};
+use super::Model;
+use crate::spinner;
+use bubbletea_rs::{Cmd, Msg};
+use std::future::Future;
+
+impl<I: Item + Send + Sync + 'static> Model<I> {
+    /// Starts loading the items from `load` and returns the command that
+    /// runs it.
+    ///
+    /// Until the [`ItemsLoadedMsg`] it sends is passed to `update`, the
+    /// list shows its spinner and "Loading…" instead of the items. Starting
+    /// another load while one runs supersedes it: the list keeps loading
+    /// until the newest load finishes, and items from older ones are
+    /// dropped whenever they arrive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bubbletea_widgets::list::{DefaultDelegate, DefaultItem, Model};
+    ///
+    /// let mut list: Model<DefaultItem> = Model::new(vec![], DefaultDelegate::new(), 80, 24);
+    /// let cmd = list.set_items_cmd(async {
+    ///     vec![DefaultItem::new("Fetched", "from the network")]
+    /// });
+    /// assert!(list.is_loading());
+    /// // Return `cmd` from `update` so the runtime runs it
+    /// # drop(cmd);
+    /// ```
+    pub fn set_items_cmd<F>(&mut self, load: F) -> Cmd
+    where
+        F: Future<Output = Vec<I>> + Send + 'static,
+    {
+        self.loading = true;
+        self.load_tag += 1;
+        let id = self.id;
+        let tag = self.load_tag;
+        let loaded: Cmd = Box::pin(async move {
+            let items = load.await;
+            Some(Box::new(ItemsLoadedMsg { id, items, tag }) as Msg)
+        });
+        bubbletea_rs::batch(vec![self.start_spinner(), loaded])
+    }
+
+    /// Returns whether items started by [`set_items_cmd`](Self::set_items_cmd)
+    /// are still loading.
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// Shows the spinner and returns the command that animates it.
+    ///
+    /// Matches Go's `StartSpinner`. The spinner keeps turning while the
+    /// list receives its tick messages, until
+    /// [`stop_spinner`](Self::stop_spinner).
+    pub fn start_spinner(&mut self) -> Cmd {
+        self.show_spinner = true;
+        self.spinner.tick()
+    }
+
+    /// Hides the spinner. Matches Go's `StopSpinner`.
+    pub fn stop_spinner(&mut self) {
+        self.show_spinner = false;
+    }
+
+    /// The list's ID, which [`ItemsLoadedMsg`] carries.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Whether `msg` belongs to the loading lifecycle: loaded items, or a
+    /// spinner tick while the spinner shows.
+    pub(super) fn is_loading_msg(&self, msg: &Msg) -> bool {
+        msg.is::<ItemsLoadedMsg<I>>() || (self.show_spinner && msg.is::<spinner::TickMsg>())
+    }
+
+    /// Handles a message for which [`is_loading_msg`](Self::is_loading_msg)
+    /// holds.
+    pub(super) fn handle_loading(&mut self, msg: Msg) -> Option<Cmd> {
+        let loaded = match msg.downcast::<ItemsLoadedMsg<I>>() {
+            Ok(loaded) => loaded,
+            Err(msg) => return self.spinner.update(msg),
+        };
+        if loaded.id == self.id && loaded.tag == self.load_tag && self.loading {
+            self.loading = false;
+            self.stop_spinner();
+            self.set_items(loaded.items);
+        }
+        None
+    }
+}
@@ -34,7 +34,12 @@
 //! - `FilterApplied`: Filter accepted; only matching items are displayed
 //!
 //! When filtering is active, fuzzy match indices are stored per item and delegates can use
-//! them to apply character-level highlighting (see `defaultitem`).
+//! them to apply character-level highlighting (see `defaultitem`). Matches are ranked by
+//! score, best first; `set_filter_func` swaps in other matching, such as substring or regex.
+//!
+//! ### Sections
+//! With `set_section_delegate`, items are listed under headers for their `Item::group`.
+//! Headers cannot be selected, and collapsing a section hides its items from navigation.
 //!
 //! ### Help Integration
 //! The list implements `help::KeyMap`, so you can embed `help::Model` and get contextual
@@ -118,9 +123,13 @@ pub mod style;
 
 // Internal modules
 mod api;
+mod builder;
 mod filtering;
+mod loading;
 mod model;
 mod rendering;
+mod sections;
+mod status;
 mod types;
 
 // Re-export public types from submodules
@@ -148,6 +157,9 @@ mod types;
 /// ```
 pub use model::Model;
 
+/// Fluent builder for [`Model`], started with [`Model::builder`].
+pub use builder::Builder;
+
 /// Key binding configuration for list navigation and interaction.
 ///
 /// `ListKeyMap` defines all the keyboard shortcuts used for list operations
@@ -196,7 +208,16 @@ pub use style::ListStyles;
 ///     }
 /// }
 /// ```
-pub use types::{FilterState, FilterStateInfo, Item, ItemDelegate};
+pub use types::{
+    FilterFunc, FilterState, FilterStateInfo, Item, ItemDelegate, ItemsLoadedMsg, Rank,
+    StatusMessageTimeoutMsg,
+};
+
+/// The built-in filter functions; see [`Model::set_filter_func`].
+pub use filtering::{default_filter, unsorted_filter};
+
+/// Section headers for grouped lists; see [`Model::set_section_delegate`].
+pub use sections::{DefaultSectionDelegate, SectionDelegate};
 
 /// Ready-to-use implementations for common list scenarios.
 ///
@@ -262,21 +283,26 @@ impl<I: Item> help::KeyMap for Model<I> {
     ///
     /// # Binding Groups
     ///
-    /// 1. **Cursor movement**: Up/down navigation
-    /// 2. **Page navigation**: Page up/down, home/end
-    /// 3. **Filtering**: Start filter, clear filter, accept
-    /// 4. **Help and quit**: Show help, quit application
+    /// 1. **Navigation**: Up/down, page up/down, home/end, and folding
+    ///    sections when the list has them
+    /// 2. **Filtering**: Start filter, clear filter, accept
+    /// 3. **Help and quit**: Show help, quit application
     fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        // Column 1: Primary Navigation
+        let mut navigation = vec![
+            &self.keymap.cursor_up,
+            &self.keymap.cursor_down,
+            &self.keymap.next_page,
+            &self.keymap.prev_page,
+            &self.keymap.go_to_start,
+            &self.keymap.go_to_end,
+        ];
+        if self.sections.is_some() {
+            navigation.push(&self.keymap.toggle_group);
+            navigation.push(&self.keymap.expand_all_groups);
+        }
         vec![
-            // Column 1: Primary Navigation
-            vec![
-                &self.keymap.cursor_up,
-                &self.keymap.cursor_down,
-                &self.keymap.next_page,
-                &self.keymap.prev_page,
-                &self.keymap.go_to_start,
-                &self.keymap.go_to_end,
-            ],
+            navigation,
             // Column 2: Filtering Actions
             vec![
                 &self.keymap.filter,
@@ -295,6 +321,83 @@ impl<I: Item> help::KeyMap for Model<I> {
 }
 
 // BubbleTeaModel implementation - integrates with bubbletea-rs runtime
+/// Applies the theme to the list chrome (title, status bar, pagination).
+///
+/// Item rendering is owned by the delegate; restyle a [`DefaultDelegate`]
+/// with [`DefaultItemStyles::from_theme`].
+impl<I: Item + Send + Sync + 'static> crate::theme::Themeable for Model<I> {
+    fn apply_theme(&mut self, theme: &crate::theme::Theme) {
+        self.set_styles(ListStyles::from_theme(theme));
+    }
+}
+
+/// Searches the filter values of the visible items, so an applied filter
+/// narrows the search too. Going to a hit selects the item.
+impl<I: Item + Send + Sync + 'static> crate::search::Searchable for Model<I> {
+    fn matches(&self, query: &str) -> Vec<crate::search::Hit> {
+        self.visible_items()
+            .iter()
+            .enumerate()
+            .flat_map(|(row, item)| crate::search::hits_in(&item.filter_value(), query, row, 0))
+            .collect()
+    }
+
+    fn goto(&mut self, hit: crate::search::Hit) {
+        if hit.row < self.len() {
+            self.move_cursor_to(hit.row);
+        }
+    }
+}
+
+/// Saved state of a list; see [`Stateful`](crate::state::Stateful). The
+/// items themselves are not included.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Index of the selected item among the visible ones.
+    pub cursor: usize,
+    /// Text in the filter input.
+    pub filter: String,
+    /// Whether the filter is being typed, applied or off.
+    pub filter_state: FilterState,
+}
+
+impl<I: Item + Send + Sync + 'static> crate::state::Stateful for Model<I> {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            cursor: self.cursor,
+            filter: self.filter_input.value(),
+            filter_state: self.filter_state.clone(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.filter_input.set_value(&state.filter);
+        // apply_filter leaves the state alone unless the text is empty, in
+        // which case it falls back to Unfiltered
+        self.apply_filter();
+        match state.filter_state {
+            FilterState::Unfiltered => {
+                self.filtered_items.clear();
+                self.filter_state = FilterState::Unfiltered;
+                self.update_pagination();
+            }
+            FilterState::Filtering => {
+                self.filter_state = FilterState::Filtering;
+                // The blink command is lost, but typing into the filter works
+                std::mem::drop(self.filter_input.focus());
+            }
+            FilterState::FilterApplied if !state.filter.is_empty() => {
+                self.filter_state = FilterState::FilterApplied;
+            }
+            FilterState::FilterApplied => {}
+        }
+        self.move_cursor_to(state.cursor);
+    }
+}
+
 impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
     /// Initializes a new empty list model with default settings.
     ///
@@ -339,6 +442,8 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
     /// - **Home/End**: Jump to first/last item
     /// - **/** : Start filtering mode
     /// - **Ctrl+C**: Clear any active filter
+    /// - **Tab / Shift+Tab**: Fold the selected item's section / unfold all,
+    ///   when the list has sections
     ///
     /// # Viewport and Paginator Management
     ///
@@ -346,6 +451,17 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
     /// - Manages viewport scrolling to ensure the cursor remains visible
     /// - Synchronizes the paginator component to reflect the current page
     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some((width, height)) = crate::widget::resized(&msg, self.resize_margins) {
+            self.set_size(width, height);
+            return None;
+        }
+        if self.is_loading_msg(&msg) {
+            return self.handle_loading(msg);
+        }
+        if let Some(timeout) = msg.downcast_ref::<StatusMessageTimeoutMsg>() {
+            self.expire_status_message(timeout);
+            return None;
+        }
         if self.filter_state == FilterState::Filtering {
             if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
                 match key_msg.key {
@@ -410,7 +526,14 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
             return None;
         }
 
+        #[cfg(feature = "mouse")]
+        if crate::mouse::event(&msg).is_some() {
+            self.handle_mouse(&msg);
+            return None;
+        }
+
         if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            let before = self.cursor;
             if self.keymap.cursor_up.matches(key_msg) {
                 if self.cursor > 0 {
                     if self.is_cursor_at_viewport_top() {
@@ -472,6 +595,12 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
             {
                 self.help.show_all = !self.help.show_all;
                 self.update_pagination(); // Recalculate layout since help height changes
+            } else if self.sections.is_some() && self.keymap.toggle_group.matches(key_msg) {
+                if let Some(group) = self.selected_item().and_then(|item| item.group()) {
+                    self.toggle_group(&group);
+                }
+            } else if self.sections.is_some() && self.keymap.expand_all_groups.matches(key_msg) {
+                self.expand_all_groups();
             } else if self.keymap.quit.matches(key_msg) {
                 return Some(bubbletea_rs::quit());
             } else if key_msg.key == crossterm::event::KeyCode::Enter {
@@ -493,12 +622,15 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
                 }
             }
 
+            // Items of collapsed sections are passed over in the direction moved
+            self.skip_collapsed(self.cursor >= before);
+
             // Synchronize the paginator component with the current cursor position.
             // This calculation determines which "page" the cursor is on based on
             // items per page, ensuring the pagination indicator (dots) accurately
             // reflects the user's position in the list.
-            if self.per_page > 0 {
-                self.paginator.page = self.cursor / self.per_page;
+            if let Some(page) = self.cursor.checked_div(self.per_page) {
+                self.paginator.page = page;
             }
         }
         None
@@ -550,7 +682,13 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
     /// let output = list.view();
     /// // Contains formatted list with title, items, and status bar
     /// ```
+    ///
+    /// In accessible mode this is the plain-text
+    /// [`accessible_view`](Model::accessible_view).
     fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
         let mut sections = Vec::new();
 
         // Header: Title or filter input
@@ -595,3 +733,52 @@ impl<I: Item + Send + Sync + 'static> BubbleTeaModel for Model<I> {
         sections.join("\n")
     }
 }
+
+#[cfg(feature = "mouse")]
+impl<I: Item + Send + Sync + 'static> Model<I> {
+    /// Returns the item or section header drawn on `row` of the view.
+    fn row_at(&self, row: usize) -> Option<sections::Row> {
+        let header = self.view_header();
+        let header_lines = if header.is_empty() {
+            0
+        } else {
+            header.split('\n').count()
+        };
+        let offset = row.checked_sub(header_lines)?;
+        if self.sections.is_some() {
+            return self.section_row_at(offset);
+        }
+        let item_height = self.delegate.height() + self.delegate.spacing();
+        if item_height == 0 || offset % item_height >= self.delegate.height() {
+            return None;
+        }
+        let slot = offset / item_height;
+        let index = self.viewport_start + slot;
+        (slot < self.max_visible_items() && index < self.len())
+            .then_some(sections::Row::Item(index))
+    }
+
+    fn handle_mouse(&mut self, msg: &Msg) {
+        use crate::mouse::Gesture;
+        match self.mouse.handle(msg, self.width, self.height) {
+            Some(Gesture::Wheel { dy, .. }) if dy != 0 && !self.is_empty() => {
+                let index = self.cursor.saturating_add_signed(dy as isize);
+                self.move_cursor_to(index);
+                self.skip_collapsed(dy > 0);
+            }
+            Some(Gesture::Press { row, .. }) => match self.row_at(row) {
+                Some(sections::Row::Item(index)) => self.move_cursor_to(index),
+                Some(sections::Row::Header { name, .. }) => {
+                    self.toggle_group(&name);
+                }
+                None => {}
+            },
+            Some(Gesture::Drag { row, .. }) => {
+                if let Some(sections::Row::Item(index)) = self.row_at(row) {
+                    self.move_cursor_to(index);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -4,9 +4,21 @@
 //! including construction, basic state management, and essential accessors.
 
 use super::keys::ListKeyMap;
+use super::sections::SectionDelegate;
 use super::style::ListStyles;
-use super::types::{FilterState, FilteredItem, Item, ItemDelegate};
+use super::types::{FilterFunc, FilterState, FilteredItem, Item, ItemDelegate};
+use crate::widget::Margins;
 use crate::{help, paginator, spinner, textinput};
+use bubbletea_rs::Cmd;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
 
 /// A flexible, interactive list component with filtering, pagination, and customizable rendering.
 ///
@@ -99,6 +111,7 @@ use crate::{help, paginator, spinner, textinput};
 /// let list = Model::new(items, DefaultDelegate::new(), 80, 24);
 /// ```
 pub struct Model<I: Item> {
+    pub(super) id: i64,
     pub(super) title: String,
     pub(super) items: Vec<I>,
     pub(super) delegate: Box<dyn ItemDelegate<I> + Send + Sync>,
@@ -112,14 +125,20 @@ pub struct Model<I: Item> {
     #[allow(dead_code)]
     pub(super) spinner: spinner::Model,
     pub(super) show_spinner: bool,
+    // Items are being loaded by `set_items_cmd`.
+    pub(super) loading: bool,
+    // Matches loaded items to the latest `set_items_cmd`.
+    pub(super) load_tag: u64,
     pub(super) width: usize,
     pub(super) height: usize,
     pub(super) styles: ListStyles,
 
     // Status bar
     pub(super) show_status_bar: bool,
-    #[allow(dead_code)]
-    pub(super) status_message_lifetime: usize,
+    pub(super) status_message: String,
+    pub(super) status_message_lifetime: Duration,
+    // Matches a timeout to the status message it was started for.
+    pub(super) status_message_tag: u64,
     pub(super) status_item_singular: Option<String>,
     pub(super) status_item_plural: Option<String>,
 
@@ -145,6 +164,18 @@ pub struct Model<I: Item> {
 
     // Filter
     pub(super) filter_input: textinput::Model,
+    pub(super) filter_func: FilterFunc,
+
+    // Sections: headers are drawn only once a section delegate is set.
+    pub(super) sections: Option<Box<dyn SectionDelegate + Send + Sync>>,
+    pub(super) collapsed_groups: HashSet<String>,
+
+    // Margins kept when following the terminal size, if enabled.
+    pub(super) resize_margins: Option<Margins>,
+
+    // Mouse click and drag state.
+    #[cfg(feature = "mouse")]
+    pub(super) mouse: crate::mouse::Tracker,
 }
 
 impl<I: Item + Send + Sync + 'static> Model<I> {
@@ -199,6 +230,7 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         paginator.inactive_dot = styles.inactive_pagination_dot.render("");
 
         let mut list = Self {
+            id: next_id(),
             title: "List".to_string(),
             items,
             delegate: Box::new(delegate),
@@ -207,11 +239,15 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
             show_title: true,
             spinner: spinner::new(&[]),
             show_spinner: false,
+            loading: false,
+            load_tag: 0,
             width,
             height,
             styles,
             show_status_bar: true,
-            status_message_lifetime: 1,
+            status_message: String::new(),
+            status_message_lifetime: Duration::from_secs(1),
+            status_message_tag: 0,
             status_item_singular: None,
             status_item_plural: None,
             show_pagination: true,
@@ -223,6 +259,12 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
             cursor: 0,
             viewport_start: 0,
             filter_input: textinput::new(),
+            filter_func: Box::new(super::default_filter),
+            sections: None,
+            collapsed_groups: HashSet::new(),
+            resize_margins: None,
+            #[cfg(feature = "mouse")]
+            mouse: crate::mouse::Tracker::new(),
         };
 
         // Calculate the actual pagination based on the provided height
@@ -233,8 +275,9 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     /// Sets the items displayed in the list.
     ///
     /// This method replaces all current items with the provided vector.
-    /// The cursor is reset to position 0, and pagination is recalculated
-    /// based on the new item count.
+    /// The cursor is reset to position 0, an active filter is run again
+    /// over the new items, and pagination is recalculated based on the new
+    /// item count. Matches Go's `SetItems`.
     ///
     /// # Arguments
     ///
@@ -256,7 +299,7 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     pub fn set_items(&mut self, items: Vec<I>) {
         self.items = items;
         self.cursor = 0;
-        self.update_pagination();
+        self.items_changed(None);
     }
 
     /// Returns a vector of currently visible items.
@@ -484,7 +527,7 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
 
             // Calculate actual footer height based on styles
             let mut footer_height = 0;
-            if self.show_help {
+            if self.shows_footer() {
                 footer_height += self.calculate_element_height("help");
             }
             if self.show_pagination {
@@ -492,11 +535,10 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
             }
 
             let available_height = self.height.saturating_sub(header_height + footer_height);
-            let items_per_page = if item_height > 0 {
-                (available_height / item_height).max(1)
-            } else {
-                5 // Match Go version default
-            };
+            let items_per_page = available_height
+                .checked_div(item_height)
+                .map(|n| n.max(1))
+                .unwrap_or(5); // Match Go version default
 
             self.per_page = items_per_page;
             self.paginator.set_per_page(items_per_page);
@@ -558,7 +600,8 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     ///
     /// # Returns
     ///
-    /// A reference to the selected item, or `None` if no valid selection exists.
+    /// A reference to the selected item, or `None` if no valid selection exists,
+    /// as when every item is in a collapsed section.
     ///
     /// # Examples
     ///
@@ -575,6 +618,9 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     /// }
     /// ```
     pub fn selected_item(&self) -> Option<&I> {
+        if self.is_hidden(self.cursor) {
+            return None;
+        }
         if self.filter_state == FilterState::Unfiltered {
             self.items.get(self.cursor)
         } else {
@@ -837,6 +883,47 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         self
     }
 
+    /// Makes the list follow the terminal size.
+    ///
+    /// Every `WindowSizeMsg` then resizes the list to the terminal size
+    /// minus `margins`, as if [`set_size`](Self::set_size) had been called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bubbletea_widgets::list::{Model, DefaultDelegate, DefaultItem};
+    /// use bubbletea_widgets::widget::Margins;
+    /// let list: Model<DefaultItem> = Model::new(vec![], DefaultDelegate::new(), 80, 24)
+    ///     .auto_resize(Margins::new(1, 0, 2, 0));
+    /// ```
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Sets the screen cell where the list is drawn, so clicks can select
+    /// items. Once set, the wheel only moves the cursor while the pointer
+    /// is over the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bubbletea_widgets::list::{Model, DefaultDelegate, DefaultItem};
+    /// let list: Model<DefaultItem> = Model::new(vec![], DefaultDelegate::new(), 80, 24)
+    ///     .with_mouse_origin(0, 2);
+    /// ```
+    #[cfg(feature = "mouse")]
+    pub fn with_mouse_origin(mut self, x: u16, y: u16) -> Self {
+        self.set_mouse_origin(x, y);
+        self
+    }
+
+    /// Sets the screen cell where the list is drawn (mutable version).
+    #[cfg(feature = "mouse")]
+    pub fn set_mouse_origin(&mut self, x: u16, y: u16) {
+        self.mouse.set_origin(x, y);
+    }
+
     // === UI Component Toggles and Access ===
 
     /// Returns whether pagination is currently shown.
@@ -944,7 +1031,12 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     /// Inserts an item at the specified index.
     ///
     /// All items at and after the specified index are shifted to the right.
-    /// The cursor and pagination are updated appropriately.
+    /// An active filter is run again, so the item shows if it matches, and
+    /// the selected item stays selected.
+    ///
+    /// Returns a command to run, for parity with Go's `InsertItem`, whose
+    /// filtering runs in one; here filtering is immediate and there is
+    /// none yet.
     ///
     /// # Arguments
     ///
@@ -963,27 +1055,49 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     /// list.insert_item(0, DefaultItem::new("First", "Description"));
     /// assert_eq!(list.len(), 1);
     /// ```
-    pub fn insert_item(&mut self, index: usize, item: I) {
+    pub fn insert_item(&mut self, index: usize, item: I) -> Option<Cmd> {
+        let selected = self
+            .global_index()
+            .map(|selected| selected + usize::from(selected >= index));
         self.items.insert(index, item);
-        // Clear any active filter since item indices have changed
-        if self.filter_state != FilterState::Unfiltered {
-            self.filter_state = FilterState::Unfiltered;
-            self.filtered_items.clear();
-        }
-        // Update cursor if needed to maintain current selection
-        if index <= self.cursor {
-            self.cursor = self
-                .cursor
-                .saturating_add(1)
-                .min(self.items.len().saturating_sub(1));
-        }
-        self.update_pagination();
+        self.items_changed(selected);
+        None
+    }
+
+    /// Replaces the item at the specified index, keeping its place and the
+    /// selection. An active filter is run again, so the item drops out if
+    /// it no longer matches.
+    ///
+    /// Returns a command to run, as [`insert_item`](Self::insert_item)
+    /// does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= items_len()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bubbletea_widgets::list::{Model, DefaultDelegate, DefaultItem};
+    /// let mut list = Model::new(
+    ///     vec![DefaultItem::new("Draft", "unsaved")],
+    ///     DefaultDelegate::new(), 80, 24
+    /// );
+    /// list.set_item(0, DefaultItem::new("Draft", "saved"));
+    /// assert_eq!(list.items()[0].desc, "saved");
+    /// ```
+    pub fn set_item(&mut self, index: usize, item: I) -> Option<Cmd> {
+        self.items[index] = item;
+        let selected = self.global_index();
+        self.items_changed(selected);
+        None
     }
 
     /// Removes and returns the item at the specified index.
     ///
-    /// All items after the specified index are shifted to the left.
-    /// The cursor and pagination are updated appropriately.
+    /// All items after the specified index are shifted to the left. The
+    /// selected item stays selected; when it is the one removed, the item
+    /// that takes its place is. An active filter is kept.
     ///
     /// # Arguments
     ///
@@ -1022,23 +1136,15 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         let item_ref = &self.items[index];
         let _ = self.delegate.on_remove(index, item_ref);
 
+        let selected = self
+            .global_index()
+            .and_then(|selected| match selected.cmp(&index) {
+                std::cmp::Ordering::Less => Some(selected),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(selected - 1),
+            });
         let item = self.items.remove(index);
-        // Clear any active filter since item indices have changed
-        if self.filter_state != FilterState::Unfiltered {
-            self.filter_state = FilterState::Unfiltered;
-            self.filtered_items.clear();
-        }
-        // Update cursor to maintain valid position
-        if !self.items.is_empty() {
-            if index < self.cursor {
-                self.cursor = self.cursor.saturating_sub(1);
-            } else if self.cursor >= self.items.len() {
-                self.cursor = self.items.len().saturating_sub(1);
-            }
-        } else {
-            self.cursor = 0;
-        }
-        self.update_pagination();
+        self.items_changed(selected);
         item
     }
 
@@ -1077,25 +1183,20 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
             return; // No movement needed
         }
 
+        let selected = self.global_index().map(|selected| {
+            if selected == from_index {
+                to_index
+            } else if from_index < selected && to_index >= selected {
+                selected - 1
+            } else if from_index > selected && to_index <= selected {
+                selected + 1
+            } else {
+                selected
+            }
+        });
         let item = self.items.remove(from_index);
         self.items.insert(to_index, item);
-
-        // Clear any active filter since item indices have changed
-        if self.filter_state != FilterState::Unfiltered {
-            self.filter_state = FilterState::Unfiltered;
-            self.filtered_items.clear();
-        }
-
-        // Update cursor to follow the moved item if it was selected
-        if self.cursor == from_index {
-            self.cursor = to_index;
-        } else if from_index < self.cursor && to_index >= self.cursor {
-            self.cursor = self.cursor.saturating_sub(1);
-        } else if from_index > self.cursor && to_index <= self.cursor {
-            self.cursor = self.cursor.saturating_add(1);
-        }
-
-        self.update_pagination();
+        self.items_changed(selected);
     }
 
     /// Adds an item to the end of the list.
@@ -1115,13 +1216,9 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     /// assert_eq!(list.len(), 1);
     /// ```
     pub fn push_item(&mut self, item: I) {
+        let selected = self.global_index();
         self.items.push(item);
-        // Clear any active filter since item indices have changed
-        if self.filter_state != FilterState::Unfiltered {
-            self.filter_state = FilterState::Unfiltered;
-            self.filtered_items.clear();
-        }
-        self.update_pagination();
+        self.items_changed(selected);
     }
 
     /// Removes and returns the last item from the list.
@@ -1147,20 +1244,36 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
             return None;
         }
 
+        let last = self.items.len() - 1;
+        let selected = self.global_index().filter(|&selected| selected != last);
         let item = self.items.pop();
-        // Clear any active filter since item indices have changed
-        if self.filter_state != FilterState::Unfiltered {
-            self.filter_state = FilterState::Unfiltered;
-            self.filtered_items.clear();
-        }
-        // Update cursor if it's now out of bounds
-        if self.cursor >= self.items.len() && !self.items.is_empty() {
-            self.cursor = self.items.len() - 1;
-        } else if self.items.is_empty() {
-            self.cursor = 0;
+        self.items_changed(selected);
+        item
+    }
+
+    /// Brings the filter, cursor, viewport and pagination in line with
+    /// changed items. An active filter is run again, and the cursor goes
+    /// back to `selected`, the index among all items of the item that was
+    /// selected, or stays where it was when that item is gone or filtered
+    /// out.
+    fn items_changed(&mut self, selected: Option<usize>) {
+        let position = self.cursor;
+        // Filtering with no text yet shows nothing to rerun
+        if self.filter_state != FilterState::Unfiltered && !self.filter_input.value().is_empty() {
+            self.apply_filter();
         }
+        let visible = selected.and_then(|selected| {
+            if self.filter_state == FilterState::Unfiltered {
+                (selected < self.items.len()).then_some(selected)
+            } else {
+                self.filtered_items
+                    .iter()
+                    .position(|fi| fi.index == selected)
+            }
+        });
         self.update_pagination();
-        item
+        self.move_cursor_to(visible.unwrap_or(position));
+        self.skip_collapsed(true);
     }
 
     /// Returns a reference to the underlying items collection.
@@ -1690,10 +1803,24 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     // === Advanced Filtering API ===
 }
 
+// Delegates render with only the `Item` bound.
+impl<I: Item> Model<I> {
+    /// Returns the index of the selected item among all items, filtered
+    /// or not, or `None` when nothing is selected. Matches Go's
+    /// `GlobalIndex`.
+    pub fn global_index(&self) -> Option<usize> {
+        if self.filter_state == FilterState::Unfiltered {
+            (self.cursor < self.items.len()).then_some(self.cursor)
+        } else {
+            self.filtered_items.get(self.cursor).map(|fi| fi.index)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::list::{DefaultDelegate, DefaultItem};
+    use crate::list::{DefaultDelegate, DefaultItem, State};
 
     #[test]
     fn test_pagination_calculation_fix() {
@@ -1745,4 +1872,458 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_search_selects_visible_item() {
+        use crate::search::Searchable;
+
+        let items: Vec<DefaultItem> = ["Apple", "Banana", "Cherry", "Pineapple"]
+            .iter()
+            .map(|t| DefaultItem::new(t, ""))
+            .collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24);
+        let hits = list.matches("apple");
+        assert_eq!(hits.iter().map(|h| h.row).collect::<Vec<_>>(), [0, 3]);
+        list.goto(hits[1]);
+        assert_eq!(list.cursor(), 3);
+        assert_eq!(list.selected_item().unwrap().title, "Pineapple");
+    }
+
+    #[cfg(feature = "mouse")]
+    #[test]
+    fn test_mouse_selects_clicked_item() {
+        use bubbletea_rs::{Model as BubbleTeaModel, MouseMsg, Msg};
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let event = |button, y| -> Msg {
+            Box::new(MouseMsg {
+                x: 4,
+                y: y as u16,
+                button,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let items: Vec<DefaultItem> = ["Apple", "Banana", "Cherry", "Pineapple"]
+            .iter()
+            .map(|t| DefaultItem::new(t, "fruit"))
+            .collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24).with_mouse_origin(0, 0);
+        let row = list
+            .view()
+            .lines()
+            .position(|l| l.contains("Cherry"))
+            .unwrap();
+
+        list.update(event(MouseEventKind::Down(MouseButton::Left), row));
+        assert_eq!(list.cursor(), 2);
+        list.update(event(MouseEventKind::Up(MouseButton::Left), row));
+
+        // The blank line between items selects nothing
+        list.update(event(MouseEventKind::Down(MouseButton::Left), row + 2));
+        assert_eq!(list.cursor(), 2);
+
+        list.update(event(MouseEventKind::ScrollDown, row));
+        assert_eq!(list.selected_item().unwrap().title, "Pineapple");
+    }
+
+    #[test]
+    fn test_state_restores_filter_and_cursor() {
+        use crate::state::Stateful;
+
+        let fruit = || -> Vec<DefaultItem> {
+            ["Apple", "Banana", "Cherry", "Pineapple"]
+                .iter()
+                .map(|t| DefaultItem::new(t, "fruit"))
+                .collect()
+        };
+        let mut list = Model::new(fruit(), DefaultDelegate::new(), 80, 24);
+        list.set_filter_text("ap");
+        list.filter_state = FilterState::FilterApplied;
+        list.cursor = 1;
+        let saved = list.state();
+        assert_eq!(saved.filter, "ap");
+
+        let mut restored = Model::new(fruit(), DefaultDelegate::new(), 80, 24);
+        restored.restore(saved.clone());
+        assert_eq!(restored.state(), saved);
+        assert_eq!(restored.selected_item().unwrap().title, "Pineapple");
+
+        // A cursor beyond the items is clamped
+        restored.restore(State {
+            cursor: 10,
+            ..State::default()
+        });
+        assert_eq!(restored.cursor(), 3);
+        assert_eq!(restored.filter_state, FilterState::Unfiltered);
+    }
+
+    #[test]
+    fn test_builder_matches_setters() {
+        use bubbletea_rs::Model as BubbleTeaModel;
+
+        let items = vec![
+            DefaultItem::new("Apple", "Red"),
+            DefaultItem::new("Banana", "Yellow"),
+        ];
+        let built = Model::builder()
+            .items(items.clone())
+            .title("Fruit")
+            .width(40)
+            .height(10)
+            .show_status_bar(false)
+            .show_help(false)
+            .build();
+
+        let mut set = Model::new(items, DefaultDelegate::new(), 40, 10);
+        set.title = "Fruit".to_string();
+        set.set_show_status_bar(false);
+        set.set_show_help(false);
+        set.update_pagination();
+
+        assert!(!built.show_status_bar());
+        assert!(!built.show_help());
+        assert!(built.show_title());
+        assert_eq!(built.view(), set.view());
+    }
+
+    #[test]
+    fn test_accessible_view_lists_page_and_announces_selection() {
+        let items: Vec<DefaultItem> = (0..23)
+            .map(|i| DefaultItem::new(&format!("Task {}", i), "Description"))
+            .collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24).with_title("Todo");
+        list.set_status_bar_item_name("task", "tasks");
+        list.cursor = 2;
+
+        let view = list.accessible_view();
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines[0], "Todo");
+        assert_eq!(lines[1], "Task 0");
+        assert_eq!(lines.len(), list.per_page() + 2);
+        assert_eq!(lines[lines.len() - 1], "task 3 of 23 selected: Task 2");
+
+        let empty: Model<DefaultItem> = Model::new(vec![], DefaultDelegate::new(), 80, 24);
+        assert_eq!(empty.accessible_view(), "List\nNo items.");
+    }
+
+    #[test]
+    fn test_items_load_in_the_background() {
+        use crate::list::ItemsLoadedMsg;
+        use bubbletea_rs::Model as BubbleTeaModel;
+
+        let mut list: Model<DefaultItem> = Model::new(vec![], DefaultDelegate::new(), 80, 24);
+        let _cmd = list.set_items_cmd(async { vec![DefaultItem::new("Late", "")] });
+        let first = list.load_tag;
+        let _cmd = list.set_items_cmd(async { vec![DefaultItem::new("Later", "")] });
+        assert!(list.is_loading());
+        assert!(list.show_spinner());
+        assert!(list.view_items().contains("Loading…"));
+        assert_eq!(list.accessible_view(), "List\nLoading…");
+
+        // The spinner turns while it shows
+        let tick = list.spinner().tick_msg();
+        assert!(list.update(Box::new(tick)).is_some());
+
+        // Items for another list are not taken
+        let other = ItemsLoadedMsg {
+            id: list.id() + 1,
+            items: vec![DefaultItem::new("Wrong", "")],
+            tag: list.load_tag,
+        };
+        list.update(Box::new(other));
+        assert!(list.is_loading());
+
+        // A load that was started over finishes without effect
+        let stale = ItemsLoadedMsg {
+            id: list.id(),
+            items: vec![DefaultItem::new("Stale", "")],
+            tag: first,
+        };
+        list.update(Box::new(stale.clone()));
+        assert!(list.is_loading());
+        assert!(list.is_empty());
+
+        let loaded = ItemsLoadedMsg {
+            id: list.id(),
+            items: (0..3)
+                .map(|i| DefaultItem::new(&format!("Item {}", i), ""))
+                .collect(),
+            tag: list.load_tag,
+        };
+        list.update(Box::new(loaded));
+        assert!(!list.is_loading());
+        assert!(!list.show_spinner());
+        assert_eq!(list.len(), 3);
+
+        // Nor does it once the latest load is in
+        list.update(Box::new(stale));
+        assert_eq!(list.len(), 3);
+        let tick = list.spinner().tick_msg();
+        assert!(list.update(Box::new(tick)).is_none());
+    }
+
+    #[test]
+    fn test_status_message_shows_until_its_lifetime_is_up() {
+        use crate::list::StatusMessageTimeoutMsg;
+        use bubbletea_rs::Model as BubbleTeaModel;
+
+        let items = vec![DefaultItem::new("Milk", ""), DefaultItem::new("Eggs", "")];
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24);
+        list.set_status_message_lifetime(Duration::from_millis(10));
+        let first = list.new_status_message("Deleted Bread");
+        let second = list.new_status_message("Deleted Butter");
+        assert!(list.view_footer().contains("Deleted Butter"));
+        assert!(list.accessible_view().ends_with("\nDeleted Butter"));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        // The first message's timeout does not clear its replacement
+        let stale = runtime.block_on(first).expect("timeout message");
+        assert!(stale.is::<StatusMessageTimeoutMsg>());
+        list.update(stale);
+        assert_eq!(list.status_message(), Some("Deleted Butter"));
+
+        let timeout = runtime.block_on(second).expect("timeout message");
+        list.update(timeout);
+        assert_eq!(list.status_message(), None);
+        assert!(!list.view_footer().contains("Deleted"));
+    }
+
+    #[test]
+    fn test_status_message_fits_height_with_help_hidden() {
+        use bubbletea_rs::Model as BubbleTeaModel;
+
+        let items = (0..50)
+            .map(|i| DefaultItem::new(&format!("Item {}", i), ""))
+            .collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 20);
+        list.set_show_help(false);
+        let before = list.view().lines().count();
+        assert!(before <= 20);
+
+        std::mem::drop(list.new_status_message("Deleted Item 0"));
+        let view = list.view();
+        assert!(view.contains("Deleted Item 0"));
+        assert!(view.lines().count() <= 20, "{}", view.lines().count());
+    }
+
+    #[test]
+    fn test_filter_ranks_matches_and_can_be_replaced() {
+        use crate::list::{unsorted_filter, Rank};
+
+        let items = vec![
+            DefaultItem::new("a-lp-ha", ""),
+            DefaultItem::new("Alphabet", ""),
+            DefaultItem::new("alpha", ""),
+        ];
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24);
+        list.set_filter_text("alpha");
+        list.set_filter_state(FilterState::FilterApplied);
+        list.apply_filter();
+
+        // The closest match comes first, and is the one selected
+        let titles: Vec<String> = list.visible_items().iter().map(|i| i.to_string()).collect();
+        assert_eq!(titles[0], "alpha");
+        assert_eq!(list.global_index(), Some(2));
+        assert_eq!(
+            list.matches_for_original_item(2),
+            Some(&vec![0, 1, 2, 3, 4])
+        );
+
+        list.set_filter_func(Box::new(unsorted_filter));
+        assert_eq!(list.selected_item().unwrap().to_string(), "a-lp-ha");
+
+        // Case-sensitive prefix matching, listed last item first
+        list.set_filter_func(Box::new(|term: &str, targets: &[String]| {
+            targets
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, target)| target.starts_with(term))
+                .map(|(index, _)| Rank {
+                    index,
+                    matched_indexes: (0..term.chars().count()).collect(),
+                })
+                .collect()
+        }));
+        list.set_filter_text("Alpha");
+        list.apply_filter();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.global_index(), Some(1));
+    }
+
+    #[test]
+    fn test_item_mutations_keep_filter_and_selection() {
+        let titles = ["apple", "banana", "apricot", "cherry"];
+        let items = titles.iter().map(|t| DefaultItem::new(t, "")).collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24)
+            .with_filter_func(Box::new(crate::list::unsorted_filter));
+        list.set_filter_text("ap");
+        list.set_filter_state(FilterState::FilterApplied);
+        list.apply_filter();
+        list.move_cursor_to(1);
+        assert_eq!(list.selected_item().unwrap().title, "apricot");
+
+        // A matching item shows up, and the selection stays put
+        assert!(list.insert_item(0, DefaultItem::new("grape", "")).is_none());
+        let visible = |list: &Model<DefaultItem>| -> Vec<String> {
+            list.visible_items().into_iter().map(|i| i.title).collect()
+        };
+        assert_eq!(visible(&list), ["grape", "apple", "apricot"]);
+        assert_eq!(list.selected_item().unwrap().title, "apricot");
+        assert_eq!(list.global_index(), Some(3));
+
+        // Items that stop matching drop out
+        list.set_item(0, DefaultItem::new("melon", ""));
+        assert_eq!(visible(&list), ["apple", "apricot"]);
+        assert_eq!(list.selected_item().unwrap().title, "apricot");
+
+        list.remove_item(1);
+        assert_eq!(visible(&list), ["apricot"]);
+        assert_eq!(list.global_index(), Some(2));
+
+        // The filter stays applied when nothing matches any more
+        list.remove_item(2);
+        assert!(list.is_empty());
+        assert_eq!(list.filter_state, FilterState::FilterApplied);
+    }
+
+    #[test]
+    fn test_set_items_reapplies_filter() {
+        let items = ["apple", "banana", "apricot"]
+            .iter()
+            .map(|t| DefaultItem::new(t, ""))
+            .collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 24);
+        list.set_filter_text("ap");
+        list.set_filter_state(FilterState::FilterApplied);
+        list.apply_filter();
+        list.move_cursor_to(1);
+
+        list.set_items(vec![
+            DefaultItem::new("kiwi", ""),
+            DefaultItem::new("melon", ""),
+        ]);
+        assert!(list.visible_items().is_empty());
+        assert!(list.selected_item().is_none());
+        assert_eq!(list.filter_state, FilterState::FilterApplied);
+
+        list.set_items(vec![
+            DefaultItem::new("kiwi", ""),
+            DefaultItem::new("grape", ""),
+        ]);
+        let visible: Vec<String> = list.visible_items().into_iter().map(|i| i.title).collect();
+        assert_eq!(visible, ["grape"]);
+        assert_eq!(list.selected_item().unwrap().title, "grape");
+    }
+
+    #[test]
+    fn test_sections_group_items_and_collapse() {
+        use crate::list::DefaultSectionDelegate;
+        use bubbletea_rs::{KeyMsg, Model as _};
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |key| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let items = vec![
+            DefaultItem::new("todo.md", "").with_group("Pinned"),
+            DefaultItem::new("main.rs", "").with_group("Recent"),
+            DefaultItem::new("lib.rs", "").with_group("Recent"),
+            DefaultItem::new("Cargo.toml", "").with_group("All"),
+        ];
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 30)
+            .with_sections(DefaultSectionDelegate::new());
+        let view = list.accessible_view();
+        assert!(view.starts_with("List\nPinned (1)\ntodo.md\nRecent (2)\nmain.rs\nlib.rs\nAll (1)"));
+
+        // Headers are passed over
+        list.update(key(KeyCode::Down));
+        assert_eq!(list.selected_item().unwrap().title, "main.rs");
+
+        // Folding the selected section hides its items and moves on
+        list.update(key(KeyCode::Tab));
+        assert!(list.is_group_collapsed("Recent"));
+        assert_eq!(list.selected_item().unwrap().title, "Cargo.toml");
+        assert!(list
+            .accessible_view()
+            .contains("Recent (2), collapsed\nAll (1)\nCargo.toml"));
+        let view = list.view();
+        assert!(view.contains("▸ Recent (2)"));
+        assert!(!view.contains("main.rs"));
+
+        list.update(key(KeyCode::Up));
+        assert_eq!(list.selected_item().unwrap().title, "todo.md");
+
+        list.update(key(KeyCode::BackTab));
+        assert!(!list.is_group_collapsed("Recent"));
+        assert!(list.view().contains("main.rs"));
+
+        // Scrolling keeps the selection in view despite the headers
+        let items = (0..30)
+            .map(|i| {
+                DefaultItem::new(&format!("item {}", i), "").with_group(&format!("G{}", i / 3))
+            })
+            .collect();
+        let mut list = Model::new(items, DefaultDelegate::new(), 80, 14)
+            .with_sections(DefaultSectionDelegate::new());
+        for i in 1..30 {
+            list.update(key(KeyCode::Down));
+            let line = format!("\nitem {}\n", i);
+            assert!(
+                list.accessible_view().contains(&line),
+                "item {} not shown",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_collapsed_sections_stay_unselected_as_items_change() {
+        use crate::list::DefaultSectionDelegate;
+
+        let item = |title: &str, group: &str| DefaultItem::new(title, "").with_group(group);
+        let mut list = Model::new(
+            vec![item("main.rs", "Recent")],
+            DefaultDelegate::new(),
+            80,
+            24,
+        )
+        .with_sections(DefaultSectionDelegate::new());
+        list.collapse_group("Pinned");
+
+        // New items put the cursor on a collapsed section's item
+        list.set_items(vec![
+            item("todo.md", "Pinned"),
+            item("main.rs", "Recent"),
+            item("lib.rs", "Recent"),
+        ]);
+        assert_eq!(list.selected_item().unwrap().title, "main.rs");
+
+        list.remove_item(1);
+        assert_eq!(list.selected_item().unwrap().title, "lib.rs");
+
+        // So does filtering, which starts from the top
+        list.set_filter_text(".");
+        list.set_filter_state(FilterState::FilterApplied);
+        list.apply_filter();
+        let visible: Vec<String> = list.visible_items().into_iter().map(|i| i.title).collect();
+        assert_eq!(visible, ["todo.md", "lib.rs"]);
+        assert_eq!(list.selected_item().unwrap().title, "lib.rs");
+        list.clear_filter();
+        assert_eq!(list.selected_item().unwrap().title, "lib.rs");
+
+        // With every section collapsed nothing can be selected
+        list.collapse_group("Recent");
+        assert!(list.selected_item().is_none());
+        list.insert_item(0, item("notes.md", "Recent"));
+        assert!(list.selected_item().is_none());
+        list.expand_group("Recent");
+        assert!(list.selected_item().is_some());
+    }
 }
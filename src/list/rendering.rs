@@ -71,7 +71,7 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     /// **CRITICAL**: The delegate's `render` method receives the *original* item index
     /// from the full items list, not a viewport-relative or filtered-relative index.
     /// This design ensures that:
-    /// - Cursor highlighting works correctly (`index == m.cursor`)
+    /// - Cursor highlighting works correctly (`Some(index) == m.global_index()`)
     /// - Filter highlighting can find matches by searching filtered_items
     /// - Navigation state remains consistent across viewport changes
     ///
@@ -79,41 +79,31 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
     ///
     /// When no items are available (either empty list or no filter matches),
     /// displays an appropriate message styled with the list's no-items style.
+    /// While items are loading, "Loading…" takes their place in the same style.
+    ///
+    /// With a section delegate set, headers are drawn between the items;
+    /// see [`view_sections`](Self::view_sections).
     ///
     /// # Returns
     ///
     /// A formatted string containing all visible items with proper styling and spacing.
     pub(super) fn view_items(&self) -> String {
+        if self.loading {
+            return self.styles.no_items.render("Loading…");
+        }
         if self.is_empty() {
             return self.styles.no_items.render("No items.");
         }
 
         // Calculate how many items can fit in the viewport
-        let item_height = self.delegate.height() + self.delegate.spacing();
-        if item_height == 0 {
+        let max_visible_items = self.max_visible_items();
+        if max_visible_items == 0 {
             return String::new();
         }
-
-        // Calculate available height for items using the same logic as update_pagination()
-        let mut header_height = 0;
-        if self.show_title {
-            header_height += self.calculate_element_height("title");
-        }
-        if self.show_status_bar {
-            header_height += self.calculate_element_height("status_bar");
-        }
-
-        let mut footer_height = 0;
-        if self.show_help {
-            footer_height += self.calculate_element_height("help");
-        }
-        if self.show_pagination {
-            footer_height += self.calculate_element_height("pagination");
+        if self.sections.is_some() {
+            return self.view_sections();
         }
 
-        let available_height = self.height.saturating_sub(header_height + footer_height);
-        let max_visible_items = (available_height / item_height).max(1);
-
         // Determine which items to render based on viewport position
         let items_to_render: Vec<(usize, &I)> = if self.filter_state == FilterState::Unfiltered {
             // For unfiltered lists, use original items with their indices
@@ -157,6 +147,35 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         result
     }
 
+    /// Returns how many items `view_items` renders at most, or 0 when the
+    /// delegate has no height.
+    pub(super) fn max_visible_items(&self) -> usize {
+        let item_height = self.delegate.height() + self.delegate.spacing();
+        if item_height == 0 {
+            return 0;
+        }
+
+        // Calculate available height for items using the same logic as update_pagination()
+        let mut header_height = 0;
+        if self.show_title {
+            header_height += self.calculate_element_height("title");
+        }
+        if self.show_status_bar {
+            header_height += self.calculate_element_height("status_bar");
+        }
+
+        let mut footer_height = 0;
+        if self.shows_footer() {
+            footer_height += self.calculate_element_height("help");
+        }
+        if self.show_pagination {
+            footer_height += self.calculate_element_height("pagination");
+        }
+
+        let available_height = self.height.saturating_sub(header_height + footer_height);
+        (available_height / item_height).max(1)
+    }
+
     /// Renders the status line for header display (matching Go version layout).
     ///
     /// This creates a simple status line showing item counts that appears in the header
@@ -198,19 +217,86 @@ impl<I: Item + Send + Sync + 'static> Model<I> {
         }
     }
 
+    /// Renders the list as plain text for screen readers.
+    ///
+    /// The title (or the filter being typed) comes first, then the items
+    /// on the current page one per line, then an announcement of the
+    /// selection such as `item 3 of 10 selected: Banana`. Items are shown
+    /// by their `Display` form, without the delegate's styling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bubbletea_widgets::list::{Model, DefaultDelegate, DefaultItem};
+    /// let items = vec![DefaultItem::new("Apple", ""), DefaultItem::new("Banana", "")];
+    /// let list = Model::new(items, DefaultDelegate::new(), 80, 24).with_title("Fruit");
+    /// assert_eq!(list.accessible_view(), "Fruit\nApple\nBanana\nitem 1 of 2 selected: Apple");
+    /// ```
+    pub fn accessible_view(&self) -> String {
+        let mut lines = Vec::new();
+        if self.filter_state == FilterState::Filtering {
+            lines.push(format!("Filter: {}", self.filter_input.value()));
+        } else if self.show_title && !self.title.is_empty() {
+            if self.filter_state == FilterState::FilterApplied {
+                lines.push(format!("{} ({} matched)", self.title, self.len()));
+            } else {
+                lines.push(self.title.clone());
+            }
+        }
+        if self.loading {
+            lines.push("Loading…".to_string());
+            return lines.join("\n");
+        }
+        if self.is_empty() {
+            lines.push("No items.".to_string());
+            return lines.join("\n");
+        }
+
+        if self.sections.is_some() {
+            lines.extend(self.accessible_sections());
+        } else {
+            let visible: Box<dyn Iterator<Item = &I>> =
+                if self.filter_state == FilterState::Unfiltered {
+                    Box::new(self.items.iter())
+                } else {
+                    Box::new(self.filtered_items.iter().map(|fi| &fi.item))
+                };
+            let page = visible
+                .skip(self.viewport_start)
+                .take(self.max_visible_items().max(1));
+            lines.extend(page.map(|item| crate::a11y::plain(&item.to_string())));
+        }
+        if self.show_spinner {
+            lines.push("Loading".to_string());
+        }
+        let noun = self.status_item_singular.as_deref().unwrap_or("item");
+        let label = self
+            .selected_item()
+            .map(|item| item.to_string())
+            .unwrap_or_default();
+        lines.push(crate::a11y::selected(noun, self.cursor, self.len(), &label));
+        lines.extend(self.status_message().map(str::to_string));
+        lines.join("\n")
+    }
+
     /// Renders the footer containing only help information (matching Go version layout).
     ///
     /// The footer now only includes help text, as status information has been moved
     /// to the header area to match the Go version's layout.
     ///
     /// The help content automatically adapts to the current filtering state,
-    /// showing relevant key bindings for the user's current context.
+    /// showing relevant key bindings for the user's current context. A status
+    /// message takes the place of the help while it is shown, even with the
+    /// help hidden.
     ///
     /// # Returns
     ///
     /// A formatted string containing just the help information,
     /// or an empty string if help is disabled.
     pub(super) fn view_footer(&self) -> String {
+        if let Some(message) = self.status_message() {
+            return self.styles.help_style.render(message);
+        }
         if !self.show_help {
             return String::new();
         }
@@ -0,0 +1,358 @@
+//! Grouped sections for list components.
+//!
+//! With a [`SectionDelegate`] set, items are listed under a header for their
+//! [`Item::group`], such as "Pinned", "Recent" and "All". Headers are not
+//! items: the cursor steps from the last item of one section straight to the
+//! first item of the next. A section can be collapsed to its header, hiding
+//! its items until it is expanded again.
+
+use super::types::{FilterState, Item};
+use super::Model;
+use crate::text;
+use lipgloss_extras::prelude::*;
+
+/// Renders the header rows of a sectioned list.
+///
+/// # Examples
+///
+/// ```
+/// use bubbletea_widgets::list::SectionDelegate;
+///
+/// struct Plain;
+///
+/// impl SectionDelegate for Plain {
+///     fn render(&self, name: &str, count: usize, _collapsed: bool, _width: usize) -> String {
+///         format!("── {} ({}) ──", name, count)
+///     }
+/// }
+/// ```
+pub trait SectionDelegate {
+    /// Renders the header of the section `name`, which holds `count` visible
+    /// items, in at most `width` columns.
+    fn render(&self, name: &str, count: usize, collapsed: bool, width: usize) -> String;
+
+    /// The number of lines a header takes; one by default.
+    fn height(&self) -> usize {
+        1
+    }
+}
+
+/// Draws headers as `▾ Name (count)`, with `▸` once collapsed.
+#[derive(Debug, Clone)]
+pub struct DefaultSectionDelegate {
+    /// Style of the whole header.
+    pub style: Style,
+}
+
+impl DefaultSectionDelegate {
+    /// Creates the delegate with a bold, muted header style.
+    pub fn new() -> Self {
+        Self {
+            style: Style::new().bold(true).foreground(AdaptiveColor {
+                Light: "#A49FA5",
+                Dark: "#777777",
+            }),
+        }
+    }
+}
+
+impl Default for DefaultSectionDelegate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SectionDelegate for DefaultSectionDelegate {
+    fn render(&self, name: &str, count: usize, collapsed: bool, width: usize) -> String {
+        let marker = if collapsed { '▸' } else { '▾' };
+        let header = format!("{} {} ({})", marker, name, count);
+        self.style.render(&text::truncate(&header, width))
+    }
+}
+
+/// A line of a sectioned list: a section header, or the item at a visible
+/// position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Row {
+    Header {
+        name: String,
+        count: usize,
+        collapsed: bool,
+    },
+    Item(usize),
+}
+
+impl<I: Item + Send + Sync + 'static> Model<I> {
+    /// Lists the items under a header for their [`Item::group`], drawn by
+    /// `delegate`. Items without a group get no header.
+    ///
+    /// Items of a section should be next to each other; an applied filter
+    /// ranks matches by score, which can split sections, unless the filter
+    /// function is [`unsorted_filter`](super::unsorted_filter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bubbletea_widgets::list::{DefaultDelegate, DefaultItem, DefaultSectionDelegate, Model};
+    ///
+    /// let items = vec![
+    ///     DefaultItem::new("todo.md", "").with_group("Pinned"),
+    ///     DefaultItem::new("main.rs", "").with_group("Recent"),
+    ///     DefaultItem::new("lib.rs", "").with_group("Recent"),
+    /// ];
+    /// let mut list = Model::new(items, DefaultDelegate::new(), 80, 24)
+    ///     .with_sections(DefaultSectionDelegate::new());
+    ///
+    /// list.collapse_group("Pinned");
+    /// assert!(list.is_group_collapsed("Pinned"));
+    /// // The cursor left the collapsed section
+    /// assert_eq!(list.selected_item().unwrap().title, "main.rs");
+    /// ```
+    pub fn set_section_delegate<D>(&mut self, delegate: D)
+    where
+        D: SectionDelegate + Send + Sync + 'static,
+    {
+        self.sections = Some(Box::new(delegate));
+        self.skip_collapsed(true);
+    }
+
+    /// Lists the items under section headers (builder pattern); see
+    /// [`set_section_delegate`](Self::set_section_delegate).
+    pub fn with_sections<D>(mut self, delegate: D) -> Self
+    where
+        D: SectionDelegate + Send + Sync + 'static,
+    {
+        self.set_section_delegate(delegate);
+        self
+    }
+
+    /// Lists the items without section headers again. Collapsed sections
+    /// are remembered for when headers come back.
+    pub fn clear_section_delegate(&mut self) {
+        self.sections = None;
+    }
+
+    /// Hides the items of the section `name` under its header. A selected
+    /// item in it passes the selection on to the next item shown.
+    pub fn collapse_group(&mut self, name: &str) {
+        self.collapsed_groups.insert(name.to_string());
+        self.skip_collapsed(true);
+    }
+
+    /// Shows the items of the section `name` again.
+    pub fn expand_group(&mut self, name: &str) {
+        self.collapsed_groups.remove(name);
+    }
+
+    /// Shows the items of every section.
+    pub fn expand_all_groups(&mut self) {
+        self.collapsed_groups.clear();
+    }
+
+    /// Collapses the section `name` if expanded and expands it otherwise,
+    /// returning whether it is now collapsed.
+    pub fn toggle_group(&mut self, name: &str) -> bool {
+        if self.is_group_collapsed(name) {
+            self.expand_group(name);
+            false
+        } else {
+            self.collapse_group(name);
+            true
+        }
+    }
+
+    /// Whether the section `name` is collapsed.
+    pub fn is_group_collapsed(&self, name: &str) -> bool {
+        self.collapsed_groups.contains(name)
+    }
+
+    /// The original index and item at visible position `pos`.
+    fn visible_entry(&self, pos: usize) -> Option<(usize, &I)> {
+        if self.filter_state == FilterState::Unfiltered {
+            self.items.get(pos).map(|item| (pos, item))
+        } else {
+            self.filtered_items.get(pos).map(|fi| (fi.index, &fi.item))
+        }
+    }
+
+    /// Whether the item at visible position `pos` is in a collapsed section.
+    pub(super) fn is_hidden(&self, pos: usize) -> bool {
+        self.sections.is_some()
+            && self
+                .visible_entry(pos)
+                .and_then(|(_, item)| item.group())
+                .is_some_and(|group| self.collapsed_groups.contains(&group))
+    }
+
+    /// Moves the cursor off a collapsed section, to the nearest item shown
+    /// in the direction of travel, or the other way at the end of the list.
+    /// With every item hidden the cursor stays put and nothing is selected.
+    pub(super) fn skip_collapsed(&mut self, forward: bool) {
+        if !self.is_hidden(self.cursor) {
+            return;
+        }
+        let after = (self.cursor + 1..self.len()).find(|&pos| !self.is_hidden(pos));
+        let before = (0..self.cursor).rev().find(|&pos| !self.is_hidden(pos));
+        let target = if forward {
+            after.or(before)
+        } else {
+            before.or(after)
+        };
+        if let Some(pos) = target {
+            self.move_cursor_to(pos);
+        }
+    }
+
+    /// Every row of the sectioned list, top to bottom.
+    pub(super) fn rows(&self) -> Vec<Row> {
+        let groups: Vec<Option<String>> = (0..self.len())
+            .map(|pos| self.visible_entry(pos).and_then(|(_, item)| item.group()))
+            .collect();
+        let mut rows = Vec::new();
+        let mut pos = 0;
+        while pos < groups.len() {
+            let Some(name) = &groups[pos] else {
+                rows.push(Row::Item(pos));
+                pos += 1;
+                continue;
+            };
+            let end = groups[pos..]
+                .iter()
+                .position(|group| group.as_ref() != Some(name))
+                .map_or(groups.len(), |len| pos + len);
+            let collapsed = self.collapsed_groups.contains(name);
+            rows.push(Row::Header {
+                name: name.clone(),
+                count: end - pos,
+                collapsed,
+            });
+            if !collapsed {
+                rows.extend((pos..end).map(Row::Item));
+            }
+            pos = end;
+        }
+        rows
+    }
+
+    /// The number of lines `row` takes.
+    fn row_height(&self, row: &Row) -> usize {
+        match row {
+            Row::Header { .. } => self.sections.as_ref().map_or(0, |s| s.height()),
+            Row::Item(_) => self.delegate.height() + self.delegate.spacing(),
+        }
+    }
+
+    /// The rows drawn: from the item at `viewport_start`, with the header
+    /// right above it, scrolled on as far as it takes to show the cursor.
+    pub(super) fn section_window(&self) -> Vec<Row> {
+        let rows = self.rows();
+        let budget = self.max_visible_items() * (self.delegate.height() + self.delegate.spacing());
+        let is_header = |row: &Row| matches!(row, Row::Header { .. });
+        let header_above =
+            |at: usize| at.saturating_sub(usize::from(at > 0 && is_header(&rows[at - 1])));
+
+        let first = rows
+            .iter()
+            .position(|row| matches!(row, Row::Item(pos) if *pos >= self.viewport_start))
+            .unwrap_or(0);
+        let mut start = header_above(first);
+        if let Some(cursor) = rows.iter().position(|row| *row == Row::Item(self.cursor)) {
+            if cursor < start {
+                start = header_above(cursor);
+            }
+            while start < cursor
+                && rows[start..=cursor]
+                    .iter()
+                    .map(|row| self.row_height(row))
+                    .sum::<usize>()
+                    > budget
+            {
+                start += 1;
+            }
+        }
+
+        let mut used = 0;
+        let mut window = Vec::new();
+        for row in rows.into_iter().skip(start) {
+            used += self.row_height(&row);
+            if used > budget && !window.is_empty() {
+                break;
+            }
+            window.push(row);
+        }
+        window
+    }
+
+    /// Renders the visible rows of a sectioned list.
+    pub(super) fn view_sections(&self) -> String {
+        let Some(sections) = &self.sections else {
+            return String::new();
+        };
+        let mut lines = Vec::new();
+        for row in self.section_window() {
+            match row {
+                Row::Header {
+                    name,
+                    count,
+                    collapsed,
+                } => lines.push(sections.render(&name, count, collapsed, self.width)),
+                Row::Item(pos) => {
+                    let Some((index, item)) = self.visible_entry(pos) else {
+                        continue;
+                    };
+                    let rendered = self.delegate.render(self, index, item);
+                    if !rendered.is_empty() {
+                        lines.push(rendered);
+                        lines.extend((0..self.delegate.spacing()).map(|_| String::new()));
+                    }
+                }
+            }
+        }
+        let mut result = lines.join("\n");
+        while result.ends_with('\n') {
+            result.pop();
+        }
+        result
+    }
+
+    /// The visible rows of a sectioned list as plain text, headers reading
+    /// like `Recent (2)` or `Pinned (1), collapsed`.
+    pub(super) fn accessible_sections(&self) -> Vec<String> {
+        self.section_window()
+            .into_iter()
+            .filter_map(|row| match row {
+                Row::Header {
+                    name,
+                    count,
+                    collapsed,
+                } => Some(format!(
+                    "{} ({}){}",
+                    name,
+                    count,
+                    if collapsed { ", collapsed" } else { "" }
+                )),
+                Row::Item(pos) => self
+                    .visible_entry(pos)
+                    .map(|(_, item)| crate::a11y::plain(&item.to_string())),
+            })
+            .collect()
+    }
+
+    /// The row drawn at `line` of the items area, if any.
+    #[cfg(feature = "mouse")]
+    pub(super) fn section_row_at(&self, line: usize) -> Option<Row> {
+        let mut top = 0;
+        for row in self.section_window() {
+            let height = self.row_height(&row);
+            if line < top + height {
+                let drawn = match row {
+                    Row::Header { .. } => height,
+                    Row::Item(_) => self.delegate.height(),
+                };
+                return (line - top < drawn).then_some(row);
+            }
+            top += height;
+        }
+        None
+    }
+}
@@ -0,0 +1,75 @@
+//! Transient status messages for list components.
+//!
+//! A status message is a short notice such as "Deleted 'groceries'" shown
+//! in the footer, in place of the help, until its lifetime is up. This
+//! matches Go's `NewStatusMessage` and `StatusMessageLifetime`.
+
+use super::types::{Item, StatusMessageTimeoutMsg};
+use super::Model;
+use bubbletea_rs::{Cmd, Msg};
+use std::time::Duration;
+
+impl<I: Item + Send + Sync + 'static> Model<I> {
+    /// Shows `message` in the footer and returns the command that clears
+    /// it after [`status_message_lifetime`](Self::status_message_lifetime).
+    /// A newer message replaces the shown one and gets a lifetime of its
+    /// own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bubbletea_widgets::list::{DefaultDelegate, DefaultItem, Model};
+    ///
+    /// let mut list = Model::new(
+    ///     vec![DefaultItem::new("Groceries", "")],
+    ///     DefaultDelegate::new(),
+    ///     80,
+    ///     24,
+    /// );
+    /// let removed = list.remove_item(0);
+    /// let cmd = list.new_status_message(format!("Deleted {}", removed));
+    /// assert_eq!(list.status_message(), Some("Deleted Groceries"));
+    /// // Return `cmd` from `update` so the message expires
+    /// # drop(cmd);
+    /// ```
+    pub fn new_status_message(&mut self, message: impl Into<String>) -> Cmd {
+        self.status_message = message.into();
+        self.status_message_tag += 1;
+        self.update_pagination();
+        let id = self.id;
+        let tag = self.status_message_tag;
+        bubbletea_rs::tick(self.status_message_lifetime, move |_| {
+            Box::new(StatusMessageTimeoutMsg { id, tag }) as Msg
+        })
+    }
+
+    /// The status message being shown, if any.
+    pub fn status_message(&self) -> Option<&str> {
+        Some(self.status_message.as_str()).filter(|message| !message.is_empty())
+    }
+
+    /// How long status messages are shown; one second by default.
+    pub fn status_message_lifetime(&self) -> Duration {
+        self.status_message_lifetime
+    }
+
+    /// Sets how long status messages are shown. Messages already shown
+    /// keep the lifetime they were given.
+    pub fn set_status_message_lifetime(&mut self, lifetime: Duration) {
+        self.status_message_lifetime = lifetime;
+    }
+
+    /// Whether the footer is drawn: for the help, or for a status message
+    /// while help is hidden. Item rows leave room for it.
+    pub(super) fn shows_footer(&self) -> bool {
+        self.show_help || self.status_message().is_some()
+    }
+
+    /// Clears the status message if `timeout` is for the one shown.
+    pub(super) fn expire_status_message(&mut self, timeout: &StatusMessageTimeoutMsg) {
+        if timeout.id == self.id && timeout.tag == self.status_message_tag {
+            self.status_message.clear();
+            self.update_pagination();
+        }
+    }
+}
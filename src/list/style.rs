@@ -48,6 +48,7 @@
 //! println!("Truncated: item1, item2{}", ELLIPSIS);
 //! ```
 
+use crate::theme::Theme;
 use lipgloss_extras::prelude::*;
 
 /// Unicode bullet character (•) used in pagination indicators and visual separators.
@@ -95,19 +96,14 @@ pub const BULLET: &str = "•";
 /// ```rust
 /// use bubbletea_widgets::list::style::ELLIPSIS;
 ///
-/// // Simulate text truncation
+/// // `text::truncate` measures display width and appends this marker
 /// let long_text = "This is a very long text that needs truncation";
-/// let max_width = 20;
+/// let truncated = bubbletea_widgets::text::truncate(long_text, 20);
 ///
-/// let truncated = if long_text.len() > max_width {
-///     format!("{}{}", &long_text[..max_width-1], ELLIPSIS)
-/// } else {
-///     long_text.to_string()
-/// };
-///
-/// println!("{}", truncated); // "This is a very lon…"
+/// assert_eq!(truncated, "This is a very long…");
+/// assert!(truncated.ends_with(ELLIPSIS));
 /// ```
-pub const ELLIPSIS: &str = "…";
+pub const ELLIPSIS: &str = crate::text::ELLIPSIS;
 
 /// Comprehensive styling configuration for all list component UI elements.
 ///
@@ -353,3 +349,45 @@ impl Default for ListStyles {
         }
     }
 }
+
+impl ListStyles {
+    /// Creates list styles from a theme, keeping the default layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::list::{DefaultDelegate, DefaultItem, DefaultItemStyles, ListStyles, Model};
+    /// use bubbletea_widgets::theme::Theme;
+    ///
+    /// let theme = Theme::dark();
+    /// let mut delegate = DefaultDelegate::new();
+    /// delegate.styles = DefaultItemStyles::from_theme(&theme);
+    /// let list: Model<DefaultItem> =
+    ///     Model::new(vec![], delegate, 40, 10).with_styles(ListStyles::from_theme(&theme));
+    /// ```
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let defaults = Self::default();
+        Self {
+            title: defaults
+                .title
+                .background(p.secondary.clone())
+                .foreground(p.background.clone()),
+            spinner: theme.muted.clone(),
+            filter_prompt: Style::new().foreground(p.success.clone()),
+            filter_cursor: Style::new().foreground(p.primary.clone()),
+            status_bar: defaults.status_bar.foreground(p.muted.clone()),
+            status_empty: theme.subtle.clone(),
+            status_bar_active_filter: theme.text.clone(),
+            status_bar_filter_count: theme.subtle.clone(),
+            no_items: theme.muted.clone(),
+            arabic_pagination: theme.muted.clone(),
+            active_pagination_dot: defaults.active_pagination_dot.foreground(p.muted.clone()),
+            inactive_pagination_dot: defaults
+                .inactive_pagination_dot
+                .foreground(p.subtle.clone()),
+            divider_dot: defaults.divider_dot.foreground(p.subtle.clone()),
+            ..defaults
+        }
+    }
+}
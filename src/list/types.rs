@@ -75,6 +75,15 @@ pub trait Item: Display + Clone {
     /// }
     /// ```
     fn filter_value(&self) -> String;
+
+    /// The name of the section this item is listed under, or `None` for
+    /// none. Only used once the list has a section delegate; see
+    /// [`Model::set_section_delegate`](super::Model::set_section_delegate).
+    /// Items of a section should be next to each other, as a new header
+    /// starts wherever the section changes.
+    fn group(&self) -> Option<String> {
+        None
+    }
 }
 
 /// Trait for customizing how list items are rendered and behave.
@@ -126,7 +135,7 @@ pub trait ItemDelegate<I: Item> {
     ///
     /// impl<I: Item + Send + Sync + 'static> ItemDelegate<I> for CustomDelegate {
     ///     fn render(&self, m: &Model<I>, index: usize, item: &I) -> String {
-    ///         if index == m.cursor() {
+    ///         if Some(index) == m.global_index() {
     ///             format!("> {}", item)  // Highlight selected item
     ///         } else {
     ///             format!("  {}", item)  // Normal item
@@ -427,6 +436,27 @@ pub(super) struct FilteredItem<I: Item> {
     pub matches: Vec<usize>,
 }
 
+/// An item that matched a filter, as returned by a [`FilterFunc`].
+///
+/// Matches Go's `list.Rank`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Rank {
+    /// The index of the item among the targets the filter was given.
+    pub index: usize,
+    /// The character indices of the item's filter value that matched,
+    /// which delegates highlight.
+    pub matched_indexes: Vec<usize>,
+}
+
+/// Decides which items match a filter term, and in what order they are
+/// listed.
+///
+/// It is given the term and every item's [`Item::filter_value`], and
+/// returns a [`Rank`] for each match, best first. Matches Go's
+/// `list.FilterFunc`; see [`default_filter`](super::default_filter) and
+/// [`unsorted_filter`](super::unsorted_filter).
+pub type FilterFunc = Box<dyn Fn(&str, &[String]) -> Vec<Rank> + Send + Sync>;
+
 /// Represents the current filtering state of the list.
 ///
 /// This enum tracks the three distinct phases of the filtering process:
@@ -451,7 +481,8 @@ pub(super) struct FilteredItem<I: Item> {
 /// let state = FilterState::Unfiltered;
 /// assert_eq!(state, FilterState::Unfiltered);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FilterState {
     /// No filtering is active; all items are shown normally.
     ///
@@ -460,6 +491,7 @@ pub enum FilterState {
     /// - The list title is shown in the header
     /// - No filter input box is displayed
     /// - Navigation keys work normally
+    #[default]
     Unfiltered,
 
     /// User is actively typing a filter term; live filtering UI is shown.
@@ -515,3 +547,28 @@ pub struct FilterStateInfo {
     /// Whether the list is in the process of clearing the filter.
     pub is_clearing: bool,
 }
+
+/// Carries the items loaded by [`Model::set_items_cmd`](super::Model::set_items_cmd).
+///
+/// The list replaces its items with these once the message comes back
+/// through its `update`, and stops showing that it is loading. The ID
+/// matches the message to the list that asked for it, and only the latest
+/// load of that list is taken.
+#[derive(Debug, Clone)]
+pub struct ItemsLoadedMsg<I> {
+    /// The ID of the list that started the load.
+    pub id: i64,
+    /// The loaded items.
+    pub items: Vec<I>,
+    // Matches the message to the load it was started for.
+    pub(super) tag: u64,
+}
+
+/// Clears a status message set with
+/// [`Model::new_status_message`](super::Model::new_status_message) once its
+/// lifetime is up; pass it back to the list's `update`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusMessageTimeoutMsg {
+    pub(super) id: i64,
+    pub(super) tag: u64,
+}
@@ -0,0 +1,802 @@
+//! Scrolling log viewer.
+//!
+//! The log viewer keeps the most recent lines in a ring buffer capped at
+//! [`Model::max_lines`] and displays them inside an embedded
+//! [`viewport`](crate::viewport). In follow mode it sticks to the bottom as
+//! lines arrive, like `tail -f`; scrolling up pauses following and scrolling
+//! back to the bottom resumes it.
+//!
+//! Each line is assigned a [`Level`] by a parser hook (by default
+//! [`parse_level`], which looks for `ERROR`, `WARN`, `INFO`, ... near the
+//! start of the line). Levels drive the line color and the minimum-level
+//! filter. Lines can be prefixed with their arrival time and searched like
+//! the `markdown` viewer.
+//!
+//! # Navigation Controls
+//!
+//! Scrolling uses the viewport key bindings. In addition:
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `F` | Toggle follow mode |
+//! | `L` | Cycle the minimum level shown |
+//! | `t` | Toggle timestamps |
+//! | `/` | Start a search |
+//! | `enter` / `esc` | Run / abandon the search being typed |
+//! | `n` / `N` | Jump to the next / previous match |
+//! | `esc` | Clear the active search |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::logview::{self, Level};
+//!
+//! let mut logs = logview::new(80, 10).with_max_lines(1000);
+//! logs.push("INFO server started");
+//! logs.push("WARN disk 91% full");
+//! logs.push("ERROR connection refused");
+//!
+//! logs.set_min_level(Some(Level::Warn));
+//! assert_eq!(logs.visible_len(), 2);
+//! assert_eq!(logs.search("refused"), 1);
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{format_time, highlight};
+use crate::theme::Theme;
+use crate::{textinput, viewport};
+use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::collections::VecDeque;
+use std::time::SystemTime;
+
+/// Severity of a log line, from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Level {
+    /// Very detailed tracing output.
+    Trace,
+    /// Debugging output.
+    Debug,
+    /// Normal operational messages.
+    Info,
+    /// Something unexpected that did not stop the program.
+    Warn,
+    /// Failures.
+    Error,
+}
+
+impl Level {
+    /// Returns the upper-case name of the level.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        }
+    }
+}
+
+/// Finds a level keyword among the first few non-numeric words of `line`.
+///
+/// Recognises `TRACE`, `DEBUG`, `INFO`, `WARN`/`WARNING` and
+/// `ERROR`/`ERR`/`FATAL` in any case, including bracketed forms such as
+/// `[warn]` or `level=error`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::logview::{parse_level, Level};
+///
+/// assert_eq!(parse_level("2024-01-01 12:00:00 [WARN] low memory"), Some(Level::Warn));
+/// assert_eq!(parse_level("level=error msg=boom"), Some(Level::Error));
+/// assert_eq!(parse_level("plain text"), None);
+/// ```
+pub fn parse_level(line: &str) -> Option<Level> {
+    line.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty() && !w.chars().all(|c| c.is_ascii_digit()))
+        .take(4)
+        .find_map(|word| match word.to_ascii_uppercase().as_str() {
+            "TRACE" => Some(Level::Trace),
+            "DEBUG" => Some(Level::Debug),
+            "INFO" => Some(Level::Info),
+            "WARN" | "WARNING" => Some(Level::Warn),
+            "ERROR" | "ERR" | "FATAL" => Some(Level::Error),
+            _ => None,
+        })
+}
+
+/// A function that assigns a level to a raw log line.
+pub type LevelParser = Box<dyn Fn(&str) -> Option<Level> + Send>;
+
+/// A stored log line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// When the line was added.
+    pub time: SystemTime,
+    /// The level assigned by the parser, if any.
+    pub level: Option<Level>,
+    /// The raw text of the line.
+    pub text: String,
+}
+
+/// Visual styles for the log viewer.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Lines without a level.
+    pub text: Style,
+    /// Trace lines.
+    pub trace: Style,
+    /// Debug lines.
+    pub debug: Style,
+    /// Info lines.
+    pub info: Style,
+    /// Warning lines.
+    pub warn: Style,
+    /// Error lines.
+    pub error: Style,
+    /// The timestamp prefix.
+    pub timestamp: Style,
+    /// Search matches.
+    pub search_match: Style,
+    /// The search match the viewer is positioned on.
+    pub current_match: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        let highlight = Style::new().foreground(Color::from("#1A1A1A"));
+        Self {
+            text: Style::new(),
+            trace: Style::new().foreground(AdaptiveColor {
+                Light: "#B2B2B2",
+                Dark: "#4E4E4E",
+            }),
+            debug: Style::new().foreground(AdaptiveColor {
+                Light: "#6E6E6E",
+                Dark: "#808080",
+            }),
+            info: Style::new(),
+            warn: Style::new().foreground(AdaptiveColor {
+                Light: "#B7791F",
+                Dark: "#F2C94C",
+            }),
+            error: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+            timestamp: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            search_match: highlight.clone().background(Color::from("#F2C94C")),
+            current_match: highlight.background(Color::from("#EE6FF8")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates log viewer styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let highlight = Style::new().foreground(p.background.clone());
+        Self {
+            text: theme.text.clone(),
+            trace: theme.subtle.clone(),
+            debug: theme.muted.clone(),
+            info: theme.text.clone(),
+            warn: theme.warning.clone(),
+            error: theme.error.clone(),
+            timestamp: theme.muted.clone(),
+            search_match: highlight.clone().background(p.warning.clone()),
+            current_match: highlight.background(p.primary.clone()),
+        }
+    }
+
+    fn for_level(&self, level: Option<Level>) -> &Style {
+        match level {
+            None => &self.text,
+            Some(Level::Trace) => &self.trace,
+            Some(Level::Debug) => &self.debug,
+            Some(Level::Info) => &self.info,
+            Some(Level::Warn) => &self.warn,
+            Some(Level::Error) => &self.error,
+        }
+    }
+}
+
+/// Key bindings for the log viewer, in addition to the viewport's.
+#[derive(Debug, Clone)]
+pub struct LogViewKeyMap {
+    /// Toggles follow mode. Default: `F`.
+    pub follow: key::Binding,
+    /// Cycles the minimum level shown. Default: `L`.
+    pub cycle_level: key::Binding,
+    /// Toggles timestamps. Default: `t`.
+    pub timestamps: key::Binding,
+    /// Starts typing a search query. Default: `/`.
+    pub search: key::Binding,
+    /// Jumps to the next match. Default: `n`.
+    pub next_match: key::Binding,
+    /// Jumps to the previous match. Default: `N`.
+    pub prev_match: key::Binding,
+    /// Runs the query being typed. Default: `enter`.
+    pub accept: key::Binding,
+    /// Abandons typing or clears the active search. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for LogViewKeyMap {
+    fn default() -> Self {
+        Self {
+            follow: key::new_binding(vec![
+                key::with_keys_str(&["F"]),
+                key::with_help("F", "follow"),
+            ]),
+            cycle_level: key::new_binding(vec![
+                key::with_keys_str(&["L"]),
+                key::with_help("L", "level"),
+            ]),
+            timestamps: key::new_binding(vec![
+                key::with_keys_str(&["t"]),
+                key::with_help("t", "timestamps"),
+            ]),
+            search: key::new_binding(vec![
+                key::with_keys_str(&["/"]),
+                key::with_help("/", "search"),
+            ]),
+            next_match: key::new_binding(vec![
+                key::with_keys_str(&["n"]),
+                key::with_help("n", "next match"),
+            ]),
+            prev_match: key::new_binding(vec![
+                key::with_keys_str(&["N"]),
+                key::with_help("N", "previous match"),
+            ]),
+            accept: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "search"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "clear search"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for LogViewKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.follow, &self.cycle_level, &self.search]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.follow, &self.cycle_level, &self.timestamps],
+            vec![&self.search, &self.accept, &self.cancel],
+            vec![&self.next_match, &self.prev_match],
+        ]
+    }
+}
+
+/// A scrolling, filterable, searchable log viewer.
+pub struct Model {
+    entries: VecDeque<Entry>,
+    visible: Vec<usize>,
+    follow: bool,
+    min_level: Option<Level>,
+    parser: LevelParser,
+    query: String,
+    matches: Vec<usize>,
+    current: usize,
+    searching: bool,
+    search_input: textinput::Model,
+    /// Maximum number of lines kept; the oldest are dropped first.
+    pub max_lines: usize,
+    /// Whether lines are prefixed with their arrival time.
+    pub show_timestamps: bool,
+    /// The viewport displaying the lines.
+    pub viewport: viewport::Model,
+    /// Visual styles.
+    pub styles: Styles,
+    /// Key bindings.
+    pub keymap: LogViewKeyMap,
+}
+
+/// Creates a log viewer with the given viewport size.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates an empty log viewer in follow mode, keeping up to 10,000
+    /// lines.
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut search_input = textinput::new();
+        search_input.prompt = "/".to_string();
+        Self {
+            entries: VecDeque::new(),
+            visible: Vec::new(),
+            follow: true,
+            min_level: None,
+            parser: Box::new(parse_level),
+            query: String::new(),
+            matches: Vec::new(),
+            current: 0,
+            searching: false,
+            search_input,
+            max_lines: 10_000,
+            show_timestamps: false,
+            viewport: viewport::new(width, height),
+            styles: Styles::default(),
+            keymap: LogViewKeyMap::default(),
+        }
+    }
+
+    /// Sets the maximum number of lines kept.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self.trim();
+        self
+    }
+
+    /// Replaces the level parser hook.
+    pub fn with_parser(mut self, parser: impl Fn(&str) -> Option<Level> + Send + 'static) -> Self {
+        self.parser = Box::new(parser);
+        self
+    }
+
+    /// Shows or hides timestamps.
+    pub fn with_timestamps(mut self, show: bool) -> Self {
+        self.show_timestamps = show;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self.refresh();
+        self
+    }
+
+    /// Appends a line, or several if `text` contains newlines.
+    pub fn push(&mut self, text: &str) {
+        let time = SystemTime::now();
+        for line in text.lines() {
+            let level = (self.parser)(line);
+            self.entries.push_back(Entry {
+                time,
+                level,
+                text: line.to_string(),
+            });
+        }
+        self.trim();
+        self.refresh();
+    }
+
+    /// Appends a prepared entry, keeping its level and time.
+    pub fn push_entry(&mut self, entry: Entry) {
+        self.entries.push_back(entry);
+        self.trim();
+        self.refresh();
+    }
+
+    /// Removes all lines.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.refresh();
+    }
+
+    /// Returns the number of stored lines.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no lines are stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the stored lines, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of lines passing the level filter.
+    pub fn visible_len(&self) -> usize {
+        self.visible.len()
+    }
+
+    /// Returns whether the viewer follows new lines.
+    pub fn following(&self) -> bool {
+        self.follow
+    }
+
+    /// Enables or disables follow mode; enabling jumps to the bottom.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        if follow {
+            self.viewport.goto_bottom();
+        }
+    }
+
+    /// Returns the minimum level shown.
+    pub fn min_level(&self) -> Option<Level> {
+        self.min_level
+    }
+
+    /// Hides lines below `level`. Lines without a level are always shown.
+    pub fn set_min_level(&mut self, level: Option<Level>) {
+        self.min_level = level;
+        self.refresh();
+    }
+
+    /// Shows or hides timestamps.
+    pub fn set_show_timestamps(&mut self, show: bool) {
+        self.show_timestamps = show;
+        self.refresh();
+    }
+
+    /// Resizes the viewer.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.viewport.width = width;
+        self.viewport.height = height;
+        self.refresh();
+    }
+
+    /// Returns whether a search query is being typed.
+    pub fn searching(&self) -> bool {
+        self.searching
+    }
+
+    /// Returns the active search query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the indices of displayed lines matching the active query.
+    pub fn matches(&self) -> &[usize] {
+        &self.matches
+    }
+
+    /// Searches the displayed lines case-insensitively and scrolls to the
+    /// first match, leaving follow mode. Returns the number of matches.
+    pub fn search(&mut self, query: &str) -> usize {
+        self.query = query.to_string();
+        self.current = 0;
+        self.refresh();
+        if !self.matches.is_empty() {
+            self.follow = false;
+            self.scroll_to_current();
+        }
+        self.matches.len()
+    }
+
+    /// Clears the active search.
+    pub fn clear_search(&mut self) {
+        self.search("");
+    }
+
+    /// Moves to the next match, wrapping around.
+    pub fn next_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + 1) % self.matches.len();
+            self.follow = false;
+            self.refresh();
+            self.scroll_to_current();
+        }
+    }
+
+    /// Moves to the previous match, wrapping around.
+    pub fn prev_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + self.matches.len() - 1) % self.matches.len();
+            self.follow = false;
+            self.refresh();
+            self.scroll_to_current();
+        }
+    }
+
+    fn trim(&mut self) {
+        while self.entries.len() > self.max_lines {
+            self.entries.pop_front();
+        }
+    }
+
+    fn scroll_to_current(&mut self) {
+        if let Some(&line) = self.matches.get(self.current) {
+            let top = self.viewport.y_offset;
+            if line < top || line >= top + self.viewport.height {
+                self.viewport
+                    .set_y_offset(line.saturating_sub(self.viewport.height / 2));
+            }
+        }
+    }
+
+    /// Rebuilds the displayed lines from the stored entries.
+    fn refresh(&mut self) {
+        let min = self.min_level;
+        self.visible = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| match (min, e.level) {
+                (Some(min), Some(level)) => level >= min,
+                _ => true,
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        let needle = self.query.to_lowercase();
+        self.matches = if needle.is_empty() {
+            Vec::new()
+        } else {
+            self.visible
+                .iter()
+                .enumerate()
+                .filter(|(_, &i)| self.entries[i].text.to_lowercase().contains(&needle))
+                .map(|(row, _)| row)
+                .collect()
+        };
+        if self.current >= self.matches.len() {
+            self.current = 0;
+        }
+
+        let lines: Vec<String> = self
+            .visible
+            .iter()
+            .enumerate()
+            .map(|(row, &i)| {
+                let entry = &self.entries[i];
+                let body = match self.matches.binary_search(&row) {
+                    Ok(n) => {
+                        let style = if n == self.current {
+                            &self.styles.current_match
+                        } else {
+                            &self.styles.search_match
+                        };
+                        highlight(&entry.text, &needle, style)
+                    }
+                    Err(_) => self.styles.for_level(entry.level).render(&entry.text),
+                };
+                if self.show_timestamps {
+                    let stamp = self.styles.timestamp.render(&format_time(entry.time));
+                    format!("{} {}", stamp, body)
+                } else {
+                    body
+                }
+            })
+            .collect();
+        self.viewport.set_content_lines(lines);
+        if self.follow {
+            self.viewport.goto_bottom();
+        }
+    }
+
+    fn cycle_level(&mut self) {
+        let next = match self.min_level {
+            None => Some(Level::Debug),
+            Some(Level::Trace) => Some(Level::Debug),
+            Some(Level::Debug) => Some(Level::Info),
+            Some(Level::Info) => Some(Level::Warn),
+            Some(Level::Warn) => Some(Level::Error),
+            Some(Level::Error) => None,
+        };
+        self.set_min_level(next);
+    }
+
+    /// Handles log viewer keys and forwards everything else to the viewport.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.searching {
+                if self.keymap.accept.matches(key_msg) {
+                    self.searching = false;
+                    self.search_input.blur();
+                    let query = self.search_input.value();
+                    self.search(&query);
+                    return None;
+                }
+                if self.keymap.cancel.matches(key_msg) {
+                    self.searching = false;
+                    self.search_input.blur();
+                    return None;
+                }
+                return self.search_input.update(msg);
+            }
+            if self.keymap.search.matches(key_msg) {
+                self.searching = true;
+                self.search_input.reset();
+                return Some(self.search_input.focus());
+            }
+            if self.keymap.follow.matches(key_msg) {
+                self.set_follow(!self.follow);
+                return None;
+            }
+            if self.keymap.cycle_level.matches(key_msg) {
+                self.cycle_level();
+                return None;
+            }
+            if self.keymap.timestamps.matches(key_msg) {
+                self.set_show_timestamps(!self.show_timestamps);
+                return None;
+            }
+            if self.keymap.next_match.matches(key_msg) {
+                self.next_match();
+                return None;
+            }
+            if self.keymap.prev_match.matches(key_msg) {
+                self.prev_match();
+                return None;
+            }
+            if self.keymap.cancel.matches(key_msg) && !self.query.is_empty() {
+                self.clear_search();
+                return None;
+            }
+            let cmd = self.viewport.update(msg);
+            // Scrolling away from the bottom pauses following; returning
+            // to it resumes.
+            self.follow = self.viewport.at_bottom();
+            return cmd;
+        } else if self.searching {
+            return self.search_input.update(msg);
+        }
+        self.viewport.update(msg)
+    }
+
+    /// Renders the visible lines, followed by the search prompt while a
+    /// query is being typed.
+    pub fn view(&self) -> String {
+        let body = self.viewport.view();
+        if self.searching {
+            format!("{}\n{}", body, self.search_input.view())
+        } else {
+            body
+        }
+    }
+}
+
+/// Saved view settings and position of a log viewer; see
+/// [`Stateful`](crate::state::Stateful). The log lines are not included.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Minimum level shown.
+    pub min_level: Option<Level>,
+    /// The active search query.
+    pub query: String,
+    /// Index of the current match among [`Model::matches`].
+    pub current_match: usize,
+    /// Whether the viewer follows new lines.
+    pub follow: bool,
+    /// Lines scrolled from the top, ignored while following.
+    pub scroll: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            min_level: self.min_level,
+            query: self.query.clone(),
+            current_match: self.current,
+            follow: self.follow,
+            scroll: self.viewport.y_offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.searching = false;
+        self.min_level = state.min_level;
+        self.query = state.query;
+        self.refresh();
+        self.current = state
+            .current_match
+            .min(self.matches.len().saturating_sub(1));
+        self.refresh();
+        if state.follow {
+            self.set_follow(true);
+        } else {
+            self.follow = false;
+            self.viewport.set_y_offset(state.scroll);
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+        self.refresh();
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(80, 24), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut m = Model::new(40, 5).with_max_lines(3);
+        m.push("one\ntwo\nthree\nfour");
+        let texts: Vec<&str> = m.entries().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["two", "three", "four"]);
+    }
+
+    #[test]
+    fn test_follow_pauses_on_scroll_up() {
+        let mut m = Model::new(40, 5);
+        for i in 0..20 {
+            m.push(&format!("line {}", i));
+        }
+        assert!(m.following());
+        assert!(m.viewport.at_bottom());
+        m.update(key(KeyCode::Up));
+        assert!(!m.following());
+        let offset = m.viewport.y_offset;
+        m.push("line 20");
+        assert_eq!(m.viewport.y_offset, offset);
+        m.update(key(KeyCode::Char('F')));
+        assert!(m.following());
+        assert!(m.viewport.at_bottom());
+    }
+
+    #[test]
+    fn test_level_filter_and_custom_parser() {
+        let mut m =
+            Model::new(40, 5).with_parser(|line| line.starts_with('!').then_some(Level::Error));
+        m.push("!boom\nINFO fine\ncontinuation");
+        m.set_min_level(Some(Level::Warn));
+        // Unleveled lines pass the filter; "INFO" is not recognised by
+        // the custom parser.
+        assert_eq!(m.visible_len(), 3);
+        m.push_entry(Entry {
+            time: SystemTime::now(),
+            level: Some(Level::Debug),
+            text: "hidden".into(),
+        });
+        assert_eq!(m.visible_len(), 3);
+        m.update(key(KeyCode::Char('L')));
+        assert_eq!(m.min_level(), Some(Level::Error));
+    }
+
+    #[test]
+    fn test_search_and_timestamps() {
+        let mut m = Model::new(40, 5);
+        m.push_entry(Entry {
+            time: UNIX_EPOCH + Duration::from_secs(3_723),
+            level: None,
+            text: "hello world".into(),
+        });
+        m.push("other");
+        assert_eq!(m.search("WORLD"), 1);
+        assert!(!m.following());
+        m.set_show_timestamps(true);
+        let view = lipgloss_extras::lipgloss::strip_ansi(&m.view());
+        assert!(view.starts_with("01:02:03 hello world"));
+    }
+}
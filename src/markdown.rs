@@ -0,0 +1,924 @@
+//! Scrollable Markdown viewer.
+//!
+//! This module renders CommonMark (plus GitHub-style tables and
+//! strikethrough) into styled terminal text and displays it inside an
+//! embedded [`viewport`](crate::viewport), so README or help screens can be
+//! shown without shelling out to an external pager.
+//!
+//! Supported elements: headings, paragraphs with word wrapping, emphasis,
+//! strong, strikethrough, inline code, fenced and indented code blocks, block
+//! quotes, ordered/unordered (nested) lists, task list markers, links, images
+//! (alt text), tables and horizontal rules.
+//!
+//! # Navigation Controls
+//!
+//! Scrolling uses the viewport key bindings. In addition:
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `/` | Start a search |
+//! | `enter` / `esc` | Run / abandon the search being typed |
+//! | `n` / `N` | Jump to the next / previous match |
+//! | `esc` | Clear the active search |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::markdown;
+//!
+//! let mut viewer = markdown::new(60, 20);
+//! viewer.set_content("# Title\n\nSome *emphasis* and a [link](https://example.com).\n\n- one\n- two");
+//! let view = viewer.view();
+//! assert!(view.contains("Title"));
+//!
+//! // Search and jump between matches.
+//! assert_eq!(viewer.search("two"), 1);
+//! ```
+//!
+//! Rendering without a viewer:
+//!
+//! ```rust
+//! use bubbletea_widgets::markdown::{render, Styles};
+//!
+//! let out = render("| a | b |\n|---|---|\n| 1 | 2 |", 40, &Styles::default());
+//! assert!(out.contains('│'));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, highlight};
+use crate::theme::Theme;
+use crate::{textinput, viewport};
+use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+/// Visual styles for rendered Markdown.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Plain paragraph text.
+    pub text: Style,
+    /// Level 1 headings.
+    pub h1: Style,
+    /// Level 2-6 headings.
+    pub heading: Style,
+    /// `*emphasis*`.
+    pub emphasis: Style,
+    /// `**strong**`.
+    pub strong: Style,
+    /// `~~strikethrough~~`.
+    pub strikethrough: Style,
+    /// Inline `code`.
+    pub code: Style,
+    /// Lines of fenced or indented code blocks.
+    pub code_block: Style,
+    /// Link text.
+    pub link: Style,
+    /// Link destinations shown after the link text.
+    pub link_url: Style,
+    /// Block quote bar and text.
+    pub quote: Style,
+    /// List bullets and numbers.
+    pub bullet: Style,
+    /// Horizontal rules and table separators.
+    pub rule: Style,
+    /// Table header cells.
+    pub table_header: Style,
+    /// Search matches.
+    pub search_match: Style,
+    /// The search match the viewer is positioned on.
+    pub current_match: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            text: Style::new(),
+            h1: Style::new()
+                .bold(true)
+                .foreground(Color::from("#FFFDF5"))
+                .background(Color::from("#5A56E0"))
+                .padding(0, 1, 0, 1),
+            heading: Style::new().bold(true).foreground(Color::from("#00AFFF")),
+            emphasis: Style::new().italic(true),
+            strong: Style::new().bold(true),
+            strikethrough: Style::new().strikethrough(true),
+            code: Style::new()
+                .foreground(Color::from("#FF5F87"))
+                .background(Color::from("#303030")),
+            code_block: Style::new().foreground(Color::from("#C4C4C4")),
+            link: Style::new()
+                .foreground(Color::from("#00AF87"))
+                .underline(true),
+            link_url: Style::new().foreground(Color::from("#626262")),
+            quote: Style::new().foreground(Color::from("#8A8A8A")),
+            bullet: Style::new().foreground(Color::from("#EE6FF8")),
+            rule: Style::new().foreground(Color::from("#3C3C3C")),
+            table_header: Style::new().bold(true),
+            search_match: Style::new()
+                .foreground(Color::from("#000000"))
+                .background(Color::from("#F2C94C")),
+            current_match: Style::new()
+                .foreground(Color::from("#000000"))
+                .background(Color::from("#FF8700")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates Markdown styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let highlight = Style::new().foreground(p.background.clone());
+        Self {
+            text: theme.text.clone(),
+            h1: Style::new()
+                .bold(true)
+                .foreground(p.background.clone())
+                .background(p.secondary.clone())
+                .padding(0, 1, 0, 1),
+            heading: Style::new().bold(true).foreground(p.accent.clone()),
+            emphasis: Style::new().italic(true),
+            strong: Style::new().bold(true),
+            strikethrough: Style::new().strikethrough(true),
+            code: Style::new()
+                .foreground(p.primary.clone())
+                .background(p.selection.clone()),
+            code_block: theme.text.clone(),
+            link: theme.accent.clone().underline(true),
+            link_url: theme.muted.clone(),
+            quote: theme.muted.clone(),
+            bullet: Style::new().foreground(p.primary.clone()),
+            rule: theme.subtle.clone(),
+            table_header: Style::new().bold(true),
+            search_match: highlight.clone().background(p.warning.clone()),
+            current_match: highlight.background(p.primary.clone()),
+        }
+    }
+}
+
+/// Renders Markdown to styled terminal text wrapped at `width` columns.
+pub fn render(markdown: &str, width: usize, styles: &Styles) -> String {
+    render_lines(markdown, width, styles).join("\n")
+}
+
+fn render_lines(markdown: &str, width: usize, styles: &Styles) -> Vec<String> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut r = Renderer::new(width.max(10), styles);
+    for event in Parser::new_ext(markdown, options) {
+        r.event(event);
+    }
+    r.flush();
+    while r.out.last().is_some_and(|l| l.is_empty()) {
+        r.out.pop();
+    }
+    r.out
+}
+
+#[derive(Default)]
+struct TableState {
+    rows: Vec<Vec<String>>,
+    header_rows: usize,
+    cell: String,
+}
+
+struct Renderer<'s> {
+    styles: &'s Styles,
+    width: usize,
+    out: Vec<String>,
+    spans: Vec<(String, Style)>,
+    inline: Vec<Style>,
+    lists: Vec<Option<u64>>,
+    bullet: Option<String>,
+    quote_depth: usize,
+    code: Option<String>,
+    links: Vec<String>,
+    table: Option<TableState>,
+}
+
+impl<'s> Renderer<'s> {
+    fn new(width: usize, styles: &'s Styles) -> Self {
+        Self {
+            styles,
+            width,
+            out: Vec::new(),
+            spans: Vec::new(),
+            inline: Vec::new(),
+            lists: Vec::new(),
+            bullet: None,
+            quote_depth: 0,
+            code: None,
+            links: Vec::new(),
+            table: None,
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.inline
+            .last()
+            .cloned()
+            .unwrap_or_else(|| self.styles.text.clone())
+    }
+
+    fn push_inline(&mut self, style: &Style) {
+        let combined = style.clone().inherit(self.style());
+        self.inline.push(combined);
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        if let Some(table) = &mut self.table {
+            table.cell.push_str(text);
+        } else if let Some(code) = &mut self.code {
+            code.push_str(text);
+        } else {
+            self.spans.push((text.to_string(), style));
+        }
+    }
+
+    fn gap(&mut self) {
+        if self.out.last().is_some_and(|l| !l.is_empty()) {
+            self.out.push(self.prefix());
+        }
+    }
+
+    fn prefix(&self) -> String {
+        let bar = self.styles.quote.render("│ ");
+        bar.repeat(self.quote_depth).trim_end().to_string()
+    }
+
+    fn indent(&self) -> usize {
+        2 * self.lists.len().saturating_sub(1)
+    }
+
+    /// Wraps the buffered inline spans into output lines.
+    fn flush(&mut self) {
+        if self.spans.is_empty() {
+            return;
+        }
+        let spans = std::mem::take(&mut self.spans);
+        let quote = self.styles.quote.render("│ ").repeat(self.quote_depth);
+        let quote_width = 2 * self.quote_depth;
+        let indent = self.indent();
+        let bullet = self.bullet.take();
+        let bullet_width = bullet.as_deref().map(text::width).unwrap_or(0);
+        let avail = self
+            .width
+            .saturating_sub(quote_width + indent + bullet_width)
+            .max(1);
+
+        // Group styled pieces into words; a word may span several spans
+        // (e.g. "**bold**." is one word made of two pieces).
+        let mut words: Vec<(String, usize)> = Vec::new();
+        let mut pending_space = true;
+        for (text, style) in &spans {
+            for (i, piece) in text.split(' ').enumerate() {
+                if i > 0 {
+                    pending_space = true;
+                }
+                if piece.is_empty() {
+                    continue;
+                }
+                let rendered = style.render(piece);
+                let w = text::width(piece);
+                match words.last_mut() {
+                    Some((word, width)) if !pending_space => {
+                        word.push_str(&rendered);
+                        *width += w;
+                    }
+                    _ => words.push((rendered, w)),
+                }
+                pending_space = false;
+            }
+        }
+
+        let mut lines: Vec<String> = Vec::new();
+        let mut line = String::new();
+        let mut line_width = 0;
+        for (word, w) in words {
+            if line_width > 0 && line_width + 1 + w > avail {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            } else if line_width > 0 {
+                line.push(' ');
+                line_width += 1;
+            }
+            line.push_str(&word);
+            line_width += w;
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+
+        for (i, l) in lines.into_iter().enumerate() {
+            let lead = match (&bullet, i) {
+                (Some(b), 0) => b.clone(),
+                _ => " ".repeat(bullet_width),
+            };
+            self.out
+                .push(format!("{}{}{}{}", quote, " ".repeat(indent), lead, l));
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(t) => {
+                let style = self.style();
+                self.text(&t, style);
+            }
+            Event::Code(t) => {
+                let style = self.styles.code.clone();
+                self.text(&t, style);
+            }
+            Event::SoftBreak => {
+                let style = self.style();
+                self.text(" ", style);
+            }
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                self.gap();
+                let rule = "─".repeat(self.width);
+                self.out.push(self.styles.rule.render(&rule));
+            }
+            Event::TaskListMarker(done) => {
+                let marker = if done { "[x] " } else { "[ ] " };
+                let style = self.styles.bullet.clone();
+                self.text(marker, style);
+            }
+            Event::Html(t) | Event::InlineHtml(t) => {
+                let style = self.style();
+                self.text(&t, style);
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph if self.bullet.is_none() => self.gap(),
+            Tag::Heading { .. } => {
+                self.flush();
+                self.gap();
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.gap();
+                self.quote_depth += 1;
+                self.push_inline(&self.styles.quote.clone());
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                self.gap();
+                if let CodeBlockKind::Fenced(lang) = kind {
+                    if !lang.is_empty() {
+                        self.out.push(self.styles.link_url.render(&lang));
+                    }
+                }
+                self.code = Some(String::new());
+            }
+            Tag::List(start) => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.gap();
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        let m = format!("{}. ", n);
+                        *n += 1;
+                        m
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.bullet = Some(self.styles.bullet.render(&marker));
+            }
+            Tag::Emphasis => self.push_inline(&self.styles.emphasis.clone()),
+            Tag::Strong => self.push_inline(&self.styles.strong.clone()),
+            Tag::Strikethrough => self.push_inline(&self.styles.strikethrough.clone()),
+            Tag::Link { dest_url, .. } => {
+                self.links.push(dest_url.to_string());
+                self.push_inline(&self.styles.link.clone());
+            }
+            Tag::Image { .. } => {
+                let style = self.styles.link_url.clone();
+                self.text("Image: ", style);
+                self.push_inline(&self.styles.link.clone());
+            }
+            Tag::Table(_) => {
+                self.flush();
+                self.gap();
+                self.table = Some(TableState::default());
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(t) = &mut self.table {
+                    t.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(t) = &mut self.table {
+                    t.cell.clear();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.flush(),
+            TagEnd::Heading(level) => {
+                let text: String = self.spans.drain(..).map(|(t, _)| t).collect();
+                let rendered = if level == HeadingLevel::H1 {
+                    self.styles.h1.render(&text)
+                } else {
+                    let hashes = "#".repeat(level as usize);
+                    self.styles.heading.render(&format!("{} {}", hashes, text))
+                };
+                self.out.push(rendered);
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.inline.pop();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+            }
+            TagEnd::CodeBlock => {
+                let code = self.code.take().unwrap_or_default();
+                let quote = self.styles.quote.render("│ ").repeat(self.quote_depth);
+                let indent = " ".repeat(self.indent() + 2);
+                for line in code.trim_end_matches('\n').split('\n') {
+                    self.out.push(format!(
+                        "{}{}{}",
+                        quote,
+                        indent,
+                        self.styles.code_block.render(line)
+                    ));
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+            }
+            TagEnd::Item => self.flush(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Image => {
+                self.inline.pop();
+            }
+            TagEnd::Link => {
+                self.inline.pop();
+                if let Some(url) = self.links.pop() {
+                    let shown = self
+                        .spans
+                        .last()
+                        .is_some_and(|(t, _)| t.trim() == url.as_str());
+                    if !shown && !url.is_empty() {
+                        let style = self.styles.link_url.clone();
+                        self.text(&format!(" ({})", url), style);
+                    }
+                }
+            }
+            TagEnd::TableCell => {
+                if let Some(t) = &mut self.table {
+                    let cell = std::mem::take(&mut t.cell);
+                    if let Some(row) = t.rows.last_mut() {
+                        row.push(cell.trim().to_string());
+                    }
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(t) = &mut self.table {
+                    t.header_rows = t.rows.len();
+                }
+            }
+            TagEnd::Table => {
+                if let Some(t) = self.table.take() {
+                    self.render_table(t);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn render_table(&mut self, table: TableState) {
+        let cols = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths = vec![0; cols];
+        for row in &table.rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(text::width(cell));
+            }
+        }
+        let sep = self.styles.rule.render(" │ ");
+        for (r, row) in table.rows.iter().enumerate() {
+            let cells: Vec<String> = (0..cols)
+                .map(|i| {
+                    let cell = row.get(i).map(String::as_str).unwrap_or("");
+                    let padded = text::pad(cell, widths[i], text::Align::Left);
+                    if r < table.header_rows {
+                        self.styles.table_header.render(&padded)
+                    } else {
+                        self.styles.text.render(&padded)
+                    }
+                })
+                .collect();
+            self.out.push(cells.join(&sep));
+            if r + 1 == table.header_rows {
+                let line: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+                self.out.push(self.styles.rule.render(&line.join("─┼─")));
+            }
+        }
+    }
+}
+
+/// Key bindings for searching rendered Markdown.
+#[derive(Debug, Clone)]
+pub struct MarkdownKeyMap {
+    /// Starts typing a search query. Default: `/`.
+    pub search: key::Binding,
+    /// Jumps to the next match. Default: `n`.
+    pub next_match: key::Binding,
+    /// Jumps to the previous match. Default: `N`.
+    pub prev_match: key::Binding,
+    /// Runs the query being typed. Default: `enter`.
+    pub accept: key::Binding,
+    /// Abandons typing or clears the active search. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for MarkdownKeyMap {
+    fn default() -> Self {
+        Self {
+            search: key::new_binding(vec![
+                key::with_keys_str(&["/"]),
+                key::with_help("/", "search"),
+            ]),
+            next_match: key::new_binding(vec![
+                key::with_keys_str(&["n"]),
+                key::with_help("n", "next match"),
+            ]),
+            prev_match: key::new_binding(vec![
+                key::with_keys_str(&["N"]),
+                key::with_help("N", "previous match"),
+            ]),
+            accept: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "search"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "clear search"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for MarkdownKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.search, &self.next_match, &self.prev_match]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.search, &self.accept, &self.cancel],
+            vec![&self.next_match, &self.prev_match],
+        ]
+    }
+}
+
+/// A scrollable, searchable Markdown viewer.
+pub struct Model {
+    source: String,
+    lines: Vec<String>,
+    query: String,
+    matches: Vec<usize>,
+    current: usize,
+    searching: bool,
+    search_input: textinput::Model,
+    /// The viewport displaying the rendered document.
+    pub viewport: viewport::Model,
+    /// Rendering styles. Call [`Model::set_content`] again after changing them.
+    pub styles: Styles,
+    /// Search key bindings.
+    pub keymap: MarkdownKeyMap,
+}
+
+/// Creates a viewer with the given viewport size.
+pub fn new(width: usize, height: usize) -> Model {
+    Model::new(width, height)
+}
+
+impl Model {
+    /// Creates a viewer with the given viewport size.
+    pub fn new(width: usize, height: usize) -> Self {
+        let mut search_input = textinput::new();
+        search_input.prompt = "/".to_string();
+        Self {
+            source: String::new(),
+            lines: Vec::new(),
+            query: String::new(),
+            matches: Vec::new(),
+            current: 0,
+            searching: false,
+            search_input,
+            viewport: viewport::new(width, height),
+            styles: Styles::default(),
+            keymap: MarkdownKeyMap::default(),
+        }
+    }
+
+    /// Sets the Markdown source and renders it at the viewport width.
+    pub fn set_content(&mut self, markdown: &str) {
+        self.source = markdown.to_string();
+        self.rerender();
+    }
+
+    /// Returns the Markdown source.
+    pub fn content(&self) -> &str {
+        &self.source
+    }
+
+    /// Resizes the viewer, re-wrapping the document.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.viewport.width = width;
+        self.viewport.height = height;
+        self.rerender();
+    }
+
+    /// Returns the rendered lines.
+    pub fn rendered_lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns whether a search query is being typed.
+    pub fn searching(&self) -> bool {
+        self.searching
+    }
+
+    /// Returns the active search query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the indices of rendered lines matching the active query.
+    pub fn matches(&self) -> &[usize] {
+        &self.matches
+    }
+
+    /// Searches the rendered text case-insensitively and scrolls to the first
+    /// match. Returns the number of matching lines.
+    pub fn search(&mut self, query: &str) -> usize {
+        self.query = query.to_string();
+        let needle = query.to_lowercase();
+        self.matches = if needle.is_empty() {
+            Vec::new()
+        } else {
+            self.lines
+                .iter()
+                .enumerate()
+                .filter(|(_, l)| lipgloss::strip_ansi(l).to_lowercase().contains(&needle))
+                .map(|(i, _)| i)
+                .collect()
+        };
+        self.current = 0;
+        self.refresh_viewport();
+        self.scroll_to_current();
+        self.matches.len()
+    }
+
+    /// Clears the active search.
+    pub fn clear_search(&mut self) {
+        self.search("");
+    }
+
+    /// Moves to the next match, wrapping around.
+    pub fn next_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + 1) % self.matches.len();
+            self.refresh_viewport();
+            self.scroll_to_current();
+        }
+    }
+
+    /// Moves to the previous match, wrapping around.
+    pub fn prev_match(&mut self) {
+        if !self.matches.is_empty() {
+            self.current = (self.current + self.matches.len() - 1) % self.matches.len();
+            self.refresh_viewport();
+            self.scroll_to_current();
+        }
+    }
+
+    fn rerender(&mut self) {
+        self.lines = render_lines(&self.source, self.viewport.width, &self.styles);
+        let query = self.query.clone();
+        self.search(&query);
+    }
+
+    fn scroll_to_current(&mut self) {
+        if let Some(&line) = self.matches.get(self.current) {
+            let top = self.viewport.y_offset;
+            if line < top || line >= top + self.viewport.height {
+                self.viewport
+                    .set_y_offset(line.saturating_sub(self.viewport.height / 2));
+            }
+        }
+    }
+
+    fn refresh_viewport(&mut self) {
+        if self.matches.is_empty() {
+            self.viewport.set_content_lines(self.lines.clone());
+            return;
+        }
+        let needle = self.query.to_lowercase();
+        let mut lines = self.lines.clone();
+        for (n, &i) in self.matches.iter().enumerate() {
+            let style = if n == self.current {
+                &self.styles.current_match
+            } else {
+                &self.styles.search_match
+            };
+            lines[i] = highlight(&lipgloss::strip_ansi(&lines[i]), &needle, style);
+        }
+        self.viewport.set_content_lines(lines);
+    }
+
+    /// Handles search keys and forwards everything else to the viewport.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.searching {
+                if self.keymap.accept.matches(key_msg) {
+                    self.searching = false;
+                    self.search_input.blur();
+                    let query = self.search_input.value();
+                    self.search(&query);
+                    return None;
+                }
+                if self.keymap.cancel.matches(key_msg) {
+                    self.searching = false;
+                    self.search_input.blur();
+                    return None;
+                }
+                return self.search_input.update(msg);
+            }
+            if self.keymap.search.matches(key_msg) {
+                self.searching = true;
+                self.search_input.reset();
+                return Some(self.search_input.focus());
+            }
+            if self.keymap.next_match.matches(key_msg) {
+                self.next_match();
+                return None;
+            }
+            if self.keymap.prev_match.matches(key_msg) {
+                self.prev_match();
+                return None;
+            }
+            if self.keymap.cancel.matches(key_msg) && !self.query.is_empty() {
+                self.clear_search();
+                return None;
+            }
+        } else if self.searching {
+            return self.search_input.update(msg);
+        }
+        self.viewport.update(msg)
+    }
+
+    /// Renders the visible part of the document, followed by the search
+    /// prompt while a query is being typed.
+    pub fn view(&self) -> String {
+        let body = self.viewport.view();
+        if self.searching {
+            format!("{}\n{}", body, self.search_input.view())
+        } else {
+            body
+        }
+    }
+}
+
+/// Saved scroll position and search of a markdown viewer; see
+/// [`Stateful`](crate::state::Stateful). The source is not included.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Lines scrolled from the top.
+    pub scroll: usize,
+    /// The active search query.
+    pub query: String,
+    /// Index of the current match among [`Model::matches`].
+    pub current_match: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            scroll: self.viewport.y_offset,
+            query: self.query.clone(),
+            current_match: self.current,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.searching = false;
+        self.search(&state.query);
+        self.current = state
+            .current_match
+            .min(self.matches.len().saturating_sub(1));
+        self.refresh_viewport();
+        self.viewport.set_y_offset(state.scroll);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+        self.rerender();
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(80, 24), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn plain(md: &str, width: usize) -> Vec<String> {
+        render_lines(md, width, &Styles::default())
+            .iter()
+            .map(|l| lipgloss::strip_ansi(l))
+            .collect()
+    }
+
+    #[test]
+    fn test_headings_and_paragraph_wrapping() {
+        let lines = plain("## Section\n\nalpha beta gamma delta", 12);
+        assert_eq!(lines[0], "## Section");
+        assert_eq!(lines[1], "");
+        assert_eq!(lines[2], "alpha beta");
+        assert_eq!(lines[3], "gamma delta");
+    }
+
+    #[test]
+    fn test_nested_and_ordered_lists() {
+        let lines = plain("1. one\n2. two\n   - inner", 40);
+        assert_eq!(lines, vec!["1. one", "2. two", "  • inner"]);
+    }
+
+    #[test]
+    fn test_code_block_and_link() {
+        let lines = plain("```rust\nfn main() {}\n```\n\n[docs](https://d.rs)", 40);
+        assert_eq!(lines[0], "rust");
+        assert_eq!(lines[1], "  fn main() {}");
+        assert_eq!(lines[3], "docs (https://d.rs)");
+    }
+
+    #[test]
+    fn test_table_alignment() {
+        let lines = plain("| a | bb |\n|---|---|\n| ccc | d |", 40);
+        assert_eq!(lines[0], "a   │ bb");
+        assert_eq!(lines[1], "────┼───");
+        assert_eq!(lines[2], "ccc │ d ");
+    }
+
+    #[test]
+    fn test_search_keys() {
+        let mut m = Model::new(40, 3);
+        m.set_content("first\n\nsecond\n\nthird\n\nsecond again");
+        m.update(Box::new(KeyMsg {
+            key: KeyCode::Char('/'),
+            modifiers: KeyModifiers::NONE,
+        }));
+        assert!(m.searching());
+        for c in "second".chars() {
+            m.update(Box::new(KeyMsg {
+                key: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+            }));
+        }
+        m.update(Box::new(KeyMsg {
+            key: KeyCode::Enter,
+            modifiers: KeyModifiers::NONE,
+        }));
+        assert!(!m.searching());
+        assert_eq!(m.matches(), &[2, 6]);
+        m.next_match();
+        assert!(m.viewport.y_offset > 0);
+    }
+}
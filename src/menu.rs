@@ -0,0 +1,953 @@
+//! Vertical action menu component with separators, accelerators and nested submenus.
+//!
+//! This module provides a keyboard driven menu suitable both as a standalone screen
+//! (e.g. a main menu) and as a popup/context menu anchored at a terminal position.
+//! Menus are built from [`Item`]s, which can be plain actions, separators, or
+//! submenus containing further items. Open submenus cascade to the right of their
+//! parent entry.
+//!
+//! # Core Components
+//!
+//! - **`Model`**: The menu state, navigation logic and rendering
+//! - **`Item`**: A single entry (action, separator or submenu)
+//! - **`Styles`**: Visual styling for entries and the surrounding box
+//! - **`MenuKeyMap`**: Configurable keyboard bindings
+//! - **`SelectedMsg`**: Message emitted when an action is chosen
+//!
+//! # Navigation Controls
+//!
+//! | Keys | Action |
+//! |------|--------|
+//! | `↑`, `k` | Move to the previous enabled entry |
+//! | `↓`, `j` | Move to the next enabled entry |
+//! | `Home` / `End` | Jump to the first / last enabled entry |
+//! | `Enter`, `Space` | Activate the entry (opens submenus) |
+//! | `→`, `l` | Open the highlighted submenu |
+//! | `←`, `h`, `Esc` | Close the innermost submenu (or the popup) |
+//!
+//! Accelerator keys assigned with [`Item::with_accelerator`] activate their entry
+//! directly and take precedence over the navigation keys above.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::menu::{Item, Model};
+//!
+//! let mut menu = Model::new(vec![
+//!     Item::new("Open").with_accelerator('o'),
+//!     Item::new("Save").with_accelerator('s'),
+//!     Item::separator(),
+//!     Item::submenu("Export", vec![Item::new("PDF"), Item::new("HTML")]),
+//!     Item::new("Print").with_disabled(true),
+//! ]);
+//! menu.focus();
+//!
+//! assert_eq!(menu.selected_item().unwrap().label(), "Open");
+//! assert!(menu.view().contains("Export"));
+//! ```
+//!
+//! Reacting to selections with an `on_select` hook:
+//!
+//! ```rust
+//! use bubbletea_widgets::menu::{Item, Model};
+//!
+//! let menu = Model::new(vec![Item::new("Quit")])
+//!     .with_on_select(Box::new(|item| {
+//!         if item.label() == "Quit" {
+//!             Some(bubbletea_rs::quit())
+//!         } else {
+//!             None
+//!         }
+//!     }));
+//! ```
+//!
+//! As a popup anchored at a position:
+//!
+//! ```rust
+//! use bubbletea_widgets::menu::{Item, Model};
+//!
+//! let mut menu = Model::new(vec![Item::new("Copy"), Item::new("Paste")])
+//!     .with_position(10, 4)
+//!     .with_popup(true);
+//! menu.open();
+//! let rendered = menu.view_anchored();
+//! assert!(rendered.starts_with("\n\n\n\n"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use crossterm::event::{KeyCode, KeyModifiers};
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Callback invoked when an action entry is activated.
+///
+/// The hook receives the chosen item and may return a command to run. When no
+/// hook is installed the menu emits a [`SelectedMsg`] instead.
+pub type OnSelectFunc = Box<dyn Fn(&Item) -> Option<Cmd> + Send>;
+
+/// The kind of a menu entry.
+#[derive(Debug, Clone)]
+pub enum ItemKind {
+    /// A regular, selectable action.
+    Action,
+    /// A horizontal divider. Separators are never selectable.
+    Separator,
+    /// An entry that opens a nested menu containing the given items.
+    Submenu(Vec<Item>),
+}
+
+/// A single entry in a menu.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::menu::Item;
+///
+/// let item = Item::new("Delete").with_accelerator('d').with_disabled(true);
+/// assert_eq!(item.label(), "Delete");
+/// assert_eq!(item.accelerator(), Some('d'));
+/// assert!(!item.selectable());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Item {
+    label: String,
+    accelerator: Option<char>,
+    disabled: bool,
+    kind: ItemKind,
+}
+
+impl Item {
+    /// Creates a new action entry with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            accelerator: None,
+            disabled: false,
+            kind: ItemKind::Action,
+        }
+    }
+
+    /// Creates a separator entry.
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            accelerator: None,
+            disabled: false,
+            kind: ItemKind::Separator,
+        }
+    }
+
+    /// Creates an entry that opens a nested menu with the given items.
+    pub fn submenu(label: impl Into<String>, items: Vec<Item>) -> Self {
+        Self {
+            label: label.into(),
+            accelerator: None,
+            disabled: false,
+            kind: ItemKind::Submenu(items),
+        }
+    }
+
+    /// Assigns an accelerator key that activates this entry directly.
+    ///
+    /// If the character appears in the label its first occurrence is
+    /// highlighted using [`Styles::accelerator`].
+    pub fn with_accelerator(mut self, key: char) -> Self {
+        self.accelerator = Some(key);
+        self
+    }
+
+    /// Enables or disables the entry. Disabled entries are rendered dimmed and
+    /// are skipped during navigation.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the entry label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the accelerator key, if any.
+    pub fn accelerator(&self) -> Option<char> {
+        self.accelerator
+    }
+
+    /// Returns the kind of this entry.
+    pub fn kind(&self) -> &ItemKind {
+        &self.kind
+    }
+
+    /// Returns whether the entry is disabled.
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Sets whether the entry is disabled.
+    pub fn set_disabled(&mut self, disabled: bool) {
+        self.disabled = disabled;
+    }
+
+    /// Returns whether the entry is a separator.
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, ItemKind::Separator)
+    }
+
+    /// Returns the nested items if this entry is a submenu.
+    pub fn children(&self) -> Option<&[Item]> {
+        match &self.kind {
+            ItemKind::Submenu(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the cursor may rest on this entry.
+    pub fn selectable(&self) -> bool {
+        !self.disabled && !self.is_separator()
+    }
+}
+
+/// Message emitted when an action entry is activated and no `on_select` hook
+/// is installed.
+#[derive(Debug, Clone)]
+pub struct SelectedMsg {
+    /// The ID of the menu that produced the selection.
+    pub id: i64,
+    /// Index path from the root menu to the chosen entry.
+    pub path: Vec<usize>,
+    /// Label of the chosen entry.
+    pub label: String,
+}
+
+/// Message emitted when a popup menu is dismissed without a selection.
+#[derive(Debug, Clone)]
+pub struct ClosedMsg {
+    /// The ID of the menu that was closed.
+    pub id: i64,
+}
+
+/// Key bindings for menu navigation.
+#[derive(Debug, Clone)]
+pub struct MenuKeyMap {
+    /// Moves to the previous enabled entry. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves to the next enabled entry. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Jumps to the first enabled entry. Default: `Home`.
+    pub first: key::Binding,
+    /// Jumps to the last enabled entry. Default: `End`.
+    pub last: key::Binding,
+    /// Activates the highlighted entry. Default: `Enter`, `Space`.
+    pub select: key::Binding,
+    /// Opens the highlighted submenu. Default: `→`, `l`.
+    pub open: key::Binding,
+    /// Closes the innermost submenu or the popup. Default: `←`, `h`, `Esc`.
+    pub back: key::Binding,
+}
+
+impl Default for MenuKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::Binding::new(vec![KeyCode::Up, KeyCode::Char('k')]).with_help("↑/k", "up"),
+            down: key::Binding::new(vec![KeyCode::Down, KeyCode::Char('j')])
+                .with_help("↓/j", "down"),
+            first: key::Binding::new(vec![KeyCode::Home]).with_help("home", "first"),
+            last: key::Binding::new(vec![KeyCode::End]).with_help("end", "last"),
+            select: key::Binding::new(vec![KeyCode::Enter, KeyCode::Char(' ')])
+                .with_help("enter", "select"),
+            open: key::Binding::new(vec![KeyCode::Right, KeyCode::Char('l')])
+                .with_help("→/l", "open submenu"),
+            back: key::Binding::new(vec![KeyCode::Left, KeyCode::Char('h'), KeyCode::Esc])
+                .with_help("←/esc", "back"),
+        }
+    }
+}
+
+impl KeyMapTrait for MenuKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.up, &self.down, &self.select, &self.back]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.first, &self.last],
+            vec![&self.select, &self.open, &self.back],
+        ]
+    }
+}
+
+/// Visual styles for the menu.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style for regular entries.
+    pub item: Style,
+    /// Style for the highlighted entry.
+    pub selected: Style,
+    /// Style for disabled entries.
+    pub disabled: Style,
+    /// Style for separator lines.
+    pub separator: Style,
+    /// Style applied to the accelerator character within a label.
+    pub accelerator: Style,
+    /// Style wrapping each open menu level (border, padding, ...).
+    pub frame: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            item: Style::new(),
+            selected: Style::new().foreground(Color::from("#EE6FF8")).bold(true),
+            disabled: Style::new().foreground(Color::from("#626262")),
+            separator: Style::new().foreground(Color::from("#3C3C3C")),
+            accelerator: Style::new().underline(true),
+            frame: Style::new()
+                .border(rounded_border())
+                .border_foreground(Color::from("#874BFD")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates menu styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            item: theme.text.clone(),
+            selected: theme.selected.clone(),
+            disabled: theme.muted.clone(),
+            separator: theme.subtle.clone(),
+            accelerator: Style::new().underline(true),
+            frame: theme.frame.clone(),
+        }
+    }
+}
+
+/// The menu model.
+///
+/// The model keeps a stack of cursor positions, one per open menu level. The
+/// first element is the cursor of the root menu; each further element belongs
+/// to an open submenu.
+pub struct Model {
+    items: Vec<Item>,
+    cursors: Vec<usize>,
+    id: i64,
+    focus: bool,
+    popup: bool,
+    visible: bool,
+    position: (u16, u16),
+    on_select: Option<OnSelectFunc>,
+    /// Key bindings used by [`Model::update`].
+    pub keymap: MenuKeyMap,
+    /// Visual styles used by [`Model::view`].
+    pub styles: Styles,
+    /// Submenu indicator rendered after submenu labels.
+    pub submenu_indicator: String,
+}
+
+impl std::fmt::Debug for Model {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Model")
+            .field("items", &self.items)
+            .field("cursors", &self.cursors)
+            .field("id", &self.id)
+            .field("focus", &self.focus)
+            .field("popup", &self.popup)
+            .field("visible", &self.visible)
+            .field("position", &self.position)
+            .field("on_select", &self.on_select.is_some())
+            .finish()
+    }
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Model {
+    /// Creates a new menu with the given entries.
+    ///
+    /// The cursor starts on the first selectable entry. Menus start unfocused;
+    /// call [`Model::focus`] (or [`Model::open`] for popups) before forwarding
+    /// key messages.
+    pub fn new(items: Vec<Item>) -> Self {
+        let first = first_selectable(&items).unwrap_or(0);
+        Self {
+            items,
+            cursors: vec![first],
+            id: next_id(),
+            focus: false,
+            popup: false,
+            visible: true,
+            position: (0, 0),
+            on_select: None,
+            keymap: MenuKeyMap::default(),
+            styles: Styles::default(),
+            submenu_indicator: "▸".to_string(),
+        }
+    }
+
+    /// Sets the hook invoked when an action entry is activated.
+    pub fn with_on_select(mut self, f: OnSelectFunc) -> Self {
+        self.on_select = Some(f);
+        self
+    }
+
+    /// Sets whether the menu behaves as a popup.
+    ///
+    /// Popup menus start hidden, and closing the root level hides the menu
+    /// and emits a [`ClosedMsg`]. Selecting an action also hides a popup.
+    pub fn with_popup(mut self, popup: bool) -> Self {
+        self.popup = popup;
+        self.visible = !popup;
+        self
+    }
+
+    /// Sets the anchor position (column, row) used by [`Model::view_anchored`].
+    pub fn with_position(mut self, x: u16, y: u16) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets the key bindings.
+    pub fn with_keymap(mut self, keymap: MenuKeyMap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Returns the unique ID of this menu.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the root entries.
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Replaces the entries and closes any open submenus.
+    pub fn set_items(&mut self, items: Vec<Item>) {
+        self.cursors = vec![first_selectable(&items).unwrap_or(0)];
+        self.items = items;
+    }
+
+    /// Sets the hook invoked when an action entry is activated.
+    pub fn set_on_select(&mut self, f: OnSelectFunc) {
+        self.on_select = Some(f);
+    }
+
+    /// Sets the anchor position (column, row) used by [`Model::view_anchored`].
+    pub fn set_position(&mut self, x: u16, y: u16) {
+        self.position = (x, y);
+    }
+
+    /// Returns the anchor position (column, row).
+    pub fn position(&self) -> (u16, u16) {
+        self.position
+    }
+
+    /// Returns whether the menu is a popup.
+    pub fn is_popup(&self) -> bool {
+        self.popup
+    }
+
+    /// Returns whether the menu is currently shown.
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows and focuses the menu, resetting it to the root level.
+    pub fn open(&mut self) {
+        self.cursors = vec![first_selectable(&self.items).unwrap_or(0)];
+        self.visible = true;
+        self.focus = true;
+    }
+
+    /// Hides and blurs the menu.
+    pub fn close(&mut self) {
+        self.visible = false;
+        self.focus = false;
+    }
+
+    /// Sets focus so the menu reacts to key messages.
+    pub fn focus(&mut self) {
+        self.focus = true;
+    }
+
+    /// Removes focus.
+    pub fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    /// Returns whether the menu has focus.
+    pub fn focused(&self) -> bool {
+        self.focus
+    }
+
+    /// Returns the number of open levels (1 when only the root is open).
+    pub fn depth(&self) -> usize {
+        self.cursors.len()
+    }
+
+    /// Returns the cursor index within the innermost open level.
+    pub fn cursor(&self) -> usize {
+        *self.cursors.last().unwrap_or(&0)
+    }
+
+    /// Returns the index path from the root to the highlighted entry.
+    pub fn path(&self) -> &[usize] {
+        &self.cursors
+    }
+
+    /// Returns the highlighted entry of the innermost open level.
+    pub fn selected_item(&self) -> Option<&Item> {
+        self.current_items().get(self.cursor())
+    }
+
+    /// Moves the cursor to the previous selectable entry, wrapping around.
+    pub fn cursor_up(&mut self) {
+        self.step(-1);
+    }
+
+    /// Moves the cursor to the next selectable entry, wrapping around.
+    pub fn cursor_down(&mut self) {
+        self.step(1);
+    }
+
+    /// Opens the highlighted submenu. Returns `false` if the entry is not an
+    /// enabled submenu.
+    pub fn open_submenu(&mut self) -> bool {
+        let first = match self.selected_item() {
+            Some(item) if !item.disabled => match item.children() {
+                Some(children) => first_selectable(children).unwrap_or(0),
+                None => return false,
+            },
+            _ => return false,
+        };
+        self.cursors.push(first);
+        true
+    }
+
+    /// Closes the innermost open submenu. Returns `false` at the root level.
+    pub fn close_submenu(&mut self) -> bool {
+        if self.cursors.len() > 1 {
+            self.cursors.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Activates the highlighted entry.
+    ///
+    /// Submenus are opened; actions invoke the `on_select` hook (or emit a
+    /// [`SelectedMsg`]) and close popup menus. Disabled entries and
+    /// separators are ignored.
+    pub fn select(&mut self) -> Option<Cmd> {
+        let item = self.selected_item()?.clone();
+        if !item.selectable() {
+            return None;
+        }
+        if item.children().is_some() {
+            self.open_submenu();
+            return None;
+        }
+        let path = self.cursors.clone();
+        if self.popup {
+            self.close();
+        }
+        match &self.on_select {
+            Some(f) => f(&item),
+            None => {
+                let id = self.id;
+                let label = item.label.clone();
+                Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+                    Box::new(SelectedMsg {
+                        id,
+                        path: path.clone(),
+                        label: label.clone(),
+                    }) as Msg
+                }))
+            }
+        }
+    }
+
+    /// Processes key messages.
+    ///
+    /// Returns the command produced by activating an entry, or a command
+    /// emitting [`ClosedMsg`] when a popup is dismissed.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus || !self.visible {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+
+        if let KeyCode::Char(c) = key_msg.key {
+            if key_msg.modifiers.difference(KeyModifiers::SHIFT).is_empty() {
+                if let Some(idx) = self.accelerator_index(c) {
+                    if let Some(cursor) = self.cursors.last_mut() {
+                        *cursor = idx;
+                    }
+                    return self.select();
+                }
+            }
+        }
+
+        if self.keymap.up.matches(key_msg) {
+            self.cursor_up();
+        } else if self.keymap.down.matches(key_msg) {
+            self.cursor_down();
+        } else if self.keymap.first.matches(key_msg) {
+            if let Some(idx) = first_selectable(self.current_items()) {
+                self.set_cursor(idx);
+            }
+        } else if self.keymap.last.matches(key_msg) {
+            if let Some(idx) = self.current_items().iter().rposition(Item::selectable) {
+                self.set_cursor(idx);
+            }
+        } else if self.keymap.select.matches(key_msg) {
+            return self.select();
+        } else if self.keymap.open.matches(key_msg) {
+            self.open_submenu();
+        } else if self.keymap.back.matches(key_msg) && !self.close_submenu() && self.popup {
+            self.close();
+            let id = self.id;
+            return Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+                Box::new(ClosedMsg { id }) as Msg
+            }));
+        }
+        None
+    }
+
+    /// Renders the menu and any open submenus side by side.
+    ///
+    /// Hidden popups render as an empty string. In accessible mode this is
+    /// the [`accessible_view`](Self::accessible_view).
+    pub fn view(&self) -> String {
+        if !self.visible {
+            return String::new();
+        }
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        let mut items: &[Item] = &self.items;
+        let mut offset = 0;
+        let mut columns = Vec::with_capacity(self.cursors.len());
+        for (level, &cursor) in self.cursors.iter().enumerate() {
+            let is_active = level + 1 == self.cursors.len();
+            let rendered = self.render_level(items, cursor, is_active);
+            columns.push(format!("{}{}", "\n".repeat(offset), rendered));
+            offset += cursor;
+            match items.get(cursor).and_then(Item::children) {
+                Some(children) => items = children,
+                None => break,
+            }
+        }
+        let refs: Vec<&str> = columns.iter().map(String::as_str).collect();
+        lipgloss::join_horizontal(lipgloss::TOP, &refs)
+    }
+
+    /// Renders the open menu level as plain text: one line per entry, then
+    /// which entry has the cursor. Separators are left out, and hidden
+    /// popups render as an empty string.
+    pub fn accessible_view(&self) -> String {
+        if !self.visible {
+            return String::new();
+        }
+        let cursor = self.cursors.last().copied().unwrap_or(0);
+        let mut lines = Vec::new();
+        let mut position = 0;
+        let mut label = "";
+        for (i, item) in self.current_items().iter().enumerate() {
+            if item.is_separator() {
+                continue;
+            }
+            if i == cursor {
+                position = lines.len();
+                label = item.label();
+            }
+            let mut line = item.label().to_string();
+            if item.children().is_some() {
+                line.push_str(", submenu");
+            }
+            if item.disabled() {
+                line.push_str(", unavailable");
+            }
+            lines.push(line);
+        }
+        let announcement = crate::a11y::selected("item", position, lines.len(), label);
+        lines.push(announcement);
+        lines.join("\n")
+    }
+
+    /// Renders the menu offset by its anchor position.
+    ///
+    /// The output is prefixed with one blank line per row and every line is
+    /// indented by the anchor column, so it can be printed below other
+    /// content or used as the top layer of a simple popup.
+    pub fn view_anchored(&self) -> String {
+        let view = self.view();
+        if view.is_empty() {
+            return view;
+        }
+        let (x, y) = self.position;
+        let indent = " ".repeat(x as usize);
+        let body = view
+            .lines()
+            .map(|line| format!("{}{}", indent, line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!("{}{}", "\n".repeat(y as usize), body)
+    }
+
+    fn current_items(&self) -> &[Item] {
+        let mut items: &[Item] = &self.items;
+        for &cursor in &self.cursors[..self.cursors.len().saturating_sub(1)] {
+            match items.get(cursor).and_then(Item::children) {
+                Some(children) => items = children,
+                None => break,
+            }
+        }
+        items
+    }
+
+    fn set_cursor(&mut self, idx: usize) {
+        if let Some(cursor) = self.cursors.last_mut() {
+            *cursor = idx;
+        }
+    }
+
+    fn step(&mut self, delta: isize) {
+        let items = self.current_items();
+        let len = items.len();
+        if len == 0 {
+            return;
+        }
+        let mut idx = self.cursor();
+        for _ in 0..len {
+            idx = (idx as isize + delta).rem_euclid(len as isize) as usize;
+            if items[idx].selectable() {
+                self.set_cursor(idx);
+                return;
+            }
+        }
+    }
+
+    fn accelerator_index(&self, c: char) -> Option<usize> {
+        self.current_items().iter().position(|item| {
+            item.selectable() && item.accelerator.is_some_and(|a| a.eq_ignore_ascii_case(&c))
+        })
+    }
+
+    fn render_level(&self, items: &[Item], cursor: usize, active: bool) -> String {
+        let indicator_width = text::width(&self.submenu_indicator);
+        let has_submenu = items.iter().any(|i| i.children().is_some());
+        let label_width = items
+            .iter()
+            .map(|i| text::width(&i.label))
+            .max()
+            .unwrap_or(0);
+        let inner_width = label_width + if has_submenu { indicator_width + 1 } else { 0 };
+
+        let lines: Vec<String> = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                if item.is_separator() {
+                    return self.styles.separator.render(&"─".repeat(inner_width + 2));
+                }
+                let style = if item.disabled {
+                    &self.styles.disabled
+                } else if i == cursor {
+                    &self.styles.selected
+                } else {
+                    &self.styles.item
+                };
+                let marker = if i == cursor && active && !item.disabled {
+                    ">"
+                } else {
+                    " "
+                };
+                let mut label = self.render_label(item, style);
+                let pad = label_width.saturating_sub(text::width(&item.label));
+                label.push_str(&" ".repeat(pad));
+                if has_submenu {
+                    label.push(' ');
+                    if item.children().is_some() {
+                        label.push_str(&style.render(&self.submenu_indicator));
+                    } else {
+                        label.push_str(&" ".repeat(indicator_width));
+                    }
+                }
+                format!("{} {}", marker, label)
+            })
+            .collect();
+        self.styles.frame.render(&lines.join("\n"))
+    }
+
+    fn render_label(&self, item: &Item, style: &Style) -> String {
+        let accel_at = item.accelerator.and_then(|a| {
+            item.label
+                .char_indices()
+                .find(|(_, c)| c.eq_ignore_ascii_case(&a))
+        });
+        match accel_at {
+            Some((idx, c)) if !item.disabled => {
+                let end = idx + c.len_utf8();
+                format!(
+                    "{}{}{}",
+                    style.render(&item.label[..idx]),
+                    style
+                        .clone()
+                        .inherit(self.styles.accelerator.clone())
+                        .render(&item.label[idx..end]),
+                    style.render(&item.label[end..])
+                )
+            }
+            _ => style.render(&item.label),
+        }
+    }
+}
+
+fn first_selectable(items: &[Item]) -> Option<usize> {
+    items.iter().position(Item::selectable)
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::default(), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn sample() -> Model {
+        let mut m = Model::new(vec![
+            Item::new("Open").with_accelerator('o'),
+            Item::separator(),
+            Item::new("Print").with_disabled(true),
+            Item::submenu(
+                "Export",
+                vec![Item::new("PDF").with_accelerator('p'), Item::new("HTML")],
+            ),
+        ]);
+        m.focus();
+        m
+    }
+
+    #[test]
+    fn test_navigation_skips_separators_and_disabled() {
+        let mut m = sample();
+        assert_eq!(m.cursor(), 0);
+        m.update(key(KeyCode::Down));
+        assert_eq!(m.cursor(), 3);
+        m.update(key(KeyCode::Down));
+        assert_eq!(m.cursor(), 0, "navigation wraps around");
+        m.update(key(KeyCode::Up));
+        assert_eq!(m.cursor(), 3);
+    }
+
+    #[test]
+    fn test_submenu_open_and_close() {
+        let mut m = sample();
+        m.update(key(KeyCode::End));
+        assert!(m.update(key(KeyCode::Enter)).is_none());
+        assert_eq!(m.depth(), 2);
+        assert_eq!(m.selected_item().unwrap().label(), "PDF");
+        assert!(m.view().contains("HTML"));
+        m.update(key(KeyCode::Esc));
+        assert_eq!(m.depth(), 1);
+        assert_eq!(m.selected_item().unwrap().label(), "Export");
+    }
+
+    #[test]
+    fn test_accelerator_selects_and_uses_hook() {
+        use std::sync::{Arc, Mutex};
+        let chosen = Arc::new(Mutex::new(String::new()));
+        let sink = chosen.clone();
+        let mut m = sample().with_on_select(Box::new(move |item| {
+            *sink.lock().unwrap() = item.label().to_string();
+            None
+        }));
+        m.update(key(KeyCode::Right));
+        m.update(key(KeyCode::Char('o')));
+        assert_eq!(*chosen.lock().unwrap(), "Open");
+    }
+
+    #[test]
+    fn test_select_without_hook_emits_command() {
+        let mut m = sample();
+        assert!(m.select().is_some());
+    }
+
+    #[test]
+    fn test_popup_lifecycle() {
+        let mut m = sample().with_popup(true).with_position(2, 1);
+        assert!(!m.visible());
+        assert!(m.view().is_empty());
+        m.open();
+        assert!(m.visible());
+        let anchored = m.view_anchored();
+        assert!(anchored.starts_with('\n'));
+        assert!(anchored.lines().nth(1).unwrap().starts_with("  "));
+        assert!(m.update(key(KeyCode::Esc)).is_some());
+        assert!(!m.visible());
+        assert!(!m.focused());
+    }
+}
@@ -0,0 +1,365 @@
+//! Shared mouse handling, available with the `mouse` feature.
+//!
+//! Terminals report the mouse as raw [`MouseMsg`] events: a button went
+//! down, moved while held, came back up, or the wheel turned. Components
+//! care about higher-level gestures instead, so each one keeps a [`Tracker`]
+//! that turns those events into [`Gesture`]s with coordinates relative to
+//! the component.
+//!
+//! A tracker needs to know where its component is drawn, since events carry
+//! screen coordinates. Set that with the component's `with_mouse_origin` or
+//! `set_mouse_origin`, typically from the parent's layout code. Without an
+//! origin a component still scrolls with the wheel but ignores clicks.
+//!
+//! The list, table, viewport, file picker and text area handle gestures out
+//! of the box when the feature is enabled:
+//!
+//! | Gesture | List / table / file picker | Viewport | Text area |
+//! |---------|----------------------------|----------|-----------|
+//! | Wheel | Moves the selection | Scrolls by `mouse_wheel_delta` lines | - |
+//! | Shift + wheel | - | Scrolls horizontally | - |
+//! | Left press | Selects the entry under the pointer | - | Moves the cursor there |
+//! | Left drag | Selection follows the pointer | Drags the content along | Selects text |
+//!
+//! Mouse events only arrive once reporting is turned on for the program,
+//! for example with bubbletea-rs's `enable_mouse_cell_motion` command.
+//! Drags need cell motion; the wheel and clicks work with any mode.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::mouse::{Gesture, Tracker};
+//! use bubbletea_rs::{MouseMsg, Msg};
+//! use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+//!
+//! let event = |kind, x, y| -> Msg {
+//!     Box::new(MouseMsg { x, y, button: kind, modifiers: KeyModifiers::NONE })
+//! };
+//!
+//! // A 20x5 component drawn at column 10, row 3
+//! let mut tracker = Tracker::new().with_origin(10, 3);
+//! let down = event(MouseEventKind::Down(MouseButton::Left), 12, 4);
+//! assert_eq!(tracker.handle(&down, 20, 5), Some(Gesture::Press { col: 2, row: 1 }));
+//!
+//! let up = event(MouseEventKind::Up(MouseButton::Left), 12, 4);
+//! assert_eq!(tracker.handle(&up, 20, 5), Some(Gesture::Click { col: 2, row: 1 }));
+//!
+//! // Outside the component
+//! let down = event(MouseEventKind::Down(MouseButton::Left), 0, 0);
+//! assert_eq!(tracker.handle(&down, 20, 5), None);
+//! ```
+
+use bubbletea_rs::{MouseMsg, Msg};
+use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+/// A screen area, in cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rect {
+    /// Column of the left edge.
+    pub x: u16,
+    /// Row of the top edge.
+    pub y: u16,
+    /// Width in columns.
+    pub width: usize,
+    /// Height in rows.
+    pub height: usize,
+}
+
+impl Rect {
+    /// Creates an area from its top-left corner and size.
+    pub fn new(x: u16, y: u16, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Reports whether the screen cell at `x`, `y` is inside the area.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        self.local(x, y).is_some()
+    }
+
+    /// Converts a screen cell to `(col, row)` inside the area, or `None`
+    /// when the cell is outside.
+    pub fn local(&self, x: u16, y: u16) -> Option<(usize, usize)> {
+        let col = x.checked_sub(self.x)? as usize;
+        let row = y.checked_sub(self.y)? as usize;
+        (col < self.width && row < self.height).then_some((col, row))
+    }
+}
+
+/// Returns the mouse event carried by `msg`, if any.
+pub fn event(msg: &Msg) -> Option<&MouseMsg> {
+    msg.downcast_ref::<MouseMsg>()
+}
+
+/// Returns the `(dx, dy)` steps of a wheel event, or `None` for other
+/// events.
+///
+/// Scrolling down or right is positive. Holding shift turns the vertical
+/// wheel into a horizontal one, since most mice have no horizontal wheel.
+pub fn wheel(event: &MouseMsg) -> Option<(i32, i32)> {
+    let (dx, dy) = match event.button {
+        MouseEventKind::ScrollUp => (0, -1),
+        MouseEventKind::ScrollDown => (0, 1),
+        MouseEventKind::ScrollLeft => (-1, 0),
+        MouseEventKind::ScrollRight => (1, 0),
+        _ => return None,
+    };
+    if dx == 0 && event.modifiers.contains(KeyModifiers::SHIFT) {
+        Some((dy, 0))
+    } else {
+        Some((dx, dy))
+    }
+}
+
+/// Where a [`Tracker`] is in a click or drag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum State {
+    /// No button is held.
+    #[default]
+    Idle,
+    /// The left button went down at this screen cell and has not moved.
+    Pressed {
+        /// Screen column.
+        x: u16,
+        /// Screen row.
+        y: u16,
+    },
+    /// The left button is held and the pointer has moved.
+    Dragging {
+        /// Screen cell where the button went down.
+        from: (u16, u16),
+        /// Screen cell of the latest drag event.
+        to: (u16, u16),
+    },
+}
+
+/// A mouse gesture, in cells relative to the component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+    /// The left button went down inside the component.
+    Press {
+        /// Column inside the component.
+        col: usize,
+        /// Row inside the component.
+        row: usize,
+    },
+    /// The left button was released where it was pressed.
+    Click {
+        /// Column inside the component.
+        col: usize,
+        /// Row inside the component.
+        row: usize,
+    },
+    /// The pointer moved with the left button held after a press inside
+    /// the component. The position is clamped to the component, while
+    /// `dx` and `dy` give the movement since the previous event.
+    Drag {
+        /// Column inside the component.
+        col: usize,
+        /// Row inside the component.
+        row: usize,
+        /// Columns moved since the previous event.
+        dx: i32,
+        /// Rows moved since the previous event.
+        dy: i32,
+    },
+    /// The left button was released after a drag.
+    DragEnd,
+    /// The wheel turned over the component; see [`wheel`].
+    Wheel {
+        /// Horizontal steps.
+        dx: i32,
+        /// Vertical steps.
+        dy: i32,
+    },
+}
+
+/// Turns raw mouse events into [`Gesture`]s for one component.
+#[derive(Debug, Clone, Default)]
+pub struct Tracker {
+    origin: Option<(u16, u16)>,
+    state: State,
+}
+
+impl Tracker {
+    /// Creates a tracker without an origin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the screen cell of the component's top-left corner.
+    pub fn with_origin(mut self, x: u16, y: u16) -> Self {
+        self.set_origin(x, y);
+        self
+    }
+
+    /// Sets the screen cell of the component's top-left corner.
+    pub fn set_origin(&mut self, x: u16, y: u16) {
+        self.origin = Some((x, y));
+    }
+
+    /// Returns the screen cell of the component's top-left corner.
+    pub fn origin(&self) -> Option<(u16, u16)> {
+        self.origin
+    }
+
+    /// Returns the click or drag in progress.
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Interprets `msg` for a component of `width` x `height` cells.
+    ///
+    /// Returns `None` for non-mouse messages and for events that make no
+    /// gesture, such as presses outside the component.
+    pub fn handle(&mut self, msg: &Msg, width: usize, height: usize) -> Option<Gesture> {
+        let event = event(msg)?;
+        let area = self.origin.map(|(x, y)| Rect::new(x, y, width, height));
+        let inside = area.and_then(|a| a.local(event.x, event.y));
+
+        if let Some((dx, dy)) = wheel(event) {
+            // Without an origin there is no way to tell, so assume the
+            // wheel is meant for this component.
+            return (area.is_none() || inside.is_some()).then_some(Gesture::Wheel { dx, dy });
+        }
+
+        match event.button {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let (col, row) = inside?;
+                self.state = State::Pressed {
+                    x: event.x,
+                    y: event.y,
+                };
+                Some(Gesture::Press { col, row })
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let last = match self.state {
+                    State::Idle => return None,
+                    State::Pressed { x, y } => (x, y),
+                    State::Dragging { to, .. } => to,
+                };
+                let from = match self.state {
+                    State::Dragging { from, .. } => from,
+                    _ => last,
+                };
+                let to = (event.x, event.y);
+                if to == last {
+                    return None;
+                }
+                self.state = State::Dragging { from, to };
+                let area = area?;
+                let clamp = |v: u16, start: u16, len: usize| {
+                    (v.saturating_sub(start) as usize).min(len.saturating_sub(1))
+                };
+                Some(Gesture::Drag {
+                    col: clamp(event.x, area.x, width),
+                    row: clamp(event.y, area.y, height),
+                    dx: i32::from(to.0) - i32::from(last.0),
+                    dy: i32::from(to.1) - i32::from(last.1),
+                })
+            }
+            MouseEventKind::Up(MouseButton::Left) => match std::mem::take(&mut self.state) {
+                State::Idle => None,
+                State::Pressed { x, y } if (x, y) == (event.x, event.y) => {
+                    let (col, row) = inside?;
+                    Some(Gesture::Click { col, row })
+                }
+                State::Pressed { .. } => None,
+                State::Dragging { .. } => Some(Gesture::DragEnd),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(button: MouseEventKind, x: u16, y: u16) -> Msg {
+        Box::new(MouseMsg {
+            x,
+            y,
+            button,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_rect_local() {
+        let rect = Rect::new(5, 2, 10, 3);
+        assert_eq!(rect.local(5, 2), Some((0, 0)));
+        assert_eq!(rect.local(14, 4), Some((9, 2)));
+        assert!(!rect.contains(15, 4));
+        assert!(!rect.contains(4, 2));
+    }
+
+    #[test]
+    fn test_wheel_with_shift_scrolls_horizontally() {
+        let mut msg = MouseMsg {
+            x: 0,
+            y: 0,
+            button: MouseEventKind::ScrollDown,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(wheel(&msg), Some((0, 1)));
+        msg.modifiers = KeyModifiers::SHIFT;
+        assert_eq!(wheel(&msg), Some((1, 0)));
+        msg.button = MouseEventKind::Moved;
+        assert_eq!(wheel(&msg), None);
+    }
+
+    #[test]
+    fn test_drag_reports_steps_and_ends() {
+        let mut tracker = Tracker::new().with_origin(0, 0);
+        let left = MouseButton::Left;
+        tracker.handle(&event(MouseEventKind::Down(left), 3, 3), 10, 10);
+        assert_eq!(
+            tracker.handle(&event(MouseEventKind::Drag(left), 3, 1), 10, 10),
+            Some(Gesture::Drag {
+                col: 3,
+                row: 1,
+                dx: 0,
+                dy: -2
+            })
+        );
+        // Leaving the component clamps the position but keeps dragging
+        assert_eq!(
+            tracker.handle(&event(MouseEventKind::Drag(left), 20, 1), 10, 10),
+            Some(Gesture::Drag {
+                col: 9,
+                row: 1,
+                dx: 17,
+                dy: 0
+            })
+        );
+        assert_eq!(
+            tracker.state(),
+            State::Dragging {
+                from: (3, 3),
+                to: (20, 1)
+            }
+        );
+        assert_eq!(
+            tracker.handle(&event(MouseEventKind::Up(left), 20, 1), 10, 10),
+            Some(Gesture::DragEnd)
+        );
+        assert_eq!(tracker.state(), State::Idle);
+    }
+
+    #[test]
+    fn test_wheel_outside_origin_is_ignored() {
+        let mut tracker = Tracker::new();
+        let wheel = event(MouseEventKind::ScrollUp, 50, 50);
+        assert_eq!(
+            tracker.handle(&wheel, 10, 10),
+            Some(Gesture::Wheel { dx: 0, dy: -1 })
+        );
+        tracker.set_origin(0, 0);
+        assert_eq!(tracker.handle(&wheel, 10, 10), None);
+    }
+}
@@ -0,0 +1,664 @@
+//! Table of contents sidebar.
+//!
+//! An outline lists document sections as an indented tree built from
+//! [`Entry`]s, each with a nesting level, a title and a target line. Moving
+//! the cursor and pressing `enter` emits a [`JumpMsg`] carrying the target,
+//! which the application passes on to a viewer, for example
+//! `codeview::Model::goto_line` or `viewport::Model::set_y_offset`.
+//! Sections with children can be collapsed and expanded.
+//!
+//! [`from_markdown`] builds entries from the ATX headings of a Markdown
+//! document. To keep the outline in step with a scrolling viewer, call
+//! [`Model::set_position`] with the viewer's current line; the enclosing
+//! section is then highlighted.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `↑`/`k`, `↓`/`j` | Move between entries |
+//! | `←`/`h` | Collapse the section, or move to its parent |
+//! | `→`/`l` | Expand the section |
+//! | `g`, `G` | First / last entry |
+//! | `enter` | Emit a [`JumpMsg`] for the selected entry |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::outline;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let doc = "# Guide\nIntro\n## Install\nSteps\n## Usage\n### Flags\n";
+//! let toc = outline::new(outline::from_markdown(doc), 30, 10);
+//! assert_eq!(
+//!     strip_ansi(&toc.view()),
+//!     "▾ Guide\n    Install\n  ▾ Usage\n      Flags"
+//! );
+//! assert_eq!(toc.entries()[2].target, 5);
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// A section of the outline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Nesting level; 1 for top-level sections.
+    pub level: usize,
+    /// Section title.
+    pub title: String,
+    /// Where the section starts, usually a one-based line number.
+    pub target: usize,
+}
+
+impl Entry {
+    /// Creates an entry.
+    pub fn new(level: usize, title: &str, target: usize) -> Self {
+        Self {
+            level,
+            title: title.to_string(),
+            target,
+        }
+    }
+}
+
+/// Builds entries from the ATX headings (`#` to `######`) of a Markdown
+/// document, skipping fenced code blocks. Targets are one-based source line
+/// numbers.
+pub fn from_markdown(source: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut in_fence = false;
+    for (i, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if !(1..=6).contains(&level) {
+            continue;
+        }
+        let rest = &trimmed[level..];
+        if !rest.is_empty() && !rest.starts_with(' ') {
+            continue;
+        }
+        let title = rest.trim().trim_end_matches('#').trim_end();
+        entries.push(Entry::new(level, title, i + 1));
+    }
+    entries
+}
+
+/// Message emitted when the user jumps to an entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JumpMsg {
+    /// The ID of the outline.
+    pub id: i64,
+    /// Index of the entry.
+    pub index: usize,
+    /// Title of the entry.
+    pub title: String,
+    /// Target of the entry.
+    pub target: usize,
+}
+
+/// Key bindings for the outline.
+#[derive(Debug, Clone)]
+pub struct OutlineKeyMap {
+    /// Moves to the previous entry. Default: `↑`, `k`.
+    pub up: key::Binding,
+    /// Moves to the next entry. Default: `↓`, `j`.
+    pub down: key::Binding,
+    /// Collapses the section or moves to its parent. Default: `←`, `h`.
+    pub collapse: key::Binding,
+    /// Expands the section. Default: `→`, `l`.
+    pub expand: key::Binding,
+    /// Moves to the first entry. Default: `g`, `home`.
+    pub top: key::Binding,
+    /// Moves to the last entry. Default: `G`, `end`.
+    pub bottom: key::Binding,
+    /// Jumps to the selected entry. Default: `enter`.
+    pub jump: key::Binding,
+}
+
+impl Default for OutlineKeyMap {
+    fn default() -> Self {
+        Self {
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "k"]),
+                key::with_help("↑/k", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "j"]),
+                key::with_help("↓/j", "down"),
+            ]),
+            collapse: key::new_binding(vec![
+                key::with_keys_str(&["left", "h"]),
+                key::with_help("←/h", "collapse"),
+            ]),
+            expand: key::new_binding(vec![
+                key::with_keys_str(&["right", "l"]),
+                key::with_help("→/l", "expand"),
+            ]),
+            top: key::new_binding(vec![
+                key::with_keys_str(&["g", "home"]),
+                key::with_help("g", "top"),
+            ]),
+            bottom: key::new_binding(vec![
+                key::with_keys_str(&["G", "end"]),
+                key::with_help("G", "bottom"),
+            ]),
+            jump: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "go"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for OutlineKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.up, &self.down, &self.jump]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.up, &self.down, &self.top, &self.bottom],
+            vec![&self.collapse, &self.expand, &self.jump],
+        ]
+    }
+}
+
+/// Visual styles for the outline.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Entries other than the selected and current ones.
+    pub entry: Style,
+    /// Top-level entries.
+    pub top_level: Style,
+    /// The entry under the cursor.
+    pub selected: Style,
+    /// The section containing the viewer's position.
+    pub current: Style,
+    /// The `▾`/`▸` markers of sections with children.
+    pub marker: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            entry: Style::new().foreground(AdaptiveColor {
+                Light: "#847A85",
+                Dark: "#A49FA5",
+            }),
+            top_level: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#1A1A1A",
+                    Dark: "#DDDDDD",
+                })
+                .bold(true),
+            selected: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            current: Style::new().foreground(AdaptiveColor {
+                Light: "#2E8B57",
+                Dark: "#04B575",
+            }),
+            marker: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates outline styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            entry: theme.muted.clone(),
+            top_level: theme.title.clone(),
+            selected: theme.selected.clone(),
+            current: theme.success.clone(),
+            marker: theme.subtle.clone(),
+        }
+    }
+}
+
+/// A table of contents.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    entries: Vec<Entry>,
+    collapsed: HashSet<usize>,
+    cursor: usize,
+    offset: usize,
+    current: Option<usize>,
+    focus: bool,
+    /// Width in columns; longer titles are truncated.
+    pub width: usize,
+    /// Number of visible rows.
+    pub height: usize,
+    /// Key bindings.
+    pub keymap: OutlineKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused outline of `entries` with every section expanded.
+pub fn new(entries: Vec<Entry>, width: usize, height: usize) -> Model {
+    Model::new(entries, width, height)
+}
+
+impl Model {
+    /// Creates a focused outline of `entries` with every section expanded.
+    pub fn new(entries: Vec<Entry>, width: usize, height: usize) -> Self {
+        Self {
+            id: next_id(),
+            entries,
+            collapsed: HashSet::new(),
+            cursor: 0,
+            offset: 0,
+            current: None,
+            focus: true,
+            width,
+            height,
+            keymap: OutlineKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this outline.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the entries.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Replaces the entries, expanding everything and resetting the cursor.
+    pub fn set_entries(&mut self, entries: Vec<Entry>) {
+        self.entries = entries;
+        self.collapsed.clear();
+        self.cursor = 0;
+        self.offset = 0;
+        self.current = None;
+    }
+
+    /// Returns the index of the entry under the cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Returns the entry under the cursor.
+    pub fn selected(&self) -> Option<&Entry> {
+        self.entries.get(self.cursor)
+    }
+
+    /// Moves the cursor to an entry, expanding its ancestors so it is
+    /// visible. Out-of-range indices are clamped.
+    pub fn select(&mut self, index: usize) {
+        if self.entries.is_empty() {
+            return;
+        }
+        self.cursor = index.min(self.entries.len() - 1);
+        let mut i = self.cursor;
+        while let Some(parent) = self.parent(i) {
+            self.collapsed.remove(&parent);
+            i = parent;
+        }
+        self.scroll_to_cursor();
+    }
+
+    /// Highlights the section containing `target`: the last entry whose
+    /// target is at or before it. Call this as the viewer scrolls.
+    pub fn set_position(&mut self, target: usize) {
+        self.current = self.entries.iter().rposition(|e| e.target <= target);
+    }
+
+    /// Returns the index of the section containing the last position given
+    /// to [`set_position`](Self::set_position).
+    pub fn current(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Returns whether the entry at `index` has children.
+    pub fn has_children(&self, index: usize) -> bool {
+        match (self.entries.get(index), self.entries.get(index + 1)) {
+            (Some(e), Some(next)) => next.level > e.level,
+            _ => false,
+        }
+    }
+
+    /// Collapses the section at `index`, hiding its children.
+    pub fn collapse(&mut self, index: usize) {
+        if self.has_children(index) {
+            self.collapsed.insert(index);
+            // Keep the cursor out of hidden sections.
+            if self.cursor > index && self.ancestors(self.cursor).contains(&index) {
+                self.cursor = index;
+            }
+            self.scroll_to_cursor();
+        }
+    }
+
+    /// Expands the section at `index`.
+    pub fn expand(&mut self, index: usize) {
+        self.collapsed.remove(&index);
+    }
+
+    /// Returns whether the section at `index` is collapsed.
+    pub fn is_collapsed(&self, index: usize) -> bool {
+        self.collapsed.contains(&index)
+    }
+
+    fn parent(&self, index: usize) -> Option<usize> {
+        let level = self.entries.get(index)?.level;
+        (0..index).rev().find(|&i| self.entries[i].level < level)
+    }
+
+    fn ancestors(&self, index: usize) -> Vec<usize> {
+        let mut out = Vec::new();
+        let mut i = index;
+        while let Some(parent) = self.parent(i) {
+            out.push(parent);
+            i = parent;
+        }
+        out
+    }
+
+    /// Returns the indices of entries not hidden by a collapsed ancestor.
+    fn visible(&self) -> Vec<usize> {
+        let mut out = Vec::new();
+        let mut hide_below: Option<usize> = None;
+        for (i, e) in self.entries.iter().enumerate() {
+            if let Some(level) = hide_below {
+                if e.level > level {
+                    continue;
+                }
+                hide_below = None;
+            }
+            out.push(i);
+            if self.collapsed.contains(&i) {
+                hide_below = Some(e.level);
+            }
+        }
+        out
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let visible = self.visible();
+        let row = visible.iter().position(|&i| i == self.cursor).unwrap_or(0);
+        if row < self.offset {
+            self.offset = row;
+        } else if self.height > 0 && row >= self.offset + self.height {
+            self.offset = row + 1 - self.height;
+        }
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let visible = self.visible();
+        let row = visible.iter().position(|&i| i == self.cursor).unwrap_or(0);
+        let row = row.saturating_add_signed(delta).min(visible.len() - 1);
+        self.cursor = visible[row];
+        self.scroll_to_cursor();
+    }
+
+    /// Moves through the tree and emits a [`JumpMsg`] on `enter`. Blurred
+    /// outlines ignore input.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus || self.entries.is_empty() {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if self.keymap.up.matches(key_msg) {
+            self.move_by(-1);
+        } else if self.keymap.down.matches(key_msg) {
+            self.move_by(1);
+        } else if self.keymap.top.matches(key_msg) {
+            self.move_by(isize::MIN);
+        } else if self.keymap.bottom.matches(key_msg) {
+            self.move_by(isize::MAX);
+        } else if self.keymap.collapse.matches(key_msg) {
+            if self.has_children(self.cursor) && !self.is_collapsed(self.cursor) {
+                self.collapse(self.cursor);
+            } else if let Some(parent) = self.parent(self.cursor) {
+                self.select(parent);
+            }
+        } else if self.keymap.expand.matches(key_msg) {
+            self.expand(self.cursor);
+        } else if self.keymap.jump.matches(key_msg) {
+            let (id, index) = (self.id, self.cursor);
+            let Entry { title, target, .. } = self.entries[index].clone();
+            return Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+                Box::new(JumpMsg {
+                    id,
+                    index,
+                    title: title.clone(),
+                    target,
+                }) as Msg
+            }));
+        }
+        None
+    }
+
+    /// Renders the visible part of the tree.
+    pub fn view(&self) -> String {
+        let s = &self.styles;
+        let min_level = self.entries.iter().map(|e| e.level).min().unwrap_or(1);
+        let visible = self.visible();
+        let end = if self.height == 0 {
+            visible.len()
+        } else {
+            (self.offset + self.height).min(visible.len())
+        };
+        visible[self.offset.min(end)..end]
+            .iter()
+            .map(|&i| {
+                let e = &self.entries[i];
+                let indent = "  ".repeat(e.level - min_level);
+                let marker = if !self.has_children(i) {
+                    "  "
+                } else if self.is_collapsed(i) {
+                    "▸ "
+                } else {
+                    "▾ "
+                };
+                let avail = self.width.saturating_sub(text::width(&indent) + 2);
+                let title = text::truncate(&e.title, avail);
+                let style = if i == self.cursor && self.focus {
+                    &s.selected
+                } else if Some(i) == self.current {
+                    &s.current
+                } else if e.level == min_level {
+                    &s.top_level
+                } else {
+                    &s.entry
+                };
+                let marker = if marker.trim().is_empty() {
+                    marker.to_string()
+                } else {
+                    s.marker.render(marker)
+                };
+                format!("{}{}{}", indent, marker, style.render(&title))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved collapsed sections and position of an outline; see
+/// [`Stateful`](crate::state::Stateful). The entries are not included.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Indices of the collapsed sections, sorted.
+    pub collapsed: Vec<usize>,
+    /// Index of the entry under the cursor.
+    pub cursor: usize,
+    /// Rows scrolled from the top.
+    pub scroll: usize,
+}
+
+/// Restoring expands the ancestors of the saved cursor entry, like
+/// [`Model::select`].
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        let mut collapsed: Vec<usize> = self.collapsed.iter().copied().collect();
+        collapsed.sort_unstable();
+        State {
+            collapsed,
+            cursor: self.cursor,
+            scroll: self.offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.collapsed = state
+            .collapsed
+            .into_iter()
+            .filter(|&i| self.has_children(i))
+            .collect();
+        self.offset = state
+            .scroll
+            .min(self.visible().len().saturating_sub(self.height.max(1)));
+        self.select(state.cursor);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new(), 30, 20), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn sample() -> Model {
+        Model::new(
+            vec![
+                Entry::new(1, "Intro", 1),
+                Entry::new(2, "Goals", 10),
+                Entry::new(2, "Scope", 20),
+                Entry::new(1, "Design", 30),
+                Entry::new(2, "Storage", 40),
+            ],
+            20,
+            10,
+        )
+    }
+
+    #[test]
+    fn test_from_markdown_skips_code_fences() {
+        let doc = "# A\n```\n# not a heading\n```\n## B ##\n#hashtag\n";
+        assert_eq!(
+            from_markdown(doc),
+            vec![Entry::new(1, "A", 1), Entry::new(2, "B", 5)]
+        );
+    }
+
+    #[test]
+    fn test_collapse_hides_children_and_moves_to_parent() {
+        let mut o = sample();
+        o.update(key(KeyCode::Down));
+        assert_eq!(o.cursor(), 1);
+        // A leaf moves to its parent, then the parent collapses.
+        o.update(key(KeyCode::Left));
+        assert_eq!(o.cursor(), 0);
+        o.update(key(KeyCode::Left));
+        assert!(o.is_collapsed(0));
+        assert_eq!(strip_ansi(&o.view()), "▸ Intro\n▾ Design\n    Storage");
+        o.update(key(KeyCode::Down));
+        assert_eq!(o.cursor(), 3);
+        o.select(2);
+        assert!(!o.is_collapsed(0));
+    }
+
+    #[test]
+    fn test_enter_emits_jump_and_position_tracks_section() {
+        let mut o = sample();
+        o.update(key(KeyCode::Char('G')));
+        assert_eq!(o.selected().unwrap().target, 40);
+        assert!(o.update(key(KeyCode::Enter)).is_some());
+        o.set_position(25);
+        assert_eq!(o.current(), Some(2));
+        o.set_position(0);
+        assert_eq!(o.current(), None);
+    }
+
+    #[test]
+    fn test_height_and_width_limits() {
+        let mut o = sample();
+        o.height = 2;
+        o.width = 8;
+        o.update(key(KeyCode::Char('G')));
+        assert_eq!(strip_ansi(&o.view()), "▾ Design\n    Sto…");
+    }
+}
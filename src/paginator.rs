@@ -5,7 +5,9 @@
 //! for handling the state and view of the pagination control itself.
 
 use crate::key::{self, KeyMap as KeyMapTrait};
-use bubbletea_rs::{KeyMsg, Msg};
+use crate::text;
+use bubbletea_rs::{KeyMsg, MouseMsg, Msg};
+use crossterm::event::{MouseButton, MouseEventKind};
 
 /// The type of pagination to display.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -219,6 +221,14 @@ pub struct Model {
 
     /// Key bindings.
     pub keymap: PaginatorKeyMap,
+
+    /// Screen position `(x, y)` where the paginator is rendered.
+    ///
+    /// When set, left clicks on a dot in Dots mode jump directly to that page.
+    /// Parents embedding the paginator in a wider layout are responsible for
+    /// keeping this in sync with where the view is drawn. `None` disables
+    /// mouse handling.
+    pub mouse_origin: Option<(u16, u16)>,
 }
 
 impl Default for Model {
@@ -233,6 +243,7 @@ impl Default for Model {
     /// - Inactive dot: "○" (for dots mode)
     /// - Arabic format: "%d/%d" (current/total)
     /// - Default key bindings
+    /// - No mouse origin (mouse clicks are ignored)
     ///
     /// # Examples
     ///
@@ -255,10 +266,31 @@ impl Default for Model {
             inactive_dot: "○".to_string(),
             arabic_format: "%d/%d".to_string(),
             keymap: PaginatorKeyMap::default(),
+            mouse_origin: None,
         }
     }
 }
 
+/// Saved page of a paginator; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The current page, starting at 0.
+    pub page: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State { page: self.page }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.page = state.page.min(self.total_pages.saturating_sub(1));
+    }
+}
+
 impl Model {
     /// Creates a new paginator model with default settings.
     ///
@@ -437,6 +469,64 @@ impl Model {
         self.inactive_dot = dot.to_string();
     }
 
+    /// Sets the screen position where the paginator is rendered (builder pattern).
+    ///
+    /// This enables mouse support: a left click on a dot at this origin jumps
+    /// to the corresponding page.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the first rendered cell
+    /// * `y` - The row the paginator is rendered on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::paginator::{Model, Type};
+    /// use bubbletea_rs::{MouseMsg, Msg};
+    /// use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+    ///
+    /// let mut paginator = Model::new()
+    ///     .with_per_page(10)
+    ///     .with_total_items(50)
+    ///     .with_mouse_origin(10, 20);
+    /// paginator.paginator_type = Type::Dots;
+    ///
+    /// // Click the fourth dot, rendered at column 13 on row 20
+    /// let click: Msg = Box::new(MouseMsg {
+    ///     x: 13,
+    ///     y: 20,
+    ///     button: MouseEventKind::Down(MouseButton::Left),
+    ///     modifiers: KeyModifiers::NONE,
+    /// });
+    /// paginator.update(&click);
+    /// assert_eq!(paginator.page, 3);
+    /// ```
+    pub fn with_mouse_origin(mut self, x: u16, y: u16) -> Self {
+        self.mouse_origin = Some((x, y));
+        self
+    }
+
+    /// Sets the screen position where the paginator is rendered (mutable version).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The column of the first rendered cell
+    /// * `y` - The row the paginator is rendered on
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::paginator::Model;
+    ///
+    /// let mut paginator = Model::new();
+    /// paginator.set_mouse_origin(4, 12);
+    /// assert_eq!(paginator.mouse_origin, Some((4, 12)));
+    /// ```
+    pub fn set_mouse_origin(&mut self, x: u16, y: u16) {
+        self.mouse_origin = Some((x, y));
+    }
+
     /// Sets the total number of pages directly.
     ///
     /// The minimum value is 1; any value less than 1 will be clamped to 1.
@@ -720,12 +810,130 @@ impl Model {
         self.page == self.total_pages.saturating_sub(1)
     }
 
+    /// Returns the starting column of each dot in the rendered Dots view.
+    ///
+    /// Offsets are relative to the start of the paginator's output and are
+    /// measured in terminal cells, so styled or wide dot strings are handled
+    /// correctly. The returned vector has one entry per page. In Arabic mode
+    /// there are no individual page targets and the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::paginator::{Model, Type};
+    ///
+    /// let mut paginator = Model::new().with_per_page(10).with_total_items(30);
+    /// paginator.paginator_type = Type::Dots;
+    /// assert_eq!(paginator.dot_offsets(), vec![0, 1, 2]);
+    ///
+    /// paginator.set_active_dot("[*]");
+    /// paginator.set_inactive_dot("[ ]");
+    /// assert_eq!(paginator.dot_offsets(), vec![0, 3, 6]);
+    /// ```
+    pub fn dot_offsets(&self) -> Vec<usize> {
+        if self.paginator_type != Type::Dots {
+            return Vec::new();
+        }
+
+        let mut offsets = Vec::with_capacity(self.total_pages);
+        let mut x = 0;
+        for i in 0..self.total_pages {
+            offsets.push(x);
+            x += self.dot_width(i);
+        }
+        offsets
+    }
+
+    /// Returns the page whose dot is rendered at the given column.
+    ///
+    /// The column is relative to the start of the paginator's output. This is
+    /// useful for parents that track mouse positions themselves and only need
+    /// to translate a click into a page index.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - Column relative to the paginator's first cell
+    ///
+    /// # Returns
+    ///
+    /// `Some(page)` if a dot occupies the column, or `None` if the column is
+    /// outside the dots or the paginator is in Arabic mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::paginator::{Model, Type};
+    ///
+    /// let mut paginator = Model::new().with_per_page(10).with_total_items(50);
+    /// paginator.paginator_type = Type::Dots;
+    ///
+    /// assert_eq!(paginator.page_at_x(0), Some(0));
+    /// assert_eq!(paginator.page_at_x(3), Some(3));
+    /// assert_eq!(paginator.page_at_x(5), None); // Past the last dot
+    ///
+    /// paginator.paginator_type = Type::Arabic;
+    /// assert_eq!(paginator.page_at_x(0), None);
+    /// ```
+    pub fn page_at_x(&self, col: usize) -> Option<usize> {
+        self.dot_offsets()
+            .into_iter()
+            .enumerate()
+            .find(|&(i, start)| col >= start && col < start + self.dot_width(i))
+            .map(|(i, _)| i)
+    }
+
+    /// Jumps to the page whose dot is rendered at the given column.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - Column relative to the paginator's first cell
+    ///
+    /// # Returns
+    ///
+    /// `true` if a dot was hit and the page was changed, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::paginator::{Model, Type};
+    ///
+    /// let mut paginator = Model::new().with_per_page(10).with_total_items(50);
+    /// paginator.paginator_type = Type::Dots;
+    ///
+    /// assert!(paginator.click_at_x(2));
+    /// assert_eq!(paginator.page, 2);
+    ///
+    /// assert!(!paginator.click_at_x(42));
+    /// assert_eq!(paginator.page, 2);
+    /// ```
+    pub fn click_at_x(&mut self, col: usize) -> bool {
+        match self.page_at_x(col) {
+            Some(page) if page != self.page => {
+                self.page = page;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Width in cells of the dot rendered for page `i`.
+    fn dot_width(&self, i: usize) -> usize {
+        if i == self.page {
+            text::width(&self.active_dot)
+        } else {
+            text::width(&self.inactive_dot)
+        }
+    }
+
     /// Updates the paginator based on received messages.
     ///
     /// This method should be called from your application's `update()` method
     /// to handle pagination key presses. It automatically responds to the
     /// configured key bindings for next/previous page navigation.
     ///
+    /// When `mouse_origin` is set, left clicks on a dot in Dots mode jump
+    /// directly to that page.
+    ///
     /// # Arguments
     ///
     /// * `msg` - The message to process, typically containing key press events
@@ -759,6 +967,15 @@ impl Model {
             } else if self.keymap.prev_page.matches(key_msg) {
                 self.prev_page();
             }
+        } else if let Some(mouse_msg) = msg.downcast_ref::<MouseMsg>() {
+            if mouse_msg.button != MouseEventKind::Down(MouseButton::Left) {
+                return;
+            }
+            if let Some((x, y)) = self.mouse_origin {
+                if mouse_msg.y == y && mouse_msg.x >= x {
+                    self.click_at_x((mouse_msg.x - x) as usize);
+                }
+            }
         }
     }
 
@@ -792,13 +1009,24 @@ impl Model {
     /// paginator.page = 2;
     /// assert_eq!(paginator.view(), "○○•○○"); // Third bullet filled (active page), others hollow
     /// ```
+    ///
+    /// In accessible mode this is the [`accessible_view`](Self::accessible_view).
     pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
         match self.paginator_type {
             Type::Arabic => self.arabic_view(),
             Type::Dots => self.dots_view(),
         }
     }
 
+    /// Renders the position as plain text, such as `page 2 of 5`, whatever
+    /// the paginator type.
+    pub fn accessible_view(&self) -> String {
+        crate::a11y::position("page", self.page, self.total_pages.max(1))
+    }
+
     fn arabic_view(&self) -> String {
         self.arabic_format
             .replacen("%d", &(self.page + 1).to_string(), 1)
@@ -819,3 +1047,74 @@ impl Model {
         s
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn dots(pages: usize) -> Model {
+        let mut paginator = Model::new().with_per_page(1).with_total_items(pages);
+        paginator.paginator_type = Type::Dots;
+        paginator
+    }
+
+    fn click(x: u16, y: u16, button: MouseEventKind) -> Msg {
+        Box::new(MouseMsg {
+            x,
+            y,
+            button,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_page_at_x_follows_dot_widths() {
+        let mut paginator = dots(3);
+        paginator.active_dot = "[*]".to_string();
+        paginator.inactive_dot = "-".to_string();
+        // Drawn as "[*]--"
+        assert_eq!(paginator.page_at_x(0), Some(0));
+        assert_eq!(paginator.page_at_x(2), Some(0));
+        assert_eq!(paginator.page_at_x(3), Some(1));
+        assert_eq!(paginator.page_at_x(4), Some(2));
+        assert_eq!(paginator.page_at_x(5), None);
+
+        // A click on the current page changes nothing
+        assert!(!paginator.click_at_x(1));
+        assert!(paginator.click_at_x(4));
+        assert_eq!(paginator.page, 2);
+        // Drawn as "--[*]" now
+        assert_eq!(paginator.page_at_x(2), Some(2));
+        assert_eq!(paginator.page_at_x(1), Some(1));
+    }
+
+    #[test]
+    fn test_update_clicks_dots_relative_to_origin() {
+        let left = MouseEventKind::Down(MouseButton::Left);
+        let mut paginator = dots(5);
+
+        // Without an origin clicks are ignored
+        paginator.update(&click(2, 0, left));
+        assert_eq!(paginator.page, 0);
+
+        paginator.set_mouse_origin(10, 4);
+        paginator.update(&click(13, 4, left));
+        assert_eq!(paginator.page, 3);
+
+        // Other rows, columns left of the origin and other buttons miss
+        paginator.update(&click(11, 5, left));
+        paginator.update(&click(9, 4, left));
+        paginator.update(&click(11, 4, MouseEventKind::Down(MouseButton::Right)));
+        paginator.update(&click(11, 4, MouseEventKind::Up(MouseButton::Left)));
+        assert_eq!(paginator.page, 3);
+
+        paginator.update(&click(10, 4, left));
+        assert_eq!(paginator.page, 0);
+
+        // Arabic pages have no dots to click
+        paginator.paginator_type = Type::Arabic;
+        paginator.update(&click(12, 4, left));
+        assert_eq!(paginator.page, 0);
+    }
+}
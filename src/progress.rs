@@ -31,22 +31,15 @@
 //! let cmd = progress.decr_percent(0.05); // Subtract 5%
 //! ```
 
-use bubbletea_rs::{tick as bubbletea_tick, Cmd, Model as BubbleTeaModel, Msg};
+use crate::anim::{self, Spring};
+use crate::text;
+use crate::widget::Margins;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
 use lipgloss_extras::lipgloss::blending::blend_1d;
 use lipgloss_extras::lipgloss::Color as LGColor;
 use lipgloss_extras::prelude::*;
-use std::sync::atomic::{AtomicI64, Ordering};
-use std::time::Duration;
-
-// Internal ID management for progress instances
-static LAST_ID: AtomicI64 = AtomicI64::new(0);
-
-fn next_id() -> i64 {
-    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
-}
 
 // Constants matching Go implementation
-const FPS: u32 = 60;
 const DEFAULT_WIDTH: i32 = 40;
 const DEFAULT_FREQUENCY: f64 = 18.0;
 const DEFAULT_DAMPING: f64 = 1.0;
@@ -108,6 +101,9 @@ pub enum ProgressOption {
     /// Configures the spring animation parameters for smooth transitions.
     /// First value is frequency (speed), second is damping (bounciness).
     WithSpringOptions(f64, f64),
+    /// Makes the bar follow the terminal width, less the left and right
+    /// margins, on every `WindowSizeMsg`.
+    WithAutoResize(Margins),
 }
 
 impl ProgressOption {
@@ -143,6 +139,9 @@ impl ProgressOption {
                 m.set_spring_options(*frequency, *damping);
                 m.spring_customized = true;
             }
+            ProgressOption::WithAutoResize(margins) => {
+                m.resize_margins = Some(*margins);
+            }
         }
     }
 }
@@ -367,6 +366,27 @@ pub fn with_width(w: i32) -> ProgressOption {
     ProgressOption::WithWidth(w)
 }
 
+/// Makes the progress bar follow the terminal width.
+///
+/// On every `WindowSizeMsg` passed to `update`, the width becomes the
+/// terminal width minus the left and right margins. The vertical margins
+/// are ignored since the bar is always one line tall.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::progress::{new, with_auto_resize};
+/// use bubbletea_widgets::widget::Margins;
+/// use bubbletea_rs::WindowSizeMsg;
+///
+/// let mut progress = new(&[with_auto_resize(Margins::symmetric(0, 2))]);
+/// progress.update(Box::new(WindowSizeMsg { width: 80, height: 24 }));
+/// assert_eq!(progress.width, 76);
+/// ```
+pub fn with_auto_resize(margins: Margins) -> ProgressOption {
+    ProgressOption::WithAutoResize(margins)
+}
+
 /// Configures the spring animation parameters for smooth progress transitions.
 ///
 /// The progress bar uses a spring-based physics system to animate between
@@ -402,80 +422,12 @@ pub fn with_spring_options(frequency: f64, damping: f64) -> ProgressOption {
     ProgressOption::WithSpringOptions(frequency, damping)
 }
 
-/// Message indicating that an animation frame should be processed.
-///
-/// This message is used internally by the progress bar's animation system to
-/// trigger smooth transitions between progress values. Each `FrameMsg` is
-/// associated with a specific progress bar instance and animation sequence
-/// to ensure proper message routing and prevent timing conflicts.
-///
-/// The message contains identity information that allows the progress bar
-/// to validate that it should process the frame update, preventing issues
-/// with multiple progress bars or rapid state changes.
+/// Message that advances the bar's animation.
 ///
-/// # Internal Usage
-///
-/// You typically won't create `FrameMsg` instances directly. They are
-/// generated automatically when you call methods like `set_percent()`,
-/// `incr_percent()`, or `decr_percent()` on a progress bar.
-///
-/// # Examples
-///
-/// ```rust
-/// use bubbletea_widgets::progress::new;
-///
-/// let mut progress = new(&[]);
-///
-/// // This automatically creates and schedules FrameMsg instances
-/// let cmd = progress.set_percent(0.75);
-///
-/// // The progress bar's update() method will handle the FrameMsg
-/// // to animate smoothly to 75%
-/// ```
-#[derive(Debug, Clone)]
-pub struct FrameMsg {
-    /// Unique identifier of the progress bar instance.
-    ///
-    /// This ensures that frame messages are only processed by the
-    /// correct progress bar when multiple bars exist in an application.
-    id: i64,
-    /// Animation sequence tag to prevent stale frame messages.
-    ///
-    /// The tag is incremented each time a new animation starts,
-    /// allowing the progress bar to ignore outdated frame messages
-    /// from previous animation sequences.
-    tag: i64,
-}
-
-/// Simple spring animation system (simplified version of harmonica)
-#[derive(Debug, Clone)]
-struct Spring {
-    frequency: f64,
-    damping: f64,
-    fps: f64,
-}
-
-impl Spring {
-    fn new(fps: f64, frequency: f64, damping: f64) -> Self {
-        Self {
-            frequency,
-            damping,
-            fps,
-        }
-    }
-
-    fn update(&self, position: f64, velocity: f64, target: f64) -> (f64, f64) {
-        let dt = 1.0 / self.fps;
-        let spring_force = -self.frequency * (position - target);
-        let damping_force = -self.damping * velocity;
-        let acceleration = spring_force + damping_force;
-
-        let new_velocity = velocity + acceleration * dt;
-        let new_position = position + new_velocity * dt;
-
-        (new_position, new_velocity)
-    }
-}
+/// Returned by [`Model::set_percent`] and friends and handled by
+/// [`Model::update`]; it is the shared [`anim::FrameMsg`], routed to the
+/// right bar by its ID.
+pub use crate::anim::FrameMsg;
 
 /// The main progress bar model containing all state and configuration.
 ///
@@ -600,6 +552,9 @@ pub struct Model {
     /// of the progress bar. When false, the width of the gradient will be set
     /// to the full width of the progress bar.
     scale_ramp: bool,
+
+    /// Margins kept when following the terminal width, if enabled.
+    resize_margins: Option<Margins>,
 }
 
 /// Creates a new progress bar with the specified configuration options.
@@ -618,7 +573,8 @@ pub struct Model {
 /// - **Fill character**: '█' (full block)
 /// - **Empty character**: '░' (light shade)
 /// - **Fill color**: "#7571F9" (purple)
-/// - **Empty color**: "#606060" (gray)
+/// - **Empty color**: "#606060" (gray) on dark backgrounds, "#D0D0D0" on light
+///   ones, chosen when the bar is created (see [`crate::theme::force_light`])
 /// - **Percentage**: Shown by default
 /// - **Animation**: Spring physics with frequency=18.0, damping=1.0
 ///
@@ -656,17 +612,17 @@ pub struct Model {
 /// ```
 pub fn new(opts: &[ProgressOption]) -> Model {
     let mut m = Model {
-        id: next_id(),
+        id: anim::next_id(),
         tag: 0,
         width: DEFAULT_WIDTH,
         full: '█',
         full_color: "#7571F9".to_string(),
         empty: '░',
-        empty_color: "#606060".to_string(),
+        empty_color: crate::theme::adaptive("#D0D0D0", "#606060").to_string(),
         show_percentage: true,
         percent_format: " %3.0f%%".to_string(),
         percentage_style: Style::new(),
-        spring: Spring::new(FPS as f64, DEFAULT_FREQUENCY, DEFAULT_DAMPING),
+        spring: Spring::new(anim::fps(anim::FPS), DEFAULT_FREQUENCY, DEFAULT_DAMPING),
         spring_customized: false,
         percent_shown: 0.0,
         target_percent: 0.0,
@@ -675,6 +631,7 @@ pub fn new(opts: &[ProgressOption]) -> Model {
         ramp_color_a: String::new(),
         ramp_color_b: String::new(),
         scale_ramp: false,
+        resize_margins: None,
     };
 
     for opt in opts {
@@ -724,7 +681,7 @@ impl Model {
     /// let cmd = progress.set_percent(0.8);
     /// ```
     pub fn set_spring_options(&mut self, frequency: f64, damping: f64) {
-        self.spring = Spring::new(FPS as f64, frequency, damping);
+        self.spring = Spring::new(anim::fps(anim::FPS), frequency, damping);
     }
 
     /// Returns the target percentage that the progress bar is animating towards.
@@ -974,6 +931,11 @@ impl Model {
     /// // 3. Process continues until animation completes
     /// ```
     pub fn update(&mut self, msg: Msg) -> std::option::Option<Cmd> {
+        if let Some((width, _)) = crate::widget::resized(&msg, self.resize_margins) {
+            self.width = width as i32;
+            return std::option::Option::None;
+        }
+
         if let Some(frame_msg) = msg.downcast_ref::<FrameMsg>() {
             if frame_msg.id != self.id || frame_msg.tag != self.tag {
                 return std::option::Option::None;
@@ -1048,10 +1010,22 @@ impl Model {
     ///     format!("Download Progress:\n{}\n", progress.view())
     /// }
     /// ```
+    ///
+    /// In accessible mode this is the [`accessible_view`](Self::accessible_view).
     pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
         self.view_as(self.percent_shown)
     }
 
+    /// Renders the progress as plain text, such as `Progress: 45%`. It
+    /// reports the target percentage rather than the animated one, so the
+    /// text only changes when the progress does.
+    pub fn accessible_view(&self) -> String {
+        format!("Progress: {:.0}%", self.percent() * 100.0)
+    }
+
     /// Renders the progress bar with a specific percentage value.
     ///
     /// This method bypasses the animation system and renders the progress bar
@@ -1111,7 +1085,7 @@ impl Model {
     pub fn view_as(&self, percent: f64) -> String {
         let percent_view = self.percentage_view(percent);
         // Use visible width (ignoring ANSI escape sequences and wide chars)
-        let percent_width = lipgloss::width_visible(&percent_view) as i32;
+        let percent_width = text::width(&percent_view) as i32;
         let bar_view = self.bar_view(percent, percent_width);
 
         format!("{}{}", bar_view, percent_view)
@@ -1178,11 +1152,7 @@ impl Model {
 
     /// Internal method to create next frame command
     fn next_frame(&self) -> Cmd {
-        let id = self.id;
-        let tag = self.tag;
-        let duration = Duration::from_nanos(1_000_000_000 / FPS as u64);
-
-        bubbletea_tick(duration, move |_| Box::new(FrameMsg { id, tag }) as Msg)
+        anim::frame(self.id, self.tag)
     }
 
     /// Internal method to render the progress bar
@@ -1257,6 +1227,33 @@ impl Model {
     }
 }
 
+/// Saved progress of a bar; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Progress from 0.0 to 1.0.
+    pub percent: f64,
+}
+
+/// Restoring jumps straight to the saved percentage without animating.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            percent: self.target_percent,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.target_percent = state.percent.clamp(0.0, 1.0);
+        self.percent_shown = self.target_percent;
+        self.velocity = 0.0;
+        // Drop frames still in flight from before
+        self.tag += 1;
+    }
+}
+
 impl BubbleTeaModel for Model {
     fn init() -> (Self, std::option::Option<Cmd>) {
         let model = new(&[]);
@@ -1357,8 +1354,8 @@ mod tests {
         // Test Go's: New(WithSpringOptions(20.0, 0.8))
         let progress = new(&[with_spring_options(20.0, 0.8)]);
         assert!(progress.spring_customized);
-        assert_eq!(progress.spring.frequency, 20.0);
-        assert_eq!(progress.spring.damping, 0.8);
+        assert_eq!(progress.spring.angular_frequency(), 20.0);
+        assert_eq!(progress.spring.damping_ratio(), 0.8);
     }
 
     #[test]
@@ -1449,9 +1446,9 @@ mod tests {
         let mut progress = new(&[]);
         progress.set_spring_options(25.0, 1.5);
 
-        assert_eq!(progress.spring.frequency, 25.0);
-        assert_eq!(progress.spring.damping, 1.5);
-        assert_eq!(progress.spring.fps, FPS as f64);
+        assert_eq!(progress.spring.angular_frequency(), 25.0);
+        assert_eq!(progress.spring.damping_ratio(), 1.5);
+        assert_eq!(progress.spring.delta_time(), anim::fps(anim::FPS));
     }
 
     #[test]
@@ -1568,7 +1565,7 @@ mod tests {
     #[test]
     fn test_spring_animation_physics() {
         // Test that spring physics work correctly
-        let spring = Spring::new(60.0, 10.0, 1.0);
+        let spring = Spring::new(anim::fps(60), 10.0, 1.0);
 
         // Test movement towards target
         let (new_pos, _new_vel) = spring.update(0.0, 0.0, 1.0);
@@ -1588,9 +1585,9 @@ mod tests {
         let view_100 = progress.view_as(1.0); // 100%
 
         // All views should have same total length based on visible characters
-        assert_eq!(lipgloss::width_visible(&view_0), 20);
-        assert_eq!(lipgloss::width_visible(&view_50), 20);
-        assert_eq!(lipgloss::width_visible(&view_100), 20);
+        assert_eq!(text::width(&view_0), 20);
+        assert_eq!(text::width(&view_50), 20);
+        assert_eq!(text::width(&view_100), 20);
 
         // 0% should be all empty, 100% should be all full
         let bar_0 = progress.bar_view(0.0, 0);
@@ -0,0 +1,467 @@
+//! Star rating input.
+//!
+//! A rating shows a row of symbols such as `★★★☆☆` and lets the user pick a
+//! value with the arrow keys or by typing a number. With half steps enabled
+//! the value moves in increments of 0.5 and a half-filled glyph marks the
+//! remainder. The glyphs are configurable, so the same widget can show
+//! hearts, dots or anything else. Changes emit a [`ChangeMsg`]. It
+//! implements [`Component`](crate::Component); blurred ratings ignore input.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `→`/`l`, `←`/`h` | Increase / decrease by one step |
+//! | `0`–`9` | Set the value; pressing the current number again takes off half a step when half steps are enabled |
+//! | `home`, `end` | Set to zero / the maximum |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::rating;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut stars = rating::new(5).with_half_steps(true).with_value(3.5);
+//! assert_eq!(strip_ansi(&stars.view()), "★★★⯪☆");
+//!
+//! stars.set_value(1.0);
+//! assert_eq!(stars.value(), 1.0);
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use crossterm::event::KeyCode;
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted when the value changes through key input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangeMsg {
+    /// The ID of the rating that changed.
+    pub id: i64,
+    /// The new value.
+    pub value: f64,
+}
+
+/// Key bindings for the rating. Number keys are always handled.
+#[derive(Debug, Clone)]
+pub struct RatingKeyMap {
+    /// Increases the value by one step. Default: `→`, `l`.
+    pub increase: key::Binding,
+    /// Decreases the value by one step. Default: `←`, `h`.
+    pub decrease: key::Binding,
+    /// Sets the value to zero. Default: `home`.
+    pub clear: key::Binding,
+    /// Sets the value to the maximum. Default: `end`.
+    pub max: key::Binding,
+}
+
+impl Default for RatingKeyMap {
+    fn default() -> Self {
+        Self {
+            increase: key::new_binding(vec![
+                key::with_keys_str(&["right", "l"]),
+                key::with_help("→/l", "more"),
+            ]),
+            decrease: key::new_binding(vec![
+                key::with_keys_str(&["left", "h"]),
+                key::with_help("←/h", "less"),
+            ]),
+            clear: key::new_binding(vec![
+                key::with_keys_str(&["home"]),
+                key::with_help("home", "none"),
+            ]),
+            max: key::new_binding(vec![
+                key::with_keys_str(&["end"]),
+                key::with_help("end", "all"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for RatingKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.decrease, &self.increase]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.decrease, &self.increase],
+            vec![&self.clear, &self.max],
+        ]
+    }
+}
+
+/// The symbols a rating is drawn with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyphs {
+    /// A filled symbol.
+    pub full: String,
+    /// A half-filled symbol, used with half steps.
+    pub half: String,
+    /// An empty symbol.
+    pub empty: String,
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self {
+            full: "★".to_string(),
+            half: "⯪".to_string(),
+            empty: "☆".to_string(),
+        }
+    }
+}
+
+/// Visual styles for the rating.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Filled and half-filled symbols while focused.
+    pub filled: Style,
+    /// Filled and half-filled symbols while blurred.
+    pub blurred_filled: Style,
+    /// Empty symbols.
+    pub empty: Style,
+    /// Style of the label while focused.
+    pub label: Style,
+    /// Style of the label while blurred.
+    pub blurred_label: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            filled: Style::new().foreground(AdaptiveColor {
+                Light: "#B7791F",
+                Dark: "#FFD700",
+            }),
+            blurred_filled: Style::new().foreground(AdaptiveColor {
+                Light: "#847A85",
+                Dark: "#A49FA5",
+            }),
+            empty: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            label: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            blurred_label: Style::new(),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates rating styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            filled: theme.warning.clone(),
+            blurred_filled: theme.muted.clone(),
+            empty: theme.subtle.clone(),
+            label: theme.selected.clone(),
+            blurred_label: theme.text.clone(),
+        }
+    }
+}
+
+/// A star rating input.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    /// The value in half steps, so that 3.5 stars is 7.
+    halves: usize,
+    max: usize,
+    focus: bool,
+    /// Whether the value moves in steps of 0.5 instead of 1.
+    pub half_steps: bool,
+    /// Text shown before the symbols; empty for none.
+    pub label: String,
+    /// The symbols to draw.
+    pub glyphs: Glyphs,
+    /// Key bindings.
+    pub keymap: RatingKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused rating out of `max` with no value.
+pub fn new(max: usize) -> Model {
+    Model::new(max)
+}
+
+impl Model {
+    /// Creates a focused rating out of `max` with no value.
+    pub fn new(max: usize) -> Self {
+        Self {
+            id: next_id(),
+            halves: 0,
+            max,
+            focus: true,
+            half_steps: false,
+            label: String::new(),
+            glyphs: Glyphs::default(),
+            keymap: RatingKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Enables or disables half steps.
+    pub fn with_half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self.set_value(self.value());
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the label shown before the symbols.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Sets the filled, half-filled and empty symbols.
+    pub fn with_glyphs(mut self, full: &str, half: &str, empty: &str) -> Self {
+        self.glyphs = Glyphs {
+            full: full.to_string(),
+            half: half.to_string(),
+            empty: empty.to_string(),
+        };
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this rating.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the number of symbols.
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> f64 {
+        self.halves as f64 / 2.0
+    }
+
+    /// Sets the value, clamped to `0..=max` and rounded to the nearest step,
+    /// without emitting a message.
+    pub fn set_value(&mut self, value: f64) {
+        let value = value.clamp(0.0, self.max as f64);
+        self.halves = if self.half_steps {
+            (value * 2.0).round() as usize
+        } else {
+            value.round() as usize * 2
+        };
+    }
+
+    fn step(&self) -> usize {
+        if self.half_steps {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Handles key input while focused, returning a [`ChangeMsg`] command
+    /// when the value changes.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let before = self.halves;
+        if self.keymap.increase.matches(key_msg) {
+            self.halves = (self.halves + self.step()).min(self.max * 2);
+        } else if self.keymap.decrease.matches(key_msg) {
+            self.halves = self.halves.saturating_sub(self.step());
+        } else if self.keymap.clear.matches(key_msg) {
+            self.halves = 0;
+        } else if self.keymap.max.matches(key_msg) {
+            self.halves = self.max * 2;
+        } else if let KeyCode::Char(c @ '0'..='9') = key_msg.key {
+            let n = (c as usize - '0' as usize).min(self.max) * 2;
+            // Typing the current number again takes off half a step, so
+            // every half value is reachable from the number keys.
+            self.halves = if self.half_steps && n > 0 && self.halves == n {
+                n - 1
+            } else {
+                n
+            };
+        }
+        if self.halves == before {
+            return None;
+        }
+        let (id, value) = (self.id, self.value());
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ChangeMsg { id, value }) as Msg
+        }))
+    }
+
+    /// Renders the label followed by the symbols.
+    pub fn view(&self) -> String {
+        let mut filled = self.glyphs.full.repeat(self.halves / 2);
+        if self.halves % 2 == 1 {
+            filled.push_str(&self.glyphs.half);
+        }
+        let empty = self.glyphs.empty.repeat(self.max - self.halves.div_ceil(2));
+        let filled_style = if self.focus {
+            &self.styles.filled
+        } else {
+            &self.styles.blurred_filled
+        };
+        let mut symbols = String::new();
+        if !filled.is_empty() {
+            symbols.push_str(&filled_style.render(&filled));
+        }
+        if !empty.is_empty() {
+            symbols.push_str(&self.styles.empty.render(&empty));
+        }
+        if self.label.is_empty() {
+            return symbols;
+        }
+        let label = if self.focus {
+            self.styles.label.render(&self.label)
+        } else {
+            self.styles.blurred_label.render(&self.label)
+        };
+        format!("{}  {}", label, symbols)
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved value of a rating; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The current value.
+    pub value: f64,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            value: self.value(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_value(state.value);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(5), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Component;
+    use crossterm::event::KeyModifiers;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_arrow_keys_step_and_clamp() {
+        let mut r = Model::new(3);
+        assert!(r.update(key(KeyCode::Left)).is_none());
+        for _ in 0..5 {
+            r.update(key(KeyCode::Right));
+        }
+        assert_eq!(r.value(), 3.0);
+        r.half_steps = true;
+        assert!(r.update(key(KeyCode::Left)).is_some());
+        assert_eq!(r.value(), 2.5);
+        assert_eq!(strip_ansi(&r.view()), "★★⯪");
+    }
+
+    #[test]
+    fn test_number_keys() {
+        let mut r = Model::new(5).with_half_steps(true);
+        r.update(key(KeyCode::Char('4')));
+        assert_eq!(r.value(), 4.0);
+        r.update(key(KeyCode::Char('4')));
+        assert_eq!(r.value(), 3.5);
+        r.update(key(KeyCode::Char('9')));
+        assert_eq!(r.value(), 5.0);
+        r.update(key(KeyCode::Char('0')));
+        assert_eq!(r.value(), 0.0);
+    }
+
+    #[test]
+    fn test_custom_glyphs_and_rounding() {
+        let r = Model::new(4).with_glyphs("♥", "♡", "·").with_value(2.4);
+        assert_eq!(r.value(), 2.0);
+        assert_eq!(strip_ansi(&r.view()), "♥♥··");
+    }
+
+    #[test]
+    fn test_blurred_ignores_input() {
+        let mut r = Model::new(5).with_label("Score");
+        r.blur();
+        assert!(r.update(key(KeyCode::Char('3'))).is_none());
+        assert_eq!(r.value(), 0.0);
+        assert_eq!(strip_ansi(&r.view()), "Score  ☆☆☆☆☆");
+    }
+}
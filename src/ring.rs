@@ -0,0 +1,331 @@
+//! Circular progress ring.
+//!
+//! A ring shows progress as an arc that fills clockwise from twelve
+//! o'clock, with the percentage, or any other short text, centered inside.
+//! It fits places where a linear bar doesn't, such as pomodoro timers and
+//! dashboard tiles. The circle is drawn with quadrant block characters
+//! (`▗▄▖`, `▐`, `▌`, …), which gives two dots per cell in each direction so
+//! that even a three-row ring reads as round.
+//!
+//! A ring is `size` rows tall and `2 * size` columns wide, so it is close to
+//! circular in most terminal fonts.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::ring;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut timer = ring::new(5).with_percent(0.4);
+//! assert!(strip_ansi(&timer.view()).contains("40%"));
+//!
+//! timer.set_text("12:30");
+//! assert!(strip_ansi(&timer.view()).contains("12:30"));
+//! ```
+
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::f64::consts::TAU;
+
+/// Quadrant characters indexed by a bitmask of the lit dots: 1 top left,
+/// 2 top right, 4 bottom left, 8 bottom right.
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// Smallest size that leaves room for text inside the ring.
+const MIN_SIZE: usize = 3;
+
+/// Visual styles for the ring.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The completed part of the ring.
+    pub filled: Style,
+    /// The remaining part of the ring.
+    pub track: Style,
+    /// The centered text.
+    pub text: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            filled: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+            track: Style::new().foreground(AdaptiveColor {
+                Light: "#D0D0D0",
+                Dark: "#3C3C3C",
+            }),
+            text: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#1A1A1A",
+                    Dark: "#DDDDDD",
+                })
+                .bold(true),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates ring styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            filled: Style::new().foreground(theme.palette.primary.clone()),
+            track: theme.subtle.clone(),
+            text: theme.title.clone(),
+        }
+    }
+}
+
+/// A circular progress indicator.
+#[derive(Debug, Clone)]
+pub struct Model {
+    percent: f64,
+    text: Option<String>,
+    /// Height in rows; the width is twice this.
+    pub size: usize,
+    /// Thickness of the ring as a fraction of its radius.
+    pub thickness: f64,
+    /// Whether to show the percentage when no text is set.
+    pub show_percentage: bool,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an empty ring `size` rows tall.
+pub fn new(size: usize) -> Model {
+    Model::new(size)
+}
+
+impl Model {
+    /// Creates an empty ring `size` rows tall. Sizes below three are
+    /// raised to three.
+    pub fn new(size: usize) -> Self {
+        Self {
+            percent: 0.0,
+            text: None,
+            size: size.max(MIN_SIZE),
+            thickness: 0.3,
+            show_percentage: true,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the progress, from 0.0 to 1.0.
+    pub fn with_percent(mut self, percent: f64) -> Self {
+        self.set_percent(percent);
+        self
+    }
+
+    /// Sets the text shown in the center instead of the percentage.
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    /// Sets the thickness of the ring as a fraction of its radius.
+    pub fn with_thickness(mut self, thickness: f64) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the progress, from 0.0 to 1.0.
+    pub fn percent(&self) -> f64 {
+        self.percent
+    }
+
+    /// Sets the progress, clamped to 0.0–1.0.
+    pub fn set_percent(&mut self, percent: f64) {
+        self.percent = if percent.is_nan() {
+            0.0
+        } else {
+            percent.clamp(0.0, 1.0)
+        };
+    }
+
+    /// Sets the text shown in the center instead of the percentage, such as
+    /// the time left on a timer.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = Some(text.to_string());
+    }
+
+    /// Goes back to showing the percentage in the center.
+    pub fn clear_text(&mut self) {
+        self.text = None;
+    }
+
+    /// Returns the text drawn in the center, if any.
+    fn center_text(&self) -> Option<String> {
+        match &self.text {
+            Some(text) => Some(text.clone()),
+            None if self.show_percentage => {
+                Some(format!("{}%", (self.percent * 100.0).round() as u32))
+            }
+            None => None,
+        }
+    }
+
+    /// Classifies each dot of the grid as off the ring, on the track or on
+    /// the filled arc. Returns `(filled, track)` bitmasks per cell.
+    fn cells(&self) -> Vec<Vec<(usize, usize)>> {
+        let rows = self.size;
+        let cols = self.size * 2;
+        // Measure in column widths; a row is two columns tall.
+        let (cx, cy) = (cols as f64 / 2.0, rows as f64);
+        let outer = cx;
+        let inner = outer * (1.0 - self.thickness.clamp(0.05, 1.0));
+        let mut grid = vec![vec![(0, 0); cols]; rows];
+        for (r, row) in grid.iter_mut().enumerate() {
+            for (c, cell) in row.iter_mut().enumerate() {
+                for (bit, (dx, dy)) in [(0.25, 0.25), (0.75, 0.25), (0.25, 0.75), (0.75, 0.75)]
+                    .into_iter()
+                    .enumerate()
+                {
+                    let x = c as f64 + dx - cx;
+                    let y = (r as f64 + dy) * 2.0 - cy;
+                    let dist = x.hypot(y);
+                    if dist > outer || dist < inner {
+                        continue;
+                    }
+                    // Clockwise from twelve o'clock.
+                    let angle = x.atan2(-y).rem_euclid(TAU) / TAU;
+                    if angle < self.percent {
+                        cell.0 |= 1 << bit;
+                    } else {
+                        cell.1 |= 1 << bit;
+                    }
+                }
+            }
+        }
+        grid
+    }
+
+    /// Rings are driven by [`set_percent`](Self::set_percent) and ignore
+    /// messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the ring with its text centered.
+    pub fn view(&self) -> String {
+        let s = &self.styles;
+        let grid = self.cells();
+        let cols = self.size * 2;
+        let middle = self.size / 2;
+        let text = self.center_text().map(|t| {
+            let w = text::width(&t).min(cols);
+            let start = (cols - w) / 2;
+            (t, start, start + w)
+        });
+        grid.into_iter()
+            .enumerate()
+            .map(|(r, row)| {
+                let mut out = String::new();
+                let mut c = 0;
+                while c < cols {
+                    if let Some((t, start, end)) = text.as_ref().filter(|_| r == middle) {
+                        if c == *start {
+                            out.push_str(&s.text.render(t));
+                            c = *end;
+                            continue;
+                        }
+                    }
+                    // A cell has a single color, so one holding both parts
+                    // of the ring takes the color of the larger part.
+                    let (filled, track) = row[c];
+                    let glyph = QUADRANTS[filled | track].to_string();
+                    if filled | track == 0 {
+                        out.push(' ');
+                    } else if filled.count_ones() >= track.count_ones() {
+                        out.push_str(&s.filled.render(&glyph));
+                    } else {
+                        out.push_str(&s.track.render(&glyph));
+                    }
+                    c += 1;
+                }
+                out.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(5), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_shape() {
+        let ring = Model::new(5).with_percent(1.0);
+        assert_eq!(
+            strip_ansi(&ring.view()),
+            [
+                " ▗▄████▄▖",
+                "▟█▘    ▝█▙",
+                "█▌ 100% ▐█",
+                "▜█▖    ▗█▛",
+                " ▝▀████▀▘",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_arc_fills_clockwise_from_top() {
+        let ring = Model::new(5).with_percent(0.25);
+        let grid = ring.cells();
+        // The top right is filled and the bottom left is not.
+        assert_ne!(grid[0][6].0, 0);
+        assert_eq!(grid[4][2].0, 0);
+        assert_ne!(grid[4][2].1, 0);
+        let full = Model::new(5).with_percent(1.0).cells();
+        assert!(full.iter().flatten().all(|&(_, track)| track == 0));
+    }
+
+    #[test]
+    fn test_text_and_clamping() {
+        let mut ring = Model::new(1).with_percent(2.0);
+        assert_eq!(ring.size, MIN_SIZE);
+        assert_eq!(ring.percent(), 1.0);
+        ring.set_text("5m");
+        assert!(strip_ansi(&ring.view())
+            .lines()
+            .nth(1)
+            .unwrap()
+            .contains("5m"));
+        ring.clear_text();
+        ring.show_percentage = false;
+        assert!(!strip_ansi(&ring.view()).contains('%'));
+    }
+}
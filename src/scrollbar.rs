@@ -0,0 +1,289 @@
+//! Standalone scrollbar.
+//!
+//! A scrollbar draws a track with a thumb whose size and position reflect
+//! which part of some content is visible. It knows nothing about the content
+//! itself: give it the content length, the viewport size and the scroll
+//! offset (all in lines or columns) and render it next to any view, be it a
+//! list, a table, a [`viewport`](crate::viewport) or a custom widget.
+//!
+//! When all of the content fits, no thumb is drawn. Setting `hide_when_fits`
+//! renders blank space instead of the bare track, keeping layouts stable.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::scrollbar::{self, Orientation};
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut bar = scrollbar::new(Orientation::Vertical, 4);
+//! // 20 lines of content, 10 visible, scrolled to the bottom.
+//! bar.set_state(20, 10, 10);
+//! assert_eq!(bar.thumb(), Some((2, 2)));
+//! assert_eq!(strip_ansi(&bar.view()), "│\n│\n┃\n┃");
+//! ```
+//!
+//! Keeping a scrollbar in sync with a viewport:
+//!
+//! ```rust
+//! use bubbletea_rs::Model;
+//! use bubbletea_widgets::{scrollbar, viewport};
+//! use lipgloss_extras::lipgloss;
+//!
+//! let mut vp = viewport::new(20, 5);
+//! vp.set_content(&"line\n".repeat(50));
+//!
+//! let mut bar = scrollbar::new(scrollbar::Orientation::Vertical, 5);
+//! bar.sync_viewport(&vp);
+//! let screen = lipgloss::join_horizontal(lipgloss::TOP, &[&vp.view(), &bar.view()]);
+//! assert_eq!(screen.lines().count(), 5);
+//! ```
+
+use crate::text;
+use crate::theme::Theme;
+use crate::viewport;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+
+/// Direction of the scrollbar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// A column of cells beside the content.
+    #[default]
+    Vertical,
+    /// A row of cells below the content.
+    Horizontal,
+}
+
+/// Visual styles for the scrollbar.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the track.
+    pub track: Style,
+    /// Style of the thumb.
+    pub thumb: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            track: Style::new().foreground(AdaptiveColor {
+                Light: "#D0D0D0",
+                Dark: "#3C3C3C",
+            }),
+            thumb: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates scrollbar styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            track: theme.subtle.clone(),
+            thumb: Style::new().foreground(theme.palette.secondary.clone()),
+        }
+    }
+}
+
+/// A vertical or horizontal scrollbar.
+#[derive(Debug, Clone)]
+pub struct Model {
+    content: usize,
+    visible: usize,
+    offset: usize,
+    /// Direction of the scrollbar.
+    pub orientation: Orientation,
+    /// Number of cells in the track.
+    pub length: usize,
+    /// Character drawn for the track.
+    pub track_char: char,
+    /// Character drawn for the thumb.
+    pub thumb_char: char,
+    /// Whether to draw blank cells instead of the track when everything
+    /// fits.
+    pub hide_when_fits: bool,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a scrollbar with a track `length` cells long.
+pub fn new(orientation: Orientation, length: usize) -> Model {
+    Model::new(orientation, length)
+}
+
+impl Model {
+    /// Creates a scrollbar with a track `length` cells long.
+    pub fn new(orientation: Orientation, length: usize) -> Self {
+        let (track_char, thumb_char) = match orientation {
+            Orientation::Vertical => ('│', '┃'),
+            Orientation::Horizontal => ('─', '━'),
+        };
+        Self {
+            content: 0,
+            visible: 0,
+            offset: 0,
+            orientation,
+            length,
+            track_char,
+            thumb_char,
+            hide_when_fits: false,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the track and thumb characters.
+    pub fn with_chars(mut self, track: char, thumb: char) -> Self {
+        self.track_char = track;
+        self.thumb_char = thumb;
+        self
+    }
+
+    /// Sets whether to draw blank cells when everything fits.
+    pub fn with_hide_when_fits(mut self, hide: bool) -> Self {
+        self.hide_when_fits = hide;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets the content length, the number of visible lines or columns, and
+    /// the scroll offset. The offset is clamped to the scrollable range.
+    pub fn set_state(&mut self, content: usize, visible: usize, offset: usize) {
+        self.content = content;
+        self.visible = visible;
+        self.offset = offset.min(content.saturating_sub(visible));
+    }
+
+    /// Sets the state from a viewport's vertical or horizontal scroll
+    /// position, depending on the orientation.
+    pub fn sync_viewport(&mut self, vp: &viewport::Model) {
+        match self.orientation {
+            Orientation::Vertical => {
+                let frame = vp.style.get_vertical_frame_size() as usize;
+                self.set_state(
+                    vp.line_count(),
+                    vp.height.saturating_sub(frame),
+                    vp.y_offset,
+                );
+            }
+            Orientation::Horizontal => {
+                let frame = vp.style.get_horizontal_frame_size() as usize;
+                let longest = vp.lines().iter().map(|l| text::width(l)).max().unwrap_or(0);
+                self.set_state(longest, vp.width.saturating_sub(frame), vp.x_offset);
+            }
+        }
+    }
+
+    /// Returns whether all of the content is visible.
+    pub fn fits(&self) -> bool {
+        self.content <= self.visible
+    }
+
+    /// Returns the start and length of the thumb in cells, or `None` when
+    /// everything fits.
+    pub fn thumb(&self) -> Option<(usize, usize)> {
+        if self.fits() || self.length == 0 {
+            return None;
+        }
+        let len = ((self.length * self.visible) as f64 / self.content as f64)
+            .round()
+            .clamp(1.0, self.length as f64) as usize;
+        let max_offset = self.content - self.visible;
+        let start = ((self.length - len) * self.offset) as f64 / max_offset as f64;
+        Some((start.round() as usize, len))
+    }
+
+    /// Scrollbars are driven by [`set_state`](Self::set_state) and ignore
+    /// messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the scrollbar as a column or a row of cells.
+    pub fn view(&self) -> String {
+        let cells: Vec<String> = match self.thumb() {
+            None if self.hide_when_fits => vec![" ".to_string(); self.length],
+            None => vec![self.styles.track.render(&self.track_char.to_string()); self.length],
+            Some((start, len)) => (0..self.length)
+                .map(|i| {
+                    if (start..start + len).contains(&i) {
+                        self.styles.thumb.render(&self.thumb_char.to_string())
+                    } else {
+                        self.styles.track.render(&self.track_char.to_string())
+                    }
+                })
+                .collect(),
+        };
+        match self.orientation {
+            Orientation::Vertical => cells.join("\n"),
+            Orientation::Horizontal => cells.concat(),
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Orientation::Vertical, 10), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_thumb_size_and_position() {
+        let mut bar = Model::new(Orientation::Vertical, 10);
+        bar.set_state(100, 10, 0);
+        assert_eq!(bar.thumb(), Some((0, 1)));
+        bar.set_state(100, 10, 45);
+        assert_eq!(bar.thumb(), Some((5, 1)));
+        bar.set_state(100, 10, 500);
+        assert_eq!(bar.thumb(), Some((9, 1)));
+        bar.set_state(20, 10, 5);
+        assert_eq!(bar.thumb(), Some((3, 5)));
+    }
+
+    #[test]
+    fn test_fitting_content_has_no_thumb() {
+        let mut bar = Model::new(Orientation::Horizontal, 3);
+        bar.set_state(5, 10, 0);
+        assert_eq!(bar.thumb(), None);
+        assert_eq!(strip_ansi(&bar.view()), "───");
+        bar.hide_when_fits = true;
+        assert_eq!(bar.view(), "   ");
+    }
+
+    #[test]
+    fn test_sync_viewport() {
+        let mut vp = viewport::new(10, 4);
+        vp.set_content(&"x\n".repeat(19));
+        vp.set_y_offset(100);
+        let mut bar = Model::new(Orientation::Vertical, 4);
+        bar.sync_viewport(&vp);
+        let (start, len) = bar.thumb().unwrap();
+        assert_eq!(start + len, 4);
+    }
+}
@@ -0,0 +1,590 @@
+//! `/`-style search across widgets.
+//!
+//! The [`Model`] is a one-line search bar built on a
+//! [`textinput`](crate::textinput). It does not own what it searches;
+//! instead, [`Model::update`] receives the target as a [`Searchable`] and
+//! asks it for the [`Hit`]s of the query and to move to one of them. The
+//! viewport, list, table and textarea implement [`Searchable`], so every
+//! widget gets the same keys: `/` to type a query, `enter` to run it, `n` and
+//! `N` to step through the hits and `esc` to clear them.
+//!
+//! Matching is case-insensitive. [`find_all`] does the matching for a single
+//! string and is what the built-in implementations use; custom widgets can
+//! use it to implement [`Searchable`] too.
+//!
+//! While a query is being typed the bar takes every key, so route messages
+//! with [`Model::handles`] the same way as for a
+//! [`FocusRing`](crate::focus::FocusRing).
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `/` | Start typing a query |
+//! | `enter` / `esc` | Run / abandon the query being typed |
+//! | `n` / `N` | Next / previous hit, wrapping around |
+//! | `esc` | Clear the active search |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::{search, viewport};
+//!
+//! let mut pager = viewport::new(40, 2);
+//! pager.set_content("alpha\nbeta\ngamma\ndelta\nalpha again");
+//!
+//! let mut bar = search::new();
+//! assert_eq!(bar.search("alpha", &mut pager), 2);
+//! bar.next(&mut pager);
+//! assert_eq!(bar.current().map(|hit| hit.row), Some(4));
+//! assert_eq!(pager.y_offset, 3);
+//! ```
+
+use crate::cmd_util;
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{batch, Cmd, KeyMsg, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// One occurrence of a query in a [`Searchable`] widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+    /// The line, item or row containing the hit.
+    pub row: usize,
+    /// The table column containing the hit; 0 for widgets with one text
+    /// per row.
+    pub cell: usize,
+    /// Character offset where the hit starts.
+    pub start: usize,
+    /// Character offset just past the hit.
+    pub end: usize,
+}
+
+/// A widget whose content can be searched with the search bar.
+pub trait Searchable {
+    /// Returns every occurrence of `query`, in reading order. An empty query
+    /// has no hits.
+    fn matches(&self, query: &str) -> Vec<Hit>;
+
+    /// Moves the widget so `hit` is visible and, where the widget has a
+    /// cursor or selection, on it.
+    fn goto(&mut self, hit: Hit);
+}
+
+/// Returns the character ranges of every case-insensitive, non-overlapping
+/// occurrence of `query` in `text`.
+pub fn find_all(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let hay: Vec<char> = text.chars().map(fold).collect();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= hay.len() {
+        if hay[i..i + needle.len()] == needle[..] {
+            found.push((i, i + needle.len()));
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    found
+}
+
+/// Returns the hits of `query` in `text`, reported at `row` and `cell`.
+#[cfg(any(
+    test,
+    feature = "list",
+    feature = "table",
+    feature = "textarea",
+    feature = "viewport"
+))]
+pub(crate) fn hits_in(text: &str, query: &str, row: usize, cell: usize) -> Vec<Hit> {
+    find_all(text, query)
+        .into_iter()
+        .map(|(start, end)| Hit {
+            row,
+            cell,
+            start,
+            end,
+        })
+        .collect()
+}
+
+/// Key bindings for the search bar.
+#[derive(Debug, Clone)]
+pub struct SearchKeyMap {
+    /// Starts typing a query. Default: `/`.
+    pub start: key::Binding,
+    /// Runs the query being typed. Default: `enter`.
+    pub accept: key::Binding,
+    /// Abandons typing or clears the active search. Default: `esc`.
+    pub cancel: key::Binding,
+    /// Jumps to the next hit. Default: `n`.
+    pub next: key::Binding,
+    /// Jumps to the previous hit. Default: `N`.
+    pub prev: key::Binding,
+}
+
+impl Default for SearchKeyMap {
+    fn default() -> Self {
+        Self {
+            start: key::new_binding(vec![
+                key::with_keys_str(&["/"]),
+                key::with_help("/", "search"),
+            ]),
+            accept: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "search"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "clear search"),
+            ]),
+            next: key::new_binding(vec![
+                key::with_keys_str(&["n"]),
+                key::with_help("n", "next match"),
+            ]),
+            prev: key::new_binding(vec![
+                key::with_keys_str(&["N"]),
+                key::with_help("N", "previous match"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for SearchKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.start, &self.next, &self.prev]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.start, &self.accept, &self.cancel],
+            vec![&self.next, &self.prev],
+        ]
+    }
+}
+
+/// Visual styles for the search bar.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The query shown after it has been run.
+    pub query: Style,
+    /// The `current/total` hit counter.
+    pub count: Style,
+    /// The notice shown when the query has no hits.
+    pub no_match: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            query: Style::new().foreground(AdaptiveColor {
+                Light: "#1A1A1A",
+                Dark: "#DDDDDD",
+            }),
+            count: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            no_match: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates search bar styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            query: theme.text.clone(),
+            count: theme.muted.clone(),
+            no_match: theme.error.clone(),
+        }
+    }
+}
+
+/// Runs the incremental search once typing has paused; sent when a
+/// [debounce](Model::with_debounce) is set.
+#[derive(Debug, Clone)]
+pub struct QueryMsg {
+    id: i64,
+}
+
+/// A search bar driving a [`Searchable`] widget.
+pub struct Model {
+    id: i64,
+    input: textinput::Model,
+    typing: bool,
+    query: String,
+    hits: Vec<Hit>,
+    current: usize,
+    /// Whether hits are updated while the query is typed.
+    pub incremental: bool,
+    /// How long typing must pause before an incremental search runs. Zero,
+    /// the default, searches on every keystroke.
+    pub debounce: Duration,
+    /// Key bindings.
+    pub keymap: SearchKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an idle search bar.
+pub fn new() -> Model {
+    Model::new()
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model {
+    /// Creates an idle search bar that searches as the query is typed.
+    pub fn new() -> Self {
+        let mut input = textinput::new();
+        input.prompt = "/".to_string();
+        Self {
+            id: next_id(),
+            input,
+            typing: false,
+            query: String::new(),
+            hits: Vec::new(),
+            current: 0,
+            incremental: true,
+            debounce: Duration::ZERO,
+            keymap: SearchKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets whether hits are updated while the query is typed.
+    pub fn with_incremental(mut self, incremental: bool) -> Self {
+        self.incremental = incremental;
+        self
+    }
+
+    /// Waits until typing has paused for `delay` before running an
+    /// incremental search, for targets that are slow to search.
+    ///
+    /// The search then arrives as a [`QueryMsg`] from the returned command;
+    /// pass it back to [`Model::update`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::search;
+    /// use std::time::Duration;
+    ///
+    /// let bar = search::new().with_debounce(Duration::from_millis(150));
+    /// assert_eq!(bar.debounce, Duration::from_millis(150));
+    /// ```
+    pub fn with_debounce(mut self, delay: Duration) -> Self {
+        self.debounce = delay;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns whether a query is being typed.
+    pub fn typing(&self) -> bool {
+        self.typing
+    }
+
+    /// Returns the active query.
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the hits of the active query.
+    pub fn hits(&self) -> &[Hit] {
+        &self.hits
+    }
+
+    /// Returns the hit the target was last moved to.
+    pub fn current(&self) -> Option<Hit> {
+        self.hits.get(self.current).copied()
+    }
+
+    /// Searches `target` for `query` and moves it to the first hit.
+    /// Returns the number of hits.
+    pub fn search(&mut self, query: &str, target: &mut dyn Searchable) -> usize {
+        self.query = query.to_string();
+        self.hits = target.matches(query);
+        self.current = 0;
+        if let Some(&hit) = self.hits.first() {
+            target.goto(hit);
+        }
+        self.hits.len()
+    }
+
+    /// Moves `target` to the next hit, wrapping around.
+    pub fn next(&mut self, target: &mut dyn Searchable) {
+        if !self.hits.is_empty() {
+            self.current = (self.current + 1) % self.hits.len();
+            target.goto(self.hits[self.current]);
+        }
+    }
+
+    /// Moves `target` to the previous hit, wrapping around.
+    pub fn prev(&mut self, target: &mut dyn Searchable) {
+        if !self.hits.is_empty() {
+            self.current = (self.current + self.hits.len() - 1) % self.hits.len();
+            target.goto(self.hits[self.current]);
+        }
+    }
+
+    /// Clears the active search.
+    pub fn clear(&mut self) {
+        self.query.clear();
+        self.hits.clear();
+        self.current = 0;
+    }
+
+    /// Returns whether `msg` is for the search bar: everything while a query
+    /// is being typed, otherwise `/`, and `n`, `N` and `esc` while a search
+    /// is active.
+    ///
+    /// Use this to decide whether a message should go to [`Model::update`]
+    /// or to the searched widget.
+    pub fn handles(&self, msg: &Msg) -> bool {
+        if self.typing {
+            return true;
+        }
+        msg.downcast_ref::<KeyMsg>().is_some_and(|k| {
+            self.keymap.start.matches(k)
+                || (!self.query.is_empty()
+                    && (self.keymap.next.matches(k)
+                        || self.keymap.prev.matches(k)
+                        || self.keymap.cancel.matches(k)))
+        })
+    }
+
+    /// Handles search keys and debounced queries, moving `target` to the
+    /// hits.
+    pub fn update(&mut self, msg: Msg, target: &mut dyn Searchable) -> Option<Cmd> {
+        if let Some(due) = msg.downcast_ref::<QueryMsg>() {
+            let query = self.input.value();
+            if due.id == self.id && self.typing && query != self.query {
+                self.search(&query, target);
+            }
+            return None;
+        }
+        let Some(key_msg) = msg.downcast_ref::<KeyMsg>() else {
+            return if self.typing {
+                self.input.update(msg)
+            } else {
+                None
+            };
+        };
+        if self.typing {
+            if self.keymap.accept.matches(key_msg) {
+                self.typing = false;
+                self.input.blur();
+                cmd_util::cancel(&self.debounce_id());
+                let query = self.input.value();
+                self.search(&query, target);
+                return None;
+            }
+            if self.keymap.cancel.matches(key_msg) {
+                self.typing = false;
+                self.input.blur();
+                cmd_util::cancel(&self.debounce_id());
+                self.clear();
+                return None;
+            }
+            let cmd = self.input.update(msg);
+            let query = self.input.value();
+            if self.incremental && query != self.query {
+                if self.debounce.is_zero() {
+                    self.search(&query, target);
+                } else {
+                    let due = cmd_util::debounce(
+                        &self.debounce_id(),
+                        self.debounce,
+                        QueryMsg { id: self.id },
+                    );
+                    return Some(match cmd {
+                        Some(cmd) => batch(vec![cmd, due]),
+                        None => due,
+                    });
+                }
+            }
+            return cmd;
+        }
+        if self.keymap.start.matches(key_msg) {
+            self.typing = true;
+            self.input.reset();
+            return Some(self.input.focus());
+        }
+        if self.query.is_empty() {
+            return None;
+        }
+        if self.keymap.next.matches(key_msg) {
+            self.next(target);
+        } else if self.keymap.prev.matches(key_msg) {
+            self.prev(target);
+        } else if self.keymap.cancel.matches(key_msg) {
+            self.clear();
+        }
+        None
+    }
+
+    fn debounce_id(&self) -> String {
+        format!("search-{}", self.id)
+    }
+
+    /// Renders the prompt while a query is being typed, the query and hit
+    /// count while a search is active, and nothing otherwise.
+    pub fn view(&self) -> String {
+        if self.typing {
+            return self.input.view();
+        }
+        if self.query.is_empty() {
+            return String::new();
+        }
+        let status = if self.hits.is_empty() {
+            self.styles.no_match.render("no matches")
+        } else {
+            self.styles
+                .count
+                .render(&format!("{}/{}", self.current + 1, self.hits.len()))
+        };
+        format!(
+            "{}  {}",
+            self.styles
+                .query
+                .render(&format!("{}{}", self.input.prompt, self.query)),
+            status
+        )
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+        self.input.apply_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[derive(Default)]
+    struct Lines(Vec<&'static str>, Option<Hit>);
+
+    impl Searchable for Lines {
+        fn matches(&self, query: &str) -> Vec<Hit> {
+            self.0
+                .iter()
+                .enumerate()
+                .flat_map(|(row, line)| hits_in(line, query, row, 0))
+                .collect()
+        }
+
+        fn goto(&mut self, hit: Hit) {
+            self.1 = Some(hit);
+        }
+    }
+
+    #[test]
+    fn test_find_all() {
+        assert_eq!(find_all("Foo foo FOO", "foo"), [(0, 3), (4, 7), (8, 11)]);
+        assert_eq!(find_all("aaaa", "aa"), [(0, 2), (2, 4)]);
+        assert_eq!(find_all("héllo HÉ", "hé"), [(0, 2), (6, 8)]);
+        assert!(find_all("abc", "").is_empty());
+    }
+
+    #[test]
+    fn test_typing_searches_incrementally() {
+        let mut target = Lines(vec!["one", "two", "three"], None);
+        let mut bar = Model::new();
+        assert!(bar.handles(&key(KeyCode::Char('/'))));
+        assert!(!bar.handles(&key(KeyCode::Char('n'))));
+        bar.update(key(KeyCode::Char('/')), &mut target);
+        assert!(bar.typing());
+        bar.update(key(KeyCode::Char('t')), &mut target);
+        assert_eq!(bar.hits().len(), 2);
+        bar.update(key(KeyCode::Char('h')), &mut target);
+        assert_eq!(target.1.map(|h| h.row), Some(2));
+        bar.update(key(KeyCode::Enter), &mut target);
+        assert!(!bar.typing());
+        assert_eq!(strip_ansi(&bar.view()), "/th  1/1");
+    }
+
+    #[test]
+    fn test_next_prev_wrap_and_cancel() {
+        let mut target = Lines(vec!["ab", "b", "ba"], None);
+        let mut bar = Model::new();
+        assert_eq!(bar.search("B", &mut target), 3);
+        bar.update(key(KeyCode::Char('N')), &mut target);
+        assert_eq!(
+            target.1,
+            Some(Hit {
+                row: 2,
+                cell: 0,
+                start: 0,
+                end: 1
+            })
+        );
+        bar.update(key(KeyCode::Char('n')), &mut target);
+        assert_eq!(target.1.map(|h| h.row), Some(0));
+        assert!(bar.handles(&key(KeyCode::Esc)));
+        bar.update(key(KeyCode::Esc), &mut target);
+        assert!(bar.hits().is_empty());
+        assert_eq!(bar.view(), "");
+        bar.search("zzz", &mut target);
+        assert!(strip_ansi(&bar.view()).ends_with("no matches"));
+    }
+
+    #[test]
+    fn test_debounced_typing_waits_for_query_msg() {
+        let mut target = Lines(vec!["one", "two", "three"], None);
+        let mut bar = Model::new().with_debounce(Duration::from_millis(100));
+        bar.update(key(KeyCode::Char('/')), &mut target);
+        assert!(bar.update(key(KeyCode::Char('t')), &mut target).is_some());
+        bar.update(key(KeyCode::Char('h')), &mut target);
+        assert!(bar.hits().is_empty(), "searched before the pause");
+
+        let due = || -> Msg { Box::new(QueryMsg { id: bar.id }) };
+        assert!(bar.handles(&due()));
+        bar.update(due(), &mut target);
+        assert_eq!(bar.query(), "th");
+        assert_eq!(target.1.map(|h| h.row), Some(2));
+
+        // Another bar's query is ignored
+        bar.update(key(KeyCode::Char('r')), &mut target);
+        bar.update(Box::new(QueryMsg { id: bar.id + 1 }), &mut target);
+        assert_eq!(bar.query(), "th");
+    }
+}
@@ -0,0 +1,678 @@
+//! Dropdown select.
+//!
+//! A select shows the current choice inline, like a form field, and opens a
+//! popup list when activated. Typing in the popup fuzzy-filters the options;
+//! `enter` picks the highlighted one and closes the popup, `esc` closes it
+//! without changing anything. It takes one line while closed, which makes
+//! it a better fit than a [`RadioGroup`](crate::choice::RadioGroup) for
+//! toolbars and long option lists.
+//!
+//! Options are [`Choice`]s, so they hold typed values and can be disabled.
+//! Picking a different option emits a [`ChangeMsg`].
+//!
+//! The popup is drawn below the field as part of [`Model::view`]. Place it
+//! over other content with [`compose::overlay`](crate::compose::overlay)
+//! when the field is not the last thing on screen.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `enter`, `space` | Open the popup |
+//! | `↑`/`ctrl+p`, `↓`/`ctrl+n` | Move through the matching options |
+//! | any character | Filter the options |
+//! | `enter` | Pick the option and close |
+//! | `esc` | Close without picking |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::choice::Choice;
+//! use bubbletea_widgets::select;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut region = select::new(vec![
+//!     Choice::new("us-east-1", 1),
+//!     Choice::new("eu-west-1", 2),
+//!     Choice::new("ap-south-1", 3).disabled(),
+//! ])
+//! .with_label("Region")
+//! .with_width(12);
+//! assert_eq!(strip_ansi(&region.view()), "Region Select…      ▾");
+//!
+//! region.select(1);
+//! assert_eq!(region.selected(), Some(&2));
+//! assert_eq!(strip_ansi(&region.view()), "Region eu-west-1    ▾");
+//! ```
+
+use crate::choice::Choice;
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text::{self, Align};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted when a different option is picked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeMsg {
+    /// The ID of the select that changed.
+    pub id: i64,
+    /// Index of the picked option.
+    pub index: usize,
+}
+
+/// Key bindings for the select.
+#[derive(Debug, Clone)]
+pub struct SelectKeyMap {
+    /// Opens the popup. Default: `enter`, `space`.
+    pub open: key::Binding,
+    /// Moves to the previous matching option. Default: `↑`, `ctrl+p`.
+    pub up: key::Binding,
+    /// Moves to the next matching option. Default: `↓`, `ctrl+n`.
+    pub down: key::Binding,
+    /// Picks the highlighted option. Default: `enter`.
+    pub choose: key::Binding,
+    /// Closes the popup. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for SelectKeyMap {
+    fn default() -> Self {
+        Self {
+            open: key::new_binding(vec![
+                key::with_keys_str(&["enter", "space"]),
+                key::with_help("enter", "open"),
+            ]),
+            up: key::new_binding(vec![
+                key::with_keys_str(&["up", "ctrl+p"]),
+                key::with_help("↑", "up"),
+            ]),
+            down: key::new_binding(vec![
+                key::with_keys_str(&["down", "ctrl+n"]),
+                key::with_help("↓", "down"),
+            ]),
+            choose: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "choose"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "close"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for SelectKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.open, &self.up, &self.down, &self.cancel]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.open, &self.choose, &self.cancel],
+            vec![&self.up, &self.down],
+        ]
+    }
+}
+
+/// Visual styles for the select.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The label before the field.
+    pub label: Style,
+    /// The current choice.
+    pub value: Style,
+    /// The placeholder shown when nothing is picked.
+    pub placeholder: Style,
+    /// The `▾` arrow while focused.
+    pub arrow: Style,
+    /// The popup's border.
+    pub popup: Style,
+    /// Matching options.
+    pub option: Style,
+    /// The highlighted option.
+    pub cursor: Style,
+    /// Disabled options and the no-match text.
+    pub disabled: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            label: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#1A1A1A",
+                    Dark: "#DDDDDD",
+                })
+                .bold(true),
+            value: Style::new().foreground(AdaptiveColor {
+                Light: "#1A1A1A",
+                Dark: "#DDDDDD",
+            }),
+            placeholder: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            arrow: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#EE6FF8",
+            }),
+            popup: Style::new()
+                .border(rounded_border())
+                .border_foreground(AdaptiveColor {
+                    Light: "#909090",
+                    Dark: "#626262",
+                }),
+            option: Style::new(),
+            cursor: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            disabled: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates select styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            label: theme.title.clone(),
+            value: theme.text.clone(),
+            placeholder: theme.muted.clone(),
+            arrow: Style::new().foreground(theme.palette.accent.clone()),
+            popup: Style::new()
+                .border(rounded_border())
+                .border_foreground(theme.palette.subtle.clone()),
+            option: theme.text.clone(),
+            cursor: theme.selected.clone(),
+            disabled: theme.subtle.clone(),
+        }
+    }
+}
+
+/// A single-choice field that expands into a filtered popup list.
+pub struct Model<T> {
+    id: i64,
+    options: Vec<Choice<T>>,
+    selected: Option<usize>,
+    expanded: bool,
+    filter: textinput::Model,
+    matches: Vec<usize>,
+    cursor: usize,
+    offset: usize,
+    focus: bool,
+    /// Text before the field; empty for none.
+    pub label: String,
+    /// Text shown when nothing is picked.
+    pub placeholder: String,
+    /// Width of the field and the popup's rows.
+    pub width: usize,
+    /// Most options the popup shows at once.
+    pub max_height: usize,
+    /// Key bindings.
+    pub keymap: SelectKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a select over `options` with nothing picked.
+pub fn new<T>(options: Vec<Choice<T>>) -> Model<T> {
+    Model::new(options)
+}
+
+impl<T> Model<T> {
+    /// Creates a select over `options` with nothing picked. The select
+    /// starts focused and closed.
+    pub fn new(options: Vec<Choice<T>>) -> Self {
+        let mut filter = textinput::new();
+        filter.prompt = "> ".to_string();
+        filter.set_placeholder("filter");
+        Self {
+            id: next_id(),
+            matches: (0..options.len()).collect(),
+            options,
+            selected: None,
+            expanded: false,
+            filter,
+            cursor: 0,
+            offset: 0,
+            focus: true,
+            label: String::new(),
+            placeholder: "Select…".to_string(),
+            width: 20,
+            max_height: 8,
+            keymap: SelectKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the label.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Sets the placeholder.
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Picks the option at `index`.
+    pub fn with_selected(mut self, index: usize) -> Self {
+        self.select(index);
+        self
+    }
+
+    /// Sets the width of the field.
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets how many options the popup shows at once.
+    pub fn with_max_height(mut self, max_height: usize) -> Self {
+        self.max_height = max_height.max(1);
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this select.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the options.
+    pub fn options(&self) -> &[Choice<T>] {
+        &self.options
+    }
+
+    /// Returns the index of the picked option.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Returns the value of the picked option.
+    pub fn selected(&self) -> Option<&T> {
+        self.selected.map(|i| &self.options[i].value)
+    }
+
+    /// Picks the option at `index`. Returns `false` for disabled or
+    /// out-of-range options.
+    pub fn select(&mut self, index: usize) -> bool {
+        if self.options.get(index).is_none_or(|o| o.disabled) {
+            return false;
+        }
+        self.selected = Some(index);
+        true
+    }
+
+    /// Returns whether the popup is open.
+    pub fn is_open(&self) -> bool {
+        self.expanded
+    }
+
+    /// Opens the popup with an empty filter and the picked option
+    /// highlighted.
+    pub fn open(&mut self) -> Cmd {
+        self.expanded = true;
+        self.filter.reset();
+        self.refilter();
+        self.filter.focus()
+    }
+
+    /// Closes the popup without picking anything.
+    pub fn close(&mut self) {
+        self.expanded = false;
+        self.filter.blur();
+    }
+
+    /// Returns the indices of the options matching the filter, in order.
+    pub fn matches(&self) -> &[usize] {
+        &self.matches
+    }
+
+    fn refilter(&mut self) {
+        let query = self.filter.value();
+        self.matches = if query.is_empty() {
+            (0..self.options.len()).collect()
+        } else {
+            let matcher = SkimMatcherV2::default();
+            (0..self.options.len())
+                .filter(|&i| {
+                    matcher
+                        .fuzzy_match(&self.options[i].label, &query)
+                        .is_some()
+                })
+                .collect()
+        };
+        let enabled = |&(_, &i): &(usize, &usize)| !self.options[i].disabled;
+        self.cursor = self
+            .matches
+            .iter()
+            .enumerate()
+            .find(|&(_, &i)| Some(i) == self.selected)
+            .or_else(|| self.matches.iter().enumerate().find(enabled))
+            .map_or(0, |(pos, _)| pos);
+        self.offset = 0;
+        self.scroll_to_cursor();
+    }
+
+    fn step(&mut self, forward: bool) {
+        let mut pos = self.cursor;
+        loop {
+            pos = match forward {
+                true if pos + 1 < self.matches.len() => pos + 1,
+                false if pos > 0 => pos - 1,
+                _ => return,
+            };
+            if !self.options[self.matches[pos]].disabled {
+                self.cursor = pos;
+                self.scroll_to_cursor();
+                return;
+            }
+        }
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        if self.cursor < self.offset {
+            self.offset = self.cursor;
+        } else if self.cursor >= self.offset + self.max_height {
+            self.offset = self.cursor + 1 - self.max_height;
+        }
+    }
+
+    /// Opens on the open binding; while open, filters, moves and picks.
+    /// Returns a [`ChangeMsg`] command when a different option is picked.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if !self.expanded {
+            return self.keymap.open.matches(key_msg).then(|| self.open());
+        }
+        if self.keymap.cancel.matches(key_msg) {
+            self.close();
+        } else if self.keymap.up.matches(key_msg) {
+            self.step(false);
+        } else if self.keymap.down.matches(key_msg) {
+            self.step(true);
+        } else if self.keymap.choose.matches(key_msg) {
+            let index = *self.matches.get(self.cursor)?;
+            let changed = self.selected != Some(index);
+            if !self.select(index) {
+                return None;
+            }
+            self.close();
+            if changed {
+                let id = self.id;
+                return Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+                    Box::new(ChangeMsg { id, index }) as Msg
+                }));
+            }
+        } else {
+            let before = self.filter.value();
+            let cmd = self.filter.update(msg);
+            if self.filter.value() != before {
+                self.refilter();
+            }
+            return cmd;
+        }
+        None
+    }
+
+    /// Renders the field and, while open, the popup below it. In
+    /// accessible mode this is the [`accessible_view`](Self::accessible_view).
+    pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        let s = &self.styles;
+        let value = match self.selected {
+            Some(i) => s
+                .value
+                .render(&text::fit(&self.options[i].label, self.width, Align::Left)),
+            None => s
+                .placeholder
+                .render(&text::fit(&self.placeholder, self.width, Align::Left)),
+        };
+        let arrow = if self.expanded { "▴" } else { "▾" };
+        let arrow = if self.focus {
+            s.arrow.render(arrow)
+        } else {
+            arrow.to_string()
+        };
+        let mut field = format!("{} {}", value, arrow);
+        let indent = if self.label.is_empty() {
+            0
+        } else {
+            field = format!("{} {}", s.label.render(&self.label), field);
+            text::width(&self.label) + 1
+        };
+        if !self.expanded {
+            return field;
+        }
+
+        let mut rows = vec![self.filter.view()];
+        if self.matches.is_empty() {
+            rows.push(
+                s.disabled
+                    .render(&text::fit("no matches", self.width, Align::Left)),
+            );
+        }
+        let end = (self.offset + self.max_height).min(self.matches.len());
+        for pos in self.offset..end {
+            let option = &self.options[self.matches[pos]];
+            let label = text::fit(&option.label, self.width.saturating_sub(2), Align::Left);
+            rows.push(if option.disabled {
+                s.disabled.render(&format!("  {}", label))
+            } else if pos == self.cursor {
+                s.cursor.render(&format!("> {}", label))
+            } else {
+                s.option.render(&format!("  {}", label))
+            });
+        }
+        let inner = self.width + 2;
+        let rows: Vec<String> = rows
+            .into_iter()
+            .map(|row| text::pad(&row, inner, Align::Left))
+            .collect();
+        let popup = s.popup.render(&rows.join("\n"));
+        let pad = " ".repeat(indent);
+        let mut lines = vec![field];
+        lines.extend(popup.lines().map(|l| format!("{}{}", pad, l)));
+        lines.join("\n")
+    }
+
+    /// Renders the select as plain text: the label and picked option and,
+    /// while open, the filter, the visible matches and which one has the
+    /// cursor.
+    pub fn accessible_view(&self) -> String {
+        let value = match self.selected {
+            Some(i) => self.options[i].label.clone(),
+            None => "nothing picked".to_string(),
+        };
+        let mut lines = vec![if self.label.is_empty() {
+            value
+        } else {
+            format!("{}: {}", self.label, value)
+        }];
+        if !self.expanded {
+            return lines.join("\n");
+        }
+        lines.push(format!("Filter: {}", self.filter.value()));
+        let end = (self.offset + self.max_height).min(self.matches.len());
+        for &i in &self.matches[self.offset..end] {
+            let option = &self.options[i];
+            lines.push(if option.disabled {
+                format!("{}, unavailable", option.label)
+            } else {
+                option.label.clone()
+            });
+        }
+        let label = self
+            .matches
+            .get(self.cursor)
+            .map_or("", |&i| self.options[i].label.as_str());
+        lines.push(crate::a11y::selected(
+            "option",
+            self.cursor,
+            self.matches.len(),
+            label,
+        ));
+        lines.join("\n")
+    }
+}
+
+impl<T> crate::Component for Model<T> {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+        self.close();
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+impl<T> crate::theme::Themeable for Model<T> {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+/// Saved pick of a select; see [`Stateful`](crate::state::Stateful). The
+/// popup always comes back closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Index of the picked option.
+    pub selected: Option<usize>,
+}
+
+impl<T> crate::state::Stateful for Model<T> {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            selected: self.selected,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.selected = None;
+        if let Some(index) = state.selected {
+            self.select(index);
+        }
+    }
+}
+
+impl<T: Send + 'static> BubbleTeaModel for Model<T> {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(Vec::new()), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn press(m: &mut Model<&'static str>, code: KeyCode) -> Option<Cmd> {
+        m.update(Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        }))
+    }
+
+    fn sample() -> Model<&'static str> {
+        Model::new(vec![
+            Choice::new("Apple", "apple"),
+            Choice::new("Banana", "banana"),
+            Choice::new("Blueberry", "blueberry").disabled(),
+            Choice::new("Cherry", "cherry"),
+        ])
+        .with_width(10)
+    }
+
+    #[test]
+    fn test_filter_and_pick() {
+        let mut m = sample();
+        assert!(press(&mut m, KeyCode::Enter).is_some());
+        assert!(m.is_open());
+        press(&mut m, KeyCode::Char('e'));
+        assert_eq!(m.matches(), [0, 2, 3]);
+        // The disabled blueberry is skipped.
+        press(&mut m, KeyCode::Down);
+        assert!(press(&mut m, KeyCode::Enter).is_some());
+        assert!(!m.is_open());
+        assert_eq!(m.selected(), Some(&"cherry"));
+
+        // Picking the same option again emits nothing.
+        press(&mut m, KeyCode::Enter);
+        assert_eq!(m.matches(), [0, 1, 2, 3]);
+        assert!(press(&mut m, KeyCode::Enter).is_none());
+        assert_eq!(m.selected_index(), Some(3));
+    }
+
+    #[test]
+    fn test_escape_keeps_selection() {
+        let mut m = sample().with_selected(0);
+        assert!(!m.select(2));
+        press(&mut m, KeyCode::Char(' '));
+        press(&mut m, KeyCode::Down);
+        press(&mut m, KeyCode::Esc);
+        assert!(!m.is_open());
+        assert_eq!(m.selected(), Some(&"apple"));
+    }
+
+    #[test]
+    fn test_popup_layout() {
+        let mut m = sample().with_label("Fruit").with_max_height(2);
+        press(&mut m, KeyCode::Enter);
+        let view = strip_ansi(&m.view());
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[0], "Fruit Select…    ▴");
+        assert_eq!(lines[3], "      │> Apple     │");
+        assert_eq!(lines[4], "      │  Banana    │");
+    }
+}
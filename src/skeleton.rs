@@ -0,0 +1,377 @@
+//! Animated loading placeholders.
+//!
+//! A skeleton stands in for content that is still loading. It is described by
+//! a list of [`Block`]s (lines, paragraphs, cards and table rows) which are
+//! drawn as muted bars, with a brighter shimmer band sweeping across them on
+//! every tick. Swap the skeleton for the real view once the data arrives.
+//!
+//! The animation follows the same pattern as the spinner: start it with the
+//! command returned by [`Model::tick`] and forward [`TickMsg`]s to
+//! [`Model::update`], which schedules the next frame.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::skeleton::{self, Block};
+//!
+//! let loading = skeleton::new(30, vec![
+//!     Block::Line(12),
+//!     Block::Gap,
+//!     Block::Paragraph(3),
+//!     Block::Table { rows: 2, columns: 3 },
+//! ]);
+//! let _start = loading.tick();
+//! assert_eq!(loading.view().lines().count(), 7);
+//! ```
+
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Character used for placeholder bars.
+const FILL: char = '█';
+
+/// A piece of the placeholder layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Block {
+    /// A single bar of the given width, capped at the skeleton width.
+    Line(usize),
+    /// Several lines of nearly full width, the last one shorter, like a
+    /// paragraph.
+    Paragraph(usize),
+    /// A bordered card `height` rows tall with a title bar and body lines.
+    Card {
+        /// Height including the border.
+        height: usize,
+    },
+    /// Rows of evenly spaced cells, like a table.
+    Table {
+        /// Number of rows.
+        rows: usize,
+        /// Number of cells per row.
+        columns: usize,
+    },
+    /// An empty line.
+    Gap,
+}
+
+/// Message advancing the shimmer animation.
+#[derive(Debug, Clone, Copy)]
+pub struct TickMsg {
+    /// The ID of the skeleton this tick belongs to.
+    pub id: i64,
+    tag: i64,
+}
+
+/// Visual styles for the skeleton.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the placeholder bars and card borders.
+    pub base: Style,
+    /// Style of the bars under the shimmer band.
+    pub shimmer: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            base: Style::new().foreground(AdaptiveColor {
+                Light: "#D0D0D0",
+                Dark: "#3A3A3A",
+            }),
+            shimmer: Style::new().foreground(AdaptiveColor {
+                Light: "#9B9B9B",
+                Dark: "#5C5C5C",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates skeleton styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            base: theme.subtle.clone(),
+            shimmer: theme.muted.clone(),
+        }
+    }
+}
+
+/// An animated loading placeholder.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    tag: i64,
+    frame: usize,
+    /// The layout to draw.
+    pub blocks: Vec<Block>,
+    /// Width in columns.
+    pub width: usize,
+    /// Width of the shimmer band in columns.
+    pub band: usize,
+    /// Time between animation frames.
+    pub interval: Duration,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a skeleton `width` columns wide drawing `blocks`.
+pub fn new(width: usize, blocks: Vec<Block>) -> Model {
+    Model::new(width, blocks)
+}
+
+impl Model {
+    /// Creates a skeleton `width` columns wide drawing `blocks`.
+    pub fn new(width: usize, blocks: Vec<Block>) -> Self {
+        Self {
+            id: next_id(),
+            tag: 0,
+            frame: 0,
+            blocks,
+            width,
+            band: 6,
+            interval: Duration::from_millis(60),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the width of the shimmer band.
+    pub fn with_band(mut self, band: usize) -> Self {
+        self.band = band;
+        self
+    }
+
+    /// Sets the time between animation frames.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this skeleton.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the current animation frame.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns a command that delivers the next [`TickMsg`]. Return it from
+    /// `init` to start the animation.
+    pub fn tick(&self) -> Cmd {
+        let (id, tag) = (self.id, self.tag);
+        bubbletea_tick(self.interval, move |_| Box::new(TickMsg { id, tag }) as Msg)
+    }
+
+    /// Advances the shimmer on this skeleton's [`TickMsg`]s and schedules
+    /// the next frame.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        let tick = msg.downcast_ref::<TickMsg>()?;
+        if tick.id != self.id || tick.tag != self.tag {
+            return None;
+        }
+        self.tag += 1;
+        self.frame = (self.frame + 1) % (self.width + self.band * 2).max(1);
+        Some(self.tick())
+    }
+
+    /// Returns the columns currently under the shimmer band.
+    fn shimmer(&self) -> std::ops::Range<usize> {
+        let end = self.frame;
+        end.saturating_sub(self.band)..end
+    }
+
+    /// Lays out the blocks as rows of characters.
+    fn rows(&self) -> Vec<Vec<char>> {
+        let w = self.width;
+        let bar = |len: usize| -> Vec<char> {
+            let mut row = vec![FILL; len.min(w)];
+            row.resize(w, ' ');
+            row
+        };
+        let pct = |p: usize| (w * p / 100).max(1);
+        let mut rows = Vec::new();
+        for block in &self.blocks {
+            match *block {
+                Block::Line(len) => rows.push(bar(len)),
+                Block::Gap => rows.push(vec![' '; w]),
+                Block::Paragraph(lines) => {
+                    for i in 0..lines {
+                        let p = if i + 1 == lines && lines > 1 {
+                            60
+                        } else {
+                            [100, 94, 97][i % 3]
+                        };
+                        rows.push(bar(pct(p)));
+                    }
+                }
+                Block::Table { rows: n, columns } => {
+                    let columns = columns.max(1);
+                    let gap = 2;
+                    let cell = w.saturating_sub(gap * (columns - 1)) / columns;
+                    for r in 0..n {
+                        let mut row = Vec::with_capacity(w);
+                        for c in 0..columns {
+                            if c > 0 {
+                                row.extend(std::iter::repeat_n(' ', gap));
+                            }
+                            let fill = (cell * [80, 60, 90][(r + c) % 3] / 100).max(1).min(cell);
+                            row.extend(std::iter::repeat_n(FILL, fill));
+                            row.extend(std::iter::repeat_n(' ', cell - fill));
+                        }
+                        row.resize(w, ' ');
+                        rows.push(row);
+                    }
+                }
+                Block::Card { height } => {
+                    if w < 4 || height < 2 {
+                        rows.extend((0..height).map(|_| bar(w)));
+                        continue;
+                    }
+                    let inner = w - 4;
+                    let horizontal = |l: char, r: char| {
+                        let mut row = vec![l];
+                        row.extend(std::iter::repeat_n('─', w - 2));
+                        row.push(r);
+                        row
+                    };
+                    rows.push(horizontal('╭', '╮'));
+                    for i in 0..height - 2 {
+                        let len = if i == 0 { inner * 40 / 100 } else { inner };
+                        let mut row = vec!['│', ' '];
+                        row.extend(std::iter::repeat_n(FILL, len.max(1)));
+                        row.resize(w - 1, ' ');
+                        row.push('│');
+                        rows.push(row);
+                    }
+                    rows.push(horizontal('╰', '╯'));
+                }
+            }
+        }
+        rows
+    }
+
+    /// Renders the placeholder with the shimmer band at its current
+    /// position.
+    pub fn view(&self) -> String {
+        let shimmer = self.shimmer();
+        self.rows()
+            .into_iter()
+            .map(|row| {
+                let mut out = String::new();
+                let mut run = String::new();
+                let mut run_lit = false;
+                for (col, ch) in row.into_iter().enumerate() {
+                    let lit = ch == FILL && shimmer.contains(&col);
+                    if lit != run_lit && !run.is_empty() {
+                        out.push_str(&self.render_run(&run, run_lit));
+                        run.clear();
+                    }
+                    run_lit = lit;
+                    run.push(ch);
+                }
+                out.push_str(&self.render_run(&run, run_lit));
+                out.trim_end().to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn render_run(&self, run: &str, lit: bool) -> String {
+        if run.trim().is_empty() {
+            run.to_string()
+        } else if lit {
+            self.styles.shimmer.render(run)
+        } else {
+            self.styles.base.render(run)
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        let model = Self::new(40, vec![Block::Paragraph(3)]);
+        let cmd = model.tick();
+        (model, Some(cmd))
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_layout_from_spec() {
+        let s = Model::new(
+            10,
+            vec![
+                Block::Line(4),
+                Block::Gap,
+                Block::Paragraph(2),
+                Block::Table {
+                    rows: 1,
+                    columns: 2,
+                },
+            ],
+        );
+        assert_eq!(
+            strip_ansi(&s.view()),
+            ["████", "", "██████████", "██████", "███   ██"].join("\n")
+        );
+    }
+
+    #[test]
+    fn test_card_has_border() {
+        let s = Model::new(8, vec![Block::Card { height: 4 }]);
+        assert_eq!(
+            strip_ansi(&s.view()),
+            ["╭──────╮", "│ █    │", "│ ████ │", "╰──────╯"].join("\n")
+        );
+    }
+
+    #[test]
+    fn test_ticks_advance_and_stale_ticks_are_ignored() {
+        let mut s = Model::new(4, vec![Block::Line(4)]).with_band(2);
+        let first = TickMsg { id: s.id(), tag: 0 };
+        assert!(s.update(Box::new(first)).is_some());
+        assert_eq!(s.frame(), 1);
+        assert!(s.update(Box::new(first)).is_none());
+        let other = TickMsg {
+            id: s.id() + 1000,
+            tag: 1,
+        };
+        assert!(s.update(Box::new(other)).is_none());
+        assert_eq!(s.frame(), 1);
+        assert_eq!(s.shimmer(), 0..1);
+    }
+}
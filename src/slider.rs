@@ -0,0 +1,589 @@
+//! Slider for picking a number from a range.
+//!
+//! The slider draws a track with a handle, optional tick marks and a value
+//! label, horizontally or vertically. Values snap to `step` and every change
+//! emits a [`ChangeMsg`], so a parent can react immediately (for example to
+//! adjust a volume or a threshold).
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `→`/`l`, `↑`/`k` | Increase by one step |
+//! | `←`/`h`, `↓`/`j` | Decrease by one step |
+//! | `pgup` / `pgdown` | Increase / decrease by one page step |
+//! | `home` / `end` | Jump to the minimum / maximum |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::slider;
+//!
+//! let mut volume = slider::new(0.0, 100.0)
+//!     .with_step(5.0)
+//!     .with_value(40.0)
+//!     .with_width(21)
+//!     .with_ticks(25.0);
+//!
+//! volume.increment();
+//! assert_eq!(volume.value(), 45.0);
+//! assert!(volume.view().contains("45"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Direction in which the track is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Minimum on the left, maximum on the right.
+    Horizontal,
+    /// Minimum at the bottom, maximum at the top.
+    Vertical,
+}
+
+/// Message emitted whenever the value changes through [`Model::update`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangeMsg {
+    /// The ID of the slider that changed.
+    pub id: i64,
+    /// The new value.
+    pub value: f64,
+}
+
+/// Key bindings for the slider.
+#[derive(Debug, Clone)]
+pub struct SliderKeyMap {
+    /// Increases the value by one step. Default: `→`, `l`, `↑`, `k`.
+    pub increase: key::Binding,
+    /// Decreases the value by one step. Default: `←`, `h`, `↓`, `j`.
+    pub decrease: key::Binding,
+    /// Increases the value by one page step. Default: `pgup`.
+    pub page_increase: key::Binding,
+    /// Decreases the value by one page step. Default: `pgdown`.
+    pub page_decrease: key::Binding,
+    /// Jumps to the minimum. Default: `home`.
+    pub min: key::Binding,
+    /// Jumps to the maximum. Default: `end`.
+    pub max: key::Binding,
+}
+
+impl Default for SliderKeyMap {
+    fn default() -> Self {
+        Self {
+            increase: key::new_binding(vec![
+                key::with_keys_str(&["right", "l", "up", "k"]),
+                key::with_help("→", "increase"),
+            ]),
+            decrease: key::new_binding(vec![
+                key::with_keys_str(&["left", "h", "down", "j"]),
+                key::with_help("←", "decrease"),
+            ]),
+            page_increase: key::new_binding(vec![
+                key::with_keys_str(&["pgup"]),
+                key::with_help("pgup", "increase more"),
+            ]),
+            page_decrease: key::new_binding(vec![
+                key::with_keys_str(&["pgdown"]),
+                key::with_help("pgdown", "decrease more"),
+            ]),
+            min: key::new_binding(vec![
+                key::with_keys_str(&["home"]),
+                key::with_help("home", "minimum"),
+            ]),
+            max: key::new_binding(vec![
+                key::with_keys_str(&["end"]),
+                key::with_help("end", "maximum"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for SliderKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.decrease, &self.increase]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.decrease, &self.increase],
+            vec![&self.page_decrease, &self.page_increase],
+            vec![&self.min, &self.max],
+        ]
+    }
+}
+
+/// Visual styles for the slider.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the track between the minimum and the handle.
+    pub filled: Style,
+    /// Style of the track beyond the handle.
+    pub track: Style,
+    /// Style of the handle while focused.
+    pub handle: Style,
+    /// Style of the handle while blurred.
+    pub blurred_handle: Style,
+    /// Style of tick marks.
+    pub tick: Style,
+    /// Style of the value label.
+    pub label: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            filled: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#7571F9",
+            }),
+            track: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#606060",
+            }),
+            handle: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            blurred_handle: Style::new().foreground(AdaptiveColor {
+                Light: "#8E8E8E",
+                Dark: "#A0A0A0",
+            }),
+            tick: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#606060",
+            }),
+            label: Style::new(),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates slider styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            filled: Style::new().foreground(theme.palette.secondary.clone()),
+            track: theme.subtle.clone(),
+            handle: theme.selected.clone(),
+            blurred_handle: theme.muted.clone(),
+            tick: theme.subtle.clone(),
+            label: theme.text.clone(),
+        }
+    }
+}
+
+/// A numeric slider.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    min: f64,
+    max: f64,
+    step: f64,
+    page_step: f64,
+    value: f64,
+    focus: bool,
+    /// Length of the track in cells (columns, or rows when vertical).
+    pub length: usize,
+    /// Direction of the track.
+    pub orientation: Orientation,
+    /// Interval between tick marks, in value units. `None` hides ticks.
+    pub tick_every: Option<f64>,
+    /// Whether to show the value next to the track.
+    pub show_value: bool,
+    /// Number of decimals in the value label.
+    pub decimals: usize,
+    /// Key bindings.
+    pub keymap: SliderKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused horizontal slider over `min..=max` starting at `min`.
+pub fn new(min: f64, max: f64) -> Model {
+    Model::new(min, max)
+}
+
+impl Model {
+    /// Creates a focused horizontal slider over `min..=max` starting at `min`.
+    ///
+    /// The bounds are swapped if given in the wrong order.
+    pub fn new(min: f64, max: f64) -> Self {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        Self {
+            id: next_id(),
+            min,
+            max,
+            step: 1.0,
+            page_step: 10.0,
+            value: min,
+            focus: true,
+            length: 20,
+            orientation: Orientation::Horizontal,
+            tick_every: None,
+            show_value: true,
+            decimals: 0,
+            keymap: SliderKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the step used by the arrow keys; the page step becomes ten steps.
+    pub fn with_step(mut self, step: f64) -> Self {
+        if step > 0.0 {
+            self.step = step;
+            self.page_step = step * 10.0;
+        }
+        self
+    }
+
+    /// Sets the step used by `pgup`/`pgdown`.
+    pub fn with_page_step(mut self, page_step: f64) -> Self {
+        if page_step > 0.0 {
+            self.page_step = page_step;
+        }
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the track length in cells.
+    pub fn with_width(mut self, length: usize) -> Self {
+        self.length = length.max(2);
+        self
+    }
+
+    /// Sets the orientation.
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Shows a tick mark every `interval` value units.
+    pub fn with_ticks(mut self, interval: f64) -> Self {
+        self.tick_every = (interval > 0.0).then_some(interval);
+        self
+    }
+
+    /// Sets the number of decimals shown in the value label.
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this slider.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the `(min, max)` bounds.
+    pub fn range(&self) -> (f64, f64) {
+        (self.min, self.max)
+    }
+
+    /// Returns the value as a fraction of the range, from 0.0 to 1.0.
+    pub fn percent(&self) -> f64 {
+        if self.max > self.min {
+            (self.value - self.min) / (self.max - self.min)
+        } else {
+            0.0
+        }
+    }
+
+    /// Sets the value, clamped to the range and snapped to the step grid.
+    pub fn set_value(&mut self, value: f64) {
+        let snapped = self.min + ((value - self.min) / self.step).round() * self.step;
+        self.value = snapped.clamp(self.min, self.max);
+    }
+
+    /// Increases the value by one step.
+    pub fn increment(&mut self) {
+        self.set_value(self.value + self.step);
+    }
+
+    /// Decreases the value by one step.
+    pub fn decrement(&mut self) {
+        self.set_value(self.value - self.step);
+    }
+
+    fn changed(&self, before: f64) -> Option<Cmd> {
+        if self.value == before {
+            return None;
+        }
+        let (id, value) = (self.id, self.value);
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ChangeMsg { id, value }) as Msg
+        }))
+    }
+
+    /// Handles key input while focused, returning a [`ChangeMsg`] command
+    /// when the value changed.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let before = self.value;
+        if self.keymap.increase.matches(key_msg) {
+            self.increment();
+        } else if self.keymap.decrease.matches(key_msg) {
+            self.decrement();
+        } else if self.keymap.page_increase.matches(key_msg) {
+            self.set_value(self.value + self.page_step);
+        } else if self.keymap.page_decrease.matches(key_msg) {
+            self.set_value(self.value - self.page_step);
+        } else if self.keymap.min.matches(key_msg) {
+            self.value = self.min;
+        } else if self.keymap.max.matches(key_msg) {
+            self.value = self.max;
+        }
+        self.changed(before)
+    }
+
+    fn position_of(&self, value: f64) -> usize {
+        let span = self.max - self.min;
+        if span <= 0.0 {
+            return 0;
+        }
+        (((value - self.min) / span) * self.length.saturating_sub(1) as f64).round() as usize
+    }
+
+    fn tick_positions(&self) -> Vec<usize> {
+        // The field is public, so a step that never advances can get here
+        let Some(every) = self.tick_every.filter(|&every| every > 0.0) else {
+            return Vec::new();
+        };
+        if self.length == 0 {
+            return Vec::new();
+        }
+        let mut positions = Vec::new();
+        let mut v = self.min;
+        while v <= self.max + f64::EPSILON {
+            positions.push(self.position_of(v));
+            v += every;
+        }
+        positions
+    }
+
+    /// Returns the formatted value label.
+    pub fn label(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+
+    /// Renders the slider.
+    pub fn view(&self) -> String {
+        let handle_pos = self.position_of(self.value);
+        let handle_style = if self.focus {
+            &self.styles.handle
+        } else {
+            &self.styles.blurred_handle
+        };
+        let ticks = self.tick_positions();
+        let (filled, empty, tick) = match self.orientation {
+            Orientation::Horizontal => ("━", "─", "╵"),
+            Orientation::Vertical => ("┃", "│", "╴"),
+        };
+        let cells: Vec<String> = (0..self.length)
+            .map(|i| {
+                if i == handle_pos {
+                    handle_style.render("●")
+                } else if i < handle_pos {
+                    self.styles.filled.render(filled)
+                } else {
+                    self.styles.track.render(empty)
+                }
+            })
+            .collect();
+        let label = self.styles.label.render(&self.label());
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                let mut out = cells.concat();
+                if self.show_value {
+                    out = format!("{} {}", out, label);
+                }
+                if !ticks.is_empty() {
+                    let marks: String = (0..self.length)
+                        .map(|i| if ticks.contains(&i) { tick } else { " " })
+                        .collect();
+                    out = format!("{}\n{}", out, self.styles.tick.render(&marks));
+                }
+                out
+            }
+            Orientation::Vertical => {
+                let mut rows: Vec<String> = cells
+                    .into_iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(i, cell)| {
+                        if ticks.contains(&i) {
+                            format!("{}{}", cell, self.styles.tick.render(tick))
+                        } else {
+                            cell
+                        }
+                    })
+                    .collect();
+                if self.show_value {
+                    rows.push(label);
+                }
+                rows.join("\n")
+            }
+        }
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved value of a slider; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The current value.
+    pub value: f64,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            value: self.value(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_value(state.value);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(0.0, 100.0), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Component;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_keys_step_and_clamp() {
+        let mut s = Model::new(0.0, 10.0).with_step(2.0).with_page_step(5.0);
+        assert!(s.update(key(KeyCode::Left)).is_none());
+        assert!(s.update(key(KeyCode::Right)).is_some());
+        assert_eq!(s.value(), 2.0);
+        s.update(key(KeyCode::PageUp));
+        assert_eq!(s.value(), 8.0);
+        s.update(key(KeyCode::PageUp));
+        assert_eq!(s.value(), 10.0);
+        s.update(key(KeyCode::Home));
+        assert_eq!(s.value(), 0.0);
+    }
+
+    #[test]
+    fn test_set_value_snaps_to_step() {
+        let mut s = Model::new(0.0, 1.0).with_step(0.25).with_decimals(2);
+        s.set_value(0.6);
+        assert_eq!(s.value(), 0.5);
+        assert_eq!(s.label(), "0.50");
+        assert_eq!(s.percent(), 0.5);
+    }
+
+    #[test]
+    fn test_blurred_slider_ignores_keys() {
+        let mut s = Model::new(0.0, 10.0);
+        s.blur();
+        assert!(s.update(key(KeyCode::Right)).is_none());
+        assert_eq!(s.value(), 0.0);
+    }
+
+    #[test]
+    fn test_horizontal_and_vertical_views() {
+        let s = Model::new(0.0, 4.0)
+            .with_value(2.0)
+            .with_width(5)
+            .with_ticks(2.0);
+        let plain = lipgloss_extras::lipgloss::strip_ansi(&s.view());
+        assert_eq!(plain, "━━●── 2\n╵ ╵ ╵");
+
+        let v = s.with_orientation(Orientation::Vertical);
+        let plain = lipgloss_extras::lipgloss::strip_ansi(&v.view());
+        assert_eq!(plain, "│╴\n│\n●╴\n┃\n┃╴\n2");
+    }
+
+    #[test]
+    fn test_degenerate_length_and_ticks_render() {
+        let mut s = Model::new(0.0, 4.0).with_value(2.0).with_ticks(1.0);
+        s.length = 0;
+        assert_eq!(lipgloss_extras::lipgloss::strip_ansi(&s.view()), " 2");
+
+        s.length = 5;
+        for every in [0.0, -1.0] {
+            s.tick_every = Some(every);
+            let plain = lipgloss_extras::lipgloss::strip_ansi(&s.view());
+            assert_eq!(plain, "━━●── 2");
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! Compact one-line charts.
+//!
+//! A sparkline draws a series of numbers as block characters (`▁▂▃▄▅▆▇█`),
+//! one column per value, so a dashboard row can show a trend next to a
+//! label. Values are streamed in with [`Model::push`]; once the buffer holds
+//! `capacity` values the oldest is dropped. Only the newest `width` values
+//! are drawn, right-aligned so the latest value is always in the last column.
+//!
+//! The vertical scale is taken from the visible values unless a fixed
+//! minimum or maximum is set, which keeps bars comparable across rows (for
+//! example CPU usage pinned to `0..=100`).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::sparkline;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut cpu = sparkline::new(8).with_range(0.0, 100.0);
+//! for v in [0.0, 25.0, 50.0, 75.0, 100.0] {
+//!     cpu.push(v);
+//! }
+//! assert_eq!(strip_ansi(&cpu.view()), "   ▁▃▅▆█");
+//! ```
+
+use crate::theme::Theme;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::collections::VecDeque;
+
+/// Block characters from lowest to highest.
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Visual styles for the sparkline.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the bars.
+    pub line: Style,
+    /// Style of the label before the bars.
+    pub label: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            line: Style::new().foreground(AdaptiveColor {
+                Light: "#2E8B57",
+                Dark: "#04B575",
+            }),
+            label: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates sparkline styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            line: Style::new().foreground(theme.palette.primary.clone()),
+            label: theme.muted.clone(),
+        }
+    }
+}
+
+/// A one-line chart of a streaming series.
+#[derive(Debug, Clone)]
+pub struct Model {
+    values: VecDeque<f64>,
+    capacity: usize,
+    /// Number of columns used for bars.
+    pub width: usize,
+    /// Fixed lower bound of the scale; `None` uses the smallest visible
+    /// value.
+    pub min: Option<f64>,
+    /// Fixed upper bound of the scale; `None` uses the largest visible
+    /// value.
+    pub max: Option<f64>,
+    /// Text shown before the bars; empty for none.
+    pub label: String,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates an empty sparkline `width` columns wide that keeps `width`
+/// values.
+pub fn new(width: usize) -> Model {
+    Model::new(width)
+}
+
+impl Model {
+    /// Creates an empty sparkline `width` columns wide that keeps `width`
+    /// values.
+    pub fn new(width: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(width),
+            capacity: width,
+            width,
+            min: None,
+            max: None,
+            label: String::new(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets how many values are kept. The oldest values are dropped first.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.set_capacity(capacity);
+        self
+    }
+
+    /// Fixes the lower bound of the scale.
+    pub fn with_min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Fixes the upper bound of the scale.
+    pub fn with_max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Fixes both bounds of the scale.
+    pub fn with_range(self, min: f64, max: f64) -> Self {
+        self.with_min(min).with_max(max)
+    }
+
+    /// Sets the text shown before the bars.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_string();
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns how many values are kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets how many values are kept, dropping the oldest values if the
+    /// buffer is now too long.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.values.len() > capacity {
+            self.values.pop_front();
+        }
+    }
+
+    /// Appends a value, dropping the oldest one when full.
+    pub fn push(&mut self, value: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// Appends several values in order.
+    pub fn extend(&mut self, values: impl IntoIterator<Item = f64>) {
+        for v in values {
+            self.push(v);
+        }
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Returns the stored values, oldest first.
+    pub fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.values.iter().copied()
+    }
+
+    /// Returns the newest value.
+    pub fn last(&self) -> Option<f64> {
+        self.values.back().copied()
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether no values are stored.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the newest `width` values, oldest first.
+    fn visible(&self) -> impl Iterator<Item = f64> + '_ {
+        self.values
+            .iter()
+            .skip(self.values.len().saturating_sub(self.width))
+            .copied()
+    }
+
+    /// Returns the bounds used to scale the visible values.
+    pub fn bounds(&self) -> (f64, f64) {
+        let finite = || self.visible().filter(|v| v.is_finite());
+        let lo = self
+            .min
+            .unwrap_or_else(|| finite().fold(f64::INFINITY, f64::min));
+        let hi = self
+            .max
+            .unwrap_or_else(|| finite().fold(f64::NEG_INFINITY, f64::max));
+        (lo, hi)
+    }
+
+    /// Renders the bars without styling.
+    fn bars(&self) -> String {
+        let (lo, hi) = self.bounds();
+        let span = hi - lo;
+        let top = BLOCKS.len() - 1;
+        let mut out: String = " ".repeat(self.width.saturating_sub(self.values.len()));
+        for v in self.visible() {
+            out.push(if !v.is_finite() {
+                ' '
+            } else if span > 0.0 {
+                let t = ((v - lo) / span).clamp(0.0, 1.0);
+                BLOCKS[(t * top as f64).round() as usize]
+            } else {
+                BLOCKS[top / 2]
+            });
+        }
+        out
+    }
+
+    /// Sparklines are driven by [`push`](Self::push) and ignore messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the label followed by the bars.
+    pub fn view(&self) -> String {
+        let bars = self.styles.line.render(&self.bars());
+        if self.label.is_empty() {
+            bars
+        } else {
+            format!("{} {}", self.styles.label.render(&self.label), bars)
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(20), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let mut s = Model::new(3).with_capacity(4);
+        s.extend([1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(s.values().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0, 5.0]);
+        s.set_capacity(2);
+        assert_eq!(s.values().collect::<Vec<_>>(), vec![4.0, 5.0]);
+        assert_eq!(s.last(), Some(5.0));
+    }
+
+    #[test]
+    fn test_auto_scale_uses_visible_values() {
+        let mut s = Model::new(3).with_capacity(10);
+        s.extend([100.0, 0.0, 5.0, 10.0]);
+        assert_eq!(s.bounds(), (0.0, 10.0));
+        assert_eq!(s.bars(), "▁▅█");
+    }
+
+    #[test]
+    fn test_fixed_range_clamps_and_pads() {
+        let mut s = Model::new(5).with_range(0.0, 10.0);
+        s.extend([-5.0, 20.0, f64::NAN]);
+        assert_eq!(s.bars(), "  ▁█ ");
+    }
+
+    #[test]
+    fn test_flat_series_and_label() {
+        let mut s = Model::new(3).with_label("cpu");
+        s.extend([2.0, 2.0]);
+        assert_eq!(s.bars(), " ▄▄");
+        assert!(s.view().contains("cpu"));
+    }
+}
@@ -655,7 +655,7 @@ impl Model {
     /// # Returns
     ///
     /// Returns a Cmd that schedules the next animation frame update.
-    fn tick(&self) -> Cmd {
+    pub(crate) fn tick(&self) -> Cmd {
         let id = self.id;
         let tag = self.tag;
         let fps = self.spinner.fps;
@@ -747,13 +747,24 @@ impl Model {
     /// let output = spinner.view();
     /// assert_eq!(output, "|"); // First frame of LINE spinner
     /// ```
+    ///
+    /// In accessible mode this is the [`accessible_view`](Self::accessible_view).
     pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
         if self.frame >= self.spinner.frames.len() {
             return "(error)".to_string();
         }
 
         self.style.render(&self.spinner.frames[self.frame])
     }
+
+    /// Renders the spinner as the fixed text `Loading`, since its frames
+    /// mean nothing to a screen reader and would be re-read on every tick.
+    pub fn accessible_view(&self) -> String {
+        "Loading".to_string()
+    }
 }
 
 impl BubbleTeaModel for Model {
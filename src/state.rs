@@ -0,0 +1,58 @@
+//! Saving and restoring what the user did to a component.
+//!
+//! A model mixes configuration (styles, key bindings, sizes) with state the
+//! user changes: the text typed, where the cursor is, how far a view is
+//! scrolled, which entries are selected. [`Stateful`] pulls the latter out
+//! into a plain `State` struct, defined next to each model, and puts it
+//! back later. That is enough to restore a session after a restart or to
+//! compare a model against a snapshot in tests.
+//!
+//! Every `State` is `Clone`, `Debug` and `PartialEq`. With the `serde`
+//! feature they also implement `Serialize` and `Deserialize`, along with the
+//! value types they contain, such as [`form::Value`](crate::form::Value) and
+//! [`grid::Value`](crate::grid::Value).
+//!
+//! Content the application supplies, such as list items, table rows or a
+//! viewport's text, is not part of the state; restore it first, then the
+//! state. [`Stateful::restore`] clamps cursors and offsets to the content
+//! the model has, so a stale state never leaves it out of bounds.
+//! Components that only display data passed in by the application, such as
+//! spinners and charts, have no state of their own, and neither do
+//! short-lived popups such as menus and dialogs.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::state::Stateful;
+//! use bubbletea_widgets::textinput;
+//!
+//! let mut input = textinput::new();
+//! input.set_value("hello world");
+//! input.set_cursor(5);
+//! let saved = input.state();
+//!
+//! let mut restored = textinput::new();
+//! restored.restore(saved.clone());
+//! assert_eq!(restored.value(), "hello world");
+//! assert_eq!(restored.position(), 5);
+//! assert_eq!(restored.state(), saved);
+//! ```
+//!
+//! With the `serde` feature the state can go through any serde format:
+//!
+//! ```rust,ignore
+//! let json = serde_json::to_string(&input.state())?;
+//! input.restore(serde_json::from_str(&json)?);
+//! ```
+
+/// A component whose user-facing state can be saved and restored.
+pub trait Stateful {
+    /// The saved state, usually the module's `State` struct.
+    type State;
+
+    /// Returns the current state.
+    fn state(&self) -> Self::State;
+
+    /// Puts a saved state back, clamped to the model's current content.
+    fn restore(&mut self, state: Self::State);
+}
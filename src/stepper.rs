@@ -0,0 +1,562 @@
+//! Number stepper input.
+//!
+//! A stepper shows a single number between two arrows, `‹ 42 ›`, and changes
+//! it by `step` with the arrow keys. Holding a key down (which the terminal
+//! reports as a stream of repeated presses) accelerates the change, so large
+//! ranges stay quick to traverse. When editing is enabled, `enter` swaps the
+//! number for a [`textinput`](crate::textinput) so a value can be typed
+//! directly.
+//!
+//! Every change emits a [`ChangeMsg`].
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `→`/`l`, `↑`/`k`, `+` | Increment |
+//! | `←`/`h`, `↓`/`j`, `-` | Decrement |
+//! | `enter` | Start editing, or commit the typed value |
+//! | `esc` | Cancel editing |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::stepper;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let mut quantity = stepper::new().with_range(1.0, 10.0).with_value(3.0);
+//! quantity.increment();
+//! assert_eq!(quantity.value(), 4.0);
+//! assert_eq!(strip_ansi(&quantity.view()), "‹ 4 ›");
+//! ```
+
+use crate::error::Error;
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, Instant};
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted whenever the value changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChangeMsg {
+    /// The ID of the stepper that changed.
+    pub id: i64,
+    /// The new value.
+    pub value: f64,
+}
+
+/// Key bindings for the stepper.
+#[derive(Debug, Clone)]
+pub struct StepperKeyMap {
+    /// Increments the value. Default: `→`, `l`, `↑`, `k`, `+`.
+    pub increment: key::Binding,
+    /// Decrements the value. Default: `←`, `h`, `↓`, `j`, `-`.
+    pub decrement: key::Binding,
+    /// Starts editing or commits the typed value. Default: `enter`.
+    pub edit: key::Binding,
+    /// Cancels editing. Default: `esc`.
+    pub cancel: key::Binding,
+}
+
+impl Default for StepperKeyMap {
+    fn default() -> Self {
+        Self {
+            increment: key::new_binding(vec![
+                key::with_keys_str(&["right", "l", "up", "k", "+"]),
+                key::with_help("→/+", "increment"),
+            ]),
+            decrement: key::new_binding(vec![
+                key::with_keys_str(&["left", "h", "down", "j", "-"]),
+                key::with_help("←/-", "decrement"),
+            ]),
+            edit: key::new_binding(vec![
+                key::with_keys_str(&["enter"]),
+                key::with_help("enter", "edit"),
+            ]),
+            cancel: key::new_binding(vec![
+                key::with_keys_str(&["esc"]),
+                key::with_help("esc", "cancel"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for StepperKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.decrement, &self.increment, &self.edit]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.decrement, &self.increment],
+            vec![&self.edit, &self.cancel],
+        ]
+    }
+}
+
+/// Visual styles for the stepper.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of an arrow that can still move the value.
+    pub arrow: Style,
+    /// Style of an arrow whose bound has been reached.
+    pub disabled_arrow: Style,
+    /// Style of the value while focused.
+    pub value: Style,
+    /// Style of the value while blurred.
+    pub blurred_value: Style,
+    /// Style of the error shown for invalid typed input.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            arrow: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#EE6FF8",
+            }),
+            disabled_arrow: Style::new().foreground(AdaptiveColor {
+                Light: "#D0D0D0",
+                Dark: "#3C3C3C",
+            }),
+            value: Style::new().bold(true),
+            blurred_value: Style::new(),
+            error: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates stepper styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            arrow: theme.accent.clone(),
+            disabled_arrow: theme.subtle.clone(),
+            value: theme.selected.clone(),
+            blurred_value: theme.text.clone(),
+            error: theme.error.clone(),
+        }
+    }
+}
+
+/// A numeric stepper.
+pub struct Model {
+    id: i64,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    focus: bool,
+    editing: bool,
+    err: Option<Error>,
+    last_press: Option<(bool, Instant)>,
+    streak: u32,
+    /// Number of decimals shown.
+    pub decimals: usize,
+    /// Whether `enter` opens the text entry.
+    pub editable: bool,
+    /// Whether repeated presses accelerate the change.
+    pub acceleration: bool,
+    /// Maximum gap between presses for them to count as a held key.
+    pub repeat_window: Duration,
+    /// Text entry used while editing.
+    pub input: textinput::Model,
+    /// Key bindings.
+    pub keymap: StepperKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused, unbounded stepper starting at zero.
+pub fn new() -> Model {
+    Model::new()
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model {
+    /// Creates a focused, unbounded stepper starting at zero.
+    pub fn new() -> Self {
+        let mut input = textinput::new();
+        input.prompt = String::new();
+        input.set_width(10);
+        Self {
+            id: next_id(),
+            value: 0.0,
+            min: f64::MIN,
+            max: f64::MAX,
+            step: 1.0,
+            focus: true,
+            editing: false,
+            err: None,
+            last_press: None,
+            streak: 0,
+            decimals: 0,
+            editable: true,
+            acceleration: true,
+            repeat_window: Duration::from_millis(150),
+            input,
+            keymap: StepperKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the inclusive bounds, clamping the current value.
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        self.min = min;
+        self.max = max;
+        self.value = self.value.clamp(min, max);
+        self
+    }
+
+    /// Sets the amount added or removed per press.
+    pub fn with_step(mut self, step: f64) -> Self {
+        if step > 0.0 {
+            self.step = step;
+        }
+        self
+    }
+
+    /// Sets the initial value.
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Sets the number of decimals shown.
+    pub fn with_decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Enables or disables typed entry on `enter`.
+    pub fn with_editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Enables or disables hold-to-repeat acceleration.
+    pub fn with_acceleration(mut self, acceleration: bool) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this stepper.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Sets the value, clamped to the bounds.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Returns whether the text entry is open.
+    pub fn editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Returns the error for the last rejected typed value.
+    pub fn error(&self) -> Option<&Error> {
+        self.err.as_ref()
+    }
+
+    /// Increments the value by one step.
+    pub fn increment(&mut self) {
+        self.set_value(self.value + self.step);
+    }
+
+    /// Decrements the value by one step.
+    pub fn decrement(&mut self) {
+        self.set_value(self.value - self.step);
+    }
+
+    /// Returns the step multiplier for the current run of repeated presses.
+    fn multiplier(&self) -> f64 {
+        match self.streak {
+            0..=4 => 1.0,
+            5..=14 => 5.0,
+            _ => 10.0,
+        }
+    }
+
+    fn press(&mut self, up: bool, now: Instant) {
+        let repeated = matches!(
+            self.last_press,
+            Some((dir, at)) if dir == up && now.duration_since(at) <= self.repeat_window
+        );
+        self.streak = if repeated && self.acceleration {
+            self.streak + 1
+        } else {
+            0
+        };
+        self.last_press = Some((up, now));
+        let delta = self.step * self.multiplier();
+        self.set_value(if up {
+            self.value + delta
+        } else {
+            self.value - delta
+        });
+    }
+
+    fn format(&self) -> String {
+        format!("{:.*}", self.decimals, self.value)
+    }
+
+    fn changed(&self, before: f64) -> Option<Cmd> {
+        if self.value == before {
+            return None;
+        }
+        let (id, value) = (self.id, self.value);
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ChangeMsg { id, value }) as Msg
+        }))
+    }
+
+    /// Opens the text entry, prefilled with the current value.
+    pub fn start_editing(&mut self) -> Option<Cmd> {
+        if !self.editable {
+            return None;
+        }
+        self.editing = true;
+        self.err = None;
+        self.input.set_value(&self.format());
+        self.input.cursor_end();
+        Some(self.input.focus())
+    }
+
+    fn stop_editing(&mut self) {
+        self.editing = false;
+        self.input.blur();
+    }
+
+    fn commit(&mut self) -> Option<Cmd> {
+        let text = self.input.value();
+        match text.trim().parse::<f64>() {
+            Ok(v) if v.is_finite() => {
+                let before = self.value;
+                self.set_value(v);
+                self.err = None;
+                self.stop_editing();
+                self.changed(before)
+            }
+            _ => {
+                self.err = Some(Error::Validation(format!("not a number: {}", text)));
+                None
+            }
+        }
+    }
+
+    /// Handles key input, returning a [`ChangeMsg`] command when the value
+    /// changed.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus {
+            return None;
+        }
+        if self.editing {
+            if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+                if self.keymap.edit.matches(key_msg) {
+                    return self.commit();
+                }
+                if self.keymap.cancel.matches(key_msg) {
+                    self.err = None;
+                    self.stop_editing();
+                    return None;
+                }
+            }
+            return self.input.update(msg);
+        }
+
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        let before = self.value;
+        if self.keymap.increment.matches(key_msg) {
+            self.press(true, Instant::now());
+        } else if self.keymap.decrement.matches(key_msg) {
+            self.press(false, Instant::now());
+        } else if self.keymap.edit.matches(key_msg) {
+            return self.start_editing();
+        }
+        self.changed(before)
+    }
+
+    /// Renders the stepper as `‹ value ›`, with the text entry in place of
+    /// the value while editing.
+    pub fn view(&self) -> String {
+        let arrow = |enabled: bool, glyph: &str| {
+            if enabled {
+                self.styles.arrow.render(glyph)
+            } else {
+                self.styles.disabled_arrow.render(glyph)
+            }
+        };
+        let value = if self.editing {
+            self.input.view()
+        } else if self.focus {
+            self.styles.value.render(&self.format())
+        } else {
+            self.styles.blurred_value.render(&self.format())
+        };
+        let mut out = format!(
+            "{} {} {}",
+            arrow(self.value > self.min, "‹"),
+            value,
+            arrow(self.value < self.max, "›")
+        );
+        if let Some(err) = &self.err {
+            out = format!("{}\n{}", out, self.styles.error.render(&err.to_string()));
+        }
+        out
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+        if self.editing {
+            self.stop_editing();
+        }
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved value of a stepper; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The current value.
+    pub value: f64,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            value: self.value(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_value(state.value);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_keys_respect_bounds() {
+        let mut s = Model::new().with_range(0.0, 2.0);
+        assert!(s.update(key(KeyCode::Char('-'))).is_none());
+        assert!(s.update(key(KeyCode::Char('+'))).is_some());
+        s.update(key(KeyCode::Right));
+        s.update(key(KeyCode::Right));
+        assert_eq!(s.value(), 2.0);
+    }
+
+    #[test]
+    fn test_held_key_accelerates() {
+        let mut s = Model::new();
+        let start = Instant::now();
+        for i in 0..6 {
+            s.press(true, start + Duration::from_millis(50 * i));
+        }
+        // Five single steps, then the sixth press moves by five.
+        assert_eq!(s.value(), 10.0);
+        // A pause resets the streak.
+        s.press(true, start + Duration::from_secs(5));
+        assert_eq!(s.value(), 11.0);
+    }
+
+    #[test]
+    fn test_edit_commit_and_reject() {
+        let mut s = Model::new().with_range(0.0, 100.0);
+        s.update(key(KeyCode::Enter));
+        assert!(s.editing());
+        s.input.set_value("abc");
+        s.update(key(KeyCode::Enter));
+        assert!(s.editing());
+        assert!(s.error().is_some());
+        s.input.set_value("250");
+        assert!(s.update(key(KeyCode::Enter)).is_some());
+        assert!(!s.editing());
+        assert_eq!(s.value(), 100.0);
+        assert_eq!(s.error(), None);
+    }
+
+    #[test]
+    fn test_view_and_escape() {
+        let mut s = Model::new().with_value(42.0);
+        assert_eq!(lipgloss_extras::lipgloss::strip_ansi(&s.view()), "‹ 42 ›");
+        s.update(key(KeyCode::Enter));
+        s.update(key(KeyCode::Esc));
+        assert!(!s.editing());
+        assert_eq!(s.value(), 42.0);
+    }
+}
@@ -93,7 +93,9 @@
 
 use bubbletea_rs::{tick as bubbletea_tick, Cmd, Model as BubbleTeaModel, Msg};
 use std::sync::atomic::{AtomicI64, Ordering};
-use std::time::{Duration, Instant};
+use std::time::Duration;
+// Follows tokio's clock, so ticks measure virtual time when it is paused
+use tokio::time::Instant;
 
 // Internal ID management for stopwatch instances
 static LAST_ID: AtomicI64 = AtomicI64::new(0);
@@ -1183,6 +1185,38 @@ impl Model {
     }
 }
 
+/// Saved time of a stopwatch; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Time measured so far.
+    pub elapsed: Duration,
+    /// Whether the stopwatch was running.
+    pub running: bool,
+}
+
+/// Like a new stopwatch, a restored one only ticks once the command from
+/// [`start`](Model::start) runs. Ticks scheduled before the restore are
+/// ignored.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            elapsed: self.d,
+            running: self.running,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.d = state.elapsed;
+        self.running = state.running;
+        self.start_instant = None;
+        self.last_tick = None;
+        self.tag += 1;
+    }
+}
+
 impl BubbleTeaModel for Model {
     /// Creates a new stopwatch and starts it automatically.
     ///
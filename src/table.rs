@@ -2,7 +2,7 @@
 //!
 //! This module provides a comprehensive table implementation for terminal user interfaces,
 //! designed for displaying structured data with keyboard navigation and visual selection.
-//! It features efficient viewport-based rendering, customizable styling, and integration
+//! It features rendering of only the rows in view, customizable styling, and integration
 //! with the Bubble Tea architecture.
 //!
 //! # Core Components
@@ -118,20 +118,28 @@
 //!
 //! # Performance Considerations
 //!
-//! - Uses viewport rendering for efficient display of large datasets
-//! - Only visible rows are rendered, enabling smooth performance with thousands of rows
+//! - Only the `height` rows in view are rendered and hashed, so a frame
+//!   costs the same with ten rows or ten thousand
+//! - A frame whose visible rows, selection and styles are unchanged reuses
+//!   the last rendering; [`Model::view_into`] appends it to a buffer the
+//!   caller keeps between frames
 //! - Column widths should be set appropriately to avoid layout recalculation
-//! - Selection changes trigger content rebuilding, but viewport limits render cost
 
+use crate::theme::Theme;
 use crate::{
+    clipboard::Clipboard,
     help,
     key::{self, KeyMap as KeyMapTrait},
-    viewport,
+    text,
+    widget::Margins,
 };
 use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
 use crossterm::event::KeyCode;
 use lipgloss_extras::prelude::*;
 use lipgloss_extras::table::Table as LGTable;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, PoisonError};
 
 /// Represents a table column with its title and display width.
 ///
@@ -428,7 +436,7 @@ impl Default for Styles {
     /// The default styles provide:
     /// - **Header**: Bold text with padding for clear column identification
     /// - **Cell**: Simple padding for consistent data alignment
-    /// - **Selected**: Bold text with a distinct foreground color (#212 - light purple)
+    /// - **Selected**: Bold text in pink (`212` on dark backgrounds, `162` on light ones)
     ///
     /// # Examples
     ///
@@ -447,12 +455,29 @@ impl Default for Styles {
     ///
     /// - Header padding: `(0, 1, 0, 1)` adds horizontal spacing
     /// - Cell padding: `(0, 1, 0, 1)` maintains consistent alignment
-    /// - Selected color: `"212"` is a light purple that works on most terminals
+    /// - Selected color: an adaptive pink that keeps its contrast on light terminals
     fn default() -> Self {
         Self {
             header: Style::new().bold(true).padding(0, 1, 0, 1),
             cell: Style::new().padding(0, 1, 0, 1),
-            selected: Style::new().bold(true).foreground(Color::from("212")),
+            selected: Style::new().bold(true).foreground(AdaptiveColor {
+                Light: "162",
+                Dark: "212",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates table styles from a theme.
+    ///
+    /// The header uses the theme's title style and the selected row its
+    /// selection style; padding matches [`Styles::default`].
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            header: theme.title.clone().padding(0, 1, 0, 1),
+            cell: theme.text.clone().padding(0, 1, 0, 1),
+            selected: theme.selected.clone(),
         }
     }
 }
@@ -539,6 +564,10 @@ pub struct TableKeyMap {
     ///
     /// Default: End key and `G` key (Vim-style)
     pub go_to_end: key::Binding,
+    /// Key binding for copying the selected row to the clipboard.
+    ///
+    /// Default: `y` key (Vim-style "yank")
+    pub copy: key::Binding,
 }
 
 impl Default for TableKeyMap {
@@ -559,6 +588,7 @@ impl Default for TableKeyMap {
     /// | `half_page_down` | `d` | Move down half a page |
     /// | `go_to_start` | `Home`, `g` | Jump to first row |
     /// | `go_to_end` | `End`, `G` | Jump to last row |
+    /// | `copy` | `y` | Copy the selected row |
     ///
     /// # Examples
     ///
@@ -595,6 +625,7 @@ impl Default for TableKeyMap {
                 .with_help("g/home", "go to start"),
             go_to_end: key::Binding::new(vec![KeyCode::End, KeyCode::Char('G')])
                 .with_help("G/end", "go to end"),
+            copy: key::Binding::new(vec![KeyCode::Char('y')]).with_help("y", "copy row"),
         }
     }
 }
@@ -608,7 +639,7 @@ impl KeyMapTrait for TableKeyMap {
             vec![&self.row_up, &self.row_down],
             vec![&self.page_up, &self.page_down],
             vec![&self.half_page_up, &self.half_page_down],
-            vec![&self.go_to_start, &self.go_to_end],
+            vec![&self.go_to_start, &self.go_to_end, &self.copy],
         ]
     }
 }
@@ -693,7 +724,7 @@ pub fn with_rows(rows: Vec<Row>) -> TableOption {
 /// Creates an option to set table height during construction.
 ///
 /// This option configures the vertical display space for the table,
-/// affecting how many rows are visible and viewport scrolling behavior.
+/// which is the number of rows shown at a time.
 ///
 /// # Arguments
 ///
@@ -712,7 +743,6 @@ pub fn with_rows(rows: Vec<Row>) -> TableOption {
 pub fn with_height(h: i32) -> TableOption {
     Box::new(move |m: &mut Model| {
         m.height = h;
-        m.sync_viewport_dimensions();
     })
 }
 
@@ -739,7 +769,6 @@ pub fn with_height(h: i32) -> TableOption {
 pub fn with_width(w: i32) -> TableOption {
     Box::new(move |m: &mut Model| {
         m.width = w;
-        m.sync_viewport_dimensions();
     })
 }
 
@@ -829,8 +858,152 @@ pub fn with_key_map(km: TableKeyMap) -> TableOption {
     })
 }
 
+/// Creates an option to set the clipboard the copy key writes to, in place
+/// of the default one.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::clipboard::{Clipboard, Memory};
+/// use bubbletea_widgets::table::{Model, with_clipboard};
+///
+/// let table = Model::with_options(vec![
+///     with_clipboard(Clipboard::new(Memory::new())),
+/// ]);
+/// ```
+pub fn with_clipboard(clipboard: Clipboard) -> TableOption {
+    Box::new(move |m: &mut Model| {
+        m.clipboard = clipboard;
+    })
+}
+
+/// Chainable form of [`Model::with_options`]: each method adds the
+/// matching [`TableOption`], and [`build`](Builder::build) applies them in
+/// order. Start one with [`Model::builder`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::table::{Column, Model, Row};
+///
+/// let table = Model::builder()
+///     .columns(vec![Column::new("Name", 12), Column::new("Role", 10)])
+///     .rows(vec![Row::new(vec!["Alice".into(), "Admin".into()])])
+///     .height(8)
+///     .focused(false)
+///     .build();
+/// assert_eq!(table.rows.len(), 1);
+/// assert!(!table.focus);
+/// ```
+#[derive(Default)]
+pub struct Builder {
+    options: Vec<TableOption>,
+}
+
+impl Builder {
+    /// Sets the columns.
+    pub fn columns(self, columns: Vec<Column>) -> Self {
+        self.option(with_columns(columns))
+    }
+
+    /// Sets the rows.
+    pub fn rows(self, rows: Vec<Row>) -> Self {
+        self.option(with_rows(rows))
+    }
+
+    /// Sets the number of rows shown at a time.
+    pub fn height(self, height: i32) -> Self {
+        self.option(with_height(height))
+    }
+
+    /// Sets the width in columns.
+    pub fn width(self, width: i32) -> Self {
+        self.option(with_width(width))
+    }
+
+    /// Sets whether the table starts focused.
+    pub fn focused(self, focused: bool) -> Self {
+        self.option(with_focused(focused))
+    }
+
+    /// Sets the styles.
+    pub fn styles(self, styles: Styles) -> Self {
+        self.option(with_styles(styles))
+    }
+
+    /// Sets the key bindings.
+    pub fn key_map(self, key_map: TableKeyMap) -> Self {
+        self.option(with_key_map(key_map))
+    }
+
+    /// Sets the clipboard the copy key writes to.
+    pub fn clipboard(self, clipboard: Clipboard) -> Self {
+        self.option(with_clipboard(clipboard))
+    }
+
+    /// Makes the table follow the terminal size minus `margins`; see
+    /// [`Model::auto_resize`].
+    pub fn auto_resize(self, margins: Margins) -> Self {
+        self.option(Box::new(move |m: &mut Model| {
+            m.resize_margins = Some(margins);
+        }))
+    }
+
+    /// Adds any other option.
+    pub fn option(mut self, option: TableOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Creates the table.
+    pub fn build(self) -> Model {
+        Model::with_options(self.options)
+    }
+}
+
+/// Lines drawn around the rows: the top border, the header, the line
+/// under it and the bottom border.
+const FRAME_LINES: usize = 4;
+
+/// The last rendered table and the hash of what it was rendered from.
+///
+/// Rendering goes through lipgloss for every cell, which takes long enough
+/// on large tables to notice on every frame, while most frames change
+/// nothing the table is drawn from.
+#[derive(Default)]
+struct RenderCache(Mutex<Option<(u64, String)>>);
+
+impl RenderCache {
+    /// Appends the rendering for `key` to `out`, calling `render` only when
+    /// the cached one is for another key.
+    fn render_into(&self, key: u64, render: impl FnOnce() -> String, out: &mut String) {
+        let mut cached = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match &*cached {
+            Some((k, rendered)) if *k == key => out.push_str(rendered),
+            _ => {
+                let rendered = render();
+                out.push_str(&rendered);
+                *cached = Some((key, rendered));
+            }
+        }
+    }
+}
+
+impl Clone for RenderCache {
+    fn clone(&self) -> Self {
+        let cached = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        Self(Mutex::new(cached.clone()))
+    }
+}
+
+impl std::fmt::Debug for RenderCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RenderCache").finish_non_exhaustive()
+    }
+}
+
 /// Interactive table model containing data, styling, navigation state,
-/// and a viewport for efficient rendering and scrolling.
+/// and the window of rows in view.
 #[derive(Debug, Clone)]
 pub struct Model {
     /// Column definitions controlling headers and widths.
@@ -841,7 +1014,7 @@ pub struct Model {
     pub selected: usize,
     /// Rendered width of the table in characters.
     pub width: i32,
-    /// Rendered height of the table in lines.
+    /// Number of rows shown at a time.
     pub height: i32,
     /// Key bindings for navigation and movement.
     pub keymap: TableKeyMap,
@@ -851,8 +1024,20 @@ pub struct Model {
     pub focus: bool,
     /// Help model used to render key binding help.
     pub help: help::Model,
-    /// Internal viewport that manages scrolling of rendered lines.
-    viewport: viewport::Model,
+    /// First row in view; moves as needed to keep the selection in view.
+    offset: usize,
+    /// Bumped by changes the rendered table's key cannot see, such as new
+    /// styles.
+    generation: u64,
+    /// Margins kept when following the terminal size, if enabled.
+    resize_margins: Option<Margins>,
+    /// Where the copy key puts the selected row.
+    clipboard: Clipboard,
+    /// The last rendered table, reused while nothing it shows changes.
+    rendered: RenderCache,
+    /// Mouse click and drag state.
+    #[cfg(feature = "mouse")]
+    mouse: crate::mouse::Tracker,
 }
 
 impl Model {
@@ -870,14 +1055,23 @@ impl Model {
             styles: Styles::default(),
             focus: true,
             help: help::Model::new(),
-            viewport: viewport::Model::new(0, 0),
+            offset: 0,
+            generation: 0,
+            resize_margins: None,
+            clipboard: Clipboard::default(),
+            rendered: RenderCache::default(),
+            #[cfg(feature = "mouse")]
+            mouse: crate::mouse::Tracker::new(),
         };
-        // Initialize viewport dimensions
-        s.sync_viewport_dimensions();
-        s.rebuild_viewport_content();
+        s.refresh();
         s
     }
 
+    /// Starts a [`Builder`] for a table.
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Creates a new table with configuration options (Go-compatible constructor).
     ///
     /// This constructor provides a flexible, option-based approach to table creation
@@ -938,7 +1132,13 @@ impl Model {
             styles: Styles::default(),
             focus: true,
             help: help::Model::new(),
-            viewport: viewport::Model::new(0, 0),
+            offset: 0,
+            generation: 0,
+            resize_margins: None,
+            clipboard: Clipboard::default(),
+            rendered: RenderCache::default(),
+            #[cfg(feature = "mouse")]
+            mouse: crate::mouse::Tracker::new(),
         };
 
         // Apply all options in order
@@ -946,9 +1146,7 @@ impl Model {
             opt(&mut m);
         }
 
-        // Initialize viewport after all options are applied
-        m.sync_viewport_dimensions();
-        m.rebuild_viewport_content();
+        m.refresh();
         m
     }
 
@@ -957,27 +1155,67 @@ impl Model {
         self.rows = rows;
         self
     }
-    /// Sets the table width in characters and rebuilds the viewport content.
+    /// Makes the table follow the terminal size and returns `self` for chaining.
+    ///
+    /// Every `WindowSizeMsg` then sets the width to the terminal width
+    /// minus `margins`, and shows as many rows as fit in the terminal height
+    /// minus `margins`; see [`fit_height`](Self::fit_height).
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+    /// Sets the screen cell where the table is drawn, so clicks can select
+    /// rows, and returns `self` for chaining.
+    #[cfg(feature = "mouse")]
+    pub fn with_mouse_origin(mut self, x: u16, y: u16) -> Self {
+        self.set_mouse_origin(x, y);
+        self
+    }
+    /// Sets the screen cell where the table is drawn.
+    #[cfg(feature = "mouse")]
+    pub fn set_mouse_origin(&mut self, x: u16, y: u16) {
+        self.mouse.set_origin(x, y);
+    }
+    /// Sets the table width in characters.
     pub fn set_width(&mut self, w: i32) {
         self.width = w;
-        self.sync_viewport_dimensions();
-        self.rebuild_viewport_content();
+        self.refresh();
     }
-    /// Sets the table height in lines and rebuilds the viewport content.
+    /// Sets the number of rows shown at a time.
     pub fn set_height(&mut self, h: i32) {
         self.height = h;
-        self.sync_viewport_dimensions();
-        self.rebuild_viewport_content();
+        self.refresh();
     }
-    /// Appends a row to the table and refreshes the rendered content.
+    /// Shows as many rows as fit in `lines` lines along with the borders
+    /// and header around them. At least one row is shown however few lines
+    /// there are.
+    pub fn fit_height(&mut self, lines: usize) {
+        self.set_height(lines.saturating_sub(FRAME_LINES).max(1) as i32);
+    }
+    /// Appends a row to the table.
     pub fn add_row(&mut self, row: Row) {
         self.rows.push(row);
-        self.rebuild_viewport_content();
+        self.refresh();
     }
     /// Returns a reference to the currently selected row, if any.
     pub fn selected_row(&self) -> Option<&Row> {
         self.rows.get(self.selected)
     }
+
+    /// Sets the clipboard the copy key writes to; see [`with_clipboard`].
+    pub fn set_clipboard(&mut self, clipboard: Clipboard) {
+        self.clipboard = clipboard;
+    }
+
+    /// Returns a command copying the selected row to the clipboard, its
+    /// cells separated by tabs so it pastes into a spreadsheet as a row.
+    /// The command reports a [`CopiedMsg`](crate::clipboard::CopiedMsg) or
+    /// a [`CopyErrMsg`](crate::clipboard::CopyErrMsg). Returns `None` when
+    /// the table is empty.
+    pub fn copy_selected_row(&self) -> Option<Cmd> {
+        let row = self.selected_row()?;
+        Some(self.clipboard.write_cmd(row.cells.join("\t")))
+    }
     /// Moves the selection down by one row.
     pub fn select_next(&mut self) {
         if !self.rows.is_empty() {
@@ -1105,11 +1343,11 @@ impl Model {
         }
     }
 
-    /// Sets table styles and rebuilds the viewport content.
+    /// Sets table styles and renders afresh with them.
     ///
     /// This method matches the Go version's `SetStyles` functionality by updating
-    /// the table's visual styling and ensuring the viewport content is rebuilt
-    /// to reflect the new styles.
+    /// the table's visual styling and making sure the next frame is drawn
+    /// with the new styles.
     ///
     /// # Arguments
     ///
@@ -1130,18 +1368,16 @@ impl Model {
     /// };
     ///
     /// table.set_styles(custom_styles);
-    /// // Table now uses the new styles and viewport is updated
+    /// // Table now uses the new styles
     /// ```
     pub fn set_styles(&mut self, s: Styles) {
         self.styles = s;
         self.update_viewport();
     }
 
-    /// Updates the viewport content based on current columns, rows, and styling.
+    /// Renders afresh on the next frame and brings the selected row into view.
     ///
-    /// This method matches the Go version's `UpdateViewport` functionality by
-    /// rebuilding the rendered table content and ensuring the selected row
-    /// remains visible. It should be called after any changes to table
+    /// This method matches the Go version's `UpdateViewport` functionality. It should be called after any changes to table
     /// structure, data, or styling.
     ///
     /// # Examples
@@ -1152,7 +1388,7 @@ impl Model {
     /// let mut table = Model::new(vec![Column::new("Name", 20)]);
     /// table.rows.push(Row::new(vec!["Alice".into()]));
     ///
-    /// // After manual changes, update the viewport
+    /// // After manual changes, refresh the table
     /// table.update_viewport();
     /// ```
     ///
@@ -1161,10 +1397,11 @@ impl Model {
     /// This method is automatically called by most table methods, but you may
     /// need to call it manually when:
     /// - Directly modifying the `rows` or `columns` fields
-    /// - Changing dimensions or styling outside of provided methods
+    /// - Changing dimensions or styling outside of provided methods, such as
+    ///   assigning to `styles` after the table has been drawn
     /// - Ensuring content is current after external modifications
     pub fn update_viewport(&mut self) {
-        self.rebuild_viewport_content();
+        self.refresh();
     }
 
     /// Renders help information for table navigation keys.
@@ -1217,58 +1454,88 @@ impl Model {
         self.help.view(self)
     }
 
-    /// Renders the table as a string.
+    /// Renders the table as a string, or the
+    /// [`accessible_view`](Self::accessible_view) in accessible mode.
     pub fn view(&self) -> String {
-        // Render table directly to string
-        let mut tbl = LGTable::new();
-        if self.width > 0 {
-            tbl = tbl.width(self.width);
-        }
+        let mut out = String::new();
+        self.view_into(&mut out);
+        out
+    }
 
-        let headers: Vec<String> = self.columns.iter().map(|c| c.title.clone()).collect();
-        tbl = tbl.headers(headers);
+    /// Appends the [`view`](Self::view) to `out`. A program that keeps `out`
+    /// between frames, clearing it before each, renders without allocating
+    /// while the table is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::table::{Column, Model, Row};
+    ///
+    /// let table = Model::new(vec![Column::new("Name", 10)])
+    ///     .with_rows(vec![Row::new(vec!["Alice".into()])]);
+    /// let mut frame = String::new();
+    /// for _ in 0..3 {
+    ///     frame.clear();
+    ///     table.view_into(&mut frame);
+    /// }
+    /// assert_eq!(frame, table.view());
+    /// ```
+    pub fn view_into(&self, out: &mut String) {
+        if crate::a11y::enabled() {
+            out.push_str(&self.accessible_view());
+            return;
+        }
+        let rows = self.visible_rows();
+        self.rendered.render_into(
+            self.render_key(rows.clone()),
+            || self.render_table(rows.clone()),
+            out,
+        );
+    }
 
-        let widths = self.columns.iter().map(|c| c.width).collect::<Vec<_>>();
-        let cell_style = self.styles.cell.clone();
-        let header_style = self.styles.header.clone();
-        let selected_row = self.selected as i32;
-        let selected_style = self.styles.selected.clone();
-        tbl = tbl.style_func_boxed(Box::new(move |row: i32, col: usize| {
-            let mut s = if row == lipgloss_extras::table::HEADER_ROW {
-                header_style.clone()
-            } else {
-                cell_style.clone()
-            };
-            if let Some(w) = widths.get(col) {
-                s = s.width(*w);
-            }
-            if row >= 0 && row == selected_row {
-                s = selected_style.clone().inherit(s);
-            }
-            s
-        }));
+    /// The rows in view: from the first row shown, moved as little as it
+    /// takes to include the selection, which may have been set directly.
+    fn visible_rows(&self) -> std::ops::Range<usize> {
+        let page = self.height.max(1) as usize;
+        let mut start = self.offset;
+        if self.selected < start {
+            start = self.selected;
+        } else if self.selected >= start + page {
+            start = self.selected + 1 - page;
+        }
+        let start = start.min(self.rows.len().saturating_sub(page));
+        start..(start + page).min(self.rows.len())
+    }
 
-        let row_vecs: Vec<Vec<String>> = self.rows.iter().map(|r| r.cells.clone()).collect();
-        tbl = tbl.rows(row_vecs);
-        tbl.to_string()
+    /// Hashes what the table is drawn from: the columns, the rows in view
+    /// and the selection. Other changes bump the generation.
+    fn render_key(&self, rows: std::ops::Range<usize>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for column in &self.columns {
+            (&column.title, column.width).hash(&mut hasher);
+        }
+        for row in &self.rows[rows.clone()] {
+            row.cells.hash(&mut hasher);
+        }
+        (rows, self.width, self.selected, self.generation).hash(&mut hasher);
+        hasher.finish()
     }
 
-    fn rebuild_viewport_content(&mut self) {
+    /// Renders the header and the rows in `rows` through lipgloss.
+    fn render_table(&self, rows: std::ops::Range<usize>) -> String {
         let mut tbl = LGTable::new();
         if self.width > 0 {
             tbl = tbl.width(self.width);
         }
-        // Don't set table height; viewport will handle vertical scrolling
 
-        // Headers
-        let headers: Vec<String> = self.columns.iter().map(|c| c.title.clone()).collect();
+        let (headers, row_vecs) = self.clipped_cells(rows.clone());
         tbl = tbl.headers(headers);
 
-        // Column widths via style_func
         let widths = self.columns.iter().map(|c| c.width).collect::<Vec<_>>();
         let cell_style = self.styles.cell.clone();
         let header_style = self.styles.header.clone();
-        let selected_row = self.selected as i32; // data rows are 0-based in lipgloss-table
+        // Rows are numbered from the first one in view
+        let selected_row = self.selected as i32 - rows.start as i32;
         let selected_style = self.styles.selected.clone();
         tbl = tbl.style_func_boxed(Box::new(move |row: i32, col: usize| {
             let mut s = if row == lipgloss_extras::table::HEADER_ROW {
@@ -1285,34 +1552,97 @@ impl Model {
             s
         }));
 
-        // Rows
-        let row_vecs: Vec<Vec<String>> = self.rows.iter().map(|r| r.cells.clone()).collect();
         tbl = tbl.rows(row_vecs);
+        tbl.to_string()
+    }
+
+    /// Renders the table as plain text: the column titles, then the
+    /// selected row read out cell by cell, such as
+    /// `row 2 of 5 selected: Name: Banana, Price: $0.25`.
+    pub fn accessible_view(&self) -> String {
+        let titles: Vec<&str> = self.columns.iter().map(|c| c.title.as_str()).collect();
+        let header = format!("Columns: {}", titles.join(", "));
+        let Some(row) = self.rows.get(self.selected) else {
+            return format!("{}\nNo rows.", header);
+        };
+        let cells: Vec<String> = row
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| match self.columns.get(i) {
+                Some(c) if !c.title.is_empty() => format!("{}: {}", c.title, cell),
+                _ => cell.clone(),
+            })
+            .collect();
+        format!(
+            "{}\n{}",
+            header,
+            crate::a11y::selected("row", self.selected, self.rows.len(), &cells.join(", "))
+        )
+    }
 
-        let rendered = tbl.to_string();
-        let lines: Vec<String> = rendered.split('\n').map(|s| s.to_string()).collect();
-        self.viewport.set_content_lines(lines);
+    /// Returns the headers and the rows in `rows` cut to their columns'
+    /// widths, so long values end in `…` instead of stretching the whole
+    /// column.
+    fn clipped_cells(&self, rows: std::ops::Range<usize>) -> (Vec<String>, Vec<Vec<String>>) {
+        let clip = |col: usize, cell: &str| match self.columns.get(col) {
+            Some(c) if c.width > 0 => text::truncate(cell, c.width as usize),
+            _ => cell.to_string(),
+        };
+        let headers = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| clip(i, &c.title))
+            .collect();
+        let rows = self.rows[rows]
+            .iter()
+            .map(|r| {
+                r.cells
+                    .iter()
+                    .enumerate()
+                    .map(|(i, cell)| clip(i, cell))
+                    .collect()
+            })
+            .collect();
+        (headers, rows)
+    }
 
-        // Ensure selection is visible (header is line 0; rows begin at line 1)
+    /// Renders afresh on the next frame and brings the selection into view.
+    fn refresh(&mut self) {
+        self.generation += 1;
         self.ensure_selected_visible();
     }
 
     fn ensure_selected_visible(&mut self) {
-        let target_line = self.selected.saturating_add(1); // account for header
-        let h = (self.height.max(1)) as usize;
-        let top = self.viewport.y_offset;
-        let bottom = top.saturating_add(h.saturating_sub(1));
-        if target_line < top {
-            self.viewport.set_y_offset(target_line);
-        } else if target_line > bottom {
-            let new_top = target_line.saturating_sub(h.saturating_sub(1));
-            self.viewport.set_y_offset(new_top);
-        }
+        self.offset = self.visible_rows().start;
+    }
+
+    /// Returns the row drawn on `line` of a view `lines` tall, skipping the
+    /// border and header above the rows and the border below them.
+    #[cfg(feature = "mouse")]
+    fn row_at_line(&self, line: usize, lines: usize) -> Option<usize> {
+        let rows = self.visible_rows();
+        let first = lines.checked_sub(rows.len() + 1)?;
+        let row = rows.start + line.checked_sub(first)?;
+        rows.contains(&row).then_some(row)
     }
 
-    fn sync_viewport_dimensions(&mut self) {
-        self.viewport.width = self.width.max(0) as usize;
-        self.viewport.height = self.height.max(0) as usize;
+    #[cfg(feature = "mouse")]
+    fn handle_mouse(&mut self, msg: &Msg) {
+        use crate::mouse::Gesture;
+        let (width, height) = crate::compose::size(&self.view());
+        match self.mouse.handle(msg, width, height) {
+            Some(Gesture::Wheel { dy, .. }) if dy > 0 => self.move_down(dy as usize),
+            Some(Gesture::Wheel { dy, .. }) if dy < 0 => self.move_up(dy.unsigned_abs() as usize),
+            Some(Gesture::Press { row, .. }) | Some(Gesture::Drag { row, .. }) => {
+                if let Some(row) = self.row_at_line(row, height) {
+                    self.selected = row;
+                }
+            }
+            _ => return,
+        }
+        self.ensure_selected_visible();
     }
 
     /// Gives keyboard focus to the table.
@@ -1325,6 +1655,64 @@ impl Model {
     }
 }
 
+/// Searches every cell. Going to a hit selects its row.
+impl crate::search::Searchable for Model {
+    fn matches(&self, query: &str) -> Vec<crate::search::Hit> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(row, r)| {
+                r.cells
+                    .iter()
+                    .enumerate()
+                    .flat_map(move |(cell, text)| crate::search::hits_in(text, query, row, cell))
+            })
+            .collect()
+    }
+
+    fn goto(&mut self, hit: crate::search::Hit) {
+        if hit.row < self.rows.len() {
+            self.selected = hit.row;
+            self.update_viewport();
+        }
+    }
+}
+
+/// Saved selection and scroll position of a table; see
+/// [`Stateful`](crate::state::Stateful). The rows are not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Index of the selected row.
+    pub selected: usize,
+    /// Lines scrolled from the top.
+    pub scroll: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            selected: self.selected,
+            scroll: self.offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.selected = state.selected.min(self.rows.len().saturating_sub(1));
+        self.offset = state.scroll;
+        self.update_viewport();
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+        self.refresh();
+    }
+}
+
 impl BubbleTeaModel for Model {
     /// Creates a new empty table model for Bubble Tea applications.
     ///
@@ -1409,14 +1797,26 @@ impl BubbleTeaModel for Model {
     ///
     /// # Performance Optimization
     ///
-    /// After any navigation that changes the selection, the viewport content
-    /// is automatically rebuilt to ensure the selected row remains visible
-    /// and the display is updated correctly.
+    /// After any navigation that changes the selection, the rows in view move
+    /// as little as it takes to keep the selected row among them.
     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some((width, height)) = crate::widget::resized(&msg, self.resize_margins) {
+            self.width = width as i32;
+            self.fit_height(height);
+            return None;
+        }
+        #[cfg(feature = "mouse")]
+        if self.focus && crate::mouse::event(&msg).is_some() {
+            self.handle_mouse(&msg);
+            return None;
+        }
         if let Some(k) = msg.downcast_ref::<KeyMsg>() {
             if !self.focus {
                 return None;
             }
+            if self.keymap.copy.matches(k) {
+                return self.copy_selected_row();
+            }
             if self.keymap.row_up.matches(k) {
                 self.select_prev();
             } else if self.keymap.row_down.matches(k) {
@@ -1442,7 +1842,7 @@ impl BubbleTeaModel for Model {
                 self.selected = (self.selected + (self.height as usize).max(1) / 2)
                     .min(self.rows.len().saturating_sub(1));
             }
-            // After any movement, ensure visibility without rebuilding content
+            // After any movement, keep the selected row in view
             self.ensure_selected_visible();
         }
         None
@@ -1499,7 +1899,7 @@ impl BubbleTeaModel for Model {
     /// }
     /// ```
     fn view(&self) -> String {
-        self.viewport.view()
+        Model::view(self)
     }
 }
 
@@ -1579,7 +1979,7 @@ impl help::KeyMap for Model {
     /// 1. **Row Navigation**: Single row up/down movement
     /// 2. **Page Navigation**: Full page up/down scrolling
     /// 3. **Half Page Navigation**: Half page up/down movement
-    /// 4. **Jump Navigation**: Go to start/end positions
+    /// 4. **Jump Navigation**: Go to start/end positions, and copying the row
     ///
     /// # Display Integration
     ///
@@ -1591,7 +1991,11 @@ impl help::KeyMap for Model {
             vec![&self.keymap.row_up, &self.keymap.row_down],
             vec![&self.keymap.page_up, &self.keymap.page_down],
             vec![&self.keymap.half_page_up, &self.keymap.half_page_down],
-            vec![&self.keymap.go_to_start, &self.keymap.go_to_end],
+            vec![
+                &self.keymap.go_to_start,
+                &self.keymap.go_to_end,
+                &self.keymap.copy,
+            ],
         ]
     }
 }
@@ -1633,4 +2037,234 @@ mod tests {
         let out = m.view();
         assert!(out.contains("Foooooo"));
     }
+
+    #[test]
+    fn test_search_selects_matching_row() {
+        use crate::search::{Hit, Searchable};
+        let mut m = Model::new(cols()).with_rows(vec![
+            Row::new(vec!["1".into(), "Apple".into(), "red".into()]),
+            Row::new(vec!["2".into(), "Cherry".into(), "RED".into()]),
+        ]);
+        let hits = m.matches("red");
+        assert_eq!(hits.len(), 2);
+        assert_eq!(
+            hits[1],
+            Hit {
+                row: 1,
+                cell: 2,
+                start: 0,
+                end: 3
+            }
+        );
+        m.goto(hits[1]);
+        assert_eq!(m.selected, 1);
+    }
+
+    #[test]
+    fn test_auto_resize_follows_window_size() {
+        let resize = || -> Msg {
+            Box::new(bubbletea_rs::WindowSizeMsg {
+                width: 60,
+                height: 30,
+            })
+        };
+        let mut m = Model::new(cols());
+        m.update(resize());
+        assert_eq!((m.width, m.height), (0, 20));
+
+        let mut m = Model::new(cols()).auto_resize(Margins::new(2, 0, 3, 4));
+        m.update(resize());
+        assert_eq!((m.width, m.height), (56, 21));
+
+        // The whole table fits in the terminal, borders and header included
+        let rows = (0..50)
+            .map(|i| Row::new(vec![i.to_string(), "Foo".into(), "x".into()]))
+            .collect();
+        let mut m = Model::new(cols())
+            .with_rows(rows)
+            .auto_resize(Margins::default());
+        for height in [10, 30] {
+            m.update(Box::new(bubbletea_rs::WindowSizeMsg { width: 40, height }));
+            assert_eq!(m.view().lines().count(), height as usize);
+        }
+    }
+
+    #[cfg(feature = "mouse")]
+    #[test]
+    fn test_mouse_click_and_wheel_select_rows() {
+        use bubbletea_rs::MouseMsg;
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let event = |button, y| -> Msg {
+            Box::new(MouseMsg {
+                x: 12,
+                y,
+                button,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        let mut m = Model::new(cols())
+            .with_rows(vec![
+                Row::new(vec!["1".into(), "Apple".into(), "red".into()]),
+                Row::new(vec!["2".into(), "Cherry".into(), "red".into()]),
+                Row::new(vec!["3".into(), "Lime".into(), "green".into()]),
+            ])
+            .with_mouse_origin(10, 5);
+
+        // Top border, header and separator come first, so row 2 is on line 5
+        m.update(event(MouseEventKind::Down(MouseButton::Left), 10));
+        assert_eq!(m.selected, 2);
+        // Clicking the header leaves the selection alone
+        m.update(event(MouseEventKind::Down(MouseButton::Left), 6));
+        assert_eq!(m.selected, 2);
+
+        m.update(event(MouseEventKind::ScrollUp, 7));
+        assert_eq!(m.selected, 1);
+    }
+
+    #[test]
+    fn test_restore_clamps_selection() {
+        use crate::state::Stateful;
+
+        let rows = (0..30)
+            .map(|i| Row::new(vec![i.to_string(), "x".into(), "y".into()]))
+            .collect();
+        let mut m = Model::new(cols()).with_rows(rows);
+        m.set_height(5);
+        m.restore(State {
+            selected: 12,
+            scroll: 10,
+        });
+        assert_eq!(m.state().selected, 12);
+
+        m.restore(State {
+            selected: 99,
+            scroll: 0,
+        });
+        assert_eq!(m.selected, 29);
+        assert_eq!(m.selected_row().unwrap().cells[0], "29");
+    }
+
+    #[test]
+    fn test_long_cells_are_truncated_to_column_width() {
+        let rows = vec![
+            Row::new(vec!["a very long value".into(), "x".into(), "y".into()]),
+            Row::new(vec!["日本語のテキスト".into(), "x".into(), "y".into()]),
+        ];
+        let m = Model::new(cols()).with_rows(rows);
+        let view = lipgloss_extras::lipgloss::strip_ansi(&m.view());
+        assert!(view.contains("│a very lo…│"), "{view}");
+        assert!(view.contains("│日本語の… │"), "{view}");
+        let widths: Vec<usize> = view.lines().map(text::width).collect();
+        assert!(widths.iter().all(|&w| w == widths[0]), "{view}");
+    }
+
+    #[test]
+    fn test_builder_matches_options() {
+        let rows = vec![Row::new(vec!["1".into(), "2".into(), "3".into()])];
+        let built = Model::builder()
+            .columns(cols())
+            .rows(rows.clone())
+            .height(6)
+            .focused(true)
+            .build();
+        let optioned = Model::with_options(vec![
+            with_columns(cols()),
+            with_rows(rows),
+            with_height(6),
+            with_focused(true),
+        ]);
+        assert_eq!(built.height, 6);
+        assert!(built.focus);
+        assert_eq!(built.view(), optioned.view());
+    }
+
+    #[test]
+    fn test_copy_key_copies_selected_row() {
+        use crate::clipboard::{Clipboard, CopiedMsg, Memory};
+        use crossterm::event::KeyModifiers;
+
+        let memory = Memory::new();
+        let mut table = Model::builder()
+            .columns(cols())
+            .rows(vec![
+                Row::new(vec!["1".into(), "Foo".into(), "x".into()]),
+                Row::new(vec!["2".into(), "Bar".into(), "y".into()]),
+            ])
+            .clipboard(Clipboard::new(memory.clone()))
+            .build();
+        table.select_next();
+
+        let cmd = table
+            .update(Box::new(KeyMsg {
+                key: KeyCode::Char('y'),
+                modifiers: KeyModifiers::NONE,
+            }))
+            .expect("copy key returns a command");
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let msg = runtime.block_on(cmd).expect("copy command sends a message");
+        assert_eq!(memory.text(), "2\tBar\ty");
+        assert_eq!(msg.downcast_ref::<CopiedMsg>().unwrap().0, "2\tBar\ty");
+        assert_eq!(table.selected, 1);
+    }
+
+    #[test]
+    fn test_accessible_view_reads_selected_row() {
+        let mut table = Model::builder()
+            .columns(cols())
+            .rows(vec![
+                Row::new(vec!["1".into(), "Foo".into(), "x".into()]),
+                Row::new(vec!["2".into(), "Bar".into(), "y".into()]),
+            ])
+            .build();
+        table.select_next();
+        let view = table.accessible_view();
+        assert_eq!(view.lines().count(), 2);
+        assert!(view.ends_with("row 2 of 2 selected: col1: 2, col2: Bar, col3: y"));
+        assert!(!view.contains('─'));
+
+        assert!(Model::new(cols()).accessible_view().ends_with("No rows."));
+    }
+
+    #[test]
+    fn test_view_follows_direct_edits() {
+        let mut m = Model::new(cols()).with_rows(vec![Row::new(vec![
+            "1".into(),
+            "Foo".into(),
+            "x".into(),
+        ])]);
+        assert_eq!(m.view(), m.view());
+        m.rows[0].cells[1] = "Changed".into();
+        assert!(m.view().contains("Changed"));
+        assert!(!m.view().contains("Foo"));
+    }
+
+    #[test]
+    fn test_view_draws_only_rows_in_view() {
+        let rows = (0..100)
+            .map(|i| Row::new(vec![i.to_string(), format!("row{}", i), "x".into()]))
+            .collect();
+        let mut m = Model::new(cols()).with_rows(rows);
+        m.set_height(3);
+        let view = m.view();
+        assert!(view.contains("row2"));
+        assert!(!view.contains("row3"));
+
+        // Moving past the last row in view scrolls by one row
+        m.move_down(3);
+        let view = m.view();
+        assert!(view.contains("row1") && view.contains("row3"));
+        assert!(!view.contains("row0") && !view.contains("row4"));
+
+        // A selection set directly is brought into view too
+        m.selected = 50;
+        let mut out = String::from("> ");
+        m.view_into(&mut out);
+        assert!(out.starts_with("> "));
+        assert!(out.contains("row50") && !out.contains("row47"));
+        assert_eq!(&out[2..], m.view());
+    }
 }
@@ -0,0 +1,572 @@
+//! Tag (chip) input.
+//!
+//! A tag input turns typed text into removable chips: typing `rust` and
+//! pressing `enter` or `,` adds a `rust` chip and clears the field, and
+//! `backspace` on an empty field removes the last chip. An optional list of
+//! suggestions is filtered by the typed prefix and shown as a dropdown below
+//! the field. This is the usual control for labels, topics and recipients.
+//!
+//! Every change to the tags emits a [`ChangeMsg`].
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `enter`, `,` | Add the typed text (or highlighted suggestion) as a tag |
+//! | `backspace` | Remove the last tag when the field is empty |
+//! | `↓`/`ctrl+n`, `↑`/`ctrl+p` | Move through suggestions |
+//! | `tab` | Complete the field with the highlighted or first suggestion |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::taginput;
+//!
+//! let mut labels = taginput::new()
+//!     .with_max_tags(3)
+//!     .with_suggestions(vec!["bug".into(), "feature".into(), "docs".into()]);
+//!
+//! labels.add_tag("bug").unwrap();
+//! labels.add_tag("docs").unwrap();
+//! assert_eq!(labels.tags(), ["bug", "docs"]);
+//! assert!(labels.add_tag("bug").is_err());
+//! ```
+
+use crate::error::{Error, Result};
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::textinput;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted whenever a tag is added or removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeMsg {
+    /// The ID of the tag input that changed.
+    pub id: i64,
+    /// The tags after the change.
+    pub tags: Vec<String>,
+}
+
+/// Key bindings for the tag input.
+#[derive(Debug, Clone)]
+pub struct TagInputKeyMap {
+    /// Adds the typed text as a tag. Default: `enter`, `,`.
+    pub add: key::Binding,
+    /// Removes the last tag when the field is empty. Default: `backspace`.
+    pub remove_last: key::Binding,
+    /// Highlights the next suggestion. Default: `↓`, `ctrl+n`.
+    pub next_suggestion: key::Binding,
+    /// Highlights the previous suggestion. Default: `↑`, `ctrl+p`.
+    pub prev_suggestion: key::Binding,
+    /// Completes the field with a suggestion. Default: `tab`.
+    pub complete: key::Binding,
+}
+
+impl Default for TagInputKeyMap {
+    fn default() -> Self {
+        Self {
+            add: key::new_binding(vec![
+                key::with_keys_str(&["enter", ","]),
+                key::with_help("enter", "add tag"),
+            ]),
+            remove_last: key::new_binding(vec![
+                key::with_keys_str(&["backspace"]),
+                key::with_help("backspace", "remove tag"),
+            ]),
+            next_suggestion: key::new_binding(vec![
+                key::with_keys_str(&["down", "ctrl+n"]),
+                key::with_help("↓", "next suggestion"),
+            ]),
+            prev_suggestion: key::new_binding(vec![
+                key::with_keys_str(&["up", "ctrl+p"]),
+                key::with_help("↑", "previous suggestion"),
+            ]),
+            complete: key::new_binding(vec![
+                key::with_keys_str(&["tab"]),
+                key::with_help("tab", "complete"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for TagInputKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.add, &self.remove_last, &self.complete]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![
+            vec![&self.add, &self.remove_last],
+            vec![&self.next_suggestion, &self.prev_suggestion, &self.complete],
+        ]
+    }
+}
+
+/// Visual styles for the tag input.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of each chip.
+    pub tag: Style,
+    /// Style of suggestions in the dropdown.
+    pub suggestion: Style,
+    /// Style of the highlighted suggestion.
+    pub selected_suggestion: Style,
+    /// Style of the error line.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            tag: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#7571F9"))
+                .padding(0, 1, 0, 1),
+            suggestion: Style::new().foreground(AdaptiveColor {
+                Light: "#8E8E8E",
+                Dark: "#A0A0A0",
+            }),
+            selected_suggestion: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            error: Style::new().foreground(AdaptiveColor {
+                Light: "#D7263D",
+                Dark: "#FF5F87",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates tag input styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        Self {
+            tag: Style::new()
+                .foreground(p.background.clone())
+                .background(p.secondary.clone())
+                .padding(0, 1, 0, 1),
+            suggestion: theme.muted.clone(),
+            selected_suggestion: theme.selected.clone(),
+            error: theme.error.clone(),
+        }
+    }
+}
+
+/// A tag input.
+pub struct Model {
+    id: i64,
+    tags: Vec<String>,
+    suggestions: Vec<String>,
+    matches: Vec<String>,
+    highlighted: Option<usize>,
+    err: Option<Error>,
+    /// Maximum number of tags, or `None` for no limit.
+    pub max_tags: Option<usize>,
+    /// Maximum number of suggestions shown at once.
+    pub max_suggestions: usize,
+    /// Whether the same tag may be added more than once.
+    pub allow_duplicates: bool,
+    /// The text field new tags are typed into.
+    pub input: textinput::Model,
+    /// Key bindings.
+    pub keymap: TagInputKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused, empty tag input.
+pub fn new() -> Model {
+    Model::new()
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model {
+    /// Creates a focused, empty tag input.
+    pub fn new() -> Self {
+        let mut input = textinput::new();
+        input.prompt = String::new();
+        input.set_placeholder("add tag");
+        input.set_width(20);
+        std::mem::drop(input.focus());
+        Self {
+            id: next_id(),
+            tags: Vec::new(),
+            suggestions: Vec::new(),
+            matches: Vec::new(),
+            highlighted: None,
+            err: None,
+            max_tags: None,
+            max_suggestions: 5,
+            allow_duplicates: false,
+            input,
+            keymap: TagInputKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the initial tags.
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Limits the number of tags.
+    pub fn with_max_tags(mut self, max: usize) -> Self {
+        self.max_tags = Some(max);
+        self
+    }
+
+    /// Sets the candidates offered in the suggestion dropdown.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> Self {
+        self.suggestions = suggestions;
+        self.refresh_matches();
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this tag input.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the tags.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Returns the suggestions matching the typed text.
+    pub fn matches(&self) -> &[String] {
+        &self.matches
+    }
+
+    /// Returns the error from the last rejected tag.
+    pub fn error(&self) -> Option<&Error> {
+        self.err.as_ref()
+    }
+
+    /// Adds a tag, trimming surrounding whitespace.
+    ///
+    /// Fails when the tag is empty, already present (unless duplicates are
+    /// allowed), or the tag limit has been reached.
+    pub fn add_tag(&mut self, tag: &str) -> Result<()> {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(Error::Validation("tag is empty".to_string()));
+        }
+        if self.max_tags.is_some_and(|max| self.tags.len() >= max) {
+            return Err(Error::Validation(format!(
+                "at most {} tags",
+                self.max_tags.unwrap_or(0)
+            )));
+        }
+        if !self.allow_duplicates && self.tags.iter().any(|t| t == tag) {
+            return Err(Error::Validation(format!("duplicate tag: {}", tag)));
+        }
+        self.tags.push(tag.to_string());
+        self.refresh_matches();
+        Ok(())
+    }
+
+    /// Removes and returns the tag at `index`.
+    pub fn remove_tag(&mut self, index: usize) -> Option<String> {
+        if index >= self.tags.len() {
+            return None;
+        }
+        let tag = self.tags.remove(index);
+        self.refresh_matches();
+        Some(tag)
+    }
+
+    /// Removes all tags.
+    pub fn clear(&mut self) {
+        self.tags.clear();
+        self.refresh_matches();
+    }
+
+    /// Recomputes the suggestions matching the typed prefix, excluding tags
+    /// that are already present.
+    fn refresh_matches(&mut self) {
+        let typed = self.input.value().trim().to_lowercase();
+        self.matches = self
+            .suggestions
+            .iter()
+            .filter(|s| !typed.is_empty() && s.to_lowercase().starts_with(&typed))
+            .filter(|s| self.allow_duplicates || !self.tags.contains(s))
+            .take(self.max_suggestions)
+            .cloned()
+            .collect();
+        self.highlighted = self.highlighted.filter(|&i| i < self.matches.len());
+    }
+
+    fn changed(&self) -> Cmd {
+        let id = self.id;
+        let tags = self.tags.clone();
+        bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ChangeMsg {
+                id,
+                tags: tags.clone(),
+            }) as Msg
+        })
+    }
+
+    fn commit(&mut self) -> Option<Cmd> {
+        let text = match self.highlighted {
+            Some(i) => self.matches[i].clone(),
+            None => self.input.value(),
+        };
+        if text.trim().is_empty() {
+            return None;
+        }
+        match self.add_tag(&text) {
+            Ok(()) => {
+                self.err = None;
+                self.input.set_value("");
+                self.highlighted = None;
+                self.refresh_matches();
+                Some(self.changed())
+            }
+            Err(e) => {
+                self.err = Some(e);
+                None
+            }
+        }
+    }
+
+    /// Handles key input, returning a [`ChangeMsg`] command when the tags
+    /// changed. Other keys go to the text field.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.input.focused() {
+            return None;
+        }
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.keymap.add.matches(key_msg) {
+                return self.commit();
+            }
+            if self.keymap.remove_last.matches(key_msg) && self.input.value().is_empty() {
+                self.err = None;
+                return self.tags.pop().map(|_| {
+                    self.refresh_matches();
+                    self.changed()
+                });
+            }
+            if self.keymap.next_suggestion.matches(key_msg) {
+                if !self.matches.is_empty() {
+                    self.highlighted = Some(match self.highlighted {
+                        Some(i) => (i + 1) % self.matches.len(),
+                        None => 0,
+                    });
+                }
+                return None;
+            }
+            if self.keymap.prev_suggestion.matches(key_msg) {
+                if !self.matches.is_empty() {
+                    let len = self.matches.len();
+                    self.highlighted = Some(match self.highlighted {
+                        Some(i) => (i + len - 1) % len,
+                        None => len - 1,
+                    });
+                }
+                return None;
+            }
+            if self.keymap.complete.matches(key_msg) {
+                if let Some(s) = self.matches.get(self.highlighted.unwrap_or(0)).cloned() {
+                    self.input.set_value(&s);
+                    self.input.cursor_end();
+                    self.highlighted = None;
+                    self.refresh_matches();
+                }
+                return None;
+            }
+        }
+        let cmd = self.input.update(msg);
+        self.err = None;
+        self.highlighted = None;
+        self.refresh_matches();
+        cmd
+    }
+
+    /// Renders the chips and field, followed by the suggestion dropdown and
+    /// any error.
+    pub fn view(&self) -> String {
+        let mut line: Vec<String> = self
+            .tags
+            .iter()
+            .map(|t| self.styles.tag.render(t))
+            .collect();
+        line.push(self.input.view());
+        let mut lines = vec![line.join(" ")];
+        if self.input.focused() {
+            for (i, s) in self.matches.iter().enumerate() {
+                lines.push(if self.highlighted == Some(i) {
+                    self.styles.selected_suggestion.render(&format!("> {}", s))
+                } else {
+                    self.styles.suggestion.render(&format!("  {}", s))
+                });
+            }
+        }
+        if let Some(err) = &self.err {
+            lines.push(self.styles.error.render(&err.to_string()));
+        }
+        lines.join("\n")
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        Some(self.input.focus())
+    }
+
+    fn blur(&mut self) {
+        self.input.blur();
+        self.highlighted = None;
+    }
+
+    fn focused(&self) -> bool {
+        self.input.focused()
+    }
+}
+
+/// Saved tags and typed text of a tag input; see
+/// [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The tags, in order.
+    pub tags: Vec<String>,
+    /// Text typed but not yet turned into a tag.
+    pub input: String,
+}
+
+/// Restoring adds the tags one by one, so tags beyond
+/// [`max_tags`](Model::max_tags) or unwanted duplicates are dropped.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            tags: self.tags.clone(),
+            input: self.input.value(),
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.tags.clear();
+        for tag in &state.tags {
+            let _ = self.add_tag(tag);
+        }
+        self.err = None;
+        self.highlighted = None;
+        self.input.set_value(&state.input);
+        self.refresh_matches();
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn type_str(m: &mut Model, s: &str) {
+        for c in s.chars() {
+            m.update(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_enter_and_comma_add_tags() {
+        let mut m = Model::new();
+        type_str(&mut m, "rust");
+        assert!(m.update(key(KeyCode::Enter)).is_some());
+        type_str(&mut m, "tui,");
+        assert_eq!(m.tags(), ["rust", "tui"]);
+        assert_eq!(m.input.value(), "");
+    }
+
+    #[test]
+    fn test_backspace_removes_last_tag_only_when_empty() {
+        let mut m = Model::new().with_tags(vec!["a".into(), "b".into()]);
+        type_str(&mut m, "c");
+        m.update(key(KeyCode::Backspace));
+        assert_eq!(m.tags().len(), 2);
+        assert!(m.update(key(KeyCode::Backspace)).is_some());
+        assert_eq!(m.tags(), ["a"]);
+    }
+
+    #[test]
+    fn test_max_tags_and_duplicates_rejected() {
+        let mut m = Model::new().with_max_tags(1);
+        assert!(m.add_tag("x").is_ok());
+        type_str(&mut m, "y");
+        assert!(m.update(key(KeyCode::Enter)).is_none());
+        assert!(m.error().is_some());
+        let mut m = Model::new();
+        m.add_tag("x").unwrap();
+        assert!(m.add_tag(" x ").is_err());
+    }
+
+    #[test]
+    fn test_suggestions_filter_and_select() {
+        let mut m =
+            Model::new().with_suggestions(vec!["backend".into(), "bug".into(), "docs".into()]);
+        type_str(&mut m, "b");
+        assert_eq!(m.matches(), ["backend", "bug"]);
+        m.update(key(KeyCode::Down));
+        m.update(key(KeyCode::Down));
+        m.update(key(KeyCode::Enter));
+        assert_eq!(m.tags(), ["bug"]);
+        type_str(&mut m, "b");
+        assert_eq!(m.matches(), ["backend"]);
+        m.update(key(KeyCode::Tab));
+        assert_eq!(m.input.value(), "backend");
+    }
+}
@@ -0,0 +1,401 @@
+//! Headless testing for components and whole programs, available with the
+//! `testutil` feature.
+//!
+//! A [`Driver`] plays the part of the bubbletea-rs program without a
+//! terminal: it feeds messages to a model, runs the commands the model
+//! returns and records every frame the model renders. Commands run on a
+//! paused clock, so a spinner tick or a one-minute timer resolves at once
+//! while still firing in the order the real program would see them.
+//!
+//! Frames can be compared against golden files with [`assert_golden`]. The
+//! comparison uses [`normalize`]d text, which drops ANSI styling and
+//! trailing whitespace so goldens survive color and padding changes. Set
+//! the `UPDATE_GOLDEN` environment variable to rewrite goldens instead of
+//! comparing; missing goldens are written on first use.
+//!
+//! Enable the feature for tests only:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! bubbletea-widgets = { version = "...", features = ["testutil"] }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::testutil::Driver;
+//! use bubbletea_widgets::{textinput, timer};
+//! use crossterm::event::KeyCode;
+//! use std::time::Duration;
+//!
+//! let mut input = textinput::new();
+//! std::mem::drop(input.focus());
+//! let mut driver = Driver::new(input);
+//! driver.type_text("hello").key(KeyCode::Left).type_text("!");
+//! assert_eq!(driver.widget().value(), "hell!o");
+//!
+//! // A minute-long timer runs to the end without waiting a minute
+//! let t = timer::new_with_interval(Duration::from_secs(60), Duration::from_secs(1));
+//! let init = t.init();
+//! let mut driver = Driver::new(t);
+//! driver.run_cmd(init);
+//! assert!(driver.run_until(|screen| screen == "0s", 100));
+//! ```
+
+use crate::widget::Widget;
+use bubbletea_rs::event::{BatchCmdMsg, BatchMsgInternal};
+use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg, QuitMsg, WindowSizeMsg};
+use crossterm::event::{KeyCode, KeyModifiers};
+use lipgloss_extras::lipgloss;
+use std::path::Path;
+use tokio::runtime::Runtime;
+use tokio::task::JoinSet;
+
+/// Drives a model headlessly: sends messages, runs commands and records
+/// frames.
+///
+/// Create one with [`Driver::new`] for anything implementing
+/// [`Widget`], or with [`Driver::app`] or [`Driver::init`] for an
+/// application model implementing bubbletea-rs's `Model`.
+pub struct Driver<W> {
+    widget: W,
+    update: fn(&mut W, Msg) -> Option<Cmd>,
+    view: fn(&W) -> String,
+    runtime: Runtime,
+    pending: JoinSet<Option<Msg>>,
+    frames: Vec<String>,
+    quit: bool,
+}
+
+impl<W: Widget> Driver<W> {
+    /// Wraps a widget. Messages reach it through [`Widget::update`].
+    pub fn new(widget: W) -> Self {
+        Self::with_fns(widget, |w, msg| w.update(&msg), |w| w.view())
+    }
+
+    /// Gives the widget `width` x `height` cells through
+    /// [`Widget::set_size`] and sends the matching `WindowSizeMsg`.
+    pub fn resize(&mut self, width: u16, height: u16) -> &mut Self {
+        self.widget.set_size(width as usize, height as usize);
+        self.send(Box::new(WindowSizeMsg { width, height }))
+    }
+}
+
+impl<M: BubbleTeaModel> Driver<M> {
+    /// Wraps an application model. Messages reach it through its own
+    /// `update`, so application messages arrive too.
+    pub fn app(model: M) -> Self {
+        Self::with_fns(model, |m, msg| m.update(msg), |m| m.view())
+    }
+
+    /// Creates the model with its `init` and runs the returned command
+    /// like the program would.
+    pub fn init() -> Self {
+        let (model, cmd) = M::init();
+        let mut driver = Self::app(model);
+        if let Some(cmd) = cmd {
+            driver.run_cmd(cmd);
+        }
+        driver
+    }
+}
+
+impl<W> Driver<W> {
+    fn with_fns(widget: W, update: fn(&mut W, Msg) -> Option<Cmd>, view: fn(&W) -> String) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(true)
+            .build()
+            .expect("failed to build the test runtime");
+        let mut driver = Self {
+            widget,
+            update,
+            view,
+            runtime,
+            pending: JoinSet::new(),
+            frames: Vec::new(),
+            quit: false,
+        };
+        driver.record();
+        driver
+    }
+
+    /// Returns the model.
+    pub fn widget(&self) -> &W {
+        &self.widget
+    }
+
+    /// Returns the model mutably, for setup between messages.
+    pub fn widget_mut(&mut self) -> &mut W {
+        &mut self.widget
+    }
+
+    /// Returns the model, dropping any commands still pending.
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+
+    /// Sends a message, records the frame and queues the returned command.
+    pub fn send(&mut self, msg: Msg) -> &mut Self {
+        if msg.is::<BatchMsgInternal>() {
+            if let Ok(batch) = msg.downcast::<BatchMsgInternal>() {
+                for msg in batch.messages {
+                    self.send(msg);
+                }
+            }
+            return self;
+        }
+        if msg.is::<BatchCmdMsg>() {
+            if let Ok(batch) = msg.downcast::<BatchCmdMsg>() {
+                for cmd in batch.0 {
+                    self.run_cmd(cmd);
+                }
+            }
+            return self;
+        }
+        if msg.is::<QuitMsg>() {
+            self.quit = true;
+        }
+        // Inside the runtime, tokio's clock reads the paused virtual time
+        let cmd = {
+            let _guard = self.runtime.enter();
+            (self.update)(&mut self.widget, msg)
+        };
+        self.record();
+        if let Some(cmd) = cmd {
+            self.run_cmd(cmd);
+        }
+        self
+    }
+
+    /// Sends a key press without modifiers.
+    pub fn key(&mut self, code: KeyCode) -> &mut Self {
+        self.key_with(code, KeyModifiers::NONE)
+    }
+
+    /// Sends a key press with modifiers, such as `ctrl+c`.
+    pub fn key_with(&mut self, code: KeyCode, modifiers: KeyModifiers) -> &mut Self {
+        self.send(Box::new(KeyMsg {
+            key: code,
+            modifiers,
+        }))
+    }
+
+    /// Types `text` one character at a time.
+    pub fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            self.key(KeyCode::Char(c));
+        }
+        self
+    }
+
+    /// Queues a command, such as the one returned by a component's `init`
+    /// or `focus`. It runs on the next [`step`](Self::step).
+    pub fn run_cmd(&mut self, cmd: Cmd) -> &mut Self {
+        let _guard = self.runtime.enter();
+        self.pending.spawn(cmd);
+        self
+    }
+
+    /// Returns the number of commands that have not finished yet.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Waits for the next command to finish and sends its message.
+    /// Returns `false` when no command is pending.
+    ///
+    /// The clock only moves while every command is waiting, and then
+    /// jumps straight to the next timer, so this never sleeps.
+    pub fn step(&mut self) -> bool {
+        let Some(done) = self.runtime.block_on(self.pending.join_next()) else {
+            return false;
+        };
+        match done {
+            Ok(Some(msg)) => {
+                self.send(msg);
+            }
+            Ok(None) => {}
+            Err(err) => std::panic::resume_unwind(err.into_panic()),
+        }
+        true
+    }
+
+    /// Takes up to `max_steps` steps, returning how many were taken.
+    ///
+    /// Components that animate forever, such as spinners, always have a
+    /// command pending, so the limit is what stops them.
+    pub fn run(&mut self, max_steps: usize) -> usize {
+        (0..max_steps).take_while(|_| self.step()).count()
+    }
+
+    /// Steps until the [`normalize`]d screen satisfies `done`, taking at
+    /// most `max_steps` steps. Returns whether it did.
+    pub fn run_until(&mut self, mut done: impl FnMut(&str) -> bool, max_steps: usize) -> bool {
+        for _ in 0..max_steps {
+            if done(&self.screen()) {
+                return true;
+            }
+            if !self.step() {
+                break;
+            }
+        }
+        done(&self.screen())
+    }
+
+    /// Returns whether the model sent a quit message.
+    pub fn quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Returns the current view, styling included.
+    pub fn view(&self) -> String {
+        (self.view)(&self.widget)
+    }
+
+    /// Returns the current view, [`normalize`]d.
+    pub fn screen(&self) -> String {
+        normalize(&self.view())
+    }
+
+    /// Returns every distinct frame rendered so far, oldest first. A frame
+    /// is recorded at the start and after each message that changed the
+    /// view.
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Compares the current screen against the golden file at `path`; see
+    /// [`assert_golden`].
+    #[track_caller]
+    pub fn assert_golden(&self, path: impl AsRef<Path>) {
+        assert_golden(path, &self.screen());
+    }
+
+    fn record(&mut self) {
+        let frame = self.view();
+        if self.frames.last() != Some(&frame) {
+            self.frames.push(frame);
+        }
+    }
+}
+
+/// Prepares a frame for comparison: strips ANSI escape sequences, turns
+/// `\r\n` into `\n`, trims trailing whitespace from each line and drops
+/// trailing blank lines.
+pub fn normalize(frame: &str) -> String {
+    let plain = lipgloss::strip_ansi(frame).replace("\r\n", "\n");
+    let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+    let end = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(0, |i| i + 1);
+    lines[..end].join("\n")
+}
+
+/// Compares `actual` against the golden file at `path`, panicking with
+/// the first differing line on a mismatch. Both sides are
+/// [`normalize`]d first.
+///
+/// When the file does not exist, or the `UPDATE_GOLDEN` environment
+/// variable is set, the file is written instead, creating its directory
+/// if needed.
+#[track_caller]
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    let actual = normalize(actual);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() || !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .unwrap_or_else(|e| panic!("cannot create {}: {}", dir.display(), e));
+        }
+        std::fs::write(path, format!("{}\n", actual))
+            .unwrap_or_else(|e| panic!("cannot write {}: {}", path.display(), e));
+        return;
+    }
+    let expected = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+    let expected = normalize(&expected);
+    if expected == actual {
+        return;
+    }
+    let (exp, act): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+    let line = (0..exp.len().max(act.len()))
+        .find(|&i| exp.get(i) != act.get(i))
+        .unwrap_or(0);
+    panic!(
+        "frame does not match {} at line {}\n  expected: {:?}\n    actual: {:?}\n\n{}\n\nrerun with UPDATE_GOLDEN=1 to accept the new frame",
+        path.display(),
+        line + 1,
+        exp.get(line).copied().unwrap_or("<end>"),
+        act.get(line).copied().unwrap_or("<end>"),
+        actual
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{spinner, textinput};
+
+    #[test]
+    fn test_normalize_strips_style_and_trailing_space() {
+        let styled = lipgloss::Style::new().bold(true).render("hi");
+        let frame = format!("{}   \r\n  x \n\n", styled);
+        assert_eq!(normalize(&frame), "hi\n  x");
+    }
+
+    #[test]
+    fn test_typing_records_distinct_frames() {
+        let mut input = textinput::new();
+        std::mem::drop(input.focus());
+        let mut driver = Driver::new(input);
+        driver.type_text("ab").key(KeyCode::Left);
+        assert_eq!(driver.widget().value(), "ab");
+        // The initial frame plus one per character; moving the cursor
+        // changes the styled frame too
+        assert_eq!(driver.frames().len(), 4);
+        assert!(driver.screen().contains("ab"));
+    }
+
+    #[test]
+    #[cfg(feature = "stopwatch")]
+    fn test_commands_run_on_a_paused_clock() {
+        use std::time::Duration;
+
+        let sw = crate::stopwatch::new_with_interval(Duration::from_secs(10));
+        let start = sw.start();
+        let mut driver = Driver::new(sw);
+        driver.run_cmd(start);
+        // Six ticks of ten seconds without waiting a minute
+        driver.run(7);
+        assert_eq!(driver.widget().elapsed(), Duration::from_secs(60));
+        assert_eq!(driver.pending(), 1);
+    }
+
+    #[test]
+    fn test_run_stops_at_the_step_limit() {
+        let s = spinner::new(&[]);
+        let tick = s.tick_msg();
+        let mut driver = Driver::new(s);
+        driver.send(Box::new(tick));
+        assert_eq!(driver.run(5), 5);
+        assert!(driver.frames().len() > 1);
+    }
+
+    #[test]
+    fn test_golden_writes_then_compares() {
+        let dir =
+            std::env::temp_dir().join(format!("bubbletea-widgets-golden-{}", std::process::id()));
+        let path = dir.join("frame.golden");
+        let _ = std::fs::remove_file(&path);
+        assert_golden(&path, "one  \ntwo\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\n");
+        assert_golden(&path, "\x1b[1mone\x1b[0m\ntwo");
+
+        let mismatch = std::panic::catch_unwind(|| assert_golden(&path, "one\nthree"));
+        let message = *mismatch.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("at line 2"), "{}", message);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
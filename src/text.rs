@@ -0,0 +1,325 @@
+//! Measuring, cutting and padding text by display width.
+//!
+//! Terminal layouts are counted in columns, not bytes or characters: `"é"`
+//! is two bytes but one column, `"世"` is one character but two columns,
+//! and an escape sequence such as `"\x1b[1m"` takes no columns at all. The
+//! helpers here do that counting in one place so every widget lines up the
+//! same way with CJK text, emoji and styled strings.
+//!
+//! - [`width`] measures a string, ignoring ANSI styling.
+//! - [`truncate`] shortens a string to a width, ending it with [`ELLIPSIS`].
+//! - [`pad`] and [`fit`] fill a string out to an exact width.
+//! - [`slice`] cuts out a range of columns, as horizontal scrolling does.
+//!
+//! All of them keep escape sequences intact, so styled text stays styled
+//! after it has been cut.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::text::{self, Align};
+//!
+//! assert_eq!(text::width("日本語"), 6);
+//! assert_eq!(text::truncate("日本語テキスト", 7), "日本語…");
+//! assert_eq!(text::pad("日本", 6, Align::Center), " 日本 ");
+//! assert_eq!(text::fit("overflowing", 6, Align::Left), "overf…");
+//! assert_eq!(text::slice("ab日本cd", 3, 7), " 本c");
+//! ```
+
+use lipgloss_extras::lipgloss;
+use std::iter::Peekable;
+use std::str::Chars;
+use unicode_width::UnicodeWidthChar;
+
+/// The tail [`truncate`] puts on text it had to shorten.
+pub const ELLIPSIS: &str = "…";
+
+/// Horizontal placement of text within a fixed width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// Flush left.
+    #[default]
+    Left,
+    /// Centered, with any odd column of padding on the right.
+    Center,
+    /// Flush right.
+    Right,
+}
+
+/// Returns the display width of `s` in terminal columns: the width of its
+/// widest line, not counting escape sequences.
+pub fn width(s: &str) -> usize {
+    lipgloss::width(s)
+}
+
+/// Shortens `s` to at most `width` columns, replacing the cut-off end with
+/// [`ELLIPSIS`]. Text that already fits is returned unchanged.
+pub fn truncate(s: &str, width: usize) -> String {
+    truncate_with(s, width, ELLIPSIS)
+}
+
+/// Like [`truncate`], with a custom `tail` in place of the ellipsis. An
+/// empty tail cuts the text off without any marker.
+pub fn truncate_with(s: &str, width: usize, tail: &str) -> String {
+    if self::width(s) <= width {
+        return s.to_string();
+    }
+    let tail_width = self::width(tail);
+    if tail_width > width {
+        return slice(tail, 0, width);
+    }
+    let mut out = String::new();
+    let mut rest = String::new();
+    let mut col = 0;
+    let mut cut = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            let seq = read_escape(&mut chars);
+            if cut {
+                rest.push_str(&seq);
+            } else {
+                out.push_str(&seq);
+            }
+            continue;
+        }
+        if cut {
+            continue;
+        }
+        let w = c.width().unwrap_or(0);
+        if col + w + tail_width > width {
+            cut = true;
+            continue;
+        }
+        out.push(c);
+        col += w;
+    }
+    out.push_str(tail);
+    // Closing escapes such as resets still apply after the tail.
+    out.push_str(&rest);
+    out
+}
+
+/// Pads `s` with spaces to `width` columns, placing it by `align`. Text
+/// that is already as wide or wider is returned unchanged; use [`fit`] to
+/// cut it as well.
+pub fn pad(s: &str, width: usize, align: Align) -> String {
+    let fill = width.saturating_sub(self::width(s));
+    let (left, right) = match align {
+        Align::Left => (0, fill),
+        Align::Center => (fill / 2, fill - fill / 2),
+        Align::Right => (fill, 0),
+    };
+    format!("{}{}{}", " ".repeat(left), s, " ".repeat(right))
+}
+
+/// Makes `s` exactly `width` columns wide: [`truncate`]s it if it is too
+/// long and [`pad`]s it if it is too short.
+pub fn fit(s: &str, width: usize, align: Align) -> String {
+    pad(&truncate(s, width), width, align)
+}
+
+/// Returns the columns `start..end` of a single line. Escape sequences are
+/// kept wherever they occur, so the slice has the styling it had in the
+/// full line. A wide character cut by either edge becomes spaces, keeping
+/// the slice aligned with its neighbours; the slice is not padded past the
+/// end of the text.
+pub fn slice(s: &str, start: usize, end: usize) -> String {
+    let mut out = String::new();
+    let mut col = 0;
+    let mut kept = false;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            out.push_str(&read_escape(&mut chars));
+            continue;
+        }
+        let w = c.width().unwrap_or(0);
+        if w == 0 {
+            // Combining marks go wherever their base character went.
+            if kept {
+                out.push(c);
+            }
+            continue;
+        }
+        kept = col >= start && col + w <= end;
+        if kept {
+            out.push(c);
+        } else if col < end && col + w > start {
+            let visible = (col + w).min(end) - col.max(start);
+            out.push_str(&" ".repeat(visible));
+        }
+        col += w;
+    }
+    out
+}
+
+/// Reads the rest of an escape sequence whose `ESC` was just consumed.
+/// Handles CSI (`ESC [ … final`) and OSC (`ESC ] … BEL` or `ESC ] … ESC \`)
+/// sequences; anything else is taken as a two-character sequence.
+pub(crate) fn read_escape(chars: &mut Peekable<Chars<'_>>) -> String {
+    let mut seq = String::from('\x1b');
+    match chars.next() {
+        Some('[') => {
+            seq.push('[');
+            for c in chars.by_ref() {
+                seq.push(c);
+                if ('\x40'..='\x7e').contains(&c) {
+                    break;
+                }
+            }
+        }
+        Some(']') => {
+            seq.push(']');
+            while let Some(c) = chars.next() {
+                seq.push(c);
+                if c == '\x07' {
+                    break;
+                }
+                if c == '\x1b' && chars.peek() == Some(&'\\') {
+                    seq.push(chars.next().unwrap_or('\\'));
+                    break;
+                }
+            }
+        }
+        Some(c) => seq.push(c),
+        None => {}
+    }
+    seq
+}
+
+#[cfg(any(feature = "chat", feature = "tooltip"))]
+/// Wraps `s` at word boundaries to lines of at most `width` columns,
+/// breaking words that are longer than a line.
+pub(crate) fn wrap(s: &str, width: usize) -> Vec<String> {
+    let mut out = Vec::new();
+    for paragraph in s.split('\n') {
+        let mut line = String::new();
+        let mut line_w = 0;
+        for word in paragraph.split(' ').filter(|w| !w.is_empty()) {
+            let word_w = self::width(word);
+            if line_w > 0 && line_w + 1 + word_w > width {
+                out.push(std::mem::take(&mut line));
+                line_w = 0;
+            } else if line_w > 0 {
+                line.push(' ');
+                line_w += 1;
+            }
+            for c in word.chars() {
+                let cw = c.width().unwrap_or(0);
+                if line_w + cw > width && line_w > 0 {
+                    out.push(std::mem::take(&mut line));
+                    line_w = 0;
+                }
+                line.push(c);
+                line_w += cw;
+            }
+        }
+        out.push(line);
+    }
+    out
+}
+
+#[cfg(any(feature = "logview", feature = "markdown"))]
+/// Highlights case-insensitive occurrences of `needle` in an unstyled line.
+pub(crate) fn highlight(line: &str, needle: &str, style: &lipgloss::Style) -> String {
+    let lower = line.to_lowercase();
+    // Lowercasing can change byte lengths for some scripts; fall back to
+    // highlighting the whole line rather than slicing at a bad offset.
+    if lower.len() != line.len() {
+        return style.render(line);
+    }
+    let mut out = String::new();
+    let mut pos = 0;
+    while let Some(found) = lower[pos..].find(needle) {
+        let start = pos + found;
+        let end = start + needle.len();
+        out.push_str(&line[pos..start]);
+        out.push_str(&style.render(&line[start..end]));
+        pos = end;
+    }
+    out.push_str(&line[pos..]);
+    out
+}
+
+#[cfg(any(feature = "chat", feature = "inbox", feature = "logview"))]
+/// Formats the time of day (UTC) as `HH:MM:SS`.
+pub(crate) fn format_time(time: std::time::SystemTime) -> String {
+    let secs = time
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+        % 86_400;
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "chat", feature = "tooltip"))]
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+        assert_eq!(wrap("a\n\nb", 5), vec!["a", "", "b"]);
+    }
+
+    #[test]
+    fn test_width_counts_columns_not_chars() {
+        assert_eq!(width("abc"), 3);
+        assert_eq!(width("日本"), 4);
+        assert_eq!(width("👋 hi"), 5);
+        assert_eq!(width("e\u{301}"), 1);
+        assert_eq!(width("\x1b[1mbold\x1b[0m"), 4);
+        assert_eq!(width("short\nlonger line"), 11);
+    }
+
+    #[test]
+    fn test_truncate_never_exceeds_width() {
+        assert_eq!(truncate("hello", 5), "hello");
+        assert_eq!(truncate("hello world", 5), "hell…");
+        // A wide character that would straddle the limit is dropped.
+        assert_eq!(truncate("日本語", 4), "日…");
+        assert_eq!(truncate("👋👋👋", 5), "👋👋…");
+        assert_eq!(truncate("hello", 0), "");
+        assert_eq!(truncate_with("hello world", 5, ""), "hello");
+        assert_eq!(truncate_with("hello world", 2, "..."), "..");
+        for w in 0..8 {
+            assert!(width(&truncate("日本語テキスト", w)) <= w);
+        }
+    }
+
+    #[test]
+    fn test_truncate_keeps_styling() {
+        let styled = "\x1b[1mhello world\x1b[0m";
+        assert_eq!(truncate(styled, 5), "\x1b[1mhell…\x1b[0m");
+    }
+
+    #[test]
+    fn test_pad_and_fit() {
+        assert_eq!(pad("ab", 5, Align::Left), "ab   ");
+        assert_eq!(pad("ab", 5, Align::Right), "   ab");
+        assert_eq!(pad("ab", 5, Align::Center), " ab  ");
+        assert_eq!(pad("日本", 5, Align::Left), "日本 ");
+        assert_eq!(pad("toolong", 3, Align::Left), "toolong");
+        assert_eq!(fit("toolong", 3, Align::Left), "to…");
+        assert_eq!(fit("日本語", 4, Align::Right), " 日…");
+        assert_eq!(width(&fit("\x1b[31m日本\x1b[0m", 6, Align::Left)), 6);
+    }
+
+    #[test]
+    fn test_slice_by_columns() {
+        assert_eq!(slice("hello world", 6, 11), "world");
+        assert_eq!(slice("hello", 3, 10), "lo");
+        assert_eq!(slice("hello", 7, 10), "");
+        // Wide characters cut by an edge keep their columns as spaces.
+        assert_eq!(slice("日本語", 1, 5), " 本 ");
+        assert_eq!(slice("日本語", 2, 4), "本");
+        assert_eq!(slice("e\u{301}x", 0, 1), "e\u{301}");
+        assert_eq!(
+            slice("\x1b[32mgreen\x1b[0m text", 2, 8),
+            "\x1b[32meen\x1b[0m te"
+        );
+    }
+}
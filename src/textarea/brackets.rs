@@ -0,0 +1,65 @@
+//! Bracket matching for the text area.
+
+use super::buffer::Buffer;
+
+/// The bracket that pairs with `ch`, and whether it comes after `ch`.
+fn partner(ch: char) -> Option<(char, bool)> {
+    match ch {
+        '(' => Some((')', true)),
+        '[' => Some((']', true)),
+        '{' => Some(('}', true)),
+        ')' => Some(('(', false)),
+        ']' => Some(('[', false)),
+        '}' => Some(('{', false)),
+        _ => None,
+    }
+}
+
+/// Finds the bracket at `(row, col)`, or failing that the one just before
+/// it, and the bracket that matches it. Returns both positions, or `None`
+/// when there is no bracket there or it is unmatched.
+pub(super) fn matching(
+    value: &Buffer,
+    row: usize,
+    col: usize,
+) -> Option<((usize, usize), (usize, usize))> {
+    let line = value.get(row)?;
+    let at = [Some(col), col.checked_sub(1)]
+        .into_iter()
+        .flatten()
+        .find(|&c| line.get(c).copied().and_then(partner).is_some())?;
+    let bracket = line[at];
+    let (other, forward) = partner(bracket)?;
+
+    let mut depth = 0usize;
+    let (mut r, mut c) = (row, at);
+    loop {
+        // Step to the next position in the scan direction
+        if forward {
+            c += 1;
+            while c >= value[r].len() {
+                r += 1;
+                c = 0;
+                if r >= value.len() {
+                    return None;
+                }
+            }
+        } else {
+            while c == 0 {
+                r = r.checked_sub(1)?;
+                c = value[r].len();
+            }
+            c -= 1;
+        }
+
+        let ch = value[r][c];
+        if ch == bracket {
+            depth += 1;
+        } else if ch == other {
+            if depth == 0 {
+                return Some(((row, at), (r, c)));
+            }
+            depth -= 1;
+        }
+    }
+}
@@ -0,0 +1,394 @@
+//! Line storage for the text area.
+//!
+//! Lines are kept in chunks that are shared copy-on-write. The snapshot
+//! the undo history takes before each edit then copies a pointer per chunk
+//! rather than the whole text, and comparing it with the text afterwards
+//! skips every chunk the edit did not touch.
+//!
+//! Each chunk also remembers how many rows its lines wrap to. Editing a
+//! line forgets its count and its chunk's total, so finding the rows above
+//! the cursor or the line at the top of the view only wraps lines that
+//! changed, and adds up a total per chunk for the rest.
+//!
+//! Every edit also gives the buffer a new version number, which clones
+//! share until one of them is edited, so whether two buffers hold the
+//! same text can often be told without looking at it.
+
+use std::ops::{Index, IndexMut, Range};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+static LAST_VERSION: AtomicU64 = AtomicU64::new(0);
+
+fn next_version() -> u64 {
+    LAST_VERSION.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Lines per chunk when a run of lines is split up.
+const CHUNK_LINES: usize = 512;
+
+/// The wrap width and tab width that row counts were taken at.
+pub(super) type WrapKey = (usize, usize);
+
+#[derive(Debug, Clone, Default)]
+struct Chunk {
+    lines: Vec<Vec<char>>,
+    /// Rows each line wraps to at `key`, or 0 when not counted yet
+    rows: Vec<usize>,
+    /// Sum of `rows`, once they are all counted
+    total: Option<usize>,
+    key: WrapKey,
+}
+
+impl Chunk {
+    fn new(lines: Vec<Vec<char>>, rows: Vec<usize>, key: WrapKey) -> Self {
+        Self {
+            lines,
+            rows,
+            total: None,
+            key,
+        }
+    }
+
+    /// Counts the rows of lines not counted yet at `key`.
+    fn count_rows(&mut self, key: WrapKey, wrap: &mut impl FnMut(&[char]) -> usize) -> usize {
+        if self.key != key {
+            self.key = key;
+            self.rows.fill(0);
+            self.total = None;
+        }
+        if let Some(total) = self.total {
+            return total;
+        }
+        let mut total = 0;
+        for (line, rows) in self.lines.iter().zip(&mut self.rows) {
+            if *rows == 0 {
+                *rows = wrap(line).max(1);
+            }
+            total += *rows;
+        }
+        self.total = Some(total);
+        total
+    }
+}
+
+/// The lines of a text area.
+#[derive(Debug, Clone, Default)]
+pub(super) struct Buffer {
+    chunks: Vec<Arc<Chunk>>,
+    /// Index of the first line of each chunk
+    starts: Vec<usize>,
+    len: usize,
+    /// Changed by every edit; buffers with the same version hold the
+    /// same lines
+    version: u64,
+}
+
+impl Buffer {
+    /// The number of lines.
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The version of the lines, which changes with every edit.
+    pub(super) fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Whether there are no lines at all.
+    pub(super) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The chunk holding line `index`, and the line's index within it.
+    fn locate(&self, index: usize) -> (usize, usize) {
+        let chunk = self.starts.partition_point(|&start| start <= index) - 1;
+        (chunk, index - self.starts[chunk])
+    }
+
+    pub(super) fn get(&self, index: usize) -> Option<&Vec<char>> {
+        if index >= self.len {
+            return None;
+        }
+        let (chunk, offset) = self.locate(index);
+        Some(&self.chunks[chunk].lines[offset])
+    }
+
+    /// Returns line `index` for editing, forgetting its row count.
+    pub(super) fn get_mut(&mut self, index: usize) -> Option<&mut Vec<char>> {
+        if index >= self.len {
+            return None;
+        }
+        let (chunk, offset) = self.locate(index);
+        self.version = next_version();
+        let chunk = Arc::make_mut(&mut self.chunks[chunk]);
+        chunk.rows[offset] = 0;
+        chunk.total = None;
+        Some(&mut chunk.lines[offset])
+    }
+
+    pub(super) fn iter(&self) -> impl Iterator<Item = &Vec<char>> {
+        self.iter_from(0)
+    }
+
+    /// The lines from line `index` on.
+    pub(super) fn iter_from(&self, index: usize) -> impl Iterator<Item = &Vec<char>> {
+        let (first, offset) = if index < self.len {
+            self.locate(index)
+        } else {
+            (self.chunks.len(), 0)
+        };
+        self.chunks[first..]
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, chunk)| chunk.lines[if i == 0 { offset } else { 0 }..].iter())
+    }
+
+    pub(super) fn insert(&mut self, index: usize, line: Vec<char>) {
+        self.splice(index..index, [line]);
+    }
+
+    pub(super) fn push(&mut self, line: Vec<char>) {
+        self.splice(self.len..self.len, [line]);
+    }
+
+    pub(super) fn remove(&mut self, index: usize) {
+        self.splice(index..index + 1, []);
+    }
+
+    /// Replaces the lines in `range` with `lines`. Only the chunks the
+    /// range touches are rebuilt.
+    pub(super) fn splice(
+        &mut self,
+        range: Range<usize>,
+        lines: impl IntoIterator<Item = Vec<char>>,
+    ) {
+        let Range { start, end } = range;
+        assert!(
+            start <= end && end <= self.len,
+            "line range {start}..{end} out of bounds of {} lines",
+            self.len
+        );
+        let lines: Vec<Vec<char>> = lines.into_iter().collect();
+        self.version = next_version();
+
+        // The chunks holding the range, or the one to insert into
+        let chunks = if self.chunks.is_empty() {
+            0..0
+        } else {
+            let first = self.locate(start.min(self.len - 1)).0;
+            let last = if end > start {
+                self.locate(end - 1).0
+            } else {
+                first
+            };
+            first..last + 1
+        };
+        let base = self.starts.get(chunks.start).copied().unwrap_or(0);
+        let key = self
+            .chunks
+            .get(chunks.start)
+            .map_or_else(WrapKey::default, |chunk| chunk.key);
+
+        let mut text = Vec::new();
+        let mut rows = Vec::new();
+        for chunk in self.chunks.drain(chunks.clone()) {
+            let chunk = Arc::unwrap_or_clone(chunk);
+            if chunk.key == key {
+                rows.extend(chunk.rows);
+            } else {
+                rows.extend(std::iter::repeat_n(0, chunk.lines.len()));
+            }
+            text.extend(chunk.lines);
+        }
+        let replaced = start - base..end - base;
+        rows.splice(replaced.clone(), std::iter::repeat_n(0, lines.len()));
+        text.splice(replaced, lines);
+
+        // Split long runs so a later edit copies at most a chunk's worth
+        let mut rebuilt = Vec::new();
+        if text.len() <= 2 * CHUNK_LINES {
+            if !text.is_empty() {
+                rebuilt.push(Arc::new(Chunk::new(text, rows, key)));
+            }
+        } else {
+            let mut rows = rows.into_iter();
+            let mut text = text.into_iter();
+            while text.len() > 0 {
+                let lines: Vec<_> = text.by_ref().take(CHUNK_LINES).collect();
+                let counts = rows.by_ref().take(lines.len()).collect();
+                rebuilt.push(Arc::new(Chunk::new(lines, counts, key)));
+            }
+        }
+        self.chunks.splice(chunks.start..chunks.start, rebuilt);
+
+        self.starts.clear();
+        self.len = 0;
+        for chunk in &self.chunks {
+            self.starts.push(self.len);
+            self.len += chunk.lines.len();
+        }
+    }
+
+    /// The rows chunk `i` wraps to at `key`, counting only lines that
+    /// changed since it was last counted.
+    fn chunk_rows(
+        &mut self,
+        i: usize,
+        key: WrapKey,
+        wrap: &mut impl FnMut(&[char]) -> usize,
+    ) -> usize {
+        let chunk = &self.chunks[i];
+        match chunk.total {
+            Some(total) if chunk.key == key => total,
+            _ => Arc::make_mut(&mut self.chunks[i]).count_rows(key, wrap),
+        }
+    }
+
+    /// The number of rows lines `range` wrap to at `key`, with `wrap`
+    /// counting the rows of a line.
+    pub(super) fn rows(
+        &mut self,
+        range: Range<usize>,
+        key: WrapKey,
+        mut wrap: impl FnMut(&[char]) -> usize,
+    ) -> usize {
+        let mut rows = 0;
+        for i in 0..self.chunks.len() {
+            let start = self.starts[i];
+            let end = start + self.chunks[i].lines.len();
+            if end <= range.start || start >= range.end {
+                continue;
+            }
+            let total = self.chunk_rows(i, key, &mut wrap);
+            if range.start <= start && end <= range.end {
+                rows += total;
+            } else {
+                let from = range.start.max(start) - start;
+                let to = range.end.min(end) - start;
+                rows += self.chunks[i].rows[from..to].iter().sum::<usize>();
+            }
+        }
+        rows
+    }
+
+    /// The line that row `row` of the wrapped text belongs to, and which
+    /// of the line's rows it is. Rows past the end give the last row.
+    #[cfg(any(test, feature = "mouse"))]
+    pub(super) fn line_at_row(
+        &mut self,
+        row: usize,
+        key: WrapKey,
+        mut wrap: impl FnMut(&[char]) -> usize,
+    ) -> (usize, usize) {
+        let mut above = 0;
+        for i in 0..self.chunks.len() {
+            let total = self.chunk_rows(i, key, &mut wrap);
+            if above + total <= row {
+                above += total;
+                continue;
+            }
+            for (offset, &rows) in self.chunks[i].rows.iter().enumerate() {
+                if above + rows > row {
+                    return (self.starts[i] + offset, row - above);
+                }
+                above += rows;
+            }
+        }
+        let last = self.len.saturating_sub(1);
+        let rows = self.rows(last..self.len, key, wrap);
+        (last, rows.saturating_sub(1))
+    }
+
+    /// Like `line_at_row`, for a shared buffer: rows
+    /// counted before are looked up, and the others are counted with `wrap`
+    /// without being kept.
+    pub(super) fn peek_line_at_row(
+        &self,
+        row: usize,
+        key: WrapKey,
+        mut wrap: impl FnMut(&[char]) -> usize,
+    ) -> (usize, usize) {
+        let mut above = 0;
+        let mut last = (0, 0);
+        for (chunk, start) in self.chunks.iter().zip(&self.starts) {
+            match chunk.total {
+                Some(total) if chunk.key == key && above + total <= row => {
+                    above += total;
+                    last = (
+                        start + chunk.lines.len() - 1,
+                        chunk.rows[chunk.rows.len() - 1],
+                    );
+                    continue;
+                }
+                _ => {}
+            }
+            for (offset, line) in chunk.lines.iter().enumerate() {
+                let rows = match chunk.rows[offset] {
+                    0 => wrap(line).max(1),
+                    _ if chunk.key != key => wrap(line).max(1),
+                    rows => rows,
+                };
+                if above + rows > row {
+                    return (start + offset, row - above);
+                }
+                above += rows;
+                last = (start + offset, rows);
+            }
+        }
+        // Past the end: the last row of the last line
+        let (line, rows) = last;
+        (line, rows.saturating_sub(1))
+    }
+}
+
+impl From<Vec<Vec<char>>> for Buffer {
+    fn from(lines: Vec<Vec<char>>) -> Self {
+        let mut buffer = Buffer::default();
+        buffer.splice(0..0, lines);
+        buffer
+    }
+}
+
+impl Index<usize> for Buffer {
+    type Output = Vec<char>;
+
+    fn index(&self, index: usize) -> &Vec<char> {
+        match self.get(index) {
+            Some(line) => line,
+            None => panic!("line {index} out of bounds of {} lines", self.len),
+        }
+    }
+}
+
+impl IndexMut<usize> for Buffer {
+    fn index_mut(&mut self, index: usize) -> &mut Vec<char> {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(line) => line,
+            None => panic!("line {index} out of bounds of {len} lines"),
+        }
+    }
+}
+
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        if self.version == other.version {
+            return true;
+        }
+        if self.len != other.len {
+            return false;
+        }
+        // Chunks still shared with a snapshot are equal without looking
+        if self.starts == other.starts {
+            return self
+                .chunks
+                .iter()
+                .zip(&other.chunks)
+                .all(|(a, b)| Arc::ptr_eq(a, b) || a.lines == b.lines);
+        }
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for Buffer {}
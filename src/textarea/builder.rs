@@ -0,0 +1,275 @@
+//! Fluent construction of a textarea.
+
+use super::helpers::{TextareaKeyMap, TextareaStyle};
+use super::{Model, DEFAULT_HEIGHT, DEFAULT_WIDTH};
+use crate::widget::Margins;
+
+/// A prompt function with the width of the prompts it returns.
+type PromptFunc = (usize, Box<dyn Fn(usize) -> String + Send + Sync>);
+
+/// Builds a [`Model`] from a chain of settings. Start one with
+/// [`Model::builder`].
+///
+/// Settings are applied in the order that gives the expected result
+/// regardless of the order they were chained in: the prompt and line
+/// numbers are in place before the width is split between them and the
+/// text, and the limits before the initial value is inserted.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::textarea::Model;
+///
+/// let ta = Model::builder()
+///     .width(30)
+///     .height(4)
+///     .show_line_numbers(false)
+///     .placeholder("Write a message…")
+///     .value("Hello")
+///     .build();
+/// assert_eq!(ta.value(), "Hello");
+/// assert_eq!(ta.height(), 4);
+/// ```
+pub struct Builder {
+    width: usize,
+    height: usize,
+    prompt: Option<String>,
+    prompt_func: Option<PromptFunc>,
+    placeholder: Option<String>,
+    value: Option<String>,
+    show_line_numbers: Option<bool>,
+    char_limit: Option<usize>,
+    max_height: Option<usize>,
+    max_width: Option<usize>,
+    max_lines: Option<usize>,
+    soft_wrap: Option<bool>,
+    overwrite: Option<bool>,
+    tab_width: Option<usize>,
+    soft_tabs: Option<bool>,
+    auto_indent: Option<bool>,
+    styles: Option<(TextareaStyle, TextareaStyle)>,
+    key_map: Option<TextareaKeyMap>,
+    auto_height: bool,
+    resize_margins: Option<Margins>,
+}
+
+impl Model {
+    /// Starts a [`Builder`] for a textarea.
+    pub fn builder() -> Builder {
+        Builder {
+            width: DEFAULT_WIDTH,
+            height: DEFAULT_HEIGHT,
+            prompt: None,
+            prompt_func: None,
+            placeholder: None,
+            value: None,
+            show_line_numbers: None,
+            char_limit: None,
+            max_height: None,
+            max_width: None,
+            max_lines: None,
+            soft_wrap: None,
+            overwrite: None,
+            tab_width: None,
+            soft_tabs: None,
+            auto_indent: None,
+            styles: None,
+            key_map: None,
+            auto_height: false,
+            resize_margins: None,
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the total width in columns, prompt and line numbers included.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height in rows.
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the prompt printed at the start of each line.
+    pub fn prompt(mut self, prompt: &str) -> Self {
+        self.prompt = Some(prompt.to_string());
+        self
+    }
+
+    /// Sets a function that returns the prompt for each display line; see
+    /// [`Model::set_prompt_func`].
+    pub fn prompt_func(
+        mut self,
+        prompt_width: usize,
+        func: impl Fn(usize) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.prompt_func = Some((prompt_width, Box::new(func)));
+        self
+    }
+
+    /// Sets the text shown while the textarea is empty.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the initial text, which is also the baseline for
+    /// [`Model::is_modified`].
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Shows or hides line numbers.
+    pub fn show_line_numbers(mut self, show: bool) -> Self {
+        self.show_line_numbers = Some(show);
+        self
+    }
+
+    /// Sets the most characters accepted; 0 means no limit.
+    pub fn char_limit(mut self, limit: usize) -> Self {
+        self.char_limit = Some(limit);
+        self
+    }
+
+    /// Sets the most rows the textarea grows to; 0 means no limit.
+    pub fn max_height(mut self, max_height: usize) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the most columns the textarea grows to; 0 means no limit.
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the most lines the text can hold; 0 means no limit.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets whether long lines wrap or scroll horizontally.
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = Some(soft_wrap);
+        self
+    }
+
+    /// Starts in overwrite mode, where typed characters replace the one
+    /// under the cursor.
+    pub fn overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = Some(overwrite);
+        self
+    }
+
+    /// Sets the number of columns between tab stops.
+    pub fn tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = Some(tab_width);
+        self
+    }
+
+    /// Chooses whether the tab key inserts spaces (the default) or a tab
+    /// character.
+    pub fn soft_tabs(mut self, soft_tabs: bool) -> Self {
+        self.soft_tabs = Some(soft_tabs);
+        self
+    }
+
+    /// Makes new lines start with the indentation of the line above.
+    pub fn auto_indent(mut self, auto_indent: bool) -> Self {
+        self.auto_indent = Some(auto_indent);
+        self
+    }
+
+    /// Sets the styles used while focused and while blurred.
+    pub fn styles(mut self, focused: TextareaStyle, blurred: TextareaStyle) -> Self {
+        self.styles = Some((focused, blurred));
+        self
+    }
+
+    /// Sets the key bindings.
+    pub fn key_map(mut self, key_map: TextareaKeyMap) -> Self {
+        self.key_map = Some(key_map);
+        self
+    }
+
+    /// Makes the height follow the text, up to the maximum height; see
+    /// [`Model::set_auto_height`].
+    pub fn auto_height(mut self, on: bool) -> Self {
+        self.auto_height = on;
+        self
+    }
+
+    /// Makes the textarea follow the terminal size minus `margins`; see
+    /// [`Model::auto_resize`].
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Creates the textarea. It starts blurred; focus it to take input.
+    pub fn build(self) -> Model {
+        let mut ta = Model::new();
+        if let Some(prompt) = self.prompt {
+            ta.prompt = prompt;
+        }
+        if let Some((prompt_width, func)) = self.prompt_func {
+            ta.set_prompt_func(prompt_width, func);
+        }
+        if let Some(placeholder) = self.placeholder {
+            ta.placeholder = placeholder;
+        }
+        if let Some(show) = self.show_line_numbers {
+            ta.show_line_numbers = show;
+        }
+        if let Some(limit) = self.char_limit {
+            ta.char_limit = limit;
+        }
+        if let Some(max_height) = self.max_height {
+            ta.max_height = max_height;
+        }
+        if let Some(max_width) = self.max_width {
+            ta.max_width = max_width;
+        }
+        if let Some(max_lines) = self.max_lines {
+            ta.set_max_lines(max_lines);
+        }
+        if let Some(soft_wrap) = self.soft_wrap {
+            ta.set_soft_wrap(soft_wrap);
+        }
+        if let Some(overwrite) = self.overwrite {
+            ta.set_overwrite(overwrite);
+        }
+        if let Some(tab_width) = self.tab_width {
+            ta.set_tab_width(tab_width);
+        }
+        if let Some(soft_tabs) = self.soft_tabs {
+            ta.soft_tabs = soft_tabs;
+        }
+        if let Some(auto_indent) = self.auto_indent {
+            ta.auto_indent = auto_indent;
+        }
+        if let Some((focused, blurred)) = self.styles {
+            ta.focused_style = focused;
+            ta.current_style = blurred.clone();
+            ta.blurred_style = blurred;
+        }
+        if let Some(key_map) = self.key_map {
+            ta.key_map = key_map;
+        }
+        ta.resize_margins = self.resize_margins;
+        ta.set_height(self.height);
+        ta.set_width(self.width);
+        if let Some(value) = self.value {
+            ta.set_value(value);
+            ta.set_baseline();
+        }
+        ta.set_auto_height(self.auto_height);
+        ta
+    }
+}
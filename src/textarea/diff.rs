@@ -0,0 +1,234 @@
+//! Word-level differences between the text and a saved baseline.
+//!
+//! Both texts are split into words, runs of whitespace and single
+//! punctuation characters, and the longest common subsequence of those
+//! tokens decides what was kept, inserted and deleted. The subsequence
+//! is found with Hirschberg's algorithm, which needs memory linear in
+//! the number of tokens rather than a table of every pair of them.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// One run of a word-level diff, as returned by
+/// [`Model::diff`](super::Model::diff).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// Text found in both the baseline and the current value.
+    Equal(String),
+    /// Text added since the baseline.
+    Insert(String),
+    /// Text removed since the baseline.
+    Delete(String),
+}
+
+impl Change {
+    /// The text of this run.
+    pub fn text(&self) -> &str {
+        match self {
+            Change::Equal(text) | Change::Insert(text) | Change::Delete(text) => text,
+        }
+    }
+}
+
+/// Whether the text differed from the baseline at one buffer version,
+/// kept so that asking again before the next edit does not compare the
+/// text again.
+#[derive(Debug)]
+pub(super) struct ModifiedCache(AtomicU64);
+
+impl ModifiedCache {
+    /// Nothing remembered; no buffer reaches this version.
+    const EMPTY: u64 = u64::MAX;
+
+    pub(super) fn get(&self, version: u64) -> Option<bool> {
+        let cached = self.0.load(Ordering::Relaxed);
+        (cached != Self::EMPTY && cached >> 1 == version).then_some(cached & 1 == 1)
+    }
+
+    pub(super) fn set(&self, version: u64, modified: bool) {
+        self.0
+            .store(version << 1 | u64::from(modified), Ordering::Relaxed);
+    }
+
+    pub(super) fn clear(&mut self) {
+        *self.0.get_mut() = Self::EMPTY;
+    }
+}
+
+impl Default for ModifiedCache {
+    fn default() -> Self {
+        Self(AtomicU64::new(Self::EMPTY))
+    }
+}
+
+impl Clone for ModifiedCache {
+    fn clone(&self) -> Self {
+        Self(AtomicU64::new(self.0.load(Ordering::Relaxed)))
+    }
+}
+
+/// Splits text into words, whitespace runs and punctuation characters.
+fn tokenize(text: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |ch: char| {
+        if ch.is_alphanumeric() || ch == '_' {
+            Class::Word
+        } else if ch.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut previous: Option<Class> = None;
+    for (i, ch) in text.char_indices() {
+        let current = class(ch);
+        let joins = previous
+            .as_ref()
+            .is_some_and(|p| *p == current && current != Class::Other);
+        if !joins && i > start {
+            tokens.push(&text[start..i]);
+            start = i;
+        }
+        previous = Some(current);
+    }
+    if start < text.len() {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+/// Appends a change, joining it to the last one if they are of the same
+/// kind.
+fn push(changes: &mut Vec<Change>, change: Change) {
+    match (changes.last_mut(), change) {
+        (Some(Change::Equal(text)), Change::Equal(more))
+        | (Some(Change::Insert(text)), Change::Insert(more))
+        | (Some(Change::Delete(text)), Change::Delete(more)) => text.push_str(&more),
+        (_, change) => changes.push(change),
+    }
+}
+
+/// Changes collected while aligning two token lists. The deletions and
+/// insertions between two equal runs are held back so that the
+/// deletions come first.
+#[derive(Default)]
+struct Edits {
+    changes: Vec<Change>,
+    deleted: String,
+    inserted: String,
+}
+
+impl Edits {
+    fn equal(&mut self, token: &str) {
+        self.flush();
+        push(&mut self.changes, Change::Equal(token.to_string()));
+    }
+
+    fn delete(&mut self, token: &str) {
+        self.deleted.push_str(token);
+    }
+
+    fn insert(&mut self, token: &str) {
+        self.inserted.push_str(token);
+    }
+
+    fn flush(&mut self) {
+        if !self.deleted.is_empty() {
+            push(
+                &mut self.changes,
+                Change::Delete(std::mem::take(&mut self.deleted)),
+            );
+        }
+        if !self.inserted.is_empty() {
+            push(
+                &mut self.changes,
+                Change::Insert(std::mem::take(&mut self.inserted)),
+            );
+        }
+    }
+}
+
+/// Length of the longest common subsequence of `a` and each prefix of
+/// `b`, keeping only one row of the table at a time.
+fn lcs_lengths<'a>(a: impl Iterator<Item = &'a str>, b: &[&str]) -> Vec<u32> {
+    let mut row = vec![0u32; b.len() + 1];
+    for token in a {
+        let mut diagonal = 0;
+        for (j, other) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if token == *other {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// Aligns `a` with `b` by splitting `a` in half and finding where in `b`
+/// the split falls on a longest common subsequence.
+fn align(a: &[&str], b: &[&str], edits: &mut Edits) {
+    match a {
+        [] => b.iter().for_each(|token| edits.insert(token)),
+        _ if b.is_empty() => a.iter().for_each(|token| edits.delete(token)),
+        [token] => match b.iter().position(|other| other == token) {
+            Some(j) => {
+                b[..j].iter().for_each(|other| edits.insert(other));
+                edits.equal(token);
+                b[j + 1..].iter().for_each(|other| edits.insert(other));
+            }
+            None => {
+                edits.delete(token);
+                b.iter().for_each(|other| edits.insert(other));
+            }
+        },
+        _ => {
+            let mid = a.len() / 2;
+            let front = lcs_lengths(a[..mid].iter().copied(), b);
+            let reversed: Vec<&str> = b.iter().rev().copied().collect();
+            let back = lcs_lengths(a[mid..].iter().rev().copied(), &reversed);
+            let split = (0..=b.len())
+                .max_by_key(|&j| (front[j] + back[b.len() - j], std::cmp::Reverse(j)))
+                .unwrap_or(0);
+            align(&a[..mid], &b[..split], edits);
+            align(&a[mid..], &b[split..], edits);
+        }
+    }
+}
+
+/// Word-level changes that turn `old` into `new`. Deletions come before
+/// the insertions that replace them.
+pub(super) fn diff(old: &str, new: &str) -> Vec<Change> {
+    let old = tokenize(old);
+    let new = tokenize(new);
+
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    let mut edits = Edits::default();
+    for token in &old[..prefix] {
+        edits.equal(token);
+    }
+    align(a, b, &mut edits);
+    for token in &old[old.len() - suffix..] {
+        edits.equal(token);
+    }
+    edits.flush();
+    edits.changes
+}
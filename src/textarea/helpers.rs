@@ -24,6 +24,8 @@
 //!     cursor_line_number: Style::new().foreground("#666666"),
 //!     end_of_buffer: Style::new().foreground("#3c3c3c"),
 //!     placeholder: Style::new().foreground("#666666"),
+//!     selection: Style::new().reverse(true),
+//!     matching_bracket: Style::new().bold(true).underline(true),
 //! };
 //! model.focused_style = custom.clone();
 //! model.blurred_style = custom;
@@ -54,6 +56,8 @@ pub struct TextareaKeyMap {
     pub delete_word_forward: key::Binding,
     /// Insert newline.
     pub insert_newline: key::Binding,
+    /// Insert a tab, or spaces up to the next tab stop.
+    pub insert_tab: key::Binding,
     /// Move cursor to end of line.
     pub line_end: key::Binding,
     /// Move cursor to next visual line.
@@ -64,6 +68,10 @@ pub struct TextareaKeyMap {
     pub line_start: key::Binding,
     /// Paste from clipboard.
     pub paste: key::Binding,
+    /// Copy the selection, or the current line, to the clipboard.
+    pub copy_selection: key::Binding,
+    /// Cut the selection, or the current line, to the clipboard.
+    pub cut_selection: key::Binding,
     /// Move one word left.
     pub word_backward: key::Binding,
     /// Move one word right.
@@ -81,6 +89,18 @@ pub struct TextareaKeyMap {
     pub capitalize_word_forward: key::Binding,
     /// Transpose the character to the left with the current one.
     pub transpose_character_backward: key::Binding,
+    /// Toggle between insert and overwrite mode.
+    pub toggle_overwrite: key::Binding,
+    /// Undo the last edit.
+    pub undo: key::Binding,
+    /// Redo the last undone edit.
+    pub redo: key::Binding,
+    /// Insert the most recently killed text.
+    pub yank: key::Binding,
+    /// Move the cursor to the bracket matching the one at the cursor.
+    pub jump_to_matching_bracket: key::Binding,
+    /// Replace the snippet trigger before the cursor with its body.
+    pub expand_snippet: key::Binding,
 }
 
 /// Implementation of KeyMap trait for help integration
@@ -115,6 +135,8 @@ impl crate::key::KeyMap for TextareaKeyMap {
                 &self.delete_character_backward,
                 &self.delete_character_forward,
                 &self.paste,
+                &self.copy_selection,
+                &self.cut_selection,
             ],
             vec![
                 &self.delete_word_backward,
@@ -122,6 +144,12 @@ impl crate::key::KeyMap for TextareaKeyMap {
                 &self.delete_after_cursor,
                 &self.delete_before_cursor,
             ],
+            vec![
+                &self.undo,
+                &self.redo,
+                &self.yank,
+                &self.jump_to_matching_bracket,
+            ],
         ]
     }
 }
@@ -195,6 +223,9 @@ impl Default for TextareaKeyMap {
             ])
             .with_help("enter/ctrl+m", "insert newline"),
 
+            insert_tab: key::Binding::new(vec![KeyPress::from(KeyCode::Tab)])
+                .with_help("tab", "insert tab"),
+
             delete_character_backward: key::Binding::new(vec![
                 KeyPress::from(KeyCode::Backspace),
                 KeyPress::from((KeyCode::Char('h'), KeyModifiers::CONTROL)),
@@ -225,6 +256,20 @@ impl Default for TextareaKeyMap {
             ))])
             .with_help("ctrl+v", "paste"),
 
+            // ctrl+c and ctrl+x usually quit or belong to the application,
+            // so use the CUA alternatives and their Emacs counterpart
+            copy_selection: key::Binding::new(vec![
+                KeyPress::from((KeyCode::Insert, KeyModifiers::CONTROL)),
+                KeyPress::from((KeyCode::Char('w'), KeyModifiers::ALT)),
+            ])
+            .with_help("ctrl+insert/alt+w", "copy"),
+
+            cut_selection: key::Binding::new(vec![KeyPress::from((
+                KeyCode::Delete,
+                KeyModifiers::SHIFT,
+            ))])
+            .with_help("shift+delete", "cut"),
+
             input_begin: key::Binding::new(vec![
                 KeyPress::from((KeyCode::Char('<'), KeyModifiers::ALT)),
                 KeyPress::from((KeyCode::Home, KeyModifiers::CONTROL)),
@@ -260,6 +305,40 @@ impl Default for TextareaKeyMap {
                 KeyModifiers::CONTROL,
             ))])
             .with_help("ctrl+t", "transpose character backward"),
+
+            toggle_overwrite: key::Binding::new(vec![KeyPress::from(KeyCode::Insert)])
+                .with_help("insert", "toggle overwrite"),
+
+            undo: key::Binding::new(vec![KeyPress::from((
+                KeyCode::Char('z'),
+                KeyModifiers::CONTROL,
+            ))])
+            .with_help("ctrl+z", "undo"),
+
+            redo: key::Binding::new(vec![KeyPress::from((
+                KeyCode::Char('r'),
+                KeyModifiers::CONTROL,
+            ))])
+            .with_help("ctrl+r", "redo"),
+
+            yank: key::Binding::new(vec![KeyPress::from((
+                KeyCode::Char('y'),
+                KeyModifiers::CONTROL,
+            ))])
+            .with_help("ctrl+y", "yank"),
+
+            // Terminals without the kitty protocol report ctrl+] as ctrl+5
+            jump_to_matching_bracket: key::Binding::new(vec![
+                KeyPress::from((KeyCode::Char(']'), KeyModifiers::CONTROL)),
+                KeyPress::from((KeyCode::Char('5'), KeyModifiers::CONTROL)),
+            ])
+            .with_help("ctrl+]", "matching bracket"),
+
+            expand_snippet: key::Binding::new(vec![KeyPress::from((
+                KeyCode::Char('/'),
+                KeyModifiers::ALT,
+            ))])
+            .with_help("alt+/", "expand snippet"),
         }
     }
 }
@@ -283,6 +362,10 @@ pub struct TextareaStyle {
     pub prompt: Style,
     /// Style for regular text content.
     pub text: Style,
+    /// Style for selected text.
+    pub selection: Style,
+    /// Style for the bracket matching the one at the cursor.
+    pub matching_bracket: Style,
 }
 
 impl TextareaStyle {
@@ -336,6 +419,77 @@ impl TextareaStyle {
     pub fn computed_text(&self) -> Style {
         self.text.clone().inherit(self.base.clone()).inline(true)
     }
+
+    /// Computed selection style
+    pub fn computed_selection(&self) -> Style {
+        self.selection
+            .clone()
+            .inherit(self.base.clone())
+            .inline(true)
+    }
+
+    /// Computed matching bracket style
+    pub fn computed_matching_bracket(&self) -> Style {
+        self.matching_bracket
+            .clone()
+            .inherit(self.base.clone())
+            .inline(true)
+    }
+}
+
+/// Extra styling for one line of the text, such as a lint error, a
+/// breakpoint or a diff marker. Set with
+/// [`Model::set_line_decorations`](super::Model::set_line_decorations).
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::textarea::helpers::LineDecoration;
+/// use lipgloss_extras::prelude::*;
+///
+/// let error = LineDecoration::new(3)
+///     .icon(Style::new().foreground(Color::from("9")).render("●"))
+///     .background(Color::from("52"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LineDecoration {
+    /// The decorated line, counting from 0.
+    pub line: usize,
+    /// Drawn in place of the prompt on the line's first row, padded to
+    /// the prompt's width.
+    pub icon: Option<String>,
+    /// Style for the line's number, instead of the usual one.
+    pub line_number_style: Option<Style>,
+    /// Background behind the line's text.
+    pub background: Option<Color>,
+}
+
+impl LineDecoration {
+    /// Creates a decoration for `line` that changes nothing yet.
+    pub fn new(line: usize) -> Self {
+        Self {
+            line,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the gutter icon.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the line number style.
+    pub fn line_number_style(mut self, style: Style) -> Self {
+        self.line_number_style = Some(style);
+        self
+    }
+
+    /// Sets the background color.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
 }
 
 /// Create default focused style - matching Go DefaultStyles with adaptive colors
@@ -361,8 +515,13 @@ pub fn default_focused_style() -> TextareaStyle {
             Dark: "7",
         }),
         placeholder: Style::new().foreground(Color::from("240")),
-        prompt: Style::new().foreground(Color::from("7")),
+        prompt: Style::new().foreground(AdaptiveColor {
+            Light: "245",
+            Dark: "7",
+        }),
         text: Style::new(),
+        selection: Style::new().reverse(true),
+        matching_bracket: Style::new().bold(true).underline(true),
     }
 }
 
@@ -389,11 +548,16 @@ pub fn default_blurred_style() -> TextareaStyle {
             Dark: "7",
         }),
         placeholder: Style::new().foreground(Color::from("240")),
-        prompt: Style::new().foreground(Color::from("7")),
+        prompt: Style::new().foreground(AdaptiveColor {
+            Light: "245",
+            Dark: "7",
+        }),
         text: Style::new().foreground(AdaptiveColor {
             Light: "245",
             Dark: "7",
         }),
+        selection: Style::new().reverse(true),
+        matching_bracket: Style::new().bold(true).underline(true),
     }
 }
 
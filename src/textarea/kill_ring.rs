@@ -0,0 +1,55 @@
+//! The kill ring: text removed by the kill commands, kept for yanking back.
+//!
+//! As in Emacs and readline, kills made one after another collect into a
+//! single entry, so deleting three words and yanking brings back all three.
+
+/// The most entries kept; older ones are dropped.
+const LIMIT: usize = 32;
+
+/// Which side of the cursor a kill removed text from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Direction {
+    Forward,
+    Backward,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct KillRing {
+    entries: Vec<Vec<char>>,
+    /// Whether the last edit was a kill, so the next one joins its entry.
+    appending: bool,
+}
+
+impl KillRing {
+    /// Saves text removed from the given side of the cursor.
+    pub fn kill(&mut self, text: Vec<char>, direction: Direction) {
+        if text.is_empty() {
+            return;
+        }
+        match self.entries.last_mut() {
+            Some(last) if self.appending => match direction {
+                Direction::Forward => last.extend(text),
+                Direction::Backward => {
+                    last.splice(0..0, text);
+                }
+            },
+            _ => {
+                self.entries.push(text);
+                if self.entries.len() > LIMIT {
+                    self.entries.remove(0);
+                }
+            }
+        }
+        self.appending = true;
+    }
+
+    /// Ends the current run of kills, so the next kill starts an entry.
+    pub fn end_kill(&mut self) {
+        self.appending = false;
+    }
+
+    /// Returns the most recently killed text.
+    pub fn last(&self) -> Option<&[char]> {
+        self.entries.last().map(Vec::as_slice)
+    }
+}
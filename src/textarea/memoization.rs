@@ -12,7 +12,7 @@
 use std::collections::{HashMap, VecDeque};
 use std::hash::Hash;
 use std::marker::PhantomData;
-use unicode_width::UnicodeWidthChar;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 /// A trait for objects that can provide a hash for memoization.
 /// This matches the Go Hasher interface.
@@ -165,10 +165,21 @@ impl Hasher for Line {
     }
 }
 
+/// Display width of a character, counting a tab as `tab_width` columns.
+pub fn rune_width(ch: char, tab_width: usize) -> usize {
+    crate::graphemes::rune_width(ch, tab_width)
+}
+
 /// Memoized text wrapping functionality
+///
+/// The cached lines sit behind a lock, so a text area can wrap lines while
+/// rendering through a shared reference.
 #[derive(Debug)]
 pub struct MemoizedWrap {
-    cache: HashMap<String, Vec<Vec<char>>>,
+    cache: Mutex<HashMap<String, Vec<Vec<char>>>>,
+    /// Rows, characters and words of wrapped lines, by the same key
+    counts: Mutex<HashMap<String, (usize, usize, usize)>>,
+    tab_width: usize,
 }
 
 impl MemoizedWrap {
@@ -180,64 +191,103 @@ impl MemoizedWrap {
     /// Create a new memoized wrapper with specified capacity
     pub fn with_capacity(_capacity: usize) -> Self {
         Self {
-            cache: HashMap::new(),
+            cache: Mutex::default(),
+            counts: Mutex::default(),
+            tab_width: super::DEFAULT_TAB_WIDTH,
+        }
+    }
+
+    fn lock<T>(map: &Mutex<T>) -> MutexGuard<'_, T> {
+        map.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Sets how many columns a tab takes when wrapping, dropping cached
+    /// lines wrapped with another width.
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        if tab_width != self.tab_width {
+            self.tab_width = tab_width;
+            self.clear_cache();
         }
     }
 
     /// Get memoized wrapped text
-    pub fn wrap(&mut self, runes: &[char], width: usize) -> Vec<Vec<char>> {
+    pub fn wrap(&self, runes: &[char], width: usize) -> Vec<Vec<char>> {
         let line = Line {
             runes: runes.to_vec(),
             width,
         };
 
         let key = line.hash_key();
-        if let Some(cached) = self.cache.get(&key) {
+        if let Some(cached) = Self::lock(&self.cache).get(&key) {
             return cached.clone();
         }
 
         let wrapped = self.do_wrap(runes, width);
-        self.cache.insert(key, wrapped.clone());
+        Self::lock(&self.cache).insert(key, wrapped.clone());
         wrapped
     }
 
+    /// Counts the rows `runes` wraps to at `width`, without caching the
+    /// wrapped lines.
+    pub fn row_count(&self, runes: &[char], width: usize) -> usize {
+        self.do_wrap(runes, width).len()
+    }
+
+    /// Counts the rows `runes` wraps to at `width`, its characters
+    /// (grapheme clusters) and its whitespace-separated words. Results are
+    /// cached like [`wrap`](Self::wrap).
+    pub fn counts(&self, runes: &[char], width: usize) -> (usize, usize, usize) {
+        let key = Line {
+            runes: runes.to_vec(),
+            width,
+        }
+        .hash_key();
+        if let Some(&counts) = Self::lock(&self.counts).get(&key) {
+            return counts;
+        }
+
+        let rows = self.wrap(runes, width).len();
+        let characters = crate::graphemes::count(runes);
+        let words = runes
+            .split(|ch| ch.is_whitespace())
+            .filter(|word| !word.is_empty())
+            .count();
+        Self::lock(&self.counts).insert(key, (rows, characters, words));
+        (rows, characters, words)
+    }
+
     /// Wrap text lines (port of Go wrap function)
     fn do_wrap(&self, runes: &[char], width: usize) -> Vec<Vec<char>> {
         let mut lines = vec![Vec::new()];
         let mut word = Vec::new();
         let mut row = 0;
-        let mut spaces = 0;
+        // Tabs are kept so they can be drawn at their width; other
+        // whitespace becomes plain spaces
+        let mut spaces = Vec::new();
 
         // Word wrap the runes
         for &r in runes {
             if r.is_whitespace() {
-                spaces += 1;
+                spaces.push(if r == '\t' { r } else { ' ' });
             } else {
                 word.push(r);
             }
 
-            if spaces > 0 {
+            if !spaces.is_empty() {
                 let current_line_width = self.line_width(&lines[row]);
                 let word_width = self.line_width(&word);
 
-                if current_line_width + word_width + spaces > width {
+                if current_line_width + word_width + self.line_width(&spaces) > width {
                     row += 1;
                     lines.push(Vec::new());
-                    lines[row].extend_from_slice(&word);
-                    lines[row].extend(std::iter::repeat_n(' ', spaces));
-                    spaces = 0;
-                    word.clear();
-                } else {
-                    lines[row].extend_from_slice(&word);
-                    lines[row].extend(std::iter::repeat_n(' ', spaces));
-                    spaces = 0;
-                    word.clear();
                 }
+                lines[row].append(&mut word);
+                lines[row].append(&mut spaces);
             } else {
                 // If the last character is double-width, check if we can add it
                 let last_char_width = word
                     .last()
-                    .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
+                    .map(|&ch| rune_width(ch, self.tab_width))
                     .unwrap_or(0);
                 let word_width = self.line_width(&word);
 
@@ -257,31 +307,27 @@ impl MemoizedWrap {
         let current_line_width = self.line_width(&lines[row]);
         let word_width = self.line_width(&word);
 
-        if current_line_width + word_width + spaces >= width {
+        if current_line_width + word_width + self.line_width(&spaces) >= width {
+            row += 1;
             lines.push(Vec::new());
-            lines[row + 1].extend_from_slice(&word);
-            // Add trailing space for soft-wrapped lines
-            spaces += 1;
-            lines[row + 1].extend(std::iter::repeat_n(' ', spaces));
-        } else {
-            lines[row].extend_from_slice(&word);
-            spaces += 1;
-            lines[row].extend(std::iter::repeat_n(' ', spaces));
         }
+        lines[row].append(&mut word);
+        lines[row].append(&mut spaces);
+        // Add trailing space for soft-wrapped lines
+        lines[row].push(' ');
 
         lines
     }
 
     /// Calculate the display width of a line
     fn line_width(&self, line: &[char]) -> usize {
-        line.iter()
-            .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-            .sum()
+        crate::graphemes::width(line, self.tab_width)
     }
 
     /// Clear the memoization cache
     pub fn clear_cache(&mut self) {
-        self.cache.clear();
+        Self::lock(&self.cache).clear();
+        Self::lock(&self.counts).clear();
     }
 
     /// Get cache capacity
@@ -291,7 +337,7 @@ impl MemoizedWrap {
 
     /// Get current cache size
     pub fn size(&self) -> usize {
-        self.cache.len()
+        Self::lock(&self.cache).len()
     }
 }
 
@@ -304,6 +350,8 @@ impl Default for MemoizedWrap {
 impl Clone for MemoizedWrap {
     fn clone(&self) -> Self {
         // Create a new cache since MemoCache doesn't implement Clone
-        Self::with_capacity(self.capacity())
+        let mut wrap = Self::with_capacity(self.capacity());
+        wrap.tab_width = self.tab_width;
+        wrap
     }
 }
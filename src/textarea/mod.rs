@@ -10,11 +10,30 @@
 //! navigation (insert, delete, move by character/word/line, etc.).
 //!
 //! ### Features
-//! - Soft-wrapped lines with correct column/character accounting for double-width runes
+//! - Soft-wrapped lines with correct column/character accounting for double-width runes,
+//!   or horizontal scrolling with soft wrap turned off
 //! - Optional line numbers and per-line prompts (static or via a prompt function)
-//! - Cursor movement by character, word, and line with deletion/edit helpers
+//! - Line decorations: gutter icons, line number styles and backgrounds for
+//!   diagnostics, breakpoints or diff markers
+//! - Cursor movement by character, word, and line with deletion/edit helpers;
+//!   characters are grapheme clusters, so emoji sequences and combining marks
+//!   move and delete as one
+//! - Undo and redo, one word or run of deletions per step
+//! - Bracket matching: the partner of the bracket at the cursor is highlighted
+//! - An Emacs-style kill ring: line and word deletes can be yanked back
+//! - Snippets: registered trigger words expand to a body with tab stops
+//! - Unsaved-change tracking against a baseline, with a word-level diff
+//! - Live character, word, line and row counts for status bars
+//! - Soft or hard tabs with a configurable tab width, and auto-indent
+//! - Click to place the cursor and drag to select (with the `mouse` feature)
+//! - Vim-style normal, insert and visual modes through `vim::VimMode` (with
+//!   the `vim` feature)
 //! - Viewport-driven rendering for large inputs
+//! - Auto height: the textarea grows and shrinks with its text, up to
+//!   `max_height`, like a chat input box
 //! - Clipboard paste integration (platform dependent)
+//! - Input method composition through `ime::ImeMsg`, drawn at the cursor
+//!   until committed
 //! - Theming via `TextareaStyle` for focused and blurred states
 //!
 //! ### Example
@@ -43,19 +62,44 @@
 //! See the `helpers` module for key bindings and styling utilities, and
 //! `memoization` for the internal soft-wrap cache.
 
+mod brackets;
+mod buffer;
+mod builder;
+mod diff;
 pub mod helpers;
+mod kill_ring;
 pub mod memoization;
+mod snippets;
+#[cfg(feature = "vim")]
+pub mod vim;
 
 #[cfg(test)]
 mod tests;
 
+use buffer::{Buffer, WrapKey};
+pub use builder::Builder;
+pub use diff::Change;
+pub use helpers::LineDecoration;
 use helpers::*;
+use kill_ring::{Direction, KillRing};
 use memoization::MemoizedWrap;
 
+use crate::clipboard::Clipboard;
+use crate::error::{Error, Result};
+use crate::graphemes;
+use crate::ime::{Composition, ImeMsg};
+use crate::text::{self, Align};
+use crate::theme::Theme;
+use crate::undo::{EditKind, History};
+use crate::widget::Margins;
 use crate::{cursor, viewport, Component};
-use bubbletea_rs::{Cmd, Model as BubbleTeaModel};
+use bubbletea_rs::{batch, Cmd, Model as BubbleTeaModel};
 use lipgloss_extras::lipgloss;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use lipgloss_extras::prelude::Style;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use unicode_width::UnicodeWidthChar;
 
 // Constants matching Go implementation
 const MIN_HEIGHT: usize = 1;
@@ -64,7 +108,8 @@ const DEFAULT_WIDTH: usize = 40;
 const DEFAULT_CHAR_LIMIT: usize = 0; // no limit
 const DEFAULT_MAX_HEIGHT: usize = 99;
 const DEFAULT_MAX_WIDTH: usize = 500;
-const MAX_LINES: usize = 10000;
+const DEFAULT_MAX_LINES: usize = 10000;
+const DEFAULT_TAB_WIDTH: usize = 4;
 
 /// Internal messages for clipboard operations
 #[derive(Debug, Clone)]
@@ -72,7 +117,36 @@ pub struct PasteMsg(pub String);
 
 /// Error message produced when a paste operation fails.
 #[derive(Debug, Clone)]
-pub struct PasteErrMsg(pub String);
+pub struct PasteErrMsg(pub Error);
+
+/// A limit that stopped text from being inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// [`Model::char_limit`] characters are already in the text.
+    CharLimit,
+    /// The text already has [`Model::max_lines`] lines.
+    MaxLines,
+    /// The text already has [`Model::max_height`] lines, so no new line
+    /// can be started.
+    MaxHeight,
+}
+
+/// Message emitted by [`Model::update`] when typed or pasted text was cut
+/// short or dropped because a limit was reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitReachedMsg {
+    /// The limit that was hit.
+    pub limit: Limit,
+}
+
+/// Message emitted by [`Model::update`] when
+/// [`auto_height`](Model::set_auto_height) resized the textarea, so the
+/// parent can lay out around the new height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeightChangedMsg {
+    /// The new height in rows.
+    pub height: usize,
+}
 
 /// LineInfo helper for tracking line information regarding soft-wrapped lines
 /// Direct port from Go's LineInfo struct
@@ -94,13 +168,56 @@ pub struct LineInfo {
     pub char_offset: usize,
 }
 
+/// Counts of the text, as returned by [`Model::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Characters, counting each grapheme cluster once and line breaks
+    /// not at all.
+    pub characters: usize,
+    /// Runs of non-whitespace characters.
+    pub words: usize,
+    /// Lines of text.
+    pub lines: usize,
+    /// Rows the text takes up on screen once wrapped.
+    pub rows: usize,
+}
+
+/// A dynamic prompt, shared so that clones of a model draw the same
+/// prompts.
+#[derive(Clone)]
+struct PromptFunc(Arc<dyn Fn(usize) -> String + Send + Sync>);
+
+impl std::fmt::Debug for PromptFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PromptFunc(..)")
+    }
+}
+
+/// The text and cursor position at one point in time, for undo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Snapshot {
+    value: Buffer,
+    row: usize,
+    col: usize,
+}
+
+/// A user-provided filter for inserted text.
+#[derive(Clone)]
+struct Sanitizer(Arc<dyn Fn(Vec<char>) -> Vec<char> + Send + Sync>);
+
+impl std::fmt::Debug for Sanitizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Sanitizer(..)")
+    }
+}
+
 /// Model is the Bubble Tea model for this text area element.
 /// Direct port from Go's Model struct with all fields preserved
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Model {
     // Error state
-    /// Optional error string surfaced by the component.
-    pub err: Option<String>,
+    /// The last error surfaced by the component, such as a failed paste.
+    pub err: Option<Error>,
 
     // General settings - memoization cache
     cache: MemoizedWrap,
@@ -109,11 +226,21 @@ pub struct Model {
     /// Prompt is printed at the beginning of each line
     pub prompt: String,
     /// Placeholder is the text displayed when the user hasn't entered anything yet
+    /// (wrapped to the text width and drawn in the `placeholder` style)
     pub placeholder: String,
     /// ShowLineNumbers, if enabled, causes line numbers to be printed after the prompt
     pub show_line_numbers: bool,
     /// EndOfBufferCharacter is displayed at the end of the input
     pub end_of_buffer_character: char,
+    /// Whether the tab key inserts spaces up to the next tab stop instead
+    /// of a tab character
+    pub soft_tabs: bool,
+    /// Whether a new line starts with the leading whitespace of the line
+    /// it was split from
+    pub auto_indent: bool,
+    /// Whether tabs in inserted text become `tab_width` spaces rather than
+    /// being kept as tab characters
+    pub expand_tabs: bool,
 
     // KeyMap encodes the keybindings recognized by the widget
     /// Key bindings recognized by the widget.
@@ -139,10 +266,20 @@ pub struct Model {
     /// MaxWidth is the maximum width of the text area in columns
     pub max_width: usize,
 
-    // Dynamic prompt function - Option to handle nullable function pointer
-    prompt_func: Option<fn(usize) -> String>,
+    // Dynamic prompt function, if one was set
+    prompt_func: Option<PromptFunc>,
+    // Filter for inserted text in place of the default one, if one was set
+    sanitizer: Option<Sanitizer>,
     /// promptWidth is the width of the prompt
     prompt_width: usize,
+    /// Columns between tab stops, and the width a tab is drawn at
+    tab_width: usize,
+    /// The most lines the text can hold; 0 means no limit
+    max_lines: usize,
+    /// Whether long lines wrap; otherwise they scroll horizontally
+    soft_wrap: bool,
+    /// The limit the last insertion ran into, reported by `update`
+    limit_reached: Option<Limit>,
 
     // Dimensions
     /// width is the maximum number of characters that can be displayed at once
@@ -150,9 +287,9 @@ pub struct Model {
     /// height is the maximum number of lines that can be displayed at once
     height: usize,
 
-    // Content - using Vec<Vec<char>> to match Go's [][]rune
+    // Content - lines of runes, like Go's [][]rune
     /// Underlying text value as runes (characters)
-    value: Vec<Vec<char>>,
+    value: Buffer,
 
     // State
     /// focus indicates whether user input focus should be on this input component
@@ -163,6 +300,38 @@ pub struct Model {
     row: usize,
     /// Last character offset, used to maintain state when cursor is moved vertically
     last_char_offset: usize,
+    /// Whether typed characters replace the character under the cursor
+    overwrite: bool,
+    /// Margins kept when following the terminal size, if enabled
+    resize_margins: Option<Margins>,
+    /// Whether the height follows the number of wrapped rows
+    auto_height: bool,
+    /// Where copied text goes and pasted text comes from
+    clipboard: Clipboard,
+    /// Undo and redo steps
+    history: History<Snapshot>,
+    /// Text removed by the kill commands, for yanking
+    kill_ring: KillRing,
+    /// The text saved by `set_baseline`
+    baseline: Buffer,
+    /// Whether the text differed from the baseline when last checked
+    modified: diff::ModifiedCache,
+    /// Where the selection started; the text between it and the cursor is
+    /// selected
+    selection_anchor: Option<(usize, usize)>,
+    /// Gutter icons and styles for individual lines
+    line_decorations: Vec<LineDecoration>,
+    /// Snippet bodies by trigger word
+    snippets: HashMap<String, String>,
+    /// Tab stops still to visit in the expanded snippet, last first, as
+    /// rows from the end of the text and columns from the end of their line
+    /// so edits at earlier stops do not move them
+    snippet_stops: Vec<(usize, usize)>,
+    /// Text being composed by an input method, drawn at the cursor
+    composition: Composition,
+    /// Mouse click and drag state
+    #[cfg(feature = "mouse")]
+    mouse: crate::mouse::Tracker,
 
     // Viewport is the vertically-scrollable viewport of the multi-line text input
     viewport: viewport::Model,
@@ -185,6 +354,9 @@ impl Model {
             placeholder: String::new(),
             show_line_numbers: true,
             end_of_buffer_character: ' ',
+            soft_tabs: true,
+            auto_indent: false,
+            expand_tabs: false,
             key_map: TextareaKeyMap::default(),
             focused_style: focused_style.clone(),
             blurred_style: blurred_style.clone(),
@@ -194,19 +366,38 @@ impl Model {
             max_height: DEFAULT_MAX_HEIGHT,
             max_width: DEFAULT_MAX_WIDTH,
             prompt_func: None,
+            sanitizer: None,
             prompt_width: 0,
+            tab_width: DEFAULT_TAB_WIDTH,
+            max_lines: DEFAULT_MAX_LINES,
+            soft_wrap: true,
+            limit_reached: None,
             width: DEFAULT_WIDTH,
             height: DEFAULT_HEIGHT,
-            value: vec![vec![]; MIN_HEIGHT],
+            value: Buffer::from(vec![vec![]; MIN_HEIGHT]),
             focus: false,
             col: 0,
             row: 0,
             last_char_offset: 0,
+            overwrite: false,
+            resize_margins: None,
+            auto_height: false,
+            clipboard: Clipboard::default(),
+            history: History::default(),
+            kill_ring: KillRing::default(),
+            baseline: Buffer::default(),
+            modified: diff::ModifiedCache::default(),
+            selection_anchor: None,
+            line_decorations: Vec::new(),
+            snippets: HashMap::new(),
+            snippet_stops: Vec::new(),
+            composition: Composition::default(),
+            #[cfg(feature = "mouse")]
+            mouse: crate::mouse::Tracker::new(),
             viewport: vp,
         };
 
-        // Ensure value has minimum height and maxLines capacity
-        model.value.reserve(MAX_LINES);
+        model.set_baseline();
         model.set_height(DEFAULT_HEIGHT);
         model.set_width(DEFAULT_WIDTH);
 
@@ -214,6 +405,8 @@ impl Model {
     }
 
     /// Set the value of the text input - port of Go's SetValue
+    ///
+    /// The undo history is cleared, since it described other text.
     pub fn set_value(&mut self, s: impl Into<String>) {
         self.reset();
         self.insert_string(s.into());
@@ -222,6 +415,110 @@ impl Model {
         if let Some(line) = self.value.get(self.row) {
             self.set_cursor(line.len());
         }
+        self.history.clear();
+    }
+
+    /// Reverts the last undo step. Typing is undone a word at a time and
+    /// deleting a run at a time; a pause of a second or a cursor movement
+    /// also ends a step. Returns whether there was anything to undo.
+    pub fn undo(&mut self) -> bool {
+        self.snippet_stops.clear();
+        let current = self.snapshot();
+        match self.history.undo(current) {
+            Some(previous) => {
+                self.restore(previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the last step reverted by [`undo`](Self::undo), unless
+    /// something was edited since. Returns whether there was anything to
+    /// redo.
+    pub fn redo(&mut self) -> bool {
+        self.snippet_stops.clear();
+        let current = self.snapshot();
+        match self.history.redo(current) {
+            Some(next) => {
+                self.restore(next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts the most recently killed text at the cursor. Text removed
+    /// by [`delete_before_cursor`](Self::delete_before_cursor),
+    /// [`delete_after_cursor`](Self::delete_after_cursor) and the word
+    /// deletes is killed; kills made one after another yank back together.
+    pub fn yank(&mut self) {
+        self.kill_ring.end_kill();
+        if let Some(text) = self.kill_ring.last().map(<[char]>::to_vec) {
+            self.insert_runes_from_user_input(text);
+        }
+    }
+
+    /// Whether [`undo`](Self::undo) would change anything.
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Whether [`redo`](Self::redo) would change anything.
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    /// Saves the current text as the baseline that
+    /// [`is_modified`](Self::is_modified) and [`diff`](Self::diff) compare
+    /// against, for example after loading or saving a document.
+    pub fn set_baseline(&mut self) {
+        self.baseline = self.value.clone();
+        self.modified.clear();
+    }
+
+    /// Whether the text differs from the baseline. Until
+    /// [`set_baseline`](Self::set_baseline) is called the baseline is the
+    /// text the model was created with.
+    pub fn is_modified(&self) -> bool {
+        let version = self.value.version();
+        if let Some(modified) = self.modified.get(version) {
+            return modified;
+        }
+        let modified = self.value != self.baseline;
+        self.modified.set(version, modified);
+        modified
+    }
+
+    /// Word-level changes from the baseline to the current text.
+    pub fn diff(&self) -> Vec<Change> {
+        let baseline: Vec<String> = self.baseline.iter().map(|l| l.iter().collect()).collect();
+        diff::diff(&baseline.join("\n"), &self.value())
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            value: self.value.clone(),
+            row: self.row,
+            col: self.col,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.value = snapshot.value;
+        self.row = snapshot.row.min(self.value.len().saturating_sub(1));
+        self.set_cursor(snapshot.col);
+        self.reposition_view();
+    }
+
+    /// Runs `edit` and records it in the undo history as its own step.
+    fn record_edit(&mut self, edit: impl FnOnce(&mut Self)) {
+        let before = self.snapshot();
+        edit(self);
+        if before.value != self.value {
+            self.history.record(EditKind::Other, before, Instant::now());
+            self.history.break_step();
+        }
     }
 
     /// Insert a string at the cursor position - port of Go's InsertString
@@ -255,7 +552,7 @@ impl Model {
     /// Length returns the number of characters currently in the text input - port of Go's Length()
     pub fn length(&self) -> usize {
         let mut l = 0;
-        for row in &self.value {
+        for row in self.value.iter() {
             l += row
                 .iter()
                 .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
@@ -275,6 +572,64 @@ impl Model {
         self.row
     }
 
+    /// The text split into rows as [`view`](Self::view) draws them: each
+    /// line wrapped at the text width, or whole when soft wrap is off. The
+    /// rows of a line join back into the line, so a row's length gives the
+    /// column where the next one starts.
+    pub fn wrapped_value(&mut self) -> Vec<String> {
+        let width = self.wrap_width();
+        let mut rows = Vec::new();
+        for line in self.value.iter() {
+            let mut wrapped = self.cache.wrap(line, width);
+            // Wrapping adds a space at the end that stands for the line break
+            if let Some(last) = wrapped.last_mut() {
+                last.pop();
+            }
+            rows.extend(wrapped.iter().map(|row| row.iter().collect::<String>()));
+        }
+        rows
+    }
+
+    /// Counts the characters, words, lines and screen rows of the text.
+    /// Counts are cached per line, so calling this every frame only does
+    /// new work for lines that changed.
+    pub fn stats(&mut self) -> Stats {
+        let wrap_width = self.wrap_width();
+        let mut stats = Stats {
+            lines: self.value.len(),
+            ..Stats::default()
+        };
+        for line in self.value.iter() {
+            let (rows, characters, words) = self.cache.counts(line, wrap_width);
+            stats.rows += rows;
+            stats.characters += characters;
+            stats.words += words;
+        }
+        stats
+    }
+
+    /// Returns the cursor's zero-based row and column, the column counted
+    /// in characters.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Moves the cursor to a zero-based row and column, clamped to the
+    /// text, and scrolls it into view.
+    pub fn set_position(&mut self, row: usize, col: usize) {
+        self.row = row.min(self.value.len().saturating_sub(1));
+        self.set_cursor(col);
+        self.history.break_step();
+        self.kill_ring.end_kill();
+        self.reposition_view();
+    }
+
+    /// Moves the cursor to the start of a one-based line number, as drawn
+    /// in the gutter. Out-of-range numbers are clamped.
+    pub fn goto_line(&mut self, line: usize) {
+        self.set_position(line.saturating_sub(1), 0);
+    }
+
     /// Focused returns the focus state on the model - port of Go's Focused()
     pub fn focused(&self) -> bool {
         self.focus
@@ -282,12 +637,12 @@ impl Model {
 
     /// Reset sets the input to its default state with no input - port of Go's Reset()
     pub fn reset(&mut self) {
-        self.value = vec![vec![]; MIN_HEIGHT];
-        self.value.reserve(MAX_LINES);
+        self.value = Buffer::from(vec![vec![]; MIN_HEIGHT]);
         self.col = 0;
         self.row = 0;
         self.viewport.goto_top();
         self.set_cursor(0);
+        self.snippet_stops.clear();
     }
 
     /// Width returns the width of the textarea - port of Go's Width()
@@ -304,7 +659,7 @@ impl Model {
     pub fn set_width(&mut self, w: usize) {
         // Update prompt width only if there is no prompt function
         if self.prompt_func.is_none() {
-            self.prompt_width = self.prompt.width();
+            self.prompt_width = text::width(&self.prompt);
         }
 
         // Add base style borders and padding to reserved outer width
@@ -313,10 +668,10 @@ impl Model {
         // Add prompt width to reserved inner width
         let mut reserved_inner = self.prompt_width;
 
-        // Add line number width to reserved inner width
+        // Add line number width to reserved inner width: the digits of the
+        // largest line number plus a margin on each side
         if self.show_line_numbers {
-            let ln_width = 4; // Up to 3 digits for line number plus 1 margin
-            reserved_inner += ln_width;
+            reserved_inner += text::width(&self.format_line_number(""));
         }
 
         // Input width must be at least one more than the reserved inner and outer width
@@ -345,13 +700,278 @@ impl Model {
         }
     }
 
+    /// Makes the height follow the text, like a chat input box: the
+    /// textarea grows as lines are added or wrap and shrinks as they are
+    /// removed, from one row up to [`max_height`](Self::max_height).
+    /// [`update`](Self::update) returns a [`HeightChangedMsg`] command when
+    /// the height changes.
+    pub fn set_auto_height(&mut self, on: bool) {
+        self.auto_height = on;
+        self.fit_height();
+    }
+
+    /// Whether the height follows the text; see
+    /// [`set_auto_height`](Self::set_auto_height).
+    pub fn auto_height(&self) -> bool {
+        self.auto_height
+    }
+
+    /// Sets the height to the rows the text wraps to, when
+    /// [`auto_height`](Self::auto_height) is on. Returns whether it changed.
+    fn fit_height(&mut self) -> bool {
+        if !self.auto_height {
+            return false;
+        }
+        let height = self.height;
+        let rows = self.rows(0..self.value.len());
+        self.set_height(rows);
+        if self.height == height {
+            return false;
+        }
+        // Rows scrolled out of view come back as the textarea grows
+        self.set_y_offset(self.viewport.y_offset);
+        self.reposition_view();
+        true
+    }
+
+    /// Makes the textarea follow the terminal size. Every `WindowSizeMsg`
+    /// then calls `set_width` and `set_height` with the terminal size minus
+    /// `margins`, whether or not the textarea is focused.
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Sets the clipboard used to copy, cut and paste, in place of the
+    /// default one.
+    pub fn with_clipboard(mut self, clipboard: Clipboard) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
+    /// Sets the clipboard used to copy, cut and paste; see
+    /// [`with_clipboard`](Self::with_clipboard).
+    pub fn set_clipboard(&mut self, clipboard: Clipboard) {
+        self.clipboard = clipboard;
+    }
+
+    /// Sets the screen cell where the textarea is drawn, so clicks can
+    /// move the cursor and drags can select, and returns `self` for
+    /// chaining.
+    #[cfg(feature = "mouse")]
+    pub fn with_mouse_origin(mut self, x: u16, y: u16) -> Self {
+        self.set_mouse_origin(x, y);
+        self
+    }
+
+    /// Sets the screen cell where the textarea is drawn.
+    #[cfg(feature = "mouse")]
+    pub fn set_mouse_origin(&mut self, x: u16, y: u16) {
+        self.mouse.set_origin(x, y);
+    }
+
+    /// Returns the selected range as `(row, column)` start and end
+    /// positions, start first, or `None` when nothing is selected.
+    pub fn selection(&self) -> Option<((usize, usize), (usize, usize))> {
+        let (row, col) = self.selection_anchor?;
+        let row = row.min(self.value.len().saturating_sub(1));
+        let anchor = (row, col.min(self.value[row].len()));
+        let cursor = (self.row, self.col);
+        match anchor.cmp(&cursor) {
+            std::cmp::Ordering::Less => Some((anchor, cursor)),
+            std::cmp::Ordering::Greater => Some((cursor, anchor)),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Returns the selected text, or `None` when nothing is selected.
+    pub fn selected_text(&self) -> Option<String> {
+        let ((start_row, start_col), (end_row, end_col)) = self.selection()?;
+        let mut text = String::new();
+        for row in start_row..=end_row {
+            let line = &self.value[row];
+            let from = if row == start_row { start_col } else { 0 };
+            let to = if row == end_row { end_col } else { line.len() };
+            text.extend(&line[from..to]);
+            if row < end_row {
+                text.push('\n');
+            }
+        }
+        Some(text)
+    }
+
+    /// Unselects the selected text, leaving the cursor where it is.
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+    }
+
+    /// Removes the selected text and puts the cursor where it began.
+    fn delete_selection(&mut self) {
+        let Some(((start_row, start_col), (end_row, end_col))) = self.selection() else {
+            return;
+        };
+        let tail = self.value[end_row].split_off(end_col);
+        self.value.splice(start_row + 1..end_row + 1, []);
+        self.value[start_row].truncate(start_col);
+        self.value[start_row].extend(tail);
+        self.selection_anchor = None;
+        self.row = start_row;
+        self.set_cursor(start_col);
+        self.reposition_view();
+    }
+
     /// SetPromptFunc supersedes the Prompt field and sets a dynamic prompt instead
     /// Port of Go's SetPromptFunc
-    pub fn set_prompt_func(&mut self, prompt_width: usize, func: fn(usize) -> String) {
-        self.prompt_func = Some(func);
+    ///
+    /// `func` gets the display line and may capture state, such as blame
+    /// or diff markers to show per line.
+    pub fn set_prompt_func(
+        &mut self,
+        prompt_width: usize,
+        func: impl Fn(usize) -> String + Send + Sync + 'static,
+    ) {
+        self.prompt_func = Some(PromptFunc(Arc::new(func)));
         self.prompt_width = prompt_width;
     }
 
+    /// Returns the current cursor mode (blink/static/hidden)
+    pub fn cursor_mode(&self) -> cursor::Mode {
+        self.cursor.mode()
+    }
+
+    /// Changes the cursor behavior at runtime. Switching to `Mode::Blink`
+    /// returns the command that starts the blink loop.
+    pub fn set_cursor_mode(&mut self, mode: cursor::Mode) -> Option<Cmd> {
+        self.cursor.set_mode(mode)
+    }
+
+    /// Returns whether the textarea is in overwrite mode
+    pub fn overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Switches between insert and overwrite mode. In overwrite mode typed
+    /// characters replace the character under the cursor (newlines are still
+    /// inserted) and the cursor is drawn with its overwrite shape.
+    pub fn set_overwrite(&mut self, overwrite: bool) {
+        self.overwrite = overwrite;
+        self.cursor.set_overwrite(overwrite);
+    }
+
+    /// Returns the number of columns between tab stops.
+    pub fn tab_width(&self) -> usize {
+        self.tab_width
+    }
+
+    /// Sets the number of columns between tab stops (at least 1). Tab
+    /// characters in the text are drawn this wide.
+    pub fn set_tab_width(&mut self, tab_width: usize) {
+        self.tab_width = tab_width.max(1);
+        self.cache.set_tab_width(self.tab_width);
+    }
+
+    /// The most lines the text can hold; 0 means no limit.
+    pub fn max_lines(&self) -> usize {
+        self.max_lines
+    }
+
+    /// Sets the most lines the text can hold; 0 means no limit. Text that
+    /// is already there is kept, but no lines can be added past the limit.
+    pub fn set_max_lines(&mut self, max_lines: usize) {
+        self.max_lines = max_lines;
+    }
+
+    /// Sets the gutter icons, line number styles and backgrounds of
+    /// individual lines, replacing any set before. When several
+    /// decorations name the same line, the last one wins.
+    ///
+    /// Decorations stay on their line numbers as the text is edited, so
+    /// set them again after edits that add or remove lines.
+    pub fn set_line_decorations(&mut self, decorations: Vec<LineDecoration>) {
+        self.line_decorations = decorations;
+    }
+
+    /// Returns the line decorations.
+    pub fn line_decorations(&self) -> &[LineDecoration] {
+        &self.line_decorations
+    }
+
+    /// Whether long lines wrap onto several rows.
+    pub fn soft_wrap(&self) -> bool {
+        self.soft_wrap
+    }
+
+    /// Sets whether long lines wrap onto several rows. Without soft wrap
+    /// each line takes one row, and the view scrolls horizontally to keep
+    /// the cursor in sight.
+    pub fn set_soft_wrap(&mut self, soft_wrap: bool) {
+        self.soft_wrap = soft_wrap;
+        self.viewport.x_offset = 0;
+        self.reposition_view();
+    }
+
+    /// The width lines are wrapped at: the text width, or no limit when
+    /// soft wrap is off.
+    fn wrap_width(&self) -> usize {
+        if self.soft_wrap {
+            self.width
+        } else {
+            usize::MAX
+        }
+    }
+
+    /// The range of characters of an unwrapped line that fit in the
+    /// columns scrolled into view.
+    fn visible_range(&self, line: &[char]) -> (usize, usize) {
+        let mut column = 0;
+        let mut from = line.len();
+        let mut to = line.len();
+        let mut i = 0;
+        while i < line.len() {
+            let next = graphemes::next_boundary(line, i);
+            let width = graphemes::width(&line[i..next], self.tab_width);
+            if from == line.len() && column >= self.viewport.x_offset {
+                from = i;
+            }
+            if column + width > self.viewport.x_offset + self.width {
+                to = i;
+                break;
+            }
+            column += width;
+            i = next;
+        }
+        (from.min(to), to)
+    }
+
+    /// Scrolls horizontally so the cursor's column is in view, when soft
+    /// wrap is off.
+    fn reposition_column(&mut self) {
+        if self.soft_wrap {
+            return;
+        }
+        let column = self.line_info().char_offset;
+        let x_offset = &mut self.viewport.x_offset;
+        if column < *x_offset {
+            *x_offset = column;
+        } else if column >= *x_offset + self.width {
+            *x_offset = column + 1 - self.width;
+        }
+    }
+
+    /// Inserts a tab at the cursor: spaces up to the next tab stop when
+    /// [`soft_tabs`](Self::soft_tabs) is set, a tab character otherwise.
+    pub fn insert_tab(&mut self) {
+        if !self.soft_tabs {
+            self.insert_rune('\t');
+            return;
+        }
+        let column = self.value.get(self.row).map_or(0, |line| {
+            graphemes::width(&line[..self.col.min(line.len())], self.tab_width)
+        });
+        let spaces = self.tab_width - column % self.tab_width;
+        self.insert_runes_from_user_input(vec![' '; spaces]);
+    }
+
     /// SetCursor moves the cursor to the given position - port of Go's SetCursor()
     pub fn set_cursor(&mut self, col: usize) {
         self.col = clamp(
@@ -409,11 +1029,10 @@ impl Model {
                 break;
             }
             if let Some(line) = self.value.get(self.row) {
-                if let Some(&ch) = line.get(self.col) {
-                    offset += UnicodeWidthChar::width(ch).unwrap_or(0);
-                }
+                let next = graphemes::next_boundary(line, self.col);
+                offset += graphemes::width(&line[self.col..next], self.tab_width);
+                self.col = next;
             }
-            self.col += 1;
         }
     }
 
@@ -447,10 +1066,9 @@ impl Model {
                 if self.col >= line.len() || offset >= nli.char_width.saturating_sub(1) {
                     break;
                 }
-                if let Some(&ch) = line.get(self.col) {
-                    offset += UnicodeWidthChar::width(ch).unwrap_or(0);
-                }
-                self.col += 1;
+                let next = graphemes::next_boundary(line, self.col);
+                offset += graphemes::width(&line[self.col..next], self.tab_width);
+                self.col = next;
             } else {
                 break;
             }
@@ -471,6 +1089,91 @@ impl Model {
         }
     }
 
+    /// Moves the cursor to the bracket matching the one at the cursor, or
+    /// just before it. Returns whether there was a match to jump to.
+    pub fn jump_to_matching_bracket(&mut self) -> bool {
+        let Some((_, (row, col))) = brackets::matching(&self.value, self.row, self.col) else {
+            return false;
+        };
+        self.row = row;
+        self.set_cursor(col);
+        true
+    }
+
+    /// Registers a snippet. When `trigger` is the word before the cursor,
+    /// [`expand_snippet`](Self::expand_snippet) replaces it with `body`, in
+    /// which `$1` to `$9` mark tab stops, `$0` where the cursor ends up and
+    /// `$$` a dollar sign.
+    pub fn register_snippet(&mut self, trigger: impl Into<String>, body: impl Into<String>) {
+        self.snippets.insert(trigger.into(), body.into());
+    }
+
+    /// Replaces the snippet trigger before the cursor with its body and
+    /// moves to the body's first tab stop; the insert tab key then visits
+    /// the others. Lines of the body take the indentation of the current
+    /// line. Returns whether there was a trigger to expand.
+    pub fn expand_snippet(&mut self) -> bool {
+        let Some(line) = self.value.get(self.row) else {
+            return false;
+        };
+        let before = &line[..self.col.min(line.len())];
+        let Some((trigger_len, body)) = self
+            .snippets
+            .iter()
+            .map(|(trigger, body)| (trigger.chars().collect::<Vec<_>>(), body))
+            .filter(|(trigger, _)| {
+                // The trigger must be a whole word, not the end of one
+                !trigger.is_empty()
+                    && before.ends_with(trigger)
+                    && before[..before.len() - trigger.len()]
+                        .last()
+                        .is_none_or(|&ch| !(ch.is_alphanumeric() || ch == '_'))
+            })
+            .max_by_key(|(trigger, _)| trigger.len())
+            .map(|(trigger, body)| (trigger.len(), body.clone()))
+        else {
+            return false;
+        };
+        let indent: Vec<char> = line
+            .iter()
+            .copied()
+            .take_while(|ch| ch.is_whitespace())
+            .collect();
+        let (text, stops) = snippets::expand(&body, &indent);
+
+        self.col -= trigger_len;
+        self.value[self.row].drain(self.col..self.col + trigger_len);
+        let start = (self.row, self.col);
+        self.insert_runes_from_user_input(text.clone());
+
+        self.snippet_stops = stops
+            .iter()
+            .rev()
+            .map(|&offset| {
+                let (row, col) = snippets::position(&text, offset, start);
+                let line_len = self.value.get(row).map_or(0, Vec::len);
+                (
+                    self.value.len().saturating_sub(row),
+                    line_len.saturating_sub(col),
+                )
+            })
+            .collect();
+        self.next_snippet_stop();
+        true
+    }
+
+    /// Moves the cursor to the next tab stop of the last expanded snippet.
+    /// Returns false when there are none left.
+    pub fn next_snippet_stop(&mut self) -> bool {
+        let Some((rows_after, cols_after)) = self.snippet_stops.pop() else {
+            return false;
+        };
+        self.row = self.value.len().saturating_sub(rows_after.max(1));
+        let line_len = self.value[self.row].len();
+        self.set_cursor(line_len.saturating_sub(cols_after));
+        true
+    }
+
     // Internal helper functions matching Go implementation structure
 
     /// Port of Go's insertRunesFromUserInput
@@ -480,10 +1183,11 @@ impl Model {
 
         if self.char_limit > 0 {
             let avail_space = self.char_limit.saturating_sub(self.length());
-            if avail_space == 0 {
-                return;
-            }
             if avail_space < runes.len() {
+                self.limit_reached = Some(Limit::CharLimit);
+                if avail_space == 0 {
+                    return;
+                }
                 runes.truncate(avail_space);
             }
         }
@@ -504,9 +1208,10 @@ impl Model {
         }
 
         // Obey the maximum line limit
-        if MAX_LINES > 0 && self.value.len() + lines.len() - 1 > MAX_LINES {
-            let allowed_height = (MAX_LINES - self.value.len() + 1).max(0);
-            lines.truncate(allowed_height);
+        if self.max_lines > 0 && self.value.len() + lines.len() - 1 > self.max_lines {
+            let allowed_height = (self.max_lines + 1).saturating_sub(self.value.len());
+            lines.truncate(allowed_height.max(1));
+            self.limit_reached = Some(Limit::MaxLines);
         }
 
         if lines.is_empty() {
@@ -534,9 +1239,8 @@ impl Model {
 
         if lines.len() > 1 {
             // Add the new lines maintaining cursor on the first line's end
-            for (i, line) in lines[1..].iter().enumerate() {
-                self.value.insert(self.row + 1 + i, line.clone());
-            }
+            let at = self.row + 1;
+            self.value.splice(at..at, lines[1..].iter().cloned());
             // Move cursor to end of the last inserted line (Go behavior on SetValue)
             self.row += lines.len() - 1;
             self.col = lines.last().map(|l| l.len()).unwrap_or(0);
@@ -550,22 +1254,52 @@ impl Model {
         self.set_cursor(self.col);
     }
 
-    /// Sanitize runes for input - simple version
+    /// Sanitize runes for input - port of Go's sanitizer use in insertRunesFromUserInput
+    ///
+    /// Carriage returns and other control characters are dropped, keeping
+    /// line breaks and tabs; tabs become spaces when
+    /// [`expand_tabs`](Self::expand_tabs) is set. A filter set with
+    /// [`set_sanitizer`](Self::set_sanitizer) runs instead.
     fn sanitize_runes(&self, runes: Vec<char>) -> Vec<char> {
-        // For now, just return as-is. In Go this handles special characters
-        runes
+        if let Some(sanitizer) = &self.sanitizer {
+            return (sanitizer.0)(runes);
+        }
+        let mut clean = Vec::with_capacity(runes.len());
+        for ch in runes {
+            match ch {
+                '\n' => clean.push(ch),
+                '\t' if self.expand_tabs => clean.extend(std::iter::repeat_n(' ', self.tab_width)),
+                '\t' => clean.push(ch),
+                ch if ch.is_control() => {}
+                ch => clean.push(ch),
+            }
+        }
+        clean
+    }
+
+    /// Filters text with `sanitizer` before it is inserted, whether typed,
+    /// pasted or set with [`set_value`](Self::set_value), in place of the
+    /// default filter. It gets the characters to insert and returns the
+    /// ones to keep; line breaks in the result start new lines. A boxed
+    /// closure can be passed as well.
+    pub fn set_sanitizer(
+        &mut self,
+        sanitizer: impl Fn(Vec<char>) -> Vec<char> + Send + Sync + 'static,
+    ) {
+        self.sanitizer = Some(Sanitizer(Arc::new(sanitizer)));
     }
 
     /// LineInfo returns line information for the current cursor position
     /// Port of Go's LineInfo() - enhanced with better wrapped line handling
-    pub fn line_info(&mut self) -> LineInfo {
+    pub fn line_info(&self) -> LineInfo {
         if self.row >= self.value.len() {
             return LineInfo::default();
         }
 
         // Clone the line to avoid borrowing issues
         let current_line = self.value[self.row].clone();
-        let width = self.width;
+        let width = self.wrap_width();
+        let tab_width = self.tab_width;
         let grid = self.cache.wrap(&current_line, width);
 
         // Find out which visual wrap line we are currently on
@@ -582,21 +1316,14 @@ impl Model {
                     row_offset: i + 1,
                     start_column: self.col,
                     width: next_line.len(),
-                    char_width: next_line
-                        .iter()
-                        .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-                        .sum(),
+                    char_width: graphemes::width(next_line, tab_width),
                 };
             }
 
             // Check if cursor falls within this wrapped line
             if counter + line.len() >= self.col {
                 let col_in_line = self.col.saturating_sub(counter);
-                let char_off: usize = line
-                    .iter()
-                    .take(col_in_line.min(line.len()))
-                    .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-                    .sum();
+                let char_off = graphemes::width(&line[..col_in_line.min(line.len())], tab_width);
 
                 return LineInfo {
                     char_offset: char_off,
@@ -605,10 +1332,7 @@ impl Model {
                     row_offset: i,
                     start_column: counter,
                     width: line.len(),
-                    char_width: line
-                        .iter()
-                        .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-                        .sum(),
+                    char_width: graphemes::width(line, tab_width),
                 };
             }
 
@@ -619,19 +1343,13 @@ impl Model {
         if let Some(last_line) = grid.last() {
             let last_counter = counter - last_line.len();
             return LineInfo {
-                char_offset: last_line
-                    .iter()
-                    .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-                    .sum(),
+                char_offset: graphemes::width(last_line, tab_width),
                 column_offset: last_line.len(),
                 height: grid.len(),
                 row_offset: grid.len().saturating_sub(1),
                 start_column: last_counter,
                 width: last_line.len(),
-                char_width: last_line
-                    .iter()
-                    .map(|&ch| UnicodeWidthChar::width(ch).unwrap_or(0))
-                    .sum(),
+                char_width: graphemes::width(last_line, tab_width),
             };
         }
 
@@ -639,22 +1357,25 @@ impl Model {
     }
 
     /// Delete before cursor - port of Go's deleteBeforeCursor()
+    ///
+    /// The removed text goes to the kill ring.
     pub fn delete_before_cursor(&mut self) {
         if let Some(line) = self.value.get_mut(self.row) {
-            let tail = if self.col <= line.len() {
-                line[self.col..].to_vec()
-            } else {
-                Vec::new()
-            };
-            *line = tail;
+            let col = self.col.min(line.len());
+            let killed = line.drain(..col).collect();
+            self.kill_ring.kill(killed, Direction::Backward);
         }
         self.set_cursor(0);
     }
 
     /// Delete after cursor - port of Go's deleteAfterCursor()
+    ///
+    /// The removed text goes to the kill ring.
     pub fn delete_after_cursor(&mut self) {
         if let Some(line) = self.value.get_mut(self.row) {
-            line.truncate(self.col);
+            let col = self.col.min(line.len());
+            let killed = line.drain(col..).collect();
+            self.kill_ring.kill(killed, Direction::Forward);
             let line_len = line.len();
             self.set_cursor(line_len);
         }
@@ -674,8 +1395,9 @@ impl Model {
 
         if let Some(line) = self.value.get_mut(self.row) {
             if !line.is_empty() && self.col > 0 {
-                line.remove(self.col - 1);
-                self.set_cursor(self.col - 1);
+                let start = graphemes::prev_boundary(line, self.col);
+                line.drain(start..self.col);
+                self.set_cursor(start);
             }
         }
     }
@@ -684,7 +1406,8 @@ impl Model {
     pub fn delete_character_forward(&mut self) {
         if let Some(line) = self.value.get_mut(self.row) {
             if !line.is_empty() && self.col < line.len() {
-                line.remove(self.col);
+                let end = graphemes::next_boundary(line, self.col);
+                line.drain(self.col..end);
             }
         }
 
@@ -694,8 +1417,13 @@ impl Model {
     }
 
     /// Delete word backward - port of Go's deleteWordLeft()
+    ///
+    /// The removed text, or the joined line break, goes to the kill ring.
     pub fn delete_word_backward(&mut self) {
         if self.col == 0 {
+            if self.row > 0 {
+                self.kill_ring.kill(vec!['\n'], Direction::Backward);
+            }
             self.merge_line_above(self.row);
             return;
         }
@@ -735,13 +1463,16 @@ impl Model {
         if let Some(line_mut) = self.value.get_mut(self.row) {
             let end_clamped = end.min(line_mut.len());
             let start_clamped = start.min(end_clamped);
-            line_mut.drain(start_clamped..end_clamped);
+            let killed = line_mut.drain(start_clamped..end_clamped).collect();
+            self.kill_ring.kill(killed, Direction::Backward);
         }
 
         self.set_cursor(start);
     }
 
     /// Delete word forward - port of Go's deleteWordRight()
+    ///
+    /// The removed text, or the joined line break, goes to the kill ring.
     pub fn delete_word_forward(&mut self) {
         let line = if let Some(line) = self.value.get(self.row) {
             line.clone()
@@ -750,6 +1481,9 @@ impl Model {
         };
 
         if self.col >= line.len() || line.is_empty() {
+            if self.row + 1 < self.value.len() {
+                self.kill_ring.kill(vec!['\n'], Direction::Forward);
+            }
             self.merge_line_below(self.row);
             return;
         }
@@ -785,11 +1519,8 @@ impl Model {
 
         // Delete the selected text
         if let Some(line) = self.value.get_mut(self.row) {
-            if new_col > line.len() {
-                line.truncate(old_col);
-            } else {
-                line.drain(old_col..new_col);
-            }
+            let killed = line.drain(old_col..new_col.min(line.len())).collect();
+            self.kill_ring.kill(killed, Direction::Forward);
         }
 
         self.set_cursor(old_col);
@@ -852,8 +1583,16 @@ impl Model {
     }
 
     /// Insert newline - port of Go's InsertNewline()
+    ///
+    /// With [`auto_indent`](Self::auto_indent) set, the new line starts
+    /// with the leading whitespace of the line it was split from.
     pub fn insert_newline(&mut self) {
         if self.max_height > 0 && self.value.len() >= self.max_height {
+            self.limit_reached = Some(Limit::MaxHeight);
+            return;
+        }
+        if self.max_lines > 0 && self.value.len() >= self.max_lines {
+            self.limit_reached = Some(Limit::MaxLines);
             return;
         }
 
@@ -862,7 +1601,20 @@ impl Model {
             0,
             self.value.get(self.row).map_or(0, |line| line.len()),
         );
+        let indent: Vec<char> = if self.auto_indent {
+            self.value[self.row]
+                .iter()
+                .take(self.col)
+                .take_while(|&&ch| ch == ' ' || ch == '\t')
+                .copied()
+                .collect()
+        } else {
+            Vec::new()
+        };
         self.split_line(self.row, self.col);
+        if !indent.is_empty() {
+            self.insert_runes_from_user_input(indent);
+        }
     }
 
     /// Move cursor one character left - port of Go's characterLeft()
@@ -877,7 +1629,8 @@ impl Model {
             }
         }
         if self.col > 0 {
-            self.set_cursor(self.col - 1);
+            let line = self.value.get(self.row).map_or(&[][..], |line| line);
+            self.set_cursor(graphemes::prev_boundary(line, self.col));
         }
     }
 
@@ -885,7 +1638,7 @@ impl Model {
     pub fn character_right(&mut self) {
         if let Some(line) = self.value.get(self.row) {
             if self.col < line.len() {
-                self.set_cursor(self.col + 1);
+                self.set_cursor(graphemes::next_boundary(line, self.col));
             } else if self.row < self.value.len() - 1 {
                 self.row += 1;
                 self.cursor_start();
@@ -1049,161 +1802,375 @@ impl Model {
         }
     }
 
-    /// View renders the text area - port of Go's View()
+    /// Renders the text as plain lines, without prompts, line numbers or
+    /// wrapping, followed by the cursor position such as
+    /// `line 2 of 5, column 4`. An empty area reads its placeholder.
+    pub fn accessible_view(&self) -> String {
+        if self.value.iter().all(|line| line.is_empty()) && self.value.len() <= 1 {
+            let mut out = if self.placeholder.is_empty() {
+                "empty".to_string()
+            } else {
+                format!("empty, {}", self.placeholder)
+            };
+            if let Some(err) = &self.err {
+                out.push_str(&format!("\nError: {}", err));
+            }
+            return out;
+        }
+        let mut lines: Vec<String> = self
+            .value
+            .iter()
+            .map(|line| line.iter().collect())
+            .collect();
+        lines.push(format!(
+            "{}, column {}",
+            crate::a11y::position("line", self.row, self.value.len()),
+            self.col + 1
+        ));
+        if let Some(err) = &self.err {
+            lines.push(format!("Error: {}", err));
+        }
+        lines.join("\n")
+    }
+
+    /// View renders the text area - port of Go's View(). In accessible
+    /// mode this is the [`accessible_view`](Self::accessible_view).
+    ///
+    /// Scrolls first if the cursor is out of view, which edits through the
+    /// model's own methods never leave it; see [`render`](Self::render).
     pub fn view(&mut self) -> String {
+        self.reposition_column();
+        self.set_y_offset(self.viewport.y_offset);
+        self.render()
+    }
+
+    /// Renders the text area as scrolled, without moving the view to the
+    /// cursor. This is what [`view`](Self::view) draws after an update,
+    /// through a shared reference.
+    pub fn render(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+
         // Early return for empty placeholder case
         if self.value.is_empty() || (self.value.len() == 1 && self.value[0].is_empty()) {
             return self.placeholder_view();
         }
 
-        // Set cursor text style for rendering
-        self.cursor.text_style = self.current_style.computed_cursor_line();
+        // The cursor is drawn over the cursor line's style
+        let mut cursor = self.cursor.clone();
+        cursor.text_style = self.current_style.computed_cursor_line();
 
-        let mut s = String::new();
         let line_info = self.line_info();
+        // Only the rows scrolled into view are drawn
+        let key = self.wrap_key();
+        let (first_line, first_row) =
+            self.value
+                .peek_line_at_row(self.viewport.y_offset, key, |line| {
+                    self.cache.row_count(line, key.0)
+                });
         let style = &self.current_style;
 
+        // Compute each style once per frame rather than once per line, and
+        // render the fragments that are the same on every line up front
+        let prompt_style = style.computed_prompt();
+        let text_style = style.computed_text();
+        let cursor_line_style = style.computed_cursor_line();
+        let line_number_style = style.computed_line_number();
+        let cursor_line_number_style = style.computed_cursor_line_number();
+        let fixed_prompt = self
+            .prompt_func
+            .is_none()
+            .then(|| prompt_style.render(&self.prompt));
+        let (blank_line_number, blank_cursor_line_number) = if self.show_line_numbers {
+            let blank = self.format_line_number("");
+            (
+                line_number_style.render(&blank),
+                cursor_line_number_style.render(&blank),
+            )
+        } else {
+            (String::new(), String::new())
+        };
+
+        let wrap_width = self.wrap_width();
+        let mut s = String::with_capacity(self.height * (self.width + 32));
+
         // Track display lines and widest line number for padding
-        let mut display_line = 0;
+        let mut display_line = self.viewport.y_offset;
+        let mut rows_drawn = 0;
         let mut widest_line_number = 0;
 
+        let selection = self.selection();
+        let selection_style = style.computed_selection();
+        let brackets = if self.focus {
+            brackets::matching(&self.value, self.row, self.col)
+        } else {
+            None
+        };
+        let bracket_style = style.computed_matching_bracket();
+
+        let decorations: HashMap<usize, &LineDecoration> = self
+            .line_decorations
+            .iter()
+            .map(|decoration| (decoration.line, decoration))
+            .collect();
+        let prompt_columns = self.prompt_columns();
+
         // Process each document line
-        for (doc_line_idx, line) in self.value.iter().enumerate() {
-            let wrapped_lines = self.cache.wrap(line, self.width);
+        for (doc_line_idx, line) in (first_line..).zip(self.value.iter_from(first_line)) {
+            if rows_drawn == self.height {
+                break;
+            }
+            let wrapped_lines = self.cache.wrap(line, wrap_width);
             let is_current_doc_line = doc_line_idx == self.row;
+            // Selected columns of this line; a line selected past its end
+            // highlights the trailing space that stands for the line break
+            let mut marks = Vec::new();
+            if let Some(((r0, c0), (r1, c1))) = selection {
+                if (r0..=r1).contains(&doc_line_idx) {
+                    let from = if doc_line_idx == r0 { c0 } else { 0 };
+                    let to = if doc_line_idx == r1 { c1 } else { usize::MAX };
+                    marks.push((from, to, &selection_style));
+                }
+            }
+            // Both brackets of a matched pair are highlighted
+            if let Some((a, b)) = brackets {
+                for (row, col) in [a, b] {
+                    if row == doc_line_idx {
+                        marks.push((col, col + 1, &bracket_style));
+                    }
+                }
+            }
+            let mut wrap_start = 0;
+
+            let decoration = decorations.get(&doc_line_idx).copied();
+            let background_styles =
+                decoration
+                    .and_then(|d| d.background.clone())
+                    .map(|background| {
+                        (
+                            text_style.clone().background(background.clone()),
+                            cursor_line_style.clone().background(background),
+                        )
+                    });
+            let (line_text_style, line_cursor_style) = match &background_styles {
+                Some((text, cursor_line)) => (text, cursor_line),
+                None => (&text_style, &cursor_line_style),
+            };
 
             for (wrap_idx, wrapped_line) in wrapped_lines.iter().enumerate() {
-                let prompt = self.get_prompt_string(display_line);
-                s.push_str(&style.computed_prompt().render(&prompt));
+                if doc_line_idx == first_line && wrap_idx < first_row {
+                    wrap_start += wrapped_line.len();
+                    continue;
+                }
+                if rows_drawn == self.height {
+                    break;
+                }
+                rows_drawn += 1;
+                // Without soft wrap only the columns scrolled into view show
+                let (from, to) = if self.soft_wrap {
+                    (0, wrapped_line.len())
+                } else {
+                    self.visible_range(wrapped_line)
+                };
+                let visible = &wrapped_line[from..to];
+
+                match (decoration.and_then(|d| d.icon.as_ref()), &fixed_prompt) {
+                    (Some(icon), _) if wrap_idx == 0 => {
+                        s.push_str(&text::pad(icon, prompt_columns, Align::Left))
+                    }
+                    (_, Some(prompt)) => s.push_str(prompt),
+                    (_, None) => {
+                        s.push_str(&prompt_style.render(&self.get_prompt_string(display_line)))
+                    }
+                }
                 display_line += 1;
 
                 // Line numbers
-                let mut ln = String::new();
                 if self.show_line_numbers {
-                    if wrap_idx == 0 {
-                        if is_current_doc_line {
-                            ln = style
-                                .computed_cursor_line_number()
-                                .render(&self.format_line_number(doc_line_idx + 1));
-                        } else {
-                            ln = style
-                                .computed_line_number()
-                                .render(&self.format_line_number(doc_line_idx + 1));
+                    let decorated_style = decoration.and_then(|d| d.line_number_style.as_ref());
+                    let ln = match (wrap_idx == 0, is_current_doc_line, decorated_style) {
+                        (true, _, Some(number_style)) => number_style
+                            .clone()
+                            .inherit(style.base.clone())
+                            .inline(true)
+                            .render(&self.format_line_number(doc_line_idx + 1)),
+                        (true, true, None) => cursor_line_number_style
+                            .render(&self.format_line_number(doc_line_idx + 1)),
+                        (true, false, None) => {
+                            line_number_style.render(&self.format_line_number(doc_line_idx + 1))
                         }
-                    } else if is_current_doc_line {
-                        ln = style
-                            .computed_cursor_line_number()
-                            .render(&self.format_line_number(""));
-                    } else {
-                        ln = style
-                            .computed_line_number()
-                            .render(&self.format_line_number(""));
-                    }
+                        (false, true, _) => blank_cursor_line_number.clone(),
+                        (false, false, _) => blank_line_number.clone(),
+                    };
+                    // Track widest line number for padding
+                    widest_line_number = widest_line_number.max(text::width(&ln));
                     s.push_str(&ln);
                 }
 
-                // Track widest line number for padding
-                let lnw = lipgloss::width(&ln);
-                if lnw > widest_line_number {
-                    widest_line_number = lnw;
-                }
-
-                let strwidth = wrapped_line
-                    .iter()
-                    .map(|&ch| unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0))
-                    .sum::<usize>();
+                let strwidth = graphemes::width(visible, self.tab_width);
                 let mut padding = self.width.saturating_sub(strwidth);
 
                 // Handle width overflow from trailing spaces
                 if strwidth > self.width {
                     // Remove trailing space if it causes overflow
-                    let content: String = wrapped_line
-                        .iter()
-                        .collect::<String>()
-                        .trim_end()
-                        .to_string();
+                    let content: String = visible.iter().collect::<String>().trim_end().to_string();
                     let new_wrapped_line: Vec<char> = content.chars().collect();
-                    let new_strwidth = new_wrapped_line
-                        .iter()
-                        .map(|&ch| unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0))
-                        .sum::<usize>();
+                    let new_strwidth = graphemes::width(&new_wrapped_line, self.tab_width);
                     padding = self.width.saturating_sub(new_strwidth);
                 }
 
                 // Render cursor if on current line and wrap
                 if is_current_doc_line && line_info.row_offset == wrap_idx {
-                    let col_offset = line_info.column_offset;
+                    let col_offset = line_info.column_offset.saturating_sub(from);
 
                     // Before cursor
-                    let before: String = wrapped_line.iter().take(col_offset).collect();
-                    s.push_str(&style.computed_cursor_line().render(&before));
+                    let before = &visible[..col_offset.min(visible.len())];
+                    s.push_str(&self.render_run(
+                        before,
+                        wrap_start + from,
+                        &marks,
+                        line_cursor_style,
+                    ));
+                    s.push_str(&self.composition.view());
+                    padding = padding.saturating_sub(self.composition.width());
 
                     // Cursor
                     if self.col >= line.len() && line_info.char_offset >= self.width {
-                        self.cursor.set_char(" ");
-                        s.push_str(&self.cursor.view());
+                        cursor.set_char(" ");
+                        s.push_str(&cursor.view());
                     } else {
-                        let cursor_char = wrapped_line.get(col_offset).unwrap_or(&' ');
-                        self.cursor.set_char(&cursor_char.to_string());
-                        s.push_str(&self.cursor.view());
+                        // The cursor covers a whole grapheme cluster
+                        let end = graphemes::next_boundary(visible, col_offset).max(col_offset + 1);
+                        let cluster = visible.get(col_offset..end).unwrap_or(&[' ']);
+                        // The cursor covers the first column of a tab
+                        let (cursor_text, tab_rest) = match cluster {
+                            ['\t'] => (
+                                " ".to_string(),
+                                " ".repeat(self.tab_width.saturating_sub(1)),
+                            ),
+                            _ => (cluster.iter().collect(), String::new()),
+                        };
+                        cursor.set_char(&cursor_text);
+                        s.push_str(&cursor.view());
 
                         // After cursor
-                        let after: String = wrapped_line.iter().skip(col_offset + 1).collect();
-                        s.push_str(&style.computed_cursor_line().render(&after));
+                        if !tab_rest.is_empty() {
+                            s.push_str(&line_cursor_style.render(&tab_rest));
+                        }
+                        let after = visible.get(end..).unwrap_or_default();
+                        s.push_str(&self.render_run(
+                            after,
+                            wrap_start + from + end,
+                            &marks,
+                            line_cursor_style,
+                        ));
                     }
                 } else {
                     // Regular line content
-                    let content: String = wrapped_line.iter().collect();
                     let line_style = if is_current_doc_line {
-                        style.computed_cursor_line()
+                        line_cursor_style
                     } else {
-                        style.computed_text()
+                        line_text_style
                     };
-                    s.push_str(&line_style.render(&content));
+                    s.push_str(&self.render_run(visible, wrap_start + from, &marks, line_style));
                 }
 
                 // Add padding
-                s.push_str(&style.computed_text().render(&" ".repeat(padding.max(0))));
+                s.push_str(&line_text_style.render(&" ".repeat(padding)));
                 s.push('\n');
+                wrap_start += wrapped_line.len();
             }
         }
 
         // Fill remaining height
-        for _ in 0..(self.height.saturating_sub(display_line)) {
-            let prompt = self.get_prompt_string(display_line);
-            s.push_str(&style.computed_prompt().render(&prompt));
+        let end_of_buffer_style = style.computed_end_of_buffer();
+        for _ in 0..(self.height.saturating_sub(rows_drawn)) {
+            match &fixed_prompt {
+                Some(prompt) => s.push_str(prompt),
+                None => s.push_str(&prompt_style.render(&self.get_prompt_string(display_line))),
+            }
             display_line += 1;
 
             let left_gutter = self.end_of_buffer_character.to_string();
             let right_gap_width =
-                self.width().saturating_sub(lipgloss::width(&left_gutter)) + widest_line_number;
-            let right_gap = " ".repeat(right_gap_width.max(0));
-            s.push_str(
-                &style
-                    .computed_end_of_buffer()
-                    .render(&(left_gutter + &right_gap)),
-            );
+                self.width().saturating_sub(text::width(&left_gutter)) + widest_line_number;
+            let right_gap = " ".repeat(right_gap_width);
+            s.push_str(&end_of_buffer_style.render(&(left_gutter + &right_gap)));
             s.push('\n');
         }
 
         s
     }
 
-    /// Get prompt string for a given display line - port of Go's getPromptString()
-    fn get_prompt_string(&self, display_line: usize) -> String {
-        if let Some(prompt_func) = self.prompt_func {
-            let prompt = prompt_func(display_line);
-            let pl = prompt.len();
-            if pl < self.prompt_width {
-                format!("{}{}", " ".repeat(self.prompt_width - pl), prompt)
+    /// Renders `chars`, which start at column `start` of their line, in
+    /// `style`, except for the columns covered by `marks`, which get the
+    /// style of the last mark covering them.
+    fn render_run(
+        &self,
+        chars: &[char],
+        start: usize,
+        marks: &[(usize, usize, &Style)],
+        style: &Style,
+    ) -> String {
+        let end = start + chars.len();
+        if !marks
+            .iter()
+            .any(|&(from, to, _)| from < to && from < end && to > start)
+        {
+            return style.render(&self.expand_tabs(chars.iter()));
+        }
+        let style_at = |i: usize| {
+            marks
+                .iter()
+                .rev()
+                .find(|(from, to, _)| (*from..*to).contains(&(start + i)))
+                .map_or(style, |(_, _, mark_style)| *mark_style)
+        };
+        let mut out = String::new();
+        let mut run_start = 0;
+        for i in 1..=chars.len() {
+            if i == chars.len() || !std::ptr::eq(style_at(i), style_at(run_start)) {
+                let part = &chars[run_start..i];
+                out.push_str(&style_at(run_start).render(&self.expand_tabs(part.iter())));
+                run_start = i;
+            }
+        }
+        out
+    }
+
+    /// Collects characters for display, drawing each tab as `tab_width`
+    /// spaces.
+    fn expand_tabs<'a>(&self, chars: impl Iterator<Item = &'a char>) -> String {
+        let mut out = String::new();
+        for &ch in chars {
+            if ch == '\t' {
+                out.extend(std::iter::repeat_n(' ', self.tab_width));
             } else {
-                prompt
+                out.push(ch);
             }
+        }
+        out
+    }
+
+    /// Get prompt string for a given display line - port of Go's getPromptString()
+    fn get_prompt_string(&self, display_line: usize) -> String {
+        if let Some(PromptFunc(prompt_func)) = &self.prompt_func {
+            text::pad(&prompt_func(display_line), self.prompt_width, Align::Right)
         } else {
             self.prompt.clone()
         }
     }
 
+    /// Columns the prompt takes up on each row.
+    fn prompt_columns(&self) -> usize {
+        if self.prompt_func.is_some() {
+            self.prompt_width
+        } else {
+            text::width(&self.prompt)
+        }
+    }
+
     /// Format line number for display - port of Go's formatLineNumber()
     fn format_line_number(&self, x: impl std::fmt::Display) -> String {
         // Calculate digits based on max height to ensure consistent formatting
@@ -1216,20 +2183,56 @@ impl Model {
     }
 
     /// Render placeholder text - port of Go's placeholder view logic
-    fn placeholder_view(&mut self) -> String {
+    fn placeholder_view(&self) -> String {
         if self.placeholder.is_empty() {
             return String::new();
         }
 
-        let mut s = String::new();
-
-        // Split placeholder into lines
-        let placeholder_lines: Vec<&str> = self.placeholder.lines().collect();
+        let style = &self.current_style;
+        let prompt_style = style.computed_prompt();
+        let placeholder_style = style.computed_placeholder();
+        let line_number_style = style.computed_line_number();
+        let end_of_buffer_style = style.computed_end_of_buffer();
+
+        // Word wrap the placeholder to the text width, then split any word
+        // that is wider than that on its own
+        let mut placeholder_lines: Vec<String> = Vec::new();
+        for line in self.placeholder.lines() {
+            let chars: Vec<char> = line.chars().collect();
+            for wrapped in self.cache.wrap(&chars, self.width) {
+                let wrapped: Vec<char> = wrapped
+                    .iter()
+                    .collect::<String>()
+                    .trim_end()
+                    .chars()
+                    .collect();
+                let mut start = 0;
+                loop {
+                    let mut end = start;
+                    let mut width = 0;
+                    while end < wrapped.len() {
+                        let next = graphemes::next_boundary(&wrapped, end);
+                        let cluster_width = graphemes::width(&wrapped[end..next], self.tab_width);
+                        if width + cluster_width > self.width && end > start {
+                            break;
+                        }
+                        width += cluster_width;
+                        end = next;
+                    }
+                    placeholder_lines.push(wrapped[start..end].iter().collect());
+                    start = end;
+                    if start >= wrapped.len() {
+                        break;
+                    }
+                }
+            }
+        }
 
+        let mut s = String::new();
         for i in 0..self.height {
             // Render prompt
             let prompt = self.get_prompt_string(i);
-            s.push_str(&prompt);
+            s.push_str(&prompt_style.render(&prompt));
 
             // Render line numbers
             if self.show_line_numbers {
@@ -1238,17 +2241,19 @@ impl Model {
                 } else {
                     self.format_line_number("")
                 };
-                s.push_str(&ln);
+                s.push_str(&line_number_style.render(&ln));
             }
 
             // Render line content
-            if i < placeholder_lines.len() {
+            if let Some(line) = placeholder_lines.get(i) {
                 // Render placeholder line (format_line_number already includes spacing)
-                s.push_str(placeholder_lines[i]);
+                s.push_str(&placeholder_style.render(line));
             } else {
                 // End of buffer character for empty lines
                 if self.end_of_buffer_character != ' ' {
-                    s.push(self.end_of_buffer_character);
+                    s.push_str(
+                        &end_of_buffer_style.render(&self.end_of_buffer_character.to_string()),
+                    );
                 }
             }
 
@@ -1261,13 +2266,43 @@ impl Model {
 
     /// Scroll viewport down by n lines - for testing viewport functionality
     pub fn scroll_down(&mut self, lines: usize) {
-        self.viewport.set_y_offset(self.viewport.y_offset + lines);
+        self.set_y_offset(self.viewport.y_offset + lines);
     }
 
     /// Scroll viewport up by n lines - for testing viewport functionality  
     pub fn scroll_up(&mut self, lines: usize) {
-        self.viewport
-            .set_y_offset(self.viewport.y_offset.saturating_sub(lines));
+        self.set_y_offset(self.viewport.y_offset.saturating_sub(lines));
+    }
+
+    /// Scrolls so that row `offset` of the wrapped text is at the top,
+    /// without scrolling past the last row.
+    fn set_y_offset(&mut self, offset: usize) {
+        let rows = self.rows(0..self.value.len());
+        self.viewport.y_offset = offset.min(rows.saturating_sub(self.viewport.height));
+    }
+
+    /// The wrap width and tab width that rows are counted at.
+    fn wrap_key(&self) -> WrapKey {
+        (self.wrap_width(), self.tab_width)
+    }
+
+    /// The number of rows the lines in `range` wrap to. Counts are kept per
+    /// line until it is edited.
+    fn rows(&mut self, range: std::ops::Range<usize>) -> usize {
+        let key = self.wrap_key();
+        let cache = &self.cache;
+        self.value
+            .rows(range, key, |line| cache.row_count(line, key.0))
+    }
+
+    /// The line that row `row` of the wrapped text belongs to, and which of
+    /// the line's rows it is.
+    #[cfg(feature = "mouse")]
+    fn line_at_row(&mut self, row: usize) -> (usize, usize) {
+        let key = self.wrap_key();
+        let cache = &self.cache;
+        self.value
+            .line_at_row(row, key, |line| cache.row_count(line, key.0))
     }
 
     /// Get cursor line number for display - port of Go's cursorLineNumber()
@@ -1277,13 +2312,7 @@ impl Model {
         }
 
         // Count visual lines from all preceding document lines
-        let mut line_count = 0;
-        for i in 0..self.row {
-            if let Some(line) = self.value.get(i).cloned() {
-                let wrapped_lines = self.cache.wrap(&line, self.width);
-                line_count += wrapped_lines.len();
-            }
-        }
+        let mut line_count = self.rows(0..self.row);
 
         // Add the row offset within the current document line
         line_count += self.line_info().row_offset;
@@ -1298,34 +2327,105 @@ impl Model {
 
         if cursor_line < minimum {
             // Cursor is above the visible area, scroll up
-            self.viewport.set_y_offset(cursor_line);
+            self.set_y_offset(cursor_line);
         } else if cursor_line > maximum {
             // Cursor is below the visible area, scroll down
             let new_offset = cursor_line.saturating_sub(self.viewport.height.saturating_sub(1));
-            self.viewport.set_y_offset(new_offset);
+            self.set_y_offset(new_offset);
         }
+        self.reposition_column();
     }
 
     /// Update handles incoming messages and updates the textarea state - port of Go's Update()
+    ///
+    /// Returns a [`LimitReachedMsg`] command when typed or pasted text was
+    /// cut short by [`char_limit`](Self::char_limit),
+    /// [`max_lines`](Self::max_lines) or [`max_height`](Self::max_height),
+    /// and a [`HeightChangedMsg`] command when
+    /// [`auto_height`](Self::set_auto_height) resized the textarea.
     pub fn update(&mut self, msg: Option<bubbletea_rs::Msg>) -> Option<bubbletea_rs::Cmd> {
+        self.limit_reached = None;
+        let mut cmds: Vec<Cmd> = self.handle_msg(msg).into_iter().collect();
+        if let Some(limit) = self.limit_reached.take() {
+            cmds.push(Box::pin(async move {
+                Some(Box::new(LimitReachedMsg { limit }) as bubbletea_rs::Msg)
+            }));
+        }
+        if self.fit_height() {
+            let height = self.height;
+            cmds.push(Box::pin(async move {
+                Some(Box::new(HeightChangedMsg { height }) as bubbletea_rs::Msg)
+            }));
+        }
+        match cmds.len() {
+            0 => None,
+            1 => cmds.pop(),
+            _ => Some(batch(cmds)),
+        }
+    }
+
+    fn handle_msg(&mut self, msg: Option<bubbletea_rs::Msg>) -> Option<bubbletea_rs::Cmd> {
+        if let Some(msg) = &msg {
+            if let Some((width, height)) = crate::widget::resized(msg, self.resize_margins) {
+                self.set_width(width);
+                self.set_height(height);
+                return None;
+            }
+        }
+
         if !self.focus {
             return None;
         }
 
         if let Some(msg) = msg {
-            // Handle clipboard messages first
-            if let Some(paste_msg) = msg.downcast_ref::<PasteMsg>() {
-                self.insert_string(paste_msg.0.clone());
+            // Handle clipboard messages first. Bracketed pastes from the
+            // terminal arrive whole too, rather than as a key per character,
+            // and either way a paste is one undo step.
+            let pasted = msg
+                .downcast_ref::<PasteMsg>()
+                .map(|paste| &paste.0)
+                .or_else(|| {
+                    msg.downcast_ref::<bubbletea_rs::PasteMsg>()
+                        .map(|paste| &paste.0)
+                });
+            if let Some(text) = pasted {
+                self.snippet_stops.clear();
+                self.kill_ring.end_kill();
+                self.record_edit(|m| m.insert_string(text.clone()));
+                return None;
+            }
+
+            if let Some(paste_err) = msg.downcast_ref::<PasteErrMsg>() {
+                self.err = Some(paste_err.0.clone());
+                return None;
+            }
+
+            // Composed text is inserted once committed, as one undo step
+            if let Some(ime) = msg.downcast_ref::<ImeMsg>() {
+                if let Some(text) = self.composition.update(ime) {
+                    self.snippet_stops.clear();
+                    self.kill_ring.end_kill();
+                    self.record_edit(|m| m.insert_string(text));
+                }
                 return None;
             }
 
-            if let Some(_paste_err) = msg.downcast_ref::<PasteErrMsg>() {
-                // Handle paste error - could be logged or shown to user
+            #[cfg(feature = "mouse")]
+            if crate::mouse::event(&msg).is_some() {
+                self.handle_mouse(&msg);
                 return None;
             }
 
             // Handle key messages
             if let Some(key_msg) = msg.downcast_ref::<bubbletea_rs::KeyMsg>() {
+                if self.composition.swallows(key_msg) {
+                    return None;
+                }
+                let km = &self.key_map;
+                if !crate::key::matches(key_msg, &[&km.copy_selection, &km.cut_selection]) {
+                    // Keys act on the cursor, so any other key ends a selection
+                    self.selection_anchor = None;
+                }
                 return self.handle_key_msg(key_msg);
             }
 
@@ -1351,12 +2451,53 @@ impl Model {
             return Some(cmd);
         }
 
+        if crate::key::matches_binding(key_msg, &self.key_map.toggle_overwrite) {
+            self.set_overwrite(!self.overwrite);
+            return None;
+        }
+
+        if crate::key::matches_binding(key_msg, &self.key_map.undo) {
+            self.undo();
+            return None;
+        }
+        if crate::key::matches_binding(key_msg, &self.key_map.redo) {
+            self.redo();
+            return None;
+        }
+        // While a snippet is being filled in, tab moves between its stops
+        if !self.snippet_stops.is_empty()
+            && crate::key::matches_binding(key_msg, &self.key_map.insert_tab)
+        {
+            self.next_snippet_stop();
+            self.history.break_step();
+            self.reposition_view();
+            return None;
+        }
+
+        let edit = self.edit_kind(key_msg).map(|kind| (kind, self.snapshot()));
+        if !self.is_kill_key(key_msg) {
+            self.kill_ring.end_kill();
+        }
+
         self.handle_movement_keys(key_msg);
         self.handle_deletion_keys(key_msg);
         self.handle_text_operations(key_msg);
         self.handle_text_insertion(key_msg);
         self.handle_character_input(key_msg);
 
+        match edit {
+            Some((kind, before)) => {
+                if before.value != self.value {
+                    self.history.record(kind, before, Instant::now());
+                }
+            }
+            None => {
+                // Moving away ends the snippet
+                self.history.break_step();
+                self.snippet_stops.clear();
+            }
+        }
+
         // Reposition viewport if cursor moved or content changed
         if self.row != old_row || self.col != old_col {
             self.reposition_view();
@@ -1365,6 +2506,59 @@ impl Model {
         None
     }
 
+    /// Classifies the edit a key makes for the undo history, or `None` for
+    /// keys that do not edit.
+    fn edit_kind(&self, key_msg: &bubbletea_rs::KeyMsg) -> Option<EditKind> {
+        use crate::key::matches_binding;
+
+        let km = &self.key_map;
+        let deletes = [
+            &km.delete_character_backward,
+            &km.delete_character_forward,
+            &km.delete_word_backward,
+            &km.delete_word_forward,
+            &km.delete_after_cursor,
+            &km.delete_before_cursor,
+        ];
+        let transforms = [
+            &km.yank,
+            &km.uppercase_word_forward,
+            &km.lowercase_word_forward,
+            &km.capitalize_word_forward,
+            &km.transpose_character_backward,
+            &km.expand_snippet,
+        ];
+        if deletes.iter().any(|b| matches_binding(key_msg, b)) {
+            Some(EditKind::Delete)
+        } else if transforms.iter().any(|b| matches_binding(key_msg, b)) {
+            Some(EditKind::Other)
+        } else if matches_binding(key_msg, &km.insert_newline)
+            || matches_binding(key_msg, &km.insert_tab)
+        {
+            Some(EditKind::Space)
+        } else {
+            match self.extract_character_from_key_msg(key_msg) {
+                Some(ch) if ch.is_whitespace() => Some(EditKind::Space),
+                Some(ch) if !ch.is_control() => Some(EditKind::Word),
+                _ => None,
+            }
+        }
+    }
+
+    /// Whether a key removes text into the kill ring.
+    fn is_kill_key(&self, key_msg: &bubbletea_rs::KeyMsg) -> bool {
+        let km = &self.key_map;
+        crate::key::matches(
+            key_msg,
+            &[
+                &km.delete_word_backward,
+                &km.delete_word_forward,
+                &km.delete_after_cursor,
+                &km.delete_before_cursor,
+            ],
+        )
+    }
+
     /// Handle clipboard-related key bindings
     fn handle_clipboard_keys(
         &mut self,
@@ -1375,6 +2569,15 @@ impl Model {
         if matches_binding(key_msg, &self.key_map.paste) {
             return Some(self.paste_command());
         }
+        if matches_binding(key_msg, &self.key_map.copy_selection) {
+            if let Err(err) = self.copy_selection() {
+                self.err = Some(err);
+            }
+        } else if matches_binding(key_msg, &self.key_map.cut_selection) {
+            if let Err(err) = self.cut_selection() {
+                self.err = Some(err);
+            }
+        }
 
         None
     }
@@ -1407,6 +2610,8 @@ impl Model {
             self.move_to_begin();
         } else if matches_binding(key_msg, &self.key_map.input_end) {
             self.move_to_end();
+        } else if matches_binding(key_msg, &self.key_map.jump_to_matching_bracket) {
+            self.jump_to_matching_bracket();
         }
     }
 
@@ -1450,6 +2655,12 @@ impl Model {
 
         if matches_binding(key_msg, &self.key_map.insert_newline) {
             self.insert_newline();
+        } else if matches_binding(key_msg, &self.key_map.insert_tab) {
+            self.insert_tab();
+        } else if matches_binding(key_msg, &self.key_map.yank) {
+            self.yank();
+        } else if matches_binding(key_msg, &self.key_map.expand_snippet) {
+            self.expand_snippet();
         }
     }
 
@@ -1461,6 +2672,13 @@ impl Model {
                 // Ignore control characters that aren't handled above
                 return;
             }
+            if self.overwrite && self.col < self.value.get(self.row).map_or(0, |l| l.len()) {
+                let line = &mut self.value[self.row];
+                let end = graphemes::next_boundary(line, self.col);
+                line.splice(self.col..end, [ch]);
+                self.set_cursor(self.col + 1);
+                return;
+            }
             self.insert_rune(ch);
         }
     }
@@ -1493,72 +2711,129 @@ impl Model {
 
     /// Create paste command for clipboard integration - port of Go's Paste()
     fn paste_command(&self) -> bubbletea_rs::Cmd {
-        // Use tick with minimal duration to trigger clipboard read
-        bubbletea_rs::tick(
-            std::time::Duration::from_millis(1),
-            |_| match Self::read_clipboard() {
-                Ok(content) => Box::new(PasteMsg(content)) as bubbletea_rs::Msg,
-                Err(err) => Box::new(PasteErrMsg(err)) as bubbletea_rs::Msg,
-            },
-        )
+        self.clipboard.read_cmd(|result| match result {
+            Ok(content) => Box::new(PasteMsg(content)) as bubbletea_rs::Msg,
+            Err(err) => Box::new(PasteErrMsg(err)) as bubbletea_rs::Msg,
+        })
     }
 
-    /// Read from system clipboard - matches textinput implementation
-    fn read_clipboard() -> Result<String, String> {
-        #[cfg(feature = "clipboard-support")]
-        {
-            use clipboard::{ClipboardContext, ClipboardProvider};
+    /// Copy text to the clipboard
+    pub fn copy_to_clipboard(&self, text: &str) -> Result<()> {
+        self.clipboard.write(text)
+    }
 
-            let res: Result<String, String> = (|| {
-                let mut ctx: ClipboardContext = ClipboardProvider::new()
-                    .map_err(|e| format!("Failed to create clipboard context: {}", e))?;
-                ctx.get_contents()
-                    .map_err(|e| format!("Failed to read clipboard: {}", e))
-            })();
-            res
-        }
-        #[cfg(not(feature = "clipboard-support"))]
-        {
-            // Return empty string instead of error to avoid disrupting workflow
-            Ok(String::new())
-        }
+    /// Copies the selected text to the clipboard, or the cursor's line and
+    /// its line break when nothing is selected.
+    pub fn copy_selection(&self) -> Result<()> {
+        let content = self
+            .selected_text()
+            .unwrap_or_else(|| self.current_line_text());
+        self.copy_to_clipboard(&content)
     }
 
-    /// Copy text to system clipboard
-    pub fn copy_to_clipboard(&self, text: &str) -> Result<(), String> {
-        #[cfg(feature = "clipboard-support")]
-        {
-            use clipboard::{ClipboardContext, ClipboardProvider};
+    /// Moves the selected text to the clipboard, or the cursor's line and
+    /// its line break when nothing is selected.
+    pub fn cut_selection(&mut self) -> Result<()> {
+        match self.selected_text() {
+            Some(content) => {
+                self.copy_to_clipboard(&content)?;
+                self.record_edit(Self::delete_selection);
+            }
+            None => {
+                self.copy_to_clipboard(&self.current_line_text())?;
+                self.record_edit(Self::delete_line);
+            }
+        }
+        Ok(())
+    }
 
-            let mut ctx: ClipboardContext = ClipboardProvider::new()
-                .map_err(|e| format!("Failed to create clipboard context: {}", e))?;
+    /// The cursor's line with a line break after it, as copied when nothing
+    /// is selected.
+    fn current_line_text(&self) -> String {
+        let mut text: String = self.value.get(self.row).into_iter().flatten().collect();
+        text.push('\n');
+        text
+    }
 
-            ctx.set_contents(text.to_string())
-                .map_err(|e| format!("Failed to write to clipboard: {}", e))
+    /// Removes the cursor's line, or empties it when it is the only one.
+    fn delete_line(&mut self) {
+        if self.value.len() > 1 {
+            self.value.remove(self.row);
+            self.row = self.row.min(self.value.len() - 1);
+        } else if let Some(line) = self.value.get_mut(self.row) {
+            line.clear();
         }
-        #[cfg(not(feature = "clipboard-support"))]
-        {
-            let _ = text; // Suppress unused parameter warning
-            Err("Clipboard support not enabled".to_string())
+        self.set_cursor(0);
+        self.reposition_view();
+    }
+
+    /// Returns the text position drawn at `col`, `row` of the view,
+    /// counting the prompt and line numbers and following soft wraps.
+    /// Cells past the end of a line give its end; rows below the text give
+    /// the end of the last line.
+    #[cfg(feature = "mouse")]
+    fn position_at(&mut self, col: usize, row: usize) -> (usize, usize) {
+        let prompt_width = self.prompt_columns();
+        let line_number_width = if self.show_line_numbers {
+            text::width(&self.format_line_number(""))
+        } else {
+            0
+        };
+        let mut target = col.saturating_sub(prompt_width + line_number_width);
+        if !self.soft_wrap {
+            target += self.viewport.x_offset;
         }
-    }
 
-    /// Copy current selection to clipboard (if selection is implemented)
-    pub fn copy_selection(&self) -> Result<(), String> {
-        // For now, copy entire content
-        // In a full implementation, this would copy only selected text
-        let content = self.value();
-        self.copy_to_clipboard(&content)
+        let row = self.viewport.y_offset + row;
+        if row >= self.rows(0..self.value.len()) {
+            let last = self.value.len().saturating_sub(1);
+            return (last, self.value[last].len());
+        }
+        let (doc_row, wrap_row) = self.line_at_row(row);
+        let wrapped = self.cache.wrap(&self.value[doc_row], self.wrap_width());
+        let start: usize = wrapped[..wrap_row].iter().map(Vec::len).sum();
+        let wrapped_line = &wrapped[wrap_row];
+
+        let mut x = 0;
+        let mut offset = 0;
+        while offset < wrapped_line.len() {
+            let next = graphemes::next_boundary(wrapped_line, offset);
+            let w = graphemes::width(&wrapped_line[offset..next], self.tab_width);
+            if x + w > target {
+                break;
+            }
+            x += w;
+            offset = next;
+        }
+        // Stay on this visual line rather than the next one's start
+        if wrap_row + 1 < wrapped.len() {
+            offset = offset.min(wrapped_line.len().saturating_sub(1));
+        }
+        (doc_row, (start + offset).min(self.value[doc_row].len()))
     }
 
-    /// Cut current selection to clipboard (if selection is implemented)
-    pub fn cut_selection(&mut self) -> Result<(), String> {
-        // For now, cut entire content
-        // In a full implementation, this would cut only selected text
-        let content = self.value();
-        self.copy_to_clipboard(&content)?;
-        self.reset();
-        Ok(())
+    #[cfg(feature = "mouse")]
+    fn handle_mouse(&mut self, msg: &bubbletea_rs::Msg) {
+        use crate::mouse::Gesture;
+        let (width, height) = crate::compose::size(&self.view());
+        let (row, col) = match self.mouse.handle(msg, width, height) {
+            Some(Gesture::Press { col, row }) => {
+                let position = self.position_at(col, row);
+                self.selection_anchor = Some(position);
+                position
+            }
+            Some(Gesture::Drag { col, row, .. }) => self.position_at(col, row),
+            Some(Gesture::Click { .. }) => {
+                self.selection_anchor = None;
+                return;
+            }
+            _ => return,
+        };
+        self.row = row;
+        self.set_cursor(col);
+        self.history.break_step();
+        self.kill_ring.end_kill();
+        self.reposition_view();
     }
 }
 
@@ -1580,6 +2855,7 @@ impl Component for Model {
         self.focus = false;
         self.current_style = self.blurred_style.clone();
         self.cursor.blur();
+        self.composition.cancel();
     }
 
     fn focused(&self) -> bool {
@@ -1594,6 +2870,107 @@ pub fn default_styles() -> (TextareaStyle, TextareaStyle) {
     (focused, blurred)
 }
 
+/// Focused and blurred styles derived from a theme.
+pub fn styles_from_theme(theme: &Theme) -> (TextareaStyle, TextareaStyle) {
+    let p = &theme.palette;
+    let focused = TextareaStyle {
+        base: Style::new(),
+        cursor_line: Style::new().background(p.selection.clone()),
+        cursor_line_number: Style::new().foreground(p.primary.clone()),
+        end_of_buffer: theme.subtle.clone(),
+        line_number: theme.muted.clone(),
+        placeholder: theme.muted.clone(),
+        prompt: Style::new().foreground(p.secondary.clone()),
+        text: theme.text.clone(),
+        selection: Style::new().reverse(true),
+        matching_bracket: Style::new()
+            .foreground(p.primary.clone())
+            .bold(true)
+            .underline(true),
+    };
+    let blurred = TextareaStyle {
+        cursor_line: theme.muted.clone(),
+        cursor_line_number: theme.muted.clone(),
+        prompt: theme.muted.clone(),
+        text: theme.muted.clone(),
+        ..focused.clone()
+    };
+    (focused, blurred)
+}
+
+/// Searches the text line by line. Going to a hit puts the cursor at its
+/// start.
+impl crate::search::Searchable for Model {
+    fn matches(&self, query: &str) -> Vec<crate::search::Hit> {
+        self.value
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                crate::search::hits_in(&line.iter().collect::<String>(), query, row, 0)
+            })
+            .collect()
+    }
+
+    fn goto(&mut self, hit: crate::search::Hit) {
+        if hit.row < self.value.len() {
+            self.row = hit.row;
+            self.set_cursor(hit.start);
+            self.reposition_view();
+        }
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        let (focused, blurred) = styles_from_theme(theme);
+        self.current_style = if self.focus {
+            focused.clone()
+        } else {
+            blurred.clone()
+        };
+        self.focused_style = focused;
+        self.blurred_style = blurred;
+    }
+}
+
+/// Saved state of a textarea; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The text, with lines separated by `\n`
+    pub value: String,
+    /// Cursor line
+    pub row: usize,
+    /// Cursor column, in characters
+    pub col: usize,
+    /// First visible line
+    pub scroll: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            value: self.value(),
+            row: self.row,
+            col: self.col,
+            scroll: self.viewport.y_offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_value(state.value);
+        self.row = state.row.min(self.value.len().saturating_sub(1));
+        self.set_cursor(state.col);
+        // The viewport only gets its lines when rendering, so set the offset
+        // directly and let the cursor pull it back into range.
+        let cursor_line = self.cursor_line_number();
+        self.viewport.y_offset = state.scroll.min(cursor_line);
+        self.reposition_view();
+    }
+}
+
 /// Create a new textarea model - convenience function
 pub fn new() -> Model {
     Model::new()
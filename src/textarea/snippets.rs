@@ -0,0 +1,60 @@
+//! Snippet bodies for the text area.
+//!
+//! A body is inserted as written, except that `$1` to `$9` mark tab stops,
+//! visited in order, `$0` marks where the cursor ends up, and `$$` is a
+//! literal dollar sign.
+
+/// Expands `body` into the text to insert and the offsets, in chars, of its
+/// tab stops in visiting order. Lines after the first get `indent` in front.
+/// The last stop is `$0`, or the end of the text when there is none.
+pub(super) fn expand(body: &str, indent: &[char]) -> (Vec<char>, Vec<usize>) {
+    // `$0` is numbered past the others so it sorts last
+    const FINAL: u32 = 10;
+
+    let mut text = Vec::new();
+    let mut stops: Vec<(u32, usize)> = Vec::new();
+    let mut chars = body.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '$' => match chars.peek().and_then(|d| d.to_digit(10)) {
+                Some(n) => {
+                    chars.next();
+                    let n = if n == 0 { FINAL } else { n };
+                    // Only the first of repeated stops is visited
+                    if !stops.iter().any(|&(m, _)| m == n) {
+                        stops.push((n, text.len()));
+                    }
+                }
+                None => {
+                    if chars.peek() == Some(&'$') {
+                        chars.next();
+                    }
+                    text.push('$');
+                }
+            },
+            '\n' => {
+                text.push('\n');
+                text.extend_from_slice(indent);
+            }
+            _ => text.push(ch),
+        }
+    }
+    if !stops.iter().any(|&(n, _)| n == FINAL) {
+        stops.push((FINAL, text.len()));
+    }
+    stops.sort_by_key(|&(n, _)| n);
+    (text, stops.into_iter().map(|(_, offset)| offset).collect())
+}
+
+/// The row and column that `offset` into `text` ends up at when `text` is
+/// inserted at `(row, col)`.
+pub(super) fn position(text: &[char], offset: usize, (row, col): (usize, usize)) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    match before.iter().rposition(|&ch| ch == '\n') {
+        Some(newline) => (
+            row + before.iter().filter(|&&ch| ch == '\n').count(),
+            before.len() - newline - 1,
+        ),
+        None => (row, col + before.len()),
+    }
+}
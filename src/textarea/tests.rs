@@ -5,7 +5,9 @@
 
 #[cfg(test)]
 mod textarea_tests {
-    use crate::textarea::Model;
+    use crate::textarea::{
+        Change, HeightChangedMsg, Limit, LimitReachedMsg, Model, PasteMsg, Stats,
+    };
     use crate::Component;
 
     /// Test result structure matching Go's want struct
@@ -604,6 +606,19 @@ mod textarea_tests {
         assert_eq!(textarea.height(), 3);
     }
 
+    #[test]
+    fn test_line_number_gutter_matches_max_height() {
+        let mut textarea = new_text_area();
+        textarea.max_height = 1000;
+        textarea.set_width(20);
+        // Prompt (2) plus a gutter of four digits with a margin each side
+        assert_eq!(textarea.width(), 12);
+
+        textarea.max_height = 9;
+        textarea.set_width(20);
+        assert_eq!(textarea.width(), 15);
+    }
+
     #[test]
     fn test_line_info_calculation() {
         let mut textarea = new_text_area();
@@ -834,6 +849,25 @@ mod textarea_tests {
         assert_eq!(textarea.prompt_width, 2);
     }
 
+    #[test]
+    fn test_prompt_function_can_capture_state() {
+        let markers = ['+', ' ', '-'];
+        let mut textarea = Model::builder()
+            .show_line_numbers(false)
+            .prompt_func(2, move |line| format!("{} ", markers[line % 3]))
+            .value("first\nsecond\nthird")
+            .build();
+        textarea.set_height(3);
+        assert_eq!(
+            normalize_string(&textarea.view()),
+            "+ first\n  second\n- third"
+        );
+
+        // Clones share the function
+        let mut clone = textarea.clone();
+        assert_eq!(clone.view(), textarea.view());
+    }
+
     #[test]
     fn test_comprehensive_editing_operations() {
         let mut textarea = new_text_area();
@@ -935,6 +969,21 @@ mod textarea_tests {
         assert_eq!(info1.height, info2.height);
     }
 
+    #[test]
+    fn test_render_through_shared_reference_keeps_cache() {
+        let mut textarea = new_text_area();
+        textarea.set_width(10);
+        textarea.set_height(2);
+        textarea.insert_string("one\ntwo\nthree long line\nfour");
+        textarea.cache.clear_cache();
+
+        let shared = &textarea;
+        let rendered = shared.render();
+        assert!(shared.cache.size() > 0, "wrapped lines stay cached");
+        assert_eq!(rendered, textarea.view());
+        assert!(rendered.contains("two") && !rendered.contains("three"));
+    }
+
     #[test]
     fn test_edge_cases() {
         // Test various boundary conditions that could cause infinite loops
@@ -1021,4 +1070,1032 @@ mod textarea_tests {
         textarea.scroll_up(1000); // Excessive scroll back
                                   // Should not crash and should handle gracefully
     }
+
+    #[test]
+    fn test_copy_and_cut_use_injected_clipboard() {
+        use crate::clipboard::{Clipboard, ClipboardProvider, Memory};
+
+        let memory = Memory::new();
+        let mut textarea = new_text_area().with_clipboard(Clipboard::new(memory.clone()));
+        textarea.insert_string("one\ntwo");
+
+        // Without a selection, the cursor's line is copied
+        textarea.copy_selection().unwrap();
+        assert_eq!(memory.text(), "two\n");
+
+        memory.write("").unwrap();
+        textarea.row = 0;
+        textarea.cut_selection().unwrap();
+        assert_eq!(memory.text(), "one\n");
+        assert_eq!(textarea.value(), "two");
+        assert_eq!((textarea.row, textarea.col), (0, 0));
+
+        textarea.cut_selection().unwrap();
+        assert_eq!(textarea.value(), "");
+        assert!(textarea.undo());
+        assert_eq!(textarea.value(), "two");
+    }
+
+    #[test]
+    fn test_copy_and_cut_keys() {
+        use crate::clipboard::{Clipboard, Memory};
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let memory = Memory::new();
+        let mut textarea = new_text_area().with_clipboard(Clipboard::new(memory.clone()));
+        textarea.insert_string("one two\nthree");
+        textarea.row = 0;
+        textarea.set_cursor(4);
+        textarea.selection_anchor = Some((0, 7));
+
+        textarea.update(Some(Box::new(KeyMsg {
+            key: KeyCode::Char('w'),
+            modifiers: KeyModifiers::ALT,
+        })));
+        assert_eq!(memory.text(), "two");
+        assert!(
+            textarea.selection().is_some(),
+            "copying keeps the selection"
+        );
+
+        textarea.update(Some(Box::new(KeyMsg {
+            key: KeyCode::Delete,
+            modifiers: KeyModifiers::SHIFT,
+        })));
+        assert_eq!(textarea.value(), "one \nthree");
+
+        textarea.update(Some(Box::new(KeyMsg {
+            key: KeyCode::Insert,
+            modifiers: KeyModifiers::CONTROL,
+        })));
+        assert_eq!(memory.text(), "one \n");
+    }
+
+    #[test]
+    fn test_accessible_view_reads_text_and_cursor() {
+        let mut textarea = new_text_area();
+        textarea.placeholder = "Write a message".to_string();
+        assert_eq!(textarea.accessible_view(), "empty, Write a message");
+
+        textarea.insert_string("one\ntwo");
+        assert_eq!(
+            textarea.accessible_view(),
+            "one\ntwo\nline 2 of 2, column 4"
+        );
+    }
+
+    #[test]
+    fn test_overwrite_mode() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode| -> Option<bubbletea_rs::Msg> {
+            Some(Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            }))
+        };
+
+        let mut textarea = new_text_area();
+        textarea.insert_string("abc\ndef");
+        textarea.move_to_begin();
+
+        textarea.update(key(KeyCode::Insert));
+        assert!(textarea.overwrite());
+        assert!(textarea.cursor.overwrite());
+
+        for ch in ['x', 'y', 'z', 'w'] {
+            textarea.update(key(KeyCode::Char(ch)));
+        }
+        // Overwriting stops at the end of the line and then appends
+        assert_eq!(textarea.value(), "xyzw\ndef");
+
+        // Newlines are still inserted in overwrite mode
+        textarea.update(key(KeyCode::Enter));
+        assert_eq!(textarea.value(), "xyzw\n\ndef");
+
+        textarea.update(key(KeyCode::Insert));
+        assert!(!textarea.overwrite());
+
+        let textarea = Model::builder().overwrite(true).build();
+        assert!(textarea.overwrite());
+        assert!(textarea.cursor.overwrite());
+    }
+
+    #[test]
+    fn test_search_moves_cursor_to_hit() {
+        use crate::search::Searchable;
+
+        let mut textarea = new_text_area();
+        textarea.insert_string("first line\nsecond Line");
+        let hits = textarea.matches("line");
+        assert_eq!(hits.len(), 2);
+        textarea.goto(hits[1]);
+        assert_eq!(textarea.line(), 1);
+        assert_eq!(textarea.line_info().char_offset, 7);
+    }
+
+    #[test]
+    fn test_auto_resize_applies_while_blurred() {
+        use crate::widget::Margins;
+        use bubbletea_rs::WindowSizeMsg;
+
+        let resize = || -> Option<bubbletea_rs::Msg> {
+            Some(Box::new(WindowSizeMsg {
+                width: 50,
+                height: 12,
+            }))
+        };
+
+        let mut textarea = Model::new().auto_resize(Margins::new(2, 0, 0, 0));
+        textarea.update(resize());
+        assert_eq!(textarea.height(), 10);
+        assert_eq!(textarea.viewport.width, 50);
+
+        let mut textarea = Model::new();
+        let height = textarea.height();
+        textarea.update(resize());
+        assert_eq!(textarea.height(), height);
+    }
+
+    #[test]
+    fn test_builder_applies_limits_before_value() {
+        let textarea = Model::builder()
+            .width(20)
+            .height(3)
+            .prompt("> ")
+            .char_limit(5)
+            .value("hello world")
+            .build();
+        assert_eq!(textarea.value(), "hello");
+        assert_eq!(textarea.prompt, "> ");
+        assert_eq!(textarea.height(), 3);
+        assert!(!textarea.focused());
+    }
+
+    #[test]
+    fn test_max_lines_limits_insertion_and_reports_it() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let mut textarea = new_text_area();
+        textarea.set_max_lines(2);
+        assert_eq!(textarea.max_lines(), 2);
+
+        textarea.insert_string("one\ntwo\nthree");
+        assert_eq!(textarea.value(), "one\ntwo");
+
+        let cmd = textarea
+            .update(Some(Box::new(KeyMsg {
+                key: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+            })))
+            .expect("a refused newline returns a command");
+        let msg = runtime.block_on(cmd).expect("the command sends a message");
+        assert_eq!(
+            msg.downcast_ref::<LimitReachedMsg>(),
+            Some(&LimitReachedMsg {
+                limit: Limit::MaxLines
+            })
+        );
+        assert_eq!(textarea.value(), "one\ntwo");
+
+        // Edits that fit report nothing
+        assert!(textarea
+            .update(Some(Box::new(KeyMsg {
+                key: KeyCode::Char('!'),
+                modifiers: KeyModifiers::NONE,
+            })))
+            .is_none());
+        assert_eq!(textarea.value(), "one\ntwo!");
+
+        textarea.char_limit = textarea.length();
+        let cmd = textarea
+            .update(Some(Box::new(PasteMsg("more".into()))))
+            .expect("a dropped paste returns a command");
+        let msg = runtime.block_on(cmd).expect("the command sends a message");
+        assert_eq!(
+            msg.downcast_ref::<LimitReachedMsg>().map(|m| m.limit),
+            Some(Limit::CharLimit)
+        );
+    }
+
+    #[test]
+    fn test_baseline_tracks_modifications() {
+        let mut textarea = new_text_area();
+        assert!(!textarea.is_modified());
+
+        textarea.set_value("the quick brown fox");
+        assert!(textarea.is_modified());
+        textarea.set_baseline();
+        assert!(!textarea.is_modified());
+        assert_eq!(
+            textarea.diff(),
+            vec![Change::Equal("the quick brown fox".into())]
+        );
+
+        textarea.set_value("the slow brown fox, jumping");
+        assert!(textarea.is_modified());
+        assert_eq!(
+            textarea.diff(),
+            vec![
+                Change::Equal("the ".into()),
+                Change::Delete("quick".into()),
+                Change::Insert("slow".into()),
+                Change::Equal(" brown fox".into()),
+                Change::Insert(", jumping".into()),
+            ]
+        );
+
+        // Editing back to the baseline clears the modified state
+        textarea.set_value("the quick brown fox");
+        assert!(!textarea.is_modified());
+    }
+
+    #[test]
+    fn test_diff_aligns_separate_edits() {
+        let mut textarea = new_text_area();
+        textarea.set_value("one two three four five six");
+        textarea.set_baseline();
+
+        textarea.set_value("one 2 three four 5 six seven");
+        assert_eq!(
+            textarea.diff(),
+            vec![
+                Change::Equal("one ".into()),
+                Change::Delete("two".into()),
+                Change::Insert("2".into()),
+                Change::Equal(" three four ".into()),
+                Change::Delete("five".into()),
+                Change::Insert("5".into()),
+                Change::Equal(" six".into()),
+                Change::Insert(" seven".into()),
+            ]
+        );
+
+        // The answer is remembered until the next edit
+        textarea.set_value("one two three four five six");
+        assert!(!textarea.is_modified());
+        textarea.insert_string("!");
+        assert!(textarea.is_modified());
+        assert!(textarea.is_modified());
+        textarea.delete_character_backward();
+        assert!(!textarea.is_modified());
+    }
+
+    #[test]
+    fn test_without_soft_wrap_long_lines_scroll_horizontally() {
+        let mut textarea = new_text_area();
+        textarea.show_line_numbers = false;
+        textarea.set_width(12);
+        textarea.set_height(2);
+        textarea.set_soft_wrap(false);
+
+        textarea.insert_string("the quick brown fox\nshort");
+        textarea.cursor_up();
+        textarea.cursor_end();
+        assert_eq!(textarea.line_info().height, 1);
+
+        // The end of the first line is scrolled into view, and the second
+        // line is scrolled along with it
+        let view = normalize_string(&textarea.view());
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines[0], "> brown fox");
+        assert_eq!(lines[1], ">");
+
+        textarea.cursor_start();
+        let view = normalize_string(&textarea.view());
+        assert_eq!(view.lines().next(), Some("> the quick"));
+    }
+
+    #[test]
+    fn test_cursor_moves_and_deletes_by_grapheme_cluster() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let mut textarea = new_text_area();
+        textarea.insert_string(format!("ae\u{301}{family}b"));
+        assert_eq!(textarea.col, 9);
+
+        textarea.character_left(false);
+        assert_eq!(textarea.col, 8);
+        textarea.character_left(false);
+        assert_eq!(textarea.col, 3, "the ZWJ sequence is one step");
+        assert_eq!(textarea.line_info().char_offset, 2);
+        textarea.character_left(false);
+        assert_eq!(textarea.col, 1, "the combining mark goes with its letter");
+        textarea.character_right();
+        assert_eq!(textarea.col, 3);
+
+        textarea.delete_character_forward();
+        assert_eq!(textarea.value(), "ae\u{301}b");
+        textarea.delete_character_backward();
+        assert_eq!(textarea.value(), "ab");
+
+        // The family emoji is drawn two columns wide
+        textarea.set_value(family);
+        assert_eq!(textarea.line_info().char_offset, 2);
+    }
+
+    #[test]
+    fn test_line_decorations_replace_prompt_and_style_lines() {
+        use crate::textarea::LineDecoration;
+        use lipgloss_extras::lipgloss::renderer::{self, ColorProfileKind};
+        use lipgloss_extras::prelude::{Color, Style};
+
+        renderer::set_color_profile(ColorProfileKind::TrueColor);
+
+        let mut textarea = new_text_area();
+        textarea.insert_string("fine\nbroken\nfine");
+        textarea.set_line_decorations(vec![LineDecoration::new(1)
+            .icon("●")
+            .line_number_style(Style::new().foreground(Color::from("9")))
+            .background(Color::from("52"))]);
+        assert_eq!(textarea.line_decorations().len(), 1);
+
+        let view = textarea.view();
+        let lines: Vec<String> = normalize_string(&view)
+            .lines()
+            .map(str::to_string)
+            .collect();
+        assert!(lines[0].starts_with("> "));
+        assert!(lines[1].starts_with("● "), "got {:?}", lines[1]);
+        assert!(lines[2].starts_with("> "));
+
+        // Only the decorated line gets the background
+        let raw: Vec<&str> = view.lines().collect();
+        assert!(raw[1].contains("\x1b[48;2;95;0;0m"), "got {:?}", raw[1]);
+        assert!(!raw[0].contains("\x1b[48;2;95;0;0m"));
+    }
+
+    #[test]
+    fn test_jump_to_matching_bracket() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut textarea = new_text_area();
+        textarea.insert_string("f(a[b]\n  c)");
+        textarea.row = 0;
+        textarea.set_cursor(1);
+        assert!(textarea.jump_to_matching_bracket());
+        assert_eq!((textarea.row, textarea.col), (1, 3));
+
+        // Just after a bracket counts too
+        textarea.row = 0;
+        textarea.set_cursor(6);
+        textarea.update(Some(Box::new(KeyMsg {
+            key: KeyCode::Char(']'),
+            modifiers: KeyModifiers::CONTROL,
+        })));
+        assert_eq!((textarea.row, textarea.col), (0, 3));
+        assert_eq!(textarea.value(), "f(a[b]\n  c)");
+
+        // The matching bracket is highlighted
+        let bracket = textarea
+            .focused_style
+            .computed_matching_bracket()
+            .render("]");
+        assert!(textarea.view().contains(&bracket));
+
+        textarea.set_cursor(0);
+        assert!(!textarea.jump_to_matching_bracket());
+    }
+
+    #[test]
+    fn test_placeholder_is_styled_and_wrapped() {
+        let mut textarea = new_text_area();
+        textarea.show_line_numbers = false;
+        textarea.set_width(14);
+        textarea.set_height(4);
+        textarea.placeholder = "Describe the change briefly\nsupercalifragilistic".into();
+
+        let view = textarea.view();
+        assert_eq!(
+            normalize_string(&view),
+            "> Describe\n> the change\n> briefly\n> supercalifra"
+        );
+        let styled = textarea
+            .focused_style
+            .computed_placeholder()
+            .render("the change");
+        assert!(view.contains(&styled));
+    }
+
+    #[test]
+    fn test_cursor_position_and_goto_line() {
+        let mut textarea = new_text_area();
+        textarea.insert_string("one\ntwo\nthree\nfour");
+        assert_eq!(textarea.cursor_position(), (3, 4));
+
+        textarea.set_position(1, 2);
+        assert_eq!(textarea.cursor_position(), (1, 2));
+        textarea.set_position(9, 99);
+        assert_eq!(textarea.cursor_position(), (3, 4));
+
+        textarea.goto_line(1);
+        assert_eq!(textarea.cursor_position(), (0, 0));
+        textarea.goto_line(3);
+        assert_eq!(textarea.cursor_position(), (2, 0));
+    }
+
+    #[test]
+    fn test_view_keeps_focused_and_blurred_styles() {
+        let mut textarea = new_text_area();
+        textarea.insert_string("styled");
+        let prompt = textarea.focused_style.computed_prompt().render("> ");
+        assert!(textarea.view().contains(&prompt));
+
+        textarea.blur();
+        let text = textarea
+            .blurred_style
+            .computed_cursor_line()
+            .render("styled");
+        assert!(textarea.view().contains(&text));
+    }
+
+    #[test]
+    fn test_stats_count_text() {
+        let mut textarea = new_text_area();
+        textarea.show_line_numbers = false;
+        textarea.set_width(12);
+        textarea.insert_string("one two three four\n\ne\u{301}t\u{e9}");
+        assert_eq!(
+            textarea.stats(),
+            Stats {
+                characters: 18 + 3,
+                words: 5,
+                lines: 3,
+                rows: 3 + 1 + 1,
+            }
+        );
+
+        textarea.insert_string(" five");
+        assert_eq!(textarea.stats().words, 6);
+    }
+
+    #[test]
+    fn test_expand_snippet() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |textarea: &mut Model, key, modifiers| {
+            textarea.update(Some(Box::new(KeyMsg { key, modifiers })));
+        };
+
+        let mut textarea = new_text_area();
+        textarea.register_snippet("fn", "fn $1($2) {\n    $0\n}");
+        textarea.insert_string("  cfn");
+        assert!(!textarea.expand_snippet(), "only whole words expand");
+
+        textarea.set_value("  fn");
+        key(&mut textarea, KeyCode::Char('/'), KeyModifiers::ALT);
+        assert_eq!(textarea.value(), "  fn () {\n      \n  }");
+        assert_eq!((textarea.row, textarea.col), (0, 5));
+
+        for ch in "main".chars() {
+            key(&mut textarea, KeyCode::Char(ch), KeyModifiers::NONE);
+        }
+        key(&mut textarea, KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!((textarea.row, textarea.col), (0, 10));
+        for ch in "a: u8".chars() {
+            key(&mut textarea, KeyCode::Char(ch), KeyModifiers::NONE);
+        }
+        key(&mut textarea, KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!((textarea.row, textarea.col), (1, 6));
+
+        // With the stops used up, tab inserts a tab again
+        key(&mut textarea, KeyCode::Tab, KeyModifiers::NONE);
+        assert_eq!(textarea.value(), "  fn main(a: u8) {\n        \n  }");
+
+        // Without stops the cursor ends after the body
+        textarea.set_value("x");
+        textarea.register_snippet("x", "a$$b");
+        assert!(textarea.expand_snippet());
+        assert_eq!(textarea.value(), "a$b");
+        assert_eq!(textarea.col, 3);
+    }
+
+    #[test]
+    fn test_view_draws_only_rows_in_view() {
+        let mut textarea = new_text_area();
+        textarea.show_line_numbers = false;
+        textarea.set_height(3);
+        textarea.insert_string(
+            (1..=10)
+                .map(|i| format!("line {i}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        textarea.set_position(9, 0);
+        assert_eq!(
+            normalize_string(&textarea.view()),
+            "> line 8\n> line 9\n> line 10"
+        );
+
+        textarea.scroll_up(2);
+        assert_eq!(
+            normalize_string(&textarea.view()),
+            "> line 6\n> line 7\n> line 8"
+        );
+
+        // Scrolling stops with the last line at the bottom
+        textarea.scroll_down(100);
+        assert_eq!(textarea.viewport.y_offset, 7);
+    }
+
+    #[test]
+    fn test_buffer_splices_across_chunks() {
+        use crate::textarea::buffer::Buffer;
+
+        let line = |i: usize| i.to_string().chars().collect::<Vec<_>>();
+        let mut buffer = Buffer::from((0..3000).map(line).collect::<Vec<_>>());
+        let snapshot = buffer.clone();
+        assert!(buffer == snapshot);
+
+        // Replace lines spanning several chunks
+        buffer.splice(500..2500, [line(9999)]);
+        assert_eq!(buffer.len(), 1001);
+        assert_eq!(buffer[499], line(499));
+        assert_eq!(buffer[500], line(9999));
+        assert_eq!(buffer[501], line(2500));
+        assert!(buffer != snapshot);
+        assert_eq!(snapshot[2000], line(2000), "snapshots keep their lines");
+
+        // Row counts are kept until a line is edited
+        let mut wrapped = 0;
+        let mut rows = |buffer: &mut Buffer| {
+            buffer.rows(0..buffer.len(), (80, 4), |_| {
+                wrapped += 1;
+                2
+            })
+        };
+        assert_eq!(rows(&mut buffer), 2002);
+        assert_eq!(rows(&mut buffer), 2002);
+        buffer[10].push('x');
+        assert_eq!(rows(&mut buffer), 2002);
+        assert_eq!(wrapped, 1002);
+        assert_eq!(buffer.line_at_row(1001, (80, 4), |_| 2), (500, 1));
+
+        // A shared buffer finds the same rows, counted or not
+        buffer[10].push('y');
+        for row in [0, 21, 1001, 2001, 5000] {
+            let expected = buffer.clone().line_at_row(row, (80, 4), |_| 2);
+            assert_eq!(buffer.peek_line_at_row(row, (80, 4), |_| 2), expected);
+            assert_eq!(
+                Buffer::from(vec![line(0)])
+                    .peek_line_at_row(row, (9, 4), |_| 3)
+                    .1,
+                row.min(2)
+            );
+        }
+    }
+
+    #[test]
+    fn test_inserted_text_is_sanitized() {
+        let mut textarea = new_text_area();
+        textarea.insert_string("one\r\ntwo\x07\tthree\x1b[0m");
+        assert_eq!(textarea.value(), "one\ntwo\tthree[0m");
+
+        textarea.reset();
+        textarea.expand_tabs = true;
+        textarea.set_tab_width(2);
+        textarea.insert_string("a\tb");
+        assert_eq!(textarea.value(), "a  b");
+
+        // A custom filter replaces the default one
+        textarea.reset();
+        textarea.set_sanitizer(|runes| {
+            runes
+                .into_iter()
+                .filter(|ch| !ch.is_ascii_digit())
+                .collect()
+        });
+        textarea.insert_string("r2d2\r");
+        assert_eq!(textarea.value(), "rd\r");
+
+        let boxed: Box<dyn Fn(Vec<char>) -> Vec<char> + Send + Sync> = Box::new(|runes| {
+            runes
+                .into_iter()
+                .map(|ch| ch.to_ascii_uppercase())
+                .collect()
+        });
+        textarea.set_sanitizer(boxed);
+        textarea.insert_string("x");
+        assert_eq!(textarea.value(), "rd\rX");
+    }
+
+    #[test]
+    fn test_auto_height_follows_wrapped_rows() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let mut textarea = Model::builder()
+            .width(20)
+            .height(6)
+            .max_height(4)
+            .auto_height(true)
+            .build();
+        let _ = textarea.focus();
+        assert_eq!(textarea.height(), 1);
+
+        let enter = |textarea: &mut Model| {
+            let cmd = textarea.update(Some(Box::new(KeyMsg {
+                key: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+            })))?;
+            let msg = runtime.block_on(cmd)?;
+            msg.downcast_ref::<HeightChangedMsg>().map(|m| m.height)
+        };
+        assert_eq!(enter(&mut textarea), Some(2));
+        assert_eq!(enter(&mut textarea), Some(3));
+        assert_eq!(textarea.height(), 3);
+
+        // Wrapped rows count too, up to the maximum height
+        textarea.insert_string("a long line that wraps");
+        textarea.update(None);
+        assert_eq!(textarea.height(), 4);
+        assert_eq!(enter(&mut textarea), None, "already at max_height");
+
+        textarea.reset();
+        textarea.update(None);
+        assert_eq!(textarea.height(), 1);
+        textarea.insert_string("short");
+        assert_eq!(textarea.view().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_bracketed_paste_is_one_undo_step() {
+        let mut textarea = new_text_area();
+        textarea.insert_string("start ");
+        textarea.update(Some(Box::new(bubbletea_rs::PasteMsg(
+            "one two\r\nthree".into(),
+        ))));
+        assert_eq!(textarea.value(), "start one two\nthree");
+        assert_eq!(textarea.cursor_position(), (1, 5));
+
+        assert!(textarea.undo());
+        assert_eq!(textarea.value(), "start ");
+        assert!(textarea.redo());
+        assert_eq!(textarea.value(), "start one two\nthree");
+    }
+
+    #[test]
+    fn test_ime_composition_is_drawn_at_the_cursor() {
+        use crate::ime::ImeMsg;
+
+        let mut textarea = new_text_area();
+        textarea.insert_string("one\ntwo");
+        textarea.update(Some(Box::new(ImeMsg::Preedit("é".into()))));
+        assert_eq!(textarea.value(), "one\ntwo");
+        assert!(lipgloss_extras::lipgloss::strip_ansi(&textarea.view()).contains("2 twoé "));
+
+        // Blurring drops the composition
+        textarea.blur();
+        assert!(!lipgloss_extras::lipgloss::strip_ansi(&textarea.view()).contains('é'));
+        textarea.focus = true;
+
+        textarea.update(Some(Box::new(ImeMsg::Preedit("e".into()))));
+        textarea.update(Some(Box::new(ImeMsg::Commit("é".into()))));
+        assert_eq!(textarea.value(), "one\ntwoé");
+        assert!(textarea.undo());
+        assert_eq!(textarea.value(), "one\ntwo");
+    }
+
+    #[test]
+    fn test_wrapped_value_matches_view_rows() {
+        let mut textarea = new_text_area();
+        textarea.set_width(16);
+        textarea.insert_string("the quick brown fox jumps\n\nover");
+
+        let rows = textarea.wrapped_value();
+        assert_eq!(rows.concat(), "the quick brown fox jumpsover");
+        assert_eq!(rows.len(), textarea.stats().rows);
+        let view = normalize_string(&textarea.view());
+        for (row, drawn) in rows.iter().zip(view.lines()) {
+            assert!(drawn.ends_with(row.trim_end()), "{row:?} in {drawn:?}");
+        }
+
+        textarea.set_soft_wrap(false);
+        assert_eq!(
+            textarea.wrapped_value(),
+            ["the quick brown fox jumps", "", "over"]
+        );
+    }
+
+    #[test]
+    fn test_undo_redo_coalesces_words() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode, modifiers: KeyModifiers| -> Option<bubbletea_rs::Msg> {
+            Some(Box::new(KeyMsg {
+                key: code,
+                modifiers,
+            }))
+        };
+        let ctrl = |c: char| key(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        let mut textarea = new_text_area();
+        for ch in "hello world".chars() {
+            textarea.update(key(KeyCode::Char(ch), KeyModifiers::NONE));
+        }
+        textarea.update(key(KeyCode::Backspace, KeyModifiers::NONE));
+        textarea.update(key(KeyCode::Backspace, KeyModifiers::NONE));
+        assert_eq!(textarea.value(), "hello wor");
+
+        // Both backspaces go in one step, then the second word, then the first
+        textarea.update(ctrl('z'));
+        assert_eq!(textarea.value(), "hello world");
+        textarea.update(ctrl('z'));
+        assert_eq!(textarea.value(), "hello ");
+        textarea.update(ctrl('z'));
+        assert_eq!(textarea.value(), "");
+        assert!(!textarea.undo());
+
+        textarea.update(ctrl('r'));
+        assert_eq!(textarea.value(), "hello ");
+        assert_eq!(textarea.line_info().char_offset, 6);
+
+        // A new edit drops what could be redone
+        textarea.update(key(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert!(!textarea.can_redo());
+        assert!(!textarea.redo());
+        assert_eq!(textarea.value(), "hello x");
+
+        // Moving the cursor ends the step
+        textarea.update(key(KeyCode::Left, KeyModifiers::NONE));
+        textarea.update(key(KeyCode::Char('y'), KeyModifiers::NONE));
+        textarea.undo();
+        assert_eq!(textarea.value(), "hello x");
+
+        textarea.set_value("fresh");
+        assert!(!textarea.can_undo());
+    }
+
+    #[test]
+    fn test_tabs_and_auto_indent() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode| -> Option<bubbletea_rs::Msg> {
+            Some(Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            }))
+        };
+
+        // Soft tabs fill up to the next tab stop
+        let mut textarea = Model::builder().width(40).auto_indent(true).build();
+        textarea.focus = true;
+        textarea.insert_string("ab");
+        textarea.update(key(KeyCode::Tab));
+        assert_eq!(textarea.value(), "ab  ");
+        textarea.update(key(KeyCode::Tab));
+        assert_eq!(textarea.value(), "ab      ");
+
+        // The new line keeps the indentation
+        textarea.set_value("    fn main() {");
+        textarea.update(key(KeyCode::Enter));
+        textarea.insert_string("x");
+        assert_eq!(textarea.value(), "    fn main() {\n    x");
+
+        // Hard tabs are kept in the value and drawn tab_width wide
+        let mut textarea = Model::builder()
+            .width(40)
+            .tab_width(8)
+            .soft_tabs(false)
+            .auto_indent(true)
+            .build();
+        textarea.insert_tab();
+        textarea.insert_string("x");
+        textarea.insert_newline();
+        assert_eq!(textarea.value(), "\tx\n\t");
+        assert_eq!(textarea.line_info().char_offset, 8);
+        let view = normalize_string(&textarea.view());
+        assert!(view.lines().next().unwrap().ends_with("        x"));
+    }
+
+    #[test]
+    fn test_kills_collect_and_yank_back() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let ctrl = |c: char| -> Option<bubbletea_rs::Msg> {
+            Some(Box::new(KeyMsg {
+                key: KeyCode::Char(c),
+                modifiers: KeyModifiers::CONTROL,
+            }))
+        };
+
+        let mut textarea = new_text_area();
+        textarea.insert_string("one two three");
+
+        // Kills in a row yank back as one
+        textarea.update(ctrl('w'));
+        textarea.update(ctrl('u'));
+        assert_eq!(textarea.value(), "");
+        textarea.update(ctrl('y'));
+        assert_eq!(textarea.value(), "one two three");
+
+        // Any other key starts a new entry
+        textarea.update(ctrl('a'));
+        textarea.update(ctrl('k'));
+        assert_eq!(textarea.value(), "");
+        textarea.update(ctrl('y'));
+        textarea.update(ctrl('y'));
+        assert_eq!(textarea.value(), "one two threeone two three");
+    }
+
+    #[cfg(feature = "mouse")]
+    #[test]
+    fn test_mouse_click_moves_cursor_and_drag_selects() {
+        use bubbletea_rs::MouseMsg;
+        use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+
+        let event = |button, x, y| -> Option<bubbletea_rs::Msg> {
+            Some(Box::new(MouseMsg {
+                x,
+                y,
+                button,
+                modifiers: KeyModifiers::NONE,
+            }))
+        };
+        let left = MouseButton::Left;
+
+        let clipboard = crate::clipboard::Memory::new();
+        let mut textarea = new_text_area()
+            .with_clipboard(crate::clipboard::Clipboard::new(clipboard.clone()))
+            .with_mouse_origin(10, 2);
+        textarea.insert_string("hello world\nsecond line");
+
+        // The prompt and line numbers take the first 6 columns
+        textarea.update(event(MouseEventKind::Down(left), 10 + 6 + 6, 2));
+        textarea.update(event(MouseEventKind::Up(left), 10 + 6 + 6, 2));
+        assert_eq!((textarea.row, textarea.col), (0, 6));
+        assert!(textarea.selection().is_none());
+
+        textarea.update(event(MouseEventKind::Down(left), 10 + 6 + 6, 2));
+        textarea.update(event(MouseEventKind::Drag(left), 10 + 6 + 3, 3));
+        textarea.update(event(MouseEventKind::Up(left), 10 + 6 + 3, 3));
+        assert_eq!(textarea.selection(), Some(((0, 6), (1, 3))));
+        assert_eq!(textarea.selected_text().as_deref(), Some("world\nsec"));
+        assert!(textarea.view().contains("\x1b[7m"));
+
+        textarea.cut_selection().unwrap();
+        assert_eq!(clipboard.text(), "world\nsec");
+        assert_eq!(textarea.value(), "hello ond line");
+        assert_eq!((textarea.row, textarea.col), (0, 6));
+
+        // Clicking past the end of a line puts the cursor at its end
+        textarea.update(event(MouseEventKind::Down(left), 10 + 30, 2));
+        assert_eq!((textarea.row, textarea.col), (0, 14));
+    }
+
+    #[cfg(feature = "vim")]
+    #[test]
+    fn test_vim_line_commands_at_line_limit() {
+        use crate::textarea::vim::VimMode;
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let keys = |editor: &mut VimMode, keys: &str| {
+            for ch in keys.chars() {
+                let key = if ch == '\x1b' {
+                    KeyCode::Esc
+                } else {
+                    KeyCode::Char(ch)
+                };
+                editor.update(Some(Box::new(KeyMsg {
+                    key,
+                    modifiers: KeyModifiers::NONE,
+                })));
+            }
+        };
+        let full = |value: &str| {
+            let mut textarea = new_text_area();
+            textarea.set_max_lines(value.lines().count());
+            textarea.set_value(value);
+            VimMode::new(textarea)
+        };
+
+        let mut editor = full("one\ntwo");
+        keys(&mut editor, "ggO\x1b");
+        assert_eq!(editor.textarea.value(), "one\ntwo");
+        assert_eq!(editor.textarea.cursor_position(), (0, 0));
+
+        let mut editor = full("one\ntwo");
+        keys(&mut editor, "ggo\x1b");
+        assert_eq!(editor.textarea.value(), "one\ntwo");
+        assert_eq!(editor.textarea.cursor_position().0, 0);
+
+        for put in ["Gyyp", "GyyP", "ggyyp", "ggyyP"] {
+            let mut editor = full("a\nb\nc");
+            keys(&mut editor, put);
+            assert_eq!(editor.textarea.value(), "a\nb\nc", "{put}");
+            assert!(editor.textarea.cursor_position().0 < 3, "{put}");
+        }
+    }
+
+    #[cfg(feature = "vim")]
+    #[test]
+    fn test_vim_mode_motions_and_operators() {
+        use crate::textarea::vim::{Mode, VimMode};
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let keys = |editor: &mut VimMode, keys: &str| {
+            for ch in keys.chars() {
+                let key = if ch == '\x1b' {
+                    KeyCode::Esc
+                } else {
+                    KeyCode::Char(ch)
+                };
+                editor.update(Some(Box::new(KeyMsg {
+                    key,
+                    modifiers: KeyModifiers::NONE,
+                })));
+            }
+        };
+
+        let mut editor = VimMode::new(new_text_area());
+        editor.textarea.set_value("one two three\nfour five\nsix");
+        keys(&mut editor, "gg");
+        assert_eq!(editor.mode(), Mode::Normal);
+        assert_eq!(editor.textarea.cursor_position(), (0, 0));
+
+        // Typed letters are commands, not text
+        keys(&mut editor, "wl");
+        assert_eq!(editor.textarea.cursor_position(), (0, 5));
+        keys(&mut editor, "$");
+        assert_eq!(
+            editor.textarea.cursor_position(),
+            (0, 12),
+            "on the last character"
+        );
+
+        keys(&mut editor, "0dw");
+        assert_eq!(editor.textarea.value(), "two three\nfour five\nsix");
+        assert_eq!(editor.register(), "one ");
+
+        keys(&mut editor, "jddp");
+        assert_eq!(editor.textarea.value(), "two three\nsix\nfour five");
+        assert_eq!(editor.textarea.cursor_position(), (2, 0));
+
+        keys(&mut editor, "ggyyP");
+        assert_eq!(
+            editor.textarea.value(),
+            "two three\ntwo three\nsix\nfour five"
+        );
+        keys(&mut editor, "2dd");
+        assert_eq!(editor.textarea.value(), "six\nfour five");
+        keys(&mut editor, "u");
+        assert_eq!(
+            editor.textarea.value(),
+            "two three\ntwo three\nsix\nfour five"
+        );
+
+        keys(&mut editor, "Gx");
+        assert_eq!(
+            editor.textarea.value(),
+            "two three\ntwo three\nsix\nour five"
+        );
+
+        keys(&mut editor, "A!\x1b");
+        assert_eq!(editor.mode(), Mode::Normal);
+        assert_eq!(
+            editor.textarea.value(),
+            "two three\ntwo three\nsix\nour five!"
+        );
+        assert_eq!(editor.textarea.cursor_position(), (3, 8));
+
+        keys(&mut editor, "ggcwten\x1b");
+        assert_eq!(
+            editor.textarea.value(),
+            "ten three\ntwo three\nsix\nour five!"
+        );
+
+        keys(&mut editor, "ohi\x1b");
+        assert_eq!(
+            editor.textarea.value(),
+            "ten three\nhi\ntwo three\nsix\nour five!"
+        );
+
+        // Visual mode takes the character under the cursor too
+        keys(&mut editor, "j0vey");
+        assert_eq!(editor.register(), "two");
+        assert_eq!(editor.mode(), Mode::Normal);
+        keys(&mut editor, "vld");
+        assert_eq!(
+            editor.textarea.value(),
+            "ten three\nhi\no three\nsix\nour five!"
+        );
+        assert!(editor.textarea.selection().is_none());
+    }
 }
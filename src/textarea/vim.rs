@@ -0,0 +1,594 @@
+//! Vim-style modal editing for the text area.
+//!
+//! [`VimMode`] wraps a [`Model`] and reads keys the way Vim does. Normal
+//! mode moves and edits with single letters, insert mode hands keys to the
+//! text area as usual, and visual mode selects text for an operator. Each
+//! command is carried out with the text area's own editing methods, so undo,
+//! limits and soft wrap behave as they do without the wrapper.
+//!
+//! Supported in normal mode:
+//!
+//! | Keys                      | Action                                          |
+//! | ------------------------- | ----------------------------------------------- |
+//! | `h` `j` `k` `l`, arrows   | Move by character or line                       |
+//! | `w` `b` `e`               | Next word start, previous word start, word end  |
+//! | `0` `^` `$`               | Line start, first non-blank, line end           |
+//! | `gg` `G`                  | First line, last line (or line N with a count)  |
+//! | `i` `a` `I` `A` `o` `O`   | Enter insert mode                               |
+//! | `x` `D` `C`               | Delete a character, to line end, or change it   |
+//! | `d` `c` `y` + motion      | Delete, change or yank over the motion          |
+//! | `dd` `cc` `yy`            | Delete, change or yank whole lines              |
+//! | `p` `P`                   | Put the register after or before the cursor     |
+//! | `u` `ctrl+r`              | Undo and redo                                   |
+//! | `v`                       | Enter visual mode                               |
+//!
+//! A count in front repeats the command, as in `3dd` or `2w`. In visual
+//! mode the motions extend the selection, and `d`/`x`, `c` and `y` act on
+//! it. Escape returns to normal mode.
+//!
+//! ```rust
+//! use bubbletea_widgets::textarea::{self, vim::{Mode, VimMode}};
+//! use bubbletea_widgets::Component;
+//!
+//! let mut editor = VimMode::new(textarea::new());
+//! let _ = editor.focus();
+//! editor.textarea.set_value("hello world");
+//! assert_eq!(editor.mode(), Mode::Normal);
+//! ```
+
+use super::{graphemes, Model};
+use crate::Component;
+use bubbletea_rs::{Cmd, KeyMsg, Msg};
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// The mode a [`VimMode`] editor is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Keys are commands.
+    #[default]
+    Normal,
+    /// Keys type text.
+    Insert,
+    /// Motions extend a selection that an operator then acts on.
+    Visual,
+}
+
+/// A text area with Vim-style normal, insert and visual modes.
+#[derive(Debug, Clone)]
+pub struct VimMode {
+    /// The wrapped text area.
+    pub textarea: Model,
+    mode: Mode,
+    /// Count typed so far, 0 when none
+    count: usize,
+    /// Operator or prefix waiting for the rest of the command
+    pending: Option<char>,
+    /// The unnamed register, and whether it holds whole lines
+    register: String,
+    linewise: bool,
+}
+
+impl VimMode {
+    /// Wraps `textarea`, starting in normal mode.
+    pub fn new(textarea: Model) -> Self {
+        Self {
+            textarea,
+            mode: Mode::Normal,
+            count: 0,
+            pending: None,
+            register: String::new(),
+            linewise: false,
+        }
+    }
+
+    /// The current mode.
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Switches mode, dropping any half-typed command. Leaving visual mode
+    /// drops the selection.
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.count = 0;
+        self.pending = None;
+        match mode {
+            Mode::Visual => {
+                self.textarea.selection_anchor = Some((self.textarea.row, self.textarea.col));
+            }
+            _ => self.textarea.clear_selection(),
+        }
+        self.mode = mode;
+        self.textarea.history.break_step();
+        self.clamp_cursor();
+    }
+
+    /// The text last deleted or yanked, which `p` and `P` put back.
+    pub fn register(&self) -> &str {
+        &self.register
+    }
+
+    /// Handles a message. Keys are read as Vim commands outside insert
+    /// mode; everything else goes to the text area.
+    pub fn update(&mut self, msg: Option<Msg>) -> Option<Cmd> {
+        let key = msg
+            .as_ref()
+            .and_then(|msg| msg.downcast_ref::<KeyMsg>())
+            .map(|key| (key.key, key.modifiers));
+        let Some((code, modifiers)) = key else {
+            return self.textarea.update(msg);
+        };
+        if !self.textarea.focused() {
+            return None;
+        }
+        if self.mode == Mode::Insert {
+            if code == KeyCode::Esc {
+                // Vim leaves the cursor on the last character typed
+                if self.textarea.col > 0 {
+                    self.textarea.character_left(true);
+                }
+                self.set_mode(Mode::Normal);
+                return None;
+            }
+            return self.textarea.update(msg);
+        }
+
+        self.handle_command(code, modifiers);
+        self.clamp_cursor();
+        self.textarea.kill_ring.end_kill();
+        self.textarea.reposition_view();
+        None
+    }
+
+    /// Renders the text area.
+    pub fn view(&mut self) -> String {
+        self.textarea.view()
+    }
+
+    fn handle_command(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        if code == KeyCode::Esc {
+            self.set_mode(Mode::Normal);
+            return;
+        }
+        if modifiers.contains(KeyModifiers::CONTROL) {
+            if code == KeyCode::Char('r') {
+                for _ in 0..self.take_count() {
+                    self.textarea.redo();
+                }
+            }
+            return;
+        }
+        if modifiers.contains(KeyModifiers::ALT) {
+            return;
+        }
+        let ch = match code {
+            KeyCode::Char(ch) => ch,
+            KeyCode::Left | KeyCode::Backspace => 'h',
+            KeyCode::Down => 'j',
+            KeyCode::Up => 'k',
+            KeyCode::Right => 'l',
+            KeyCode::Home => '0',
+            KeyCode::End => '$',
+            _ => return,
+        };
+
+        // Counts; a lone 0 is the line start motion
+        if let Some(digit) = ch.to_digit(10) {
+            if digit > 0 || self.count > 0 {
+                self.count = self.count * 10 + digit as usize;
+                return;
+            }
+        }
+
+        match (self.pending.take(), ch) {
+            (Some('g'), 'g') => {
+                let line = self.count.max(1);
+                self.count = 0;
+                self.move_to_line(line);
+            }
+            (Some(op), _) if op == ch => self.operate_on_lines(op),
+            (Some(op), motion) if op != 'g' => {
+                let motion = if op == 'c' && motion == 'w' {
+                    'e'
+                } else {
+                    motion
+                };
+                self.operate_on_motion(op, motion);
+            }
+            (_, 'g') => self.pending = Some('g'),
+            (None, _) if self.mode == Mode::Visual => self.visual_command(ch),
+            (None, _) => self.normal_command(ch),
+            _ => self.count = 0,
+        }
+    }
+
+    fn normal_command(&mut self, ch: char) {
+        match ch {
+            'd' | 'c' | 'y' => self.pending = Some(ch),
+            'i' => self.set_mode(Mode::Insert),
+            'a' => {
+                if self.current_line_len() > 0 {
+                    self.textarea.character_right();
+                }
+                self.set_mode(Mode::Insert);
+            }
+            'I' => {
+                self.first_non_blank();
+                self.set_mode(Mode::Insert);
+            }
+            'A' => {
+                self.textarea.cursor_end();
+                self.set_mode(Mode::Insert);
+            }
+            'o' => {
+                self.textarea.record_edit(|m| {
+                    m.cursor_end();
+                    m.insert_newline();
+                });
+                self.set_mode(Mode::Insert);
+            }
+            'O' => {
+                self.textarea.record_edit(|m| {
+                    let lines = m.value.len();
+                    m.cursor_start();
+                    m.insert_string("\n");
+                    // At the line limit no line opens and the cursor stays
+                    if m.value.len() > lines {
+                        m.row -= 1;
+                    }
+                    m.set_cursor(0);
+                });
+                self.set_mode(Mode::Insert);
+            }
+            'x' => self.operate_on_motion('d', 'l'),
+            'D' => self.operate_on_motion('d', '$'),
+            'C' => self.operate_on_motion('c', '$'),
+            'p' | 'P' => {
+                for _ in 0..self.take_count() {
+                    self.put(ch == 'p');
+                }
+            }
+            'u' => {
+                for _ in 0..self.take_count() {
+                    self.textarea.undo();
+                }
+            }
+            'v' => self.set_mode(Mode::Visual),
+            'G' => {
+                let line = match self.take_count_or_zero() {
+                    0 => self.textarea.value.len(),
+                    n => n,
+                };
+                self.move_to_line(line);
+            }
+            motion => {
+                for _ in 0..self.take_count() {
+                    self.apply_motion(motion);
+                }
+                self.textarea.history.break_step();
+            }
+        }
+    }
+
+    fn visual_command(&mut self, ch: char) {
+        match ch {
+            'd' | 'x' | 'c' | 'y' => {
+                let Some((start, end)) = self.textarea.selection_anchor.map(|anchor| {
+                    let cursor = (self.textarea.row, self.textarea.col);
+                    (anchor.min(cursor), anchor.max(cursor))
+                }) else {
+                    return;
+                };
+                // The character under the cursor is part of the selection
+                let line = &self.textarea.value[end.0];
+                let end = (end.0, graphemes::next_boundary(line, end.1).min(line.len()));
+                self.textarea.selection_anchor = None;
+                self.mode = Mode::Normal;
+                let op = if ch == 'x' { 'd' } else { ch };
+                self.operate(op, start, end, false);
+            }
+            'G' => {
+                let line = match self.take_count_or_zero() {
+                    0 => self.textarea.value.len(),
+                    n => n,
+                };
+                self.move_to_line(line);
+            }
+            'v' => self.set_mode(Mode::Normal),
+            motion => {
+                for _ in 0..self.take_count() {
+                    self.apply_motion(motion);
+                }
+            }
+        }
+    }
+
+    /// Moves the cursor by `motion`. Returns false for keys that are not
+    /// motions.
+    fn apply_motion(&mut self, motion: char) -> bool {
+        let ta = &mut self.textarea;
+        match motion {
+            'h' => {
+                if ta.col > 0 {
+                    ta.character_left(true);
+                }
+            }
+            'l' => {
+                if ta.col < ta.value[ta.row].len() {
+                    let line = &ta.value[ta.row];
+                    ta.set_cursor(graphemes::next_boundary(line, ta.col));
+                }
+            }
+            'j' => ta.cursor_down(),
+            'k' => ta.cursor_up(),
+            'w' => self.word_start_forward(),
+            'b' => self.word_start_backward(),
+            'e' => self.word_end_forward(),
+            '0' => ta.cursor_start(),
+            '^' => self.first_non_blank(),
+            '$' => ta.cursor_end(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Runs operator `op` from the cursor to where `motion` moves it.
+    fn operate_on_motion(&mut self, op: char, motion: char) {
+        let from = (self.textarea.row, self.textarea.col);
+        let mut moved = true;
+        for _ in 0..self.take_count() {
+            moved &= self.apply_motion(motion);
+        }
+        if !moved {
+            self.textarea.row = from.0;
+            self.textarea.set_cursor(from.1);
+            return;
+        }
+        let mut to = (self.textarea.row, self.textarea.col);
+        if motion == 'e' {
+            // `e` includes the character it lands on
+            let line = &self.textarea.value[to.0];
+            to.1 = graphemes::next_boundary(line, to.1).min(line.len());
+        } else if motion == 'w' && to.0 > from.0 {
+            // A word motion off the end of the line stops at the line end
+            to = (from.0, self.textarea.value[from.0].len());
+        }
+        self.operate(op, from.min(to), from.max(to), false);
+    }
+
+    /// Runs operator `op` on count whole lines from the cursor's.
+    fn operate_on_lines(&mut self, op: char) {
+        let count = self.take_count();
+        let ta = &self.textarea;
+        let first = ta.row;
+        let last = (first + count).min(ta.value.len()) - 1;
+        let end = (last, ta.value[last].len());
+        self.operate(op, (first, 0), end, true);
+    }
+
+    /// Yanks the text from `start` to `end` into the register, and for `d`
+    /// and `c` removes it, `c` then entering insert mode. Whole lines
+    /// take their line breaks with them.
+    fn operate(&mut self, op: char, start: (usize, usize), end: (usize, usize), lines: bool) {
+        let ta = &mut self.textarea;
+        ta.selection_anchor = Some(start);
+        ta.row = end.0;
+        ta.set_cursor(end.1);
+        let text = ta.selected_text().unwrap_or_default();
+        ta.selection_anchor = None;
+        if text.is_empty() && !lines {
+            ta.row = start.0;
+            ta.set_cursor(start.1);
+            return;
+        }
+        self.register = text;
+        self.linewise = lines;
+        if lines {
+            self.register.push('\n');
+        }
+
+        let ta = &mut self.textarea;
+        match op {
+            'd' if lines => ta.record_edit(|m| {
+                m.value.splice(start.0..end.0 + 1, []);
+                if m.value.is_empty() {
+                    m.value.push(Vec::new());
+                }
+                m.row = start.0.min(m.value.len() - 1);
+                m.set_cursor(0);
+            }),
+            'c' if lines => ta.record_edit(|m| {
+                m.value.splice(start.0..end.0 + 1, [Vec::new()]);
+                m.row = start.0;
+                m.set_cursor(0);
+            }),
+            'd' | 'c' => ta.record_edit(|m| {
+                m.selection_anchor = Some(start);
+                m.delete_selection();
+            }),
+            _ => {
+                ta.row = start.0;
+                ta.set_cursor(start.1);
+            }
+        }
+        if op == 'd' && lines {
+            self.first_non_blank();
+        }
+        if op == 'c' {
+            self.set_mode(Mode::Insert);
+        }
+    }
+
+    /// Puts the register after the cursor, or before it when `after` is
+    /// false. Whole lines go below or above the cursor's line.
+    fn put(&mut self, after: bool) {
+        if self.register.is_empty() {
+            return;
+        }
+        let text = self.register.clone();
+        let ta = &mut self.textarea;
+        if self.linewise {
+            let lines = text.strip_suffix('\n').unwrap_or(&text).to_string();
+            // Whole lines go in whole or not at all
+            let added = lines.split('\n').count();
+            if ta.max_lines > 0 && ta.value.len() + added > ta.max_lines {
+                return;
+            }
+            let row = ta.row;
+            ta.record_edit(|m| {
+                if after {
+                    m.cursor_end();
+                    m.insert_string(format!("\n{lines}"));
+                    m.row = row + 1;
+                } else {
+                    m.cursor_start();
+                    m.insert_string(format!("{lines}\n"));
+                    m.row = row;
+                }
+                m.set_cursor(0);
+            });
+            self.first_non_blank();
+        } else {
+            ta.record_edit(|m| {
+                if after && m.col < m.value[m.row].len() {
+                    let line = &m.value[m.row];
+                    m.set_cursor(graphemes::next_boundary(line, m.col));
+                }
+                m.insert_string(text);
+                // The cursor ends on the last character put
+                m.character_left(true);
+            });
+        }
+    }
+
+    /// Moves to the start of the next word, on this line or a later one.
+    fn word_start_forward(&mut self) {
+        let ta = &mut self.textarea;
+        if ta.value[ta.row]
+            .get(ta.col)
+            .is_some_and(|ch| !ch.is_whitespace())
+        {
+            ta.word_right();
+        }
+        loop {
+            let line = &ta.value[ta.row];
+            if let Some(skip) = line[ta.col..].iter().position(|ch| !ch.is_whitespace()) {
+                ta.set_cursor(ta.col + skip);
+                return;
+            }
+            if ta.row + 1 >= ta.value.len() {
+                ta.cursor_end();
+                return;
+            }
+            ta.row += 1;
+            ta.set_cursor(0);
+            // An empty line counts as a word
+            if ta.value[ta.row].is_empty() {
+                return;
+            }
+        }
+    }
+
+    /// Moves to the start of this word, or of the previous one when already
+    /// there, looking back over earlier lines.
+    fn word_start_backward(&mut self) {
+        let ta = &mut self.textarea;
+        loop {
+            ta.word_left();
+            let line = &ta.value[ta.row];
+            if line.get(ta.col).is_some_and(|ch| !ch.is_whitespace()) || ta.row == 0 {
+                return;
+            }
+            ta.row -= 1;
+            ta.cursor_end();
+            if ta.value[ta.row].is_empty() {
+                return;
+            }
+        }
+    }
+
+    /// Moves to the last character of this word, or of the next one when
+    /// already there.
+    fn word_end_forward(&mut self) {
+        let ta = &mut self.textarea;
+        ta.character_right();
+        loop {
+            let line = &ta.value[ta.row];
+            match line[ta.col..].iter().position(|ch| !ch.is_whitespace()) {
+                Some(skip) => {
+                    ta.set_cursor(ta.col + skip);
+                    break;
+                }
+                None if ta.row + 1 < ta.value.len() => {
+                    ta.row += 1;
+                    ta.set_cursor(0);
+                }
+                None => break,
+            }
+        }
+        ta.word_right();
+        if ta.col > 0 {
+            ta.character_left(true);
+        }
+    }
+
+    fn first_non_blank(&mut self) {
+        let ta = &mut self.textarea;
+        let line = &ta.value[ta.row];
+        let col = line
+            .iter()
+            .position(|ch| !ch.is_whitespace())
+            .unwrap_or(line.len());
+        ta.set_cursor(col);
+    }
+
+    /// Moves to the first non-blank of one-based line `line`.
+    fn move_to_line(&mut self, line: usize) {
+        self.textarea.goto_line(line);
+        self.first_non_blank();
+        self.textarea.history.break_step();
+    }
+
+    fn current_line_len(&self) -> usize {
+        let ta = &self.textarea;
+        ta.value.get(ta.row).map_or(0, Vec::len)
+    }
+
+    /// Keeps the cursor on a character outside insert mode, as Vim does.
+    fn clamp_cursor(&mut self) {
+        if self.mode == Mode::Insert {
+            return;
+        }
+        let ta = &mut self.textarea;
+        let line = ta.value.get(ta.row).map_or(&[][..], |line| line);
+        if !line.is_empty() && ta.col >= line.len() {
+            let last = graphemes::prev_boundary(line, line.len());
+            ta.set_cursor(last);
+        }
+        if self.mode == Mode::Visual && ta.selection_anchor.is_none() {
+            ta.selection_anchor = Some((ta.row, ta.col));
+        }
+    }
+
+    /// The count typed before the command, 1 when none, and clears it.
+    fn take_count(&mut self) -> usize {
+        self.take_count_or_zero().max(1)
+    }
+
+    fn take_count_or_zero(&mut self) -> usize {
+        std::mem::take(&mut self.count)
+    }
+}
+
+impl Component for VimMode {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.textarea.focus()
+    }
+
+    fn blur(&mut self) {
+        self.textarea.blur();
+    }
+
+    fn focused(&self) -> bool {
+        self.textarea.focused()
+    }
+}
@@ -0,0 +1,68 @@
+//! Prefix and suffix adornments for the textinput component.
+//!
+//! Adornments are fixed text drawn around the editable region, such as a
+//! URL scheme or a unit. They are not part of the value and take their
+//! width out of the input's width, so the editable window shrinks to fit.
+
+use super::model::Model;
+use crate::text;
+use lipgloss_extras::prelude::Style;
+
+impl Model {
+    /// Sets text drawn before the editable region in `adornment_style`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let mut input = new();
+    /// input.set_prefix("https://");
+    /// input.set_suffix(".git");
+    /// input.set_value("example.com/repo");
+    /// assert_eq!(input.value(), "example.com/repo");
+    /// ```
+    pub fn set_prefix(&mut self, prefix: &str) {
+        self.prefix = prefix.to_string();
+        self.handle_overflow();
+    }
+
+    /// Sets text drawn after the editable region in `adornment_style`.
+    pub fn set_suffix(&mut self, suffix: &str) {
+        self.suffix = suffix.to_string();
+        self.handle_overflow();
+    }
+
+    /// The text drawn before the editable region.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The text drawn after the editable region.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// The width left for the value once the adornments are drawn, at
+    /// least one column; zero or less when the width is unlimited.
+    pub(super) fn editable_width(&self) -> i32 {
+        if self.width <= 0 {
+            return self.width;
+        }
+        let adornments = text::width(&self.prefix) + text::width(&self.suffix);
+        (self.width - adornments as i32).max(1)
+    }
+
+    /// Puts the prompt and adornments around the rendered editable region.
+    pub(super) fn adorn(&self, prompt_style: &Style, field: String) -> String {
+        let mut out = prompt_style.render(&self.prompt);
+        if !self.prefix.is_empty() {
+            out.push_str(&self.adornment_style.render(&self.prefix));
+        }
+        out.push_str(&field);
+        if !self.suffix.is_empty() {
+            out.push_str(&self.adornment_style.render(&self.suffix));
+        }
+        out
+    }
+}
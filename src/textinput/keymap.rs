@@ -32,12 +32,28 @@ pub struct KeyMap {
     pub line_end: Binding,
     /// Paste from clipboard.
     pub paste: Binding,
+    /// Copy the value to the clipboard.
+    pub copy: Binding,
     /// Accept the current suggestion.
     pub accept_suggestion: Binding,
     /// Move to the next suggestion.
     pub next_suggestion: Binding,
     /// Move to the previous suggestion.
     pub prev_suggestion: Binding,
+    /// Recall the previous history entry.
+    pub prev_history: Binding,
+    /// Recall the next history entry.
+    pub next_history: Binding,
+    /// Raise a numeric value by its step.
+    pub increment: Binding,
+    /// Lower a numeric value by its step.
+    pub decrement: Binding,
+    /// Toggle between insert and overwrite mode.
+    pub toggle_overwrite: Binding,
+    /// Revert the last edit.
+    pub undo: Binding,
+    /// Reapply the last undone edit.
+    pub redo: Binding,
 }
 
 /// DefaultKeyMap is the default set of key bindings for navigating and acting
@@ -57,8 +73,18 @@ pub fn default_key_map() -> KeyMap {
         line_start: new_binding(vec![with_keys_str(&["home", "ctrl+a"])]),
         line_end: new_binding(vec![with_keys_str(&["end", "ctrl+e"])]),
         paste: new_binding(vec![with_keys_str(&["ctrl+v"])]),
+        // ctrl+c usually quits, so use the CUA alternative and Emacs' copy
+        copy: new_binding(vec![with_keys_str(&["ctrl+insert", "alt+w"])]),
         accept_suggestion: new_binding(vec![with_keys_str(&["tab"])]),
         next_suggestion: new_binding(vec![with_keys_str(&["down", "ctrl+n"])]),
         prev_suggestion: new_binding(vec![with_keys_str(&["up", "ctrl+p"])]),
+        prev_history: new_binding(vec![with_keys_str(&["up", "ctrl+p"])]),
+        next_history: new_binding(vec![with_keys_str(&["down", "ctrl+n"])]),
+        increment: new_binding(vec![with_keys_str(&["up"])]),
+        decrement: new_binding(vec![with_keys_str(&["down"])]),
+        toggle_overwrite: new_binding(vec![with_keys_str(&["insert"])]),
+        // Terminals without enhanced keys report ctrl+_ as ctrl+7
+        undo: new_binding(vec![with_keys_str(&["ctrl+z", "ctrl+_", "ctrl+7"])]),
+        redo: new_binding(vec![with_keys_str(&["ctrl+r"])]),
     }
 }
@@ -0,0 +1,265 @@
+//! Input masks for structured values such as phone numbers and dates.
+//!
+//! A mask is a pattern like `"(###) ###-####"`. `#` takes a digit, `@` a
+//! letter and `*` a letter or digit; every other character is a literal
+//! that is filled in as the user types past it. The value keeps the
+//! literals, and the cursor only ever rests where a character can go.
+
+use super::model::Model;
+use crate::key::matches_binding;
+use bubbletea_rs::KeyMsg;
+
+/// Whether mask character `slot` takes `ch`, or `None` when `slot` is a
+/// literal.
+fn accepts(slot: char, ch: char) -> Option<bool> {
+    match slot {
+        '#' => Some(ch.is_ascii_digit()),
+        '@' => Some(ch.is_alphabetic()),
+        '*' => Some(ch.is_alphanumeric()),
+        _ => None,
+    }
+}
+
+fn is_slot(slot: char) -> bool {
+    accepts(slot, ' ').is_some()
+}
+
+/// The characters of `text` that the slots of `mask` take, in order.
+/// Literals typed where the mask has them are passed over, as are
+/// characters no slot takes.
+fn fit(mask: &[char], text: impl IntoIterator<Item = char>) -> Vec<char> {
+    let mut entered = Vec::new();
+    let mut at = 0;
+    for ch in text {
+        if at >= mask.len() {
+            break;
+        }
+        if !is_slot(mask[at]) && mask[at] == ch {
+            at += 1;
+            continue;
+        }
+        let Some(slot) = (at..mask.len()).find(|&i| is_slot(mask[i])) else {
+            break;
+        };
+        if accepts(mask[slot], ch) == Some(true) {
+            entered.push(ch);
+            at = slot + 1;
+        }
+    }
+    entered
+}
+
+/// `entered` laid into `mask`, with the literals up to the last character
+/// entered.
+fn format(mask: &[char], entered: &[char]) -> Vec<char> {
+    let mut out = Vec::new();
+    let mut chars = entered.iter();
+    let mut left = entered.len();
+    for &slot in mask {
+        if left == 0 {
+            break;
+        }
+        if is_slot(slot) {
+            out.extend(chars.next());
+            left -= 1;
+        } else {
+            out.push(slot);
+        }
+    }
+    out
+}
+
+impl Model {
+    /// Restricts input to `mask`, where `#` takes a digit, `@` a letter and
+    /// `*` a letter or digit, and any other character is a literal put in
+    /// automatically. The cursor skips the literals, and characters that do
+    /// not fit their position are ignored. An empty mask removes it.
+    ///
+    /// The current value is refitted to the mask.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let mut input = new();
+    /// input.set_mask("(###) ###-####");
+    /// input.set_value("5551234567");
+    /// assert_eq!(input.value(), "(555) 123-4567");
+    /// assert_eq!(input.unmasked_value(), "5551234567");
+    /// ```
+    pub fn set_mask(&mut self, mask: &str) {
+        if mask.is_empty() {
+            self.mask = None;
+            return;
+        }
+        let mask: Vec<char> = mask.chars().collect();
+        let entered = fit(&mask, self.value.iter().copied());
+        self.mask = Some(mask);
+        let len = entered.len();
+        self.set_masked(entered, len);
+    }
+
+    /// The mask set with [`set_mask`](Self::set_mask), if any.
+    pub fn mask(&self) -> Option<String> {
+        self.mask.as_ref().map(|mask| mask.iter().collect())
+    }
+
+    /// The value without the mask's literals: only the characters that
+    /// were entered. Without a mask this is the whole value.
+    pub fn unmasked_value(&self) -> String {
+        self.entered().into_iter().collect()
+    }
+
+    /// The characters entered into the mask's slots.
+    fn entered(&self) -> Vec<char> {
+        match &self.mask {
+            Some(mask) => self
+                .value
+                .iter()
+                .zip(mask)
+                .filter(|(_, &slot)| is_slot(slot))
+                .map(|(&ch, _)| ch)
+                .collect(),
+            None => self.value.clone(),
+        }
+    }
+
+    /// Fits `text` to the mask, for [`set_value`](Self::set_value).
+    pub(super) fn masked_runes(&self, text: Vec<char>) -> Vec<char> {
+        match &self.mask {
+            Some(mask) => format(mask, &fit(mask, text)),
+            None => text,
+        }
+    }
+
+    /// The number of entered characters before value position `pos`.
+    fn entered_before(&self, pos: usize) -> usize {
+        let mask = self.mask.as_deref().unwrap_or_default();
+        mask[..pos.min(mask.len())]
+            .iter()
+            .filter(|&&slot| is_slot(slot))
+            .count()
+    }
+
+    /// Sets the value to `entered` laid into the mask, with the cursor
+    /// where entered character `index` is, or at the end.
+    fn set_masked(&mut self, entered: Vec<char>, index: usize) {
+        let mask = self.mask.clone().unwrap_or_default();
+        let value = format(&mask, &entered);
+        let pos = if index < entered.len() {
+            mask.iter()
+                .enumerate()
+                .filter(|(_, &slot)| is_slot(slot))
+                .nth(index)
+                .map_or(value.len(), |(i, _)| i)
+        } else {
+            value.len()
+        };
+        let err = self.validate_runes(&value);
+        self.set_value_internal(value, err);
+        self.set_cursor(pos);
+    }
+
+    /// Inserts `runes` at the cursor, keeping those the mask takes. Nothing
+    /// is inserted if it would push entered characters off the end.
+    pub(super) fn insert_masked(&mut self, runes: Vec<char>) {
+        let Some(mask) = self.mask.clone() else {
+            return;
+        };
+        let entered = self.entered();
+        let index = self.entered_before(self.pos).min(entered.len());
+        // Literals typed before the cursor still line up with the mask
+        let head = format(&mask, &entered[..index]);
+        let typed = fit(&mask, head.iter().copied().chain(runes.iter().copied()));
+        let cursor = typed.len();
+        let refitted = fit(
+            &mask,
+            typed.into_iter().chain(entered[index..].iter().copied()),
+        );
+        // A full mask takes nothing more rather than push characters out
+        if refitted.len() < cursor + entered.len() - index {
+            return;
+        }
+        self.set_masked(refitted, cursor);
+    }
+
+    /// Moves and deletes by entered character rather than by value
+    /// position while a mask is set. A word is a run of slots between two
+    /// literals. Returns whether the key was handled.
+    pub(super) fn handle_masked_key(&mut self, key_msg: &KeyMsg) -> bool {
+        let Some(mask) = self.mask.clone() else {
+            return false;
+        };
+        let mut entered = self.entered();
+        let index = self.entered_before(self.pos).min(entered.len());
+        // The literals before each slot tell which word it is in
+        let words: Vec<usize> = mask
+            .iter()
+            .enumerate()
+            .filter(|(_, &slot)| is_slot(slot))
+            .enumerate()
+            .map(|(n, (i, _))| i - n)
+            .collect();
+        let word_start = |index: usize| {
+            // A mask of literals alone has no words
+            let Some(&word) = words.get(index.saturating_sub(1)) else {
+                return 0;
+            };
+            (0..index).find(|&i| words[i] == word).unwrap_or(0)
+        };
+        let word_end = |index: usize| {
+            let word = words.get(index).copied();
+            (index..entered.len())
+                .find(|&i| Some(words[i]) != word)
+                .unwrap_or(entered.len())
+        };
+
+        let km = &self.key_map;
+        let index = if matches_binding(key_msg, &km.delete_character_backward) {
+            if index == 0 {
+                return true;
+            }
+            entered.remove(index - 1);
+            index - 1
+        } else if matches_binding(key_msg, &km.delete_character_forward) {
+            if index < entered.len() {
+                entered.remove(index);
+            }
+            index
+        } else if matches_binding(key_msg, &km.delete_word_backward) {
+            let start = word_start(index);
+            entered.drain(start..index);
+            start
+        } else if matches_binding(key_msg, &km.delete_word_forward) {
+            let end = word_end(index);
+            entered.drain(index..end);
+            index
+        } else if matches_binding(key_msg, &km.delete_before_cursor) {
+            entered.drain(..index);
+            0
+        } else if matches_binding(key_msg, &km.delete_after_cursor) {
+            entered.truncate(index);
+            index
+        } else if matches_binding(key_msg, &km.character_backward) {
+            index.saturating_sub(1)
+        } else if matches_binding(key_msg, &km.character_forward) {
+            (index + 1).min(entered.len())
+        } else if matches_binding(key_msg, &km.word_backward) {
+            word_start(index)
+        } else if matches_binding(key_msg, &km.word_forward) {
+            word_end(index)
+        } else if matches_binding(key_msg, &km.line_start) {
+            0
+        } else if matches_binding(key_msg, &km.line_end) {
+            entered.len()
+        } else {
+            return false;
+        };
+        // Deleting shifts later characters into earlier slots
+        let entered = fit(&mask, entered);
+        let index = index.min(entered.len());
+        self.set_masked(entered, index);
+        true
+    }
+}
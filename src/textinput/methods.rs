@@ -1,10 +1,22 @@
 //! Core methods for the Model struct.
 
-use super::model::{paste, Model};
-use super::types::{EchoMode, PasteErrMsg, PasteMsg, ValidateFunc};
+use super::model::{paste_from, Model, Snapshot};
+use super::types::{
+    EchoMode, HideLastCharMsg, InputChangedMsg, PasteErrMsg, PasteMsg, ValidateFunc,
+};
+use crate::clipboard::Clipboard;
+use crate::cmd_util;
+use crate::cursor::Mode as CursorMode;
+use crate::error::Error;
+use crate::graphemes;
+use crate::ime::ImeMsg;
+use crate::theme::Theme;
+use crate::undo::EditKind;
 use crate::Component;
 use bubbletea_rs::{Cmd, KeyMsg, Msg};
 use crossterm::event::{KeyCode, KeyModifiers};
+use lipgloss_extras::prelude::Style;
+use std::time::Duration;
 
 impl Model {
     /// Sets the value of the text input.
@@ -29,14 +41,135 @@ impl Model {
     /// # Note
     ///
     /// This method matches Go's SetValue method exactly for compatibility.
+    ///
+    /// The undo history is cleared, so the new value cannot be undone.
     pub fn set_value(&mut self, s: &str) {
-        let runes: Vec<char> = s.chars().collect();
+        let runes = self.numeric_value(self.masked_runes(s.chars().collect()));
         let err = self.validate_runes(&runes);
         self.set_value_internal(runes, err);
+        self.history.clear();
+    }
+
+    /// Reverts the last undo step. Typing is undone a word at a time and
+    /// deleting a run at a time; a pause of a second or a cursor movement
+    /// also ends a step. Returns whether there was anything to undo.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    /// use bubbletea_rs::KeyMsg;
+    /// use crossterm::event::{KeyCode, KeyModifiers};
+    ///
+    /// let mut input = new();
+    /// input.focus();
+    /// input.set_value("git commit");
+    /// input.update(Box::new(KeyMsg {
+    ///     key: KeyCode::Char('u'),
+    ///     modifiers: KeyModifiers::CONTROL,
+    /// }));
+    /// assert_eq!(input.value(), "");
+    /// assert!(input.undo());
+    /// assert_eq!(input.value(), "git commit");
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        let current = self.snapshot();
+        match self.history.undo(current) {
+            Some(previous) => {
+                self.restore(previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the last step reverted by [`undo`](Self::undo), unless
+    /// something was edited since. Returns whether there was anything to
+    /// redo.
+    pub fn redo(&mut self) -> bool {
+        let current = self.snapshot();
+        match self.history.redo(current) {
+            Some(next) => {
+                self.restore(next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether [`undo`](Self::undo) would change anything.
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Whether [`redo`](Self::redo) would change anything.
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            value: self.value.clone(),
+            pos: self.pos,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        let err = self.validate_runes(&snapshot.value);
+        self.set_value_internal(snapshot.value, err);
+        self.set_cursor(snapshot.pos);
+        self.handle_overflow();
+    }
+
+    /// Records an edit made by a key in the undo history, given its kind
+    /// and the state from before it. Keys that do not edit end the step.
+    fn record_edit(&mut self, edit: Option<(EditKind, Snapshot)>) {
+        match edit {
+            Some((kind, before)) => {
+                if before.value != self.value {
+                    self.history.record(kind, before, std::time::Instant::now());
+                }
+            }
+            None => self.history.break_step(),
+        }
+    }
+
+    /// Classifies the edit a key makes for the undo history, or `None` for
+    /// keys that do not edit.
+    fn edit_kind(&self, key_msg: &KeyMsg) -> Option<EditKind> {
+        let km = &self.key_map;
+        let deletes = [
+            &km.delete_character_backward,
+            &km.delete_character_forward,
+            &km.delete_word_backward,
+            &km.delete_word_forward,
+            &km.delete_after_cursor,
+            &km.delete_before_cursor,
+        ];
+        if crate::key::matches(key_msg, &deletes) {
+            return Some(EditKind::Delete);
+        }
+        if crate::key::matches_binding(key_msg, &km.accept_suggestion) {
+            return Some(EditKind::Other);
+        }
+        match key_msg.key {
+            KeyCode::Char(ch)
+                if !key_msg
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                if ch.is_whitespace() {
+                    Some(EditKind::Space)
+                } else {
+                    Some(EditKind::Word)
+                }
+            }
+            _ => None,
+        }
     }
 
     /// Internal method to set value with validation
-    pub(super) fn set_value_internal(&mut self, runes: Vec<char>, err: Option<String>) {
+    pub(super) fn set_value_internal(&mut self, runes: Vec<char>, err: Option<Error>) {
         self.err = err;
 
         let empty = self.value.is_empty();
@@ -249,6 +382,98 @@ impl Model {
     pub fn blur(&mut self) {
         self.focus = false;
         self.cursor.blur();
+        self.revealed = None;
+        self.composition.cancel();
+    }
+
+    /// Returns the current cursor mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    /// use bubbletea_widgets::cursor::Mode;
+    ///
+    /// let input = new();
+    /// assert_eq!(input.cursor_mode(), Mode::Blink);
+    /// ```
+    pub fn cursor_mode(&self) -> CursorMode {
+        self.cursor.mode()
+    }
+
+    /// Changes the cursor behavior (blinking, static or hidden) at runtime.
+    ///
+    /// Switching to `Mode::Blink` returns the command that starts the blink
+    /// loop, which should be handed back to the runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The new cursor mode
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    /// use bubbletea_widgets::cursor::Mode;
+    ///
+    /// let mut input = new();
+    /// let _ = input.focus();
+    ///
+    /// assert!(input.set_cursor_mode(Mode::Static).is_none());
+    /// assert_eq!(input.cursor_mode(), Mode::Static);
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This method matches Go's deprecated SetCursorMode method.
+    pub fn set_cursor_mode(&mut self, mode: CursorMode) -> Option<Cmd> {
+        self.cursor_mode = mode;
+        self.cursor.set_mode(mode)
+    }
+
+    /// Returns whether the input is in overwrite mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let input = new();
+    /// assert!(!input.overwrite());
+    /// ```
+    pub fn overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    /// Switches between insert and overwrite mode.
+    ///
+    /// In overwrite mode typed characters replace the character under the
+    /// cursor, and the cursor is drawn with its overwrite shape. By default the
+    /// mode is toggled with the Insert key.
+    ///
+    /// # Arguments
+    ///
+    /// * `overwrite` - `true` for overwrite mode, `false` for insert mode
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    /// use bubbletea_rs::KeyMsg;
+    /// use crossterm::event::{KeyCode, KeyModifiers};
+    ///
+    /// let mut input = new();
+    /// let _ = input.focus();
+    /// input.set_value("cat");
+    /// input.cursor_start();
+    /// input.set_overwrite(true);
+    ///
+    /// input.update(Box::new(KeyMsg { key: KeyCode::Char('b'), modifiers: KeyModifiers::NONE }));
+    /// assert_eq!(input.value(), "bat");
+    /// ```
+    pub fn set_overwrite(&mut self, overwrite: bool) {
+        self.overwrite = overwrite;
+        self.cursor.set_overwrite(overwrite);
     }
 
     /// Clears all text and resets the cursor to the beginning.
@@ -313,6 +538,9 @@ impl Model {
 
     /// Sets the placeholder text displayed when the input is empty.
     ///
+    /// A placeholder wider than the input is cut to fit and ends in an
+    /// ellipsis.
+    ///
     /// # Arguments
     ///
     /// * `placeholder` - The placeholder text to display
@@ -359,6 +587,8 @@ impl Model {
     ///   - `EchoNormal`: Display characters as typed (default)
     ///   - `EchoPassword`: Display asterisks instead of actual characters
     ///   - `EchoNone`: Don't display any characters
+    ///   - `EchoPasswordLastChar`: Like `EchoPassword`, but briefly show
+    ///     the last character typed
     ///
     /// # Examples
     ///
@@ -374,6 +604,22 @@ impl Model {
         self.echo_mode = mode;
     }
 
+    /// Sets the character password modes show in place of each character
+    /// typed, `*` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::{new, EchoMode};
+    ///
+    /// let mut input = new();
+    /// input.set_echo_mode(EchoMode::EchoPassword);
+    /// input.set_echo_character('•');
+    /// ```
+    pub fn set_echo_character(&mut self, ch: char) {
+        self.echo_character = ch;
+    }
+
     /// Sets the maximum number of characters allowed in the input.
     ///
     /// # Arguments
@@ -394,6 +640,25 @@ impl Model {
         self.char_limit = limit;
     }
 
+    /// The last error: the validator rejecting the value, or a failed
+    /// paste. While there is one, the input is drawn with `error_style`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let mut input = new();
+    /// input.set_validate(Box::new(|s: &str| {
+    ///     s.parse::<u32>().map(|_| ()).map_err(|_| "not a number".to_string())
+    /// }));
+    /// input.set_value("12a");
+    /// assert_eq!(input.error().map(|e| e.to_string()).as_deref(), Some("not a number"));
+    /// ```
+    pub fn error(&self) -> Option<&Error> {
+        self.err.as_ref()
+    }
+
     /// Sets a validation function that will be called whenever the input changes.
     ///
     /// The validation function receives the current input value and should return
@@ -421,6 +686,84 @@ impl Model {
         self.validate = Some(validate);
     }
 
+    /// Sets the clipboard the paste key reads from, in place of the default
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::clipboard::{Clipboard, Osc52};
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let input = new().with_clipboard(Clipboard::new(Osc52::new()));
+    /// ```
+    pub fn with_clipboard(mut self, clipboard: Clipboard) -> Self {
+        self.set_clipboard(clipboard);
+        self
+    }
+
+    /// Sets the clipboard the paste key reads from; see
+    /// [`with_clipboard`](Self::with_clipboard).
+    pub fn set_clipboard(&mut self, clipboard: Clipboard) {
+        self.clipboard = clipboard;
+    }
+
+    /// Sends an [`InputChangedMsg`] from [`update`](Self::update)'s command
+    /// once the value has stopped changing for `delay`, for searching as
+    /// the user types without a request per keystroke. Zero, the default,
+    /// sends none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    /// use std::time::Duration;
+    ///
+    /// let input = new().with_debounce(Duration::from_millis(300));
+    /// assert_eq!(input.debounce(), Duration::from_millis(300));
+    /// ```
+    pub fn with_debounce(mut self, delay: Duration) -> Self {
+        self.set_debounce(delay);
+        self
+    }
+
+    /// Sets the pause before an [`InputChangedMsg`] is sent; see
+    /// [`with_debounce`](Self::with_debounce).
+    pub fn set_debounce(&mut self, delay: Duration) {
+        self.debounce = delay;
+    }
+
+    /// The pause before an [`InputChangedMsg`] is sent.
+    pub fn debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// The ID carried by this input's [`InputChangedMsg`]s.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns a command that puts the value on the clipboard, even in a
+    /// password mode, and reports the outcome as a
+    /// [`CopiedMsg`](crate::clipboard::CopiedMsg) or a
+    /// [`CopyErrMsg`](crate::clipboard::CopyErrMsg). The copy key (ctrl+insert
+    /// or alt+w) returns it from [`update`](Self::update).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::clipboard::{Clipboard, Memory};
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let mut input = new().with_clipboard(Clipboard::new(Memory::new()));
+    /// input.set_value("api-token");
+    /// let cmd = input.copy();
+    /// # let _ = cmd;
+    /// ```
+    pub fn copy(&self) -> Cmd {
+        self.clipboard.write_cmd(self.value())
+    }
+
     /// Processes a message and updates the text input state.
     ///
     /// This method handles keyboard input, cursor movement, text editing operations,
@@ -453,31 +796,157 @@ impl Model {
     /// assert_eq!(input.value(), "h");
     /// ```
     pub fn update(&mut self, msg: Msg) -> std::option::Option<Cmd> {
+        if let Some(hide) = msg.downcast_ref::<HideLastCharMsg>() {
+            if hide.id == self.id && hide.tag == self.reveal_tag {
+                self.revealed = None;
+            }
+            return std::option::Option::None;
+        }
         if !self.focus {
             return std::option::Option::None;
         }
+        let before = self.value.clone();
+        let key = msg.downcast_ref::<KeyMsg>().map(|key_msg| key_msg.key);
+        let mut cmds: Vec<Cmd> = self.handle_msg(msg).into_iter().collect();
+
+        if key.is_some() {
+            self.revealed = None;
+        }
+        // Only a single typed character is shown, never a paste
+        let typed = matches!(key, Some(KeyCode::Char(_))) && self.value.len() == before.len() + 1;
+        if self.echo_mode == EchoMode::EchoPasswordLastChar && typed && self.pos > 0 {
+            self.revealed = Some(self.pos - 1);
+            self.reveal_tag = self.reveal_tag.wrapping_add(1);
+            let hide = HideLastCharMsg {
+                id: self.id,
+                tag: self.reveal_tag,
+            };
+            cmds.push(bubbletea_rs::tick(self.reveal_duration, move |_| {
+                Box::new(hide) as Msg
+            }));
+        }
 
+        if !self.debounce.is_zero() && self.value != before {
+            cmds.push(cmd_util::debounce(
+                &format!("textinput-{}", self.id),
+                self.debounce,
+                InputChangedMsg {
+                    id: self.id,
+                    value: self.value(),
+                },
+            ));
+        }
+        match cmds.len() {
+            0 => std::option::Option::None,
+            1 => cmds.pop(),
+            _ => Some(bubbletea_rs::batch(cmds)),
+        }
+    }
+
+    fn handle_msg(&mut self, msg: Msg) -> std::option::Option<Cmd> {
         // Handle key messages
         if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            use crate::key::matches_binding;
+
+            if self.composition.swallows(key_msg) {
+                return std::option::Option::None;
+            }
+
+            if self.numeric.is_some() {
+                let up = matches_binding(key_msg, &self.key_map.increment);
+                if up || matches_binding(key_msg, &self.key_map.decrement) {
+                    let before = self.snapshot();
+                    self.step_numeric(if up { 1.0 } else { -1.0 });
+                    self.record_edit(Some((EditKind::Other, before)));
+                    self.history.break_step();
+                    self.handle_overflow();
+                    return std::option::Option::None;
+                }
+            }
+            // Shown suggestions keep the arrows; otherwise they browse
+            // the history
+            if !self.recall.is_empty() && !self.can_accept_suggestion() {
+                let prev = matches_binding(key_msg, &self.key_map.prev_history);
+                if prev || matches_binding(key_msg, &self.key_map.next_history) {
+                    let before = self.snapshot();
+                    if prev {
+                        self.recall_previous();
+                    } else {
+                        self.recall_next();
+                    }
+                    self.record_edit(Some((EditKind::Other, before)));
+                    self.history.break_step();
+                    self.handle_overflow();
+                    return std::option::Option::None;
+                }
+            }
+            self.recall_index = None;
+
+            if matches_binding(key_msg, &self.key_map.undo) {
+                self.undo();
+                return std::option::Option::None;
+            }
+            if matches_binding(key_msg, &self.key_map.redo) {
+                self.redo();
+                return std::option::Option::None;
+            }
+            let edit = self.edit_kind(key_msg).map(|kind| (kind, self.snapshot()));
+
             // Check key bindings in order of priority
             if let Some(cmd) = self.handle_suggestion_keys(key_msg) {
+                self.record_edit(edit);
                 return cmd;
             }
             if let Some(cmd) = self.handle_clipboard_keys(key_msg) {
                 return cmd;
             }
 
-            self.handle_deletion_keys(key_msg);
-            self.handle_movement_keys(key_msg);
-            self.handle_character_input(key_msg);
+            if matches_binding(key_msg, &self.key_map.toggle_overwrite) {
+                self.set_overwrite(!self.overwrite);
+                return std::option::Option::None;
+            }
+
+            if !self.handle_masked_key(key_msg) {
+                self.handle_deletion_keys(key_msg);
+                self.handle_movement_keys(key_msg);
+                self.handle_character_input(key_msg);
+            }
+            self.record_edit(edit);
 
             self.update_suggestions();
         }
 
-        // Handle paste messages
-        if let Some(paste_msg) = msg.downcast_ref::<PasteMsg>() {
-            let chars: Vec<char> = paste_msg.0.chars().collect();
+        // Composed text is inserted once committed, as one undo step
+        if let Some(ime) = msg.downcast_ref::<ImeMsg>() {
+            if let Some(text) = self.composition.update(ime) {
+                let before = self.snapshot();
+                self.insert_runes_from_user_input(text.chars().collect());
+                self.record_edit(Some((EditKind::Other, before)));
+                self.history.break_step();
+            }
+        }
+
+        // Handle paste messages, from the clipboard or a bracketed paste
+        // from the terminal. Pasted text is inserted in one go, never read
+        // as keys, and its line breaks become spaces on this single line.
+        let pasted = msg
+            .downcast_ref::<PasteMsg>()
+            .map(|paste| &paste.0)
+            .or_else(|| {
+                msg.downcast_ref::<bubbletea_rs::PasteMsg>()
+                    .map(|paste| &paste.0)
+            });
+        if let Some(text) = pasted {
+            let text = text.replace("\r\n", "\n");
+            let chars: Vec<char> = text
+                .chars()
+                .map(|ch| if matches!(ch, '\n' | '\r') { ' ' } else { ch })
+                .collect();
+            // A paste is an undo step of its own
+            let before = self.snapshot();
             self.insert_runes_from_user_input(chars);
+            self.record_edit(Some((EditKind::Other, before)));
+            self.history.break_step();
         }
 
         if let Some(paste_err) = msg.downcast_ref::<PasteErrMsg>() {
@@ -523,7 +992,10 @@ impl Model {
         use crate::key::matches_binding;
 
         if matches_binding(key_msg, &self.key_map.paste) {
-            return Some(Some(paste()));
+            return Some(Some(paste_from(&self.clipboard)));
+        }
+        if matches_binding(key_msg, &self.key_map.copy) {
+            return Some(Some(self.copy()));
         }
 
         None
@@ -538,13 +1010,15 @@ impl Model {
         } else if matches_binding(key_msg, &self.key_map.delete_character_backward) {
             self.err = None;
             if !self.value.is_empty() && self.pos > 0 {
-                self.value.remove(self.pos - 1);
-                self.pos -= 1;
+                let start = graphemes::prev_boundary(&self.value, self.pos);
+                self.value.drain(start..self.pos);
+                self.pos = start;
                 self.err = self.validate_runes(&self.value);
             }
         } else if matches_binding(key_msg, &self.key_map.delete_character_forward) {
             if !self.value.is_empty() && self.pos < self.value.len() {
-                self.value.remove(self.pos);
+                let end = graphemes::next_boundary(&self.value, self.pos);
+                self.value.drain(self.pos..end);
                 self.err = self.validate_runes(&self.value);
             }
         } else if matches_binding(key_msg, &self.key_map.delete_after_cursor) {
@@ -564,13 +1038,13 @@ impl Model {
             self.word_backward();
         } else if matches_binding(key_msg, &self.key_map.character_backward) {
             if self.pos > 0 {
-                self.set_cursor(self.pos - 1);
+                self.set_cursor(graphemes::prev_boundary(&self.value, self.pos));
             }
         } else if matches_binding(key_msg, &self.key_map.word_forward) {
             self.word_forward();
         } else if matches_binding(key_msg, &self.key_map.character_forward) {
             if self.pos < self.value.len() {
-                self.set_cursor(self.pos + 1);
+                self.set_cursor(graphemes::next_boundary(&self.value, self.pos));
             }
         } else if matches_binding(key_msg, &self.key_map.line_start) {
             self.cursor_start();
@@ -587,13 +1061,25 @@ impl Model {
             if !key_msg.modifiers.contains(KeyModifiers::CONTROL)
                 && !key_msg.modifiers.contains(KeyModifiers::ALT)
             {
-                self.insert_runes_from_user_input(vec![ch]);
+                if self.overwrite && self.mask.is_none() && self.pos < self.value.len() {
+                    if self.numeric_accepts_overwrite(ch) {
+                        self.overwrite_rune_from_user_input(ch);
+                    }
+                } else {
+                    self.insert_runes_from_user_input(vec![ch]);
+                }
             }
         }
     }
 
     /// Internal method to handle text insertion from user input
     pub(super) fn insert_runes_from_user_input(&mut self, runes: Vec<char>) {
+        if self.mask.is_some() {
+            self.insert_masked(runes);
+            return;
+        }
+        let runes = self.numeric_runes(runes);
+
         let mut avail_space = if self.char_limit > 0 {
             let space = self.char_limit - self.value.len() as i32;
             if space <= 0 {
@@ -629,11 +1115,25 @@ impl Model {
         self.set_value_internal(new_value, input_err);
     }
 
+    /// Internal method to replace the character under the cursor in overwrite mode
+    fn overwrite_rune_from_user_input(&mut self, rune: char) {
+        let mut new_value = self.value.clone();
+        let end = graphemes::next_boundary(&self.value, self.pos);
+        new_value.splice(self.pos..end, [rune]);
+        self.pos += 1;
+
+        let input_err = self.validate_runes(&new_value);
+        self.set_value_internal(new_value, input_err);
+    }
+
     /// Validate the input against the validation function if set
-    pub(super) fn validate_runes(&self, runes: &[char]) -> Option<String> {
+    pub(super) fn validate_runes(&self, runes: &[char]) -> Option<Error> {
+        if let Some(err) = self.range_error(runes) {
+            return Some(err);
+        }
         if let Some(ref validate) = self.validate {
             let value: String = runes.iter().collect();
-            validate(&value).err()
+            validate(&value).err().map(Error::Validation)
         } else {
             None
         }
@@ -641,45 +1141,42 @@ impl Model {
 
     /// Handle overflow for horizontal scrolling viewport
     pub(super) fn handle_overflow(&mut self) {
-        if self.width <= 0 {
+        let width = self.editable_width();
+        if width <= 0 || graphemes::width(&self.value, 1) <= width as usize {
             self.offset = 0;
             self.offset_right = self.value.len();
             return;
         }
+        let width = width as usize;
 
-        let value_width = self.value.len();
-        if value_width <= self.width as usize {
-            self.offset = 0;
-            self.offset_right = self.value.len();
-            return;
-        }
-
-        // Correct right offset if we've deleted characters
-        self.offset_right = self.offset_right.min(self.value.len());
-
-        if self.pos < self.offset {
-            self.offset = self.pos;
-            let mut w = 0;
-            let mut i = 0;
-            let runes = &self.value[self.offset..];
-
-            while i < runes.len() && w <= self.width as usize {
-                w += 1; // Simplified width calculation
-                i += 1;
+        // The window is measured in columns and moves a whole grapheme
+        // cluster at a time, so wide characters never shift the frame
+        self.offset = self.offset.min(self.pos);
+        let mut used = 0;
+        let mut end = self.offset;
+        while end < self.value.len() {
+            let next = graphemes::next_boundary(&self.value, end);
+            used += graphemes::width(&self.value[end..next], 1);
+            if used > width {
+                break;
             }
+            end = next;
+        }
+        self.offset_right = end;
 
-            self.offset_right = self.offset + i;
-        } else if self.pos >= self.offset_right {
+        if self.pos >= self.offset_right {
             self.offset_right = self.pos;
-
-            let mut w = 0;
-            let runes = &self.value[..self.offset_right];
-            let mut i = runes.len();
-            while i > 0 && w < self.width as usize {
-                w += 1; // Simplified width calculation
-                i = i.saturating_sub(1);
+            let mut used = 0;
+            let mut start = self.pos;
+            while start > 0 {
+                let prev = graphemes::prev_boundary(&self.value, start);
+                used += graphemes::width(&self.value[prev..start], 1);
+                if used > width {
+                    break;
+                }
+                start = prev;
             }
-            self.offset = i;
+            self.offset = start;
         }
     }
 }
@@ -713,3 +1210,30 @@ impl Component for Model {
         self.focused()
     }
 }
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.prompt_style = Style::new().foreground(theme.palette.primary.clone());
+        self.text_style = theme.text.clone();
+        self.placeholder_style = theme.muted.clone();
+        self.completion_style = theme.subtle.clone();
+        self.adornment_style = theme.muted.clone();
+        self.error_style = theme.error.clone();
+    }
+}
+
+impl crate::state::Stateful for Model {
+    type State = super::State;
+
+    fn state(&self) -> super::State {
+        super::State {
+            value: self.value(),
+            cursor: self.position(),
+        }
+    }
+
+    fn restore(&mut self, state: super::State) {
+        self.set_value(&state.value);
+        self.set_cursor(state.cursor);
+    }
+}
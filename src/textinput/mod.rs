@@ -30,16 +30,24 @@
 //! # Key Bindings
 //!
 //! The component uses the key system for handling keyboard input with customizable bindings.
+//! The cursor moves and deletes by grapheme cluster, and the visible window
+//! is measured in display columns, so emoji and wide CJK characters stay whole.
+//! Input method compositions forwarded as [`crate::ime::ImeMsg`] are drawn at
+//! the cursor until committed.
 //!
 //! # Testing
 //!
 //! This module includes comprehensive tests that match the Go implementation exactly.
 //! Run tests with: `cargo test textinput` to verify 1-1 compatibility.
 
+pub mod adornments;
 pub mod keymap;
+pub mod mask;
 pub mod methods;
 pub mod model;
 pub mod movement;
+pub mod numeric;
+pub mod recall;
 pub mod suggestions;
 pub mod types;
 pub mod view;
@@ -50,4 +58,7 @@ mod tests;
 // Re-export main types and functions for public API
 pub use keymap::{default_key_map, KeyMap};
 pub use model::{blink, new, new_model, paste, Model};
-pub use types::{EchoMode, PasteErrMsg, PasteMsg, ValidateFunc};
+pub use types::{
+    EchoMode, HideLastCharMsg, InputChangedMsg, NumericOptions, PasteErrMsg, PasteMsg, State,
+    ValidateFunc,
+};
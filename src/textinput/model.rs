@@ -1,14 +1,23 @@
 //! Core model implementation for the textinput component.
 
 use super::keymap::{default_key_map, KeyMap};
-#[cfg(feature = "clipboard-support")]
-use super::types::PasteMsg;
-use super::types::{EchoMode, PasteErrMsg, ValidateFunc};
+use super::types::{EchoMode, NumericOptions, PasteErrMsg, PasteMsg, ValidateFunc};
+use crate::clipboard::Clipboard;
 use crate::cursor::{new as cursor_new, Model as Cursor};
+use crate::error::Error;
+use crate::ime::Composition;
+use crate::undo::History;
 use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
 use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
 use std::time::Duration;
 
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
 /// The main text input component model for Bubble Tea applications.
 ///
 /// This struct represents a single-line text input field with support for:
@@ -53,8 +62,9 @@ use std::time::Duration;
 /// This struct matches the Go `Model` struct exactly for 1-1 compatibility.
 #[allow(dead_code)]
 pub struct Model {
-    /// Err is an error that was not caught by a validator.
-    pub err: Option<String>,
+    /// Err is the last error: the validator rejecting the value, or a
+    /// failed paste.
+    pub err: Option<Error>,
 
     /// Prompt is the prompt to display before the text input.
     pub prompt: String,
@@ -72,6 +82,8 @@ pub struct Model {
     /// Cursor is the cursor model.
     pub cursor: Cursor,
     /// Cursor rendering mode (blink/static/hidden).
+    ///
+    /// Kept in sync with the embedded cursor by `set_cursor_mode()`.
     pub cursor_mode: crate::cursor::Mode,
 
     /// Value is the value of the text input.
@@ -83,6 +95,10 @@ pub struct Model {
     /// Position is the cursor position.
     pub(super) pos: usize,
 
+    /// Overwrite indicates whether typed characters replace the character
+    /// under the cursor instead of being inserted.
+    pub(super) overwrite: bool,
+
     /// Width is the maximum number of characters that can be displayed at once.
     pub width: i32,
 
@@ -99,9 +115,22 @@ pub struct Model {
     /// EchoCharacter is the character to use for password fields.
     pub echo_character: char,
 
+    /// How long `EchoPasswordLastChar` shows a typed character.
+    pub reveal_duration: Duration,
+
     /// CompletionStyle is the style of the completion suggestion.
     pub completion_style: Style,
 
+    /// ErrorStyle replaces the prompt and text styles while there is an
+    /// error, and styles the message shown by `show_error`.
+    pub error_style: Style,
+
+    /// ShowError puts the error message on a line below the input.
+    pub show_error: bool,
+
+    /// AdornmentStyle is the style of the prefix and suffix.
+    pub adornment_style: Style,
+
     /// Validate is a function that validates the input.
     pub(super) validate: Option<ValidateFunc>,
 
@@ -112,6 +141,50 @@ pub struct Model {
     pub(super) matched_suggestions: Vec<Vec<char>>,
     pub(super) show_suggestions: bool,
     pub(super) current_suggestion_index: usize,
+
+    /// Where pasted text is read from.
+    pub(super) clipboard: Clipboard,
+
+    /// Undo and redo steps of the value.
+    pub(super) history: History<Snapshot>,
+
+    /// The input mask, if any; see [`Model::set_mask`].
+    pub(super) mask: Option<Vec<char>>,
+
+    /// Entries recalled with the history keys, oldest first.
+    pub(super) recall: Vec<String>,
+    /// The entry being shown, while browsing the history.
+    pub(super) recall_index: Option<usize>,
+    /// What was typed before browsing began; entries must start with it.
+    pub(super) recall_draft: String,
+
+    /// Numeric mode, if set; see [`Model::set_numeric`].
+    pub(super) numeric: Option<NumericOptions>,
+
+    /// Fixed text drawn before and after the editable region.
+    pub(super) prefix: String,
+    pub(super) suffix: String,
+
+    /// Identifies this input in the messages it sends.
+    pub(super) id: i64,
+    /// How long typing must pause before an `InputChangedMsg` is sent;
+    /// zero sends none.
+    pub(super) debounce: Duration,
+
+    /// The position of the character `EchoPasswordLastChar` is showing,
+    /// and the tag of the message that will hide it.
+    pub(super) revealed: Option<usize>,
+    pub(super) reveal_tag: u64,
+
+    /// Text being composed by an input method, drawn at the cursor.
+    pub(super) composition: Composition,
+}
+
+/// The value and cursor position at one point in time, for undo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Snapshot {
+    pub(super) value: Vec<char>,
+    pub(super) pos: usize,
 }
 
 /// Creates a new text input model with default settings.
@@ -154,12 +227,17 @@ pub fn new() -> Model {
         value: Vec::new(),
         focus: false,
         pos: 0,
+        overwrite: false,
         width: 0,
         key_map: default_key_map(),
         char_limit: 0,
         echo_mode: EchoMode::EchoNormal,
         echo_character: '*',
+        reveal_duration: Duration::from_secs(1),
         completion_style: Style::new().foreground(Color::from("240")),
+        error_style: Style::new().foreground(Color::from("9")),
+        show_error: false,
+        adornment_style: Style::new().foreground(Color::from("240")),
         validate: None,
         offset: 0,
         offset_right: 0,
@@ -167,6 +245,20 @@ pub fn new() -> Model {
         matched_suggestions: Vec::new(),
         show_suggestions: false,
         current_suggestion_index: 0,
+        clipboard: Clipboard::default(),
+        history: History::default(),
+        mask: None,
+        recall: Vec::new(),
+        recall_index: None,
+        recall_draft: String::new(),
+        numeric: None,
+        prefix: String::new(),
+        suffix: String::new(),
+        id: next_id(),
+        debounce: Duration::ZERO,
+        revealed: None,
+        reveal_tag: 0,
+        composition: Composition::default(),
     };
 
     m.cursor.set_mode(crate::cursor::Mode::Blink);
@@ -210,7 +302,9 @@ impl Default for Model {
 ///
 /// # Returns
 ///
-/// A `Cmd` that will periodically send blink messages to animate the cursor
+/// A `Cmd` that starts the blink loop of every focused text input. Each
+/// cursor then schedules its own ticks, tagged with its id, so several inputs
+/// can blink side by side without interfering.
 ///
 /// # Examples
 ///
@@ -233,18 +327,15 @@ impl Default for Model {
 /// }
 /// ```
 pub fn blink() -> Cmd {
-    use bubbletea_rs::tick as bubbletea_tick;
-    let id = 0usize;
-    let tag = 0usize;
-    bubbletea_tick(Duration::from_millis(500), move |_| {
-        Box::new(crate::cursor::BlinkMsg { id, tag }) as Msg
-    })
+    crate::cursor::blink()
 }
 
-/// Creates a command that retrieves text from the system clipboard.
+/// Creates a command that retrieves text from the default clipboard.
 ///
 /// This command reads the current clipboard contents and sends a paste message
-/// that can be handled by the text input's `update()` method.
+/// that can be handled by the text input's `update()` method. Inputs given a
+/// clipboard of their own with [`Model::with_clipboard`] read that one instead
+/// when the paste key is pressed.
 ///
 /// # Returns
 ///
@@ -269,26 +360,15 @@ pub fn blink() -> Cmd {
 /// - The clipboard contains non-text data
 /// - System clipboard permissions are denied
 pub fn paste() -> Cmd {
-    use bubbletea_rs::tick as bubbletea_tick;
-    bubbletea_tick(Duration::from_nanos(1), |_| {
-        #[cfg(feature = "clipboard-support")]
-        {
-            use clipboard::{ClipboardContext, ClipboardProvider};
-            let res: Result<String, String> = (|| {
-                let mut ctx: ClipboardContext = ClipboardProvider::new()
-                    .map_err(|e| format!("Failed to create clipboard context: {}", e))?;
-                ctx.get_contents()
-                    .map_err(|e| format!("Failed to read clipboard: {}", e))
-            })();
-            match res {
-                Ok(s) => Box::new(PasteMsg(s)) as Msg,
-                Err(e) => Box::new(PasteErrMsg(e)) as Msg,
-            }
-        }
-        #[cfg(not(feature = "clipboard-support"))]
-        {
-            Box::new(PasteErrMsg("Clipboard support not enabled".to_string())) as Msg
-        }
+    paste_from(&Clipboard::default())
+}
+
+/// Creates a command that reads `clipboard` and sends the result as a
+/// [`PasteMsg`] or a [`PasteErrMsg`].
+pub(super) fn paste_from(clipboard: &Clipboard) -> Cmd {
+    clipboard.read_cmd(|result| match result {
+        Ok(s) => Box::new(PasteMsg(s)) as Msg,
+        Err(e) => Box::new(PasteErrMsg(e)) as Msg,
     })
 }
 
@@ -0,0 +1,180 @@
+//! Numeric mode for the textinput component.
+//!
+//! In numeric mode only characters that keep the value a number, or the
+//! start of one, are accepted, and the increment and decrement keys (up and
+//! down by default) step the value within its range.
+
+use super::model::Model;
+use super::types::NumericOptions;
+use crate::error::Error;
+
+fn allows_negative(options: &NumericOptions) -> bool {
+    options.min.is_none_or(|min| min < 0.0)
+}
+
+/// Whether `text` is a number, or could become one as typing goes on.
+fn is_partial_number(text: &[char], options: &NumericOptions) -> bool {
+    let digits = match text.split_first() {
+        Some(('-', rest)) if allows_negative(options) => rest,
+        _ => text,
+    };
+    let mut seen_point = false;
+    digits.iter().all(|&ch| match ch {
+        '0'..='9' => true,
+        '.' if options.float && !seen_point => {
+            seen_point = true;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// The number of digits after the decimal point in `text`.
+fn decimals(text: &str) -> usize {
+    text.split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
+impl Model {
+    /// Turns the input into a numeric one: characters that would not make
+    /// a number are ignored, the increment and decrement keys step the
+    /// value, and values outside `min`..=`max` set a validation error.
+    /// What the current value holds that is not a number is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::{new, NumericOptions};
+    ///
+    /// let mut input = new();
+    /// input.set_numeric(NumericOptions {
+    ///     min: Some(0.0),
+    ///     max: Some(10.0),
+    ///     step: 0.5,
+    ///     float: true,
+    /// });
+    /// input.set_value("2.5");
+    /// assert_eq!(input.value_as_f64(), Some(2.5));
+    ///
+    /// input.set_value("12");
+    /// assert!(input.error().is_some());
+    /// ```
+    pub fn set_numeric(&mut self, options: NumericOptions) {
+        self.numeric = Some(options);
+        let kept = self.numeric_value(self.value.clone());
+        let err = self.validate_runes(&kept);
+        self.set_value_internal(kept, err);
+    }
+
+    /// Makes the input accept any text again.
+    pub fn clear_numeric(&mut self) {
+        self.numeric = None;
+    }
+
+    /// The options set with [`set_numeric`](Self::set_numeric), if any.
+    pub fn numeric(&self) -> Option<&NumericOptions> {
+        self.numeric.as_ref()
+    }
+
+    /// The value as a number, or `None` while it is not one, such as when
+    /// empty or just a minus sign.
+    pub fn value_as_f64(&self) -> Option<f64> {
+        self.value()
+            .parse()
+            .ok()
+            .filter(|value: &f64| value.is_finite())
+    }
+
+    /// The characters of `text` that make a number, for
+    /// [`set_value`](Self::set_value).
+    pub(super) fn numeric_value(&self, text: Vec<char>) -> Vec<char> {
+        let Some(options) = self.numeric else {
+            return text;
+        };
+        let mut kept = Vec::new();
+        for ch in text {
+            kept.push(ch);
+            if !is_partial_number(&kept, &options) {
+                kept.pop();
+            }
+        }
+        kept
+    }
+
+    /// The characters of `runes` that keep the value a number when
+    /// inserted at the cursor one by one.
+    pub(super) fn numeric_runes(&self, runes: Vec<char>) -> Vec<char> {
+        let Some(options) = self.numeric else {
+            return runes;
+        };
+        let mut head = self.value[..self.pos].to_vec();
+        let tail = &self.value[self.pos..];
+        let mut kept = Vec::new();
+        for ch in runes {
+            head.push(ch);
+            let mut candidate = head.clone();
+            candidate.extend_from_slice(tail);
+            if is_partial_number(&candidate, &options) {
+                kept.push(ch);
+            } else {
+                head.pop();
+            }
+        }
+        kept
+    }
+
+    /// Whether replacing the character under the cursor with `rune` keeps
+    /// the value a number.
+    pub(super) fn numeric_accepts_overwrite(&self, rune: char) -> bool {
+        let Some(options) = self.numeric else {
+            return true;
+        };
+        let mut candidate = self.value.clone();
+        candidate[self.pos] = rune;
+        is_partial_number(&candidate, &options)
+    }
+
+    /// The error for a number outside the range, if numeric mode is on.
+    pub(super) fn range_error(&self, runes: &[char]) -> Option<Error> {
+        let options = self.numeric?;
+        let value: f64 = runes.iter().collect::<String>().parse().ok()?;
+        match (options.min, options.max) {
+            (Some(min), _) if value < min => {
+                Some(Error::Validation(format!("must be at least {}", min)))
+            }
+            (_, Some(max)) if value > max => {
+                Some(Error::Validation(format!("must be at most {}", max)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves the value `steps` steps, keeping it within the range. An
+    /// empty value steps from zero.
+    pub(super) fn step_numeric(&mut self, steps: f64) {
+        let Some(options) = self.numeric else {
+            return;
+        };
+        let start = self.value_as_f64().unwrap_or(0.0);
+        let mut value = start + steps * options.step;
+        if let Some(max) = options.max {
+            value = value.min(max);
+        }
+        if let Some(min) = options.min {
+            value = value.max(min);
+        }
+        let places = if options.float {
+            decimals(&options.step.to_string()).max(decimals(&self.value()))
+        } else {
+            0
+        };
+        let mut runes: Vec<char> = format!("{:.*}", places, value).chars().collect();
+        // Rounding can leave a negative zero
+        if runes[1..].iter().all(|&ch| matches!(ch, '0' | '.')) && runes[0] == '-' {
+            runes.remove(0);
+        }
+        let err = self.validate_runes(&runes);
+        self.set_value_internal(runes, err);
+        self.cursor_end();
+    }
+}
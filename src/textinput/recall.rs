@@ -0,0 +1,90 @@
+//! Readline-style history recall for the textinput component.
+//!
+//! The history keys (up and down by default) step through earlier entries,
+//! for prompts and REPLs. Only entries that start with what was typed
+//! before browsing are recalled, so typing `git ` and pressing up cycles
+//! through the `git` commands. Stepping down past the newest entry brings
+//! back what was typed.
+
+use super::model::Model;
+
+impl Model {
+    /// Replaces the entries the history keys recall, oldest first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::textinput::new;
+    ///
+    /// let mut input = new();
+    /// input.set_history(vec!["ls".into(), "git status".into()]);
+    /// input.push_history("git log");
+    /// assert_eq!(input.history().len(), 3);
+    /// ```
+    pub fn set_history(&mut self, entries: Vec<String>) {
+        self.recall = entries;
+        self.recall_index = None;
+    }
+
+    /// Adds `entry` as the newest history entry, typically when the input
+    /// is submitted. Empty entries and repeats of the newest are skipped.
+    pub fn push_history(&mut self, entry: &str) {
+        if !entry.is_empty() && self.recall.last().map(String::as_str) != Some(entry) {
+            self.recall.push(entry.to_string());
+        }
+        self.recall_index = None;
+    }
+
+    /// The history entries, oldest first.
+    pub fn history(&self) -> &[String] {
+        &self.recall
+    }
+
+    /// Shows the newest entry before the current one that matches what was
+    /// typed, if there is one.
+    pub(super) fn recall_previous(&mut self) {
+        if self.recall_index.is_none() {
+            self.recall_draft = self.value();
+        }
+        let current = self.value();
+        let before = self.recall_index.unwrap_or(self.recall.len());
+        let found = (0..before).rev().find(|&i| self.recalls(i, &current));
+        if let Some(i) = found {
+            self.recall_index = Some(i);
+            self.show_recalled(self.recall[i].clone());
+        }
+    }
+
+    /// Shows the oldest entry after the current one that matches what was
+    /// typed, or what was typed once there are none.
+    pub(super) fn recall_next(&mut self) {
+        let Some(index) = self.recall_index else {
+            return;
+        };
+        let current = self.value();
+        match (index + 1..self.recall.len()).find(|&i| self.recalls(i, &current)) {
+            Some(i) => {
+                self.recall_index = Some(i);
+                self.show_recalled(self.recall[i].clone());
+            }
+            None => {
+                self.recall_index = None;
+                self.show_recalled(self.recall_draft.clone());
+            }
+        }
+    }
+
+    /// Whether entry `i` matches the draft and differs from what is shown,
+    /// so repeated entries are stepped over.
+    fn recalls(&self, i: usize, current: &str) -> bool {
+        let entry = &self.recall[i];
+        entry.starts_with(&self.recall_draft) && entry != current
+    }
+
+    fn show_recalled(&mut self, text: String) {
+        let runes = self.masked_runes(text.chars().collect());
+        let err = self.validate_runes(&runes);
+        self.set_value_internal(runes, err);
+        self.cursor_end();
+    }
+}
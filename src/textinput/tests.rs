@@ -146,6 +146,19 @@ mod textinput_tests {
         assert!(!view_none.contains("*"));
     }
 
+    #[test]
+    fn test_accessible_view_hides_echo_none_length() {
+        let mut input = new();
+        input.set_value("secret");
+        input.set_echo_mode(EchoMode::EchoPassword);
+        assert_eq!(input.accessible_view(), "> 6 hidden characters");
+
+        input.set_echo_mode(EchoMode::EchoNone);
+        assert_eq!(input.accessible_view(), "> hidden input");
+        input.reset();
+        assert_eq!(input.accessible_view(), "> hidden input");
+    }
+
     #[test]
     fn test_placeholder() {
         // Test placeholder functionality
@@ -349,6 +362,7 @@ mod textinput_tests {
             .err
             .as_ref()
             .unwrap()
+            .to_string()
             .contains("too long"));
 
         // Test invalid - missing space
@@ -358,6 +372,7 @@ mod textinput_tests {
             .err
             .as_ref()
             .unwrap()
+            .to_string()
             .contains("separate groups"));
 
         // Test invalid - non-numeric
@@ -423,7 +438,568 @@ mod textinput_tests {
         );
     }
 
+    #[test]
+    fn test_overwrite_mode_replaces_characters() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_value("hello");
+        input.cursor_start();
+
+        // Insert toggles overwrite mode and the cursor indicator with it
+        input.update(key(KeyCode::Insert));
+        assert!(input.overwrite());
+        assert!(input.cursor.overwrite());
+
+        input.update(key(KeyCode::Char('J')));
+        assert_eq!(input.value(), "Jello");
+        assert_eq!(input.position(), 1);
+
+        // Typing past the end appends
+        input.cursor_end();
+        input.update(key(KeyCode::Char('!')));
+        assert_eq!(input.value(), "Jello!");
+
+        input.update(key(KeyCode::Insert));
+        assert!(!input.overwrite());
+        input.cursor_start();
+        input.update(key(KeyCode::Char('>')));
+        assert_eq!(input.value(), ">Jello!");
+    }
+
     /// Tests specifically for placeholder rendering bug fix and regression prevention
+    #[test]
+    fn test_paste_reads_injected_clipboard() {
+        use crate::clipboard::{Clipboard, Memory};
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut input = new().with_clipboard(Clipboard::new(Memory::with_text("world")));
+        std::mem::drop(input.focus());
+        input.set_value("hello ");
+
+        let cmd = input
+            .update(Box::new(KeyMsg {
+                key: KeyCode::Char('v'),
+                modifiers: KeyModifiers::CONTROL,
+            }))
+            .expect("paste key returns a command");
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let msg = runtime
+            .block_on(cmd)
+            .expect("paste command sends a message");
+        input.update(msg);
+        assert_eq!(input.value(), "hello world");
+    }
+
+    #[test]
+    fn test_copy_key_puts_value_on_clipboard() {
+        use super::super::EchoMode;
+        use crate::clipboard::{Clipboard, CopiedMsg, Memory};
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let memory = Memory::new();
+        let mut input = new().with_clipboard(Clipboard::new(memory.clone()));
+        std::mem::drop(input.focus());
+        input.set_echo_mode(EchoMode::EchoPassword);
+        input.set_value("s3cret-token");
+
+        let cmd = input
+            .update(Box::new(KeyMsg {
+                key: KeyCode::Char('w'),
+                modifiers: KeyModifiers::ALT,
+            }))
+            .expect("copy key returns a command");
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let msg = runtime.block_on(cmd).expect("copy command sends a message");
+        assert_eq!(
+            msg.downcast_ref::<CopiedMsg>(),
+            Some(&CopiedMsg("s3cret-token".into()))
+        );
+        assert_eq!(memory.text(), "s3cret-token");
+        assert_eq!(input.value(), "s3cret-token");
+    }
+
+    #[test]
+    fn test_undo_restores_text_removed_by_ctrl_u() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode, modifiers: KeyModifiers| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers,
+            })
+        };
+        let none = KeyModifiers::NONE;
+        let ctrl = KeyModifiers::CONTROL;
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        for ch in "find me".chars() {
+            input.update(key(KeyCode::Char(ch), none));
+        }
+        input.update(key(KeyCode::Char('u'), ctrl));
+        assert_eq!(input.value(), "");
+
+        input.update(key(KeyCode::Char('_'), ctrl));
+        assert_eq!(input.value(), "find me");
+        assert_eq!(input.position(), 7);
+
+        // Typing is undone a word at a time
+        input.update(key(KeyCode::Char('z'), ctrl));
+        assert_eq!(input.value(), "find ");
+        input.update(key(KeyCode::Char('7'), ctrl));
+        assert_eq!(input.value(), "");
+        assert!(!input.can_undo());
+
+        input.update(key(KeyCode::Char('r'), ctrl));
+        assert_eq!(input.value(), "find ");
+        assert!(input.can_redo());
+
+        // A new edit drops what could be redone
+        input.update(key(KeyCode::Char('x'), none));
+        assert!(!input.can_redo());
+        assert!(!input.redo());
+        assert_eq!(input.value(), "find x");
+    }
+
+    #[test]
+    fn test_mask_inserts_literals_and_skips_them() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_mask("(###) ###-####");
+        assert_eq!(input.mask().as_deref(), Some("(###) ###-####"));
+
+        // Letters do not fit, and literals are put in as digits are typed
+        for ch in "55x51".chars() {
+            input.update(key(KeyCode::Char(ch)));
+        }
+        assert_eq!(input.value(), "(555) 1");
+        assert_eq!(input.position(), 7);
+
+        // Deleting steps back over the literals
+        input.update(key(KeyCode::Backspace));
+        input.update(key(KeyCode::Backspace));
+        assert_eq!(input.value(), "(55");
+        for ch in "51234567".chars() {
+            input.update(key(KeyCode::Char(ch)));
+        }
+        assert_eq!(input.value(), "(555) 123-4567");
+        assert_eq!(input.unmasked_value(), "5551234567");
+
+        // The cursor never rests on a literal
+        input.update(key(KeyCode::Home));
+        assert_eq!(input.position(), 1);
+        for _ in 0..3 {
+            input.update(key(KeyCode::Right));
+        }
+        assert_eq!(input.position(), 6);
+
+        // A full mask takes no more, and set_value refits formatted text
+        input.update(key(KeyCode::Char('9')));
+        assert_eq!(input.value(), "(555) 123-4567");
+        input.set_value("(212) 555-0100");
+        assert_eq!(input.unmasked_value(), "2125550100");
+
+        input.set_mask("");
+        assert_eq!(input.mask(), None);
+    }
+
+    #[test]
+    fn test_mask_without_slots_takes_editing_keys() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_mask("---");
+        for (code, modifiers) in [
+            (KeyCode::Char('w'), KeyModifiers::CONTROL),
+            (KeyCode::Char('d'), KeyModifiers::ALT),
+            (KeyCode::Backspace, KeyModifiers::NONE),
+            (KeyCode::Delete, KeyModifiers::NONE),
+            (KeyCode::Char('5'), KeyModifiers::NONE),
+        ] {
+            input.update(Box::new(KeyMsg {
+                key: code,
+                modifiers,
+            }));
+        }
+        assert_eq!(input.unmasked_value(), "");
+    }
+
+    #[test]
+    fn test_validation_error_is_shown_below_input() {
+        let mut input = new();
+        input.set_validate(Box::new(|s: &str| {
+            if s.chars().all(|c| c.is_ascii_digit()) {
+                Ok(())
+            } else {
+                Err("digits only".to_string())
+            }
+        }));
+        input.set_value("42");
+        assert!(input.error().is_none());
+        assert_eq!(input.view().lines().count(), 1);
+
+        // The message only appears when asked for
+        input.set_value("4x");
+        assert_eq!(
+            input.error(),
+            Some(&crate::Error::Validation("digits only".into()))
+        );
+        assert_eq!(input.view().lines().count(), 1);
+
+        input.show_error = true;
+        let view = input.view();
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("digits only"));
+
+        input.set_value("7");
+        assert_eq!(input.view().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_history_recall_filters_by_typed_prefix() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_history(vec!["git status".into(), "ls -la".into()]);
+        input.push_history("git log");
+        input.push_history("git log");
+        assert_eq!(input.history().len(), 3);
+
+        // Up cycles through the entries starting with what was typed
+        for ch in "git ".chars() {
+            input.update(key(KeyCode::Char(ch)));
+        }
+        input.update(key(KeyCode::Up));
+        assert_eq!(input.value(), "git log");
+        assert_eq!(input.position(), 7);
+        input.update(key(KeyCode::Up));
+        assert_eq!(input.value(), "git status");
+        input.update(key(KeyCode::Up));
+        assert_eq!(input.value(), "git status");
+
+        // Down past the newest entry brings back the typed text
+        input.update(key(KeyCode::Down));
+        assert_eq!(input.value(), "git log");
+        input.update(key(KeyCode::Down));
+        assert_eq!(input.value(), "git ");
+
+        // Editing starts a new search from the edited text
+        input.update(key(KeyCode::Up));
+        input.update(key(KeyCode::Backspace));
+        input.update(key(KeyCode::Up));
+        assert_eq!(input.value(), "git log");
+
+        // A recalled entry is an undo step
+        assert!(input.undo());
+        assert_eq!(input.value(), "git lo");
+    }
+
+    #[test]
+    fn test_numeric_mode_filters_and_steps() {
+        use super::super::NumericOptions;
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_numeric(NumericOptions {
+            min: Some(0.0),
+            max: Some(2.0),
+            step: 0.25,
+            float: true,
+        });
+
+        // Only what keeps the value a number is taken
+        for ch in "-1a.5.0".chars() {
+            input.update(key(KeyCode::Char(ch)));
+        }
+        assert_eq!(input.value(), "1.50");
+        assert_eq!(input.value_as_f64(), Some(1.5));
+
+        // Steps keep the step's precision and stop at the range
+        input.update(key(KeyCode::Up));
+        assert_eq!(input.value(), "1.75");
+        input.update(key(KeyCode::Up));
+        input.update(key(KeyCode::Up));
+        assert_eq!(input.value(), "2.00");
+        assert!(input.error().is_none());
+
+        input.set_value("3");
+        assert_eq!(
+            input.error(),
+            Some(&crate::Error::Validation("must be at most 2".into()))
+        );
+        input.update(key(KeyCode::Down));
+        assert_eq!(input.value(), "2.00");
+
+        input.set_value("");
+        assert_eq!(input.value_as_f64(), None);
+        input.update(key(KeyCode::Down));
+        assert_eq!(input.value(), "0.00");
+    }
+
+    #[test]
+    fn test_adornments_shrink_the_editable_window() {
+        use lipgloss_extras::lipgloss::strip_ansi;
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.prompt = String::new();
+        input.set_width(20);
+        input.set_prefix("https://");
+        input.set_suffix(".git");
+        input.set_value("example.com/some/repo");
+        assert_eq!(input.value(), "example.com/some/repo");
+
+        // Eight columns are left between the adornments
+        let view = strip_ansi(&input.view());
+        assert!(view.starts_with("https://"), "{view:?}");
+        assert!(view.ends_with(".git"), "{view:?}");
+        assert!(view.contains("ome/repo"), "{view:?}");
+        assert!(!view.contains("example"), "{view:?}");
+        assert_eq!(crate::text::width(&view), 21);
+    }
+
+    #[test]
+    fn test_debounce_sends_the_value_once_typing_pauses() {
+        use super::super::InputChangedMsg;
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+        use std::time::Duration;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut input = new().with_debounce(Duration::from_millis(10));
+        std::mem::drop(input.focus());
+        let first = input.update(key(KeyCode::Char('a'))).expect("a change");
+        let last = input.update(key(KeyCode::Char('b'))).expect("a change");
+
+        // Only the last change of a burst is sent
+        assert!(runtime.block_on(first).is_none());
+        let msg = runtime.block_on(last).expect("the command sends a message");
+        assert_eq!(
+            msg.downcast_ref::<InputChangedMsg>(),
+            Some(&InputChangedMsg {
+                id: input.id(),
+                value: "ab".into()
+            })
+        );
+
+        // Keys that leave the value alone send nothing
+        assert!(input.update(key(KeyCode::Left)).is_none());
+    }
+
+    #[test]
+    fn test_echo_password_last_char_masks_after_a_while() {
+        use super::super::{EchoMode, HideLastCharMsg};
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+        use lipgloss_extras::lipgloss::strip_ansi;
+        use std::time::Duration;
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.prompt = String::new();
+        input.set_echo_mode(EchoMode::EchoPasswordLastChar);
+        input.set_echo_character('•');
+        input.reveal_duration = Duration::from_millis(5);
+
+        input.update(key(KeyCode::Char('a')));
+        let stale = input.update(key(KeyCode::Char('b'))).expect("a hide timer");
+        assert_eq!(strip_ansi(&input.view()).trim_end(), "•b");
+        let latest = input.update(key(KeyCode::Char('c'))).expect("a hide timer");
+        assert_eq!(strip_ansi(&input.view()).trim_end(), "••c");
+
+        // Only the timer of the latest character hides it
+        let msg = runtime.block_on(stale).expect("the timer fires");
+        input.update(msg);
+        assert_eq!(strip_ansi(&input.view()).trim_end(), "••c");
+        let msg = runtime.block_on(latest).expect("the timer fires");
+        assert!(msg.downcast_ref::<HideLastCharMsg>().is_some());
+        input.update(msg);
+        assert_eq!(strip_ansi(&input.view()).trim_end(), "•••");
+
+        // Moving the cursor hides it at once
+        input.update(key(KeyCode::Char('d')));
+        input.update(key(KeyCode::Home));
+        assert_eq!(strip_ansi(&input.view()).trim_end(), "••••");
+        assert_eq!(input.value(), "abcd");
+    }
+
+    #[test]
+    fn test_cursor_moves_by_grapheme_and_window_by_width() {
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+        use lipgloss_extras::lipgloss::strip_ansi;
+
+        let key = |code: KeyCode| -> bubbletea_rs::Msg {
+            Box::new(KeyMsg {
+                key: code,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        // A family emoji is five chars drawn as one
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_value(&format!("{family}a"));
+        input.update(key(KeyCode::Left));
+        assert_eq!(input.position(), 5);
+        input.update(key(KeyCode::Left));
+        assert_eq!(input.position(), 0);
+        input.update(key(KeyCode::Right));
+        assert_eq!(input.position(), 5);
+        input.update(key(KeyCode::Backspace));
+        assert_eq!(input.value(), "a");
+        assert_eq!(input.position(), 0);
+
+        // Wide characters take two columns of the window each
+        input.prompt = String::new();
+        input.set_width(6);
+        input.set_value("日本語のテキスト");
+        input.update(key(KeyCode::End));
+        let view = strip_ansi(&input.view());
+        assert_eq!(view.trim_end(), "キスト");
+        input.update(key(KeyCode::Home));
+        let view = strip_ansi(&input.view());
+        assert_eq!(view.trim_end(), "日本語");
+        assert_eq!(crate::text::width(&view), 6);
+    }
+
+    #[test]
+    fn test_ime_composition_is_shown_until_committed() {
+        use crate::ime::ImeMsg;
+        use bubbletea_rs::KeyMsg;
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_value("say ");
+
+        input.update(Box::new(ImeMsg::Preedit("ni".into())));
+        // Keystrokes belong to the input method while it composes
+        input.update(Box::new(KeyMsg {
+            key: KeyCode::Char('h'),
+            modifiers: KeyModifiers::NONE,
+        }));
+        input.update(Box::new(ImeMsg::Preedit("にほ".into())));
+        assert_eq!(input.value(), "say ");
+        assert!(lipgloss_extras::lipgloss::strip_ansi(&input.view()).contains("say にほ"));
+
+        input.update(Box::new(ImeMsg::Commit("日本".into())));
+        assert_eq!(input.value(), "say 日本");
+        assert_eq!(input.position(), 6);
+        assert!(input.undo());
+        assert_eq!(input.value(), "say ");
+    }
+
+    #[test]
+    fn test_long_placeholder_is_cut_with_an_ellipsis() {
+        use lipgloss_extras::lipgloss::strip_ansi;
+
+        let mut input = new();
+        input.prompt = String::new();
+        input.set_width(10);
+        input.set_placeholder("Search files, symbols and commands");
+        let view = strip_ansi(&input.view());
+        assert_eq!(view, "Search fi… ");
+
+        // Wide characters are never split, and the field keeps its width
+        input.set_placeholder("作業ディレクトリ");
+        let view = strip_ansi(&input.view());
+        assert_eq!(view, "作業ディ…  ");
+        input.set_value("x");
+        assert_eq!(
+            crate::text::width(&view),
+            crate::text::width(&strip_ansi(&input.view()))
+        );
+
+        input.set_value("");
+        input.set_placeholder("Short");
+        assert_eq!(strip_ansi(&input.view()), "Short      ");
+    }
+
+    #[test]
+    fn test_bracketed_paste_inserts_text_on_one_line() {
+        let mut input = new();
+        std::mem::drop(input.focus());
+        input.set_value("say ");
+
+        // The paste is text, not keys, and its line breaks become spaces
+        input.update(Box::new(bubbletea_rs::PasteMsg("quit\r\nnow\n".into())));
+        assert_eq!(input.value(), "say quit now ");
+        assert_eq!(input.position(), 13);
+    }
+
     mod placeholder_rendering_tests {
         use super::*;
 
@@ -704,7 +1280,7 @@ mod textinput_tests {
 
         #[test]
         fn test_placeholder_styling_preserved() {
-            use lipgloss_extras::prelude::*;
+            use lipgloss_extras::prelude::{Color, Style};
 
             let mut input = new();
             input.set_placeholder("Styled");
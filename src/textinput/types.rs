@@ -1,5 +1,6 @@
 //! Core types for the textinput component.
 
+use crate::error::Error;
 use bubbletea_rs::Msg;
 
 /// Internal messages for clipboard operations.
@@ -10,7 +11,18 @@ pub struct PasteMsg(pub String);
 
 /// Clipboard paste error message.
 #[derive(Debug, Clone)]
-pub struct PasteErrMsg(pub String);
+pub struct PasteErrMsg(pub Error);
+
+/// Sent once typing has paused when a
+/// [debounce](super::Model::with_debounce) is set, carrying the value as it
+/// stands then.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputChangedMsg {
+    /// The ID of the input that changed.
+    pub id: i64,
+    /// The new value.
+    pub value: String,
+}
 
 /// EchoMode sets the input behavior of the text input field.
 /// Matches Go's EchoMode enum exactly.
@@ -24,6 +36,54 @@ pub enum EchoMode {
     /// EchoNone displays nothing as characters are entered. This is commonly
     /// seen for password fields on the command line.
     EchoNone,
+    /// EchoPasswordLastChar masks like `EchoPassword`, but shows the last
+    /// character typed for `reveal_duration` first, as phones do.
+    EchoPasswordLastChar,
+}
+
+/// Masks the character shown by
+/// [`EchoPasswordLastChar`](EchoMode::EchoPasswordLastChar) once its time
+/// is up; pass it back to [`Model::update`](super::Model::update).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HideLastCharMsg {
+    pub(super) id: i64,
+    pub(super) tag: u64,
+}
+
+/// Saved state of a text input; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// The text entered.
+    pub value: String,
+    /// Cursor position, in characters.
+    pub cursor: usize,
+}
+
+/// Options for a numeric input; see
+/// [`Model::set_numeric`](super::Model::set_numeric).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericOptions {
+    /// The smallest value allowed, if any. Negative numbers can only be
+    /// typed when this is unset or below zero.
+    pub min: Option<f64>,
+    /// The largest value allowed, if any.
+    pub max: Option<f64>,
+    /// How much the increment and decrement keys change the value.
+    pub step: f64,
+    /// Whether a decimal point is allowed.
+    pub float: bool,
+}
+
+impl Default for NumericOptions {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+            float: false,
+        }
+    }
 }
 
 /// ValidateFunc is a function that returns an error if the input is invalid.
@@ -36,6 +96,12 @@ impl From<PasteMsg> for Msg {
     }
 }
 
+impl From<HideLastCharMsg> for Msg {
+    fn from(msg: HideLastCharMsg) -> Self {
+        Box::new(msg) as Msg
+    }
+}
+
 impl From<PasteErrMsg> for Msg {
     fn from(msg: PasteErrMsg) -> Self {
         Box::new(msg) as Msg
@@ -2,45 +2,82 @@
 
 use super::model::Model;
 use super::types::EchoMode;
+use crate::graphemes;
+use crate::text;
+use lipgloss_extras::prelude::Style;
+use std::ops::Range;
 
 impl Model {
     /// View renders the textinput in its current state.
-    /// Matches Go's View method exactly.
+    /// Matches Go's View method exactly. In accessible mode this is the
+    /// [`accessible_view`](Self::accessible_view).
+    ///
+    /// While there is an error the prompt and text take `error_style`, and
+    /// with `show_error` set the message follows on its own line.
     pub fn view(&self) -> String {
-        // Placeholder text
-        if self.value.is_empty() && !self.placeholder.is_empty() {
-            return self.placeholder_view();
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        let field = self.field_view();
+        match &self.err {
+            Some(err) if self.show_error => {
+                format!("{}\n{}", field, self.error_style.render(&err.to_string()))
+            }
+            _ => field,
         }
+    }
 
-        let value_slice = if self.offset_right <= self.value.len() {
-            &self.value[self.offset..self.offset_right]
+    /// The prompt and text styles, which give way to `error_style` while
+    /// there is an error.
+    fn styles(&self) -> (&Style, &Style) {
+        if self.err.is_some() {
+            (&self.error_style, &self.error_style)
         } else {
-            &self.value[self.offset..]
-        };
+            (&self.prompt_style, &self.text_style)
+        }
+    }
 
-        let pos = self.pos.saturating_sub(self.offset);
-        let value_str: String = value_slice.iter().collect();
-        let display_value = self.echo_transform(&value_str);
+    /// The input itself, without the error message.
+    fn field_view(&self) -> String {
+        // Placeholder text
+        if self.value.is_empty() && !self.placeholder.is_empty() {
+            return self.placeholder_view();
+        }
 
+        // Each grapheme cluster is drawn whole, so the cursor covers all of
+        // an emoji or a letter with its accents
+        let end = self.offset_right.min(self.value.len());
+        let clusters: Vec<Range<usize>> = graphemes::clusters(&self.value[self.offset..end])
+            .into_iter()
+            .map(|range| self.offset + range.start..self.offset + range.end)
+            .collect();
+        let shown: Vec<String> = clusters
+            .iter()
+            .map(|range| self.echo_cluster(range.clone()))
+            .collect();
+        let under = clusters
+            .iter()
+            .position(|range| range.contains(&self.pos))
+            .filter(|_| self.echo_mode != EchoMode::EchoNone);
+        let display_value = shown.concat();
+
+        let (prompt_style, text_style) = self.styles();
         let mut v = String::new();
 
         // Text before cursor
-        if pos < display_value.len() {
-            v.push_str(&self.text_style.render(&display_value[..pos]));
-        } else {
-            v.push_str(&self.text_style.render(&display_value));
-        }
+        let before = under.unwrap_or(shown.len());
+        v.push_str(&text_style.render(&shown[..before].concat()));
+        v.push_str(&self.composition.view());
 
         // Cursor and text under it
-        if pos < display_value.len() {
-            let char_at_pos = display_value.chars().nth(pos).unwrap_or(' ');
+        if let Some(i) = under {
             let mut cur = self.cursor.clone();
-            cur.set_char(&char_at_pos.to_string());
+            cur.set_char(&shown[i]);
             v.push_str(&cur.view());
 
             // Text after cursor
-            if pos + 1 < display_value.len() {
-                v.push_str(&self.text_style.render(&display_value[pos + 1..]));
+            if i + 1 < shown.len() {
+                v.push_str(&text_style.render(&shown[i + 1..].concat()));
             }
 
             v.push_str(&self.completion_view(0));
@@ -49,7 +86,7 @@ impl Model {
             if self.focus && self.can_accept_suggestion() {
                 let suggestion = &self.matched_suggestions[self.current_suggestion_index];
                 if self.value.len() < suggestion.len() {
-                    let next_char = suggestion[pos];
+                    let next_char = suggestion[self.value.len()];
                     let mut cur = self.cursor.clone();
                     cur.set_char(&next_char.to_string());
                     v.push_str(&cur.view());
@@ -67,73 +104,93 @@ impl Model {
         }
 
         // Fill remaining width with background
-        let val_width = display_value.chars().count();
-        if self.width > 0 && val_width <= self.width as usize {
-            let padding = (self.width as usize).saturating_sub(val_width);
-            if val_width + padding <= self.width as usize && pos < display_value.len() {
+        let val_width = text::width(&display_value) + self.composition.width();
+        if self.editable_width() > 0 && val_width <= self.editable_width() as usize {
+            let padding = (self.editable_width() as usize).saturating_sub(val_width);
+            if val_width + padding <= self.editable_width() as usize && under.is_some() {
                 // padding += 1; // Adjust for cursor
             }
-            v.push_str(&self.text_style.render(&" ".repeat(padding)));
+            v.push_str(&text_style.render(&" ".repeat(padding)));
         }
 
-        format!("{}{}", self.prompt_style.render(&self.prompt), v)
+        self.adorn(prompt_style, v)
     }
 
-    /// Internal placeholder view rendering
-    pub(super) fn placeholder_view(&self) -> String {
-        let mut v = String::new();
-
-        let placeholder_chars: Vec<char> = self.placeholder.chars().collect();
-        let p = if self.width > 0 {
-            let mut p_vec = vec![' '; self.width as usize + 1];
-            for (i, &ch) in placeholder_chars.iter().enumerate() {
-                if i < p_vec.len() {
-                    p_vec[i] = ch;
+    /// Renders the input as plain text: the prompt and the whole value, or
+    /// the placeholder while empty. Password input is described by its
+    /// length, and input with [`EchoMode::EchoNone`] only as hidden, since
+    /// it shows nothing on screen either. A validation error follows on its
+    /// own line.
+    pub fn accessible_view(&self) -> String {
+        let prompt = self.prompt.trim();
+        let value = match self.echo_mode {
+            EchoMode::EchoNone => "hidden input".to_string(),
+            _ if self.value.is_empty() => {
+                if self.placeholder.is_empty() {
+                    "empty".to_string()
+                } else {
+                    format!("empty, {}", self.placeholder)
                 }
             }
-            p_vec
+            EchoMode::EchoNormal => format!("{}{}{}", self.prefix, self.value(), self.suffix),
+            EchoMode::EchoPassword | EchoMode::EchoPasswordLastChar => {
+                format!("{} hidden characters", self.value.len())
+            }
+        };
+        let mut out = if prompt.is_empty() {
+            value
         } else {
-            placeholder_chars
+            format!("{} {}", prompt, value)
         };
-
-        if !p.is_empty() {
-            let mut cur = self.cursor.clone();
-            cur.set_char(&p[0].to_string());
-            v.push_str(&cur.view());
-        }
-
-        if self.width < 1 && p.len() <= 1 {
-            return format!("{}{}", self.prompt_style.render(&self.prompt), v);
+        if let Some(err) = &self.err {
+            out.push_str(&format!("\nError: {}", err));
         }
+        out
+    }
 
-        if self.width > 0 {
-            let min_width = self.placeholder.chars().count();
-            let avail_width = (self.width as usize).saturating_sub(min_width) + 1;
+    /// Internal placeholder view rendering
+    pub(super) fn placeholder_view(&self) -> String {
+        // The placeholder is windowed like a value with the cursor at its
+        // start, and ends in an ellipsis when cut short. The first cluster
+        // under the cursor always shows.
+        let width = self.editable_width();
+        let shown = if width > 0 {
+            text::truncate(&self.placeholder, (width as usize).max(2))
+        } else {
+            self.placeholder.clone()
+        };
+        let runes: Vec<char> = shown.chars().collect();
+        let first = graphemes::clusters(&runes)
+            .first()
+            .map_or(0, |range| range.end);
 
-            if p.len() > 1 {
-                let end_idx = std::cmp::min(p.len(), min_width);
-                let text: String = p[1..end_idx].iter().collect();
-                v.push_str(&self.placeholder_style.render(&text));
-            }
-            v.push_str(&self.placeholder_style.render(&" ".repeat(avail_width)));
-        } else if p.len() > 1 {
-            // Render remaining placeholder text after the cursor character
-            let text: String = p[1..].iter().collect();
-            v.push_str(&self.placeholder_style.render(&text));
+        let mut v = String::new();
+        let mut cur = self.cursor.clone();
+        cur.set_char(&runes[..first].iter().collect::<String>());
+        v.push_str(&cur.view());
+        if first < runes.len() {
+            let rest: String = runes[first..].iter().collect();
+            v.push_str(&self.placeholder_style.render(&rest));
+        }
+        if width > 0 {
+            // One column past the window for the cursor, as with a value
+            let padding = (width as usize + 1).saturating_sub(text::width(&shown));
+            v.push_str(&self.placeholder_style.render(&" ".repeat(padding)));
         }
 
-        format!("{}{}", self.prompt_style.render(&self.prompt), v)
+        self.adorn(self.styles().0, v)
     }
 
-    /// Internal echo transformation
-    pub(super) fn echo_transform(&self, v: &str) -> String {
+    /// How the cluster at `range` of the value is drawn in the echo mode.
+    fn echo_cluster(&self, range: Range<usize>) -> String {
+        let revealed = self.revealed.is_some_and(|i| range.contains(&i));
         match self.echo_mode {
-            EchoMode::EchoPassword => {
-                let width = v.chars().count();
-                self.echo_character.to_string().repeat(width)
+            EchoMode::EchoNormal => self.value[range].iter().collect(),
+            EchoMode::EchoPasswordLastChar if revealed => self.value[range].iter().collect(),
+            EchoMode::EchoPassword | EchoMode::EchoPasswordLastChar => {
+                self.echo_character.to_string()
             }
             EchoMode::EchoNone => String::new(),
-            EchoMode::EchoNormal => v.to_string(),
         }
     }
 
@@ -0,0 +1,317 @@
+//! Central theming for all widgets.
+//!
+//! A [`Theme`] pairs a color [`Palette`] with a handful of semantic styles
+//! (title, muted text, selection, errors, ...). Every widget's style struct
+//! offers a `from_theme(&Theme)` constructor that maps these onto its own
+//! fields, so one theme restyles the whole application consistently.
+//!
+//! Widgets that implement [`Themeable`] can be restyled at runtime with
+//! [`apply`], which also records the theme as the [`current`] one so widgets
+//! created later can pick it up.
+//!
+//! # Built-in Themes
+//!
+//! | Constructor | Description |
+//! |-------------|-------------|
+//! | [`Theme::charm`] | Pink and purple accents matching the crate defaults |
+//! | [`Theme::dark`] | Cool blues for dark terminals |
+//! | [`Theme::light`] | Dark text and saturated accents for light terminals |
+//!
+//! # Light and Dark Terminals
+//!
+//! Default widget styles use lipgloss adaptive colors, which pick a variant
+//! based on the detected terminal background (`COLORFGBG`, falling back to
+//! dark). Detection can be wrong, so [`force_dark`] and [`force_light`]
+//! override it globally. Call them early: a few widgets, such as the
+//! progress bar, resolve their default colors when they are created.
+//!
+//! ```rust
+//! use bubbletea_widgets::theme::{self, Theme};
+//!
+//! theme::force_light();
+//! assert!(!theme::has_dark_background());
+//! assert_eq!(Theme::adaptive().name, "light");
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::theme::{self, Theme, Themeable};
+//! use bubbletea_widgets::{help, table};
+//!
+//! let mut help = help::Model::new();
+//! let mut table = table::Model::new(vec![table::Column::new("Name", 10)]);
+//!
+//! // Build styles for a single widget...
+//! table.styles = table::Styles::from_theme(&Theme::dark());
+//!
+//! // ...or switch every registered widget at once.
+//! theme::apply(&Theme::light(), &mut [&mut help, &mut table]);
+//! assert_eq!(theme::current().name, "light");
+//! ```
+
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+static CURRENT: Lazy<RwLock<Theme>> = Lazy::new(|| RwLock::new(Theme::charm()));
+
+/// The raw colors of a theme.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    /// Main accent, used for titles, cursors and selections.
+    pub primary: Color,
+    /// Secondary accent, used for frames and borders.
+    pub secondary: Color,
+    /// Tertiary accent, used for headings and links.
+    pub accent: Color,
+    /// Regular text.
+    pub foreground: Color,
+    /// The terminal background the theme is designed for.
+    pub background: Color,
+    /// De-emphasised text such as descriptions.
+    pub muted: Color,
+    /// Barely visible decoration such as separators.
+    pub subtle: Color,
+    /// Positive outcomes.
+    pub success: Color,
+    /// Warnings.
+    pub warning: Color,
+    /// Errors and validation failures.
+    pub error: Color,
+    /// Background of selected text or rows.
+    pub selection: Color,
+}
+
+/// A palette plus the semantic styles derived from it.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Name of the theme.
+    pub name: String,
+    /// The theme's colors.
+    pub palette: Palette,
+    /// Titles and headings.
+    pub title: Style,
+    /// Regular text.
+    pub text: Style,
+    /// De-emphasised text.
+    pub muted: Style,
+    /// Separators and other faint decoration.
+    pub subtle: Style,
+    /// The selected or focused item.
+    pub selected: Style,
+    /// Links and secondary highlights.
+    pub accent: Style,
+    /// Success messages.
+    pub success: Style,
+    /// Warning messages.
+    pub warning: Style,
+    /// Error messages.
+    pub error: Style,
+    /// Bordered frames around popups and panels.
+    pub frame: Style,
+}
+
+impl Theme {
+    /// Creates a theme named `name`, deriving the semantic styles from
+    /// `palette`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::theme::Theme;
+    /// use lipgloss_extras::prelude::Color;
+    ///
+    /// let mut palette = Theme::charm().palette;
+    /// palette.primary = Color::from("#00FF87");
+    /// let custom = Theme::new("mint", palette);
+    /// assert_eq!(custom.palette.primary, Color::from("#00FF87"));
+    /// ```
+    pub fn new(name: &str, palette: Palette) -> Self {
+        Self {
+            name: name.to_string(),
+            title: Style::new().bold(true).foreground(palette.primary.clone()),
+            text: Style::new().foreground(palette.foreground.clone()),
+            muted: Style::new().foreground(palette.muted.clone()),
+            subtle: Style::new().foreground(palette.subtle.clone()),
+            selected: Style::new().bold(true).foreground(palette.primary.clone()),
+            accent: Style::new().foreground(palette.accent.clone()),
+            success: Style::new().foreground(palette.success.clone()),
+            warning: Style::new().foreground(palette.warning.clone()),
+            error: Style::new().foreground(palette.error.clone()),
+            frame: Style::new()
+                .border(rounded_border())
+                .border_foreground(palette.secondary.clone()),
+            palette,
+        }
+    }
+
+    /// The pink and purple palette used by the crate's default styles.
+    pub fn charm() -> Self {
+        Self::new(
+            "charm",
+            Palette {
+                primary: Color::from("#EE6FF8"),
+                secondary: Color::from("#874BFD"),
+                accent: Color::from("#00AFFF"),
+                foreground: Color::from("#DDDDDD"),
+                background: Color::from("#1A1A1A"),
+                muted: Color::from("#777777"),
+                subtle: Color::from("#3C3C3C"),
+                success: Color::from("#04B575"),
+                warning: Color::from("#F2C94C"),
+                error: Color::from("#FF5F87"),
+                selection: Color::from("#44475A"),
+            },
+        )
+    }
+
+    /// A cool blue palette for dark terminals.
+    pub fn dark() -> Self {
+        Self::new(
+            "dark",
+            Palette {
+                primary: Color::from("#7AA2F7"),
+                secondary: Color::from("#565F89"),
+                accent: Color::from("#7DCFFF"),
+                foreground: Color::from("#C0CAF5"),
+                background: Color::from("#1A1B26"),
+                muted: Color::from("#737AA2"),
+                subtle: Color::from("#3B4261"),
+                success: Color::from("#9ECE6A"),
+                warning: Color::from("#E0AF68"),
+                error: Color::from("#F7768E"),
+                selection: Color::from("#283457"),
+            },
+        )
+    }
+
+    /// A high-contrast palette for light terminals.
+    pub fn light() -> Self {
+        Self::new(
+            "light",
+            Palette {
+                primary: Color::from("#5A56E0"),
+                secondary: Color::from("#8E8CD8"),
+                accent: Color::from("#0077B6"),
+                foreground: Color::from("#1A1A1A"),
+                background: Color::from("#FAFAFA"),
+                muted: Color::from("#6E6E6E"),
+                subtle: Color::from("#D0D0D0"),
+                success: Color::from("#2E8B57"),
+                warning: Color::from("#B7791F"),
+                error: Color::from("#D7263D"),
+                selection: Color::from("#DDE3FF"),
+            },
+        )
+    }
+
+    /// Returns [`Theme::dark`] or [`Theme::light`] to match the terminal
+    /// background.
+    pub fn adaptive() -> Self {
+        if has_dark_background() {
+            Self::dark()
+        } else {
+            Self::light()
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::charm()
+    }
+}
+
+/// Returns whether widgets assume a dark terminal background.
+pub fn has_dark_background() -> bool {
+    lipgloss::has_dark_background()
+}
+
+/// Forces adaptive colors to use their dark-background variants.
+pub fn force_dark() {
+    lipgloss::set_has_dark_background(true);
+}
+
+/// Forces adaptive colors to use their light-background variants.
+pub fn force_light() {
+    lipgloss::set_has_dark_background(false);
+}
+
+/// Returns `light` or `dark` depending on the terminal background.
+///
+/// Useful for widgets whose colors are stored as strings rather than
+/// lipgloss colors.
+pub fn adaptive<'a>(light: &'a str, dark: &'a str) -> &'a str {
+    if has_dark_background() {
+        dark
+    } else {
+        light
+    }
+}
+
+/// Widgets whose styles can be replaced from a [`Theme`] at runtime.
+pub trait Themeable {
+    /// Replaces the widget's styles with ones derived from `theme`.
+    fn apply_theme(&mut self, theme: &Theme);
+}
+
+/// Returns a copy of the current global theme.
+pub fn current() -> Theme {
+    CURRENT.read().map(|t| t.clone()).unwrap_or_default()
+}
+
+/// Sets the current global theme without restyling any widget.
+pub fn set_current(theme: Theme) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = theme;
+    }
+}
+
+/// Makes `theme` current and restyles every widget in `widgets`.
+pub fn apply(theme: &Theme, widgets: &mut [&mut dyn Themeable]) {
+    set_current(theme.clone());
+    for widget in widgets.iter_mut() {
+        widget.apply_theme(theme);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_semantic_styles_follow_palette() {
+        let theme = Theme::light();
+        assert_eq!(theme.name, "light");
+        let rendered = theme.error.render("x");
+        let expected = Style::new()
+            .foreground(theme.palette.error.clone())
+            .render("x");
+        assert_eq!(rendered, expected);
+    }
+
+    #[test]
+    fn test_apply_restyles_widgets() {
+        struct Probe(Option<String>);
+        impl Themeable for Probe {
+            fn apply_theme(&mut self, theme: &Theme) {
+                self.0 = Some(theme.name.clone());
+            }
+        }
+        let (mut a, mut b) = (Probe(None), Probe(None));
+        apply(&Theme::dark(), &mut [&mut a, &mut b]);
+        assert_eq!(a.0.as_deref(), Some("dark"));
+        assert_eq!(b.0.as_deref(), Some("dark"));
+        set_current(Theme::charm());
+    }
+
+    #[test]
+    fn test_force_dark_selects_dark_variants() {
+        force_dark();
+        assert!(has_dark_background());
+        assert_eq!(adaptive("light", "dark"), "dark");
+        assert_eq!(Theme::adaptive().name, "dark");
+    }
+}
@@ -68,7 +68,9 @@
 
 use bubbletea_rs::{tick as bubbletea_tick, Cmd, Model as BubbleTeaModel, Msg};
 use std::sync::atomic::{AtomicI64, Ordering};
-use std::time::{Duration, Instant};
+use std::time::Duration;
+// Follows tokio's clock, so ticks measure virtual time when it is paused
+use tokio::time::Instant;
 
 // Internal ID management for timer instances
 static LAST_ID: AtomicI64 = AtomicI64::new(0);
@@ -1588,6 +1590,38 @@ impl Model {
     }
 }
 
+/// Saved countdown of a timer; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Time left before the timer expires.
+    pub remaining: Duration,
+    /// Whether the timer was counting down.
+    pub running: bool,
+}
+
+/// Like a new timer, a restored one only ticks once the command from
+/// [`init`](Model::init) or [`start`](Model::start) runs. Ticks scheduled
+/// before the restore are ignored.
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            remaining: self.timeout,
+            running: self.running,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.timeout = state.remaining;
+        self.running = state.running;
+        self.start_instant = None;
+        self.last_tick = None;
+        self.tag += 1;
+    }
+}
+
 impl BubbleTeaModel for Model {
     /// Creates a new timer model with default settings for standalone use.
     ///
@@ -2066,9 +2100,9 @@ mod tests {
         };
 
         // First tick should initialize timing
-        let start_time = std::time::Instant::now();
+        let start_time = Instant::now();
         let result = timer.update(Box::new(tick_msg));
-        let end_time = std::time::Instant::now();
+        let end_time = Instant::now();
 
         assert!(result.is_some());
         assert!(timer.start_instant.is_some());
@@ -2087,8 +2121,8 @@ mod tests {
 
         // Stop the timer
         timer.running = false;
-        timer.start_instant = Some(std::time::Instant::now());
-        timer.last_tick = Some(std::time::Instant::now());
+        timer.start_instant = Some(Instant::now());
+        timer.last_tick = Some(Instant::now());
 
         // Start the timer (should reset timing)
         let start_msg = StartStopMsg {
@@ -0,0 +1,761 @@
+//! Transient toast notifications rendered as a stack in a screen corner.
+//!
+//! This module provides a notification queue. Any part of an application can
+//! raise a notification by returning one of the [`info`], [`warn`] or [`error`]
+//! commands; the toast [`Model`] picks up the resulting [`ShowMsg`], stacks the
+//! notification in the configured corner and dismisses it automatically once
+//! its display time has elapsed.
+//!
+//! # Behaviour
+//!
+//! - At most `max_visible` notifications are shown at once. Additional ones wait
+//!   in the queue and only start counting down once they become visible.
+//! - The pause key (default `ctrl+p`) freezes all countdowns, the keyboard
+//!   equivalent of hovering a notification. Pressing it again resumes them.
+//! - The dismiss key (default `ctrl+x`) removes the oldest visible notification.
+//! - With [`Model::with_slide_in`] notifications slide in from the edge of the
+//!   screen when they become visible instead of appearing at once.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::toast;
+//! use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg, KeyMsg};
+//!
+//! struct App {
+//!     toasts: toast::Model,
+//! }
+//!
+//! impl BubbleTeaModel for App {
+//!     fn init() -> (Self, Option<Cmd>) {
+//!         let toasts = toast::Model::new().with_max_visible(3);
+//!         (App { toasts }, Some(toast::info("Welcome!")))
+//!     }
+//!
+//!     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+//!         if let Some(key) = msg.downcast_ref::<KeyMsg>() {
+//!             if key.key == crossterm::event::KeyCode::Char('s') {
+//!                 return Some(toast::info("Saved"));
+//!             }
+//!         }
+//!         self.toasts.update(msg)
+//!     }
+//!
+//!     fn view(&self) -> String {
+//!         self.toasts.view()
+//!     }
+//! }
+//! ```
+//!
+//! Pushing notifications directly:
+//!
+//! ```rust
+//! use bubbletea_widgets::toast::{Level, Model};
+//!
+//! let mut toasts = Model::new();
+//! let _tick = toasts.push(Level::Error, "Connection lost");
+//! assert_eq!(toasts.len(), 1);
+//! assert!(toasts.view().contains("Connection lost"));
+//! ```
+
+use crate::anim::{self, Easing, Tween};
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{batch, tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::lipgloss;
+use lipgloss_extras::prelude::*;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Severity of a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Level {
+    /// Informational message.
+    #[default]
+    Info,
+    /// Something that may need attention.
+    Warn,
+    /// A failure.
+    Error,
+}
+
+/// Message requesting a new notification.
+///
+/// Usually produced by [`info`], [`warn`], [`error`] or [`show`]. Every toast
+/// model that receives it will display the notification.
+#[derive(Debug, Clone)]
+pub struct ShowMsg {
+    /// Severity of the notification.
+    pub level: Level,
+    /// Text to display.
+    pub text: String,
+    /// Display time; `None` uses the model's default duration.
+    pub duration: Option<Duration>,
+}
+
+/// Message driving the countdown of visible notifications.
+#[derive(Debug, Clone)]
+pub struct TickMsg {
+    /// The ID of the toast model this tick belongs to.
+    pub id: i64,
+    tag: i64,
+}
+
+/// Returns a command that raises a notification with the given level.
+pub fn show(level: Level, text: impl Into<String>) -> Cmd {
+    let text = text.into();
+    bubbletea_tick(Duration::from_nanos(1), move |_| {
+        Box::new(ShowMsg {
+            level,
+            text: text.clone(),
+            duration: None,
+        }) as Msg
+    })
+}
+
+/// Returns a command that raises an informational notification.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_widgets::toast;
+///
+/// let _cmd = toast::info("Settings saved");
+/// ```
+pub fn info(text: impl Into<String>) -> Cmd {
+    show(Level::Info, text)
+}
+
+/// Returns a command that raises a warning notification.
+pub fn warn(text: impl Into<String>) -> Cmd {
+    show(Level::Warn, text)
+}
+
+/// Returns a command that raises an error notification.
+pub fn error(text: impl Into<String>) -> Cmd {
+    show(Level::Error, text)
+}
+
+/// A single queued or visible notification.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    /// Identifier unique within the owning model.
+    pub id: u64,
+    /// Severity of the notification.
+    pub level: Level,
+    /// Text to display.
+    pub text: String,
+    remaining: Duration,
+    /// How long the notification has been sliding in.
+    shown: Duration,
+}
+
+impl Toast {
+    /// Returns the display time left before the notification is dismissed.
+    pub fn remaining(&self) -> Duration {
+        self.remaining
+    }
+}
+
+/// The screen corner notifications are stacked in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    /// Top-left corner.
+    TopLeft,
+    /// Top-right corner.
+    #[default]
+    TopRight,
+    /// Bottom-left corner.
+    BottomLeft,
+    /// Bottom-right corner.
+    BottomRight,
+}
+
+/// Key bindings for interacting with notifications.
+#[derive(Debug, Clone)]
+pub struct ToastKeyMap {
+    /// Toggles pausing of all countdowns. Default: `ctrl+p`.
+    pub pause: key::Binding,
+    /// Dismisses the oldest visible notification. Default: `ctrl+x`.
+    pub dismiss: key::Binding,
+}
+
+impl Default for ToastKeyMap {
+    fn default() -> Self {
+        Self {
+            pause: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+p"]),
+                key::with_help("ctrl+p", "pause notifications"),
+            ]),
+            dismiss: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+x"]),
+                key::with_help("ctrl+x", "dismiss notification"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for ToastKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.pause, &self.dismiss]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![vec![&self.pause, &self.dismiss]]
+    }
+}
+
+/// Visual styles for notifications.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style for informational notifications.
+    pub info: Style,
+    /// Style for warnings.
+    pub warn: Style,
+    /// Style for errors.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        let base = Style::new().border(rounded_border()).padding(0, 1, 0, 1);
+        Self {
+            info: base.clone().border_foreground(Color::from("#5A56E0")),
+            warn: base.clone().border_foreground(Color::from("#F2C94C")),
+            error: base.border_foreground(Color::from("#FF5F87")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates toast styles from a theme's accent, warning and error colors.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let base = Style::new().border(rounded_border()).padding(0, 1, 0, 1);
+        Self {
+            info: base.clone().border_foreground(p.secondary.clone()),
+            warn: base.clone().border_foreground(p.warning.clone()),
+            error: base.border_foreground(p.error.clone()),
+        }
+    }
+}
+
+impl Styles {
+    fn for_level(&self, level: Level) -> &Style {
+        match level {
+            Level::Info => &self.info,
+            Level::Warn => &self.warn,
+            Level::Error => &self.error,
+        }
+    }
+}
+
+/// The notification queue model.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    tag: i64,
+    ticking: bool,
+    paused: bool,
+    next_toast_id: u64,
+    toasts: VecDeque<Toast>,
+    width: usize,
+    height: usize,
+    /// Maximum number of notifications shown at once.
+    pub max_visible: usize,
+    /// Default display time of a notification.
+    pub duration: Duration,
+    /// Countdown resolution.
+    pub interval: Duration,
+    /// Corner the stack is anchored to.
+    pub corner: Corner,
+    /// Width of each notification box, excluding its border.
+    pub toast_width: usize,
+    /// How long a notification takes to slide in from the edge of the
+    /// screen. Zero, the default, shows it at once.
+    pub slide_in: Duration,
+    /// Key bindings used by [`Model::update`].
+    pub keymap: ToastKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+    frames: anim::Frames,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model {
+    /// Creates an empty notification queue.
+    ///
+    /// Defaults: three visible notifications, three seconds display time and
+    /// a 100ms countdown resolution in the top-right corner.
+    pub fn new() -> Self {
+        Self {
+            id: next_id(),
+            tag: 0,
+            ticking: false,
+            paused: false,
+            next_toast_id: 0,
+            toasts: VecDeque::new(),
+            width: 0,
+            height: 0,
+            max_visible: 3,
+            duration: Duration::from_secs(3),
+            interval: Duration::from_millis(100),
+            corner: Corner::default(),
+            toast_width: 30,
+            slide_in: Duration::ZERO,
+            keymap: ToastKeyMap::default(),
+            styles: Styles::default(),
+            frames: anim::Frames::new(),
+        }
+    }
+
+    /// Sets the maximum number of notifications shown at once.
+    pub fn with_max_visible(mut self, n: usize) -> Self {
+        self.max_visible = n.max(1);
+        self
+    }
+
+    /// Sets the default display time.
+    pub fn with_duration(mut self, d: Duration) -> Self {
+        self.duration = d;
+        self
+    }
+
+    /// Sets the corner the stack is anchored to.
+    pub fn with_corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Makes notifications slide in from the edge of the screen over `d`.
+    ///
+    /// The slide is animated by frame messages returned from
+    /// [`Model::push`] and [`Model::update`]; pass them back to `update`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::toast::{Level, Model};
+    /// use std::time::Duration;
+    ///
+    /// let mut toasts = Model::new().with_slide_in(Duration::from_millis(150));
+    /// let cmd = toasts.push(Level::Info, "Saved");
+    /// assert!(cmd.is_some()); // countdown and slide-in
+    /// assert!(!toasts.view().contains("Saved")); // still off screen
+    /// ```
+    pub fn with_slide_in(mut self, d: Duration) -> Self {
+        self.slide_in = d;
+        self
+    }
+
+    /// Returns the unique ID of this model.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Sets the size of the area the stack is placed in.
+    ///
+    /// With a size of zero (the default) [`Model::view`] renders only the
+    /// stack itself.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Returns all queued notifications, oldest first.
+    pub fn toasts(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+
+    /// Returns the notifications currently on screen.
+    pub fn visible(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter().take(self.max_visible)
+    }
+
+    /// Returns the number of queued notifications.
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns whether there are no notifications.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Removes all notifications.
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Returns whether countdowns are paused.
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Freezes all countdowns.
+    pub fn pause(&mut self) {
+        self.paused = true;
+        self.ticking = false;
+    }
+
+    /// Resumes countdowns, returning the tick command if notifications are
+    /// waiting.
+    pub fn resume(&mut self) -> Option<Cmd> {
+        self.paused = false;
+        self.start_ticking()
+    }
+
+    /// Queues a notification and returns the tick command if the countdown
+    /// was not already running.
+    pub fn push(&mut self, level: Level, text: impl Into<String>) -> Option<Cmd> {
+        let duration = self.duration;
+        self.push_with_duration(level, text, duration)
+    }
+
+    /// Queues a notification with a custom display time.
+    pub fn push_with_duration(
+        &mut self,
+        level: Level,
+        text: impl Into<String>,
+        duration: Duration,
+    ) -> Option<Cmd> {
+        self.next_toast_id += 1;
+        self.toasts.push_back(Toast {
+            id: self.next_toast_id,
+            level,
+            text: text.into(),
+            remaining: duration,
+            shown: Duration::ZERO,
+        });
+        let tick = self.start_ticking();
+        join(tick, self.start_sliding())
+    }
+
+    /// Removes the notification with the given ID. Returns `false` if it
+    /// does not exist.
+    pub fn dismiss(&mut self, toast_id: u64) -> bool {
+        match self.toasts.iter().position(|t| t.id == toast_id) {
+            Some(idx) => {
+                self.toasts.remove(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Handles [`ShowMsg`], [`TickMsg`], slide-in frames and the
+    /// pause/dismiss keys.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(show) = msg.downcast_ref::<ShowMsg>() {
+            let duration = show.duration.unwrap_or(self.duration);
+            return self.push_with_duration(show.level, show.text.clone(), duration);
+        }
+
+        if self.frames.accepts(&msg) {
+            let (step, slide_in) = (self.frames.interval(), self.slide_in);
+            let mut sliding = false;
+            for toast in self.toasts.iter_mut().take(self.max_visible) {
+                toast.shown = (toast.shown + step).min(slide_in);
+                sliding |= toast.shown < slide_in;
+            }
+            if !sliding {
+                self.frames.stop();
+                return None;
+            }
+            return Some(self.frames.next());
+        }
+
+        if let Some(tick) = msg.downcast_ref::<TickMsg>() {
+            if tick.id != self.id || tick.tag != self.tag || !self.ticking {
+                return None;
+            }
+            if self.paused {
+                self.ticking = false;
+                return None;
+            }
+            let interval = self.interval;
+            for toast in self.toasts.iter_mut().take(self.max_visible) {
+                toast.remaining = toast.remaining.saturating_sub(interval);
+            }
+            self.toasts.retain(|t| !t.remaining.is_zero());
+            if self.toasts.is_empty() {
+                self.ticking = false;
+                return None;
+            }
+            // Dismissed notifications may have made room for new ones
+            return join(Some(self.tick()), self.start_sliding());
+        }
+
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.is_empty() {
+                return None;
+            }
+            if self.keymap.pause.matches(key_msg) {
+                if self.paused {
+                    return self.resume();
+                }
+                self.pause();
+            } else if self.keymap.dismiss.matches(key_msg) {
+                self.toasts.pop_front();
+                return self.start_sliding();
+            }
+        }
+        None
+    }
+
+    /// Renders the visible notifications.
+    ///
+    /// When a size has been set with [`Model::set_size`] the stack is placed
+    /// in the configured corner of that area; otherwise only the stack is
+    /// returned.
+    pub fn view(&self) -> String {
+        if self.toasts.is_empty() {
+            return String::new();
+        }
+        let (h_pos, v_pos) = match self.corner {
+            Corner::TopLeft => (lipgloss::LEFT, lipgloss::TOP),
+            Corner::TopRight => (lipgloss::RIGHT, lipgloss::TOP),
+            Corner::BottomLeft => (lipgloss::LEFT, lipgloss::BOTTOM),
+            Corner::BottomRight => (lipgloss::RIGHT, lipgloss::BOTTOM),
+        };
+        let boxes: Vec<String> = self
+            .visible()
+            .map(|t| {
+                let rendered = self
+                    .styles
+                    .for_level(t.level)
+                    .clone()
+                    .width(self.toast_width as i32)
+                    .render(&format!("{} {}", icon(t.level), t.text));
+                self.slide(rendered, t)
+            })
+            .collect();
+        let refs: Vec<&str> = boxes.iter().map(String::as_str).collect();
+        let stack = lipgloss::join_vertical(h_pos, &refs);
+        if self.width == 0 || self.height == 0 {
+            return stack;
+        }
+        lipgloss::place(
+            self.width as i32,
+            self.height as i32,
+            h_pos,
+            v_pos,
+            &stack,
+            &[],
+        )
+    }
+
+    /// Cuts a notification box down to the part that has slid in so far.
+    fn slide(&self, rendered: String, toast: &Toast) -> String {
+        let fraction = Tween::new(0.0, 1.0, self.slide_in)
+            .with_easing(Easing::OutCubic)
+            .advance(toast.shown);
+        if fraction >= 1.0 {
+            return rendered;
+        }
+        let width = text::width(&rendered);
+        let cols = (width as f64 * fraction).round() as usize;
+        // The box enters from the side of the screen its corner is on.
+        let (start, end) = match self.corner {
+            Corner::TopLeft | Corner::BottomLeft => (width - cols, width),
+            Corner::TopRight | Corner::BottomRight => (0, cols),
+        };
+        rendered
+            .lines()
+            .map(|line| text::slice(line, start, end))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn start_sliding(&mut self) -> Option<Cmd> {
+        let slide_in = self.slide_in;
+        if self.visible().any(|t| t.shown < slide_in) {
+            return self.frames.start();
+        }
+        None
+    }
+
+    fn start_ticking(&mut self) -> Option<Cmd> {
+        if self.ticking || self.paused || self.toasts.is_empty() {
+            return None;
+        }
+        self.ticking = true;
+        self.tag += 1;
+        Some(self.tick())
+    }
+
+    fn tick(&self) -> Cmd {
+        let id = self.id;
+        let tag = self.tag;
+        bubbletea_tick(self.interval, move |_| Box::new(TickMsg { id, tag }) as Msg)
+    }
+}
+
+fn join(a: Option<Cmd>, b: Option<Cmd>) -> Option<Cmd> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(batch(vec![a, b])),
+        (a, b) => a.or(b),
+    }
+}
+
+fn icon(level: Level) -> &'static str {
+    match level {
+        Level::Info => "ℹ",
+        Level::Warn => "⚠",
+        Level::Error => "✖",
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn tick_msg(m: &Model) -> Msg {
+        Box::new(TickMsg {
+            id: m.id,
+            tag: m.tag,
+        })
+    }
+
+    #[test]
+    fn test_show_msg_queues_and_starts_ticking() {
+        let mut m = Model::new();
+        let cmd = m.update(Box::new(ShowMsg {
+            level: Level::Warn,
+            text: "disk almost full".into(),
+            duration: None,
+        }));
+        assert!(cmd.is_some());
+        assert_eq!(m.len(), 1);
+        // A second toast does not start another tick loop.
+        assert!(m.push(Level::Info, "second").is_none());
+    }
+
+    #[test]
+    fn test_auto_dismiss_after_duration() {
+        let mut m = Model::new().with_duration(Duration::from_millis(200));
+        m.push(Level::Info, "hello");
+        assert!(m.update(tick_msg(&m)).is_some());
+        assert_eq!(m.len(), 1);
+        assert!(m.update(tick_msg(&m)).is_none());
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_only_visible_toasts_count_down() {
+        let mut m = Model::new()
+            .with_max_visible(1)
+            .with_duration(Duration::from_millis(100));
+        m.push(Level::Info, "first");
+        m.push(Level::Info, "second");
+        assert_eq!(m.visible().count(), 1);
+        m.update(tick_msg(&m));
+        assert_eq!(m.len(), 1);
+        assert_eq!(m.toasts().next().unwrap().text, "second");
+        assert_eq!(
+            m.toasts().next().unwrap().remaining(),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn test_pause_key_freezes_countdown() {
+        let mut m = Model::new().with_duration(Duration::from_millis(100));
+        m.push(Level::Error, "boom");
+        let pause = || -> Msg {
+            Box::new(KeyMsg {
+                key: KeyCode::Char('p'),
+                modifiers: KeyModifiers::CONTROL,
+            })
+        };
+        assert!(m.update(pause()).is_none());
+        assert!(m.paused());
+        assert!(m.update(tick_msg(&m)).is_none());
+        assert_eq!(m.len(), 1);
+        assert!(m.update(pause()).is_some(), "resuming restarts ticking");
+        m.update(tick_msg(&m));
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_view_places_stack_in_corner() {
+        let mut m = Model::new().with_corner(Corner::BottomLeft);
+        m.push(Level::Info, "hi");
+        m.set_size(60, 10);
+        let view = m.view();
+        assert_eq!(view.lines().count(), 10);
+        let last = lipgloss::strip_ansi(view.lines().last().unwrap());
+        assert!(last.starts_with('╰'));
+    }
+
+    #[test]
+    fn test_slide_in_reveals_box_from_screen_edge() {
+        let mut m = Model::new().with_slide_in(Duration::from_millis(100));
+        assert!(m.push(Level::Info, "hello").is_some());
+        let full = m.toast_width + 2;
+        let width = |m: &Model| text::width(&m.view());
+        assert_eq!(width(&m), 0);
+
+        let frame = |m: &Model| -> Msg {
+            Box::new(anim::FrameMsg {
+                id: m.frames.id(),
+                tag: m.frames.tag(),
+            })
+        };
+        m.update(frame(&m));
+        // The right-hand corner reveals the left edge of the box first
+        let partial = lipgloss::strip_ansi(m.view().lines().next().unwrap());
+        assert!(
+            partial.starts_with('╭') && !partial.ends_with('╮'),
+            "{partial}"
+        );
+
+        let mut widths = vec![width(&m)];
+        while m.update(frame(&m)).is_some() {
+            widths.push(width(&m));
+        }
+        assert!(widths.len() > 2, "{widths:?}");
+        assert!(widths.windows(2).all(|w| w[0] <= w[1]), "{widths:?}");
+        assert!(widths[0] > 0 && widths[0] < full, "{widths:?}");
+        assert_eq!(width(&m), full);
+
+        // Without slide-in a notification appears at once
+        let mut m = Model::new();
+        m.push(Level::Info, "hello");
+        assert_eq!(width(&m), full);
+    }
+}
@@ -0,0 +1,345 @@
+//! On/off switch.
+//!
+//! A toggle renders a small switch such as `Wi-Fi  ● OFF ` / `Wi-Fi  ON ● `
+//! and flips it with `space` or `enter`, emitting a [`ToggleMsg`]. It
+//! implements [`Component`](crate::Component) so a settings screen can move
+//! focus between several toggles; blurred toggles ignore input and render
+//! their label dimmed.
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `space`, `enter` | Flip the switch |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::toggle;
+//!
+//! let mut wifi = toggle::new("Wi-Fi").with_labels("yes", "no");
+//! assert!(!wifi.on());
+//! wifi.toggle();
+//! assert!(wifi.on());
+//! assert!(wifi.view().contains("yes"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted when the switch is flipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToggleMsg {
+    /// The ID of the toggle that changed.
+    pub id: i64,
+    /// The new state.
+    pub on: bool,
+}
+
+/// Key bindings for the toggle.
+#[derive(Debug, Clone)]
+pub struct ToggleKeyMap {
+    /// Flips the switch. Default: `space`, `enter`.
+    pub toggle: key::Binding,
+}
+
+impl Default for ToggleKeyMap {
+    fn default() -> Self {
+        Self {
+            toggle: key::new_binding(vec![
+                key::with_keys_str(&["space", "enter"]),
+                key::with_help("space", "toggle"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for ToggleKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.toggle]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![vec![&self.toggle]]
+    }
+}
+
+/// Visual styles for the toggle.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the switch while on.
+    pub on: Style,
+    /// Style of the switch while off.
+    pub off: Style,
+    /// Style of the label while focused.
+    pub label: Style,
+    /// Style of the label while blurred.
+    pub blurred_label: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            on: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#04B575")),
+            off: Style::new()
+                .foreground(Color::from("#FFF7DB"))
+                .background(Color::from("#626262")),
+            label: Style::new()
+                .foreground(AdaptiveColor {
+                    Light: "#5A56E0",
+                    Dark: "#EE6FF8",
+                })
+                .bold(true),
+            blurred_label: Style::new(),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates toggle styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let p = &theme.palette;
+        let track = Style::new().foreground(p.background.clone());
+        Self {
+            on: track.clone().background(p.success.clone()),
+            off: track.background(p.muted.clone()),
+            label: theme.selected.clone(),
+            blurred_label: theme.text.clone(),
+        }
+    }
+}
+
+/// An on/off switch.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    on: bool,
+    focus: bool,
+    /// Text shown before the switch; empty for none.
+    pub label: String,
+    /// Text inside the switch while on.
+    pub on_label: String,
+    /// Text inside the switch while off.
+    pub off_label: String,
+    /// Key bindings.
+    pub keymap: ToggleKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a focused toggle that starts off.
+pub fn new(label: &str) -> Model {
+    Model::new(label)
+}
+
+impl Model {
+    /// Creates a focused toggle that starts off.
+    pub fn new(label: &str) -> Self {
+        Self {
+            id: next_id(),
+            on: false,
+            focus: true,
+            label: label.to_string(),
+            on_label: "ON".to_string(),
+            off_label: "OFF".to_string(),
+            keymap: ToggleKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets the text shown inside the switch for each state.
+    pub fn with_labels(mut self, on: &str, off: &str) -> Self {
+        self.on_label = on.to_string();
+        self.off_label = off.to_string();
+        self
+    }
+
+    /// Sets the initial state.
+    pub fn with_on(mut self, on: bool) -> Self {
+        self.on = on;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this toggle.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns whether the switch is on.
+    pub fn on(&self) -> bool {
+        self.on
+    }
+
+    /// Sets the state without emitting a message.
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+    }
+
+    /// Flips the switch.
+    pub fn toggle(&mut self) {
+        self.on = !self.on;
+    }
+
+    /// Flips the switch on `space`/`enter` and returns a [`ToggleMsg`]
+    /// command. Blurred toggles ignore input.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if !self.focus {
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if !self.keymap.toggle.matches(key_msg) {
+            return None;
+        }
+        self.toggle();
+        let (id, on) = (self.id, self.on);
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(ToggleMsg { id, on }) as Msg
+        }))
+    }
+
+    /// Renders the label followed by the switch, or the
+    /// [`accessible_view`](Self::accessible_view) in accessible mode.
+    pub fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
+        let switch = if self.on {
+            self.styles.on.render(&format!(" {} ● ", self.on_label))
+        } else {
+            self.styles.off.render(&format!(" ● {} ", self.off_label))
+        };
+        if self.label.is_empty() {
+            return switch;
+        }
+        let label = if self.focus {
+            self.styles.label.render(&self.label)
+        } else {
+            self.styles.blurred_label.render(&self.label)
+        };
+        format!("{}  {}", label, switch)
+    }
+
+    /// Renders the state as plain text, such as `Dark mode: on`.
+    pub fn accessible_view(&self) -> String {
+        let state = if self.on { "on" } else { "off" };
+        if self.label.is_empty() {
+            state.to_string()
+        } else {
+            format!("{}: {}", self.label, state)
+        }
+    }
+}
+
+impl crate::Component for Model {
+    fn focus(&mut self) -> Option<Cmd> {
+        self.focus = true;
+        None
+    }
+
+    fn blur(&mut self) {
+        self.focus = false;
+    }
+
+    fn focused(&self) -> bool {
+        self.focus
+    }
+}
+
+/// Saved position of a toggle; see [`Stateful`](crate::state::Stateful).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Whether the toggle is on.
+    pub on: bool,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State { on: self.on }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.set_on(state.on);
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(""), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Component;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    fn key(code: KeyCode) -> Msg {
+        Box::new(KeyMsg {
+            key: code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    #[test]
+    fn test_space_and_enter_flip() {
+        let mut t = Model::new("Sound");
+        assert!(t.update(key(KeyCode::Char(' '))).is_some());
+        assert!(t.on());
+        t.update(key(KeyCode::Enter));
+        assert!(!t.on());
+        assert!(t.update(key(KeyCode::Char('x'))).is_none());
+    }
+
+    #[test]
+    fn test_blurred_ignores_input() {
+        let mut t = Model::new("Sound").with_on(true);
+        t.blur();
+        assert!(t.update(key(KeyCode::Enter)).is_none());
+        assert!(t.on());
+    }
+
+    #[test]
+    fn test_view_uses_labels() {
+        let mut t = Model::new("Dark mode").with_labels("yes", "no");
+        assert_eq!(strip_ansi(&t.view()), "Dark mode   ● no ");
+        t.toggle();
+        assert_eq!(strip_ansi(&t.view()), "Dark mode   yes ● ");
+    }
+}
@@ -0,0 +1,265 @@
+//! Tooltips over an existing view.
+//!
+//! A tooltip is a small bordered hint, such as a validation error next to a
+//! form field, drawn on top of an already rendered view. Give
+//! [`Model::overlay`] the view string and the cell the hint is about; the
+//! box is placed beside that anchor according to its [`Placement`], flipped
+//! to the other side when it would not fit, and clamped to the edges of the
+//! view. Long text is word-wrapped to `max_width` and may contain newlines.
+//!
+//! The box is composited with [`compose::overlay`], so the underlying view
+//! keeps its styling; only the cells under the box are replaced.
+//!
+//! Tooltips hold no timers. Show one for as long as the cue is relevant,
+//! for example while the field it points at is focused and invalid.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::tooltip;
+//! use lipgloss_extras::lipgloss::strip_ansi;
+//!
+//! let form = format!("{:<30}\n{:<30}\n\n\n", "Name:  ______", "Email: ______");
+//! let hint = tooltip::new("required");
+//! let out = strip_ansi(&hint.overlay(&form, 7, 0));
+//! let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+//! assert_eq!(lines[1], "Email: ╭──────────╮");
+//! assert_eq!(lines[2], "       │ required │");
+//! ```
+
+use crate::compose;
+use crate::text::wrap;
+use crate::theme::Theme;
+use bubbletea_rs::{Cmd, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+
+/// Where the tooltip goes relative to its anchor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Above the anchor, flipping below when there is no room.
+    Above,
+    /// Below the anchor, flipping above when there is no room.
+    #[default]
+    Below,
+    /// Left of the anchor, flipping right when there is no room.
+    Left,
+    /// Right of the anchor, flipping left when there is no room.
+    Right,
+}
+
+/// Visual styles for the tooltip.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// The box around the text, including its border and padding.
+    pub container: Style,
+    /// The text.
+    pub text: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            container: Style::new()
+                .border(rounded_border())
+                .border_foreground(AdaptiveColor {
+                    Light: "#909090",
+                    Dark: "#626262",
+                })
+                .padding(0, 1, 0, 1),
+            text: Style::new().foreground(AdaptiveColor {
+                Light: "#1A1A1A",
+                Dark: "#DDDDDD",
+            }),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates tooltip styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            container: Style::new()
+                .border(rounded_border())
+                .border_foreground(theme.palette.subtle.clone())
+                .padding(0, 1, 0, 1),
+            text: theme.text.clone(),
+        }
+    }
+}
+
+/// A hint box drawn over another view.
+#[derive(Debug, Clone)]
+pub struct Model {
+    /// The hint; may contain newlines.
+    pub text: String,
+    /// Where the box goes relative to the anchor.
+    pub placement: Placement,
+    /// Widest the text may be before it wraps.
+    pub max_width: usize,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a tooltip placed below its anchor.
+pub fn new(text: &str) -> Model {
+    Model::new(text)
+}
+
+impl Model {
+    /// Creates a tooltip placed below its anchor.
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            placement: Placement::Below,
+            max_width: 40,
+            styles: Styles::default(),
+        }
+    }
+
+    /// Sets where the box goes relative to the anchor.
+    pub fn with_placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Sets the widest the text may be before it wraps.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Sets the hint.
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.to_string();
+    }
+
+    /// Returns the top-left cell of the box for an anchor at `(x, y)` in a
+    /// view `view_width` x `view_height` cells large.
+    pub fn position(
+        &self,
+        x: usize,
+        y: usize,
+        view_width: usize,
+        view_height: usize,
+    ) -> (usize, usize) {
+        let (w, h) = compose::size(&self.view());
+        let fits_above = y >= h;
+        let fits_below = y + 1 + h <= view_height;
+        let fits_left = x >= w;
+        let fits_right = x + 1 + w <= view_width;
+        let placement = match self.placement {
+            Placement::Above if !fits_above => Placement::Below,
+            Placement::Below if !fits_below && fits_above => Placement::Above,
+            Placement::Left if !fits_left => Placement::Right,
+            Placement::Right if !fits_right && fits_left => Placement::Left,
+            p => p,
+        };
+        match placement {
+            Placement::Above => (x.min(view_width.saturating_sub(w)), y - h),
+            Placement::Below => (x.min(view_width.saturating_sub(w)), y + 1),
+            Placement::Left => (x - w, y.min(view_height.saturating_sub(h))),
+            Placement::Right => (x + 1, y.min(view_height.saturating_sub(h))),
+        }
+    }
+
+    /// Draws the tooltip over `view`, pointing at the cell `(x, y)`.
+    /// Returns the view unchanged when the text is empty.
+    pub fn overlay(&self, view: &str, x: usize, y: usize) -> String {
+        if self.text.is_empty() {
+            return view.to_string();
+        }
+        let (view_width, view_height) = compose::size(view);
+        let (bx, by) = self.position(x, y, view_width, view_height);
+        compose::overlay(view, &self.view(), bx, by)
+    }
+
+    /// Tooltips are positioned by [`overlay`](Self::overlay) and ignore
+    /// messages.
+    pub fn update(&mut self, _msg: Msg) -> Option<Cmd> {
+        None
+    }
+
+    /// Renders the box on its own.
+    pub fn view(&self) -> String {
+        let text = wrap(&self.text, self.max_width.max(1))
+            .iter()
+            .map(|line| self.styles.text.render(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.styles.container.render(&text)
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(""), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lipgloss_extras::lipgloss::renderer::{self, ColorProfileKind};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    #[test]
+    fn test_flips_and_clamps_at_edges() {
+        let tip = Model::new("hi");
+        // The box is 6 x 3.
+        assert_eq!(tip.position(2, 1, 20, 10), (2, 2));
+        assert_eq!(tip.position(2, 8, 20, 10), (2, 5));
+        assert_eq!(tip.position(18, 1, 20, 10), (14, 2));
+        let right = tip.clone().with_placement(Placement::Right);
+        assert_eq!(right.position(2, 9, 20, 10), (3, 7));
+        assert_eq!(right.position(17, 0, 20, 10), (11, 0));
+        let above = tip.with_placement(Placement::Above);
+        assert_eq!(above.position(0, 1, 20, 10), (0, 2));
+    }
+
+    #[test]
+    fn test_multi_line_text_wraps() {
+        let tip = Model::new("one two three\nfour").with_max_width(7);
+        assert_eq!(
+            strip_ansi(&tip.view()),
+            [
+                "╭─────────╮",
+                "│ one two │",
+                "│ three   │",
+                "│ four    │",
+                "╰─────────╯",
+            ]
+            .join("\n")
+        );
+        assert_eq!(tip.overlay("x", 0, 0).lines().count(), 6);
+        assert_eq!(Model::new("").overlay("x", 0, 0), "x");
+    }
+
+    #[test]
+    fn test_default_colors_in_dark_mode() {
+        renderer::set_color_profile(ColorProfileKind::TrueColor);
+        crate::theme::force_dark();
+        let view = Model::new("hi").view();
+        assert!(view.contains("38;2;221;221;221"), "{view:?}");
+        assert!(view.contains("38;2;98;98;98"), "{view:?}");
+    }
+}
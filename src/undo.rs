@@ -0,0 +1,115 @@
+//! Undo and redo history shared by the text inputs.
+//!
+//! Each undo step is a snapshot of the text and cursor taken before the
+//! step's first edit; the widget decides what a snapshot holds. Edits of
+//! the same kind made in quick succession extend the current step instead
+//! of starting a new one, so typing a word or holding backspace is undone
+//! in one go.
+
+use std::time::{Duration, Instant};
+
+/// Edits further apart than this start a new undo step.
+const PAUSE: Duration = Duration::from_secs(1);
+
+/// The most undo steps kept; older ones are dropped.
+const LIMIT: usize = 100;
+
+/// What an edit did, for deciding which edits share an undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EditKind {
+    /// Typed a character that is part of a word.
+    Word,
+    /// Typed whitespace or a newline.
+    Space,
+    /// Deleted text.
+    Delete,
+    /// Anything else, such as a paste or a case change. Always a step of
+    /// its own.
+    Other,
+}
+
+impl EditKind {
+    /// Whether an edit of kind `next` extends a step that ended with an
+    /// edit of this kind. Whitespace extends the word before it, but the
+    /// next word starts a new step.
+    fn continued_by(self, next: EditKind) -> bool {
+        matches!(
+            (self, next),
+            (EditKind::Word, EditKind::Word | EditKind::Space)
+                | (EditKind::Space, EditKind::Space)
+                | (EditKind::Delete, EditKind::Delete)
+        )
+    }
+}
+
+/// Undo and redo stacks of snapshots.
+#[derive(Debug, Clone)]
+pub(crate) struct History<S> {
+    undo: Vec<S>,
+    redo: Vec<S>,
+    /// Kind and time of the last edit, while its step can still grow.
+    last: Option<(EditKind, Instant)>,
+}
+
+impl<S> Default for History<S> {
+    fn default() -> Self {
+        Self {
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last: None,
+        }
+    }
+}
+
+impl<S> History<S> {
+    /// Records an edit of `kind` made at `now`, given the state from just
+    /// before it. Starts a new undo step unless the edit extends the
+    /// current one, and forgets everything that could be redone.
+    pub fn record(&mut self, kind: EditKind, before: S, now: Instant) {
+        let extends = self.last.is_some_and(|(last, at)| {
+            now.saturating_duration_since(at) < PAUSE && last.continued_by(kind)
+        });
+        if !extends {
+            self.undo.push(before);
+            if self.undo.len() > LIMIT {
+                self.undo.remove(0);
+            }
+        }
+        self.redo.clear();
+        self.last = Some((kind, now));
+    }
+
+    /// Ends the current undo step, so the next edit starts a new one.
+    pub fn break_step(&mut self) {
+        self.last = None;
+    }
+
+    /// Returns the state to go back to, keeping `current` for redo.
+    pub fn undo(&mut self, current: S) -> Option<S> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.last = None;
+        Some(previous)
+    }
+
+    /// Returns the state an undo left, keeping `current` for undo.
+    pub fn redo(&mut self, current: S) -> Option<S> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last = None;
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets every step.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -142,15 +142,19 @@
 //! - `line_count()`: Total content lines
 //! - `visible_lines()`: Currently displayed content
 
+use crate::anim::{self, Spring};
 use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::widget::Margins;
 use bubbletea_rs::{Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
 use crossterm::event::KeyCode;
-use lipgloss_extras::lipgloss::width as lg_width;
 use lipgloss_extras::prelude::*;
-use unicode_width::UnicodeWidthChar;
 
 const SPACEBAR: char = ' ';
 
+/// Angular frequency of the smooth scroll spring; see [`anim::Spring`].
+const SCROLL_FREQUENCY: f64 = 18.0;
+
 /// Keyboard binding configuration for viewport navigation.
 ///
 /// This struct defines all key combinations that control viewport scrolling,
@@ -520,8 +524,8 @@ pub struct Model {
     /// Whether mouse wheel scrolling is enabled.
     ///
     /// When `true`, mouse wheel events will scroll the viewport content.
-    /// Note: Actual mouse wheel support depends on the terminal and
-    /// bubbletea-rs mouse event capabilities.
+    /// Wheel events are only handled with the `mouse` feature enabled and
+    /// mouse reporting turned on for the program.
     pub mouse_wheel_enabled: bool,
     /// Number of lines to scroll per mouse wheel event.
     ///
@@ -571,6 +575,23 @@ pub struct Model {
     /// Tracks initialization state to ensure proper configuration.
     /// Set automatically during construction and configuration.
     initialized: bool,
+    /// Margins kept when following the terminal size, if enabled.
+    ///
+    /// Set through [`auto_resize`](Self::auto_resize).
+    resize_margins: Option<Margins>,
+    /// Whether keyboard scrolling glides to the new offset.
+    ///
+    /// Set through [`with_smooth_scroll`](Self::with_smooth_scroll).
+    smooth_scroll: bool,
+    /// Top line drawn and its velocity while a smooth scroll is under way.
+    scroll_motion: Option<(f64, f64)>,
+    /// Spring moving the drawn top line toward `y_offset`.
+    scroll_spring: Spring,
+    /// Frame clock of the smooth scroll.
+    frames: anim::Frames,
+    /// Mouse click and drag state.
+    #[cfg(feature = "mouse")]
+    mouse: crate::mouse::Tracker,
 }
 
 impl Model {
@@ -643,6 +664,13 @@ impl Model {
             lines: Vec::new(),
             longest_line_width: 0,
             initialized: false,
+            resize_margins: None,
+            smooth_scroll: false,
+            scroll_motion: None,
+            scroll_spring: Spring::new(anim::fps(anim::FPS), SCROLL_FREQUENCY, 1.0),
+            frames: anim::Frames::new(),
+            #[cfg(feature = "mouse")]
+            mouse: crate::mouse::Tracker::new(),
         };
         model.set_initial_values();
         model
@@ -780,6 +808,119 @@ impl Model {
         self
     }
 
+    /// Builder method to make the viewport follow the terminal size.
+    ///
+    /// Every `WindowSizeMsg` then sets the dimensions to the terminal size
+    /// minus `margins`. The scroll position is clamped so the last page
+    /// stays full after the viewport grows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::viewport::Model;
+    /// use bubbletea_widgets::widget::Margins;
+    /// use bubbletea_rs::{Model as BubbleTeaModel, WindowSizeMsg};
+    ///
+    /// // Leave one line for a header and one for a footer
+    /// let mut viewport = Model::new(0, 0).auto_resize(Margins::symmetric(1, 0));
+    /// viewport.update(Box::new(WindowSizeMsg { width: 100, height: 30 }));
+    ///
+    /// assert_eq!(viewport.width, 100);
+    /// assert_eq!(viewport.height, 28);
+    /// ```
+    pub fn auto_resize(mut self, margins: Margins) -> Self {
+        self.resize_margins = Some(margins);
+        self
+    }
+
+    /// Builder method to make keyboard scrolling glide to the new position
+    /// instead of jumping there.
+    ///
+    /// The offsets change at once as usual; only the drawing catches up,
+    /// driven by a spring. While it does, `update` returns frame commands
+    /// whose messages must be passed back to `update`. Mouse scrolling and
+    /// the scroll methods called directly stay immediate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::viewport::Model;
+    /// use bubbletea_rs::{KeyMsg, Model as BubbleTeaModel};
+    /// use crossterm::event::{KeyCode, KeyModifiers};
+    ///
+    /// let mut viewport = Model::new(20, 5).with_smooth_scroll(true);
+    /// viewport.set_content(&(1..=50).map(|i| format!("Line {}", i)).collect::<Vec<_>>().join("\n"));
+    ///
+    /// let cmd = viewport.update(Box::new(KeyMsg {
+    ///     key: KeyCode::PageDown,
+    ///     modifiers: KeyModifiers::NONE,
+    /// }));
+    /// assert!(cmd.is_some()); // the first animation frame
+    /// assert_eq!(viewport.y_offset, 5);
+    /// assert!(viewport.is_scrolling());
+    /// assert_eq!(viewport.visible_lines()[0], "Line 1"); // not moved yet
+    /// ```
+    pub fn with_smooth_scroll(mut self, smooth: bool) -> Self {
+        self.set_smooth_scroll(smooth);
+        self
+    }
+
+    /// Turns smooth scrolling on or off (mutable version).
+    ///
+    /// See [`with_smooth_scroll`](Self::with_smooth_scroll). Turning it off
+    /// ends a glide under way at its destination.
+    pub fn set_smooth_scroll(&mut self, smooth: bool) {
+        self.smooth_scroll = smooth;
+        if !smooth {
+            self.stop_scrolling();
+        }
+    }
+
+    /// Returns whether a smooth scroll is still moving the content.
+    pub fn is_scrolling(&self) -> bool {
+        self.scroll_motion.is_some()
+    }
+
+    /// Builder method to set the screen cell where the viewport is drawn.
+    ///
+    /// Dragging the content with the left button needs this to know whether
+    /// a press landed on the viewport. Once it is set, the wheel also only
+    /// scrolls while the pointer is over the viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bubbletea_widgets::viewport::Model;
+    /// use bubbletea_rs::{Model as BubbleTeaModel, MouseMsg, Msg};
+    /// use crossterm::event::{KeyModifiers, MouseButton, MouseEventKind};
+    ///
+    /// let mut viewport = Model::new(20, 5).with_mouse_origin(0, 1);
+    /// viewport.set_content(&(1..=20).map(|i| format!("Line {}", i)).collect::<Vec<_>>().join("\n"));
+    ///
+    /// // Grab the content at row 4 and pull it up two rows
+    /// for (kind, y) in [
+    ///     (MouseEventKind::Down(MouseButton::Left), 4),
+    ///     (MouseEventKind::Drag(MouseButton::Left), 2),
+    ///     (MouseEventKind::Up(MouseButton::Left), 2),
+    /// ] {
+    ///     viewport.update(Box::new(MouseMsg { x: 3, y, button: kind, modifiers: KeyModifiers::NONE }));
+    /// }
+    /// assert_eq!(viewport.y_offset, 2);
+    /// ```
+    #[cfg(feature = "mouse")]
+    pub fn with_mouse_origin(mut self, x: u16, y: u16) -> Self {
+        self.set_mouse_origin(x, y);
+        self
+    }
+
+    /// Sets the screen cell where the viewport is drawn (mutable version).
+    ///
+    /// See [`with_mouse_origin`](Self::with_mouse_origin).
+    #[cfg(feature = "mouse")]
+    pub fn set_mouse_origin(&mut self, x: u16, y: u16) {
+        self.mouse.set_origin(x, y);
+    }
+
     /// Returns whether the viewport is scrolled to the very top of the content.
     ///
     /// This method checks if the vertical scroll position is at the beginning,
@@ -1337,8 +1478,8 @@ impl Model {
 
         let mut lines = Vec::new();
         if !self.lines.is_empty() {
-            let top = self.y_offset;
-            let bottom = (self.y_offset + h).min(self.lines.len());
+            let bottom = (self.shown_y_offset() + h).min(self.lines.len());
+            let top = self.shown_y_offset().min(bottom);
             lines = self.lines[top..bottom].to_vec();
         }
 
@@ -1349,7 +1490,7 @@ impl Model {
 
         let mut cut_lines = Vec::new();
         for line in lines {
-            let cut_line = cut_string(&line, self.x_offset, self.x_offset + w);
+            let cut_line = text::slice(&line, self.x_offset, self.x_offset + w);
             cut_lines.push(cut_line);
         }
         cut_lines
@@ -1985,6 +2126,78 @@ impl Model {
     }
 
     /// Get the maximum Y offset
+    /// Scrolls by `dx` columns and `dy` lines; negative values go left or up.
+    #[cfg(feature = "mouse")]
+    fn scroll_by(&mut self, dx: i32, dy: i32) {
+        let max_x = self.longest_line_width.saturating_sub(self.width);
+        self.x_offset = self.x_offset.saturating_add_signed(dx as isize).min(max_x);
+        if dy < 0 {
+            self.scroll_up(dy.unsigned_abs() as usize);
+        } else if dy > 0 {
+            self.scroll_down(dy as usize);
+        }
+    }
+
+    #[cfg(feature = "mouse")]
+    fn handle_mouse(&mut self, msg: &Msg) {
+        use crate::mouse::Gesture;
+        match self.mouse.handle(msg, self.width, self.height) {
+            Some(Gesture::Wheel { dx, dy }) if self.mouse_wheel_enabled => {
+                let delta = self.mouse_wheel_delta as i32;
+                self.scroll_by(dx * self.horizontal_step as i32, dy * delta);
+            }
+            // The content follows the pointer, like dragging a page
+            Some(Gesture::Drag { dx, dy, .. }) => self.scroll_by(-dx, -dy),
+            _ => {}
+        }
+    }
+
+    /// Starts gliding from the drawn line `from` toward `y_offset`, keeping
+    /// the velocity of a glide already under way.
+    fn glide_from(&mut self, from: f64) -> Option<Cmd> {
+        let velocity = self.scroll_motion.map_or(0.0, |(_, velocity)| velocity);
+        self.scroll_motion = Some((from, velocity));
+        // A running clock carries on toward the new offset.
+        self.frames.start()
+    }
+
+    /// Advances a smooth scroll by one frame.
+    fn scroll_frame(&mut self) -> Option<Cmd> {
+        let Some((pos, velocity)) = self.scroll_motion else {
+            self.frames.stop();
+            return None;
+        };
+        let target = self.y_offset as f64;
+        let (pos, velocity) = self.scroll_spring.update(pos, velocity, target);
+        // Within half a line the glide is drawn at its target already
+        if (pos - target).abs() < 0.5 && velocity.abs() < 1.0 {
+            self.stop_scrolling();
+            return None;
+        }
+        self.scroll_motion = Some((pos, velocity));
+        Some(self.frames.next())
+    }
+
+    fn stop_scrolling(&mut self) {
+        self.scroll_motion = None;
+        if self.frames.is_running() {
+            self.frames.stop();
+        }
+    }
+
+    /// The top line drawn: `y_offset`, or where a smooth scroll has got to.
+    fn shown_y_offset(&self) -> usize {
+        match self.scroll_motion {
+            Some((pos, _)) => (pos.round().max(0.0) as usize).min(self.max_y_offset()),
+            None => self.y_offset,
+        }
+    }
+
+    fn shown_y_offset_f64(&self) -> f64 {
+        self.scroll_motion
+            .map_or(self.y_offset as f64, |(pos, _)| pos)
+    }
+
     fn max_y_offset(&self) -> usize {
         let frame_size = self.style.get_vertical_frame_size();
         self.lines
@@ -2077,6 +2290,25 @@ impl Model {
     pub fn line_count(&self) -> usize {
         self.lines.len()
     }
+
+    /// Renders the visible lines as plain text and, when the content does
+    /// not fit, which lines are showing, such as `lines 11 to 20 of 100`.
+    pub fn accessible_view(&self) -> String {
+        let visible = self.visible_lines();
+        let mut out = crate::a11y::plain(&visible.join("\n"));
+        if self.lines.len() > visible.len() && !visible.is_empty() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!(
+                "lines {} to {} of {}",
+                self.y_offset + 1,
+                self.y_offset + visible.len(),
+                self.lines.len()
+            ));
+        }
+        out
+    }
 }
 
 impl Default for Model {
@@ -2110,6 +2342,56 @@ impl Default for Model {
     }
 }
 
+/// Searches the content lines, ignoring styling. Going to a hit scrolls it
+/// to the middle of the viewport unless it is already visible.
+impl crate::search::Searchable for Model {
+    fn matches(&self, query: &str) -> Vec<crate::search::Hit> {
+        self.lines
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                crate::search::hits_in(&lipgloss_extras::lipgloss::strip_ansi(line), query, row, 0)
+            })
+            .collect()
+    }
+
+    fn goto(&mut self, hit: crate::search::Hit) {
+        if hit.row < self.y_offset || hit.row >= self.y_offset + self.height {
+            self.set_y_offset(hit.row.saturating_sub(self.height / 2));
+        }
+    }
+}
+
+/// Saved scroll position of a viewport; see
+/// [`Stateful`](crate::state::Stateful). The content is not included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct State {
+    /// Lines scrolled from the top.
+    pub y_offset: usize,
+    /// Columns scrolled from the left.
+    pub x_offset: usize,
+}
+
+impl crate::state::Stateful for Model {
+    type State = State;
+
+    fn state(&self) -> State {
+        State {
+            y_offset: self.y_offset,
+            x_offset: self.x_offset,
+        }
+    }
+
+    fn restore(&mut self, state: State) {
+        self.stop_scrolling();
+        self.set_y_offset(state.y_offset);
+        self.x_offset = state
+            .x_offset
+            .min(self.longest_line_width.saturating_sub(self.width));
+    }
+}
+
 impl BubbleTeaModel for Model {
     /// Initializes a new viewport instance for Bubble Tea applications.
     ///
@@ -2150,7 +2432,8 @@ impl BubbleTeaModel for Model {
     ///
     /// # Returns
     ///
-    /// Always returns `None` as viewport operations don't generate commands
+    /// `None`, except for the animation frames of a
+    /// [smooth scroll](Self::with_smooth_scroll)
     ///
     /// # Supported Key Bindings
     ///
@@ -2206,7 +2489,18 @@ impl BubbleTeaModel for Model {
     /// }
     /// ```
     fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some((width, height)) = crate::widget::resized(&msg, self.resize_margins) {
+            self.width = width;
+            self.height = height;
+            self.set_y_offset(self.y_offset);
+            self.stop_scrolling();
+            return None;
+        }
+        if self.frames.accepts(&msg) {
+            return self.scroll_frame();
+        }
         if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            let (before, from) = (self.y_offset, self.shown_y_offset_f64());
             if self.keymap.page_down.matches(key_msg) {
                 self.page_down();
             } else if self.keymap.page_up.matches(key_msg) {
@@ -2224,9 +2518,19 @@ impl BubbleTeaModel for Model {
             } else if self.keymap.right.matches(key_msg) {
                 self.scroll_right();
             }
+            if self.smooth_scroll && self.y_offset != before {
+                return self.glide_from(from);
+            }
+        }
+        #[cfg(feature = "mouse")]
+        {
+            let before = self.y_offset;
+            self.handle_mouse(&msg);
+            // The content follows the mouse directly
+            if self.y_offset != before {
+                self.stop_scrolling();
+            }
         }
-        // Mouse wheel basic support if MouseMsg is available in bubbletea-rs
-        // Note: bubbletea-rs MouseMsg does not currently expose wheel events in this crate version.
         None
     }
 
@@ -2281,7 +2585,11 @@ impl BubbleTeaModel for Model {
     /// - **Style Application**: Applied lipgloss styles are rendered into the output
     /// - **Line Joining**: Multiple lines are joined with newline characters
     /// - **Frame Accounting**: Styling frame sizes are automatically considered
+    /// - **Accessible Mode**: Renders the [`accessible_view`](Model::accessible_view)
     fn view(&self) -> String {
+        if crate::a11y::enabled() {
+            return self.accessible_view();
+        }
         let visible = self.visible_lines();
         let mut output = String::new();
 
@@ -2300,7 +2608,7 @@ impl BubbleTeaModel for Model {
 /// Calculates the display width of the longest line in a collection.
 ///
 /// This internal helper function determines the maximum display width among all
-/// provided lines, using proper Unicode width calculation via the `lg_width` function.
+/// provided lines, using proper Unicode width calculation via [`text::width`].
 /// This is essential for horizontal scrolling calculations and determining the
 /// maximum horizontal scroll offset.
 ///
@@ -2314,73 +2622,15 @@ impl BubbleTeaModel for Model {
 ///
 /// # Implementation Notes
 ///
-/// - Uses `lg_width()` for proper Unicode width calculation
+/// - Uses `text::width()` for proper Unicode width calculation
 /// - Handles empty collections gracefully
 /// - Accounts for wide characters (CJK, emojis, etc.)
 fn find_longest_line_width(lines: &[String]) -> usize {
-    lines.iter().map(|line| lg_width(line)).max().unwrap_or(0)
-}
-
-/// Extracts a substring based on display width positions for horizontal scrolling.
-///
-/// This internal helper function cuts a string to show only the portion between
-/// specified display width positions. It properly handles Unicode characters with
-/// varying display widths, making it essential for horizontal scrolling in the viewport.
-///
-/// # Arguments
-///
-/// * `s` - The source string to cut
-/// * `start` - The starting display width position (inclusive)
-/// * `end` - The ending display width position (exclusive)
-///
-/// # Returns
-///
-/// A string containing only the characters within the specified width range
-///
-/// # Implementation Details
-///
-/// - **Unicode-aware**: Properly handles wide characters (CJK, emojis)
-/// - **Width-based**: Uses display width, not character count
-/// - **Boundary safe**: Returns empty string if start is beyond string width
-/// - **Performance optimized**: Single pass through characters when possible
-///
-/// # Examples (Internal Use)
-///
-/// ```ignore
-/// // Wide characters take 2 display columns
-/// let result = cut_string("Hello 世界 World", 3, 8);
-/// // Shows characters from display column 3 to 7
-/// ```
-fn cut_string(s: &str, start: usize, end: usize) -> String {
-    if start >= lg_width(s) {
-        return String::new();
-    }
-
-    let chars: Vec<char> = s.chars().collect();
-    let mut current_width = 0;
-    let mut start_idx = 0;
-    let mut end_idx = chars.len();
-
-    // Find start index
-    for (i, &ch) in chars.iter().enumerate() {
-        if current_width >= start {
-            start_idx = i;
-            break;
-        }
-        current_width += ch.width().unwrap_or(0);
-    }
-
-    // Find end index
-    current_width = 0;
-    for (i, &ch) in chars.iter().enumerate() {
-        if current_width >= end {
-            end_idx = i;
-            break;
-        }
-        current_width += ch.width().unwrap_or(0);
-    }
-
-    chars[start_idx..end_idx].iter().collect()
+    lines
+        .iter()
+        .map(|line| text::width(line))
+        .max()
+        .unwrap_or(0)
 }
 
 /// Creates a new viewport with the specified dimensions.
@@ -2418,3 +2668,46 @@ fn cut_string(s: &str, start: usize, end: usize) -> String {
 pub fn new(width: usize, height: usize) -> Model {
     Model::new(width, height)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_smooth_scroll_glides_to_offset() {
+        let content: Vec<String> = (1..=50).map(|i| format!("Line {}", i)).collect();
+        let mut viewport = Model::new(20, 10).with_smooth_scroll(true);
+        viewport.set_content_lines(content);
+
+        let page_down = || -> Msg {
+            Box::new(KeyMsg {
+                key: KeyCode::PageDown,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        assert!(viewport.update(page_down()).is_some());
+        assert_eq!(viewport.y_offset, 10);
+
+        let mut tops = vec![viewport.shown_y_offset()];
+        let mut frames = 0;
+        while viewport.is_scrolling() {
+            let frame = anim::FrameMsg {
+                id: viewport.frames.id(),
+                tag: viewport.frames.tag(),
+            };
+            viewport.update(Box::new(frame));
+            tops.push(viewport.shown_y_offset());
+            frames += 1;
+            assert!(frames < 120, "never settled");
+        }
+        assert!(frames > 3, "jumped in {frames} frames");
+        assert!(tops.windows(2).all(|w| w[0] <= w[1]), "{tops:?}");
+        assert_eq!(viewport.visible_lines()[0], "Line 11");
+
+        // Without smooth scrolling nothing is animated
+        viewport.set_smooth_scroll(false);
+        assert!(viewport.update(page_down()).is_none());
+        assert_eq!(viewport.visible_lines()[0], "Line 21");
+    }
+}
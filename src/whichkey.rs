@@ -0,0 +1,443 @@
+//! "Which-key" popup listing the keys available after a leader key.
+//!
+//! Editors such as Emacs and Neovim show a small popup when a prefix key is
+//! pressed, listing every key that can follow it. This module provides the
+//! same for Bubble Tea applications: give the popup a leader
+//! [`Binding`](crate::key::Binding) and a [`KeyMap`](crate::key::KeyMap) of
+//! continuations. Pressing the leader opens the popup; the next key closes
+//! it, and if that key is one of the continuations a [`ChosenMsg`] names it.
+//! The popup also closes by itself after a timeout.
+//!
+//! While the popup is open, route key messages to it first and skip your own
+//! key handling, so the continuation is not interpreted twice.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::key::{self, KeyMap};
+//! use bubbletea_widgets::whichkey;
+//!
+//! struct GotoKeys {
+//!     top: key::Binding,
+//!     bottom: key::Binding,
+//! }
+//!
+//! impl KeyMap for GotoKeys {
+//!     fn short_help(&self) -> Vec<&key::Binding> {
+//!         vec![&self.top, &self.bottom]
+//!     }
+//!     fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+//!         vec![self.short_help()]
+//!     }
+//! }
+//!
+//! let goto = GotoKeys {
+//!     top: key::Binding::new(vec!["g"]).with_help("g", "top"),
+//!     bottom: key::Binding::new(vec!["e"]).with_help("e", "end"),
+//! };
+//! let leader = key::Binding::new(vec!["g"]).with_help("g", "goto");
+//!
+//! let mut popup = whichkey::new(leader, &goto);
+//! let _hide = popup.show();
+//! assert!(popup.visible());
+//! assert!(popup.view().contains("end"));
+//! ```
+
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::text;
+use crate::theme::Theme;
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// Message emitted when a continuation key is pressed while the popup is
+/// open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChosenMsg {
+    /// The ID of the popup.
+    pub id: i64,
+    /// Index of the continuation among the popup's entries.
+    pub index: usize,
+    /// Help key of the continuation, such as `"g"`.
+    pub key: String,
+    /// Help description of the continuation.
+    pub desc: String,
+}
+
+/// Message that closes the popup once its timeout has elapsed.
+#[derive(Debug, Clone, Copy)]
+pub struct HideMsg {
+    id: i64,
+    tag: i64,
+}
+
+/// Visual styles for the popup.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Border and padding around the popup.
+    pub frame: Style,
+    /// Title naming the leader key.
+    pub title: Style,
+    /// Continuation keys.
+    pub key: Style,
+    /// Arrow between a key and its description.
+    pub separator: Style,
+    /// Continuation descriptions.
+    pub desc: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            frame: Style::new()
+                .border(rounded_border())
+                .border_foreground(AdaptiveColor {
+                    Light: "#8E8CD8",
+                    Dark: "#874BFD",
+                })
+                .padding(0, 1, 0, 1),
+            title: Style::new().bold(true).foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#EE6FF8",
+            }),
+            key: Style::new().foreground(AdaptiveColor {
+                Light: "#5A56E0",
+                Dark: "#EE6FF8",
+            }),
+            separator: Style::new().foreground(AdaptiveColor {
+                Light: "#909090",
+                Dark: "#626262",
+            }),
+            desc: Style::new(),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates popup styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            frame: theme.frame.clone().padding(0, 1, 0, 1),
+            title: theme.title.clone(),
+            key: theme.accent.clone(),
+            separator: theme.subtle.clone(),
+            desc: theme.text.clone(),
+        }
+    }
+}
+
+/// A transient popup listing the continuations of a leader key.
+#[derive(Debug, Clone)]
+pub struct Model {
+    id: i64,
+    tag: i64,
+    visible: bool,
+    continuations: Vec<key::Binding>,
+    /// Key that opens the popup.
+    pub leader: key::Binding,
+    /// How long the popup stays open without input; zero disables the
+    /// timeout.
+    pub timeout: Duration,
+    /// Widest the popup may be, border included. Entries wrap into as many
+    /// columns as fit.
+    pub max_width: usize,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+/// Creates a hidden popup opened by `leader` listing the enabled bindings
+/// of `continuations`.
+pub fn new(leader: key::Binding, continuations: &dyn KeyMapTrait) -> Model {
+    Model::new(leader, continuations)
+}
+
+impl Model {
+    /// Creates a hidden popup opened by `leader` listing the enabled
+    /// bindings of `continuations`.
+    pub fn new(leader: key::Binding, continuations: &dyn KeyMapTrait) -> Self {
+        let mut m = Self {
+            id: next_id(),
+            tag: 0,
+            visible: false,
+            continuations: Vec::new(),
+            leader,
+            timeout: Duration::from_secs(2),
+            max_width: 60,
+            styles: Styles::default(),
+        };
+        m.set_keymap(continuations);
+        m
+    }
+
+    /// Sets how long the popup stays open without input.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the widest the popup may be.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Returns the unique ID of this popup.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Replaces the continuations with the enabled bindings of `keymap`
+    /// that have help text.
+    pub fn set_keymap(&mut self, keymap: &dyn KeyMapTrait) {
+        self.continuations = keymap
+            .full_help()
+            .into_iter()
+            .flatten()
+            .filter(|b| b.enabled() && !b.help().key.is_empty())
+            .cloned()
+            .collect();
+    }
+
+    /// Returns the listed `(key, description)` pairs.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.continuations
+            .iter()
+            .map(|b| (b.help().key.as_str(), b.help().desc.as_str()))
+    }
+
+    /// Returns whether the popup is open.
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Opens the popup and returns the command that closes it after the
+    /// timeout.
+    pub fn show(&mut self) -> Option<Cmd> {
+        self.visible = true;
+        self.tag += 1;
+        if self.timeout.is_zero() {
+            return None;
+        }
+        let (id, tag) = (self.id, self.tag);
+        Some(bubbletea_tick(self.timeout, move |_| {
+            Box::new(HideMsg { id, tag }) as Msg
+        }))
+    }
+
+    /// Closes the popup.
+    pub fn hide(&mut self) {
+        self.visible = false;
+        self.tag += 1;
+    }
+
+    /// Opens on the leader key and closes on the next key or the timeout.
+    /// A continuation key returns a [`ChosenMsg`] command.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(hide) = msg.downcast_ref::<HideMsg>() {
+            if hide.id == self.id && hide.tag == self.tag {
+                self.hide();
+            }
+            return None;
+        }
+        let key_msg = msg.downcast_ref::<KeyMsg>()?;
+        if !self.visible {
+            return if self.leader.matches(key_msg) {
+                self.show()
+            } else {
+                None
+            };
+        }
+        self.hide();
+        let index = self.continuations.iter().position(|b| b.matches(key_msg))?;
+        let help = self.continuations[index].help();
+        let chosen = ChosenMsg {
+            id: self.id,
+            index,
+            key: help.key.clone(),
+            desc: help.desc.clone(),
+        };
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(chosen.clone()) as Msg
+        }))
+    }
+
+    /// Renders the popup, or an empty string while it is closed.
+    pub fn view(&self) -> String {
+        if !self.visible {
+            return String::new();
+        }
+        let s = &self.styles;
+        let entries: Vec<(&str, &str)> = self.entries().collect();
+        let key_w = entries
+            .iter()
+            .map(|(k, _)| text::width(k))
+            .max()
+            .unwrap_or(0);
+        let desc_w = entries
+            .iter()
+            .map(|(_, d)| text::width(d))
+            .max()
+            .unwrap_or(0);
+        let cell_w = key_w + 3 + desc_w;
+        let gap = 3;
+        // Border and padding take two columns on each side.
+        let inner = self.max_width.saturating_sub(4).max(cell_w);
+        let columns = ((inner + gap) / (cell_w + gap)).clamp(1, entries.len().max(1));
+        let rows = entries.len().div_ceil(columns);
+
+        let mut lines = vec![s.title.render(&self.leader.help().key)];
+        for r in 0..rows {
+            let cells: Vec<String> = (0..columns)
+                .filter_map(|c| entries.get(c * rows + r))
+                .map(|(k, d)| {
+                    format!(
+                        "{}{}{}{}",
+                        s.key.render(k),
+                        " ".repeat(key_w - text::width(k) + 1),
+                        s.separator.render("→ "),
+                        s.desc.render(&format!("{:<w$}", d, w = desc_w))
+                    )
+                })
+                .collect();
+            lines.push(cells.join(&" ".repeat(gap)).trim_end().to_string());
+        }
+        s.frame.render(&lines.join("\n"))
+    }
+}
+
+/// Key map with no bindings, used by [`BubbleTeaModel::init`].
+struct NoContinuations;
+
+impl KeyMapTrait for NoContinuations {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        Vec::new()
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        Vec::new()
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        let leader = key::Binding::new(vec!["space"]).with_help("space", "leader");
+        (Self::new(leader, &NoContinuations), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use lipgloss_extras::lipgloss::strip_ansi;
+
+    struct Keys(Vec<key::Binding>);
+
+    impl KeyMapTrait for Keys {
+        fn short_help(&self) -> Vec<&key::Binding> {
+            self.0.iter().collect()
+        }
+        fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+            vec![self.short_help()]
+        }
+    }
+
+    fn key(c: char) -> Msg {
+        Box::new(KeyMsg {
+            key: KeyCode::Char(c),
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    fn popup() -> Model {
+        let keys = Keys(vec![
+            key::Binding::new(vec!["w"]).with_help("w", "save"),
+            key::Binding::new(vec!["q"]).with_help("q", "quit"),
+            key::Binding::new(vec!["x"])
+                .with_help("x", "hidden")
+                .with_disabled(),
+        ]);
+        let leader = key::Binding::new(vec![" "]).with_help("space", "leader");
+        Model::new(leader, &keys)
+    }
+
+    #[test]
+    fn test_leader_opens_and_next_key_closes() {
+        let mut p = popup();
+        assert!(p.update(key('w')).is_none());
+        assert!(!p.visible());
+        assert!(p.update(key(' ')).is_some());
+        assert!(p.visible());
+        assert!(p.update(key('z')).is_none());
+        assert!(!p.visible());
+    }
+
+    #[test]
+    fn test_continuation_is_chosen() {
+        let mut p = popup();
+        p.show();
+        assert!(p.update(key('q')).is_some());
+        assert!(!p.visible());
+        assert_eq!(p.entries().count(), 2);
+    }
+
+    #[test]
+    fn test_stale_timeout_is_ignored() {
+        let mut p = popup();
+        p.show();
+        let stale = HideMsg {
+            id: p.id(),
+            tag: p.tag,
+        };
+        p.hide();
+        p.show();
+        p.update(Box::new(stale));
+        assert!(p.visible());
+        p.update(Box::new(HideMsg {
+            id: p.id(),
+            tag: p.tag,
+        }));
+        assert!(!p.visible());
+    }
+
+    #[test]
+    fn test_view_lists_entries_in_columns() {
+        let mut p = popup().with_max_width(80);
+        assert_eq!(p.view(), "");
+        p.show();
+        let view = strip_ansi(&p.view());
+        assert!(view.contains("space"));
+        assert!(view.contains("w → save   q → quit"));
+        p.max_width = 10;
+        let narrow = strip_ansi(&p.view());
+        assert!(narrow.contains("w → save"));
+        assert!(!narrow.contains("save   q"));
+    }
+}
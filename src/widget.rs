@@ -0,0 +1,564 @@
+//! A common interface for every component.
+//!
+//! Each component has its own `update` and `view`, and they disagree on the
+//! details: most take the message by value, some by reference, and the text
+//! area renders through [`render`](textarea::Model::render) rather than its
+//! `&mut self` `view`. The [`Widget`] trait papers over those
+//! differences so containers can hold children as `Box<dyn Widget>`, route
+//! messages to them and lay them out without knowing their types.
+//!
+//! [`Widget::update`] takes the message by reference so a container can
+//! offer the same message to several children, as it does with a resize.
+//! Messages are owned boxes that cannot be cloned in general, so the
+//! implementations here copy the message with [`clone_msg`] before handing
+//! it to the component's own `update`. That covers the terminal events
+//! (keys, mouse, paste, resize, focus), every tick and control message
+//! defined in this crate and the types added with [`register_msg`]; any
+//! other message is dropped without reaching the component. Application
+//! messages a component should see can be registered, or passed to its own
+//! `update` directly.
+//!
+//! Layout goes through [`Widget::set_size`], which maps the given cells onto
+//! each component's own width and height settings, and
+//! [`Widget::desired_size`], which reports the size the component would like
+//! and defaults to the size of its current view.
+//!
+//! [`help`](crate::help) and [`search`](crate::search) render on behalf of
+//! another component, so they are not widgets themselves.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_rs::{KeyMsg, Msg, WindowSizeMsg};
+//! use bubbletea_widgets::widget::{self, Widget};
+//! use bubbletea_widgets::{spinner, textinput, viewport};
+//! use crossterm::event::{KeyCode, KeyModifiers};
+//!
+//! let mut log = viewport::new(10, 2);
+//! log.set_content("one\ntwo\nthree\nfour\nfive");
+//!
+//! let mut children: Vec<Box<dyn Widget>> = vec![
+//!     Box::new(spinner::new(&[])),
+//!     Box::new(log),
+//!     Box::new(textinput::new()),
+//! ];
+//!
+//! let resize: Msg = Box::new(WindowSizeMsg { width: 40, height: 12 });
+//! if let Some((width, height)) = widget::window_size(&resize) {
+//!     for child in children.iter_mut() {
+//!         child.set_size(width, height / 3);
+//!     }
+//! }
+//!
+//! let key: Msg = Box::new(KeyMsg { key: KeyCode::Down, modifiers: KeyModifiers::NONE });
+//! for child in children.iter_mut() {
+//!     let _cmd = child.update(&key);
+//! }
+//! assert!(children[1].view().starts_with("two"));
+//! ```
+
+use crate::compose;
+use crate::*;
+use bubbletea_rs::{
+    BlurMsg, Cmd, FocusMsg, KeyMsg, Model as BubbleTeaModel, MouseMsg, Msg, WindowSizeMsg,
+};
+use std::any::TypeId;
+use std::sync::{PoisonError, RwLock};
+
+/// A component that a container can update, render and resize without
+/// knowing its type.
+///
+/// Only [`update`](Widget::update) and [`view`](Widget::view) are required.
+pub trait Widget: Send {
+    /// Handles a message. The message is borrowed so the caller can offer
+    /// it to other widgets as well.
+    ///
+    /// The widgets in this crate only see messages that [`clone_msg`] can
+    /// copy; others are dropped, unless their type is added with
+    /// [`register_msg`].
+    fn update(&mut self, msg: &Msg) -> Option<Cmd>;
+
+    /// Renders the widget.
+    fn view(&self) -> String;
+
+    /// Gives the widget `width` x `height` cells. Widgets with a fixed size
+    /// ignore this.
+    fn set_size(&mut self, _width: usize, _height: usize) {}
+
+    /// Returns the size the widget would like as `(width, height)`. The
+    /// default measures the current view.
+    fn desired_size(&self) -> (usize, usize) {
+        compose::size(&self.view())
+    }
+}
+
+impl Widget for Box<dyn Widget> {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        self.as_mut().update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.as_ref().view()
+    }
+
+    fn set_size(&mut self, width: usize, height: usize) {
+        self.as_mut().set_size(width, height)
+    }
+
+    fn desired_size(&self) -> (usize, usize) {
+        self.as_ref().desired_size()
+    }
+}
+
+/// Returns the terminal size carried by a resize message.
+pub fn window_size(msg: &Msg) -> Option<(usize, usize)> {
+    msg.downcast_ref::<WindowSizeMsg>()
+        .map(|m| (m.width as usize, m.height as usize))
+}
+
+/// Space to leave around a component that follows the terminal size.
+///
+/// List, table, viewport, text area, help, progress and file picker take
+/// margins through their `auto_resize` builder. They then resize themselves
+/// on every [`WindowSizeMsg`] to the terminal size minus the margins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Margins {
+    /// Rows above the component.
+    pub top: usize,
+    /// Columns to the right of the component.
+    pub right: usize,
+    /// Rows below the component.
+    pub bottom: usize,
+    /// Columns to the left of the component.
+    pub left: usize,
+}
+
+impl Margins {
+    /// Creates margins in CSS order: top, right, bottom, left.
+    pub fn new(top: usize, right: usize, bottom: usize, left: usize) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Creates the same margin on every side.
+    pub fn all(margin: usize) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+
+    /// Creates margins of `vertical` rows above and below and `horizontal`
+    /// columns on either side.
+    pub fn symmetric(vertical: usize, horizontal: usize) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Returns the space left inside a `width` x `height` area.
+    pub fn inner(&self, width: usize, height: usize) -> (usize, usize) {
+        (
+            width.saturating_sub(self.left + self.right),
+            height.saturating_sub(self.top + self.bottom),
+        )
+    }
+}
+
+/// Returns the size a component with auto-resize `margins` should take for
+/// `msg`. This is `None` unless `msg` is a resize and margins are set.
+pub fn resized(msg: &Msg, margins: Option<Margins>) -> Option<(usize, usize)> {
+    let (width, height) = window_size(msg)?;
+    Some(margins?.inner(width, height))
+}
+
+fn copy<T: Clone + Send + 'static>(msg: &Msg) -> Option<Msg> {
+    msg.downcast_ref::<T>().map(|m| Box::new(m.clone()) as Msg)
+}
+
+type CopyFn = fn(&Msg) -> Option<Msg>;
+
+/// Message types added with [`register_msg`] and how to copy them.
+static REGISTERED: RwLock<Vec<(TypeId, CopyFn)>> = RwLock::new(Vec::new());
+
+/// Lets [`clone_msg`], and so every [`Widget`], pass on messages of type
+/// `T`, such as an application's own messages that a component handles.
+/// Registering a type twice has no further effect.
+///
+/// # Examples
+///
+/// ```rust
+/// use bubbletea_rs::Msg;
+/// use bubbletea_widgets::widget::{clone_msg, register_msg};
+///
+/// #[derive(Clone)]
+/// struct Refresh;
+///
+/// let msg: Msg = Box::new(Refresh);
+/// assert!(clone_msg(&msg).is_none());
+/// register_msg::<Refresh>();
+/// assert!(clone_msg(&msg).unwrap().is::<Refresh>());
+/// ```
+pub fn register_msg<T: Clone + Send + 'static>() {
+    let mut registered = REGISTERED.write().unwrap_or_else(PoisonError::into_inner);
+    if !registered.iter().any(|(id, _)| *id == TypeId::of::<T>()) {
+        registered.push((TypeId::of::<T>(), copy::<T>));
+    }
+}
+
+/// Copies a message that components react to: terminal events, the tick
+/// and control messages of this crate's components and the types added
+/// with [`register_msg`]. Returns `None` for any other message.
+pub fn clone_msg(msg: &Msg) -> Option<Msg> {
+    const COPIES: &[fn(&Msg) -> Option<Msg>] = &[
+        copy::<KeyMsg>,
+        copy::<MouseMsg>,
+        copy::<bubbletea_rs::PasteMsg>,
+        copy::<WindowSizeMsg>,
+        copy::<FocusMsg>,
+        copy::<BlurMsg>,
+        copy::<cursor::InitialBlinkMsg>,
+        copy::<cursor::BlinkMsg>,
+        copy::<textinput::PasteMsg>,
+        copy::<textinput::PasteErrMsg>,
+        copy::<textinput::HideLastCharMsg>,
+        #[cfg(feature = "textarea")]
+        copy::<textarea::PasteMsg>,
+        #[cfg(feature = "textarea")]
+        copy::<textarea::PasteErrMsg>,
+        copy::<ime::ImeMsg>,
+        copy::<spinner::TickMsg>,
+        copy::<anim::FrameMsg>,
+        #[cfg(feature = "timer")]
+        copy::<timer::TickMsg>,
+        #[cfg(feature = "timer")]
+        copy::<timer::StartStopMsg>,
+        #[cfg(feature = "stopwatch")]
+        copy::<stopwatch::TickMsg>,
+        #[cfg(feature = "stopwatch")]
+        copy::<stopwatch::StartStopMsg>,
+        #[cfg(feature = "stopwatch")]
+        copy::<stopwatch::ResetMsg>,
+        #[cfg(feature = "skeleton")]
+        copy::<skeleton::TickMsg>,
+        #[cfg(feature = "indicator")]
+        copy::<indicator::TickMsg>,
+        #[cfg(feature = "toast")]
+        copy::<toast::ShowMsg>,
+        #[cfg(feature = "toast")]
+        copy::<toast::TickMsg>,
+        #[cfg(feature = "whichkey")]
+        copy::<whichkey::HideMsg>,
+        copy::<search::QueryMsg>,
+        #[cfg(feature = "filepicker")]
+        copy::<filepicker::ReadDirMsg>,
+        #[cfg(feature = "filepicker")]
+        copy::<filepicker::ErrorMsg>,
+        #[cfg(feature = "form")]
+        copy::<form::SubmitMsg>,
+        #[cfg(feature = "list")]
+        copy::<list::StatusMessageTimeoutMsg>,
+    ];
+    COPIES.iter().find_map(|copy| copy(msg)).or_else(|| {
+        let registered = REGISTERED.read().unwrap_or_else(PoisonError::into_inner);
+        registered.iter().find_map(|(_, copy)| copy(msg))
+    })
+}
+
+// Components whose `update` takes an owned message; each entry maps
+// `set_size` onto the component's own size settings.
+macro_rules! owned_update {
+    ($ty:ty) => {
+        impl Widget for $ty {
+            fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+                BubbleTeaModel::update(self, clone_msg(msg)?)
+            }
+
+            fn view(&self) -> String {
+                BubbleTeaModel::view(self)
+            }
+        }
+    };
+    ($ty:ty, |$m:ident, $w:pat_param, $h:pat_param| $resize:expr) => {
+        impl Widget for $ty {
+            fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+                BubbleTeaModel::update(self, clone_msg(msg)?)
+            }
+
+            fn view(&self) -> String {
+                BubbleTeaModel::view(self)
+            }
+
+            fn set_size(&mut self, width: usize, height: usize) {
+                let ($m, $w, $h) = (self, width, height);
+                $resize
+            }
+        }
+    };
+}
+
+#[cfg(feature = "banner")]
+owned_update!(banner::Model, |m, w, _| m.width = w);
+#[cfg(feature = "charts")]
+owned_update!(barchart::Model, |m, w, h| (m.width, m.height) = (w, h));
+#[cfg(feature = "breadcrumb")]
+owned_update!(breadcrumb::Model, |m, w, _| m.width = w);
+#[cfg(feature = "charts")]
+owned_update!(chart::Model, |m, w, h| (m.width, m.height) = (w, h));
+#[cfg(feature = "chat")]
+owned_update!(chat::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "codeview")]
+owned_update!(codeview::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "colorpicker")]
+owned_update!(colorpicker::Model);
+#[cfg(feature = "confirm")]
+owned_update!(confirm::Model);
+#[cfg(feature = "dialog")]
+owned_update!(dialog::Model, |m, w, _| m.width = w);
+#[cfg(feature = "filepicker")]
+owned_update!(filepicker::Model, |m, _, h| m.set_height(h.max(1)));
+#[cfg(feature = "form")]
+owned_update!(form::Model);
+#[cfg(feature = "grid")]
+owned_update!(grid::Model, |m, w, h| (m.width, m.height) = (w, h));
+#[cfg(feature = "hexview")]
+owned_update!(hexview::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "inbox")]
+owned_update!(inbox::Model, |m, w, h| (m.width, m.height) = (w, h));
+#[cfg(feature = "indicator")]
+owned_update!(indicator::Model);
+#[cfg(feature = "logview")]
+owned_update!(logview::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "markdown")]
+owned_update!(markdown::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "menu")]
+owned_update!(menu::Model);
+#[cfg(feature = "outline")]
+owned_update!(outline::Model, |m, w, h| (m.width, m.height) = (w, h));
+#[cfg(feature = "progress")]
+owned_update!(progress::Model, |m, w, _| m.width = w as i32);
+#[cfg(feature = "rating")]
+owned_update!(rating::Model);
+#[cfg(feature = "charts")]
+owned_update!(ring::Model);
+#[cfg(feature = "scrollbar")]
+owned_update!(scrollbar::Model, |m, w, h| {
+    m.length = match m.orientation {
+        scrollbar::Orientation::Vertical => h,
+        scrollbar::Orientation::Horizontal => w,
+    }
+});
+#[cfg(feature = "skeleton")]
+owned_update!(skeleton::Model, |m, w, _| m.width = w);
+#[cfg(feature = "slider")]
+owned_update!(slider::Model, |m, w, _| m.length = w);
+#[cfg(feature = "charts")]
+owned_update!(sparkline::Model, |m, w, _| m.width = w);
+owned_update!(spinner::Model);
+#[cfg(feature = "stepper")]
+owned_update!(stepper::Model);
+#[cfg(feature = "stopwatch")]
+owned_update!(stopwatch::Model);
+#[cfg(feature = "table")]
+owned_update!(table::Model, |m, w, h| {
+    m.set_width(w as i32);
+    m.fit_height(h);
+});
+#[cfg(feature = "taginput")]
+owned_update!(taginput::Model);
+owned_update!(textinput::Model, |m, w, _| m.set_width(w as i32));
+#[cfg(feature = "timer")]
+owned_update!(timer::Model);
+#[cfg(feature = "toast")]
+owned_update!(toast::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "toggle")]
+owned_update!(toggle::Model);
+#[cfg(feature = "tooltip")]
+owned_update!(tooltip::Model, |m, w, _| m.max_width = w);
+#[cfg(feature = "viewport")]
+owned_update!(viewport::Model, |m, w, h| {
+    (m.width, m.height) = (w, h);
+    m.set_y_offset(m.y_offset);
+});
+#[cfg(feature = "whichkey")]
+owned_update!(whichkey::Model, |m, w, _| m.max_width = w);
+#[cfg(feature = "wizard")]
+owned_update!(wizard::Model);
+#[cfg(feature = "image")]
+owned_update!(image::Model, |m, w, h| m.set_size(w, h));
+#[cfg(feature = "json")]
+owned_update!(jsonview::Model, |m, w, h| (m.width, m.height) = (w, h));
+
+#[cfg(feature = "list")]
+impl<I: list::Item + Send + Sync + 'static> Widget for list::Model<I> {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        let msg = clone_msg(msg).or_else(|| copy::<list::ItemsLoadedMsg<I>>(msg))?;
+        BubbleTeaModel::update(self, msg)
+    }
+
+    fn view(&self) -> String {
+        BubbleTeaModel::view(self)
+    }
+
+    fn set_size(&mut self, width: usize, height: usize) {
+        list::Model::set_size(self, width, height);
+    }
+}
+
+#[cfg(feature = "choice")]
+impl<T: Send + 'static> Widget for choice::RadioGroup<T> {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        choice::RadioGroup::update(self, clone_msg(msg)?)
+    }
+
+    fn view(&self) -> String {
+        choice::RadioGroup::view(self)
+    }
+}
+
+#[cfg(feature = "choice")]
+impl<T: Send + 'static> Widget for choice::CheckboxGroup<T> {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        choice::CheckboxGroup::update(self, clone_msg(msg)?)
+    }
+
+    fn view(&self) -> String {
+        choice::CheckboxGroup::view(self)
+    }
+}
+
+#[cfg(feature = "select")]
+impl<T: Send + 'static> Widget for select::Model<T> {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        select::Model::update(self, clone_msg(msg)?)
+    }
+
+    fn view(&self) -> String {
+        select::Model::view(self)
+    }
+
+    fn set_size(&mut self, width: usize, _height: usize) {
+        self.width = width;
+    }
+}
+
+#[cfg(feature = "textarea")]
+impl Widget for textarea::Model {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        textarea::Model::update(self, Some(clone_msg(msg)?))
+    }
+
+    fn view(&self) -> String {
+        self.render()
+    }
+
+    fn set_size(&mut self, width: usize, height: usize) {
+        self.set_width(width);
+        self.set_height(height);
+    }
+}
+
+impl Widget for cursor::Model {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        cursor::Model::update(self, msg)
+    }
+
+    fn view(&self) -> String {
+        cursor::Model::view(self)
+    }
+}
+
+#[cfg(feature = "paginator")]
+impl Widget for paginator::Model {
+    fn update(&mut self, msg: &Msg) -> Option<Cmd> {
+        paginator::Model::update(self, msg);
+        None
+    }
+
+    fn view(&self) -> String {
+        paginator::Model::view(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_clone_msg_covers_events_and_ticks() {
+        let key: Msg = Box::new(KeyMsg {
+            key: KeyCode::Enter,
+            modifiers: KeyModifiers::NONE,
+        });
+        let copy = clone_msg(&key).unwrap();
+        assert_eq!(copy.downcast_ref::<KeyMsg>().unwrap().key, KeyCode::Enter);
+
+        let mut spinner = spinner::new(&[]);
+        let tick: Msg = Box::new(spinner.tick_msg());
+        assert!(clone_msg(&tick).is_some());
+        assert!(Widget::update(&mut spinner, &tick).is_some());
+
+        #[cfg(feature = "filepicker")]
+        {
+            let err: Msg = Box::new(filepicker::ErrorMsg {
+                id: 7,
+                err: crate::Error::filesystem(
+                    "/root",
+                    &std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied"),
+                ),
+            });
+            let copy = clone_msg(&err).unwrap();
+            assert_eq!(copy.downcast_ref::<filepicker::ErrorMsg>().unwrap().id, 7);
+        }
+
+        struct Custom;
+        let custom: Msg = Box::new(Custom);
+        assert!(clone_msg(&custom).is_none());
+    }
+
+    #[test]
+    fn test_same_message_reaches_every_child() {
+        let mut a = textinput::new();
+        let mut b = textinput::new();
+        std::mem::drop(a.focus());
+        std::mem::drop(b.focus());
+        let mut children: Vec<Box<dyn Widget>> = vec![Box::new(a), Box::new(b)];
+        let key: Msg = Box::new(KeyMsg {
+            key: KeyCode::Char('x'),
+            modifiers: KeyModifiers::NONE,
+        });
+        for child in children.iter_mut() {
+            child.update(&key);
+        }
+        assert!(children.iter().all(|c| c.view().contains('x')));
+    }
+
+    #[test]
+    fn test_resized_applies_margins() {
+        let msg: Msg = Box::new(WindowSizeMsg {
+            width: 80,
+            height: 24,
+        });
+        assert_eq!(resized(&msg, None), None);
+        assert_eq!(resized(&msg, Some(Margins::default())), Some((80, 24)));
+        assert_eq!(
+            resized(&msg, Some(Margins::new(1, 2, 3, 4))),
+            Some((74, 20))
+        );
+        assert_eq!(Margins::all(50).inner(80, 24), (0, 0));
+    }
+
+    #[test]
+    #[cfg(all(feature = "table", feature = "scrollbar"))]
+    fn test_set_size_reaches_component_settings() {
+        let mut table = table::Model::new(vec![table::Column::new("Name", 10)]);
+        Widget::set_size(&mut table, 30, 6);
+        // Two rows, with the borders and header
+        assert_eq!((table.width, table.height), (30, 2));
+
+        let mut bar = scrollbar::new(scrollbar::Orientation::Vertical, 3);
+        Widget::set_size(&mut bar, 1, 9);
+        assert_eq!(bar.length, 9);
+        assert_eq!(Widget::desired_size(&bar).1, 9);
+    }
+}
@@ -0,0 +1,604 @@
+//! Multi-step wizard that sequences child models.
+//!
+//! A wizard owns an ordered list of steps, each implementing the [`Step`]
+//! trait. Only the active step receives input. Moving forward runs the
+//! step's validation gate first, so users cannot skip past an incomplete
+//! page, while moving back is always allowed. A paginator or progress bar
+//! shows how far along the user is, and finishing the last step emits a
+//! [`CompletedMsg`] with the values of every step merged together.
+//!
+//! [`form::Model`](crate::form::Model) implements [`Step`], so the common
+//! case of a wizard made of several small forms needs no glue code: a form
+//! submitting on its last field also advances the wizard. Any other
+//! component can be a step once boxed as a [`Widget`].
+//!
+//! # Key Bindings
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | `ctrl+n` | Validate the current step and advance (or finish) |
+//! | `ctrl+p` | Go back one step |
+//!
+//! All other input is routed to the active step.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bubbletea_widgets::form::{Field, Model as Form};
+//! use bubbletea_widgets::wizard::{Indicator, Model};
+//!
+//! let mut wizard = Model::new()
+//!     .with_step("Account", Form::new(vec![Field::input("user", "Username")]))
+//!     .with_step("Plan", Form::new(vec![Field::select("plan", "Plan", &["Free", "Pro"])]))
+//!     .with_indicator(Indicator::Dots);
+//! let _cmd = wizard.init();
+//!
+//! assert_eq!(wizard.current(), 0);
+//! wizard.next_step();
+//! assert_eq!(wizard.current(), 1);
+//! assert!(wizard.view().contains("Plan"));
+//! ```
+
+use crate::error::Error;
+use crate::form::{self, Value};
+use crate::key::{self, KeyMap as KeyMapTrait};
+use crate::theme::Theme;
+use crate::widget::Widget;
+use crate::{paginator, progress};
+use bubbletea_rs::{tick as bubbletea_tick, Cmd, KeyMsg, Model as BubbleTeaModel, Msg};
+use lipgloss_extras::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+static LAST_ID: AtomicI64 = AtomicI64::new(0);
+
+fn next_id() -> i64 {
+    LAST_ID.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+/// A page of a wizard.
+///
+/// Only [`Step::update`] and [`Step::view`] are required; the remaining
+/// methods have no-op defaults.
+pub trait Step: Send {
+    /// Called when the step becomes active. Returns an optional command,
+    /// typically a focus or cursor blink command.
+    fn enter(&mut self) -> Option<Cmd> {
+        None
+    }
+
+    /// Handles a message while the step is active.
+    fn update(&mut self, msg: Msg) -> Option<Cmd>;
+
+    /// Renders the step.
+    fn view(&self) -> String;
+
+    /// Gate run before leaving the step forwards. An error keeps the wizard
+    /// on this step and is shown below it.
+    fn validate(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Values contributed to the wizard's aggregated results.
+    fn values(&self) -> HashMap<String, Value> {
+        HashMap::new()
+    }
+
+    /// Returns `true` if `msg` means the step has finished on its own, for
+    /// example a form emitting its submit message. The wizard then advances
+    /// as if the next key had been pressed.
+    fn completes_on(&self, _msg: &Msg) -> bool {
+        false
+    }
+}
+
+impl Step for form::Model {
+    fn enter(&mut self) -> Option<Cmd> {
+        self.init()
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        form::Model::update(self, msg)
+    }
+
+    fn view(&self) -> String {
+        form::Model::view(self)
+    }
+
+    fn validate(&mut self) -> Result<(), String> {
+        form::Model::validate(self).map_err(|i| {
+            self.focus_field(i);
+            "please fix the highlighted fields".to_string()
+        })
+    }
+
+    fn values(&self) -> HashMap<String, Value> {
+        form::Model::values(self)
+    }
+
+    fn completes_on(&self, msg: &Msg) -> bool {
+        msg.downcast_ref::<form::SubmitMsg>()
+            .is_some_and(|m| m.id == self.id())
+    }
+}
+
+/// Any boxed [`Widget`] can be a step. It has no validation gate and
+/// contributes no values.
+impl Step for Box<dyn Widget> {
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        Widget::update(self.as_mut(), &msg)
+    }
+
+    fn view(&self) -> String {
+        Widget::view(self.as_ref())
+    }
+}
+
+/// Message emitted when the last step is completed.
+#[derive(Debug, Clone)]
+pub struct CompletedMsg {
+    /// The ID of the wizard that completed.
+    pub id: i64,
+    /// Values of every step, merged in step order.
+    pub values: HashMap<String, Value>,
+}
+
+/// How the wizard shows its progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indicator {
+    /// Paginator dots, one per step.
+    Dots,
+    /// A progress bar filled to the completed fraction.
+    Bar,
+    /// No indicator.
+    None,
+}
+
+/// Key bindings for the wizard.
+#[derive(Debug, Clone)]
+pub struct WizardKeyMap {
+    /// Validates the step and advances. Default: `ctrl+n`.
+    pub next: key::Binding,
+    /// Returns to the previous step. Default: `ctrl+p`.
+    pub back: key::Binding,
+}
+
+impl Default for WizardKeyMap {
+    fn default() -> Self {
+        Self {
+            next: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+n"]),
+                key::with_help("ctrl+n", "next step"),
+            ]),
+            back: key::new_binding(vec![
+                key::with_keys_str(&["ctrl+p"]),
+                key::with_help("ctrl+p", "previous step"),
+            ]),
+        }
+    }
+}
+
+impl KeyMapTrait for WizardKeyMap {
+    fn short_help(&self) -> Vec<&key::Binding> {
+        vec![&self.next, &self.back]
+    }
+
+    fn full_help(&self) -> Vec<Vec<&key::Binding>> {
+        vec![vec![&self.next, &self.back]]
+    }
+}
+
+/// Visual styles for the wizard.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style of the step title.
+    pub title: Style,
+    /// Style of the "Step 1 of 3" counter.
+    pub counter: Style,
+    /// Style of validation errors.
+    pub error: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            title: Style::new().bold(true).foreground(Color::from("#F25D94")),
+            counter: Style::new().foreground(Color::from("240")),
+            error: Style::new().foreground(Color::from("196")),
+        }
+    }
+}
+
+impl Styles {
+    /// Creates wizard styles from a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            title: theme.title.clone(),
+            counter: theme.muted.clone(),
+            error: theme.error.clone(),
+        }
+    }
+}
+
+/// A multi-step wizard.
+pub struct Model {
+    id: i64,
+    steps: Vec<(String, Box<dyn Step>)>,
+    current: usize,
+    completed: bool,
+    err: Option<Error>,
+    /// Progress indicator shown below the step.
+    pub indicator: Indicator,
+    /// Paginator used by [`Indicator::Dots`].
+    pub paginator: paginator::Model,
+    /// Progress bar used by [`Indicator::Bar`].
+    pub progress: progress::Model,
+    /// Key bindings.
+    pub keymap: WizardKeyMap,
+    /// Visual styles.
+    pub styles: Styles,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model {
+    /// Creates an empty wizard showing paginator dots.
+    pub fn new() -> Self {
+        let mut paginator = paginator::Model::new();
+        paginator.paginator_type = paginator::Type::Dots;
+        Self {
+            id: next_id(),
+            steps: Vec::new(),
+            current: 0,
+            completed: false,
+            err: None,
+            indicator: Indicator::Dots,
+            paginator,
+            progress: progress::new(&[progress::with_width(40)]),
+            keymap: WizardKeyMap::default(),
+            styles: Styles::default(),
+        }
+    }
+
+    /// Appends a step with the given title.
+    pub fn with_step(mut self, title: &str, step: impl Step + 'static) -> Self {
+        self.add_step(title, step);
+        self
+    }
+
+    /// Sets the progress indicator.
+    pub fn with_indicator(mut self, indicator: Indicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Sets the styles.
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
+    /// Appends a step with the given title.
+    pub fn add_step(&mut self, title: &str, step: impl Step + 'static) {
+        self.steps.push((title.to_string(), Box::new(step)));
+        self.paginator.set_total_pages(self.steps.len());
+    }
+
+    /// Activates the first step and returns its enter command.
+    pub fn init(&mut self) -> Option<Cmd> {
+        self.go_to(0)
+    }
+
+    /// Returns the unique ID of this wizard.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Returns the index of the active step.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the number of steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns whether the wizard has no steps.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the title of the active step.
+    pub fn title(&self) -> Option<&str> {
+        self.steps.get(self.current).map(|(t, _)| t.as_str())
+    }
+
+    /// Returns whether the last step has been completed.
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Returns the validation error of the active step, if any.
+    pub fn error(&self) -> Option<&Error> {
+        self.err.as_ref()
+    }
+
+    /// Returns the values of every step, merged in step order.
+    pub fn values(&self) -> HashMap<String, Value> {
+        self.steps
+            .iter()
+            .flat_map(|(_, step)| step.values())
+            .collect()
+    }
+
+    fn go_to(&mut self, index: usize) -> Option<Cmd> {
+        if index >= self.steps.len() {
+            return None;
+        }
+        self.current = index;
+        self.paginator.page = index;
+        self.err = None;
+        self.steps[index].1.enter()
+    }
+
+    /// Validates the active step and advances.
+    ///
+    /// On the last step this marks the wizard completed and returns a
+    /// command emitting [`CompletedMsg`]. A failed validation keeps the
+    /// wizard in place and records the error.
+    pub fn next_step(&mut self) -> Option<Cmd> {
+        let (_, step) = self.steps.get_mut(self.current)?;
+        if let Err(err) = step.validate() {
+            self.err = Some(Error::Validation(err));
+            return None;
+        }
+        if self.current + 1 < self.steps.len() {
+            return self.go_to(self.current + 1);
+        }
+        self.err = None;
+        self.completed = true;
+        let id = self.id;
+        let values = self.values();
+        Some(bubbletea_tick(Duration::from_nanos(1), move |_| {
+            Box::new(CompletedMsg {
+                id,
+                values: values.clone(),
+            }) as Msg
+        }))
+    }
+
+    /// Returns to the previous step without validating.
+    pub fn prev_step(&mut self) -> Option<Cmd> {
+        self.completed = false;
+        self.go_to(self.current.checked_sub(1)?)
+    }
+
+    /// Handles wizard navigation and routes other messages to the active step.
+    pub fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        if let Some(key_msg) = msg.downcast_ref::<KeyMsg>() {
+            if self.keymap.next.matches(key_msg) {
+                return self.next_step();
+            }
+            if self.keymap.back.matches(key_msg) {
+                return self.prev_step();
+            }
+        }
+        let (_, step) = self.steps.get_mut(self.current)?;
+        if step.completes_on(&msg) {
+            return self.next_step();
+        }
+        step.update(msg)
+    }
+
+    fn indicator_view(&self) -> String {
+        match self.indicator {
+            Indicator::Dots => self.paginator.view(),
+            Indicator::Bar => {
+                let done = if self.completed {
+                    self.steps.len()
+                } else {
+                    self.current
+                };
+                self.progress
+                    .view_as(done as f64 / self.steps.len().max(1) as f64)
+            }
+            Indicator::None => String::new(),
+        }
+    }
+
+    /// Renders the active step with its title, error and progress indicator.
+    pub fn view(&self) -> String {
+        let Some((title, step)) = self.steps.get(self.current) else {
+            return String::new();
+        };
+        let header = format!(
+            "{}  {}",
+            self.styles.title.render(title),
+            self.styles.counter.render(&format!(
+                "Step {} of {}",
+                self.current + 1,
+                self.steps.len()
+            )),
+        );
+        let mut parts = vec![header, step.view()];
+        if let Some(err) = &self.err {
+            parts.push(self.styles.error.render(&err.to_string()));
+        }
+        let indicator = self.indicator_view();
+        if !indicator.is_empty() {
+            parts.push(indicator);
+        }
+        parts.join("\n\n")
+    }
+}
+
+impl crate::theme::Themeable for Model {
+    fn apply_theme(&mut self, theme: &Theme) {
+        self.styles = Styles::from_theme(theme);
+    }
+}
+
+impl BubbleTeaModel for Model {
+    fn init() -> (Self, Option<Cmd>) {
+        (Self::new(), None)
+    }
+
+    fn update(&mut self, msg: Msg) -> Option<Cmd> {
+        self.update(msg)
+    }
+
+    fn view(&self) -> String {
+        self.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    struct Named {
+        name: String,
+        required: bool,
+    }
+
+    impl Step for Named {
+        fn update(&mut self, msg: Msg) -> Option<Cmd> {
+            if let Some(KeyMsg {
+                key: KeyCode::Char(c),
+                ..
+            }) = msg.downcast_ref::<KeyMsg>()
+            {
+                self.name.push(*c);
+            }
+            None
+        }
+
+        fn view(&self) -> String {
+            format!("name: {}", self.name)
+        }
+
+        fn validate(&mut self) -> Result<(), String> {
+            if self.required && self.name.is_empty() {
+                return Err("name is required".to_string());
+            }
+            Ok(())
+        }
+
+        fn values(&self) -> HashMap<String, Value> {
+            HashMap::from([("name".to_string(), Value::Text(self.name.clone()))])
+        }
+    }
+
+    fn ctrl(c: char) -> Msg {
+        Box::new(KeyMsg {
+            key: KeyCode::Char(c),
+            modifiers: KeyModifiers::CONTROL,
+        })
+    }
+
+    fn wizard() -> Model {
+        Model::new()
+            .with_step(
+                "First",
+                Named {
+                    name: String::new(),
+                    required: true,
+                },
+            )
+            .with_step(
+                "Second",
+                Named {
+                    name: String::new(),
+                    required: false,
+                },
+            )
+    }
+
+    #[test]
+    fn test_validation_gates_next() {
+        let mut w = wizard();
+        w.init();
+        assert!(w.update(ctrl('n')).is_none());
+        assert_eq!(w.current(), 0);
+        assert_eq!(
+            w.error(),
+            Some(&Error::Validation("name is required".into()))
+        );
+        assert!(w.view().contains("name is required"));
+
+        w.update(Box::new(KeyMsg {
+            key: KeyCode::Char('a'),
+            modifiers: KeyModifiers::NONE,
+        }));
+        w.update(ctrl('n'));
+        assert_eq!(w.current(), 1);
+        assert!(w.error().is_none());
+    }
+
+    #[test]
+    fn test_back_and_complete() {
+        let mut w = wizard();
+        w.init();
+        assert!(w.update(ctrl('p')).is_none());
+        w.update(Box::new(KeyMsg {
+            key: KeyCode::Char('z'),
+            modifiers: KeyModifiers::NONE,
+        }));
+        w.next_step();
+        w.prev_step();
+        assert_eq!(w.current(), 0);
+        w.next_step();
+        assert!(w.next_step().is_some());
+        assert!(w.completed());
+        assert_eq!(w.values()["name"], Value::Text(String::new()));
+    }
+
+    #[test]
+    fn test_boxed_widget_step() {
+        let confirm: Box<dyn Widget> = Box::new(crate::confirm::new("Proceed?"));
+        let mut w = Model::new().with_step("Confirm", confirm);
+        w.init();
+        assert!(w.view().contains("Proceed?"));
+    }
+
+    #[test]
+    fn test_indicators() {
+        let mut w = wizard().with_indicator(Indicator::Bar);
+        w.init();
+        assert!(w.view().contains("Step 1 of 2"));
+        assert!(w.view().contains("0%"));
+        w.indicator = Indicator::Dots;
+        assert_eq!(w.paginator.total_pages, 2);
+        assert!(w.view().contains(&w.paginator.view()));
+    }
+
+    #[test]
+    fn test_form_submit_advances() {
+        let first = form::Model::new(vec![form::Field::input("a", "A")]);
+        let first_id = first.id();
+        let mut w = Model::new()
+            .with_step("One", first)
+            .with_step("Two", form::Model::new(vec![form::Field::input("b", "B")]));
+        w.init();
+
+        let submit = |id| -> Msg {
+            Box::new(form::SubmitMsg {
+                id,
+                values: HashMap::new(),
+            })
+        };
+        // A submit message from an unrelated form is ignored.
+        w.update(submit(first_id + 1000));
+        assert_eq!(w.current(), 0);
+        w.update(submit(first_id));
+        assert_eq!(w.current(), 1);
+        assert_eq!(w.values().len(), 2);
+    }
+}